@@ -218,7 +218,10 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("LogicalPosition".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("Coord".into(), "float".into()),
@@ -282,6 +285,10 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "SwipeGestureRecognizer",
+        "DragArea",
+        "DropArea",
+        "PinchGestureRecognizer",
+        "Shortcut",
         "Flickable",
         "SimpleText",
         "ComplexText",
@@ -293,6 +300,8 @@ fn gen_corelib(
         "Rotate",
         "Opacity",
         "Layer",
+        "DropShadow",
+        "Blur",
     ];
 
     config.export.include = [
@@ -316,6 +325,7 @@ fn gen_corelib(
         "PointerEventButton",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -367,10 +377,14 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "slint_color_brighter",
         "slint_color_darker",
+        "slint_color_saturate",
+        "slint_color_desaturate",
         "slint_color_transparentize",
         "slint_color_mix",
         "slint_color_with_alpha",
@@ -444,7 +458,9 @@ fn gen_corelib(
         .with_src(crate_dir.join("properties.rs"))
         .with_src(crate_dir.join("properties/ffi.rs"))
         .with_src(crate_dir.join("callbacks.rs"))
-        .with_after_include("namespace slint { class Color; class Brush; }")
+        .with_after_include(
+            "namespace slint { class Color; class Brush; namespace private_api { struct PathData; } namespace cbindgen_private { using private_api::PathData; } }",
+        )
         .generate()
         .context("Unable to generate bindings for slint_properties_internal.h")?
         .write_to_file(include_dir.join("slint_properties_internal.h"));
@@ -496,15 +512,20 @@ fn gen_corelib(
                 "slint_image_set_nine_slice_edges",
                 "SharedPixelBuffer",
                 "SharedImageBuffer",
+                "Rgb565Pixel",
+                "Gray8Pixel",
+                "Alpha8Pixel",
                 "StaticTextures",
                 "BorrowedOpenGLTextureOrigin"
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; struct RemoteImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImageFrame{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
+            "slint_color_saturate",
+            "slint_color_desaturate",
             "slint_color_transparentize",
             "slint_color_mix",
             "slint_color_with_alpha",
@@ -521,8 +542,15 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
-            vec!["Color"],
+            vec![
+                "Brush",
+                "LinearGradient",
+                "GradientStop",
+                "RadialGradient",
+                "ConicGradient",
+                "ImagePattern",
+            ],
+            vec!["Color", "Image", "PatternTiling"],
             "slint_brush_internal.h",
             "",
         ),
@@ -570,6 +598,8 @@ fn gen_corelib(
             "slint_new_path_events",
             "slint_color_brighter",
             "slint_color_darker",
+            "slint_color_saturate",
+            "slint_color_desaturate",
             "slint_color_transparentize",
             "slint_color_mix",
             "slint_color_with_alpha",
@@ -218,6 +218,8 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return std::equal(a.spring._0, a.spring._0 + 3, b.spring._0);
     }
     return true;
 }
@@ -287,6 +289,12 @@ struct ModelChangeListener
     virtual void row_removed(size_t index, size_t count) = 0;
     virtual void row_changed(size_t index) = 0;
     virtual void reset() = 0;
+    /// The default implementation reports the move as a removal followed by an insertion.
+    virtual void row_moved(size_t old_index, size_t new_index, size_t count)
+    {
+        row_removed(old_index, count);
+        row_added(new_index, count);
+    }
 };
 using ModelPeer = std::weak_ptr<ModelChangeListener>;
 
@@ -313,6 +321,16 @@ long int model_length(const std::shared_ptr<M> &model)
     }
 }
 
+template<typename M>
+auto model_to_shared_vector(const std::shared_ptr<M> &model)
+{
+    SharedVector<std::decay_t<decltype(access_array_index(model, 0))>> result;
+    for (long int i = 0, length = model_length(model); i < length; ++i) {
+        result.push_back(access_array_index(model, i));
+    }
+    return result;
+}
+
 } // namespace private_api
 
 /// \rst
@@ -402,6 +420,18 @@ protected:
         model_row_data_dirty_property.mark_dirty();
         for_each_peers([=](auto peer) { peer->row_removed(index, count); });
     }
+    /// Notify the views that \a count rows starting at \a old_index were moved, so that they
+    /// now start at \a new_index. The views keep the state of the moved rows, such as the
+    /// instances created by a `for` loop.
+    void row_moved(size_t old_index, size_t new_index, size_t count)
+    {
+        if (old_index == new_index || count == 0) {
+            return;
+        }
+        tracked_rows.clear();
+        model_row_data_dirty_property.mark_dirty();
+        for_each_peers([=](auto peer) { peer->row_moved(old_index, new_index, count); });
+    }
 
     /// Notify the views that the model has been changed and that everything needs to be reloaded
     void reset()
@@ -537,6 +567,20 @@ public:
         this->row_added(index, 1);
     }
 
+    /// Moves \a count rows starting at \a old_index so that they start at \a new_index once
+    /// moved. The views keep the state of the moved rows.
+    void move_rows(size_t old_index, size_t new_index, size_t count)
+    {
+        if (old_index < new_index) {
+            std::rotate(data.begin() + old_index, data.begin() + old_index + count,
+                        data.begin() + new_index + count);
+        } else {
+            std::rotate(data.begin() + new_index, data.begin() + old_index,
+                        data.begin() + old_index + count);
+        }
+        this->row_moved(old_index, new_index, count);
+    }
+
     /// Erases all rows from the VectorModel.
     void clear()
     {
@@ -762,6 +806,10 @@ struct MapModelInner : private_api::ModelChangeListener
     {
         target_model.row_removed(index, count);
     }
+    void row_moved(size_t old_index, size_t new_index, size_t count) override
+    {
+        target_model.row_moved(old_index, new_index, count);
+    }
     void reset() override { target_model.Model<MappedModelData>::reset(); }
 
     slint::MapModel<SourceModelData, MappedModelData> &target_model;
@@ -1032,6 +1080,13 @@ struct ReverseModelInner : private_api::ModelChangeListener
         target_model.row_removed(source_model->row_count() - first_removed_row, count);
     }
 
+    void row_moved(size_t old_index, size_t new_index, size_t count) override
+    {
+        auto row_count = source_model->row_count();
+        target_model.row_moved(row_count - old_index - count, row_count - new_index - count,
+                               count);
+    }
+
     void reset() override { target_model.reset(); }
 
     std::shared_ptr<slint::Model<ModelData>> source_model;
@@ -1125,6 +1180,23 @@ class Repeater
                 data[i].state = State::Dirty;
             }
         }
+        void row_moved(size_t old_index, size_t new_index, size_t count) override
+        {
+            is_dirty.set(true);
+            auto first = std::min(old_index, new_index);
+            auto last = std::max(old_index, new_index) + count;
+            if (old_index < new_index) {
+                std::rotate(data.begin() + old_index, data.begin() + old_index + count,
+                            data.begin() + last);
+            } else {
+                std::rotate(data.begin() + new_index, data.begin() + old_index,
+                            data.begin() + last);
+            }
+            for (std::size_t i = first; i < last; ++i) {
+                // the instances are kept, but their index changed
+                data[i].state = State::Dirty;
+            }
+        }
         void reset() override
         {
             is_dirty.set(true);
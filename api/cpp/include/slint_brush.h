@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 #pragma once
+#include <optional>
 #include <string_view>
+#include <vector>
 #include "slint_color.h"
+#include "slint_image.h"
 #include "slint_brush_internal.h"
 #include "slint_string.h"
 
@@ -61,24 +64,40 @@ private:
 };
 
 /// \private
-/// RadialGradientBrush represents a circular gradient centered in the middle
+/// RadialGradientBrush represents a circular gradient with a center and a radius
 class RadialGradientBrush
 {
 public:
-    /// Constructs an empty linear gradient with no color stops.
-    RadialGradientBrush() = default;
-    /// Constructs a new circular radial gradient . The color stops will be
+    /// Constructs an empty circular radial gradient centered in the middle, with no color stops.
+    RadialGradientBrush() : inner(make_circle_gradient(0.5, 0.5, 1., nullptr, 0)) { }
+    /// Constructs a new circular radial gradient centered in the middle. The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
     RadialGradientBrush(const GradientStop *firstStop, int stopCount)
-        : inner(make_circle_gradient(firstStop, stopCount))
+        : inner(make_circle_gradient(0.5, 0.5, 1., firstStop, stopCount))
+    {
+    }
+    /// Constructs a new circular radial gradient with the center at (\a centerX, \a centerY),
+    /// relative to the size of the shape, and the \a radius relative to the default radius. The
+    /// color stops will be constructed from the stops array pointed to be \a firstStop, with the
+    /// length \a stopCount.
+    RadialGradientBrush(float centerX, float centerY, float radius, const GradientStop *firstStop,
+                        int stopCount)
+        : inner(make_circle_gradient(centerX, centerY, radius, firstStop, stopCount))
     {
     }
 
+    /// Returns the x coordinate of the center, relative to the width of the shape.
+    float centerX() const { return inner[0].position; }
+    /// Returns the y coordinate of the center, relative to the height of the shape.
+    float centerY() const { return inner[1].position; }
+    /// Returns the radius, relative to the default radius of half the size of the shape.
+    float radius() const { return inner[2].position; }
+
     /// Returns the number of gradient stops.
-    int stopCount() const { return int(inner.size()); }
+    int stopCount() const { return int(inner.size()) - fake_stop_count; }
 
     /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
-    const GradientStop *stopsBegin() const { return inner.begin(); }
+    const GradientStop *stopsBegin() const { return inner.begin() + fake_stop_count; }
     /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
     /// it can only be used for comparison.
     const GradientStop *stopsEnd() const { return inner.end(); }
@@ -88,18 +107,116 @@ private:
 
     friend class slint::Brush;
 
+    // The gradient's first stops are fake stops to store the center and the radius
+    static constexpr int fake_stop_count = 3;
+
+    static SharedVector<private_api::GradientStop>
+    make_circle_gradient(float centerX, float centerY, float radius, const GradientStop *firstStop,
+                         int stopCount)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, centerX });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, centerY });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, radius });
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
+/// \private
+/// ConicGradientBrush represents a gradient where the colors are interpolated around a center
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient starting at 0 degrees and centered in the middle, with no
+    /// color stops.
+    ConicGradientBrush() : inner(make_conic_gradient(0., 0.5, 0.5, nullptr, 0)) { }
+    /// Constructs a new conic gradient starting at \a fromAngle (in degrees), with the center at
+    /// (\a centerX, \a centerY), relative to the size of the shape. The color stops will be
+    /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
+    ConicGradientBrush(float fromAngle, float centerX, float centerY,
+                       const GradientStop *firstStop, int stopCount)
+        : inner(make_conic_gradient(fromAngle, centerX, centerY, firstStop, stopCount))
+    {
+    }
+
+    /// Returns the angle in degrees at which the gradient starts.
+    float fromAngle() const { return inner[0].position; }
+    /// Returns the x coordinate of the center, relative to the width of the shape.
+    float centerX() const { return inner[1].position; }
+    /// Returns the y coordinate of the center, relative to the height of the shape.
+    float centerY() const { return inner[2].position; }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - fake_stop_count; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + fake_stop_count; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    // The gradient's first stops are fake stops to store the angle and the center
+    static constexpr int fake_stop_count = 3;
+
     static SharedVector<private_api::GradientStop>
-    make_circle_gradient(const GradientStop *firstStop, int stopCount)
+    make_conic_gradient(float fromAngle, float centerX, float centerY,
+                        const GradientStop *firstStop, int stopCount)
     {
         SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, fromAngle });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, centerX });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, centerY });
         for (int i = 0; i < stopCount; ++i, ++firstStop)
             gradient.push_back(*firstStop);
         return gradient;
     }
 };
 
+
+/// \private
+/// ImagePatternBrush represents a fill that repeats an image.
+class ImagePatternBrush
+{
+public:
+    /// Constructs a new pattern that repeats \a image according to \a tiling.
+    ImagePatternBrush(const Image &image, cbindgen_private::PatternTiling tiling)
+        : inner { image.data, tiling, 0., 0., 1. }
+    {
+    }
+
+    /// Returns the image repeated by this pattern.
+    Image image() const { return Image(inner.image); }
+    /// Returns the tiling mode of this pattern.
+    cbindgen_private::PatternTiling tiling() const { return inner.tiling; }
+
+private:
+    cbindgen_private::types::ImagePatternBrush inner;
+
+    friend class slint::Brush;
+};
+
 }
 
+/// GradientStop describes a single color stop in a gradient. The colors between multiple stops
+/// are interpolated.
+struct GradientStop
+{
+    /// The color to draw at this stop.
+    Color color;
+    /// The position of this stop on the entire shape, as a normalized value between 0 and 1.
+    float position;
+
+    /// Returns true if \a a and \a b have the same color and position; false otherwise.
+    friend bool operator==(const GradientStop &a, const GradientStop &b) = default;
+};
+
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
 /// brush is either a solid color or a linear gradient.
 class Brush
@@ -123,10 +240,39 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
+    /// \private
+    /// Constructs a new brush that fills with the image pattern \a pattern.
+    Brush(const private_api::ImagePatternBrush &pattern)
+        : data(Inner::ImagePattern(pattern.inner))
+    {
+    }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
-    /// of the first stop.
+    /// of the first stop. If the brush is an image pattern, a transparent color is returned.
     inline Color color() const;
 
+    /// Returns the color stops of the brush, sorted by position, if it is a linear, radial, or
+    /// conic gradient. For a solid color or an image pattern, an empty vector is returned.
+    inline std::vector<GradientStop> stops() const;
+
+    /// Returns the angle in degrees of the brush if it is a linear gradient; std::nullopt
+    /// otherwise.
+    std::optional<float> angle() const
+    {
+        if (data.tag != Tag::LinearGradient || data.linear_gradient._0.size() == 0) {
+            return std::nullopt;
+        }
+        // The gradient's first stop is a fake stop to store the angle
+        return data.linear_gradient._0[0].position;
+    }
+
     /// Returns a new version of this brush that has the brightness increased
     /// by the specified factor. This is done by calling Color::brighter on
     /// all the colors of this brush.
@@ -135,6 +281,14 @@ public:
     /// by the specified factor. This is done by calling Color::darker on
     /// all the colors of this brush.
     [[nodiscard]] inline Brush darker(float factor) const;
+    /// Returns a new version of this brush that has the saturation increased
+    /// by the specified factor. This is done by calling Color::saturate on
+    /// all the colors of this brush.
+    [[nodiscard]] inline Brush saturate(float factor) const;
+    /// Returns a new version of this brush that has the saturation decreased
+    /// by the specified factor. This is done by calling Color::desaturate on
+    /// all the colors of this brush.
+    [[nodiscard]] inline Brush desaturate(float factor) const;
 
     /// Returns a new version of this brush with the opacity decreased by \a factor.
     ///
@@ -173,10 +327,58 @@ Color Brush::color() const
         }
         break;
     case Tag::RadialGradient:
-        if (data.radial_gradient._0.size() > 0) {
-            result.inner = data.radial_gradient._0[0].color;
+        if (data.radial_gradient._0.size() > private_api::RadialGradientBrush::fake_stop_count) {
+            result.inner =
+                    data.radial_gradient._0[private_api::RadialGradientBrush::fake_stop_count]
+                            .color;
         }
         break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > private_api::ConicGradientBrush::fake_stop_count) {
+            result.inner =
+                    data.conic_gradient._0[private_api::ConicGradientBrush::fake_stop_count]
+                            .color;
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
+    }
+    return result;
+}
+
+inline std::vector<GradientStop> Brush::stops() const
+{
+    const private_api::GradientStop *begin = nullptr, *end = nullptr;
+    switch (data.tag) {
+    case Tag::LinearGradient:
+        if (data.linear_gradient._0.size() > 1) {
+            begin = data.linear_gradient._0.begin() + 1;
+            end = data.linear_gradient._0.end();
+        }
+        break;
+    case Tag::RadialGradient:
+        if (data.radial_gradient._0.size() > private_api::RadialGradientBrush::fake_stop_count) {
+            begin = data.radial_gradient._0.begin()
+                    + private_api::RadialGradientBrush::fake_stop_count;
+            end = data.radial_gradient._0.end();
+        }
+        break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > private_api::ConicGradientBrush::fake_stop_count) {
+            begin = data.conic_gradient._0.begin()
+                    + private_api::ConicGradientBrush::fake_stop_count;
+            end = data.conic_gradient._0.end();
+        }
+        break;
+    case Tag::SolidColor:
+    case Tag::ImagePattern:
+        break;
+    }
+    std::vector<GradientStop> result;
+    for (auto it = begin; it != end; ++it) {
+        Color color;
+        color.inner = it->color;
+        result.push_back({ color, it->position });
     }
     return result;
 }
@@ -196,11 +398,21 @@ inline Brush Brush::brighter(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_brighter(&data.radial_gradient._0[i].color, factor,
                                                           &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(&data.conic_gradient._0[i].color, factor,
+                                                          &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -220,11 +432,92 @@ inline Brush Brush::darker(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_darker(&data.radial_gradient._0[i].color, factor,
                                                         &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(&data.conic_gradient._0[i].color, factor,
+                                                        &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
+    }
+    return result;
+}
+
+inline Brush Brush::saturate(float factor) const
+{
+    Brush result = *this;
+    switch (data.tag) {
+    case Tag::SolidColor:
+        cbindgen_private::types::slint_color_saturate(&data.solid_color._0, factor,
+                                                      &result.data.solid_color._0);
+        break;
+    case Tag::LinearGradient:
+        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_saturate(&data.linear_gradient._0[i].color, factor,
+                                                          &result.data.linear_gradient._0[i].color);
+        }
+        break;
+    case Tag::RadialGradient:
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_saturate(&data.radial_gradient._0[i].color, factor,
+                                                          &result.data.radial_gradient._0[i].color);
+        }
+        break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_saturate(&data.conic_gradient._0[i].color, factor,
+                                                          &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
+    }
+    return result;
+}
+
+inline Brush Brush::desaturate(float factor) const
+{
+    Brush result = *this;
+    switch (data.tag) {
+    case Tag::SolidColor:
+        cbindgen_private::types::slint_color_desaturate(&data.solid_color._0, factor,
+                                                        &result.data.solid_color._0);
+        break;
+    case Tag::LinearGradient:
+        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_desaturate(
+                    &data.linear_gradient._0[i].color, factor,
+                    &result.data.linear_gradient._0[i].color);
+        }
+        break;
+    case Tag::RadialGradient:
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_desaturate(
+                    &data.radial_gradient._0[i].color, factor,
+                    &result.data.radial_gradient._0[i].color);
+        }
+        break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_desaturate(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -245,12 +538,23 @@ inline Brush Brush::transparentize(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_transparentize(
                     &data.radial_gradient._0[i].color, factor,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -271,12 +575,23 @@ inline Brush Brush::with_alpha(float alpha) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = private_api::RadialGradientBrush::fake_stop_count;
+             i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_with_alpha(
                     &data.radial_gradient._0[i].color, alpha,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = private_api::ConicGradientBrush::fake_stop_count;
+             i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(
+                    &data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
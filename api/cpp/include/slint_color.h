@@ -12,6 +12,8 @@ namespace slint {
 
 namespace private_api {
 class LinearGradientBrush;
+class RadialGradientBrush;
+class ConicGradientBrush;
 }
 
 class Color;
@@ -181,6 +183,16 @@ public:
     /// result is converted back to RGB and the alpha channel is unchanged.
     /// So for example `darker(0.3)` will decrease the brightness by 30%.
     [[nodiscard]] inline Color darker(float factor) const;
+    /// Returns a new version of this color that has the saturation increased
+    /// by the specified factor. This is done by converting the color to the HSV
+    /// color space and multiplying the saturation with (1 + factor), clamped to 1.
+    /// The result is converted back to RGB and the alpha channel is unchanged.
+    [[nodiscard]] inline Color saturate(float factor) const;
+    /// Returns a new version of this color that has the saturation decreased
+    /// by the specified factor. This is done by converting the color to the HSV
+    /// color space and dividing the saturation by (1 + factor). The result is
+    /// converted back to RGB and the alpha channel is unchanged.
+    [[nodiscard]] inline Color desaturate(float factor) const;
 
     /// Returns a new version of this color with the opacity decreased by \a factor.
     ///
@@ -217,6 +229,8 @@ public:
 private:
     cbindgen_private::types::Color inner;
     friend class private_api::LinearGradientBrush;
+    friend class private_api::RadialGradientBrush;
+    friend class private_api::ConicGradientBrush;
     friend class Brush;
 };
 
@@ -234,6 +248,20 @@ inline Color Color::darker(float factor) const
     return result;
 }
 
+inline Color Color::saturate(float factor) const
+{
+    Color result;
+    cbindgen_private::types::slint_color_saturate(&inner, factor, &result.inner);
+    return result;
+}
+
+inline Color Color::desaturate(float factor) const
+{
+    Color result;
+    cbindgen_private::types::slint_color_desaturate(&inner, factor, &result.inner);
+    return result;
+}
+
 inline Color Color::transparentize(float factor) const
 {
     Color result;
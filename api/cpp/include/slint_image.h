@@ -12,6 +12,10 @@
 
 namespace slint {
 
+namespace private_api {
+class ImagePatternBrush;
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to copy.
 ///
@@ -143,6 +147,13 @@ public:
     /// top-left. This is different from the default OpenGL coordinate system. If you want to
     /// flip the origin, use BorrowedOpenGLTextureOrigin::BottomLeft.
     ///
+    /// The texture is not copied, and only renderers that use OpenGL draw it. Check that the
+    /// graphics API passed to the rendering notifier is `NativeOpenGL` before using this function.
+    /// The texture must stay valid until the next frame was rendered after the image was replaced.
+    /// If the texture is written by a different, shared OpenGL context, that context must create a
+    /// fence with `glFenceSync`, and the rendering notifier must wait on it with `glWaitSync` before
+    /// Slint renders the frame.
+    ///
     /// Safety:
     ///
     /// This function is unsafe because invalid texture ids may lead to undefined behavior in OpenGL
@@ -233,6 +244,7 @@ private:
     using Tag = cbindgen_private::types::ImageInner::Tag;
     using Data = cbindgen_private::types::Image;
     Data data;
+    friend class private_api::ImagePatternBrush;
 };
 
 namespace private_api {
@@ -4,6 +4,7 @@
 #pragma once
 #include <initializer_list>
 #include <string_view>
+#include "slint_point.h"
 #include "slint_pathdata_internal.h"
 
 namespace slint::private_api {
@@ -27,8 +28,8 @@ public:
     {
     }
 
-    PathData(const PathEvent *firstEvent, size_t event_count, const Point *firstCoordinate,
-             size_t coordinate_count)
+    PathData(const PathEvent *firstEvent, size_t event_count,
+             const LogicalPosition *firstCoordinate, size_t coordinate_count)
         : data(events_from_array(firstEvent, event_count, firstCoordinate, coordinate_count))
     {
     }
@@ -49,13 +50,12 @@ private:
         return tmp;
     }
 
-    static cbindgen_private::types::PathData events_from_array(const PathEvent *firstEvent,
-                                                               size_t event_count,
-                                                               const Point *firstCoordinate,
-                                                               size_t coordinate_count)
+    static cbindgen_private::types::PathData
+    events_from_array(const PathEvent *firstEvent, size_t event_count,
+                      const LogicalPosition *firstCoordinate, size_t coordinate_count)
     {
         SharedVector<PathEvent> events;
-        SharedVector<Point> coordinates;
+        SharedVector<LogicalPosition> coordinates;
         slint_new_path_events(&events, &coordinates, firstEvent, event_count, firstCoordinate,
                               coordinate_count);
         return Data::Events(events, coordinates);
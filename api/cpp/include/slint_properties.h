@@ -4,6 +4,7 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <type_traits>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -24,40 +25,72 @@ inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, int *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*started)(void *), void (*finished)(void *))
 {
     cbindgen_private::slint_property_set_animated_binding_int(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, transition_data,
+            started, finished);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, float *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*started)(void *), void (*finished)(void *))
 {
     cbindgen_private::slint_property_set_animated_binding_float(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, transition_data,
+            started, finished);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Color *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*started)(void *), void (*finished)(void *))
 {
     cbindgen_private::slint_property_set_animated_binding_color(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, transition_data,
+            started, finished);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Brush *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*started)(void *), void (*finished)(void *))
 {
     cbindgen_private::slint_property_set_animated_binding_brush(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, transition_data,
+            started, finished);
+}
+
+inline void slint_property_set_animated_binding_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, PathData *),
+        void *user_data, void (*drop_user_data)(void *),
+        const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *),
+        void (*started)(void *), void (*finished)(void *))
+{
+    cbindgen_private::slint_property_set_animated_binding_path(
+            handle, binding, user_data, drop_user_data, animation_data, transition_data,
+            started, finished);
+}
+
+/// Returns a function calling the `callback` member of the UserData, or nullptr if there is no
+/// such callback.
+template<typename UserData, typename Callback, Callback UserData::*callback>
+constexpr auto animation_callback() -> void (*)(void *)
+{
+    if constexpr (std::is_same_v<Callback, std::nullptr_t>) {
+        return nullptr;
+    } else {
+        return [](void *user_data) { (reinterpret_cast<UserData *>(user_data)->*callback)(); };
+    }
 }
 
 template<typename T>
@@ -106,26 +139,42 @@ struct Property
 
     inline void set_animated_value(const T &value,
                                    const cbindgen_private::PropertyAnimation &animation_data) const;
-    template<typename F>
-    inline void
-    set_animated_binding(F binding, const cbindgen_private::PropertyAnimation &animation_data) const
+    template<typename F, typename Started = std::nullptr_t, typename Finished = std::nullptr_t>
+    inline void set_animated_binding(F binding,
+                                     const cbindgen_private::PropertyAnimation &animation_data,
+                                     Started started = nullptr, Finished finished = nullptr) const
     {
+        struct UserData
+        {
+            F binding;
+            Started started;
+            Finished finished;
+        };
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
                 [](void *user_data, T *value) {
-                    *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
+                    *reinterpret_cast<T *>(value) =
+                            reinterpret_cast<UserData *>(user_data)->binding();
                 },
-                new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
-                &animation_data, nullptr);
+                new UserData { binding, started, finished },
+                [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); },
+                &animation_data, nullptr,
+                private_api::animation_callback<UserData, Started, &UserData::started>(),
+                private_api::animation_callback<UserData, Finished, &UserData::finished>());
     }
 
-    template<typename F, typename Trans>
-    inline void set_animated_binding_for_transition(F binding, Trans animation) const
+    template<typename F, typename Trans, typename Started = std::nullptr_t,
+             typename Finished = std::nullptr_t>
+    inline void set_animated_binding_for_transition(F binding, Trans animation,
+                                                    Started started = nullptr,
+                                                    Finished finished = nullptr) const
     {
         struct UserData
         {
             F binding;
             Trans animation;
+            Started started;
+            Finished finished;
         };
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
@@ -133,11 +182,13 @@ struct Property
                     *reinterpret_cast<T *>(value) =
                             reinterpret_cast<UserData *>(user_data)->binding();
                 },
-                new UserData { binding, animation },
+                new UserData { binding, animation, started, finished },
                 [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
                 [](void *user_data, uint64_t *instant) {
                     return reinterpret_cast<UserData *>(user_data)->animation(instant);
-                });
+                },
+                private_api::animation_callback<UserData, Started, &UserData::started>(),
+                private_api::animation_callback<UserData, Finished, &UserData::finished>());
     }
 
     bool is_dirty() const { return cbindgen_private::slint_property_is_dirty(&inner); }
@@ -418,36 +418,48 @@ public:
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
     ///
-    /// The \a text is the unicode representation of the key.
-    void dispatch_key_press_event(const SharedString &text)
+    /// The \a text is the unicode representation of the key. The \a key_code is the key pressed
+    /// without the effect of the modifiers, or empty if it's not known, then \a text is used. The
+    /// \a scan_code is the platform specific code of the physical key, or 0 if it's not known.
+    void dispatch_key_press_event(const SharedString &text, const SharedString &key_code = {},
+                                  int scan_code = 0)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, false);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, &key_code,
+                scan_code, false);
     }
 
     /// Dispatch an auto-repeated key press event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
     ///
-    /// The \a text is the unicode representation of the key.
-    void dispatch_key_press_repeat_event(const SharedString &text)
+    /// The \a text is the unicode representation of the key. The \a key_code is the key pressed
+    /// without the effect of the modifiers, or empty if it's not known, then \a text is used. The
+    /// \a scan_code is the platform specific code of the physical key, or 0 if it's not known.
+    void dispatch_key_press_repeat_event(const SharedString &text,
+                                         const SharedString &key_code = {}, int scan_code = 0)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, true);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, &key_code,
+                scan_code, true);
     }
 
     /// Dispatch a key release event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
     ///
-    /// The \a text is the unicode representation of the key.
-    void dispatch_key_release_event(const SharedString &text)
+    /// The \a text is the unicode representation of the key. The \a key_code is the key released
+    /// without the effect of the modifiers, or empty if it's not known, then \a text is used. The
+    /// \a scan_code is the platform specific code of the physical key, or 0 if it's not known.
+    void dispatch_key_release_event(const SharedString &text, const SharedString &key_code = {},
+                                    int scan_code = 0)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyReleased, &text, false);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyReleased, &text, &key_code,
+                scan_code, false);
     }
 
     /// Dispatches a pointer or mouse press event to the scene.
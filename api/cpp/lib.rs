@@ -125,7 +125,7 @@ unsafe impl Send for UserData {}
     let window_adapter = &*(win as *const Rc<dyn WindowAdapter>);
     core::ptr::write(
         error_str,
-        match window_adapter.renderer().register_font_from_memory(data.as_slice()) {
+        match window_adapter.renderer().register_font_from_memory(data.as_slice().into()) {
             Ok(()) => Default::default(),
             Err(err) => err.to_string().into(),
         },
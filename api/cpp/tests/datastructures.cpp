@@ -200,6 +200,40 @@ TEST_CASE("Image")
     }
 }
 
+TEST_CASE("Brush gradient stops")
+{
+    using namespace slint;
+
+    auto red = Color::from_rgb_uint8(255, 0, 0);
+    auto blue = Color::from_rgb_uint8(0, 0, 255);
+
+    Brush solid(red);
+    REQUIRE(solid.stops().empty());
+    REQUIRE(!solid.angle().has_value());
+
+    private_api::GradientStop stops[] = { { red, 0.f }, { blue, 1.f } };
+    Brush linear(private_api::LinearGradientBrush(90.f, stops, 2));
+    REQUIRE(linear.angle() == 90.f);
+    REQUIRE(linear.stops() == std::vector<GradientStop> { { red, 0.f }, { blue, 1.f } });
+
+    Brush radial(private_api::RadialGradientBrush(stops, 2));
+    REQUIRE(!radial.angle().has_value());
+    REQUIRE(radial.stops() == std::vector<GradientStop> { { red, 0.f }, { blue, 1.f } });
+
+    private_api::RadialGradientBrush empty_radial;
+    REQUIRE(empty_radial.centerX() == 0.5f);
+    REQUIRE(empty_radial.centerY() == 0.5f);
+    REQUIRE(empty_radial.radius() == 1.f);
+    REQUIRE(empty_radial.stopCount() == 0);
+    REQUIRE(Brush(empty_radial).stops().empty());
+
+    private_api::ConicGradientBrush empty_conic;
+    REQUIRE(empty_conic.fromAngle() == 0.f);
+    REQUIRE(empty_conic.centerX() == 0.5f);
+    REQUIRE(empty_conic.centerY() == 0.5f);
+    REQUIRE(empty_conic.stopCount() == 0);
+}
+
 TEST_CASE("SharedVector")
 {
     using namespace slint;
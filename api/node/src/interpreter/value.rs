@@ -261,6 +261,7 @@ fn try_convert_image<BufferType: AsRef<[u8]> + FromNapiValue>(
         | Type::ComponentFactory { .. }
         | Type::Easing
         | Type::PathData
+        | Type::LengthArray
         | Type::LayoutCache
         | Type::ElementReference => Err(napi::Error::from_reason("reason")),
     }
@@ -297,8 +297,22 @@ pub fn to_string(&self) -> String {
                 );
             }
             Brush::RadialGradient(gradient) => {
+                let center = gradient.center();
                 return format!(
-                    "radial-gradient(circle, {})",
+                    "radial-gradient(circle {}% at {}% {}%, {})",
+                    gradient.radius() * 100.,
+                    center.x * 100.,
+                    center.y * 100.,
+                    gradient_stops_to_string(gradient.stops())
+                );
+            }
+            Brush::ConicGradient(gradient) => {
+                let center = gradient.center();
+                return format!(
+                    "conic-gradient(from {}deg at {}% {}%, {})",
+                    gradient.from_angle(),
+                    center.x * 100.,
+                    center.y * 100.,
                     gradient_stops_to_string(gradient.stops())
                 );
             }
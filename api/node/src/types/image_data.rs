@@ -68,6 +68,13 @@ pub fn data(&self) -> Buffer {
                         (self.width() * self.height()) as usize,
                     ))
                 }
+                SharedImageBuffer::RGB565(_)
+                | SharedImageBuffer::Gray8(_)
+                | SharedImageBuffer::Alpha8(_) => {
+                    if let Some(buffer) = self.inner.to_rgba8() {
+                        return Buffer::from(buffer.as_bytes());
+                    }
+                }
             }
         }
 
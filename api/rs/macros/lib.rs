@@ -434,3 +434,341 @@ pub fn slint(stream: TokenStream) -> TokenStream {
     }
     result
 }
+
+/// Returns the named fields of the struct the derive macro is applied to
+fn named_fields(input: &syn::DeriveInput) -> syn::Result<&syn::FieldsNamed> {
+    match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(fields), .. }) => Ok(fields),
+        _ => {
+            Err(syn::Error::new(input.ident.span(), "Only `struct` with named field are supported"))
+        }
+    }
+}
+
+/// Calls the parser for each item of the attributes with the given name, like `#[sync(...)]`
+fn parse_attributes(
+    attrs: &[syn::Attribute],
+    name: &str,
+    mut parser: impl FnMut(syn::meta::ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(name)) {
+        attr.parse_nested_meta(&mut parser)?;
+    }
+    Ok(())
+}
+
+/// The attributes of a field of a struct with the `SyncProperties` or `ConvertStruct` derive
+struct FieldAttributes {
+    /// The name of the property or struct field, as written in the `.slint` file
+    name: String,
+    skip: bool,
+    /// Only set for `SyncProperties`
+    out: bool,
+}
+
+/// Parses `name = "..."`, `skip`, and (if `allow_out` is set) `out` in the attributes of the field
+fn parse_field_attributes(
+    field: &syn::Field,
+    attr_name: &str,
+    allow_out: bool,
+) -> syn::Result<FieldAttributes> {
+    let mut result = FieldAttributes {
+        name: field.ident.as_ref().unwrap().to_string(),
+        skip: false,
+        out: false,
+    };
+    parse_attributes(&field.attrs, attr_name, |meta| {
+        if meta.path.is_ident("name") {
+            result.name = meta.value()?.parse::<syn::LitStr>()?.value();
+        } else if meta.path.is_ident("skip") {
+            result.skip = true;
+        } else if allow_out && meta.path.is_ident("out") {
+            result.out = true;
+        } else if allow_out {
+            return Err(meta.error("expected `name = \"...\"`, `skip`, or `out`"));
+        } else {
+            return Err(meta.error("expected `name = \"...\"` or `skip`"));
+        }
+        Ok(())
+    })?;
+    Ok(result)
+}
+
+/// This derive macro maps the fields of a struct to the properties of a component, to copy
+/// the values between the struct and the component without a call to each getter and setter.
+///
+/// The `#[sync(component = MyComponent)]` attribute specifies the component. Each field is synced
+/// with the property that has the same name, unless renamed with `#[sync(name = "other-name")]`.
+/// Fields marked with `#[sync(skip)]` are ignored, and fields marked with `#[sync(out)]` are only
+/// read from the component, for `out` properties. The types of the fields must be convertible
+/// with [`Into`] from and to the types of the properties.
+///
+/// The macro generates these functions:
+///  - `sync_to(&self, instance: &MyComponent)` sets the properties to the values of the fields.
+///  - `sync_from(&mut self, instance: &MyComponent)` sets the fields to the values of the properties.
+///  - `on_changed(instance: &MyComponent, handler: impl Fn(&MyComponent) + 'static) -> PropertyChangeSubscription`
+///    calls the handler from the event loop when one of the properties changes, as long as the returned
+///    subscription is alive.
+///
+/// ```rust,ignore
+/// slint::slint! {
+///     export component Settings inherits Window {
+///         in-out property <string> user-name;
+///         in-out property <float> volume;
+///         out property <bool> modified;
+///     }
+/// }
+///
+/// #[derive(Default, slint::SyncProperties)]
+/// #[sync(component = Settings)]
+/// struct Config {
+///     #[sync(name = "user-name")]
+///     user: String,
+///     volume: f32,
+///     #[sync(out)]
+///     modified: bool,
+///     #[sync(skip)]
+///     path: std::path::PathBuf,
+/// }
+///
+/// let ui = Settings::new().unwrap();
+/// let mut config = Config::default();
+/// config.sync_to(&ui);
+/// config.sync_from(&ui);
+/// ```
+#[proc_macro_derive(SyncProperties, attributes(sync))]
+pub fn sync_properties(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match sync_properties_impl(&input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn sync_properties_impl(input: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = named_fields(input)?;
+
+    let mut component = None;
+    parse_attributes(&input.attrs, "sync", |meta| {
+        if meta.path.is_ident("component") {
+            component = Some(meta.value()?.parse::<syn::Path>()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `component = ...`"))
+        }
+    })?;
+    let component = component.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "Missing `#[sync(component = ...)]` attribute with the type of the component",
+        )
+    })?;
+
+    let mut in_fields = Vec::new();
+    let mut setters = Vec::new();
+    let mut out_fields = Vec::new();
+    let mut getters = Vec::new();
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().unwrap();
+        let attributes = parse_field_attributes(field, "sync", true)?;
+        if attributes.skip {
+            continue;
+        }
+        let name = attributes.name.replace('-', "_");
+        if !attributes.out {
+            in_fields.push(field_ident);
+            setters.push(quote::format_ident!("set_{}", name, span = field_ident.span()));
+        }
+        out_fields.push(field_ident);
+        getters.push(quote::format_ident!("get_{}", name, span = field_ident.span()));
+    }
+
+    // The values of all the properties, as nested pairs so that they can be compared whatever their number
+    let values =
+        getters.iter().rev().fold(quote!(()), |rest, getter| quote!((instance.#getter(), #rest)));
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[allow(dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Sets the properties of the component to the values of the fields.
+            pub fn sync_to(&self, instance: &#component) {
+                #(instance.#setters(::core::convert::Into::into(::core::clone::Clone::clone(&self.#in_fields)));)*
+            }
+
+            /// Sets the fields to the values of the properties of the component.
+            pub fn sync_from(&mut self, instance: &#component) {
+                #(self.#out_fields = ::core::convert::Into::into(instance.#getters());)*
+            }
+
+            /// Calls the handler from the event loop when one of the properties of the component
+            /// changes, as long as the returned subscription is alive.
+            pub fn on_changed(
+                instance: &#component,
+                handler: impl Fn(&#component) + 'static,
+            ) -> ::slint::PropertyChangeSubscription {
+                ::slint::PropertyChangeSubscription::new(
+                    ::slint::ComponentHandle::as_weak(instance),
+                    |instance: &::slint::Weak<#component>| {
+                        instance.upgrade().map(|instance| #values)
+                    },
+                    move |instance, _| {
+                        if let ::core::option::Option::Some(instance) = instance.upgrade() {
+                            handler(&instance)
+                        }
+                    },
+                )
+            }
+        }
+    })
+}
+
+/// This derive macro implements the conversions between a Rust struct and a struct declared in
+/// a `.slint` file, so that the Rust type can be used in the application logic and converted when
+/// it's passed to the user interface.
+///
+/// The `#[convert(into = MyStruct)]` attribute specifies the type generated for the `.slint` struct.
+/// The macro implements `From<MyRustType> for MyStruct` and `From<MyStruct> for MyRustType`. Each
+/// field is converted from and to the field of the `.slint` struct that has the same name, unless
+/// renamed with `#[convert(name = "other-name")]`. Dashes in names are mapped to underscores, like
+/// in the generated code. Fields marked with `#[convert(skip)]` are ignored, and set to their default
+/// value when converting from the `.slint` struct. Fields of the `.slint` struct without a matching
+/// field are set to their default value. The types of the fields must be convertible with [`Into`].
+///
+/// With the `#[convert(interpreter)]` attribute, the macro also implements the conversion to and
+/// from `::slint_interpreter::Value`, with a `Value::Struct`, for the applications that load `.slint`
+/// files at run-time.
+///
+/// A model of the Rust type can then be mapped to a model of the `.slint` struct:
+///
+/// ```rust,ignore
+/// slint::slint! {
+///     export struct TodoItem { title: string, is-done: bool }
+///     export component TodoList inherits Window {
+///         in property <[TodoItem]> items;
+///     }
+/// }
+///
+/// #[derive(Clone, slint::ConvertStruct)]
+/// #[convert(into = TodoItem)]
+/// struct Task {
+///     title: String,
+///     #[convert(name = "is-done")]
+///     done: bool,
+///     #[convert(skip)]
+///     id: u64,
+/// }
+///
+/// use slint::ModelExt;
+/// let tasks = std::rc::Rc::new(slint::VecModel::from(vec![
+///     Task { title: "Write the documentation".into(), done: false, id: 1 },
+/// ]));
+/// let ui = TodoList::new().unwrap();
+/// ui.set_items(slint::ModelRc::new(tasks.clone().map(TodoItem::from)));
+/// ```
+#[proc_macro_derive(ConvertStruct, attributes(convert))]
+pub fn convert_struct(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match convert_struct_impl(&input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn convert_struct_impl(input: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = named_fields(input)?;
+
+    let mut targets = Vec::new();
+    let mut interpreter = false;
+    parse_attributes(&input.attrs, "convert", |meta| {
+        if meta.path.is_ident("into") {
+            targets.push(meta.value()?.parse::<syn::Path>()?);
+        } else if meta.path.is_ident("interpreter") {
+            interpreter = true;
+        } else {
+            return Err(meta.error("expected `into = ...` or `interpreter`"));
+        }
+        Ok(())
+    })?;
+    if targets.is_empty() && !interpreter {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Missing `#[convert(into = ...)]` attribute with the type of the struct, or `#[convert(interpreter)]`",
+        ));
+    }
+
+    let mut converted = Vec::new();
+    let mut names = Vec::new();
+    let mut skipped = Vec::new();
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().unwrap();
+        let attributes = parse_field_attributes(field, "convert", false)?;
+        if attributes.skip {
+            skipped.push(field_ident);
+        } else {
+            converted.push(field_ident);
+            names.push(attributes.name.replace('_', "-"));
+        }
+    }
+    let target_fields = names
+        .iter()
+        .zip(&converted)
+        .map(|(name, field)| {
+            quote::format_ident!("{}", name.replace('-', "_"), span = field.span())
+        })
+        .collect::<Vec<_>>();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut result = proc_macro2::TokenStream::new();
+    for target in &targets {
+        result.extend(quote! {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #target #where_clause {
+                #[allow(clippy::needless_update)]
+                fn from(value: #ident #ty_generics) -> Self {
+                    Self {
+                        #(#target_fields: ::core::convert::Into::into(value.#converted),)*
+                        ..::core::default::Default::default()
+                    }
+                }
+            }
+
+            impl #impl_generics ::core::convert::From<#target> for #ident #ty_generics #where_clause {
+                fn from(value: #target) -> Self {
+                    Self {
+                        #(#converted: ::core::convert::Into::into(value.#target_fields),)*
+                        #(#skipped: ::core::default::Default::default(),)*
+                    }
+                }
+            }
+        });
+    }
+    if interpreter {
+        result.extend(quote! {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::slint_interpreter::Value #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    let mut struct_ = ::slint_interpreter::Struct::default();
+                    #(struct_.set_field(::core::convert::Into::into(#names), ::core::convert::Into::into(value.#converted));)*
+                    ::slint_interpreter::Value::Struct(struct_)
+                }
+            }
+
+            impl #impl_generics ::core::convert::TryFrom<::slint_interpreter::Value> for #ident #ty_generics #where_clause {
+                type Error = ();
+                fn try_from(value: ::slint_interpreter::Value) -> ::core::result::Result<Self, ()> {
+                    let ::slint_interpreter::Value::Struct(struct_) = value else {
+                        return ::core::result::Result::Err(());
+                    };
+                    ::core::result::Result::Ok(Self {
+                        #(#converted: ::core::convert::TryFrom::try_from(
+                            ::core::clone::Clone::clone(struct_.get_field(#names).ok_or(())?)
+                        ).map_err(|_| ())?,)*
+                        #(#skipped: ::core::default::Default::default(),)*
+                    })
+                }
+            }
+        });
+    }
+    Ok(result)
+}
@@ -56,6 +56,21 @@ pub fn get_counter(&self) -> i32 {
         /// In this case, this is the setter that sets the value of the `counter` property
         /// declared in the `.slint` design markup.
         pub fn set_counter(&self, value: i32) {}
+        /// For each property of the root of the component that can be animated, such as numbers,
+        /// lengths, and colors, a setter is generated that animates the property to the new value
+        /// with the given animation. The regular setter uses the animation of the `animate` block
+        /// declared for the property, if any.
+        pub fn set_counter_with_animation(&self, value: i32, animation: crate::Animation) {}
+        /// A function to observe the changes of each property declared at the root of the component
+        /// is generated. This is the function that registers the function f to be called with the new
+        /// value of the `counter` property when it changes. The function is called from the event loop,
+        /// and it is unregistered when the returned subscription is dropped.
+        pub fn on_counter_changed(
+            &self,
+            f: impl Fn(i32) + 'static,
+        ) -> crate::PropertyChangeSubscription {
+            unimplemented!()
+        }
         /// Returns the value of the `user_name` property declared in the `.slint` design markup.
         pub fn get_user_name(&self) -> crate::SharedString {
             unimplemented!()
@@ -136,11 +136,18 @@ struct MyComponent{ /*...*/ }
 For each top-level property
   - A setter [`fn set_<property_name>(&self, value: <PropertyType>)`](docs::generated_code::SampleComponent::set_counter)
   - A getter [`fn get_<property_name>(&self) -> <PropertyType>`](docs::generated_code::SampleComponent::get_counter)
+  - A change handler [`fn on_<property_name>_changed(&self, callback: impl Fn(<PropertyType>) + 'static) -> PropertyChangeSubscription`](docs::generated_code::SampleComponent::on_counter_changed)
 
 For each top-level callback
   - [`fn invoke_<callback_name>(&self)`](docs::generated_code::SampleComponent::invoke_hello): to invoke the callback
   - [`fn on_<callback_name>(&self, callback: impl Fn(<CallbackArgs>) + 'static)`](docs::generated_code::SampleComponent::on_hello): to set the callback handler.
 
+If the component declares an `out property <string> focused-element` without a binding, the compiler binds it to the id
+of the element that has the focus, and the component also has
+  - `fn get_focused_element(&self) -> SharedString`: the id of the element that has the focus, or an empty string
+  - `fn on_focused_element_changed(&self, callback: impl Fn(SharedString) + 'static) -> PropertyChangeSubscription`: to observe the focus
+  - `fn invoke_focus_element(&self, id: SharedString) -> bool`: to move the focus to the element with that id. Returns false if there is no such element.
+
 Note: All dashes (`-`) are replaced by underscores (`_`) in names of types or functions.
 
 After instantiating the component, call [`ComponentHandle::run()`] on show it on the screen and spin the event loop to
@@ -174,6 +181,7 @@ struct MyComponent{ /*...*/ }
 For each property
   - A setter: `fn set_<property_name>(&self, value: <PropertyType>)`
   - A getter: `fn get_<property_name>(&self) -> <PropertyType>`
+  - A change handler: `fn on_<property_name>_changed(&self, callback: impl Fn(<PropertyType>) + 'static) -> PropertyChangeSubscription`
 
 For each callback
   - `fn invoke_<callback_name>(&self, <CallbackArgs>) -> <ReturnValue>` to invoke the callback
@@ -202,20 +210,33 @@ struct MyComponent{ /*...*/ }
 );
 
 pub use slint_macros::slint;
+pub use slint_macros::ConvertStruct;
+pub use slint_macros::SyncProperties;
 
 pub use i_slint_core::api::*;
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
 pub use i_slint_core::component_factory::ComponentFactory;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use i_slint_core::graphics::{register_image_provider, ImageProviderError};
 #[cfg(not(target_arch = "wasm32"))]
 pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
 // keep in sync with internal/interpreter/api.rs
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Alpha8Pixel, Brush, Color, ConicGradientBrush, FontFace, FontFamily, FontStyle, GradientStop,
+    Gray8Pixel, Image, LinearGradientBrush, LoadImageError, RadialGradientBrush,
+    RenderingCacheBudget, RenderingCacheStatistics, Rgb565Pixel, Rgb8Pixel, Rgba8Pixel, RgbaColor,
+    SharedPixelBuffer,
 };
+#[cfg(all(feature = "futures", target_has_atomic = "ptr"))]
+pub use i_slint_core::model::StreamModel;
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+pub use i_slint_core::model::VecModelSender;
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, LazyModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer,
+    ModelRc, ModelTracker, ReverseModel, SelectionMode, SelectionModel, SortModel, SortRole,
+    StandardListViewItem, TableColumn, TableModel, TableViewModel, TreeModel,
+    TreeModelChangeListener, TreeModelNotify, TreeModelTracker, TreeRow, VecModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
@@ -249,6 +270,31 @@ pub fn run_event_loop_until_quit() -> Result<(), PlatformError> {
     })
 }
 
+/// Puts the text into the clipboard of the system, the one used for the Ctrl+C and Ctrl+V shortcuts.
+///
+/// # Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::set_clipboard_text("Hello").unwrap();
+/// assert_eq!(slint::clipboard_text().unwrap().as_deref(), Some("Hello"));
+/// ```
+pub fn set_clipboard_text(text: &str) -> Result<(), PlatformError> {
+    i_slint_backend_selector::with_platform(|b| {
+        b.set_clipboard_text(text, platform::Clipboard::DefaultClipboard);
+        Ok(())
+    })
+}
+
+/// Returns the text in the clipboard of the system, the one used for the Ctrl+C and Ctrl+V shortcuts,
+/// or None if the clipboard doesn't contain text or the platform doesn't support a clipboard.
+///
+/// See [`set_clipboard_text()`] for an example.
+pub fn clipboard_text() -> Result<Option<alloc::string::String>, PlatformError> {
+    i_slint_backend_selector::with_platform(|b| {
+        Ok(b.clipboard_text(platform::Clipboard::DefaultClipboard))
+    })
+}
+
 /// Spawns a [`Future`](core::future::Future) to execute in the Slint event loop.
 ///
 /// This function is intended to be invoked only from the main Slint thread that runs the event loop.
@@ -60,6 +60,25 @@ pub fn set_property_binding<T: Clone + 'static, StrongRef: StrongItemTreeRef + '
         .set_binding(move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak).unwrap()))
 }
 
+fn animation_callbacks<StrongRef: StrongItemTreeRef + 'static>(
+    component_strong: &StrongRef,
+    started: Option<fn(StrongRef)>,
+    finished: Option<fn(StrongRef)>,
+) -> i_slint_core::properties::AnimationCallbacks {
+    let callback = |handler: fn(StrongRef)| -> Rc<dyn Fn()> {
+        let weak = component_strong.to_weak();
+        Rc::new(move || {
+            if let Some(strong) = <StrongRef as StrongItemTreeRef>::from_weak(&weak) {
+                handler(strong)
+            }
+        })
+    };
+    i_slint_core::properties::AnimationCallbacks {
+        started: started.map(callback),
+        finished: finished.map(callback),
+    }
+}
+
 pub fn set_animated_property_binding<
     T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
     StrongRef: StrongItemTreeRef + 'static,
@@ -68,11 +87,14 @@ pub fn set_animated_property_binding<
     component_strong: &StrongRef,
     binding: fn(StrongRef) -> T,
     animation_data: PropertyAnimation,
+    started: Option<fn(StrongRef)>,
+    finished: Option<fn(StrongRef)>,
 ) {
     let weak = component_strong.to_weak();
-    property.set_animated_binding(
+    property.set_animated_binding_with_callbacks(
         move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak).unwrap()),
         animation_data,
+        animation_callbacks(component_strong, started, finished),
     )
 }
 
@@ -86,14 +108,17 @@ pub fn set_animated_property_binding_for_transition<
     compute_animation_details: fn(
         StrongRef,
     ) -> (PropertyAnimation, i_slint_core::animations::Instant),
+    started: Option<fn(StrongRef)>,
+    finished: Option<fn(StrongRef)>,
 ) {
     let weak_1 = component_strong.to_weak();
     let weak_2 = weak_1.clone();
-    property.set_animated_binding_for_transition(
+    property.set_animated_binding_for_transition_with_callbacks(
         move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak_1).unwrap()),
         move || {
             compute_animation_details(<StrongRef as StrongItemTreeRef>::from_weak(&weak_2).unwrap())
         },
+        animation_callbacks(component_strong, started, finished),
     )
 }
 
@@ -164,6 +189,14 @@ pub fn use_24_hour_format() -> bool {
     i_slint_core::date_time::use_24_hour_format()
 }
 
+pub fn prefers_reduced_motion() -> bool {
+    i_slint_core::context::prefers_reduced_motion()
+}
+
+pub fn prefers_high_contrast() -> bool {
+    i_slint_core::context::prefers_high_contrast()
+}
+
 /// internal re_exports used by the macro generated
 pub mod re_exports {
     pub use alloc::boxed::Box;
@@ -0,0 +1,65 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use ::slint::slint;
+use slint_interpreter::{Struct, Value};
+
+slint! {
+    export struct TodoItem { title: string, is-done: bool, priority: int }
+}
+
+#[derive(Clone, Debug, PartialEq, ::slint::ConvertStruct)]
+#[convert(into = TodoItem, interpreter)]
+struct Task {
+    title: String,
+    #[convert(name = "is-done")]
+    done: bool,
+    #[convert(skip)]
+    id: u64,
+}
+
+#[test]
+fn convert_to_generated_struct() {
+    let task = Task { title: "Write tests".into(), done: true, id: 42 };
+    let item = TodoItem::from(task.clone());
+    assert_eq!(item.title, "Write tests");
+    assert!(item.is_done);
+    // Fields without a matching field are set to their default value
+    assert_eq!(item.priority, 0);
+    assert_eq!(Task::from(item), Task { id: 0, ..task });
+}
+
+#[test]
+fn convert_to_interpreter_value() {
+    let task = Task { title: "Write tests".into(), done: true, id: 42 };
+    let Value::Struct(value) = Value::from(task.clone()) else { panic!("not a struct") };
+    assert_eq!(value.get_field("title"), Some(&Value::String("Write tests".into())));
+    assert_eq!(value.get_field("is-done"), Some(&Value::Bool(true)));
+    assert_eq!(value.get_field("id"), None);
+    assert_eq!(Task::try_from(Value::Struct(value)), Ok(Task { id: 0, ..task.clone() }));
+
+    // Missing fields, fields of the wrong type, and other values are errors
+    let mut missing = Struct::default();
+    missing.set_field("title".into(), Value::String("Write tests".into()));
+    assert_eq!(Task::try_from(Value::Struct(missing.clone())), Err(()));
+    missing.set_field("is-done".into(), Value::Number(1.));
+    assert_eq!(Task::try_from(Value::Struct(missing)), Err(()));
+    assert_eq!(Task::try_from(Value::Bool(true)), Err(()));
+
+    // The value can be set as the property of a component loaded at run-time
+    i_slint_backend_testing::init_no_event_loop();
+    let code = r#"
+        export struct TodoItem { title: string, is-done: bool }
+        export component TodoList {
+            in-out property <TodoItem> current;
+            out property <string> label: current.is-done ? "done: " + current.title : current.title;
+        }"#;
+    let compiler = slint_interpreter::Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("TodoList").unwrap().create().unwrap();
+    instance.set_property("current", task.clone().into()).unwrap();
+    assert_eq!(instance.get_property("label").unwrap(), Value::String("done: Write tests".into()));
+    let current = Task::try_from(instance.get_property("current").unwrap()).unwrap();
+    assert_eq!(current, Task { id: 0, ..task });
+}
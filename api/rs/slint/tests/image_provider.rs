@@ -0,0 +1,67 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use ::slint::slint;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn image_loaded_through_provider() {
+    i_slint_backend_testing::init_integration_test_with_system_time();
+
+    static MISSING_AVAILABLE: AtomicBool = AtomicBool::new(false);
+    slint::register_image_provider("test-image", |url| {
+        let available = url == "test-image://logo.png"
+            || (url == "test-image://missing.png" && MISSING_AVAILABLE.load(Ordering::SeqCst));
+        if !available {
+            return Err(format!("{url} not found").into());
+        }
+        Ok(std::fs::read(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../../logo/MadeWithSlint-logo-dark.png"),
+        )?)
+    });
+
+    slint!(export component TestWindow inherits Window {
+        out property <bool> logo-pending: logo.loading-status == ImageLoadingStatus.pending;
+        out property <bool> logo-ready: logo.loading-status == ImageLoadingStatus.ready;
+        out property <int> logo-width: logo.source.width;
+        out property <bool> missing-pending: missing.loading-status == ImageLoadingStatus.pending;
+        out property <bool> missing-error: missing.loading-status == ImageLoadingStatus.error;
+        out property <bool> missing-ready: missing.loading-status == ImageLoadingStatus.ready;
+        out property <bool> local-ready: local.loading-status == ImageLoadingStatus.ready;
+        logo := Image { source: @image-url("test-image://logo.png"); }
+        missing := Image { source: @image-url("test-image://missing.png"); }
+        local := Image { source: @image-url("../../../logo/MadeWithSlint-logo-dark.png"); }
+    });
+
+    let window = TestWindow::new().unwrap();
+    assert!(window.get_logo_pending());
+    assert_eq!(window.get_logo_width(), 0);
+    assert!(window.get_missing_pending());
+    assert!(window.get_local_ready());
+
+    let wait_until_loaded = |window: &TestWindow| {
+        let timer = slint::Timer::default();
+        let window_weak = window.as_weak();
+        timer.start(slint::TimerMode::Repeated, std::time::Duration::from_millis(10), move || {
+            let window = window_weak.unwrap();
+            if !window.get_logo_pending() && !window.get_missing_pending() {
+                slint::quit_event_loop().unwrap();
+            }
+        });
+        slint::run_event_loop().unwrap();
+    };
+    wait_until_loaded(&window);
+
+    assert!(window.get_logo_ready());
+    assert_eq!(window.get_logo_width(), 424);
+    assert!(window.get_missing_error());
+
+    // The image that failed to load isn't cached, so it's loaded again when it's used the next time
+    MISSING_AVAILABLE.store(true, Ordering::SeqCst);
+    let window = TestWindow::new().unwrap();
+    assert!(window.get_logo_ready());
+    assert!(window.get_missing_pending());
+    wait_until_loaded(&window);
+    assert!(window.get_missing_ready());
+}
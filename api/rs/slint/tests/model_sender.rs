@@ -0,0 +1,44 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::{Model, VecModel};
+use std::rc::Rc;
+
+#[test]
+fn main() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    // The sender can only be created in the event loop thread
+    std::thread::spawn(|| {
+        assert_eq!(
+            Rc::new(VecModel::<i32>::default()).sender().map(drop),
+            Err(slint::EventLoopError::NoEventLoopProvider)
+        );
+    })
+    .join()
+    .unwrap();
+
+    let model = Rc::new(VecModel::from(vec![-1]));
+    let sender = model.sender().unwrap();
+    let thread = std::thread::spawn(move || {
+        sender.clear();
+        for i in 0..100 {
+            sender.push(i);
+        }
+        sender.extend(100..200);
+        sender.apply(|model| model.remove(0));
+        sender.apply(|_| slint::quit_event_loop().unwrap());
+    });
+    slint::run_event_loop_until_quit().unwrap();
+    thread.join().unwrap();
+    assert_eq!(model.iter().collect::<Vec<_>>(), (1..200).collect::<Vec<_>>());
+
+    // Updates sent after the model was dropped are discarded
+    let model = Rc::new(VecModel::<i32>::default());
+    let sender = model.sender().unwrap();
+    drop(model);
+    sender.push(1);
+    slint::invoke_from_event_loop(|| slint::quit_event_loop().unwrap()).unwrap();
+    slint::run_event_loop_until_quit().unwrap();
+    assert!(sender.is_closed());
+}
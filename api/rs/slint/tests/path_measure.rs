@@ -0,0 +1,28 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::{LogicalPosition, PathMeasure};
+
+#[test]
+fn main() {
+    assert_eq!(PathMeasure::from_svg_commands("").point_at(0.), None);
+    assert_eq!(PathMeasure::from_svg_commands("not a path").length(), 0.);
+
+    let measure = PathMeasure::from_svg_commands("M 10 10 L 40 10 L 40 50");
+    assert_eq!(measure.length(), 70.);
+
+    let start = measure.point_at(0.).unwrap();
+    assert_eq!(start.position, LogicalPosition::new(10., 10.));
+    assert_eq!(start.angle, 0.);
+
+    let corner = measure.point_at(30.).unwrap();
+    assert_eq!(corner.position, LogicalPosition::new(40., 10.));
+    assert_eq!(corner.angle, 0.);
+
+    let on_second_line = measure.point_at(50.).unwrap();
+    assert_eq!(on_second_line.position, LogicalPosition::new(40., 30.));
+    assert_eq!(on_second_line.angle, 90.);
+
+    // The distance is clamped to the length of the path
+    assert_eq!(measure.point_at(100.).unwrap().position, LogicalPosition::new(40., 50.));
+}
@@ -0,0 +1,58 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+#[test]
+fn register_font_from_memory() {
+    slint::slint! {
+        export component Ui inherits Window {
+            out property <length> text-width: text.preferred-width;
+            out property <length> fallback-text-width: fallback-text.preferred-width;
+            text := Text { text: "Slint"; font-family: "Plaster"; font-size: 20px; }
+            fallback-text := Text { text: "Slint"; font-family: "Does Not Exist, Plaster"; font-size: 20px; }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+
+    assert!(ui.window().register_font_from_memory(b"not a font").is_err());
+
+    // Before the font is registered, a fallback font is used
+    let fallback_width = ui.get_text_width();
+
+    // The data is only borrowed for the duration of the call
+    let data = std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../../examples/slide_puzzle/plaster-font/Plaster-Regular.ttf"),
+    )
+    .unwrap();
+    assert!(!ui.window().font_families().iter().any(|family| family.name == "Plaster"));
+    ui.window().register_font_from_memory(&data).unwrap();
+    drop(data);
+
+    // The registered font is listed with the system fonts
+    let families = ui.window().font_families();
+    let plaster = families.iter().find(|family| family.name == "Plaster").unwrap();
+    assert_eq!(plaster.faces, [slint::FontFace { weight: 400, style: slint::FontStyle::Normal }]);
+
+    let ui = Ui::new().unwrap();
+    assert_ne!(ui.get_text_width(), fallback_width);
+    // The font is selected from the list of fallback families
+    assert_eq!(ui.get_fallback_text_width(), ui.get_text_width());
+}
@@ -0,0 +1,61 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::Rgba8Pixel;
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+#[test]
+fn render_hidden_window() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <color> c: red;
+            background: blue;
+            Rectangle {
+                x: 10phx;
+                y: 20phx;
+                width: 30phx;
+                height: 40phx;
+                background: c;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+
+    window.set_size(slint::PhysicalSize::new(100, 80));
+    let pixel = |image: &slint::SharedPixelBuffer<Rgba8Pixel>, x: usize, y: usize| {
+        image.as_slice()[y * image.width() as usize + x]
+    };
+
+    let image = ui.window().render_to_image().unwrap();
+    assert_eq!((image.width(), image.height()), (100, 80));
+    assert_eq!(pixel(&image, 0, 0), Rgba8Pixel::new(0, 0, 255, 255));
+    assert_eq!(pixel(&image, 10, 20), Rgba8Pixel::new(255, 0, 0, 255));
+    assert_eq!(pixel(&image, 39, 59), Rgba8Pixel::new(255, 0, 0, 255));
+    assert_eq!(pixel(&image, 40, 60), Rgba8Pixel::new(0, 0, 255, 255));
+
+    ui.set_c(slint::Color::from_rgb_u8(0, 255, 0));
+    let image = ui.window().render_to_image().unwrap();
+    assert_eq!(pixel(&image, 20, 30), Rgba8Pixel::new(0, 255, 0, 255));
+
+    // Rendering to an image doesn't change the kind of buffer the window renders into
+    assert!(window.draw_if_needed(|renderer| {
+        assert_eq!(renderer.repaint_buffer_type(), RepaintBufferType::ReusedBuffer);
+    }));
+}
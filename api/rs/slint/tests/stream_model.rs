@@ -0,0 +1,44 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(feature = "futures")]
+
+use slint::{Model, StreamModel};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A stream where all the items of the iterator are ready
+struct IterStream<I>(I);
+
+impl<I: Iterator + Unpin> futures_core::Stream for IterStream<I> {
+    type Item = I::Item;
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next())
+    }
+}
+
+fn run_event_loop() {
+    slint::invoke_from_event_loop(|| slint::quit_event_loop().unwrap()).unwrap();
+    slint::run_event_loop_until_quit().unwrap();
+}
+
+#[test]
+fn main() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    let model = StreamModel::new(IterStream(0..5)).unwrap();
+    assert_eq!(model.row_count(), 0);
+    run_event_loop();
+    assert!(model.is_finished());
+    assert_eq!(model.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    model.set_max_rows(Some(2));
+    assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 4]);
+
+    let model = StreamModel::new_keyed(IterStream(
+        [("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)].into_iter(),
+    ))
+    .unwrap();
+    model.set_max_rows(Some(2));
+    run_event_loop();
+    assert_eq!(model.iter().collect::<Vec<_>>(), vec![5, 4]);
+}
@@ -283,7 +283,8 @@ module.exports = grammar({
         ";",
       ),
 
-    animate_body: ($) => seq("{", repeat($.animate_option), "}"),
+    animate_body: ($) =>
+      seq("{", repeat(choice($.animate_option, $.callback_event)), "}"),
 
     if_expr: ($) =>
       seq(
@@ -694,6 +695,7 @@ module.exports = grammar({
         "ease-out-bounce",
         "ease-in-out-bounce",
         seq("cubic-bezier", $.arguments),
+        seq("spring", $.arguments),
       ),
 
     user_type_identifier: ($) => prec(1, $._identifier),
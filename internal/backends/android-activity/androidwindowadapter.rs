@@ -10,7 +10,7 @@
 use i_slint_core::api::{LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window};
 use i_slint_core::items::ColorScheme;
 use i_slint_core::platform::{
-    Key, PointerEventButton, WindowAdapter, WindowEvent, WindowProperties,
+    Key, KeyInput, PointerEventButton, WindowAdapter, WindowEvent, WindowProperties,
 };
 use i_slint_core::timers::{Timer, TimerMode};
 use i_slint_core::window::{InputMethodRequest, WindowInner};
@@ -496,14 +496,13 @@ fn button_for_event(
 fn map_key_event(key_event: &android_activity::input::KeyEvent) -> Option<WindowEvent> {
     let text = map_key_code(key_event.key_code())?;
     let repeat = key_event.repeat_count() > 0;
-    match key_event.action() {
-        KeyAction::Down if repeat => Some(WindowEvent::KeyPressRepeated { text }),
-        KeyAction::Down => Some(WindowEvent::KeyPressed { text }),
-        KeyAction::Up => Some(WindowEvent::KeyReleased { text }),
-        KeyAction::Multiple if repeat => Some(WindowEvent::KeyPressRepeated { text }),
-        KeyAction::Multiple => Some(WindowEvent::KeyPressed { text }),
-        _ => None,
-    }
+    let key = match key_event.action() {
+        KeyAction::Down | KeyAction::Multiple if repeat => KeyInput::press_repeat(text),
+        KeyAction::Down | KeyAction::Multiple => KeyInput::press(text),
+        KeyAction::Up => KeyInput::release(text),
+        _ => return None,
+    };
+    Some(WindowEvent::KeyInput(key.with_scan_code(key_event.scan_code())))
 }
 
 fn map_key_code(code: android_activity::input::Keycode) -> Option<SharedString> {
@@ -18,7 +18,7 @@
 use std::rc::Rc;
 
 use i_slint_core::api::LogicalPosition;
-use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
+use i_slint_core::platform::{KeyInput, PlatformError, PointerEventButton, WindowEvent};
 use i_slint_core::window::WindowAdapter;
 use i_slint_core::{Property, SharedString};
 use input::LibinputInterface;
@@ -308,11 +308,13 @@ fn process_events<F>(
                     }
 
                     if let Some(text) = map_key_sym(sym) {
-                        let event = match state {
-                            KeyState::Pressed => WindowEvent::KeyPressed { text },
-                            KeyState::Released => WindowEvent::KeyReleased { text },
+                        let key = match state {
+                            KeyState::Pressed => KeyInput::press(text),
+                            KeyState::Released => KeyInput::release(text),
                         };
-                        window.dispatch_event(event);
+                        window.dispatch_event(WindowEvent::KeyInput(
+                            key.with_scan_code(key_event.key() as i32),
+                        ));
                     }
                 }
                 _ => {}
@@ -142,6 +142,10 @@ fn resize(
     fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void {
         self.glutin_context.display().get_proc_address(name)
     }
+
+    fn buffer_age(&self) -> u32 {
+        self.glutin_surface.buffer_age()
+    }
 }
 
 impl FemtoVGRendererAdapter {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use i_slint_core::input::FocusEventResult;
+use i_slint_core::items::ScrollBarPolicy;
 
 use super::*;
 
@@ -22,6 +23,7 @@ pub struct NativeScrollView {
     pub native_padding_bottom: Property<LogicalLength>,
     pub enabled: Property<bool>,
     pub has_focus: Property<bool>,
+    pub scrollbar_policy: Property<ScrollBarPolicy>,
     data: Property<NativeSliderData>,
     widget_ptr: std::cell::Cell<SlintTypeErasedWidgetPtr>,
     animation_tracker: Property<i32>,
@@ -30,7 +32,7 @@ pub struct NativeScrollView {
 }
 
 impl Item for NativeScrollView {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
         let animation_tracker_property_ptr = Self::FIELD_OFFSETS.animation_tracker.apply_pin(self);
         self.widget_ptr.set(cpp! { unsafe [animation_tracker_property_ptr as "void*"] -> SlintTypeErasedWidgetPtr as "std::unique_ptr<SlintTypeErasedWidget>"  {
             return make_unique_animated_widget<QWidget>(animation_tracker_property_ptr);
@@ -72,9 +74,25 @@ fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
             let paddings = paddings.clone();
             move || LogicalLength::new(paddings.as_ref().get().left as _)
         });
+        // Without scrollbars, only the frame remains, which has the same size on both sides
+        let no_scrollbars = {
+            let weak = self_rc.downgrade();
+            move || {
+                weak.upgrade()
+                    .and_then(|self_rc| self_rc.downcast::<NativeScrollView>())
+                    .map_or(false, |this| {
+                        this.as_pin_ref().scrollbar_policy() == ScrollBarPolicy::Never
+                    })
+            }
+        };
+
         self.native_padding_right.set_binding({
             let paddings = paddings.clone();
-            move || LogicalLength::new(paddings.as_ref().get().right as _)
+            let no_scrollbars = no_scrollbars.clone();
+            move || {
+                let paddings = paddings.as_ref().get();
+                LogicalLength::new(if no_scrollbars() { paddings.left } else { paddings.right } as _)
+            }
         });
         self.native_padding_top.set_binding({
             let paddings = paddings.clone();
@@ -82,7 +100,10 @@ fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
         });
         self.native_padding_bottom.set_binding({
             let paddings = paddings;
-            move || LogicalLength::new(paddings.as_ref().get().bottom as _)
+            move || {
+                let paddings = paddings.as_ref().get();
+                LogicalLength::new(if no_scrollbars() { paddings.top } else { paddings.bottom } as _)
+            }
         });
     }
 
@@ -8,8 +8,8 @@
     RenderingMetrics, RenderingMetricsCollector,
 };
 use i_slint_core::graphics::{
-    euclid, Brush, Color, FontRequest, IntRect, Point, Rgba8Pixel, SharedImageBuffer,
-    SharedPixelBuffer,
+    euclid, Brush, Color, FontRequest, FontStyle, IntRect, PathDataEvent, Rgba8Pixel,
+    SharedImageBuffer, SharedPixelBuffer,
 };
 use i_slint_core::input::{KeyEvent, KeyEventType, MouseEvent};
 use i_slint_core::item_rendering::{
@@ -17,15 +17,16 @@
 };
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef};
 use i_slint_core::items::{
-    self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, MouseCursor, Opacity,
-    PointerEventButton, RenderingResult, TextOverflow, TextStrokeStyle, TextWrap,
+    self, BlendMode, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, MouseCursor,
+    Opacity, PatternTiling, PointerEventButton, RenderingResult, TextOverflow, TextStrokeStyle,
+    TextWrap,
 };
 use i_slint_core::layout::Orientation;
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, ScaleFactor,
 };
-use i_slint_core::platform::{PlatformError, WindowEvent};
+use i_slint_core::platform::{KeyInput, PlatformError, WindowEvent};
 use i_slint_core::window::{WindowAdapter, WindowAdapterInternal, WindowInner};
 use i_slint_core::{ImageInner, Property, SharedString};
 use items::{TextHorizontalAlignment, TextVerticalAlignment};
@@ -94,6 +95,8 @@ struct SlintWidget : QWidget {
 
         SlintWidget() {
             setMouseTracking(true);
+            setAcceptDrops(true);
+            setAttribute(Qt::WA_AcceptTouchEvents);
             setFocusPolicy(Qt::StrongFocus);
             setAttribute(Qt::WA_TranslucentBackground);
             // WA_TranslucentBackground sets WA_NoSystemBackground, but we actually need WA_NoSystemBackground
@@ -175,6 +178,17 @@ struct SlintWidget : QWidget {
                 let button = from_qt_button(button);
                 rust_window.mouse_event(MouseEvent::Released{ position, button, click_count: 0 })
             });
+            // There is no hover with touch input
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                bool from_touch = event->pointingDevice()->type() == QInputDevice::DeviceType::TouchScreen;
+            #else
+                bool from_touch = event->source() == Qt::MouseEventSynthesizedBySystem || event->source() == Qt::MouseEventSynthesizedByQt;
+            #endif
+            if (from_touch) {
+                rust!(Slint_mouseReleaseEventTouchExit [rust_window: &QtWindow as "void*"] {
+                    rust_window.mouse_event(MouseEvent::Exit)
+                });
+            }
             if (parent_of_popup_to_close) {
                 rust!(Slint_mouseReleaseEventClosePopup [parent_of_popup_to_close: &QtWindow as "void*"] {
                     parent_of_popup_to_close.close_popup();
@@ -211,14 +225,120 @@ struct SlintWidget : QWidget {
             });
         }
 
+        bool event(QEvent *event) override {
+            switch (event->type()) {
+            case QEvent::TouchBegin:
+            case QEvent::TouchUpdate:
+            case QEvent::TouchEnd:
+            case QEvent::TouchCancel:
+                if (rust_window) {
+                    touchEvent(static_cast<QTouchEvent *>(event));
+                    return true;
+                }
+                break;
+            default:
+                break;
+            }
+            return QWidget::event(event);
+        }
+        // Slint handles the touch points itself, to recognize the gestures
+        void touchEvent(QTouchEvent *event) {
+            bool cancel = event->type() == QEvent::TouchCancel;
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                const auto &points = event->points();
+            #else
+                const auto &points = event->touchPoints();
+            #endif
+            for (const auto &point : points) {
+                #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                    QPointF pos = point.position();
+                #else
+                    QPointF pos = point.pos();
+                #endif
+                int id = point.id();
+                int state = cancel ? -1 : int(point.state());
+                rust!(Slint_touchEvent [rust_window: &QtWindow as "void*", id: i32 as "int", state: i32 as "int", pos: qttypes::QPointF as "QPointF"] {
+                    rust_window.touch_event(id as u64, state, LogicalPoint::new(pos.x as _, pos.y as _))
+                });
+            }
+        }
+
+        // URLs and text are preferred, otherwise the first format is passed as UTF-8 text,
+        // which covers the drags started by a DragArea with a custom mime-type
+        static void drop_data(const QMimeData *mime, QString &mime_type, QString &data) {
+            if (mime->hasUrls()) {
+                QStringList urls;
+                for (const auto &url : mime->urls())
+                    urls << QString::fromUtf8(url.toEncoded());
+                mime_type = QStringLiteral("text/uri-list");
+                data = urls.join(QLatin1Char('\n'));
+            } else if (mime->hasText()) {
+                mime_type = QStringLiteral("text/plain");
+                data = mime->text();
+            } else if (!mime->formats().isEmpty()) {
+                mime_type = mime->formats().first();
+                data = QString::fromUtf8(mime->data(mime_type));
+            }
+        }
+        static QPoint drop_position(const QDropEvent *event) {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                return event->position().toPoint();
+            #else
+                return event->pos();
+            #endif
+        }
+        void dragEnterEvent(QDragEnterEvent *event) override {
+            dragMoveEvent(event);
+            // Always accept the enter event, otherwise Qt doesn't send the move events
+            event->accept();
+        }
+        void dragMoveEvent(QDragMoveEvent *event) override {
+            if (!rust_window)
+                return;
+            QString mime_type, data;
+            drop_data(event->mimeData(), mime_type, data);
+            QPoint pos = drop_position(event);
+            bool accepted = rust!(Slint_dragMoveEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", mime_type: qttypes::QString as "QString", data: qttypes::QString as "QString"] -> bool as "bool" {
+                let position = LogicalPoint::new(pos.x as _, pos.y as _);
+                rust_window.external_drag_event(Some(position), items::DropEvent { mime_type: String::from(mime_type).into(), data: String::from(data).into() })
+            });
+            if (accepted)
+                event->acceptProposedAction();
+            else
+                event->ignore();
+        }
+        void dragLeaveEvent(QDragLeaveEvent *) override {
+            if (!rust_window)
+                return;
+            rust!(Slint_dragLeaveEvent [rust_window: &QtWindow as "void*"] {
+                rust_window.external_drag_event(None, Default::default());
+            });
+        }
+        void dropEvent(QDropEvent *event) override {
+            if (!rust_window)
+                return;
+            QString mime_type, data;
+            drop_data(event->mimeData(), mime_type, data);
+            QPoint pos = drop_position(event);
+            bool accepted = rust!(Slint_dropEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", mime_type: qttypes::QString as "QString", data: qttypes::QString as "QString"] -> bool as "bool" {
+                let position = LogicalPoint::new(pos.x as _, pos.y as _);
+                rust_window.external_drop_event(position, items::DropEvent { mime_type: String::from(mime_type).into(), data: String::from(data).into() })
+            });
+            if (accepted)
+                event->acceptProposedAction();
+            else
+                event->ignore();
+        }
+
         void keyPressEvent(QKeyEvent *event) override {
             if (!rust_window)
                 return;
             QString text =  event->text();
             int key = event->key();
             bool repeat = event->isAutoRepeat();
-            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool"] {
-                rust_window.key_event(key, text.clone(), false, repeat);
+            int scan_code = event->nativeScanCode();
+            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool", scan_code: i32 as "int"] {
+                rust_window.key_event(key, text.clone(), false, repeat, scan_code);
             });
         }
         void keyReleaseEvent(QKeyEvent *event) override {
@@ -231,8 +351,9 @@ struct SlintWidget : QWidget {
 
             QString text =  event->text();
             int key = event->key();
-            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString"] {
-                rust_window.key_event(key, text.clone(), true, false);
+            int scan_code = event->nativeScanCode();
+            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", scan_code: i32 as "int"] {
+                rust_window.key_event(key, text.clone(), true, false, scan_code);
             });
         }
 
@@ -309,7 +430,7 @@ struct SlintWidget : QWidget {
             case Qt::ImCurrentSelection: return ime_text.mid(qMin(ime_cursor, ime_anchor), qAbs(ime_cursor - ime_anchor));
             case Qt::ImAnchorPosition: return ime_anchor;
             case Qt::ImTextBeforeCursor: return ime_text.left(ime_cursor);
-            case Qt::ImTextAfterCursor: return ime_text.right(ime_cursor);
+            case Qt::ImTextAfterCursor: return ime_text.mid(ime_cursor);
             default: break;
             }
             return QWidget::inputMethodQuery(query);
@@ -320,13 +441,13 @@ struct SlintWidget : QWidget {
                 return;
             QString commit_string = event->commitString();
             QString preedit_string = event->preeditString();
-            int replacement_start = event->replacementStart();
+            // The replacement is relative to the cursor, in UTF-16 units, but Slint expects UTF-8 bytes
+            int replacement_start_utf16 = event->replacementStart();
             QStringView ime_text(this->ime_text);
-            replacement_start = replacement_start < 0 ?
-                -ime_text.mid(ime_cursor,-replacement_start).toUtf8().size() :
-                ime_text.mid(ime_cursor,replacement_start).toUtf8().size();
-            int replacement_length = qMax(0, event->replacementLength());
-            ime_text.mid(ime_cursor + replacement_start, replacement_length).toUtf8().size();
+            int replacement_start = replacement_start_utf16 < 0 ?
+                -ime_text.mid(ime_cursor + replacement_start_utf16, -replacement_start_utf16).toUtf8().size() :
+                ime_text.mid(ime_cursor, replacement_start_utf16).toUtf8().size();
+            int replacement_length = ime_text.mid(ime_cursor + replacement_start_utf16, qMax(0, event->replacementLength())).toUtf8().size();
             int preedit_cursor = -1;
             for (const QInputMethodEvent::Attribute &attribute: event->attributes()) {
                 if (attribute.type == QInputMethodEvent::Cursor) {
@@ -357,8 +478,8 @@ struct SlintWidget : QWidget {
     // Helper function used for the TextInput layouting
     //
     // if line_for_y_pos > 0, then the function will return the line at this y position
-    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, int line_for_y_pos = -1) {
-        QTextOption options;
+    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, const QTextOption &tab_options, int line_for_y_pos = -1) {
+        QTextOption options = tab_options;
         options.setWrapMode((flags & Qt::TextWordWrap) ? QTextOption::WordWrap : ((flags & Qt::TextWrapAnywhere) ? QTextOption::WrapAnywhere : QTextOption::NoWrap));
         if (flags & Qt::AlignHCenter)
             options.setAlignment(Qt::AlignCenter);
@@ -396,6 +517,12 @@ struct SlintWidget : QWidget {
         return -1;
     }
 
+    // Copies the tab stops of the options returned by text_option_with_tab_stops()
+    static void copy_tab_stops(QTextOption &options, const QTextOption &tab_options) {
+        options.setTabStopDistance(tab_options.tabStopDistance());
+        options.setTabs(tab_options.tabs());
+    }
+
     QPainterPath to_painter_path(const QRectF &rect, qreal top_left_radius, qreal top_right_radius, qreal bottom_right_radius, qreal bottom_left_radius) {
         QPainterPath path;
         if (qFuzzyCompare(top_left_radius, top_right_radius) && qFuzzyCompare(top_left_radius, bottom_right_radius) && qFuzzyCompare(top_left_radius, bottom_left_radius)) {
@@ -564,10 +691,16 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
             }}
         }
         i_slint_core::Brush::RadialGradient(g) => {
+            let (center, radius) = g.circle_for_size(
+                [width as f32, height as f32].into(),
+                (width + height) as f32 / 4.,
+            );
+            let center = qttypes::QPointF { x: center.x as _, y: center.y as _ };
+            let radius = radius as f64;
             cpp_class!(unsafe struct QRadialGradient as "QRadialGradient");
             let mut qrg = cpp! {
-                unsafe [width as "qreal", height as "qreal"] -> QRadialGradient as "QRadialGradient" {
-                    QRadialGradient qrg(width / 2, height / 2, (width + height) / 4);
+                unsafe [center as "QPointF", radius as "qreal"] -> QRadialGradient as "QRadialGradient" {
+                    QRadialGradient qrg(center, radius);
                     return qrg;
                 }
             };
@@ -583,6 +716,82 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            let center = g.center();
+            let center =
+                qttypes::QPointF { x: center.x as f64 * width, y: center.y as f64 * height };
+            // QConicalGradient starts at 3 o'clock and goes counter-clockwise, while ours start at
+            // 12 o'clock and goes clockwise.
+            let angle = (90. - g.from_angle()) as f64;
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            let mut qcg = cpp! {
+                unsafe [center as "QPointF", angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    QConicalGradient qcg(center, angle);
+                    return qcg;
+                }
+            };
+            let count = g.stops().count();
+            for (idx, s) in g.stops().enumerate() {
+                let pos: f32 = mangle_position(1. - s.position, count - idx - 1, count);
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
+        i_slint_core::Brush::ImagePattern(pattern) => {
+            let image_size = pattern.image().size();
+            let tile_size = pattern.tile_size();
+            if image_size.is_empty() || tile_size.is_empty() {
+                return qttypes::QBrush::default();
+            }
+            let Some(pixmap) = image_to_pixmap(pattern.image().into(), None) else {
+                return qttypes::QBrush::default();
+            };
+            let offset = pattern.offset();
+            let (offset_x, offset_y) = (offset.x as qttypes::qreal, offset.y as qttypes::qreal);
+            let scale_x = (tile_size.width / image_size.width as f32) as qttypes::qreal;
+            let scale_y = (tile_size.height / image_size.height as f32) as qttypes::qreal;
+            // QBrush always repeats its texture in both directions. To repeat in only one
+            // direction, pad the texture with transparent pixels, so that the next repetition
+            // falls outside of the shape.
+            let (texture_width, texture_height) = match pattern.tiling() {
+                PatternTiling::Repeat => (image_size.width as i32, image_size.height as i32),
+                PatternTiling::RepeatX => (
+                    image_size.width as i32,
+                    ((height.abs() + tile_size.height as qttypes::qreal + offset_y.abs()) / scale_y)
+                        .ceil() as i32,
+                ),
+                PatternTiling::RepeatY => (
+                    ((width.abs() + tile_size.width as qttypes::qreal + offset_x.abs()) / scale_x)
+                        .ceil() as i32,
+                    image_size.height as i32,
+                ),
+            };
+            cpp! {unsafe [
+                pixmap as "QPixmap",
+                texture_width as "int",
+                texture_height as "int",
+                offset_x as "qreal",
+                offset_y as "qreal",
+                scale_x as "qreal",
+                scale_y as "qreal"
+            ] -> qttypes::QBrush as "QBrush" {
+                QPixmap texture = pixmap;
+                if (texture.width() != texture_width || texture.height() != texture_height) {
+                    texture = QPixmap(texture_width, texture_height);
+                    texture.fill(Qt::transparent);
+                    QPainter painter(&texture);
+                    painter.drawPixmap(0, 0, pixmap);
+                }
+                QBrush brush(texture);
+                brush.setTransform(QTransform::fromTranslate(offset_x, offset_y).scale(scale_x, scale_y));
+                return brush;
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
@@ -642,14 +851,17 @@ fn draw_border_rectangle(
         size: LogicalSize,
         _: &CachedRenderingData,
     ) {
-        Self::draw_rectangle_impl(
-            &mut self.painter,
-            check_geometry!(size),
-            rect.background(),
-            rect.border_color(),
-            rect.border_width().get(),
-            rect.border_radius(),
-        );
+        let geometry = check_geometry!(size);
+        self.with_blend_mode(rect.blend_mode(), |this| {
+            Self::draw_rectangle_impl(
+                &mut this.painter,
+                geometry,
+                rect.background(),
+                rect.border_color(),
+                rect.border_width().get(),
+                rect.border_radius(),
+            )
+        });
     }
 
     fn draw_image(
@@ -659,7 +871,7 @@ fn draw_image(
         size: LogicalSize,
         _: &CachedRenderingData,
     ) {
-        self.draw_image_impl(item_rc, size, image);
+        self.with_blend_mode(image.blend_mode(), |this| this.draw_image_impl(item_rc, size, image));
     }
 
     fn draw_text(
@@ -671,13 +883,20 @@ fn draw_text(
     ) {
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text.color(), rect.width, rect.height);
-        let mut string: qttypes::QString = text.text().as_str().into();
-        let font: QFont = get_font(text.font_request(WindowInner::from_pub(self.window)));
+        let styled_text = text.styled_text();
+        let rich_text = styled_text.is_some();
+        let mut string: qttypes::QString = match &styled_text {
+            Some(styled_text) => styled_text_to_html(styled_text).as_str().into(),
+            None => text.text().as_str().into(),
+        };
+        let font_request = text.font_request(WindowInner::from_pub(self.window));
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
         let (horizontal_alignment, vertical_alignment) = text.alignment();
-        let alignment = match horizontal_alignment {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let alignment = match horizontal_alignment.resolve_for_text(text.text().as_str()) {
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            _ => key_generated::Qt_AlignmentFlag_AlignLeft,
         } | match vertical_alignment {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -685,7 +904,13 @@ fn draw_text(
         };
         let wrap = text.wrap() != TextWrap::NoWrap;
         let word_wrap = text.wrap() == TextWrap::WordWrap;
-        let elide = text.overflow() == TextOverflow::Elide;
+        let elide = text.overflow() != TextOverflow::Clip;
+        // The value of Qt::TextElideMode for lines that don't wrap
+        let elide_mode: i32 = match text.overflow() {
+            TextOverflow::ElideLeft => 0,
+            TextOverflow::ElideMiddle => 2,
+            _ => 1,
+        };
         let (stroke_brush, stroke_width, stroke_style) = text.stroke();
         let stroke_visible = !stroke_brush.is_transparent();
         let stroke_brush: qttypes::QBrush = into_qbrush(stroke_brush, rect.width, rect.height);
@@ -695,7 +920,43 @@ fn draw_text(
             TextStrokeStyle::Center => stroke_width.get(),
         };
         let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", rich_text as "bool", font as "QFont", tab_options as "QTextOption", elide as "bool", elide_mode as "int", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
+            if (rich_text) {
+                // The markup was converted to HTML, which is neither elided nor stroked
+                QTextDocument document;
+                document.setDocumentMargin(0);
+                document.setDefaultFont(font);
+                QTextOption options = document.defaultTextOption();
+                copy_tab_stops(options, tab_options);
+                options.setAlignment(alignment);
+                if (!wrap) {
+                    options.setWrapMode(QTextOption::NoWrap);
+                } else if (word_wrap) {
+                    options.setWrapMode(QTextOption::WordWrap);
+                } else {
+                    options.setWrapMode(QTextOption::WrapAnywhere);
+                }
+                document.setDefaultTextOption(options);
+                document.setHtml(string);
+                document.setTextWidth(rect.width());
+
+                qreal dy = 0;
+                if (alignment & Qt::AlignVCenter) {
+                    dy = (rect.height() - document.size().height()) / 2.0;
+                } else if (alignment & Qt::AlignBottom) {
+                    dy = rect.height() - document.size().height();
+                }
+
+                QAbstractTextDocumentLayout::PaintContext context;
+                context.palette.setBrush(QPalette::Text, fill_brush);
+                context.clip = QRectF(0, -dy, rect.width(), rect.height());
+                (*painter)->save();
+                (*painter)->translate(rect.x(), rect.y() + dy);
+                document.documentLayout()->draw((*painter).get(), context);
+                (*painter)->restore();
+                return;
+            }
+
             QString elided;
             if (!elide) {
                 elided = string;
@@ -704,11 +965,11 @@ fn draw_text(
                 while (!string.isEmpty()) {
                     int pos = string.indexOf('\n');
                     if (pos < 0) {
-                        elided += fm.elidedText(string, Qt::ElideRight, rect.width());
+                        elided += fm.elidedText(string, Qt::TextElideMode(elide_mode), rect.width());
                         break;
                     }
                     QString line = string.left(pos);
-                    elided += fm.elidedText(line, Qt::ElideRight, rect.width());
+                    elided += fm.elidedText(line, Qt::TextElideMode(elide_mode), rect.width());
                     elided += '\n';
                     string = string.mid(pos + 1);
                 }
@@ -752,19 +1013,20 @@ fn draw_text(
             }
 
             if (!stroke_visible) {
-                int flags = alignment;
-                if (wrap) {
-                    if (word_wrap) {
-                        flags |= Qt::TextWordWrap;
-                    } else {
-                        flags |= Qt::TextWrapAnywhere;
-                    }
+                QTextOption options = tab_options;
+                options.setAlignment(alignment);
+                if (!wrap) {
+                    options.setWrapMode(QTextOption::NoWrap);
+                } else if (word_wrap) {
+                    options.setWrapMode(QTextOption::WordWrap);
+                } else {
+                    options.setWrapMode(QTextOption::WrapAnywhere);
                 }
 
                 (*painter)->setFont(font);
                 (*painter)->setBrush(Qt::NoBrush);
                 (*painter)->setPen(QPen(fill_brush, 0));
-                (*painter)->drawText(rect, flags, elided);
+                (*painter)->drawText(rect, elided, options);
             } else {
                 QTextDocument document(elided);
                 document.setDocumentMargin(0);
@@ -772,6 +1034,7 @@ fn draw_text(
                 document.setDefaultFont(font);
 
                 QTextOption options = document.defaultTextOption();
+                copy_tab_stops(options, tab_options);
                 options.setAlignment(alignment);
                 if (wrap) {
                     if (word_wrap) {
@@ -848,21 +1111,24 @@ fn draw_text_input(
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text_input.color(), rect.width, rect.height);
 
-        let font: QFont =
-            get_font(text_input.font_request(&WindowInner::from_pub(self.window).window_adapter()));
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
-            TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
-        } | match text_input.vertical_alignment() {
-            TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
-            TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
-            TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
-        } | match text_input.wrap() {
-            TextWrap::NoWrap => 0,
-            TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
-            TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
-        };
+        let font_request =
+            text_input.font_request(&WindowInner::from_pub(self.window).window_adapter());
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
+        let flags =
+            match text_input.horizontal_alignment().resolve_for_text(text_input.text().as_str()) {
+                TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
+                TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+                _ => key_generated::Qt_AlignmentFlag_AlignLeft,
+            } | match text_input.vertical_alignment() {
+                TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
+                TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
+                TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
+            } | match text_input.wrap() {
+                TextWrap::NoWrap => 0,
+                TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
+                TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
+            };
 
         let visual_representation = text_input.visual_representation(Some(qt_password_character));
 
@@ -931,6 +1197,7 @@ fn draw_text_input(
                 flags as "int",
                 single_line as "bool",
                 font as "QFont",
+                tab_options as "QTextOption",
                 selection_start_position as "int",
                 selection_end_position as "int",
                 cursor_position as "int",
@@ -939,7 +1206,7 @@ fn draw_text_input(
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, tab_options);
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
             if (selection_end_position != selection_start_position) {
@@ -984,24 +1251,24 @@ fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, size: Logical
         });
 
         for x in path_events.iter() {
-            fn to_qpointf(p: Point) -> qttypes::QPointF {
+            fn to_qpointf(p: i_slint_core::api::LogicalPosition) -> qttypes::QPointF {
                 qttypes::QPointF { x: p.x as _, y: p.y as _ }
             }
             match x {
-                lyon_path::Event::Begin { at } => {
+                PathDataEvent::Begin { at } => {
                     painter_path.move_to(to_qpointf(at));
                 }
-                lyon_path::Event::Line { from: _, to } => {
+                PathDataEvent::Line { from: _, to } => {
                     painter_path.line_to(to_qpointf(to));
                 }
-                lyon_path::Event::Quadratic { from: _, ctrl, to } => {
+                PathDataEvent::Quadratic { from: _, ctrl, to } => {
                     painter_path.quad_to(to_qpointf(ctrl), to_qpointf(to));
                 }
 
-                lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
+                PathDataEvent::Cubic { from: _, ctrl1, ctrl2, to } => {
                     painter_path.cubic_to(to_qpointf(ctrl1), to_qpointf(ctrl2), to_qpointf(to));
                 }
-                lyon_path::Event::End { last: _, first: _, close } => {
+                PathDataEvent::End { last: _, first: _, close } => {
                     // FIXME: are we supposed to do something with last and first?
                     if close {
                         painter_path.close()
@@ -1010,6 +1277,24 @@ fn to_qpointf(p: Point) -> qttypes::QPointF {
             }
         }
 
+        // QPen expects the dash pattern in units of the pen width, with an even number of entries
+        let dash_pattern = path.dash_pattern();
+        let mut dash_pattern: Vec<f64> = if stroke_width > 0.
+            && dash_pattern.iter().all(|length| *length >= 0.)
+            && dash_pattern.iter().sum::<f32>() > 0.
+        {
+            dash_pattern.iter().map(|length| (*length / stroke_width) as f64).collect()
+        } else {
+            Vec::new()
+        };
+        if dash_pattern.len() % 2 == 1 {
+            dash_pattern.extend_from_within(..);
+        }
+        let dash_pattern_ptr = dash_pattern.as_ptr();
+        let dash_pattern_len = dash_pattern.len();
+        let dash_offset =
+            if stroke_width > 0. { (path.dash_offset().get() / stroke_width) as f64 } else { 0. };
+
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [
                 painter as "QPainterPtr*",
@@ -1017,11 +1302,23 @@ fn to_qpointf(p: Point) -> qttypes::QPointF {
                 mut painter_path as "QPainterPath",
                 fill_brush as "QBrush",
                 stroke_brush as "QBrush",
-                stroke_width as "float"] {
+                stroke_width as "float",
+                dash_pattern_ptr as "const qreal*",
+                dash_pattern_len as "size_t",
+                dash_offset as "qreal"] {
             (*painter)->save();
             auto cleanup = qScopeGuard([&] { (*painter)->restore(); });
             (*painter)->translate(pos);
-            (*painter)->setPen(stroke_width > 0 ? QPen(stroke_brush, stroke_width) : Qt::NoPen);
+            if (stroke_width > 0) {
+                QPen pen(stroke_brush, stroke_width);
+                if (dash_pattern_len > 0) {
+                    pen.setDashPattern(QVector<qreal>(dash_pattern_ptr, dash_pattern_ptr + dash_pattern_len));
+                    pen.setDashOffset(dash_offset);
+                }
+                (*painter)->setPen(pen);
+            } else {
+                (*painter)->setPen(Qt::NoPen);
+            }
             (*painter)->setBrush(fill_brush);
             (*painter)->drawPath(painter_path);
         }}
@@ -1266,6 +1563,13 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         }}
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [painter as "QPainterPtr*", x_factor as "float", y_factor as "float"] {
+            (*painter)->scale(x_factor, y_factor);
+        }}
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [painter as "QPainterPtr*", opacity as "float"] {
@@ -1289,6 +1593,15 @@ fn shared_image_buffer_to_pixmap(buffer: &SharedImageBuffer) -> Option<qttypes::
         SharedImageBuffer::RGB8(img) => {
             (qttypes::ImageFormat::RGB888, img.width() * 3, img.as_bytes().as_ptr())
         }
+        SharedImageBuffer::RGB565(img) => {
+            (qttypes::ImageFormat::RGB16, img.width() * 2, img.as_slice().as_ptr() as *const u8)
+        }
+        SharedImageBuffer::Gray8(img) => {
+            (qttypes::ImageFormat::Grayscale8, img.width(), img.as_slice().as_ptr() as *const u8)
+        }
+        SharedImageBuffer::Alpha8(img) => {
+            (qttypes::ImageFormat::Alpha8, img.width(), img.as_slice().as_ptr() as *const u8)
+        }
     };
     let width: i32 = buffer.width() as _;
     let height: i32 = buffer.height() as _;
@@ -1307,6 +1620,38 @@ pub(crate) fn image_to_pixmap(
 }
 
 impl QtItemRenderer<'_> {
+    /// Calls `f` with the composition mode of the painter set for the blend mode.
+    fn with_blend_mode(&mut self, blend_mode: BlendMode, f: impl FnOnce(&mut Self)) {
+        let mode = match blend_mode {
+            BlendMode::Normal => return f(self),
+            BlendMode::Multiply => {
+                cpp!(unsafe [] -> u32 as "int" { return QPainter::CompositionMode_Multiply; })
+            }
+            BlendMode::Screen => {
+                cpp!(unsafe [] -> u32 as "int" { return QPainter::CompositionMode_Screen; })
+            }
+            BlendMode::Overlay => {
+                cpp!(unsafe [] -> u32 as "int" { return QPainter::CompositionMode_Overlay; })
+            }
+            BlendMode::Darken => {
+                cpp!(unsafe [] -> u32 as "int" { return QPainter::CompositionMode_Darken; })
+            }
+            BlendMode::Lighten => {
+                cpp!(unsafe [] -> u32 as "int" { return QPainter::CompositionMode_Lighten; })
+            }
+        };
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [painter as "QPainterPtr*", mode as "int"] {
+            (*painter)->save();
+            (*painter)->setCompositionMode(QPainter::CompositionMode(mode));
+        }}
+        f(self);
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [painter as "QPainterPtr*"] {
+            (*painter)->restore();
+        }}
+    }
+
     fn draw_image_impl(
         &mut self,
         item_rc: &ItemRc,
@@ -1330,8 +1675,8 @@ fn draw_image_impl(
                 let has_source_clipping = source_rect.map_or(false, |rect| {
                     rect.origin.x != 0
                         || rect.origin.y != 0
-                        || !rect.size.width != t.width
-                        || !rect.size.height != t.height
+                        || rect.size.width != origin.width as i32
+                        || rect.size.height != origin.height as i32
                 });
                 if has_source_clipping {
                     // Source size & clipping is not implemented yet
@@ -1720,20 +2065,57 @@ fn mouse_event(&self, event: MouseEvent) {
         timer_event();
     }
 
-    fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bool) {
+    /// `state` is the Qt::TouchPointState, or -1 if the touch was cancelled
+    fn touch_event(&self, id: u64, state: i32, position: LogicalPoint) {
+        let position = i_slint_core::api::LogicalPosition::new(position.x, position.y);
+        let event = match state {
+            -1 => WindowEvent::TouchCancelled { id },
+            0x01 => WindowEvent::TouchPressed { id, position },
+            0x02 => WindowEvent::TouchMoved { id, position },
+            0x08 => WindowEvent::TouchReleased { id, position },
+            _ => return,
+        };
+        self.window.dispatch_event(event);
+        timer_event();
+    }
+
+    fn external_drag_event(&self, position: Option<LogicalPoint>, event: items::DropEvent) -> bool {
+        let accepted = WindowInner::from_pub(&self.window).process_external_drag(position, &event);
+        timer_event();
+        accepted
+    }
+
+    fn external_drop_event(&self, position: LogicalPoint, event: items::DropEvent) -> bool {
+        let accepted = WindowInner::from_pub(&self.window).process_external_drop(position, event);
+        timer_event();
+        accepted
+    }
+
+    fn key_event(
+        &self,
+        key: i32,
+        text: qttypes::QString,
+        released: bool,
+        repeat: bool,
+        scan_code: i32,
+    ) {
         i_slint_core::animations::update_animations();
         let text: String = text.into();
 
         let text = qt_key_to_string(key as key_generated::Qt_Key, text);
+        // Without the text of the event, the key is translated from the key code alone, ignoring the modifiers
+        let key_code = qt_key_to_string(key as key_generated::Qt_Key, String::new());
 
-        let event = if released {
-            WindowEvent::KeyReleased { text }
+        let key = if released {
+            KeyInput::release(text)
         } else if repeat {
-            WindowEvent::KeyPressRepeated { text }
+            KeyInput::press_repeat(text)
         } else {
-            WindowEvent::KeyPressed { text }
+            KeyInput::press(text)
         };
-        self.window.dispatch_event(event);
+        self.window.dispatch_event(WindowEvent::KeyInput(
+            key.with_key_code(key_code).with_scan_code(scan_code),
+        ));
 
         timer_event();
     }
@@ -2067,6 +2449,37 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
         }};
     }
 
+    fn start_drag(&self, event: items::DropEvent, finished: Box<dyn FnOnce()>) -> bool {
+        let self_weak = self.self_weak.clone();
+        let mime_type: qttypes::QString = event.mime_type.as_str().into();
+        let data: qttypes::QString = event.data.as_str().into();
+        // QDrag::exec() runs a nested event loop until the drop, so don't start it from within
+        // the mouse event handler
+        i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+            if let Some(window) = self_weak.upgrade() {
+                let widget_ptr = window.widget_ptr();
+                cpp! {unsafe [widget_ptr as "QWidget*", mime_type as "QString", data as "QString"] {
+                    auto mime = new QMimeData;
+                    if (mime_type == QLatin1String("text/uri-list")) {
+                        QList<QUrl> urls;
+                        for (const auto &url : data.split(QLatin1Char('\n'), Qt::SkipEmptyParts))
+                            urls << QUrl::fromEncoded(url.trimmed().toUtf8());
+                        mime->setUrls(urls);
+                    } else if (mime_type == QLatin1String("text/plain")) {
+                        mime->setText(data);
+                    } else {
+                        mime->setData(mime_type, data.toUtf8());
+                    }
+                    auto drag = new QDrag(widget_ptr);
+                    drag->setMimeData(mime);
+                    drag->exec(Qt::CopyAction | Qt::MoveAction | Qt::LinkAction, Qt::CopyAction);
+                }};
+            }
+            finished();
+        });
+        true
+    }
+
     fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
         let widget_ptr = self.widget_ptr();
         let props = match request {
@@ -2151,13 +2564,24 @@ fn text_size(
         _scale_factor: ScaleFactor,
         text_wrap: TextWrap,
     ) -> LogicalSize {
-        get_font(font_request).text_size(
+        let font = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
+        font.text_size(
             text,
+            &tab_options,
             max_width.map(|logical_width| logical_width.get()),
             text_wrap,
         )
     }
 
+    fn font_metrics(
+        &self,
+        font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> items::FontMetrics {
+        get_font(font_request).font_metrics()
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -2171,16 +2595,20 @@ fn text_input_byte_offset_for_position(
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
         let rect: qttypes::QRectF = check_geometry!(size);
         let pos = qttypes::QPointF { x: pos.x as _, y: pos.y as _ };
-        let font: QFont = get_font(font_request);
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
 
         let visual_representation = text_input.visual_representation(Some(qt_password_character));
 
         let string = qttypes::QString::from(visual_representation.text.as_str());
 
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match text_input
+            .horizontal_alignment()
+            .resolve_for_text(visual_representation.text.as_str())
+        {
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            _ => key_generated::Qt_AlignmentFlag_AlignLeft,
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -2191,7 +2619,7 @@ fn text_input_byte_offset_for_position(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
-        let byte_offset = cpp! { unsafe [font as "QFont", string as "QString", pos as "QPointF", flags as "int",
+        let byte_offset = cpp! { unsafe [font as "QFont", tab_options as "QTextOption", string as "QString", pos as "QPointF", flags as "int",
                 rect as "QRectF", single_line as "bool"] -> usize as "size_t" {
             // we need to do the \n replacement in a copy because the original need to be kept to know the utf8 offset
             auto copy = string;
@@ -2199,7 +2627,7 @@ fn text_input_byte_offset_for_position(
                 copy.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(copy, font);
-            auto line = do_text_layout(layout, flags, rect, pos.y());
+            auto line = do_text_layout(layout, flags, rect, tab_options, pos.y());
             if (line < 0 || layout.lineCount() <= line)
                 return string.toUtf8().size();
             QTextLine textLine = layout.lineAt(line);
@@ -2231,14 +2659,15 @@ fn text_input_cursor_rect_for_byte_offset(
     ) -> LogicalRect {
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
         let rect: qttypes::QRectF = check_geometry!(size);
-        let font: QFont = get_font(font_request);
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
         let text = text_input.text();
         let mut string = qttypes::QString::from(text.as_str());
         let offset: u32 = utf8_byte_offset_to_utf16_units(text.as_str(), byte_offset) as _;
-        let flags = match text_input.horizontal_alignment() {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        let flags = match text_input.horizontal_alignment().resolve_for_text(text.as_str()) {
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            _ => key_generated::Qt_AlignmentFlag_AlignLeft,
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
@@ -2249,13 +2678,13 @@ fn text_input_cursor_rect_for_byte_offset(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
-        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool"]
+        let r = cpp! { unsafe [font as "QFont", tab_options as "QTextOption", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool"]
                 -> qttypes::QRectF as "QRectF" {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, tab_options);
 
             QTextLine textLine = layout.lineForTextPosition(offset);
             if (!textLine.isValid())
@@ -2269,15 +2698,102 @@ fn text_input_cursor_rect_for_byte_offset(
         )
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> usize {
+        if pos.y < 0. {
+            return 0;
+        }
+        let rect: qttypes::QRectF = check_geometry!(text.target_size());
+        let pos = qttypes::QPointF { x: pos.x as _, y: pos.y as _ };
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
+        let styled_text = text.styled_text();
+        let string = styled_text.map_or_else(|| text.text(), |styled| styled.text.into());
+        let flags = text_layout_flags(text, &string);
+        let string = qttypes::QString::from(string.as_str());
+        cpp! { unsafe [font as "QFont", tab_options as "QTextOption", string as "QString", pos as "QPointF", flags as "int",
+                rect as "QRectF"] -> usize as "size_t" {
+            auto copy = string;
+            copy.replace(QChar('\n'), QChar::LineSeparator);
+            QTextLayout layout(copy, font);
+            auto line = do_text_layout(layout, flags, rect, tab_options, pos.y());
+            if (line < 0 || layout.lineCount() <= line)
+                return string.toUtf8().size();
+            QTextLine textLine = layout.lineAt(line);
+            int cur;
+            if (pos.x() > textLine.naturalTextWidth()) {
+                cur = textLine.textStart() + textLine.textLength();
+                // Go back before the line separator or the trailing space that ends the line
+                if (cur > textLine.textStart() && cur < string.size())
+                    cur--;
+            } else {
+                cur = textLine.xToCursor(pos.x());
+            }
+            if (cur < string.size() && string[cur].isLowSurrogate())
+                cur++;
+            return QStringView(string).left(cur).toUtf8().size();
+        }}
+    }
+
+    fn text_rects_for_byte_range(
+        &self,
+        text: Pin<&dyn RenderText>,
+        byte_range: std::ops::Range<usize>,
+        font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect> {
+        let rect: qttypes::QRectF = check_geometry!(text.target_size());
+        let font: QFont = get_font(font_request.clone());
+        let tab_options = text_option_with_tab_stops(&font_request, &font);
+        let styled_text = text.styled_text();
+        let string = styled_text.map_or_else(|| text.text(), |styled| styled.text.into());
+        let flags = text_layout_flags(text, &string);
+        let start: u32 = utf8_byte_offset_to_utf16_units(string.as_str(), byte_range.start) as _;
+        let end: u32 = utf8_byte_offset_to_utf16_units(string.as_str(), byte_range.end) as _;
+        let mut string = qttypes::QString::from(string.as_str());
+        let mut rects: Vec<LogicalRect> = Vec::new();
+        let rects_ref = &mut rects;
+        cpp! { unsafe [font as "QFont", tab_options as "QTextOption", mut string as "QString", start as "int", end as "int", flags as "int", rect as "QRectF", rects_ref as "void*"] {
+            string.replace(QChar('\n'), QChar::LineSeparator);
+            QTextLayout layout(string, font);
+            do_text_layout(layout, flags, rect, tab_options);
+            for (int i = 0; i < layout.lineCount(); ++i) {
+                QTextLine textLine = layout.lineAt(i);
+                int line_start = std::max(start, textLine.textStart());
+                int line_end = std::min(end, textLine.textStart() + textLine.textLength());
+                if (line_start >= line_end)
+                    continue;
+                qreal x1 = textLine.cursorToX(line_start);
+                qreal x2 = textLine.cursorToX(line_end);
+                QRectF r(textLine.x() + std::min(x1, x2), layout.position().y() + textLine.y(), std::abs(x2 - x1), textLine.height());
+                rust!(Slint_textRectsForByteRange_push [rects_ref: &mut Vec<LogicalRect> as "void*", r: qttypes::QRectF as "QRectF"] {
+                    rects_ref.push(LogicalRect::new(
+                        LogicalPoint::new(r.x as _, r.y as _),
+                        LogicalSize::new(r.width as _, r.height as _),
+                    ));
+                });
+            }
+        }};
+        rects
+    }
+
     fn register_font_from_memory(
         &self,
-        data: &'static [u8],
+        data: std::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let data = qttypes::QByteArray::from(data);
-        cpp! {unsafe [data as "QByteArray"] {
+        let data = qttypes::QByteArray::from(data.as_ref());
+        let font_id = cpp! {unsafe [data as "QByteArray"] -> i32 as "int" {
             ensure_initialized(true);
-            QFontDatabase::addApplicationFontFromData(data);
-        } }
+            return QFontDatabase::addApplicationFontFromData(data);
+        } };
+        if font_id < 0 {
+            return Err("QFontDatabase could not load the font data".into());
+        }
         Ok(())
     }
 
@@ -2301,6 +2817,43 @@ fn register_font_from_path(
         Ok(())
     }
 
+    fn font_families(&self) -> Vec<i_slint_core::graphics::FontFamily> {
+        let mut faces: Vec<(SharedString, i_slint_core::graphics::FontFace)> = Vec::new();
+        let faces_ptr = &mut faces;
+        cpp! {unsafe [faces_ptr as "void*"] {
+            ensure_initialized(true);
+            #if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+            QFontDatabase db;
+            const QStringList families = db.families();
+            auto styles = [&](const QString &family) { return db.styles(family); };
+            // Qt 5 uses weights from 0 to 99
+            auto weight_of = [&](const QString &family, const QString &style) { return qMin(db.weight(family, style) * 8 + 100, 900); };
+            auto is_italic = [&](const QString &family, const QString &style) { return db.italic(family, style); };
+            #else
+            // The functions of QFontDatabase are static since Qt 6
+            const QStringList families = QFontDatabase::families();
+            auto styles = [](const QString &family) { return QFontDatabase::styles(family); };
+            auto weight_of = [](const QString &family, const QString &style) { return QFontDatabase::weight(family, style); };
+            auto is_italic = [](const QString &family, const QString &style) { return QFontDatabase::italic(family, style); };
+            #endif
+            for (const QString &family : families) {
+                for (const QString &style : styles(family)) {
+                    int weight = weight_of(family, style);
+                    int slant = is_italic(family, style) ? 1 : 0;
+                    rust!(Slint_fontFamiliesAddFace [faces_ptr: &mut Vec<(SharedString, i_slint_core::graphics::FontFace)> as "void*", family: qttypes::QString as "QString", weight: i32 as "int", slant: i32 as "int"] {
+                        let style = if slant == 1 {
+                            i_slint_core::graphics::FontStyle::Italic
+                        } else {
+                            i_slint_core::graphics::FontStyle::Normal
+                        };
+                        faces_ptr.push((family.to_string().into(), i_slint_core::graphics::FontFace { weight, style }));
+                    });
+                }
+            }
+        } }
+        i_slint_core::graphics::FontFamily::collect_families(faces)
+    }
+
     fn default_font_size(&self) -> LogicalLength {
         let default_font_size = cpp!(unsafe[] -> i32 as "int" {
             return QFontInfo(qApp->font()).pixelSize();
@@ -2346,6 +2899,11 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
         );
         Ok(buffer)
     }
+
+    fn render_to_image(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        // QWidget::grab() renders the widget into a pixmap, also when it's not visible
+        self.take_snapshot()
+    }
 }
 
 fn accessible_item(item: Option<ItemRc>) -> Option<ItemRc> {
@@ -2360,17 +2918,91 @@ fn accessible_item(item: Option<ItemRc>) -> Option<ItemRc> {
     None
 }
 
+/// Converts the text and the spans of markup to the HTML that QTextDocument understands
+fn styled_text_to_html(styled_text: &i_slint_core::textlayout::StyledText) -> String {
+    let mut html = String::from("<div style=\"white-space:pre-wrap\">");
+    for span in &styled_text.spans {
+        let mut css = String::new();
+        if span.style.bold {
+            css += "font-weight:bold;";
+        }
+        if span.style.italic {
+            css += "font-style:italic;";
+        }
+        if span.style.underline {
+            css += "text-decoration:underline;";
+        }
+        if let Some(color) = span.style.color {
+            css += &format!(
+                "color:rgba({},{},{},{});",
+                color.red(),
+                color.green(),
+                color.blue(),
+                color.alpha()
+            );
+        }
+        if !css.is_empty() {
+            html += &format!("<span style=\"{css}\">");
+        }
+        for c in styled_text.text[span.range.clone()].chars() {
+            match c {
+                '<' => html += "&lt;",
+                '>' => html += "&gt;",
+                '&' => html += "&amp;",
+                '\n' => html += "<br>",
+                c => html.push(c),
+            }
+        }
+        if !css.is_empty() {
+            html += "</span>";
+        }
+    }
+    html += "</div>";
+    html
+}
+
 fn get_font(request: FontRequest) -> QFont {
     let family: qttypes::QString = request.family.unwrap_or_default().as_str().into();
+    let fallback_families: qttypes::QString = request
+        .fallback_families
+        .iter()
+        .map(|family| family.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+        .as_str()
+        .into();
     let pixel_size: f32 = request.pixel_size.map_or(0., |logical_size| logical_size.get());
     let weight: i32 = request.weight.unwrap_or(0);
     let letter_spacing: f32 =
         request.letter_spacing.map_or(0., |logical_spacing| logical_spacing.get());
-    let italic: bool = request.italic;
-    cpp!(unsafe [family as "QString", pixel_size as "float", weight as "int", letter_spacing as "float", italic as "bool"] -> QFont as "QFont" {
+    let word_spacing: f32 =
+        request.word_spacing.map_or(0., |logical_spacing| logical_spacing.get());
+    let style: i32 = match request.style {
+        FontStyle::Normal => 0,
+        FontStyle::Italic => 1,
+        FontStyle::Oblique => 2,
+    };
+    // In percent of the normal width, like QFont::Stretch
+    let stretch: i32 = match request.stretch {
+        items::FontStretch::Normal => 100,
+        items::FontStretch::UltraCondensed => 50,
+        items::FontStretch::ExtraCondensed => 62,
+        items::FontStretch::Condensed => 75,
+        items::FontStretch::SemiCondensed => 87,
+        items::FontStretch::SemiExpanded => 112,
+        items::FontStretch::Expanded => 125,
+        items::FontStretch::ExtraExpanded => 150,
+        items::FontStretch::UltraExpanded => 200,
+    };
+    cpp!(unsafe [family as "QString", fallback_families as "QString", pixel_size as "float", weight as "int", letter_spacing as "float", word_spacing as "float", style as "int", stretch as "int"] -> QFont as "QFont" {
         QFont f;
-        if (!family.isEmpty())
+        if (!family.isEmpty()) {
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 14, 0)
+            f.setFamilies(QStringList(family) + fallback_families.split(QLatin1Char(','), Qt::SkipEmptyParts));
+    #else
             f.setFamily(family);
+    #endif
+        }
         if (pixel_size > 0)
             f.setPixelSize(pixel_size);
         if (weight > 0) {
@@ -2381,7 +3013,9 @@ fn get_font(request: FontRequest) -> QFont {
     #endif
         }
         f.setLetterSpacing(QFont::AbsoluteSpacing, letter_spacing);
-        f.setItalic(italic);
+        f.setWordSpacing(word_spacing);
+        f.setStyle(QFont::Style(style));
+        f.setStretch(stretch);
         // Mark all font properties as resolved, to avoid inheriting font properties
         // from the widget hierarchy. Later we call QPainter::setFont, which would
         // merge in unset properties (such as bold, etc.) that it retrieved from
@@ -2397,8 +3031,38 @@ fn get_font(request: FontRequest) -> QFont {
 
 cpp_class! {pub unsafe struct QFont as "QFont"}
 
+cpp_class! {unsafe struct QTextOption as "QTextOption"}
+
+/// Returns text options with the tab stops of the request, to base the options of the layout of
+/// the text on.
+fn text_option_with_tab_stops(request: &FontRequest, font: &QFont) -> QTextOption {
+    let tab_width: f32 = request.tab_width.map_or(0., |width| width.get());
+    let tab_stops = request.tab_stops.iter().map(|position| position.get()).collect::<Vec<f32>>();
+    let tab_stops_ptr = tab_stops.as_ptr();
+    let tab_stops_len = tab_stops.len();
+    cpp!(unsafe [font as "const QFont*", tab_width as "float", tab_stops_ptr as "const float*", tab_stops_len as "size_t"] -> QTextOption as "QTextOption" {
+        QTextOption options;
+        if (tab_width <= 0) {
+            tab_width = 8 * QFontMetricsF(*font).horizontalAdvance(QLatin1Char(' '));
+        }
+        options.setTabStopDistance(tab_width);
+        QList<QTextOption::Tab> tabs;
+        for (size_t i = 0; i < tab_stops_len; ++i) {
+            tabs.append(QTextOption::Tab(tab_stops_ptr[i], QTextOption::LeftTab));
+        }
+        options.setTabs(tabs);
+        return options;
+    })
+}
+
 impl QFont {
-    fn text_size(&self, text: &str, max_width: Option<f32>, text_wrap: TextWrap) -> LogicalSize {
+    fn text_size(
+        &self,
+        text: &str,
+        tab_options: &QTextOption,
+        max_width: Option<f32>,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
         let string = qttypes::QString::from(text);
         let char_wrap = text_wrap == TextWrap::CharWrap;
         let mut r = qttypes::QRectF::default();
@@ -2406,12 +3070,34 @@ fn text_size(&self, text: &str, max_width: Option<f32>, text_wrap: TextWrap) ->
             r.height = f32::MAX as _;
             r.width = max as _;
         }
-        let size = cpp! { unsafe [self as "const QFont*", string as "QString", r as "QRectF", char_wrap as "bool"]
+        let size = cpp! { unsafe [self as "const QFont*", tab_options as "const QTextOption*", string as "QString", r as "QRectF", char_wrap as "bool"]
                 -> qttypes::QSizeF as "QSizeF"{
-            return QFontMetricsF(*self).boundingRect(r, r.isEmpty() ? 0 : ((char_wrap) ? Qt::TextWrapAnywhere : Qt::TextWordWrap) , string).size();
+            int tab_stops = qRound(tab_options->tabStopDistance());
+            QVector<int> tab_array;
+            for (const auto &tab : tab_options->tabs()) {
+                tab_array << qRound(tab.position);
+            }
+            tab_array << 0;
+            int flags = Qt::TextExpandTabs | (r.isEmpty() ? 0 : ((char_wrap) ? Qt::TextWrapAnywhere : Qt::TextWordWrap));
+            return QFontMetricsF(*self).boundingRect(r, flags, string, tab_stops, tab_array.data()).size();
         }};
         LogicalSize::new(size.width as _, size.height as _)
     }
+
+    fn font_metrics(&self) -> items::FontMetrics {
+        let mut metrics = [0f32; 4];
+        let metrics_ptr = metrics.as_mut_ptr();
+        cpp! { unsafe [self as "const QFont*", metrics_ptr as "float*"] {
+            QFontMetricsF fm(*self);
+            metrics_ptr[0] = fm.ascent();
+            // Qt's descent is positive
+            metrics_ptr[1] = -fm.descent();
+            metrics_ptr[2] = fm.xHeight();
+            metrics_ptr[3] = fm.capHeight();
+        }};
+        let [ascent, descent, x_height, cap_height] = metrics;
+        items::FontMetrics { ascent, descent, x_height, cap_height }
+    }
 }
 
 thread_local! {
@@ -2568,6 +3254,24 @@ pub extern "C" fn slint_qt_get_widget(
     }
 }
 
+/// Returns the Qt alignment and wrap flags to lay out the text of a `Text` item with `do_text_layout`
+fn text_layout_flags(text: Pin<&dyn RenderText>, string: &str) -> u32 {
+    let (horizontal_alignment, vertical_alignment) = text.alignment();
+    (match horizontal_alignment.resolve_for_text(string) {
+        TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
+        TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+        _ => key_generated::Qt_AlignmentFlag_AlignLeft,
+    }) | match vertical_alignment {
+        TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
+        TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
+        TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
+    } | match text.wrap() {
+        TextWrap::NoWrap => 0,
+        TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
+        TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
+    }
+}
+
 fn utf8_byte_offset_to_utf16_units(str: &str, byte_offset: usize) -> usize {
     let mut current_offset = 0;
     let mut utf16_units = 0;
@@ -52,7 +52,11 @@ fn create_window_adapter(
     fn duration_since_start(&self) -> core::time::Duration {
         if self.mock_time {
             // The slint::testing::mock_elapsed_time updates the animation tick directly
-            core::time::Duration::from_millis(i_slint_core::animations::current_tick().0)
+            core::time::Duration::from_millis(
+                i_slint_core::animations::CURRENT_ANIMATION_DRIVER
+                    .with(|driver| driver.real_tick())
+                    .0,
+            )
         } else {
             static INITIAL_INSTANT: std::sync::OnceLock<std::time::Instant> =
                 std::sync::OnceLock::new();
@@ -173,6 +177,15 @@ fn text_size(
         LogicalSize::new(text.len() as f32 * 10., 10.)
     }
 
+    fn font_metrics(
+        &self,
+        _font_request: i_slint_core::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> i_slint_core::items::FontMetrics {
+        // Matches the line height of 10 of text_size()
+        i_slint_core::items::FontMetrics { ascent: 7., descent: -3., x_height: 4., cap_height: 6. }
+    }
+
     // this works only for single line text
     fn text_input_byte_offset_for_position(
         &self,
@@ -197,9 +210,39 @@ fn text_input_cursor_rect_for_byte_offset(
         LogicalRect::new(Point2D::new(byte_offset as f32 * 10., 0.), Size2D::new(1., 10.))
     }
 
+    // this works only for single line text
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        pos: LogicalPoint,
+        _font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> usize {
+        let text_len = text.text().len();
+        let result = pos.x / 10.;
+        result.min(text_len as f32).max(0.) as usize
+    }
+
+    // this works only for single line text
+    fn text_rects_for_byte_range(
+        &self,
+        _text: Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        byte_range: core::ops::Range<usize>,
+        _font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect> {
+        if byte_range.is_empty() {
+            return Vec::new();
+        }
+        vec![LogicalRect::new(
+            Point2D::new(byte_range.start as f32 * 10., 0.),
+            Size2D::new(byte_range.len() as f32 * 10., 10.),
+        )]
+    }
+
     fn register_font_from_memory(
         &self,
-        _data: &'static [u8],
+        _data: std::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }
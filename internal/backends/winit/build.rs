@@ -8,6 +8,8 @@ fn main() {
     cfg_aliases! {
        enable_skia_renderer: { any(feature = "renderer-skia", feature = "renderer-skia-opengl", feature = "renderer-skia-vulkan")},
        enable_accesskit: { all(feature = "accessibility", not(target_arch = "wasm32")) },
+       // The platforms where winit provides the key without modifiers and the scan code of key events
+       winit_key_extensions: { any(target_os = "windows", target_os = "macos", all(unix, not(any(target_os = "ios", target_os = "android")), any(feature = "x11", feature = "wayland"))) },
     }
     // This uses `web_sys_unstable_api`, which is typically set via `RUST_FLAGS`
     println!("cargo:rustc-check-cfg=cfg(web_sys_unstable_apis)");
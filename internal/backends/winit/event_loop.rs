@@ -14,7 +14,7 @@
 use corelib::api::EventLoopError;
 use corelib::graphics::euclid;
 use corelib::input::{KeyEvent, KeyEventType, MouseEvent};
-use corelib::items::{ColorScheme, PointerEventButton};
+use corelib::items::{ColorScheme, DropEvent, PointerEventButton};
 use corelib::lengths::LogicalPoint;
 use corelib::platform::PlatformError;
 use corelib::window::*;
@@ -264,17 +264,64 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// Returns the `file://` URL of the path, as used in `text/uri-list`
+fn file_url(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy();
+    let mut url = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows paths start with the drive letter
+        url.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                url.push(byte as char)
+            }
+            b'\\' if std::path::MAIN_SEPARATOR == '\\' => url.push('/'),
+            _ => url.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    url
+}
+
+/// How long to wait for the cursor position after files were dropped before dispatching the
+/// drop at the last known position.
+const DROP_POSITION_TIMEOUT: core::time::Duration = core::time::Duration::from_millis(250);
+
+/// Files that were dropped on a window but not yet passed to its `DropArea`.
+struct PendingDrop {
+    window_id: winit::window::WindowId,
+    // the file URLs, one per line
+    urls: String,
+    deadline: corelib::animations::Instant,
+}
+
 #[derive(Default)]
 pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
+    // the file URLs of the files dragged over the window, one per line
+    hovered_files: String,
+    pending_drop: Option<PendingDrop>,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
 }
 
+impl EventLoopState {
+    /// Pass the files collected from the `DroppedFile` events to the window as a single drop.
+    fn dispatch_pending_drop(&mut self) {
+        let Some(drop) = self.pending_drop.take() else { return };
+        if let Some(window) = window_by_id(drop.window_id) {
+            WindowInner::from_pub(window.window()).process_external_drop(
+                self.cursor_pos,
+                DropEvent { mime_type: "text/uri-list".into(), data: drop.urls.into() },
+            );
+        }
+    }
+}
+
 impl winit::application::ApplicationHandler<SlintUserEvent> for EventLoopState {
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
         ALL_WINDOWS.with(|ws| {
@@ -346,6 +393,23 @@ fn window_event(
             }
 
             WindowEvent::KeyboardInput { event, is_synthetic, .. } => {
+                // The named keys are the same with or without modifiers, so only the characters
+                // are taken from the key without modifiers. The core falls back to the text otherwise.
+                #[cfg(winit_key_extensions)]
+                let (key_code_without_modifiers, scan_code) = {
+                    use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
+                    use winit::platform::scancode::PhysicalKeyExtScancode;
+                    (
+                        match event.key_without_modifiers() {
+                            winit::keyboard::Key::Character(str) => str.as_str().into(),
+                            _ => Default::default(),
+                        },
+                        event.physical_key.to_scancode().map_or(0, |code| code as i32),
+                    )
+                };
+                #[cfg(not(winit_key_extensions))]
+                let (key_code_without_modifiers, scan_code) = (Default::default(), 0);
+
                 let key_code = event.logical_key;
                 // For now: Match Qt's behavior of mapping command to control and control to meta (LWin/RWin).
                 #[cfg(target_os = "macos")]
@@ -376,9 +440,10 @@ macro_rules! winit_key_to_char {
             }
                 let text = i_slint_common::for_each_special_keys!(winit_key_to_char);
 
-                window.window().dispatch_event(match event.state {
+                use corelib::platform::KeyInput;
+                let key = match event.state {
                     winit::event::ElementState::Pressed if event.repeat => {
-                        corelib::platform::WindowEvent::KeyPressRepeated { text }
+                        KeyInput::press_repeat(text)
                     }
                     winit::event::ElementState::Pressed => {
                         if is_synthetic {
@@ -392,12 +457,13 @@ macro_rules! winit_key_to_char {
                                 return;
                             }
                         }
-                        corelib::platform::WindowEvent::KeyPressed { text }
+                        KeyInput::press(text)
                     }
-                    winit::event::ElementState::Released => {
-                        corelib::platform::WindowEvent::KeyReleased { text }
-                    }
-                });
+                    winit::event::ElementState::Released => KeyInput::release(text),
+                };
+                window.window().dispatch_event(corelib::platform::WindowEvent::KeyInput(
+                    key.with_key_code(key_code_without_modifiers).with_scan_code(scan_code),
+                ));
             }
             WindowEvent::Ime(winit::event::Ime::Preedit(string, preedit_selection)) => {
                 let event = KeyEvent {
@@ -427,6 +493,10 @@ macro_rules! winit_key_to_char {
                 );
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
+                // The first position reported after a drop is where the files were dropped
+                if self.pending_drop.as_ref().is_some_and(|drop| drop.window_id == window_id) {
+                    self.dispatch_pending_drop();
+                }
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
             }
             WindowEvent::CursorLeft { .. } => {
@@ -482,34 +552,23 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
-                }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let id = touch.id;
+                window.window().dispatch_event(match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        corelib::platform::WindowEvent::TouchPressed { id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { id, position }
+                    }
+                    winit::event::TouchPhase::Ended => {
+                        corelib::platform::WindowEvent::TouchReleased { id, position }
+                    }
+                    winit::event::TouchPhase::Cancelled => {
+                        corelib::platform::WindowEvent::TouchCancelled { id }
+                    }
+                });
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -522,6 +581,46 @@ macro_rules! winit_key_to_char {
                     //window.resize_event(inner_size_writer.???)?;
                 }
             }
+            // winit doesn't report the position of the drag: use the last seen cursor position,
+            // which isn't updated while the OS drags something over the window
+            WindowEvent::HoveredFile(path) => {
+                // winit sends one event per file
+                if !self.hovered_files.is_empty() {
+                    self.hovered_files.push('\n');
+                }
+                self.hovered_files.push_str(&file_url(&path));
+                runtime_window.process_external_drag(
+                    Some(self.cursor_pos),
+                    &DropEvent {
+                        mime_type: "text/uri-list".into(),
+                        data: self.hovered_files.as_str().into(),
+                    },
+                );
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.hovered_files.clear();
+                runtime_window.process_external_drag(None, &DropEvent::default());
+            }
+            WindowEvent::DroppedFile(path) => {
+                // The files are dropped one by one as well: collect them into a single drop, which
+                // is dispatched with the next cursor position (winit doesn't update it during the
+                // drag), or at the last known position after DROP_POSITION_TIMEOUT.
+                self.hovered_files.clear();
+                match &mut self.pending_drop {
+                    Some(drop) if drop.window_id == window_id => {
+                        drop.urls.push('\n');
+                        drop.urls.push_str(&file_url(&path));
+                    }
+                    _ => {
+                        self.dispatch_pending_drop();
+                        self.pending_drop = Some(PendingDrop {
+                            window_id,
+                            urls: file_url(&path),
+                            deadline: corelib::animations::Instant::now() + DROP_POSITION_TIMEOUT,
+                        });
+                    }
+                }
+            }
             WindowEvent::ThemeChanged(theme) => window.set_color_scheme(match theme {
                 winit::window::Theme::Dark => ColorScheme::Dark,
                 winit::window::Theme::Light => ColorScheme::Light,
@@ -574,9 +673,19 @@ fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
             })
         }
 
+        let now = corelib::animations::Instant::now();
+        if self.pending_drop.as_ref().is_some_and(|drop| drop.deadline <= now) {
+            self.dispatch_pending_drop();
+        }
+
         if event_loop.control_flow() == ControlFlow::Wait {
-            if let Some(next_timer) = corelib::platform::duration_until_next_timer_update() {
-                event_loop.set_control_flow(ControlFlow::wait_duration(next_timer));
+            let next_drop = self.pending_drop.as_ref().map(|drop| drop.deadline - now);
+            if let Some(timeout) = corelib::platform::duration_until_next_timer_update()
+                .into_iter()
+                .chain(next_drop)
+                .min()
+            {
+                event_loop.set_control_flow(ControlFlow::wait_duration(timeout));
             }
         }
     }
@@ -377,6 +377,23 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         let mut pair = clipboard_pair.borrow_mut();
         clipboard::select_clipboard(&mut pair, clipboard).and_then(|c| c.get_contents().ok())
     }
+
+    #[cfg(target_arch = "wasm32")]
+    fn prefers_reduced_motion(&self) -> bool {
+        matches_media_query("(prefers-reduced-motion: reduce)")
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn prefers_high_contrast(&self) -> bool {
+        matches_media_query("(prefers-contrast: more)")
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn matches_media_query(query: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(query).ok().flatten())
+        .is_some_and(|list| list.matches())
 }
 
 /// Spawn the event loop, using [`winit::platform::web::EventLoopExtWebSys::spawn()`]
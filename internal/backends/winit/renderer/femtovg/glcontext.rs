@@ -52,6 +52,10 @@ fn resize(
     fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void {
         self.context.display().get_proc_address(name)
     }
+
+    fn buffer_age(&self) -> u32 {
+        self.surface.buffer_age()
+    }
 }
 
 impl OpenGLContext {
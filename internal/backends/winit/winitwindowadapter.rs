@@ -80,6 +80,7 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
         ImageInner::EmbeddedImage { buffer, .. } => buffer.clone(),
         _ => return None,
     };
+    let pixel_buffer = pixel_buffer.to_rgb8_or_rgba8().unwrap_or(pixel_buffer);
 
     // This could become a method in SharedPixelBuffer...
     let rgba_pixels: Vec<u8> = match &pixel_buffer {
@@ -100,6 +101,11 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
                     .chain(std::iter::once(alpha as u8))
             })
             .collect(),
+        SharedImageBuffer::RGB565(_)
+        | SharedImageBuffer::Gray8(_)
+        | SharedImageBuffer::Alpha8(_) => {
+            unreachable!()
+        }
     };
 
     winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
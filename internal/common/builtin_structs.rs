@@ -65,6 +65,14 @@ struct PointerEvent {
                     kind: PointerEventKind,
                     /// The keyboard modifiers pressed during the event
                     modifiers: KeyboardModifiers,
+                    /// The horizontal position of the pointer, relative to the element
+                    x: Coord,
+                    /// The vertical position of the pointer, relative to the element
+                    y: Coord,
+                    /// The horizontal position of the pointer, relative to the window
+                    window_x: Coord,
+                    /// The vertical position of the pointer, relative to the window
+                    window_y: Coord,
                 }
                 private {
                 }
@@ -97,6 +105,13 @@ struct KeyEvent {
                     /// This field is set to true for key press events that are repeated,
                     /// i.e. the key is held down. It's always false for key release events.
                     repeat: bool,
+                    /// The key that was pressed or released, without the effect of the modifiers. For example, when pressing
+                    /// Shift+A, `text` is "A" and `key_code` is "a". Special keys use the same characters as in `text`, see the
+                    /// `Key` namespace. It's the same as `text` when the windowing system doesn't provide it.
+                    key_code: SharedString,
+                    /// The platform specific code of the physical key, which doesn't depend on the keyboard layout.
+                    /// It's 0 when the windowing system doesn't provide it.
+                    scan_code: i32,
                 }
                 private {
                     /// Indicates whether the key was pressed or released
@@ -115,6 +130,20 @@ struct KeyEvent {
                 }
             }
 
+            /// This structure describes the data that is dragged from a `DragArea`.
+            /// It is passed to the `can-drop` and `dropped` callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The MIME type of the data, from the `mime-type` property of the `DragArea`
+                    mime_type: SharedString,
+                    /// The data, from the `data` property of the `DragArea`
+                    data: SharedString,
+                }
+                private {
+                }
+            }
+
             /// Represents an item in a StandardListView and a StandardTableView.
             #[non_exhaustive]
             struct StandardListViewItem {
@@ -147,6 +176,26 @@ struct TableColumn {
                 }
             }
 
+            /// The metrics of the font of a `Text` or `TextInput` element, in logical pixels, for its
+            /// font size. Use them to align the baseline of a text with other elements.
+            struct FontMetrics {
+                @name = "slint::private_api::FontMetrics"
+                export {
+                    /// The distance from the baseline to the top of the line.
+                    ascent: Coord,
+                    /// The distance from the baseline to the bottom of the line. This is usually negative.
+                    descent: Coord,
+                    /// The height of lower case letters above the baseline, such as the letter x,
+                    /// or zero if the font doesn't specify it.
+                    x_height: Coord,
+                    /// The height of flat upper case letters above the baseline, such as the letter H,
+                    /// or zero if the font doesn't specify it.
+                    cap_height: Coord,
+                }
+                private {
+                }
+            }
+
             /// Value of the state property
             /// A state is just the current state, but also has information about the previous state and the moment it changed
             struct StateInfo {
@@ -28,6 +28,11 @@ enum TextHorizontalAlignment {
                 Center,
                 /// The text will be aligned to the right of the containing box.
                 Right,
+                /// The text will be aligned with the edge where it starts: the left edge for left-to-right text, and the right edge for right-to-left text.
+                /// The direction of each paragraph is determined by its first letter that has a strong direction.
+                Start,
+                /// The text will be aligned with the edge where it ends: the right edge for left-to-right text, and the left edge for right-to-left text.
+                End,
             }
 
             /// This enum describes the different types of alignment of text along the vertical axis of a [`Text`](elements.md#text) element.
@@ -54,8 +59,14 @@ enum TextWrap {
             enum TextOverflow {
                 /// The text will simply be clipped.
                 Clip,
-                /// The text will be elided with `…`.
+                /// The text will be elided with `…` at the end. Text that wraps is elided at the end of the last visible line.
                 Elide,
+                /// The start of the text will be replaced with `…`, keeping the end visible.
+                /// Text that wraps is elided like with `elide`.
+                ElideLeft,
+                /// The middle of the text will be replaced with `…`, keeping the start and the end visible.
+                /// Text that wraps is elided like with `elide`.
+                ElideMiddle,
             }
 
             /// This enum describes the positioning of a text stroke relative to the border of the glyphs in a [`Text`](elements.md#text).
@@ -66,6 +77,37 @@ enum TextStrokeStyle {
                 Center,
             }
 
+            /// This enum describes how the `text` of a [`Text`](elements.md#text) is interpreted.
+            enum TextFormat {
+                /// The text is shown as it is.
+                Plain,
+                /// The text can contain the markup tags `<b>`, `<i>`, `<u>`, `<span color="#rrggbb">`, `<a href="...">`,
+                /// and `<br>`, as well as the entities `&lt;`, `&gt;`, `&amp;`, `&quot;`, and `&apos;`.
+                Markup,
+            }
+
+            /// This enum selects a narrower or wider face of the font family of a text, if the family has one.
+            enum FontStretch {
+                /// The normal width of the font family.
+                Normal,
+                /// The narrowest width, 50% of the normal width.
+                UltraCondensed,
+                /// 62.5% of the normal width.
+                ExtraCondensed,
+                /// 75% of the normal width.
+                Condensed,
+                /// 87.5% of the normal width.
+                SemiCondensed,
+                /// 112.5% of the normal width.
+                SemiExpanded,
+                /// 125% of the normal width.
+                Expanded,
+                /// 150% of the normal width.
+                ExtraExpanded,
+                /// The widest width, 200% of the normal width.
+                UltraExpanded,
+            }
+
             /// This enum describes whether an event was rejected or accepted by an event handler.
             enum EventResult {
                 /// The event is rejected by this event handler and may then be handled by the parent item
@@ -82,6 +124,18 @@ enum FillRule {
                 Evenodd,
             }
 
+            /// This enum describes how the shapes of two paths are combined into one shape.
+            enum PathOperation {
+                /// The resulting shape covers the area of both paths.
+                Union,
+                /// The resulting shape covers the area where the two paths overlap.
+                Intersection,
+                /// The resulting shape covers the area of the first path that's not covered by the second path.
+                Difference,
+                /// The resulting shape covers the area that's covered by exactly one of the two paths.
+                Xor,
+            }
+
             /// Use this enum to add standard buttons to a [`Dialog`](elements.md#dialog). The look and positioning
             /// of these [`StandardButton`](../widgets/standardbutton.md)s depends on the environment
             /// (OS, UI environment, etc.) the application runs in.
@@ -281,12 +335,67 @@ enum ImageTiling {
                 Round,
             }
 
+            /// This enum describes whether the source of an [`Image`](elements.md#image) element is available.
+            enum ImageLoadingStatus {
+                /// The image is available, or there is no image to load.
+                Ready,
+                /// The image is loaded from a URL in the background and is not available yet.
+                Pending,
+                /// The image could not be loaded from its URL.
+                Error,
+            }
+
+            /// This enum specifies how the colors of an element are combined with the colors of what is
+            /// drawn behind it.
+            enum BlendMode {
+                /// The element is drawn over what is behind it.
+                Normal,
+                /// The colors are multiplied. The result is always darker, which is useful for shadows.
+                Multiply,
+                /// The inverted colors are multiplied and the result is inverted. The result is always lighter.
+                Screen,
+                /// Multiplies dark colors and screens light colors of the background, increasing the contrast.
+                Overlay,
+                /// The darker of the two colors is kept.
+                Darken,
+                /// The lighter of the two colors is kept.
+                Lighten,
+            }
+
+            /// This enum specifies the color space in which colors are interpolated when a `color` or
+            /// `brush` property is animated.
+            enum ColorInterpolation {
+                /// The red, green, and blue channels are interpolated as they are stored. This is fast,
+                /// but the midpoint between two saturated colors can look dull.
+                Srgb,
+                /// The channels are converted to linear light before being interpolated, which avoids
+                /// dark midpoints.
+                LinearSrgb,
+                /// The colors are interpolated in the perceptual Oklab color space, which keeps the
+                /// perceived lightness changing evenly.
+                Oklab,
+                /// The hue, saturation, and value are interpolated, going around the color wheel the
+                /// shortest way.
+                Hsv,
+            }
+
+            /// This enum specifies how an image pattern brush repeats its image to fill a shape.
+            enum PatternTiling {
+                /// The image is repeated horizontally and vertically.
+                Repeat,
+                /// The image is repeated horizontally only.
+                RepeatX,
+                /// The image is repeated vertically only.
+                RepeatY,
+            }
+
             /// This enum is used to define the type of the input field.
             #[non_exhaustive]
             enum InputType {
                 /// The default value. This will render all characters normally
                 Text,
-                /// This will render all characters with a character that defaults to "*"
+                /// This will render all characters with a character that defaults to "*".
+                /// The text can't be copied or cut to the clipboard.
                 Password,
                 /// This will only accept and render number characters (0-9)
                 Number,
@@ -392,6 +501,16 @@ enum Orientation {
                 Vertical,
             }
 
+            /// This enum describes when the scrollbars of a [`ScrollView`](../widgets/scrollview.md) are shown.
+            enum ScrollBarPolicy {
+                /// The scrollbars are shown when the viewport is bigger than the visible area.
+                AsNeeded,
+                /// The scrollbars are always shown.
+                Always,
+                /// The scrollbars are never shown. The viewport can still be scrolled.
+                Never,
+            }
+
             /// This enum indicates the color scheme used by the widget style. Use this to explicitly switch
             /// between dark and light schemes, or choose Unknown to fall back to the system default.
             enum ColorScheme {
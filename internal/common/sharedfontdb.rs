@@ -185,12 +185,17 @@ fn init_fontdb() -> FontDatabase {
 }
 
 /// This function can be used to register a custom TrueType font with Slint,
-/// for use with the `font-family` property. The provided slice must be a valid TrueType
+/// for use with the `font-family` property. The provided data must be a valid TrueType
 /// font.
-pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    FONT_DB.with_borrow_mut(|db| {
+pub fn register_font_from_memory(
+    data: std::borrow::Cow<'static, [u8]>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let face_ids = FONT_DB.with_borrow_mut(|db| {
         db.make_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
     });
+    if face_ids.is_empty() {
+        return Err("The data doesn't contain any font faces".into());
+    }
     Ok(())
 }
 
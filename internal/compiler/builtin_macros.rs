@@ -34,26 +34,41 @@ pub fn lower_macro(
                 "Arguments to cubic bezier curve must be number literal";
             // FIXME: this is not pretty to be handling there.
             // Maybe "cubic_bezier" should be a function that is lowered later
-            let mut a = || match sub_expr.next() {
-                None => {
-                    has_error.get_or_insert((n.clone(), "Not enough arguments"));
-                    0.
-                }
-                Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
-                // handle negative numbers
-                Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
-                    Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
-                    _ => {
+            let mut a = |is_x: bool| {
+                let (val, n) = match sub_expr.next() {
+                    None => {
+                        has_error.get_or_insert((n.clone(), "Not enough arguments"));
+                        return 0.;
+                    }
+                    Some((Expression::NumberLiteral(val, Unit::None), n)) => (val, n),
+                    // handle negative numbers
+                    Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
+                        Expression::NumberLiteral(val, Unit::None) => (-1.0 * val, n),
+                        _ => {
+                            has_error.get_or_insert((n, expected_argument_type_error));
+                            return 0.;
+                        }
+                    },
+                    Some((_, n)) => {
                         has_error.get_or_insert((n, expected_argument_type_error));
-                        0.
+                        return 0.;
                     }
-                },
-                Some((_, n)) => {
-                    has_error.get_or_insert((n, expected_argument_type_error));
-                    0.
+                };
+                // As in CSS, the curve must be a function of the time
+                if is_x && !(0.0..=1.0).contains(&val) {
+                    has_error.get_or_insert((
+                        n,
+                        "The x coordinates of a cubic bezier curve must be between 0 and 1",
+                    ));
                 }
+                val as f32
             };
-            let expr = Expression::EasingCurve(EasingCurve::CubicBezier(a(), a(), a(), a()));
+            let expr = Expression::EasingCurve(EasingCurve::CubicBezier(
+                a(true),
+                a(false),
+                a(true),
+                a(false),
+            ));
             if let Some((_, n)) = sub_expr.next() {
                 has_error.get_or_insert((n, "Too many argument for bezier curve"));
             }
@@ -63,11 +78,52 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
     }
 }
 
+/// `spring(stiffness, damping)` or `spring(stiffness, damping, initial-velocity)`
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 {
+        diag.push_error("Not enough arguments".into(), &node);
+        return Expression::Invalid;
+    }
+    if args.len() > 3 {
+        diag.push_error("Too many argument for spring".into(), &args[3].1);
+        return Expression::Invalid;
+    }
+    let mut parameters = [0.; 3];
+    for (parameter, (expr, n)) in parameters.iter_mut().zip(args) {
+        let value = match expr {
+            Expression::NumberLiteral(val, Unit::None) => val,
+            // handle negative numbers
+            Expression::UnaryOp { sub, op: '-' } => match *sub {
+                Expression::NumberLiteral(val, Unit::None) => -val,
+                _ => f64::NAN,
+            },
+            _ => f64::NAN,
+        };
+        if value.is_nan() {
+            diag.push_error("Arguments to spring must be number literal".into(), &n);
+            return Expression::Invalid;
+        }
+        *parameter = value as f32;
+    }
+    let [stiffness, damping, _] = parameters;
+    if stiffness <= 0. {
+        diag.push_error("The stiffness of a spring must be greater than 0".into(), &node);
+    } else if damping < 0. {
+        diag.push_error("The damping of a spring can't be negative".into(), &node);
+    }
+    Expression::EasingCurve(EasingCurve::Spring(parameters[0], parameters[1], parameters[2]))
+}
+
 fn min_max_macro(
     node: Option<NodeOrToken>,
     op: MinMaxOp,
@@ -345,7 +401,8 @@ fn to_debug_string(
         | Type::ElementReference
         | Type::LayoutCache
         | Type::Model
-        | Type::PathData => {
+        | Type::PathData
+        | Type::LengthArray => {
             diag.push_error("Cannot debug this expression".into(), &node);
             Expression::Invalid
         }
@@ -53,15 +53,21 @@ pub enum BuiltinFunction {
     ColorHsvaStruct,
     ColorBrighter,
     ColorDarker,
+    ColorSaturate,
+    ColorDesaturate,
     ColorTransparentize,
     ColorMix,
     ColorWithAlpha,
     ImageSize,
+    /// the `img.pattern(PatternTiling.repeat)`
+    ImagePattern,
     ArrayLength,
     Rgb,
     Hsv,
     ColorScheme,
     Use24HourFormat,
+    PrefersReducedMotion,
+    PrefersHighContrast,
     MonthDayCount,
     MonthOffset,
     FormatDate,
@@ -97,6 +103,8 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Abs,
     CubicBezier,
+    /// `spring(stiffness, damping, initial-velocity)` with an optional initial velocity
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
@@ -215,6 +223,14 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Brush),
                 args: vec![Type::Brush, Type::Float32],
             },
+            BuiltinFunction::ColorSaturate => Type::Function {
+                return_type: Box::new(Type::Brush),
+                args: vec![Type::Brush, Type::Float32],
+            },
+            BuiltinFunction::ColorDesaturate => Type::Function {
+                return_type: Box::new(Type::Brush),
+                args: vec![Type::Brush, Type::Float32],
+            },
             BuiltinFunction::ColorTransparentize => Type::Function {
                 return_type: Box::new(Type::Brush),
                 args: vec![Type::Brush, Type::Float32],
@@ -227,6 +243,15 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Brush),
                 args: vec![Type::Brush, Type::Float32],
             },
+            BuiltinFunction::ImagePattern => Type::Function {
+                return_type: Box::new(Type::Brush),
+                args: vec![
+                    Type::Image,
+                    Type::Enumeration(
+                        crate::typeregister::BUILTIN_ENUMS.with(|e| e.PatternTiling.clone()),
+                    ),
+                ],
+            },
             BuiltinFunction::ImageSize => Type::Function {
                 return_type: Box::new(Type::Struct {
                     fields: IntoIterator::into_iter([
@@ -310,7 +335,9 @@ pub fn ty(&self) -> Type {
                     Type::Array(Type::String.into()),
                 ],
             },
-            BuiltinFunction::Use24HourFormat => {
+            BuiltinFunction::Use24HourFormat
+            | BuiltinFunction::PrefersReducedMotion
+            | BuiltinFunction::PrefersHighContrast => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![] }
             }
             BuiltinFunction::UpdateTimers => {
@@ -358,6 +385,8 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
+            | BuiltinFunction::ColorSaturate
+            | BuiltinFunction::ColorDesaturate
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
             | BuiltinFunction::ColorWithAlpha => true,
@@ -369,6 +398,7 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ImageSize => true,
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
+            BuiltinFunction::ImagePattern => true,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
@@ -381,6 +411,7 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::RegisterBitmapFont => false,
             BuiltinFunction::Translate => false,
             BuiltinFunction::Use24HourFormat => false,
+            BuiltinFunction::PrefersReducedMotion | BuiltinFunction::PrefersHighContrast => false,
             BuiltinFunction::UpdateTimers => false,
         }
     }
@@ -424,10 +455,13 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
+            | BuiltinFunction::ColorSaturate
+            | BuiltinFunction::ColorDesaturate
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
+            BuiltinFunction::ImagePattern => true,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
@@ -440,6 +474,7 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::RegisterBitmapFont => false,
             BuiltinFunction::Translate => true,
             BuiltinFunction::Use24HourFormat => true,
+            BuiltinFunction::PrefersReducedMotion | BuiltinFunction::PrefersHighContrast => true,
             BuiltinFunction::UpdateTimers => false,
         }
     }
@@ -724,6 +759,21 @@ pub enum Expression {
     },
 
     RadialGradient {
+        /// The position of the center, relative to the size of the shape
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// The radius, relative to the default radius
+        radius: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+    },
+
+    ConicGradient {
+        /// The angle at which the gradient starts
+        from_angle: Box<Expression>,
+        /// The position of the center, relative to the size of the shape
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
     },
@@ -872,6 +922,7 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -963,7 +1014,19 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops } => {
+                visitor(center_x);
+                visitor(center_y);
+                visitor(radius);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -1068,7 +1131,19 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops } => {
+                visitor(center_x);
+                visitor(center_y);
+                visitor(radius);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -1129,6 +1204,10 @@ pub fn is_constant(&self) -> bool {
                 function.is_constant() && arguments.iter().all(|a| a.is_constant())
             }
             Expression::SelfAssignment { .. } => false,
+            // Images from a URL are empty until they are loaded
+            Expression::ImageReference {
+                resource_ref: ImageReference::AbsolutePath(path), ..
+            } => !crate::pathutils::is_remote_image_url(path),
             Expression::ImageReference { .. } => true,
             Expression::Condition { condition, false_expr, true_expr } => {
                 condition.is_constant() && false_expr.is_constant() && true_expr.is_constant()
@@ -1154,8 +1233,17 @@ pub fn is_constant(&self) -> bool {
             Expression::LinearGradient { angle, stops } => {
                 angle.is_constant() && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
-            Expression::RadialGradient { stops } => {
-                stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            Expression::RadialGradient { center_x, center_y, radius, stops } => {
+                center_x.is_constant()
+                    && center_y.is_constant()
+                    && radius.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                from_angle.is_constant()
+                    && center_x.is_constant()
+                    && center_y.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
@@ -1285,6 +1373,19 @@ pub fn maybe_convert_to(
                 },
             };
             Expression::Cast { from: Box::new(from), to: target_type }
+        } else if matches!(
+            (&ty, &target_type, &self),
+            (Type::Array(_), Type::LengthArray, Expression::Array { .. })
+        ) {
+            // Array literals with elements of other units are converted to lengths first
+            Expression::Cast {
+                from: Box::new(self.maybe_convert_to(
+                    Type::Array(Box::new(Type::LogicalLength)),
+                    node,
+                    diag,
+                )),
+                to: target_type,
+            }
         } else if matches!(
             (&ty, &target_type, &self),
             (Type::Array(_), Type::Array(_), Expression::Array { .. })
@@ -1379,6 +1480,13 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
             Type::Bool => Expression::BoolLiteral(false),
             Type::Model => Expression::Invalid,
             Type::PathData => Expression::PathData(Path::Elements(vec![])),
+            Type::LengthArray => Expression::Cast {
+                from: Box::new(Expression::Array {
+                    element_ty: Type::LogicalLength,
+                    values: vec![],
+                }),
+                to: ty.clone(),
+            },
             Type::Array(element_ty) => {
                 Expression::Array { element_ty: (**element_ty).clone(), values: vec![] }
             }
@@ -1624,6 +1732,8 @@ pub enum EasingCurve {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// stiffness, damping and initial velocity
+    Spring(f32, f32, f32),
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
@@ -1761,8 +1871,28 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
-        Expression::RadialGradient { stops } => {
-            write!(f, "@radial-gradient(circle")?;
+        Expression::RadialGradient { center_x, center_y, radius, stops } => {
+            write!(f, "@radial-gradient(circle ")?;
+            pretty_print(f, radius)?;
+            write!(f, " at ")?;
+            pretty_print(f, center_x)?;
+            write!(f, " ")?;
+            pretty_print(f, center_y)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            write!(f, "@conic-gradient(from ")?;
+            pretty_print(f, from_angle)?;
+            write!(f, " at ")?;
+            pretty_print(f, center_x)?;
+            write!(f, " ")?;
+            pretty_print(f, center_y)?;
             for (c, s) in stops {
                 write!(f, ", ")?;
                 pretty_print(f, c)?;
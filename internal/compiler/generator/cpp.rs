@@ -493,7 +493,7 @@ fn cpp_type(&self) -> Option<String> {
                 }
             }
             Type::Brush => Some("slint::Brush".to_owned()),
-            Type::LayoutCache => Some("slint::SharedVector<float>".into()),
+            Type::LayoutCache | Type::LengthArray => Some("slint::SharedVector<float>".into()),
             Type::Easing => Some("slint::cbindgen_private::EasingCurve".into()),
             _ => None,
         }
@@ -602,13 +602,30 @@ fn handle_property_init(
             if binding_expression.is_state_info {
                 format!("slint::private_api::set_state_binding({}, {});", prop_access, binding_code)
             } else {
+                let animation_callback = |code: &Option<llr::Expression>| match code {
+                    Some(code) => format!(
+                        "[this]() {{
+                            [[maybe_unused]] auto self = this;
+                            {code};
+                        }}",
+                        code = compile_expression(code, ctx)
+                    ),
+                    None => "nullptr".into(),
+                };
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, callbacks)) => {
                         let anim = compile_expression(anim, ctx);
-                        format!("{}.set_animated_binding({}, {});", prop_access, binding_code, anim)
+                        format!(
+                            "{}.set_animated_binding({}, {}, {}, {});",
+                            prop_access,
+                            binding_code,
+                            anim,
+                            animation_callback(&callbacks.started),
+                            animation_callback(&callbacks.finished),
+                        )
                     }
                     Some(llr::Animation::Transition (
-                        anim
+                        anim, callbacks
                     )) => {
                         let anim = compile_expression(anim, ctx);
                         format!(
@@ -618,10 +635,12 @@ fn handle_property_init(
                                 auto [anim, time] = {};
                                 *start_time = time;
                                 return anim;
-                            }});",
+                            }}, {}, {});",
                             prop_access,
                             binding_code,
                             anim,
+                            animation_callback(&callbacks.started),
+                            animation_callback(&callbacks.finished),
                         )
                     }
                     None => format!("{}.set_binding({});", prop_access, binding_code),
@@ -899,6 +918,8 @@ fn embed_resource(
                 units_per_em,
                 ascent,
                 descent,
+                x_height,
+                cap_height,
                 glyphs,
                 weight,
                 italic,
@@ -998,6 +1019,8 @@ fn embed_resource(
                         .units_per_em = {units_per_em},
                         .ascent = {ascent},
                         .descent = {descent},
+                        .x_height = {x_height},
+                        .cap_height = {cap_height},
                         .glyphs = slint::cbindgen_private::Slice<slint::cbindgen_private::BitmapGlyphs>{{ {glyphsets_var}, {glyphsets_size} }},
                         .weight = {weight},
                         .italic = {italic},
@@ -2940,6 +2963,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                     format!("std::make_shared<slint::private_api::UIntModel>(std::max<int>(0, {}))", f)
                 }
                 (Type::Array(_), Type::Model) => f,
+                (Type::Array(_), Type::LengthArray) => {
+                    format!("slint::private_api::model_to_shared_vector({f})")
+                }
                 (Type::Float32, Type::Color) => {
                     format!("slint::Color::from_argb_encoded({})", f)
                 }
@@ -3186,6 +3212,10 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
         Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, velocity)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Spring, {}, {}, {})",
+            stiffness, damping, velocity
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -3198,15 +3228,32 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 stops_it.join(", "), angle, stops.len()
             )
         }
-        Expression::RadialGradient{ stops} => {
+        Expression::RadialGradient{ center_x, center_y, radius, stops} => {
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let radius = compile_expression(radius, ctx);
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush({}, {}, {}, stops, {})); }}()",
+                stops_it.join(", "), center_x, center_y, radius, stops.len()
+            )
+        }
+        Expression::ConicGradient{ from_angle, center_x, center_y, stops} => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
             });
             format!(
-                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush(stops, {})); }}()",
-                stops_it.join(", "), stops.len()
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, {}, {}, stops, {})); }}()",
+                stops_it.join(", "), from_angle, center_x, center_y, stops.len()
             )
         }
         Expression::EnumerationValue(value) => {
@@ -3403,6 +3450,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorDarker => {
             format!("{}.darker({})", a.next().unwrap(), a.next().unwrap())
         }
+        BuiltinFunction::ColorSaturate => {
+            format!("{}.saturate({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ColorDesaturate => {
+            format!("{}.desaturate({})", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::ColorTransparentize => {
             format!("{}.transparentize({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -3415,6 +3468,13 @@ fn compile_builtin_function_call(
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
+        BuiltinFunction::ImagePattern => {
+            format!(
+                "slint::Brush(slint::private_api::ImagePatternBrush({}, {}))",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
@@ -3440,6 +3500,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::Use24HourFormat => {
             format!("slint::cbindgen_private::slint_date_time_use_24_hour_format()")
         }
+        BuiltinFunction::PrefersReducedMotion => {
+            "slint::cbindgen_private::slint_prefers_reduced_motion()".into()
+        }
+        BuiltinFunction::PrefersHighContrast => {
+            "slint::cbindgen_private::slint_prefers_high_contrast()".into()
+        }
         BuiltinFunction::MonthDayCount => {
             format!("slint::cbindgen_private::slint_date_time_month_day_count({}, {})", a.next().unwrap(), a.next().unwrap())
         }
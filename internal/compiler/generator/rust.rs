@@ -110,7 +110,7 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
             }
         }
         Type::Brush => Some(quote!(slint::Brush)),
-        Type::LayoutCache => Some(quote!(
+        Type::LayoutCache | Type::LengthArray => Some(quote!(
             sp::SharedVector<
                 sp::Coord,
             >
@@ -249,6 +249,11 @@ fn generate_public_component(
         &llr.public_properties,
         &llr.private_properties,
         quote!(sp::VRc::as_pin_ref(&self.0)),
+        ChangeHandlerSelf {
+            data: quote!(sp::VRc::downgrade(&self.0)),
+            upgrade: quote!(_self_weak.upgrade()),
+            init: quote!(sp::VRc::as_pin_ref(&_self_rc)),
+        },
         &ctx,
     );
 
@@ -500,22 +505,36 @@ fn handle_property_init(
                     slint::private_unstable_api::set_property_state_binding(#rust_property, &self_rc, #binding_tokens);
                 } }
             } else {
+                let animation_callback = |code: &Option<llr::Expression>| match code {
+                    Some(code) => {
+                        let code = compile_expression(code, ctx);
+                        quote!(Some(move |self_rc| {
+                            #init_self_pin_ref
+                            #code;
+                        }))
+                    }
+                    None => quote!(None),
+                };
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, callbacks)) => {
                         let anim = compile_expression(anim, ctx);
+                        let started = animation_callback(&callbacks.started);
+                        let finished = animation_callback(&callbacks.finished);
                         quote! { {
                             #init_self_pin_ref
-                            slint::private_unstable_api::set_animated_property_binding(#rust_property, &self_rc, #binding_tokens, #anim);
+                            slint::private_unstable_api::set_animated_property_binding(#rust_property, &self_rc, #binding_tokens, #anim, #started, #finished);
                         } }
                     }
-                    Some(llr::Animation::Transition(anim)) => {
+                    Some(llr::Animation::Transition(anim, callbacks)) => {
                         let anim = compile_expression(anim, ctx);
+                        let started = animation_callback(&callbacks.started);
+                        let finished = animation_callback(&callbacks.finished);
                         quote! {
                             slint::private_unstable_api::set_animated_property_binding_for_transition(
                                 #rust_property, &self_rc, #binding_tokens, move |self_rc| {
                                     #init_self_pin_ref
                                     #anim
-                                }
+                                }, #started, #finished
                             );
                         }
                     }
@@ -530,13 +549,32 @@ fn handle_property_init(
     }
 }
 
+/// How the change handlers of the public API access the component, without borrowing `self`
+struct ChangeHandlerSelf {
+    /// Expression that creates, from `self`, the data kept by the change handler
+    data: TokenStream,
+    /// Expression that gets an `Option` of the strong reference `_self_rc` from the data in `_self_weak`
+    upgrade: TokenStream,
+    /// Expression that gets `_self` from `_self_rc`
+    init: TokenStream,
+}
+
 /// Public API for Global and root component
 fn public_api(
     public_properties: &llr::PublicProperties,
     private_properties: &llr::PrivateProperties,
     self_init: TokenStream,
+    change_handler_self: ChangeHandlerSelf,
     ctx: &EvaluationContext,
 ) -> TokenStream {
+    let callback_names = public_properties
+        .iter()
+        .filter(|p| matches!(p.ty, Type::Callback { .. }))
+        .map(|p| ident(&p.name).to_string())
+        .collect::<BTreeSet<_>>();
+    let ChangeHandlerSelf { data: self_data, upgrade: self_upgrade, init: self_rc_init } =
+        change_handler_self;
+
     let mut property_and_callback_accessors: Vec<TokenStream> = vec![];
     for p in public_properties {
         let prop_ident = ident(&p.name);
@@ -609,11 +647,50 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
                         #set_value
                     }
                 ));
+                if is_animatable(&p.ty) {
+                    let animated_setter_ident = format_ident!("set_{}_with_animation", prop_ident);
+                    let value_tokens = set_primitive_property_value(&p.ty, quote!(value));
+                    property_and_callback_accessors.push(quote!(
+                        #[allow(dead_code)]
+                        pub fn #animated_setter_ident(&self, value: #rust_property_type, animation: slint::Animation) {
+                            #[allow(unused_imports)]
+                            let _self = #self_init;
+                            #prop.set_animated_value(#value_tokens as _, animation.into())
+                        }
+                    ));
+                }
             } else {
                 property_and_callback_accessors.push(quote!(
                     #[allow(dead_code)] fn #setter_ident(&self, _read_only_property : ()) { }
                 ));
             }
+
+            // Don't conflict with the `on_` function of a callback called `<name>-changed`
+            if !callback_names.contains(&format!("{prop_ident}_changed")) {
+                let on_changed_ident = format_ident!("on_{}_changed", prop_ident);
+                let prop_expression = primitive_property_value(
+                    &p.ty,
+                    MemberAccess::Direct(access_member(&p.prop, ctx).unwrap()),
+                );
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #on_changed_ident(&self, f: impl Fn(#rust_property_type) + 'static) -> slint::PropertyChangeSubscription {
+                        slint::PropertyChangeSubscription::new(
+                            #self_data,
+                            |_self_weak| (#self_upgrade).map(|_self_rc| {
+                                #[allow(unused_imports)]
+                                let _self = #self_rc_init;
+                                #prop_expression
+                            }),
+                            move |_, value| {
+                                if let ::core::option::Option::Some(value) = value {
+                                    f(::core::clone::Clone::clone(value))
+                                }
+                            },
+                        )
+                    }
+                ));
+            }
         }
     }
 
@@ -1362,6 +1439,11 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
             &global.public_properties,
             &global.private_properties,
             quote!(self.0.as_ref()),
+            ChangeHandlerSelf {
+                data: quote!(::core::clone::Clone::clone(self.0)),
+                upgrade: quote!(::core::option::Option::Some(_self_weak)),
+                init: quote!(_self_rc.as_ref()),
+            },
             &ctx,
         );
         let public_component_id = ident(&global.name);
@@ -1801,6 +1883,20 @@ fn global_inner_name(g: &llr::GlobalComponent) -> TokenStream {
     }
 }
 
+/// Returns true if properties of that type can be animated, like in `TypeRegister::property_animation_type_for_property`
+fn is_animatable(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Float32
+            | Type::Int32
+            | Type::Color
+            | Type::PhysicalLength
+            | Type::LogicalLength
+            | Type::Brush
+            | Type::Angle
+    )
+}
+
 fn property_set_value_tokens(
     property: &llr::PropertyReference,
     value_tokens: TokenStream,
@@ -2174,6 +2270,12 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::String, Type::PathData) => {
                     quote!(sp::PathData::Commands(#f))
                 }
+                (Type::Array(..), Type::LengthArray) => {
+                    quote!(match &#f { x => {
+                        x.model_tracker().track_row_count_changes();
+                        (0..x.row_count()).filter_map(|i| x.row_data_tracked(i)).collect::<sp::SharedVector<sp::Coord>>()
+                    }})
+                }
                 _ => f,
             }
         }
@@ -2400,7 +2502,11 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 let elem = fields.keys().map(|k| values.get(k).map(|e| compile_expression(e, ctx)));
                 if let Some(name) = name {
                     let name_tokens: TokenStream = struct_name_to_tokens(name.as_str());
-                    let keys = fields.keys().map(|k| ident(k));
+                    // The fields without a value keep their default value
+                    let (keys, elem): (Vec<_>, Vec<_>) = fields
+                        .keys()
+                        .filter_map(|k| Some((ident(k), compile_expression(values.get(k)?, ctx))))
+                        .unzip();
                     if name.starts_with("slint::private_api::") && name.ends_with("LayoutData") {
                         quote!(#name_tokens{#(#keys: #elem as _,)*})
                     } else {
@@ -2458,6 +2564,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, velocity)) => {
+            quote!(sp::EasingCurve::Spring([#stiffness, #damping, #velocity]))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2469,14 +2578,38 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 sp::LinearGradientBrush::new(#angle as _, [#(#stops),*])
             ))
         }
-        Expression::RadialGradient { stops } => {
+        Expression::RadialGradient { center_x, center_y, radius, stops } => {
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let radius = compile_expression(radius, ctx);
             let stops = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 quote!(sp::GradientStop{ color: #color, position: #position as _ })
             });
             quote!(slint::Brush::RadialGradient(
-                sp::RadialGradientBrush::new_circle([#(#stops),*])
+                sp::RadialGradientBrush::new_circle_at(
+                    sp::euclid::point2(#center_x as f32, #center_y as f32),
+                    #radius as _,
+                    [#(#stops),*]
+                )
+            ))
+        }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new_at(
+                    #from_angle as _,
+                    sp::euclid::point2(#center_x as f32, #center_y as f32),
+                    [#(#stops),*]
+                )
             ))
         }
         Expression::EnumerationValue(value) => {
@@ -2753,6 +2886,16 @@ fn compile_builtin_function_call(
             let factor = a.next().unwrap();
             quote!(#x.darker(#factor as f32))
         }
+        BuiltinFunction::ColorSaturate => {
+            let x = a.next().unwrap();
+            let factor = a.next().unwrap();
+            quote!(#x.saturate(#factor as f32))
+        }
+        BuiltinFunction::ColorDesaturate => {
+            let x = a.next().unwrap();
+            let factor = a.next().unwrap();
+            quote!(#x.desaturate(#factor as f32))
+        }
         BuiltinFunction::ColorTransparentize => {
             let x = a.next().unwrap();
             let factor = a.next().unwrap();
@@ -2770,6 +2913,10 @@ fn compile_builtin_function_call(
             quote!(#x.with_alpha(#alpha as f32))
         }
         BuiltinFunction::ImageSize => quote!( #(#a)*.size()),
+        BuiltinFunction::ImagePattern => {
+            let (image, tiling) = (a.next().unwrap(), a.next().unwrap());
+            quote!(slint::Brush::ImagePattern(sp::ImagePatternBrush::new(#image, #tiling)))
+        }
         BuiltinFunction::ArrayLength => {
             quote!(match &#(#a)* { x => {
                 x.model_tracker().track_row_count_changes();
@@ -2841,6 +2988,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::Use24HourFormat => {
             quote!(slint::private_unstable_api::use_24_hour_format())
         }
+        BuiltinFunction::PrefersReducedMotion => {
+            quote!(slint::private_unstable_api::prefers_reduced_motion())
+        }
+        BuiltinFunction::PrefersHighContrast => {
+            quote!(slint::private_unstable_api::prefers_high_contrast())
+        }
         BuiltinFunction::ItemAbsolutePosition => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item_rc = access_item_rc(pr, ctx);
@@ -2998,7 +3151,7 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                     )
                 },
                 #[cfg(feature = "software-renderer")]
-                crate::embedded_resources::EmbeddedResourcesKind::BitmapFontData(crate::embedded_resources::BitmapFont { family_name, character_map, units_per_em, ascent, descent, glyphs, weight, italic }) => {
+                crate::embedded_resources::EmbeddedResourcesKind::BitmapFontData(crate::embedded_resources::BitmapFont { family_name, character_map, units_per_em, ascent, descent, x_height, cap_height, glyphs, weight, italic }) => {
 
                     let character_map_size = character_map.len();
 
@@ -3050,6 +3203,8 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                             units_per_em: #units_per_em,
                             ascent: #ascent,
                             descent: #descent,
+                            x_height: #x_height,
+                            cap_height: #cap_height,
                             glyphs: sp::Slice::from_slice({
                                 #link_section
                                 static GLYPHS : [sp::BitmapGlyphs; #glyphs_size] = [#(#glyphs),*];
@@ -52,6 +52,9 @@ pub enum Type {
     /// Fake type that can represent anything that can be converted into a model.
     Model,
     PathData, // Either a vector of path elements or a two vectors of events and coordinates
+    /// A list of lengths of a builtin element, such as the dashes and gaps of a stroke.
+    /// This is a `SharedVector<Coord>`. It can be assigned from a `[length]` array.
+    LengthArray,
     Easing,
     Brush,
     /// This is usually a model
@@ -107,6 +110,7 @@ fn eq(&self, other: &Self) -> bool {
             Type::Bool => matches!(other, Type::Bool),
             Type::Model => matches!(other, Type::Model),
             Type::PathData => matches!(other, Type::PathData),
+            Type::LengthArray => matches!(other, Type::LengthArray),
             Type::Easing => matches!(other, Type::Easing),
             Type::Brush => matches!(other, Type::Brush),
             Type::Array(a) => matches!(other, Type::Array(b) if a == b),
@@ -180,6 +184,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             }
 
             Type::PathData => write!(f, "pathdata"),
+            Type::LengthArray => write!(f, "[length]"),
             Type::Easing => write!(f, "easing"),
             Type::Brush => write!(f, "brush"),
             Type::Enumeration(enumeration) => write!(f, "enum {}", enumeration.name),
@@ -229,6 +234,7 @@ pub fn is_property_type(&self) -> bool {
                 | Self::Struct { .. }
                 | Self::Array(_)
                 | Self::Brush
+                | Self::LengthArray
                 | Self::InferredProperty
         )
     }
@@ -285,6 +291,7 @@ pub fn can_convert(&self, other: &Self) -> bool {
             | (Type::Percent, Type::Float32)
             | (Type::Brush, Type::Color)
             | (Type::Color, Type::Brush) => true,
+            (Type::Array(a), Type::LengthArray) => **a == Type::LogicalLength,
             (Type::Struct { fields: a, .. }, Type::Struct { fields: b, .. }) => {
                 can_convert_struct(a, b)
             }
@@ -325,6 +332,7 @@ pub fn default_unit(&self) -> Option<Unit> {
             Type::Bool => None,
             Type::Model => None,
             Type::PathData => None,
+            Type::LengthArray => None,
             Type::Easing => None,
             Type::Brush => None,
             Type::Array(_) => None,
@@ -528,6 +528,8 @@ pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) ->
                         | "Opacity"
                         | "Layer"
                         | "BoxShadow"
+                        | "DropShadow"
+                        | "Blur"
                         | "Clip"
                 ) =>
             {
@@ -147,6 +147,17 @@ pub enum Expression {
     },
 
     RadialGradient {
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        radius: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+    },
+
+    ConicGradient {
+        from_angle: Box<Expression>,
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
     },
@@ -221,7 +232,7 @@ pub fn default_value_for_type(ty: &Type) -> Option<Self> {
             },
             Type::Bool => Expression::BoolLiteral(false),
             Type::Model => return None,
-            Type::PathData => return None,
+            Type::PathData | Type::LengthArray => return None,
             Type::Array(element_ty) => Expression::Array {
                 element_ty: (**element_ty).clone(),
                 values: vec![],
@@ -296,6 +307,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -361,7 +373,19 @@ macro_rules! visit_impl {
                     $visitor(b);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops } => {
+                $visitor(center_x);
+                $visitor(center_y);
+                $visitor(radius);
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                $visitor(from_angle);
+                $visitor(center_x);
+                $visitor(center_y);
                 for (a, b) in stops {
                     $visitor(a);
                     $visitor(b);
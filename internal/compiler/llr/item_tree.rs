@@ -29,8 +29,21 @@ pub fn ty(&self, ctx: &dyn super::TypeResolutionContext) -> Type {
 #[derive(Debug, Clone)]
 pub enum Animation {
     /// The expression is a Struct with the animation fields
-    Static(Expression),
-    Transition(Expression),
+    Static(Expression, AnimationCallbacks),
+    Transition(Expression, AnimationCallbacks),
+}
+
+/// The code of the `started` and `finished` callback handlers of an animation
+#[derive(Debug, Clone, Default)]
+pub struct AnimationCallbacks {
+    pub started: Option<Expression>,
+    pub finished: Option<Expression>,
+}
+
+impl AnimationCallbacks {
+    pub fn iter(&self) -> impl Iterator<Item = &Expression> {
+        self.started.iter().chain(self.finished.iter())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -9,7 +9,7 @@
 use itertools::Either;
 
 use super::lower_to_item_tree::{LoweredElement, LoweredSubComponentMapping, LoweringState};
-use super::{Animation, PropertyReference};
+use super::{Animation, AnimationCallbacks, PropertyReference};
 use crate::expression_tree::{BuiltinFunction, Expression as tree_Expression};
 use crate::langtype::{EnumerationValue, Type};
 use crate::layout::Orientation;
@@ -188,12 +188,28 @@ pub fn lower_expression(
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
                 .collect::<_>(),
         },
-        tree_Expression::RadialGradient { stops } => llr_Expression::RadialGradient {
-            stops: stops
-                .iter()
-                .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
-                .collect::<_>(),
-        },
+        tree_Expression::RadialGradient { center_x, center_y, radius, stops } => {
+            llr_Expression::RadialGradient {
+                center_x: Box::new(lower_expression(center_x, ctx)),
+                center_y: Box::new(lower_expression(center_y, ctx)),
+                radius: Box::new(lower_expression(radius, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+            }
+        }
+        tree_Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            llr_Expression::ConicGradient {
+                from_angle: Box::new(lower_expression(from_angle, ctx)),
+                center_x: Box::new(lower_expression(center_x, ctx)),
+                center_y: Box::new(lower_expression(center_y, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+            }
+        }
         tree_Expression::EnumerationValue(e) => llr_Expression::EnumerationValue(e.clone()),
         tree_Expression::ReturnStatement(..) => {
             panic!("The remove return pass should have removed all return")
@@ -391,24 +407,40 @@ pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> An
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
             values: animation_fields()
-                .map(|(k, ty)| {
+                .filter_map(|(k, ty)| {
+                    // Fields without a default value expression, such as the keyframes, are left out
                     let e = a.borrow().bindings.get(&k).map_or_else(
-                        || llr_Expression::default_value_for_type(&ty).unwrap(),
-                        |v| lower_expression(&v.borrow().expression, ctx),
-                    );
-                    (k, e)
+                        || llr_Expression::default_value_for_type(&ty),
+                        |v| Some(lower_expression(&v.borrow().expression, ctx)),
+                    )?;
+                    Some((k, e))
                 })
                 .collect::<_>(),
             ty: animation_ty(),
         }
     }
 
+    fn lower_animation_callback(
+        a: &ElementRc,
+        name: &str,
+        ctx: &ExpressionContext<'_>,
+    ) -> Option<llr_Expression> {
+        a.borrow().bindings.get(name).map(|b| lower_expression(&b.borrow().expression, ctx))
+    }
+
     fn animation_fields() -> impl Iterator<Item = (String, Type)> {
         IntoIterator::into_iter([
             ("duration".to_string(), Type::Int32),
             ("iteration-count".to_string(), Type::Float32),
             ("easing".to_string(), Type::Easing),
             ("delay".to_string(), Type::Int32),
+            (
+                "color-interpolation".to_string(),
+                Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.ColorInterpolation.clone()),
+                ),
+            ),
+            ("keyframes".to_string(), Type::LengthArray),
         ])
     }
 
@@ -422,14 +454,22 @@ fn animation_ty() -> Type {
     }
 
     match a {
-        PropertyAnimation::Static(a) => Animation::Static(lower_animation_element(a, ctx)),
+        PropertyAnimation::Static(a) => Animation::Static(
+            lower_animation_element(a, ctx),
+            AnimationCallbacks {
+                started: lower_animation_callback(a, "started", ctx),
+                finished: lower_animation_callback(a, "finished", ctx),
+            },
+        ),
         PropertyAnimation::Transition { state_ref, animations } => {
             let set_state = llr_Expression::StoreLocalVariable {
                 name: "state".into(),
                 value: Box::new(lower_expression(state_ref, ctx)),
             };
             let animation_ty = animation_ty();
-            let mut get_anim = llr_Expression::default_value_for_type(&animation_ty).unwrap();
+            // All the fields of the animation keep their default value
+            let mut get_anim =
+                llr_Expression::Struct { ty: animation_ty.clone(), values: Default::default() };
             for tr in animations.iter().rev() {
                 let condition = lower_expression(
                     &tr.condition(tree_Expression::ReadLocalVariable {
@@ -473,7 +513,39 @@ fn animation_ty() -> Type {
                 ])
                 .collect(),
             };
-            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]))
+            // The handlers of the transition that is used for the current state
+            let transition_callback = |name: &str| {
+                let handlers = animations
+                    .iter()
+                    .map(|tr| lower_animation_callback(&tr.animation, name, ctx))
+                    .collect::<Vec<_>>();
+                if handlers.iter().all(Option::is_none) {
+                    return None;
+                }
+                let mut code = llr_Expression::CodeBlock(vec![]);
+                for (tr, handler) in animations.iter().zip(handlers).rev() {
+                    let condition = lower_expression(
+                        &tr.condition(tree_Expression::ReadLocalVariable {
+                            name: "state".into(),
+                            ty: state_ref.ty(),
+                        }),
+                        ctx,
+                    );
+                    code = llr_Expression::Condition {
+                        condition: Box::new(condition),
+                        true_expr: Box::new(
+                            handler.unwrap_or_else(|| llr_Expression::CodeBlock(vec![])),
+                        ),
+                        false_expr: Box::new(code),
+                    }
+                }
+                Some(llr_Expression::CodeBlock(vec![set_state.clone(), code]))
+            };
+            let callbacks = AnimationCallbacks {
+                started: transition_callback("started"),
+                finished: transition_callback("finished"),
+            };
+            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]), callbacks)
         }
     }
 }
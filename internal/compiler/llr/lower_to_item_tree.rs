@@ -415,10 +415,10 @@ fn lower_sub_component(
         {
             if let Some(anim) = binding.animation.as_ref() {
                 match super::lower_expression::lower_animation(anim, &ctx) {
-                    Animation::Static(anim) => {
+                    Animation::Static(anim, _) => {
                         sub_component.animations.insert(prop, anim);
                     }
-                    Animation::Transition(_) => {
+                    Animation::Transition(..) => {
                         // Cannot set a property with a transition anyway
                     }
                 }
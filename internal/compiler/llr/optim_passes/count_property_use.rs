@@ -165,8 +165,11 @@ fn visit_property(pr: &PropertyReference, ctx: &EvaluationContext) {
 fn visit_binding_expression(binding: &BindingExpression, ctx: &EvaluationContext) {
     binding.expression.borrow().visit_property_references(ctx, &mut visit_property);
     match &binding.animation {
-        Some(Animation::Static(e) | Animation::Transition(e)) => {
-            e.visit_property_references(ctx, &mut visit_property)
+        Some(Animation::Static(e, callbacks) | Animation::Transition(e, callbacks)) => {
+            e.visit_property_references(ctx, &mut visit_property);
+            for e in callbacks.iter() {
+                e.visit_property_references(ctx, &mut visit_property);
+            }
         }
         None => (),
     }
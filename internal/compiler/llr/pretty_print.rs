@@ -315,9 +315,20 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 e(angle),
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
-            Expression::RadialGradient { stops } => write!(
+            Expression::RadialGradient { center_x, center_y, radius, stops } => write!(
                 f,
-                "@radial-gradient(circle, {})",
+                "@radial-gradient(circle {} at {} {}, {})",
+                e(radius),
+                e(center_x),
+                e(center_y),
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
+            ),
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => write!(
+                f,
+                "@conic-gradient(from {} at {} {}, {})",
+                e(from_angle),
+                e(center_x),
+                e(center_y),
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
             Expression::EnumerationValue(x) => write!(f, "{}", x),
@@ -102,6 +102,7 @@ pub enum BuiltinNamespace {
     Colors,
     Math,
     Key,
+    SystemSettings,
     SlintInternal,
 }
 
@@ -165,6 +166,9 @@ fn for_each_entry<R>(
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::SystemSettings) => {
+                SystemSettings.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
             }
@@ -180,6 +184,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::SystemSettings) => {
+                SystemSettings.lookup(ctx, name)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
             }
@@ -730,6 +737,16 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f("ease-in-elastic", Expression::EasingCurve(EasingCurve::EaseInElastic).into())
             })
@@ -808,6 +825,30 @@ fn for_each_entry<R>(
     }
 }
 
+struct SystemSettings;
+impl LookupObject for SystemSettings {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, b| {
+            f(
+                n,
+                Expression::FunctionCall {
+                    function: Expression::BuiltinFunctionReference(b, None).into(),
+                    arguments: vec![],
+                    source_location: sl(),
+                }
+                .into(),
+            )
+        };
+        None.or_else(|| f("reduced-motion", BuiltinFunction::PrefersReducedMotion))
+            .or_else(|| f("high-contrast", BuiltinFunction::PrefersHighContrast))
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -915,6 +956,9 @@ fn for_each_entry<R>(
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
+            .or_else(|| {
+                f("SystemSettings", LookupResult::Namespace(BuiltinNamespace::SystemSettings))
+            })
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
                     f("SlintInternal", LookupResult::Namespace(BuiltinNamespace::SlintInternal))
@@ -1077,6 +1121,8 @@ fn for_each_entry<R>(
             .or_else(|| f("to-hsv", member_function(BuiltinFunction::ColorHsvaStruct)))
             .or_else(|| f("brighter", member_function(BuiltinFunction::ColorBrighter)))
             .or_else(|| f("darker", member_function(BuiltinFunction::ColorDarker)))
+            .or_else(|| f("saturate", member_function(BuiltinFunction::ColorSaturate)))
+            .or_else(|| f("desaturate", member_function(BuiltinFunction::ColorDesaturate)))
             .or_else(|| f("transparentize", member_function(BuiltinFunction::ColorTransparentize)))
             .or_else(|| f("with-alpha", member_function(BuiltinFunction::ColorWithAlpha)))
             .or_else(|| f("mix", member_function(BuiltinFunction::ColorMix)))
@@ -1103,8 +1149,19 @@ fn for_each_entry<R>(
                 name: f.into(),
             })
         };
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+            })
+        };
         None.or_else(|| f("width", field_access("width")))
             .or_else(|| f("height", field_access("height")))
+            .or_else(|| f("pattern", member_function(BuiltinFunction::ImagePattern)))
     }
 }
 
@@ -512,6 +512,8 @@ pub struct TransitionPropertyAnimation {
     pub state_id: i32,
     /// false for 'to', true for 'out'
     pub is_out: bool,
+    /// The id of the state on the other side of the transition, for `in from xxx` and `out to xxx`
+    pub other_state_id: Option<i32>,
     /// The content of the `animation` object
     pub animation: ElementRc,
 }
@@ -520,15 +522,39 @@ impl TransitionPropertyAnimation {
     /// Return an expression which returns a boolean which is true if the transition is active.
     /// The state argument is an expression referencing the state property of type StateInfo
     pub fn condition(&self, state: Expression) -> Expression {
-        Expression::BinaryExpression {
+        let (field, other_field) = if self.is_out {
+            ("previous-state", "current-state")
+        } else {
+            ("current-state", "previous-state")
+        };
+        let state_is = |field: &str, id: i32| Expression::BinaryExpression {
             lhs: Box::new(Expression::StructFieldAccess {
-                base: Box::new(state),
-                name: (if self.is_out { "previous-state" } else { "current-state" }).into(),
+                base: Box::new(state.clone()),
+                name: field.into(),
             }),
-            rhs: Box::new(Expression::NumberLiteral(self.state_id as _, Unit::None)),
+            rhs: Box::new(Expression::NumberLiteral(id as _, Unit::None)),
             op: '=',
+        };
+        let condition = state_is(field, self.state_id);
+        match self.other_state_id {
+            Some(other_state_id) => Expression::BinaryExpression {
+                lhs: Box::new(condition),
+                rhs: Box::new(state_is(other_field, other_state_id)),
+                op: '&',
+            },
+            None => condition,
         }
     }
+
+    /// Return true if the transition is active for the given current and previous state ids
+    pub fn is_active(&self, current_state: i32, previous_state: i32) -> bool {
+        let (state, other_state) = if self.is_out {
+            (previous_state, current_state)
+        } else {
+            (current_state, previous_state)
+        };
+        state == self.state_id && self.other_state_id.map_or(true, |id| id == other_state)
+    }
 }
 
 #[derive(Debug)]
@@ -565,6 +591,7 @@ fn deep_clone(e: &ElementRc) -> ElementRc {
                         .map(|t| TransitionPropertyAnimation {
                             state_id: t.state_id,
                             is_out: t.is_out,
+                            other_state_id: t.other_state_id,
                             animation: deep_clone(&t.animation),
                         })
                         .collect(),
@@ -997,6 +1024,10 @@ pub fn from_node(
                     continue;
                 }
                 Type::Invalid => {} // Ok to proceed with a new declaration
+                // A component can give its own meaning to these, then they are not applied to it
+                _ if crate::typeregister::SHADOWABLE_RESERVED_PROPERTIES
+                    .contains(&prop_name.as_ref())
+                    && r.is_reserved_property(&prop_name) => {}
                 _ => {
                     diag.push_error(
                         format!("Cannot override property '{}'", prop_name),
@@ -1305,10 +1336,18 @@ pub fn from_node(
                         };
                         let lookup_result = r.lookup_property(unresolved_prop_name);
                         let valid_assign = lookup_result.is_valid_for_assignment();
+                        // The commands of a Path are animated as the path data they are compiled to
+                        let animated_type = if lookup_result.resolved_name == "commands"
+                            && r.builtin_type().is_some_and(|b| b.name == "Path")
+                        {
+                            Type::PathData
+                        } else {
+                            lookup_result.property_type
+                        };
                         if let Some(anim_element) = animation_element_from_node(
                             &anim,
                             &prop_name_token,
-                            lookup_result.property_type,
+                            animated_type,
                             diag,
                             tr,
                         ) {
@@ -1651,6 +1690,23 @@ pub fn lookup_property<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
         )
     }
 
+    /// Returns true if `name` resolves to a reserved property for this element, as opposed to a
+    /// property declared by this element, one of its base components, or its builtin element.
+    pub fn is_reserved_property(&self, name: &str) -> bool {
+        if self.property_declarations.contains_key(name) {
+            return false;
+        }
+        match &self.base_type {
+            ElementType::Component(c) => c.root_element.borrow().is_reserved_property(name),
+            ElementType::Builtin(b) => {
+                !b.is_non_item_type
+                    && !b.properties.contains_key(name)
+                    && crate::typeregister::reserved_property(name).property_type != Type::Invalid
+            }
+            _ => false,
+        }
+    }
+
     fn parse_bindings(
         &mut self,
         bindings: impl Iterator<Item = (crate::parser::SyntaxToken, SyntaxNode)>,
@@ -1939,6 +1995,43 @@ fn animation_element_from_node(
             diag,
         );
 
+        for con_node in anim.CallbackConnection() {
+            let Some(unresolved_name) = parser::identifier_text(&con_node) else { continue };
+            let PropertyLookupResult { resolved_name, property_type, .. } =
+                anim_element.lookup_property(&unresolved_name);
+            let Type::Callback { args, .. } = &property_type else {
+                diag.push_error(
+                    format!(
+                        "'{}' is not a callback in {}",
+                        unresolved_name, anim_element.base_type
+                    ),
+                    &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                );
+                continue;
+            };
+            let num_arg = con_node.DeclaredIdentifier().count();
+            if num_arg > args.len() {
+                diag.push_error(
+                    format!(
+                        "'{}' only has {} arguments, but {} were provided",
+                        unresolved_name,
+                        args.len(),
+                        num_arg
+                    ),
+                    &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                );
+            }
+            match anim_element.bindings.entry(resolved_name.into_owned()) {
+                Entry::Vacant(e) => {
+                    e.insert(BindingExpression::new_uncompiled(con_node.clone().into()).into());
+                }
+                Entry::Occupied(_) => diag.push_error(
+                    "Duplicated callback".into(),
+                    &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                ),
+            }
+        }
+
         apply_default_type_properties(&mut anim_element);
 
         Some(Rc::new(RefCell::new(anim_element)))
@@ -2377,6 +2470,8 @@ pub struct Transition {
     /// false for 'to', true for 'out'
     pub is_out: bool,
     pub state_id: String,
+    /// The state on the other side of the transition, for `in from xxx` and `out to xxx`
+    pub other_state_id: Option<String>,
     pub property_animations: Vec<(NamedReference, SourceLocation, ElementRc)>,
     pub node: syntax_nodes::Transition,
 }
@@ -2397,6 +2492,7 @@ fn from_node(
                 .DeclaredIdentifier()
                 .and_then(|x| parser::identifier_text(&x))
                 .unwrap_or_default(),
+            other_state_id: trs.TransitionState().and_then(|x| parser::identifier_text(&x)),
             property_animations: trs
                 .PropertyAnimation()
                 .flat_map(|pa| pa.QualifiedName().map(move |qn| (pa.clone(), qn)))
@@ -354,7 +354,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *CallbackConnection ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
         PropertyChangedCallback-> [ DeclaredIdentifier, CodeBlock ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
@@ -416,7 +416,9 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `transitions: [...]`
         Transitions -> [*Transition],
         /// There is an identifier "in" or "out", the DeclaredIdentifier is the state name
-        Transition -> [?DeclaredIdentifier, *PropertyAnimation],
+        Transition -> [?DeclaredIdentifier, ?TransitionState, *PropertyAnimation],
+        /// The name of the other state in `in from xxx` or `out to xxx`
+        TransitionState -> [],
         /// Export a set of declared components by name
         ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, ?ExportModule, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
@@ -416,6 +416,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 100ms; finished => { foo(); } }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -441,14 +442,15 @@ fn parse_property_animation(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::FatArrow | SyntaxKind::LParent => parse_callback_connection(&mut *p),
                 _ => {
                     p.consume();
-                    p.error("Only bindings are allowed in animations");
+                    p.error("Only bindings and callback handlers are allowed in animations");
                 }
             },
             _ => {
                 p.consume();
-                p.error("Only bindings are allowed in animations");
+                p.error("Only bindings and callback handlers are allowed in animations");
             }
         }
     }
@@ -490,6 +492,7 @@ fn parse_states(p: &mut impl Parser) {
 /// foo when bar == 1:  { color: blue; foo.color: red;   }
 /// a when b:  { color: blue; in { animate color { duration: 120s; } }   }
 /// a when b:  { out { animate foo.bar { } } foo.bar: 42;  }
+/// a when b:  { in from c { animate color { duration: 120s; } } out to c { animate color { } } }
 /// ```
 fn parse_state(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() != SyntaxKind::Identifier {
@@ -517,11 +520,23 @@ fn parse_state(p: &mut impl Parser) -> bool {
             }
             SyntaxKind::Eof => return false,
             _ => {
-                if p.nth(1).kind() == SyntaxKind::LBrace
-                    && matches!(p.peek().as_str(), "in" | "out")
+                if matches!(p.peek().as_str(), "in" | "out")
+                    && (p.nth(1).kind() == SyntaxKind::LBrace
+                        || matches!(p.nth(1).as_str(), "from" | "to"))
                 {
                     let mut p = p.start_node(SyntaxKind::Transition);
+                    let is_out = p.peek().as_str() == "out";
                     p.consume(); // "in" or "out"
+                    if p.nth(0).kind() != SyntaxKind::LBrace {
+                        let (keyword, expected) =
+                            if is_out { ("out", "to") } else { ("in", "from") };
+                        if p.peek().as_str() != expected {
+                            p.error(format!("Expected '{expected}' after '{keyword}'"));
+                        }
+                        p.consume(); // "from" or "to"
+                        let mut p = p.start_node(SyntaxKind::TransitionState);
+                        p.expect(SyntaxKind::Identifier);
+                    }
                     p.expect(SyntaxKind::LBrace);
                     if !parse_transition_inner(&mut *p) {
                         return false;
@@ -4,9 +4,9 @@
 mod apply_default_properties_from_style;
 mod binding_analysis;
 mod border_radius;
+mod check_blend_mode;
 mod check_expressions;
 mod check_public_api;
-mod check_rotation;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -28,6 +28,7 @@
 mod inlining;
 mod lower_absolute_coordinates;
 mod lower_accessibility;
+mod lower_animation_keyframes;
 mod lower_component_container;
 mod lower_layout;
 mod lower_popups;
@@ -71,6 +72,7 @@ pub async fn run_passes(
 
     let global_type_registry = type_loader.global_type_registry.clone();
     run_import_passes(doc, type_loader, diag);
+    focus_handling::create_focus_element_api(doc);
     check_public_api::check_public_api(doc, &type_loader.compiler_config, diag);
 
     let raw_type_loader =
@@ -95,6 +97,12 @@ pub async fn run_passes(
             diag,
         );
         lower_states::lower_states(component, &doc.local_registry, diag);
+        check_blend_mode::check_blend_mode(
+            component,
+            type_loader.compiler_config.embed_resources,
+            diag,
+        );
+        lower_animation_keyframes::lower_animation_keyframes(component, diag);
         lower_text_input_interface::lower_text_input_interface(component);
         repeater_component::process_repeater_components(component);
         lower_popups::lower_popups(component, &doc.local_registry, diag);
@@ -120,7 +128,7 @@ pub async fn run_passes(
         z_order::reorder_by_z_order(component, diag);
         lower_property_to_element::lower_property_to_element(
             component,
-            "opacity",
+            &["opacity"],
             core::iter::empty(),
             None,
             "Opacity",
@@ -129,7 +137,7 @@ pub async fn run_passes(
         );
         lower_property_to_element::lower_property_to_element(
             component,
-            "cache-rendering-hint",
+            &["cache-rendering-hint"],
             core::iter::empty(),
             None,
             "Layer",
@@ -140,8 +148,8 @@ pub async fn run_passes(
         lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag);
         lower_property_to_element::lower_property_to_element(
             component,
-            crate::typeregister::RESERVED_ROTATION_PROPERTIES[0].0,
-            crate::typeregister::RESERVED_ROTATION_PROPERTIES[1..]
+            &["rotation-angle", "scale"],
+            crate::typeregister::RESERVED_TRANSFORM_PROPERTIES[2..]
                 .iter()
                 .map(|(prop_name, _)| *prop_name),
             Some(&|e, prop| Expression::BinaryExpression {
@@ -150,7 +158,7 @@ pub async fn run_passes(
                     match prop {
                         "rotation-origin-x" => "width",
                         "rotation-origin-y" => "height",
-                        "rotation-angle" => return Expression::Invalid,
+                        "rotation-angle" | "scale" => return Expression::Invalid,
                         _ => unreachable!(),
                     },
                 ))
@@ -279,6 +287,5 @@ pub fn run_import_passes(
     focus_handling::replace_forward_focus_bindings_with_focus_functions(doc, diag);
     check_expressions::check_expressions(doc, diag);
     purity_check::purity_check(doc, diag);
-    check_rotation::check_rotation(doc, diag);
     unique_id::check_unique_id(doc, diag);
 }
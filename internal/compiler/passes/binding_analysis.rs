@@ -486,6 +486,12 @@ fn visit_implicit_layout_info_dependencies(
             vis(&NamedReference::new(item, "font-size").into(), N);
             vis(&NamedReference::new(item, "font-weight").into(), N);
             vis(&NamedReference::new(item, "letter-spacing").into(), N);
+            vis(&NamedReference::new(item, "word-spacing").into(), N);
+            vis(&NamedReference::new(item, "tab-width").into(), N);
+            vis(&NamedReference::new(item, "tab-stops").into(), N);
+            vis(&NamedReference::new(item, "line-height").into(), N);
+            vis(&NamedReference::new(item, "line-height-factor").into(), N);
+            vis(&NamedReference::new(item, "paragraph-spacing").into(), N);
             vis(&NamedReference::new(item, "wrap").into(), N);
             let wrap_set = item.borrow().is_binding_set("wrap", false)
                 || item
@@ -0,0 +1,52 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that warns about the `blend-mode` values that some renderers draw like `normal`.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
+use crate::object_tree::Component;
+use crate::EmbedResourcesKind;
+use std::rc::Rc;
+
+pub fn check_blend_mode(
+    component: &Rc<Component>,
+    embed_resources: EmbedResourcesKind,
+    diag: &mut BuildDiagnostics,
+) {
+    // Textures are only embedded for the software renderer, which doesn't support any blend mode
+    let software_renderer = embed_resources == EmbedResourcesKind::EmbedTextures;
+    crate::object_tree::recurse_elem_no_borrow(&component.root_element, &(), &mut |elem, _| {
+        let elem = elem.borrow();
+        let Some(binding) = elem.bindings.get("blend-mode") else { return };
+        let binding = binding.borrow();
+        let mut unsupported = Vec::new();
+        binding.expression.visit_recursive(&mut |e| {
+            let Expression::EnumerationValue(value) = e else { return };
+            if value.enumeration.name != "BlendMode" {
+                return;
+            }
+            let name = &value.enumeration.values[value.value];
+            let supported = match name.as_str() {
+                "normal" => true,
+                "multiply" | "screen" => !software_renderer,
+                _ => false,
+            };
+            if !supported && !unsupported.contains(name) {
+                unsupported.push(name.clone());
+            }
+        });
+        for name in unsupported {
+            let message = if software_renderer {
+                format!("The software renderer doesn't support blend-mode '{name}' and draws the element with the normal blend mode")
+            } else {
+                format!("blend-mode '{name}' is only supported by the Skia and Qt renderers. The FemtoVG and software renderers draw the element with the normal blend mode")
+            };
+            if binding.span.is_some() {
+                diag.push_warning(message, &*binding);
+            } else {
+                diag.push_warning(message, &*elem);
+            }
+        }
+    });
+}
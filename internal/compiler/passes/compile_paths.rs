@@ -46,15 +46,35 @@ pub fn compile_paths(
         }
 
         let element_types = &accepted_type.additional_accepted_child_types;
+        // The path elements are built-in; other children, such as components, are kept as
+        // children of the path
+        let path_element_type = |child: &ElementRc| match &child.borrow().base_type {
+            ElementType::Builtin(b) => element_types.get(&b.native_class.class_name).cloned(),
+            _ => None,
+        };
 
         let commands_binding =
             elem_.borrow_mut().bindings.remove("commands").map(RefCell::into_inner);
+        // The animation of the commands applies to the compiled path, also when the path is
+        // made of elements and `animate commands` is the only binding of the commands.
+        let (commands_binding, animation) = match commands_binding {
+            Some(binding)
+                if binding.animation.is_some()
+                    && matches!(binding.expression, Expression::Invalid) =>
+            {
+                (None, binding.animation)
+            }
+            Some(mut binding) => {
+                let animation = binding.animation.take();
+                (Some(binding), animation)
+            }
+            None => (None, None),
+        };
 
-        let path_data_binding = if let Some(commands_expr) = commands_binding {
-            if let Some(path_child) = elem_.borrow().children.iter().find(|child| {
-                element_types
-                    .contains_key(&child.borrow().base_type.as_builtin().native_class.class_name)
-            }) {
+        let mut path_data_binding = if let Some(commands_expr) = commands_binding {
+            if let Some(path_child) =
+                elem_.borrow().children.iter().find(|child| path_element_type(child).is_some())
+            {
                 diag.push_error(
                     "Path elements cannot be mixed with the use of the SVG commands property"
                         .into(),
@@ -97,15 +117,12 @@ pub fn compile_paths(
             let mut path_data = Vec::new();
 
             for child in old_children {
-                let element_name =
-                    &child.borrow().base_type.as_builtin().native_class.class_name.clone();
-
-                if let Some(path_element) = element_types.get(element_name) {
+                if let Some(path_element) = path_element_type(&child) {
                     let element_type = match path_element {
-                        ElementType::Builtin(b) => b.clone(),
+                        ElementType::Builtin(b) => b,
                         _ => panic!(
                             "Incorrect type registry -- expected built-in type for path element {}",
-                            element_name
+                            child.borrow().base_type
                         ),
                     };
 
@@ -134,6 +151,7 @@ pub fn compile_paths(
             Expression::PathData(crate::expression_tree::Path::Elements(path_data)).into()
         };
 
+        path_data_binding.animation = animation;
         elem_.borrow_mut().bindings.insert("elements".into(), RefCell::new(path_data_binding));
     });
 }
@@ -157,7 +175,7 @@ fn compile_path_from_string_literal(
             ("y".to_owned(), Type::Float32),
         ])
         .collect(),
-        name: Some("slint::private_api::Point".into()),
+        name: Some("slint::LogicalPosition".into()),
         node: None,
         rust_attributes: None,
     };
@@ -380,12 +380,25 @@ fn embed_font(
 
     let face_info = fontdb.face(font.id).unwrap();
 
+    let (x_height, cap_height) = fontdb
+        .with_face_data(font.id, |face_data, face_index| {
+            let face = ttf_parser::Face::parse(face_data, face_index).ok()?;
+            Some((
+                face.x_height().unwrap_or_default() as f32,
+                face.capital_height().unwrap_or_default() as f32,
+            ))
+        })
+        .flatten()
+        .unwrap_or_default();
+
     BitmapFont {
         family_name,
         character_map,
         units_per_em: font.units_per_em(),
         ascent: metrics.ascent,
         descent: metrics.descent,
+        x_height,
+        cap_height,
         glyphs,
         weight: face_info.weight.0,
         italic: face_info.style != fontdb::Style::Normal,
@@ -93,6 +93,7 @@ fn embed_images_from_expression(
             if embed_files != EmbedResourcesKind::Nothing
                 && (embed_files != EmbedResourcesKind::OnlyBuiltinResources
                     || path.starts_with("builtin:/"))
+                && !crate::pathutils::is_remote_image_url(path)
             {
                 *resource_ref = embed_image(
                     global_embedded_resources,
@@ -75,6 +75,111 @@ pub fn replace_forward_focus_bindings_with_focus_functions(
     }
 }
 
+/// For the components exported from `doc` whose root element declares a `focused-element` string
+/// property without a binding, bind it to the id of the element that has the focus, and declare
+/// a `focus-element(id)` function that moves the focus to the element with that id, unless the
+/// root element already declares one. Only the elements that have an id, can get the focus, and
+/// are not in a repeater or a popup are considered.
+pub fn create_focus_element_api(doc: &Document) {
+    for component in doc.exported_roots() {
+        if !doc.inner_components.iter().any(|c| Rc::ptr_eq(c, &component)) {
+            // Re-exported from another document
+            continue;
+        }
+        let root = &component.root_element;
+        // The API is opt-in: the component declares the `focused-element` property
+        let opted_in = root
+            .borrow()
+            .property_declarations
+            .get(FOCUSED_ELEMENT)
+            .is_some_and(|decl| decl.property_type == Type::String)
+            && !root.borrow().is_binding_set(FOCUSED_ELEMENT, false);
+        if !opted_in {
+            continue;
+        }
+        let create_focus_element =
+            root.borrow().lookup_property(FOCUS_ELEMENT).property_type == Type::Invalid;
+
+        let mut elements = Vec::new();
+        collect_focusable_elements(root, &mut elements);
+
+        let id_matches = |id: &str| Expression::BinaryExpression {
+            lhs: Box::new(Expression::FunctionParameterReference { index: 0, ty: Type::String }),
+            rhs: Box::new(Expression::StringLiteral(id.into())),
+            op: '=',
+        };
+        let mut focused_element = Expression::StringLiteral(String::new());
+        let mut focus_element = Expression::BoolLiteral(false);
+        // Build the conditions from the last element, so that the elements declared later,
+        // such as children, are tested first
+        for (elem, focus_code) in elements.into_iter().rev() {
+            let id = elem.borrow().id.clone();
+            let has_focus = elem.borrow().lookup_property("has-focus");
+            if has_focus.property_type == Type::Bool
+                && has_focus.property_visibility != PropertyVisibility::Private
+            {
+                focused_element = Expression::Condition {
+                    condition: Box::new(Expression::PropertyReference(NamedReference::new(
+                        &elem,
+                        "has-focus",
+                    ))),
+                    true_expr: Box::new(Expression::StringLiteral(id.clone())),
+                    false_expr: Box::new(focused_element),
+                };
+            }
+            focus_element = Expression::Condition {
+                condition: Box::new(id_matches(&id)),
+                true_expr: Box::new(Expression::CodeBlock(vec![
+                    focus_code,
+                    Expression::BoolLiteral(true),
+                ])),
+                false_expr: Box::new(focus_element),
+            };
+        }
+
+        let mut root = root.borrow_mut();
+        root.bindings.insert(FOCUSED_ELEMENT.into(), RefCell::new(focused_element.into()));
+        if !create_focus_element {
+            continue;
+        }
+        root.property_declarations.insert(
+            FOCUS_ELEMENT.into(),
+            PropertyDeclaration {
+                property_type: Type::Function {
+                    return_type: Type::Bool.into(),
+                    args: vec![Type::String],
+                },
+                visibility: PropertyVisibility::Public,
+                pure: Some(false),
+                ..Default::default()
+            },
+        );
+        root.bindings.insert(FOCUS_ELEMENT.into(), RefCell::new(focus_element.into()));
+    }
+}
+
+const FOCUSED_ELEMENT: &str = "focused-element";
+const FOCUS_ELEMENT: &str = "focus-element";
+
+/// Collect the descendants of `elem` that have an id and can be focused, with the code to focus them
+fn collect_focusable_elements(elem: &ElementRc, result: &mut Vec<(ElementRc, Expression)>) {
+    for child in elem.borrow().children.iter() {
+        if child.borrow().repeated.is_some()
+            || child.borrow().builtin_type().is_some_and(|b| b.name == "PopupWindow")
+        {
+            continue;
+        }
+        if !child.borrow().id.is_empty() {
+            if let Some(focus_code) =
+                call_set_focus_function(child, None, FocusFunctionType::SetFocus)
+            {
+                result.push((child.clone(), focus_code));
+            }
+        }
+        collect_focusable_elements(child, result);
+    }
+}
+
 /// map all `forward-focus: some-target` bindings. The key is the element that had the binding,
 /// the target is Some(ElementRc) if it's valid. The error remove_uncallable_forwards pass will
 /// set them to None if the target is not focusable. They're not removed otherwise we'd get
@@ -496,6 +496,7 @@ fn duplicate_property_animation(
                 .map(|a| TransitionPropertyAnimation {
                     state_id: a.state_id,
                     is_out: a.is_out,
+                    other_state_id: a.other_state_id,
                     animation: duplicate_element_with_mapping(
                         &a.animation,
                         mapping,
@@ -554,6 +555,7 @@ fn duplicate_transition(
     Transition {
         is_out: t.is_out,
         state_id: t.state_id.clone(),
+        other_state_id: t.other_state_id.clone(),
         property_animations: t
             .property_animations
             .iter()
@@ -583,6 +585,8 @@ fn component_requires_inlining(component: &Rc<Component>) -> bool {
         // on the top level of a component. This could be changed in the future.
         if prop.starts_with("drop-shadow-")
             || prop == "opacity"
+            || prop == "rotation-angle"
+            || (prop == "scale" && root_element.borrow().is_reserved_property(prop))
             || prop == "cache-rendering-hint"
             || prop == "visible"
         {
@@ -0,0 +1,84 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that flattens the `keyframes` of the animations into an array of numbers
+//! (the position in the animation followed by the progress, for each keyframe)
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
+use crate::langtype::Type;
+use crate::object_tree::*;
+use std::rc::Rc;
+
+pub fn lower_animation_keyframes(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        for binding in elem.borrow().bindings.values() {
+            match &binding.borrow().animation {
+                Some(PropertyAnimation::Static(animation)) => lower_keyframes(animation, diag),
+                Some(PropertyAnimation::Transition { animations, .. }) => {
+                    for transition in animations {
+                        lower_keyframes(&transition.animation, diag);
+                    }
+                }
+                None => (),
+            }
+        }
+    })
+}
+
+fn lower_keyframes(animation: &ElementRc, diag: &mut BuildDiagnostics) {
+    let animation = animation.borrow();
+    let Some(binding) = animation.bindings.get("keyframes") else { return };
+    let mut binding = binding.borrow_mut();
+    let keyframes = match &binding.expression {
+        Expression::Array { values, .. } => values.clone(),
+        Expression::Invalid => return,
+        _ => {
+            diag.push_error(
+                "The keyframes of an animation must be an array literal".into(),
+                &*binding,
+            );
+            return;
+        }
+    };
+
+    let mut values = Vec::with_capacity(keyframes.len() * 2);
+    let mut previous_at = 0.;
+    for keyframe in keyframes {
+        let field = |name: &str| match &keyframe {
+            Expression::Struct { values, .. } => values[name].clone(),
+            _ => Expression::StructFieldAccess {
+                base: Box::new(keyframe.clone()),
+                name: name.into(),
+            },
+        };
+        let at = field("at");
+        if let Some(value) = constant_number(&at) {
+            if !(previous_at..=1.).contains(&value) {
+                diag.push_error(
+                    "The keyframes must be between 0% and 100%, in increasing order".into(),
+                    &*binding,
+                );
+            }
+            previous_at = value;
+        }
+        values.push(at);
+        values.push(field("progress"));
+    }
+    binding.expression = Expression::Cast {
+        from: Box::new(Expression::Array { element_ty: Type::Float32, values }),
+        to: Type::LengthArray,
+    };
+}
+
+/// Returns the value of number literals, including the ones converted from a percentage
+fn constant_number(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(value, _) => Some(*value),
+        Expression::BinaryExpression { lhs, rhs, op: '*' } => {
+            Some(constant_number(lhs)? * constant_number(rhs)?)
+        }
+        Expression::Cast { from, .. } => constant_number(from),
+        _ => None,
+    }
+}
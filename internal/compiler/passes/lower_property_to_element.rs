@@ -6,32 +6,37 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{BindingExpression, Expression, NamedReference};
-use crate::langtype::Type;
 use crate::object_tree::{self, Component, Element, ElementRc};
 use crate::typeregister::TypeRegister;
 use std::rc::Rc;
 
-/// If any element in `component` declares a binding to `property_name`, then a new
+/// If any element in `component` declares a binding to one of `property_names`, then a new
 /// element of type `element_name` is created, injected as a parent to the element and bindings
-/// to property_name and all properties in  extra_properties are mapped.
+/// to property_names and all properties in  extra_properties are mapped.
 /// Default value for the property extra_properties is queried with the `default_value_for_extra_properties`
 pub(crate) fn lower_property_to_element(
     component: &Rc<Component>,
-    property_name: &'static str,
+    property_names: &[&'static str],
     extra_properties: impl Iterator<Item = &'static str> + Clone,
     default_value_for_extra_properties: Option<&dyn Fn(&ElementRc, &str) -> Expression>,
     element_name: &str,
     type_register: &TypeRegister,
     diag: &mut BuildDiagnostics,
 ) {
-    if let Some(b) = component.root_element.borrow().bindings.get(property_name) {
-        diag.push_warning(
-            format!(
-                "The {} property cannot be used on the root element, it will not be applied",
-                property_name
-            ),
-            &*b.borrow(),
-        );
+    for property_name in property_names {
+        let root_element = component.root_element.borrow();
+        if !root_element.is_reserved_property(property_name) {
+            continue;
+        }
+        if let Some(b) = root_element.bindings.get(*property_name) {
+            diag.push_warning(
+                format!(
+                    "The {} property cannot be used on the root element, it will not be applied",
+                    property_name
+                ),
+                &*b.borrow(),
+            );
+        }
     }
 
     object_tree::recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
@@ -46,13 +51,15 @@ pub(crate) fn lower_property_to_element(
         };
 
         let has_property_binding = |e: &ElementRc| {
-            e.borrow().base_type.lookup_property(property_name).property_type != Type::Invalid
-                && (e.borrow().bindings.contains_key(property_name)
-                    || e.borrow()
-                        .property_analysis
-                        .borrow()
-                        .get(property_name)
-                        .map_or(false, |a| a.is_set || a.is_linked))
+            property_names.iter().any(|property_name| {
+                e.borrow().is_reserved_property(property_name)
+                    && (e.borrow().bindings.contains_key(*property_name)
+                        || e.borrow()
+                            .property_analysis
+                            .borrow()
+                            .get(*property_name)
+                            .map_or(false, |a| a.is_set || a.is_linked))
+            })
         };
 
         for mut child in old_children {
@@ -63,7 +70,7 @@ pub(crate) fn lower_property_to_element(
                         &child,
                         create_property_element(
                             &root_elem,
-                            property_name,
+                            property_names,
                             extra_properties.clone(),
                             default_value_for_extra_properties,
                             element_name,
@@ -74,7 +81,7 @@ pub(crate) fn lower_property_to_element(
             } else if has_property_binding(&child) {
                 let new_child = create_property_element(
                     &child,
-                    property_name,
+                    property_names,
                     extra_properties.clone(),
                     default_value_for_extra_properties,
                     element_name,
@@ -92,14 +99,18 @@ pub(crate) fn lower_property_to_element(
 
 fn create_property_element(
     child: &ElementRc,
-    property_name: &'static str,
+    property_names: &[&'static str],
     extra_properties: impl Iterator<Item = &'static str>,
     default_value_for_extra_properties: Option<&dyn Fn(&ElementRc, &str) -> Expression>,
     element_name: &str,
     type_register: &TypeRegister,
 ) -> ElementRc {
-    let bindings = core::iter::once(property_name)
+    let bindings = property_names
+        .iter()
+        .copied()
         .chain(extra_properties)
+        // A property declared by the element itself is not the one that is lowered
+        .filter(|property_name| child.borrow().is_reserved_property(property_name))
         .map(|property_name| {
             let mut bind =
                 BindingExpression::new_two_way(NamedReference::new(child, property_name));
@@ -113,7 +124,7 @@ fn create_property_element(
         .collect();
 
     let element = Element {
-        id: format!("{}-{}", child.borrow().id, property_name),
+        id: format!("{}-{}", child.borrow().id, property_names[0]),
         base_type: type_register.lookup_element(element_name).unwrap(),
         enclosing_component: child.borrow().enclosing_component.clone(),
         bindings,
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 //! Pass that lowers synthetic `drop-shadow-*` properties to proper shadow elements
-// At the moment only shadows on `Rectangle` elements are supported, i.e. the drop shadow
-// of a rectangle is a box shadow.
+// The drop shadow of a `Rectangle` is a box shadow that is rendered as a sibling underneath
+// the rectangle. For any other element, a `DropShadow` element is injected as parent, which
+// renders the shadow from the rendered children.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::BindingExpression;
@@ -13,34 +14,22 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 
+fn is_rectangle(element: &ElementRc) -> bool {
+    !matches!(element.borrow().builtin_type(), Some(b) if b.name != "Rectangle")
+}
+
 // Creates a new element for the drop shadow properties that'll be a sibling to the specified
 // sibling element.
 fn create_box_shadow_element(
     shadow_property_bindings: HashMap<String, BindingExpression>,
     sibling_element: &ElementRc,
     type_register: &TypeRegister,
-    diag: &mut BuildDiagnostics,
-) -> Option<Element> {
-    if matches!(sibling_element.borrow().builtin_type(), Some(b) if b.name != "Rectangle") {
-        for (shadow_prop_name, shadow_prop_binding) in shadow_property_bindings {
-            diag.push_error(
-                format!("The {shadow_prop_name} property is only supported on Rectangle elements right now"),
-                &shadow_prop_binding,
-            );
-        }
-        return None;
-    }
-
+) -> Element {
     let mut element = Element {
         id: format!("{}-shadow", sibling_element.borrow().id),
         base_type: type_register.lookup_builtin_element("BoxShadow").unwrap(),
         enclosing_component: sibling_element.borrow().enclosing_component.clone(),
-        bindings: shadow_property_bindings
-            .into_iter()
-            .map(|(shadow_prop_name, expr)| {
-                (shadow_prop_name.strip_prefix("drop-shadow-").unwrap().to_string(), expr.into())
-            })
-            .collect(),
+        bindings: shadow_bindings(shadow_property_bindings),
         ..Default::default()
     };
 
@@ -58,7 +47,33 @@ fn create_box_shadow_element(
         );
     }
 
-    Some(element)
+    element
+}
+
+// Creates a new element for the drop shadow properties that'll become the parent of the
+// specified element.
+fn create_drop_shadow_element(
+    shadow_property_bindings: HashMap<String, BindingExpression>,
+    element_with_shadow: &ElementRc,
+    type_register: &TypeRegister,
+) -> ElementRc {
+    Element {
+        id: format!("{}-shadow", element_with_shadow.borrow().id),
+        base_type: type_register.lookup_builtin_element("DropShadow").unwrap(),
+        enclosing_component: element_with_shadow.borrow().enclosing_component.clone(),
+        bindings: shadow_bindings(shadow_property_bindings),
+        ..Default::default()
+    }
+    .make_rc()
+}
+
+fn shadow_bindings(shadow_property_bindings: HashMap<String, BindingExpression>) -> BindingsMap {
+    shadow_property_bindings
+        .into_iter()
+        .map(|(shadow_prop_name, expr)| {
+            (shadow_prop_name.strip_prefix("drop-shadow-").unwrap().to_string(), expr.into())
+        })
+        .collect()
 }
 
 // For a repeated element, this function creates a new element for the drop shadow properties that
@@ -67,25 +82,25 @@ fn inject_shadow_element_in_repeated_element(
     shadow_property_bindings: HashMap<String, BindingExpression>,
     repeated_element: &ElementRc,
     type_register: &TypeRegister,
-    diag: &mut BuildDiagnostics,
 ) {
     let element_with_shadow_property =
         &repeated_element.borrow().base_type.as_component().root_element.clone();
 
-    let shadow_element = match create_box_shadow_element(
-        shadow_property_bindings,
-        element_with_shadow_property,
-        type_register,
-        diag,
-    ) {
-        Some(element) => element,
-        None => return,
+    let shadow_element = if is_rectangle(element_with_shadow_property) {
+        Element::make_rc(create_box_shadow_element(
+            shadow_property_bindings,
+            element_with_shadow_property,
+            type_register,
+        ))
+    } else {
+        create_drop_shadow_element(
+            shadow_property_bindings,
+            element_with_shadow_property,
+            type_register,
+        )
     };
 
-    crate::object_tree::inject_element_as_repeated_element(
-        repeated_element,
-        Element::make_rc(shadow_element),
-    );
+    crate::object_tree::inject_element_as_repeated_element(repeated_element, shadow_element);
 }
 
 fn take_shadow_property_bindings(element: &ElementRc) -> HashMap<String, BindingExpression> {
@@ -133,7 +148,6 @@ pub fn lower_shadow_properties(
                     drop_shadow_properties,
                     elem,
                     type_register,
-                    diag,
                 );
             }
         }
@@ -144,29 +158,27 @@ pub fn lower_shadow_properties(
             std::mem::replace(&mut elem.children, new_children)
         };
 
-        // When encountering a `drop-shadow` property in a supported element, we create a new dedicated
+        // When encountering a `drop-shadow` property in a Rectangle, we create a new dedicated
         // shadow element and insert it *before* the element that had the `drop-shadow` property, to ensure
-        // that it is rendered underneath.
+        // that it is rendered underneath. Any other element is wrapped in a `DropShadow` element, which
+        // renders the shadow of its children.
         for child in old_children {
             let drop_shadow_properties = take_shadow_property_bindings(&child);
-            if !drop_shadow_properties.is_empty() {
-                let mut shadow_elem = match create_box_shadow_element(
-                    drop_shadow_properties,
-                    &child,
-                    type_register,
-                    diag,
-                ) {
-                    Some(element) => element,
-                    None => {
-                        elem.borrow_mut().children.push(child);
-                        continue;
-                    }
-                };
-
+            if drop_shadow_properties.is_empty() {
+                elem.borrow_mut().children.push(child);
+            } else if is_rectangle(&child) {
+                let mut shadow_elem =
+                    create_box_shadow_element(drop_shadow_properties, &child, type_register);
                 shadow_elem.geometry_props.clone_from(&child.borrow().geometry_props);
                 elem.borrow_mut().children.push(ElementRc::new(shadow_elem.into()));
+                elem.borrow_mut().children.push(child);
+            } else {
+                let shadow_elem =
+                    create_drop_shadow_element(drop_shadow_properties, &child, type_register);
+                adjust_geometry_for_injected_parent(&shadow_elem, &child);
+                shadow_elem.borrow_mut().children.push(child);
+                elem.borrow_mut().children.push(shadow_elem);
             }
-            elem.borrow_mut().children.push(child);
         }
     });
 }
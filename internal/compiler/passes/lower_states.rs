@@ -146,6 +146,20 @@ fn lower_transitions_in_element(
             );
             &0
         });
+        let other_state = transition.other_state_id.as_ref().map(|other_state_id| {
+            *states_id.get(other_state_id).unwrap_or_else(|| {
+                diag.push_error(
+                    format!("State '{other_state_id}' does not exist"),
+                    transition
+                        .node
+                        .TransitionState()
+                        .as_ref()
+                        .map(|x| x as &dyn Spanned)
+                        .unwrap_or(&transition.node as &dyn Spanned),
+                );
+                &0
+            })
+        });
 
         for (p, span, animation) in transition.property_animations {
             if !affected_properties.contains(&p) {
@@ -159,12 +173,16 @@ fn lower_transitions_in_element(
             let t = TransitionPropertyAnimation {
                 state_id: *state,
                 is_out: transition.is_out,
+                other_state_id: other_state,
                 animation,
             };
             props.entry(p).or_insert_with(|| (span.clone(), vec![])).1.push(t);
         }
     }
-    for (ne, (span, animations)) in props {
+    for (ne, (span, mut animations)) in props {
+        // The first matching transition is used, so the transitions between two specific states
+        // take precedence over the `in` and `out` transitions
+        animations.sort_by_key(|t| t.other_state_id.is_none());
         let e = ne.element();
         // We check earlier that the property is in the set of changed properties, so a binding bust have been assigned
         let old_anim = e.borrow().bindings.get(ne.name()).unwrap().borrow_mut().animation.replace(
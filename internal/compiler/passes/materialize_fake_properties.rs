@@ -137,7 +137,7 @@ pub fn initialize(elem: &ElementRc, name: &str) -> Option<Expression> {
         "preferred-width" => layout_constraint_prop(elem, "preferred", Orientation::Horizontal),
         "horizontal-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Horizontal),
         "vertical-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Vertical),
-        "opacity" => Expression::NumberLiteral(1., Unit::None),
+        "opacity" | "scale" => Expression::NumberLiteral(1., Unit::None),
         "visible" => Expression::BoolLiteral(true),
         _ => return None,
     };
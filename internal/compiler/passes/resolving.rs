@@ -409,13 +409,25 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
 
     fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self {
         enum GradKind {
-            Linear { angle: Box<Expression> },
-            Radial,
+            Linear {
+                angle: Box<Expression>,
+            },
+            Radial {
+                center_x: Box<Expression>,
+                center_y: Box<Expression>,
+                radius: Box<Expression>,
+            },
+            Conic {
+                from_angle: Box<Expression>,
+                center_x: Box<Expression>,
+                center_y: Box<Expression>,
+            },
         }
 
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression))
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
         let grad_text = grad_token.text();
@@ -451,10 +463,40 @@ enum GradKind {
                 ctx.diag.push_error("Expected 'circle': currently, only @radial-gradient(circle, ...) are supported".into(), &node);
                 return Expression::Invalid;
             }
-            let comma = subs.next();
-            if matches!(&comma, Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "at") {
-                ctx.diag.push_error("'at' in @radial-gradient is not yet supported".into(), &comma);
-                return Expression::Invalid;
+            let is_at = |n: &Option<NodeOrToken>| matches!(n, Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "at");
+            let to_float = |n: SyntaxNode, ctx: &mut LookupCtx| {
+                let e = syntax_nodes::Expression::from(n);
+                Box::new(Expression::from_expression_node(e.clone(), ctx).maybe_convert_to(
+                    Type::Float32,
+                    &e,
+                    ctx.diag,
+                ))
+            };
+            let mut radius = Box::new(Expression::NumberLiteral(1., Unit::None));
+            let mut center_x = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            let mut center_y = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            let mut comma = subs.next();
+            if !is_at(&comma) {
+                if let Some(NodeOrToken::Node(r)) = &comma {
+                    radius = to_float(r.clone(), ctx);
+                    comma = subs.next();
+                }
+            }
+            if is_at(&comma) {
+                match (subs.next(), subs.next()) {
+                    (Some(NodeOrToken::Node(x)), Some(NodeOrToken::Node(y))) => {
+                        center_x = to_float(x, ctx);
+                        center_y = to_float(y, ctx);
+                    }
+                    _ => {
+                        ctx.diag.push_error(
+                            "'at' must be followed by the x and y position of the center".into(),
+                            comma.as_ref().unwrap(),
+                        );
+                        return Expression::Invalid;
+                    }
+                }
+                comma = subs.next();
             }
             if comma.as_ref().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
                 ctx.diag.push_error(
@@ -463,9 +505,66 @@ enum GradKind {
                 );
                 return Expression::Invalid;
             }
-            GradKind::Radial
+            GradKind::Radial { center_x, center_y, radius }
+        } else if grad_text.starts_with("conic") {
+            let is_keyword = |n: &Option<NodeOrToken>, kw: &str| matches!(n, Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == kw);
+            let convert = |n: SyntaxNode, ty: Type, ctx: &mut LookupCtx| {
+                let e = syntax_nodes::Expression::from(n);
+                Box::new(
+                    Expression::from_expression_node(e.clone(), ctx)
+                        .maybe_convert_to(ty, &e, ctx.diag),
+                )
+            };
+            let mut from_angle = Box::new(Expression::NumberLiteral(0., Unit::Deg));
+            let mut center_x = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            let mut center_y = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            let mut comma = None;
+            let mut has_parameters = false;
+            // Look ahead to see if the first stop is preceded by 'from' or 'at'
+            let first = subs.peek().cloned();
+            if is_keyword(&first, "from") || is_keyword(&first, "at") {
+                has_parameters = true;
+                comma = subs.next();
+            }
+            if has_parameters && is_keyword(&comma, "from") {
+                match subs.next() {
+                    Some(NodeOrToken::Node(angle)) => from_angle = convert(angle, Type::Angle, ctx),
+                    _ => {
+                        ctx.diag.push_error(
+                            "'from' must be followed by an angle".into(),
+                            comma.as_ref().unwrap(),
+                        );
+                        return Expression::Invalid;
+                    }
+                }
+                comma = subs.next();
+            }
+            if has_parameters && is_keyword(&comma, "at") {
+                match (subs.next(), subs.next()) {
+                    (Some(NodeOrToken::Node(x)), Some(NodeOrToken::Node(y))) => {
+                        center_x = convert(x, Type::Float32, ctx);
+                        center_y = convert(y, Type::Float32, ctx);
+                    }
+                    _ => {
+                        ctx.diag.push_error(
+                            "'at' must be followed by the x and y position of the center".into(),
+                            comma.as_ref().unwrap(),
+                        );
+                        return Expression::Invalid;
+                    }
+                }
+                comma = subs.next();
+            }
+            if has_parameters && comma.as_ref().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
+                ctx.diag.push_error(
+                    "Expected comma after the conic gradient's angle and position".into(),
+                    comma.as_ref().map_or(&node, |x| x as &dyn Spanned),
+                );
+                return Expression::Invalid;
+            }
+            GradKind::Conic { from_angle, center_x, center_y }
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial or conic gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
@@ -570,7 +669,12 @@ enum Stop {
 
         match grad_kind {
             GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
-            GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Radial { center_x, center_y, radius } => {
+                Expression::RadialGradient { center_x, center_y, radius, stops }
+            }
+            GradKind::Conic { from_angle, center_x, center_y } => {
+                Expression::ConicGradient { from_angle, center_x, center_y, stops }
+            }
         }
     }
 
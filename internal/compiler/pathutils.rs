@@ -18,6 +18,12 @@ pub fn is_url(path: &Path) -> bool {
     to_url(path).is_some()
 }
 
+/// Check whether an image path is a URL that is loaded at run-time by the image provider
+/// that the application registers for its scheme, such as `https://...`.
+pub fn is_remote_image_url(path: &str) -> bool {
+    !path.starts_with("builtin:/") && !path.starts_with("file:") && to_url(path).is_some()
+}
+
 /// Convert a `Path` to an `url::Url` if possible
 fn to_url(path: &str) -> Option<url::Url> {
     let Ok(url) = url::Url::parse(path) else {
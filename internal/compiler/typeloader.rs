@@ -402,6 +402,7 @@ fn snapshot_element(
             .map(|t| object_tree::Transition {
                 is_out: t.is_out,
                 state_id: t.state_id.clone(),
+                other_state_id: t.other_state_id.clone(),
                 property_animations: t
                     .property_animations
                     .iter()
@@ -518,6 +519,7 @@ fn snapshot_binding_expression(
                             .map(|tpa| object_tree::TransitionPropertyAnimation {
                                 state_id: tpa.state_id,
                                 is_out: tpa.is_out,
+                                other_state_id: tpa.other_state_id,
                                 animation: self.create_and_snapshot_element(&tpa.animation),
                             })
                             .collect(),
@@ -762,12 +764,32 @@ fn snapshot_expression(
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
                     .collect(),
             },
-            Expression::RadialGradient { stops } => Expression::RadialGradient {
-                stops: stops
-                    .iter()
-                    .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
-                    .collect(),
-            },
+            Expression::RadialGradient { center_x, center_y, radius, stops } => {
+                Expression::RadialGradient {
+                    center_x: Box::new(self.snapshot_expression(center_x)),
+                    center_y: Box::new(self.snapshot_expression(center_y)),
+                    radius: Box::new(self.snapshot_expression(radius)),
+                    stops: stops
+                        .iter()
+                        .map(|(e1, e2)| {
+                            (self.snapshot_expression(e1), self.snapshot_expression(e2))
+                        })
+                        .collect(),
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                Expression::ConicGradient {
+                    from_angle: Box::new(self.snapshot_expression(from_angle)),
+                    center_x: Box::new(self.snapshot_expression(center_x)),
+                    center_y: Box::new(self.snapshot_expression(center_y)),
+                    stops: stops
+                        .iter()
+                        .map(|(e1, e2)| {
+                            (self.snapshot_expression(e1), self.snapshot_expression(e2))
+                        })
+                        .collect(),
+                }
+            }
             Expression::ReturnStatement(expr) => Expression::ReturnStatement(
                 expr.as_ref().map(|e| Box::new(self.snapshot_expression(e))),
             ),
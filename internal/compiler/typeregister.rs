@@ -93,12 +93,17 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("drop-shadow-color", Type::Color),
 ];
 
-pub const RESERVED_ROTATION_PROPERTIES: &[(&str, Type)] = &[
+pub const RESERVED_TRANSFORM_PROPERTIES: &[(&str, Type)] = &[
     ("rotation-angle", Type::Angle),
+    ("scale", Type::Float32),
     ("rotation-origin-x", Type::LogicalLength),
     ("rotation-origin-y", Type::LogicalLength),
 ];
 
+/// Reserved properties that a component may still declare itself. `scale` is a common name for
+/// a property, so it is only applied as a transform on elements that don't declare it.
+pub const SHADOWABLE_RESERVED_PROPERTIES: &[&str] = &["scale"];
+
 pub fn reserved_accessibility_properties() -> impl Iterator<Item = (&'static str, Type)> {
     [
         //("accessible-role", ...)
@@ -130,7 +135,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
         .chain(RESERVED_LAYOUT_PROPERTIES.iter())
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
-        .chain(RESERVED_ROTATION_PROPERTIES.iter())
+        .chain(RESERVED_TRANSFORM_PROPERTIES.iter())
         .map(|(k, v)| (*k, v.clone(), PropertyVisibility::InOut))
         .chain(reserved_accessibility_properties().map(|(k, v)| (k, v, PropertyVisibility::InOut)))
         .chain(
@@ -295,6 +300,7 @@ fn builtin_internal() -> Self {
         register.supported_property_animation_types.insert(Type::LogicalLength.to_string());
         register.supported_property_animation_types.insert(Type::Brush.to_string());
         register.supported_property_animation_types.insert(Type::Angle.to_string());
+        register.supported_property_animation_types.insert(Type::PathData.to_string());
 
         #[rustfmt::skip]
         macro_rules! map_type {
@@ -380,6 +386,16 @@ struct $Name:ident {
                 text_input
                     .member_functions
                     .insert("set-selection-offsets".into(), BuiltinFunction::SetSelectionOffsets);
+                text_input.properties.get_mut("tab-stops").unwrap().ty = Type::LengthArray;
+            }
+
+            _ => unreachable!(),
+        };
+
+        match &mut register.elements.get_mut("Text").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let text = Rc::get_mut(b).unwrap();
+                text.properties.get_mut("tab-stops").unwrap().ty = Type::LengthArray;
             }
 
             _ => unreachable!(),
@@ -390,6 +406,25 @@ struct $Name:ident {
                 let path = Rc::get_mut(b).unwrap();
                 path.properties.get_mut("commands").unwrap().property_visibility =
                     PropertyVisibility::Fake;
+                path.properties.get_mut("dash-pattern").unwrap().ty = Type::LengthArray;
+            }
+
+            _ => unreachable!(),
+        };
+
+        match &mut register.property_animation_type {
+            ElementType::Builtin(ref mut b) => {
+                let animation = Rc::get_mut(b).unwrap();
+                animation.properties.get_mut("keyframes").unwrap().ty =
+                    Type::Array(Box::new(Type::Struct {
+                        fields: BTreeMap::from([
+                            ("at".to_owned(), Type::Float32),
+                            ("progress".to_owned(), Type::Float32),
+                        ]),
+                        name: None,
+                        node: None,
+                        rust_attributes: None,
+                    }));
             }
 
             _ => unreachable!(),
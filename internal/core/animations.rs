@@ -96,7 +96,7 @@ pub fn solve_t_for_x(&self, x: S, t_range: core::ops::Range<S>, tolerance: S) ->
             }
 
             // Newton's method.
-            let mut t = x - from / (to - from);
+            let mut t = (x - from) / (to - from);
             for _ in 0..8 {
                 let x2 = self.x(t);
 
@@ -160,9 +160,95 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
+    /// A spring with a mass of 1, with its stiffness, damping and initial velocity.
+    /// The initial velocity is relative to the distance of the animation, per second.
+    /// Property animations with a spring run until the spring comes to rest, regardless of
+    /// their duration.
+    Spring([f32; 3]),
     // Custom(Box<dyn Fn(f32) -> f32>),
 }
 
+/// The motion of a damped spring with a mass of 1, that pulls from 0 to 1.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Spring {
+    stiffness: f32,
+    damping: f32,
+    initial_velocity: f32,
+}
+
+impl Spring {
+    /// The spring is at rest when it's closer than this to 1 ...
+    const REST_DISTANCE: f32 = 0.001;
+    /// ... and slower than this, per second.
+    const REST_VELOCITY: f32 = 0.01;
+    /// Springs that don't come to rest, such as springs without damping, are stopped after this many seconds.
+    const MAX_DURATION: f32 = 60.;
+
+    pub fn new([stiffness, damping, initial_velocity]: [f32; 3]) -> Self {
+        Self { stiffness, damping, initial_velocity }
+    }
+
+    /// Same as [`Self::new`], but with another initial velocity.
+    pub fn with_initial_velocity(self, initial_velocity: f32) -> Self {
+        Self { initial_velocity, ..self }
+    }
+
+    /// Returns the position and the velocity per second of the spring after the given time in seconds.
+    pub fn state(&self, time: f32) -> (f32, f32) {
+        if self.stiffness <= 0. {
+            return (1., 0.);
+        }
+        // The spring moves the offset `y` from the rest position 1, from -1 to 0:
+        // y'' = -stiffness * y - damping * y'
+        let omega = self.stiffness.sqrt();
+        let zeta = self.damping.max(0.) / (2. * omega);
+        let (y0, v0) = (-1., self.initial_velocity);
+        let (y, v) = if zeta < 1. {
+            // Under-damped: oscillates around the rest position
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let b = (v0 + zeta * omega * y0) / omega_d;
+            let decay = (-zeta * omega * time).exp();
+            let (sin, cos) = (omega_d * time).sin_cos();
+            let y = decay * (y0 * cos + b * sin);
+            let v = decay
+                * ((b * omega_d - zeta * omega * y0) * cos
+                    - (y0 * omega_d + zeta * omega * b) * sin);
+            (y, v)
+        } else if zeta == 1. {
+            // Critically damped
+            let b = v0 + omega * y0;
+            let decay = (-omega * time).exp();
+            (decay * (y0 + b * time), decay * (b - omega * (y0 + b * time)))
+        } else {
+            // Over-damped
+            let root = omega * (zeta * zeta - 1.).sqrt();
+            let (r1, r2) = (-zeta * omega + root, -zeta * omega - root);
+            let c2 = (v0 - r1 * y0) / (r2 - r1);
+            let c1 = y0 - c2;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        };
+        (1. + y, v)
+    }
+
+    /// Returns true if the spring has come to rest after the given time in seconds.
+    pub fn is_at_rest(&self, time: f32) -> bool {
+        let (position, velocity) = self.state(time);
+        time >= Self::MAX_DURATION
+            || ((1. - position).abs() < Self::REST_DISTANCE && velocity.abs() < Self::REST_VELOCITY)
+    }
+
+    /// Returns the time in seconds that the spring takes to come to rest, in steps of a frame.
+    pub fn duration(&self) -> f32 {
+        const FRAME: f32 = 1. / 60.;
+        let mut time = 0.;
+        while !self.is_at_rest(time) {
+            time += FRAME;
+        }
+        time
+    }
+}
+
 /// Represent an instant, in milliseconds since the AnimationDriver's initial_instant
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Ord, PartialOrd, Eq)]
@@ -227,11 +313,32 @@ pub fn as_millis(&self) -> u64 {
     }
 }
 
+/// A command to control the running animation of a property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AnimationControl {
+    /// Freeze the animation at its current value.
+    Pause,
+    /// Continue a paused animation from where it was paused.
+    Resume,
+    /// Move the animation to the given progress, between 0 (the start of the animation) and 1 (its end).
+    /// The progress covers all the iterations of the animation, or a single one if the animation repeats
+    /// forever. The delay is skipped, and a paused animation stays paused. Seeking to 1 finishes the animation.
+    Seek(f32),
+}
+
 /// The AnimationDriver
 pub struct AnimationDriver {
     /// Indicate whether there are any active animations that require a future call to update_animations.
     active_animations: Cell<bool>,
     global_instant: core::pin::Pin<Box<crate::Property<Instant>>>,
+    /// The last tick passed to update_animations, which keeps on advancing while the driver is paused.
+    real_tick: Cell<Instant>,
+    /// The time spent paused, that the animation time lags behind the real time.
+    paused_duration: Cell<core::time::Duration>,
+    paused: Cell<bool>,
+    /// The factor by which the duration and delay of the animations are scaled.
+    duration_factor: Cell<f32>,
 }
 
 impl Default for AnimationDriver {
@@ -242,14 +349,41 @@ fn default() -> Self {
                 Instant::default(),
                 "i_slint_core::AnimationDriver::global_instant",
             )),
+            real_tick: Cell::default(),
+            paused_duration: Cell::default(),
+            paused: Cell::default(),
+            duration_factor: Cell::new(default_duration_factor()),
+        }
+    }
+}
+
+/// The duration factor set with the `SLINT_ANIMATION_DURATION_FACTOR` environment variable, or 1.
+fn default_duration_factor() -> f32 {
+    #[cfg(feature = "std")]
+    if let Ok(val) = std::env::var("SLINT_ANIMATION_DURATION_FACTOR") {
+        match val.parse::<f32>() {
+            Ok(factor) if factor >= 0. => return factor,
+            _ => eprintln!(
+                "SLINT_ANIMATION_DURATION_FACTOR must be a positive number or 0, got '{val}'"
+            ),
         }
     }
+    1.
 }
 
 impl AnimationDriver {
     /// Iterates through all animations based on the new time tick and updates their state. This should be called by
     /// the windowing system driver for every frame.
     pub fn update_animations(&self, new_tick: Instant) {
+        let elapsed = new_tick.0.saturating_sub(self.real_tick.get().0);
+        self.real_tick.set(new_tick);
+        if self.paused.get() {
+            self.paused_duration
+                .set(self.paused_duration.get() + core::time::Duration::from_millis(elapsed));
+            return;
+        }
+        let new_tick =
+            Instant(new_tick.0.saturating_sub(self.paused_duration.get().as_millis() as u64));
         if self.global_instant.as_ref().get_untracked() != new_tick {
             self.active_animations.set(false);
             self.global_instant.as_ref().set(new_tick);
@@ -258,8 +392,45 @@ pub fn update_animations(&self, new_tick: Instant) {
 
     /// Returns true if there are any active or ready animations. This is used by the windowing system to determine
     /// if a new animation frame is required or not. Returns false otherwise.
+    /// This is always false while the driver is paused.
     pub fn has_active_animations(&self) -> bool {
-        self.active_animations.get()
+        self.active_animations.get() && !self.paused.get()
+    }
+
+    /// Freeze all animations: the animation time stops advancing until [`Self::resume()`] is called.
+    pub fn pause(&self) {
+        self.paused.set(true);
+    }
+
+    /// Let the animation time advance again after a call to [`Self::pause()`]. The animations continue
+    /// from where they were paused.
+    pub fn resume(&self) {
+        if self.paused.replace(false) {
+            // Re-evaluate the animations so that the running ones request new frames.
+            self.global_instant.as_ref().mark_dirty();
+        }
+    }
+
+    /// Returns true if the driver was paused with [`Self::pause()`].
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// The last tick passed to [`Self::update_animations()`]. Unlike [`Self::current_tick()`], it keeps
+    /// on advancing while the driver is paused, and doesn't register any dependency.
+    pub fn real_tick(&self) -> Instant {
+        self.real_tick.get()
+    }
+
+    /// Scale the duration and delay of the animations that start from now on by `factor`.
+    /// A factor of 0 disables the animations. Negative factors are treated as 0.
+    pub fn set_duration_factor(&self, factor: f32) {
+        self.duration_factor.set(factor.max(0.));
+    }
+
+    /// The factor set with [`Self::set_duration_factor()`].
+    pub fn duration_factor(&self) -> f32 {
+        self.duration_factor.get()
     }
 
     /// Tell the driver that there are active animations
@@ -288,6 +459,39 @@ pub fn current_tick() -> Instant {
     CURRENT_ANIMATION_DRIVER.with(|driver| driver.current_tick())
 }
 
+/// Pause or resume all the animations of the current thread.
+///
+/// While paused, the animations keep their current value, and windows don't render new frames
+/// for them. When resumed, the animations continue from where they were paused.
+/// This can be used to save resources while a window is hidden.
+pub fn set_animations_paused(paused: bool) {
+    CURRENT_ANIMATION_DRIVER.with(|driver| if paused { driver.pause() } else { driver.resume() })
+}
+
+/// Returns true if the animations of the current thread are paused.
+pub fn animations_paused() -> bool {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.is_paused())
+}
+
+/// Scale the duration and delay of all the animations of the current thread by `factor`.
+///
+/// A factor greater than 1 slows the animations down, and a factor between 0 and 1 speeds them up.
+/// A factor of 0 disables the animations: the properties change to their new value immediately,
+/// which can be used to honor the "reduce motion" setting of the system, or to make tests run
+/// faster and deterministically. The factor applies to the animations that start after the call,
+/// not to the ones that are already running. Negative factors are treated as 0.
+///
+/// The initial factor can be set with the `SLINT_ANIMATION_DURATION_FACTOR` environment variable,
+/// and defaults to 1. Values computed from `animation-tick()` are not affected.
+pub fn set_animation_duration_factor(factor: f32) {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_duration_factor(factor))
+}
+
+/// Returns the factor set with [`set_animation_duration_factor()`].
+pub fn animation_duration_factor() -> f32 {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.duration_factor())
+}
+
 /// Same as [`current_tick`], but also register that one should be running animation
 /// on next frame
 pub fn animation_tick() -> u64 {
@@ -320,7 +524,8 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     match curve {
         EasingCurve::Linear => value,
         EasingCurve::CubicBezier([a, b, c, d]) => {
-            if !(0.0..=1.0).contains(a) && !(0.0..=1.0).contains(c) {
+            // Like in CSS, the x coordinates must be between 0 and 1 for the curve to be a function of time
+            if !(0.0..=1.0).contains(a) || !(0.0..=1.0).contains(c) {
                 return value;
             };
             let curve = cubic_bezier::CubicBezierSegment {
@@ -329,7 +534,7 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 ctrl2: (*c, *d).into(),
                 to: (1., 1.).into(),
             };
-            curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
+            curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.0001))
         }
         EasingCurve::EaseInElastic => {
             const C4: f32 = 2.0 * core::f32::consts::PI / 3.0;
@@ -369,6 +574,11 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                     + 1.0
             }
         }
+        EasingCurve::Spring(parameters) => {
+            // The motion of the spring until it comes to rest is stretched to the duration
+            let spring = Spring::new(*parameters);
+            spring.state(value * spring.duration()).0
+        }
         EasingCurve::EaseInBounce => 1.0 - ease_out_bounce_curve(1.0 - value),
         EasingCurve::EaseOutBounce => ease_out_bounce_curve(value),
         EasingCurve::EaseInOutBounce => {
@@ -381,6 +591,105 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     }
 }
 
+/// Map the progress of an animation between 0 and 1 to the progress of the animated value through
+/// the keyframes, which are pairs of the progress of the animation and of the value, in increasing
+/// order of the progress of the animation. The curve is applied between two consecutive keyframes.
+pub fn keyframes_curve(keyframes: &[f32], curve: &EasingCurve, value: f32) -> f32 {
+    let mut previous = (0., 0.);
+    let keyframes = keyframes.chunks_exact(2).map(|k| (k[0].clamp(0., 1.), k[1]));
+    for keyframe in keyframes.chain(core::iter::once((1., 1.))) {
+        if value <= keyframe.0 {
+            let length = keyframe.0 - previous.0;
+            let t =
+                if length > 0. { easing_curve(curve, (value - previous.0) / length) } else { 1. };
+            return previous.1 + (keyframe.1 - previous.1) * t;
+        }
+        // Keyframes out of order are ignored
+        if keyframe.0 >= previous.0 {
+            previous = keyframe;
+        }
+    }
+    previous.1
+}
+
+#[test]
+fn keyframes_curve_test() {
+    let linear = EasingCurve::Linear;
+    assert_eq!(keyframes_curve(&[], &linear, 0.25), 0.25);
+    assert_eq!(keyframes_curve(&[], &EasingCurve::EaseOutBounce, 1.), 1.);
+
+    // Overshoot, then settle
+    let keyframes = [0.5, 1.2, 0.75, 0.9];
+    assert_eq!(keyframes_curve(&keyframes, &linear, 0.), 0.);
+    assert_eq!(keyframes_curve(&keyframes, &linear, 0.25), 0.6);
+    assert_eq!(keyframes_curve(&keyframes, &linear, 0.5), 1.2);
+    assert!((keyframes_curve(&keyframes, &linear, 0.625) - 1.05).abs() < 0.0001);
+    assert_eq!(keyframes_curve(&keyframes, &linear, 0.75), 0.9);
+    assert_eq!(keyframes_curve(&keyframes, &linear, 1.), 1.);
+
+    // The easing curve applies to each segment
+    let ease_in = EasingCurve::CubicBezier([0.42, 0.0, 1.0, 1.0]);
+    assert!((keyframes_curve(&[0.5, 0.5], &ease_in, 0.5) - 0.5).abs() < 0.0001);
+    assert!(keyframes_curve(&[0.5, 0.5], &ease_in, 0.25) < 0.25);
+
+    // A keyframe at the start jumps to its value
+    assert_eq!(keyframes_curve(&[0., 0.5], &linear, 0.), 0.5);
+    assert_eq!(keyframes_curve(&[0., 0.5], &linear, 0.5), 0.75);
+
+    // A keyframe out of order is ignored
+    assert_eq!(keyframes_curve(&[0.5, 0.2, 0.25, 2.], &linear, 0.75), 0.6);
+}
+
+#[test]
+fn cubic_bezier_easing() {
+    let ease = EasingCurve::CubicBezier([0.25, 0.1, 0.25, 1.0]);
+    assert_eq!(easing_curve(&ease, 0.0), 0.0);
+    assert_eq!(easing_curve(&ease, 1.0), 1.0);
+    // The values computed by browsers for the CSS `ease` timing function
+    assert!((easing_curve(&ease, 0.25) - 0.4085).abs() < 0.001);
+    assert!((easing_curve(&ease, 0.5) - 0.8024).abs() < 0.001);
+
+    let ease_in = EasingCurve::CubicBezier([0.42, 0.0, 1.0, 1.0]);
+    assert!((easing_curve(&ease_in, 0.5) - 0.3154).abs() < 0.001);
+
+    // The y coordinates may be outside of 0 and 1, to overshoot
+    let ease_in_out_back = EasingCurve::CubicBezier([0.68, -0.6, 0.32, 1.6]);
+    assert!((easing_curve(&ease_in_out_back, 0.2) + 0.1046).abs() < 0.001);
+
+    // The x coordinates may not, the curve is linear then
+    let invalid = EasingCurve::CubicBezier([0.5, 0.0, 1.5, 1.0]);
+    assert_eq!(easing_curve(&invalid, 0.3), 0.3);
+}
+
+#[test]
+fn spring_easing() {
+    for parameters in [[170., 26., 0.], [100., 20., 0.], [100., 40., 0.], [300., 10., 5.]] {
+        let spring = Spring::new(parameters);
+        let (position, velocity) = spring.state(0.);
+        assert!(position.abs() < 0.0001);
+        assert!((velocity - parameters[2]).abs() < 0.0001);
+        assert!(!spring.is_at_rest(0.));
+        assert!(spring.is_at_rest(spring.duration()));
+        assert!(spring.duration() < 3.);
+
+        // The velocity is the derivative of the position
+        let (position, velocity) = spring.state(0.1);
+        let (next_position, _) = spring.state(0.1001);
+        assert!(((next_position - position) / 0.0001 - velocity).abs() < 0.05 * velocity.abs());
+
+        let curve = EasingCurve::Spring(parameters);
+        assert!(easing_curve(&curve, 0.).abs() < 0.0001);
+        assert!((easing_curve(&curve, 1.) - 1.).abs() < Spring::REST_DISTANCE);
+    }
+
+    // A spring with little damping overshoots
+    let spring = Spring::new([300., 10., 0.]);
+    assert!((0..100).any(|frame| spring.state(frame as f32 / 60.).0 > 1.1));
+    // A critically damped spring doesn't
+    let spring = Spring::new([100., 20., 0.]);
+    assert!((0..100).all(|frame| spring.state(frame as f32 / 60.).0 <= 1.));
+}
+
 /*
 #[test]
 fn easing_test() {
@@ -10,7 +10,7 @@
 #[cfg(target_has_atomic = "ptr")]
 pub use crate::future::*;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
-use crate::input::{KeyEventType, MouseEvent};
+use crate::input::{KeyEventType, MouseEvent, TouchPhase};
 use crate::item_tree::ItemTreeVTable;
 use crate::window::{WindowAdapter, WindowInner};
 #[cfg(not(feature = "std"))]
@@ -286,7 +286,9 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 #[non_exhaustive]
 pub enum RenderingState {
     /// The window has been created and the graphics adapter/context initialized. When OpenGL
-    /// is used for rendering, the context will be current.
+    /// is used for rendering, the context will be current. This is also sent before the first
+    /// frame after the notifier was set, and again after the context was re-created, for example
+    /// when an Android application is resumed. Create graphics resources here.
     RenderingSetup,
     /// The scene of items is about to be rendered.  When OpenGL
     /// is used for rendering, the context will be current.
@@ -295,7 +297,8 @@ pub enum RenderingState {
     /// yet (for example GL swap buffers). When OpenGL is used for rendering, the context will be current.
     AfterRendering,
     /// The window will be destroyed and/or graphics resources need to be released due to other
-    /// constraints.
+    /// constraints. Release all graphics resources created in [`Self::RenderingSetup`]; they can't be
+    /// used anymore. A new [`Self::RenderingSetup`] follows if the window renders again.
     RenderingTeardown,
 }
 
@@ -470,6 +473,20 @@ pub fn set_rendering_notifier(
         self.0.window_adapter().renderer().set_rendering_notifier(Box::new(callback))
     }
 
+    /// Limits the memory that the renderer uses to cache graphics resources, such as the textures of
+    /// images or the rendered layers of drop shadows. When the budget is exceeded, the least recently
+    /// used resources are released, and created again when they are needed.
+    ///
+    /// The budget applies to the FemtoVG and Skia renderers; other renderers ignore it.
+    pub fn set_rendering_cache_budget(&self, budget: crate::graphics::RenderingCacheBudget) {
+        self.0.window_adapter().renderer().set_rendering_cache_budget(budget)
+    }
+
+    /// Returns statistics about the memory that the renderer uses to cache graphics resources.
+    pub fn rendering_cache_statistics(&self) -> crate::graphics::RenderingCacheStatistics {
+        self.0.window_adapter().renderer().rendering_cache_statistics()
+    }
+
     /// This function allows registering a callback that's invoked when the user tries to close a window.
     /// The callback has to return a [CloseRequestResponse].
     pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse + 'static) {
@@ -606,6 +623,20 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                     ..Default::default()
                 })
             }
+            crate::platform::WindowEvent::KeyInput(key) => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    text: key.text,
+                    key_code: key.key_code,
+                    scan_code: key.scan_code,
+                    repeat: key.repeat && !key.released,
+                    event_type: if key.released {
+                        KeyEventType::KeyReleased
+                    } else {
+                        KeyEventType::KeyPressed
+                    },
+                    ..Default::default()
+                })
+            }
             crate::platform::WindowEvent::ScaleFactorChanged { scale_factor } => {
                 self.0.set_scale_factor(scale_factor);
             }
@@ -623,6 +654,33 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.process_external_drag(
+                    Some(position.to_euclid().cast()),
+                    &crate::items::DropEvent { mime_type, data },
+                );
+            }
+            crate::platform::WindowEvent::DragExited => {
+                self.0.process_external_drag(None, &Default::default());
+            }
+            crate::platform::WindowEvent::Dropped { position, mime_type, data } => {
+                self.0.process_external_drop(
+                    position.to_euclid().cast(),
+                    crate::items::DropEvent { mime_type, data },
+                );
+            }
+            crate::platform::WindowEvent::TouchPressed { id, position } => {
+                self.0.process_touch_input(id, TouchPhase::Pressed(position.to_euclid().cast()))
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                self.0.process_touch_input(id, TouchPhase::Moved(position.to_euclid().cast()))
+            }
+            crate::platform::WindowEvent::TouchReleased { id, position } => {
+                self.0.process_touch_input(id, TouchPhase::Released(position.to_euclid().cast()))
+            }
+            crate::platform::WindowEvent::TouchCancelled { id } => {
+                self.0.process_touch_input(id, TouchPhase::Cancelled)
+            }
         }
     }
 
@@ -666,6 +724,51 @@ pub fn window_handle(&self) -> WindowHandle {
     pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.0.window_adapter().renderer().take_snapshot()
     }
+
+    /// Renders the current contents of the window into a new RGBA8 encoded pixel buffer.
+    ///
+    /// Unlike [`Self::take_snapshot()`], this function doesn't read back what was presented on
+    /// the screen, but renders the scene off-screen. This works also when the window was never
+    /// shown, as long as it has a non-zero size. This is useful for visual regression tests
+    /// or to generate thumbnails.
+    ///
+    /// Returns an error if the window's size is empty or if the renderer doesn't support
+    /// rendering to an image.
+    pub fn render_to_image(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        self.0.window_adapter().renderer().render_to_image()
+    }
+
+    /// Registers a TrueType or OpenType font from the given data, for use with the
+    /// `font-family` property. This is useful for fonts that are embedded in the binary
+    /// of the application, or downloaded at run-time.
+    ///
+    /// The data is copied, so the slice doesn't need to outlive this call. The layout of
+    /// text elements that already show text isn't re-computed, so register fonts before
+    /// showing the text that uses them.
+    ///
+    /// Returns an error if the data isn't a valid font, or if the renderer doesn't support
+    /// registering fonts.
+    #[cfg(feature = "std")]
+    pub fn register_font_from_memory(&self, data: &[u8]) -> Result<(), PlatformError> {
+        self.0
+            .window_adapter()
+            .renderer()
+            .register_font_from_memory(alloc::borrow::Cow::Owned(data.to_vec()))
+            .map_err(|err| PlatformError::Other(err.to_string()))?;
+        self.request_redraw();
+        Ok(())
+    }
+
+    /// Returns the font families installed on the system and registered with the application,
+    /// with the weights and styles of their faces. Use this to present a font picker, and use
+    /// the name of the selected family with the `font-family` property.
+    ///
+    /// The families are sorted by name. Returns an empty list if the renderer doesn't support
+    /// enumerating fonts, for example if it only uses fonts embedded at compile time.
+    #[cfg(feature = "std")]
+    pub fn font_families(&self) -> Vec<crate::graphics::FontFamily> {
+        self.0.window_adapter().renderer().font_families()
+    }
 }
 
 pub use crate::SharedString;
@@ -950,6 +1053,185 @@ pub fn quit_event_loop() -> Result<(), EventLoopError> {
         .quit_event_loop()
 }
 
+#[cfg(feature = "binding-profiler")]
+pub use crate::properties::binding_profiler::{
+    start_binding_profiling, stop_binding_profiling, BindingProfile, BindingProfileEntry,
+};
+
+pub use crate::animations::{
+    animation_duration_factor, animations_paused, set_animation_duration_factor,
+    set_animations_paused, AnimationControl, EasingCurve,
+};
+
+#[cfg(feature = "std")]
+pub use crate::graphics::{PathMeasure, PathPoint};
+
+/// The parameters of an animation, to animate a property to a value that is set from native code.
+///
+/// ```
+/// # use i_slint_core::api::{Animation, EasingCurve};
+/// let animation = Animation::new(core::time::Duration::from_millis(250))
+///     .with_easing(EasingCurve::CubicBezier([0.42, 0.0, 0.58, 1.0]));
+/// assert_eq!(animation.duration.as_millis(), 250);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct Animation {
+    /// The time it takes for the animation to complete.
+    pub duration: core::time::Duration,
+    /// The time to wait before starting the animation.
+    pub delay: core::time::Duration,
+    /// The number of times the animation runs. A negative value repeats the animation forever.
+    pub iteration_count: f32,
+    /// The easing curve of the animation.
+    pub easing: EasingCurve,
+}
+
+impl Animation {
+    /// Creates a linear animation with the given duration, without delay, that runs once.
+    pub fn new(duration: core::time::Duration) -> Self {
+        Self {
+            duration,
+            delay: Default::default(),
+            iteration_count: 1.,
+            easing: Default::default(),
+        }
+    }
+
+    /// Returns the animation with the given delay.
+    pub fn with_delay(self, delay: core::time::Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Returns the animation with the given number of iterations.
+    pub fn with_iteration_count(self, iteration_count: f32) -> Self {
+        Self { iteration_count, ..self }
+    }
+
+    /// Returns the animation with the given easing curve.
+    pub fn with_easing(self, easing: EasingCurve) -> Self {
+        Self { easing, ..self }
+    }
+}
+
+/// A handler that is run when properties change, as long as this subscription is kept alive.
+///
+/// It is returned by the `on_<property>_changed()` functions of the generated code, and by the
+/// `on_changed()` function generated by the `SyncProperties` derive macro.
+/// The handler is invoked from the event loop after the properties changed, and is unregistered
+/// when the subscription is dropped.
+#[must_use = "the handler is unregistered when the subscription is dropped"]
+pub struct PropertyChangeSubscription(crate::properties::ChangeTracker);
+
+impl PropertyChangeSubscription {
+    /// Creates a subscription that calls `notify_fn` when the value returned by `eval_fn` changes.
+    /// Any property read in `eval_fn` is tracked.
+    #[doc(hidden)]
+    pub fn new<Data: 'static, T: Default + PartialEq + 'static>(
+        data: Data,
+        eval_fn: impl Fn(&Data) -> T + 'static,
+        notify_fn: impl Fn(&Data, &T) + 'static,
+    ) -> Self {
+        let tracker = crate::properties::ChangeTracker::default();
+        tracker.init(data, eval_fn, notify_fn);
+        Self(tracker)
+    }
+}
+
+impl core::fmt::Debug for PropertyChangeSubscription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PropertyChangeSubscription").finish_non_exhaustive()
+    }
+}
+
+/// Runs a closure that reads properties, and reports when any of the properties it read changes.
+///
+/// Properties are read through the getters of components, or with functions such as
+/// [`Window::size()`]. This is useful for custom backends and integrations that cache
+/// results computed from properties, for example to know when they need to render again.
+///
+/// ```
+/// slint::slint! { export component MyApp { in property <int> foo; } }
+/// # i_slint_backend_testing::init_no_event_loop();
+/// let app = MyApp::new().unwrap();
+/// let tracker = slint::PropertyTracker::new();
+/// assert!(tracker.is_dirty());
+/// assert_eq!(tracker.evaluate(|| app.get_foo()), 0);
+/// assert!(!tracker.is_dirty());
+/// app.set_foo(42);
+/// assert!(tracker.is_dirty());
+/// assert_eq!(tracker.evaluate_if_dirty(|| app.get_foo()), Some(42));
+/// assert_eq!(tracker.evaluate_if_dirty(|| app.get_foo()), None);
+/// ```
+pub struct PropertyTracker(core::pin::Pin<Box<crate::properties::PropertyTracker<Box<dyn Fn()>>>>);
+
+impl PropertyTracker {
+    /// Creates a new tracker. It is dirty until [`Self::evaluate()`] is called for the first time.
+    pub fn new() -> Self {
+        Self::new_with_dirty_handler(|| ())
+    }
+
+    /// Creates a new tracker that calls `handler` when it becomes dirty after an evaluation.
+    ///
+    /// The handler is called immediately when one of the properties is changed, while the
+    /// properties are locked. It must not read or write any property; use it to schedule work
+    /// for later, for example to request a redraw.
+    pub fn new_with_dirty_handler(handler: impl Fn() + 'static) -> Self {
+        Self(Box::pin(crate::properties::PropertyTracker::new_with_dirty_handler(
+            Box::new(handler) as Box<dyn Fn()>,
+        )))
+    }
+
+    /// Calls `f` and records the properties that it reads, replacing the ones recorded by the
+    /// previous evaluation. The tracker is no longer dirty after this call.
+    ///
+    /// If this is called while a binding or another tracker is being evaluated, that binding
+    /// or tracker also becomes dirty when this tracker becomes dirty.
+    pub fn evaluate<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.0.as_ref().evaluate(f)
+    }
+
+    /// Calls [`Self::evaluate()`] if the tracker is dirty, and returns `None` otherwise.
+    pub fn evaluate_if_dirty<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+        self.0.as_ref().evaluate_if_dirty(f)
+    }
+
+    /// Returns true if one of the properties read during the last evaluation changed since then,
+    /// or if the tracker was never evaluated.
+    pub fn is_dirty(&self) -> bool {
+        self.0.is_dirty()
+    }
+
+    /// Marks the tracker as dirty, as well as the bindings and trackers that depend on it.
+    pub fn set_dirty(&self) {
+        self.0.set_dirty()
+    }
+}
+
+impl Default for PropertyTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for PropertyTracker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PropertyTracker").field("dirty", &self.is_dirty()).finish()
+    }
+}
+
+impl From<Animation> for crate::items::PropertyAnimation {
+    fn from(animation: Animation) -> Self {
+        Self {
+            delay: animation.delay.as_millis() as _,
+            duration: animation.duration.as_millis() as _,
+            iteration_count: animation.iteration_count,
+            easing: animation.easing,
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[non_exhaustive]
 /// Error returned from the [`invoke_from_event_loop()`] and [`quit_event_loop()`] function
@@ -21,6 +21,9 @@ pub(crate) struct SlintContextInner {
     /// This property is read by all translations, and marked dirty when the language change
     /// so that every translated string gets re-translated
     pub(crate) translations_dirty: core::pin::Pin<Box<Property<()>>>,
+    /// This property is read by the bindings that query the system settings, and marked dirty
+    /// by [`crate::platform::system_settings_changed()`]
+    pub(crate) system_settings_dirty: core::pin::Pin<Box<Property<()>>>,
     pub(crate) window_shown_hook:
         core::cell::RefCell<Option<Box<dyn FnMut(&Rc<dyn crate::platform::WindowAdapter>)>>>,
 }
@@ -38,6 +41,10 @@ pub fn new(platform: Box<dyn Platform + 'static>) -> Self {
             platform,
             window_count: 0.into(),
             translations_dirty: Box::pin(Property::new_named((), "SlintContext::translations")),
+            system_settings_dirty: Box::pin(Property::new_named(
+                (),
+                "SlintContext::system_settings",
+            )),
             window_shown_hook: Default::default(),
         }))
     }
@@ -93,3 +100,36 @@ pub fn set_window_shown_hook(
         None => Err(PlatformError::NoPlatform),
     })
 }
+
+fn with_system_settings(f: impl FnOnce(&dyn Platform) -> bool) -> bool {
+    GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return false };
+        ctx.0.system_settings_dirty.as_ref().get();
+        f(ctx.platform())
+    })
+}
+
+/// Internal function returning [`Platform::prefers_reduced_motion()`], re-evaluated
+/// when [`crate::platform::system_settings_changed()`] is called.
+pub fn prefers_reduced_motion() -> bool {
+    with_system_settings(|platform| platform.prefers_reduced_motion())
+}
+
+/// Internal function returning [`Platform::prefers_high_contrast()`], re-evaluated
+/// when [`crate::platform::system_settings_changed()`] is called.
+pub fn prefers_high_contrast() -> bool {
+    with_system_settings(|platform| platform.prefers_high_contrast())
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #[no_mangle]
+    pub extern "C" fn slint_prefers_reduced_motion() -> bool {
+        super::prefers_reduced_motion()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_prefers_high_contrast() -> bool {
+        super::prefers_high_contrast()
+    }
+}
@@ -78,32 +78,105 @@ pub fn new(update_fn: impl FnOnce() -> T) -> Self {
     }
 }
 
+/// The memory budget of a [`RenderingCache`]. When inserting an entry would exceed the budget, the
+/// least recently used entries are evicted from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RenderingCacheBudget {
+    /// The cache grows without limits.
+    #[default]
+    Unlimited,
+    /// The cache holds at most the given number of entries.
+    MaxEntries(usize),
+    /// The sum of the sizes of all entries, as specified with [`RenderingCache::insert_with_size`],
+    /// doesn't exceed the given number of bytes.
+    MaxBytes(usize),
+}
+
+/// Statistics about the usage of a [`RenderingCache`], for diagnostic purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct RenderingCacheStatistics {
+    /// The number of entries currently in the cache.
+    pub entries: usize,
+    /// The sum of the sizes of all entries currently in the cache, in bytes.
+    pub size_in_bytes: usize,
+    /// The number of entries that were evicted because of the budget since the creation of the cache.
+    pub evicted_entries: usize,
+}
+
+struct RenderingCacheEntry<T> {
+    data: CachedGraphicsData<T>,
+    /// The generation of the cache at the time the entry was inserted.
+    generation: usize,
+    size_in_bytes: usize,
+    /// The index of the entry that was used before this one, in the list of entries ordered by last use.
+    less_recently_used: Option<usize>,
+    /// The index of the entry that was used after this one, in the list of entries ordered by last use.
+    more_recently_used: Option<usize>,
+}
+
 /// The RenderingCache, in combination with CachedGraphicsData, allows back ends to store data that's either
 /// intensive to compute or has bad CPU locality. Back ends typically keep a RenderingCache instance and use
 /// the item's cached_rendering_data() integer as index in the vec_arena::Arena.
 ///
-/// This is used only for the [`crate::item_rendering::PartialRenderingCache`]
+/// The cache can be limited with a [`RenderingCacheBudget`], in which case the least recently used entries
+/// are evicted when inserting new entries. Back ends can use [`Self::set_eviction_callback`] to free the
+/// resources associated with evicted entries.
+///
+/// This is used for the [`crate::item_rendering::PartialRenderingCache`] and as storage of the
+/// [`crate::item_rendering::ItemCache`].
 pub struct RenderingCache<T> {
-    slab: slab::Slab<CachedGraphicsData<T>>,
+    slab: slab::Slab<RenderingCacheEntry<T>>,
     generation: usize,
+    budget: RenderingCacheBudget,
+    eviction_callback: Option<Box<dyn FnMut(T)>>,
+    /// The index of the least recently used entry, which is the next one to be evicted.
+    least_recently_used: Option<usize>,
+    /// The index of the most recently used entry.
+    most_recently_used: Option<usize>,
+    size_in_bytes: usize,
+    evicted_entries: usize,
 }
 
 impl<T> Default for RenderingCache<T> {
     fn default() -> Self {
-        Self { slab: Default::default(), generation: 1 }
+        Self {
+            slab: Default::default(),
+            generation: 1,
+            budget: Default::default(),
+            eviction_callback: None,
+            least_recently_used: None,
+            most_recently_used: None,
+            size_in_bytes: 0,
+            evicted_entries: 0,
+        }
     }
 }
 
 impl<T> RenderingCache<T> {
     /// Returns the generation of the cache. The generation starts at 1 and is increased
-    /// whenever the cache is cleared, for example when the GL context is lost.
+    /// whenever the cache is cleared, for example when the GL context is lost, or when entries
+    /// were evicted. Entries inserted into the cache are associated with the current generation.
     pub fn generation(&self) -> usize {
         self.generation
     }
 
-    /// Retrieves a mutable reference to the cached graphics data at index.
+    /// Returns true if a cache entry exists for the given index and it was inserted while the
+    /// cache had the given generation.
+    pub fn is_valid(&self, index: usize, generation: usize) -> bool {
+        self.slab.get(index).map_or(false, |entry| entry.generation == generation)
+    }
+
+    /// Retrieves a mutable reference to the cached graphics data at index and marks the entry
+    /// as recently used.
     pub fn get_mut(&mut self, index: usize) -> Option<&mut CachedGraphicsData<T>> {
-        self.slab.get_mut(index)
+        if !self.slab.contains(index) {
+            return None;
+        }
+        self.unlink(index);
+        self.link_as_most_recently_used(index);
+        Some(&mut self.slab[index].data)
     }
 
     /// Returns true if a cache entry exists for the given index.
@@ -113,26 +186,213 @@ pub fn contains(&self, index: usize) -> bool {
 
     /// Inserts data into the cache and returns the index for retrieval later.
     pub fn insert(&mut self, data: CachedGraphicsData<T>) -> usize {
-        self.slab.insert(data)
+        self.insert_with_size(data, 0)
+    }
+
+    /// Inserts data into the cache, that occupies `size_in_bytes` of memory, and returns
+    /// the index for retrieval later. If the entry doesn't fit into the budget of the cache, the least
+    /// recently used entries are evicted first.
+    pub fn insert_with_size(&mut self, data: CachedGraphicsData<T>, size_in_bytes: usize) -> usize {
+        self.evict_to_fit(1, size_in_bytes);
+        self.size_in_bytes += size_in_bytes;
+        let index = self.slab.insert(RenderingCacheEntry {
+            data,
+            generation: self.generation,
+            size_in_bytes,
+            less_recently_used: None,
+            more_recently_used: None,
+        });
+        self.link_as_most_recently_used(index);
+        index
     }
 
     /// Retrieves an immutable reference to the cached graphics data at index.
     pub fn get(&self, index: usize) -> Option<&CachedGraphicsData<T>> {
-        self.slab.get(index)
+        self.slab.get(index).map(|entry| &entry.data)
     }
 
     /// Removes the cached graphics data at the given index.
     pub fn remove(&mut self, index: usize) -> CachedGraphicsData<T> {
-        self.slab.remove(index)
+        self.unlink(index);
+        let entry = self.slab.remove(index);
+        self.size_in_bytes -= entry.size_in_bytes;
+        entry.data
     }
 
     /// Removes all entries from the cache and increases the cache's generation count, so
     /// that stale index access can be avoided.
     pub fn clear(&mut self) {
         self.slab.clear();
+        self.least_recently_used = None;
+        self.most_recently_used = None;
+        self.size_in_bytes = 0;
         self.generation += 1;
     }
+
+    /// Returns the budget of the cache.
+    pub fn budget(&self) -> RenderingCacheBudget {
+        self.budget
+    }
+
+    /// Sets the budget of the cache and evicts the least recently used entries that exceed the
+    /// new budget.
+    pub fn set_budget(&mut self, budget: RenderingCacheBudget) {
+        self.budget = budget;
+        self.evict_to_fit(0, 0);
+    }
+
+    /// Sets the callback that's invoked with the data of every entry that's evicted from the cache
+    /// because of the budget. Entries that are removed with [`Self::remove`] or [`Self::clear`] are not
+    /// passed to the callback.
+    pub fn set_eviction_callback(&mut self, callback: impl FnMut(T) + 'static) {
+        self.eviction_callback = Some(Box::new(callback));
+    }
+
+    /// Returns statistics about the current usage of the cache.
+    pub fn statistics(&self) -> RenderingCacheStatistics {
+        RenderingCacheStatistics {
+            entries: self.slab.len(),
+            size_in_bytes: self.size_in_bytes,
+            evicted_entries: self.evicted_entries,
+        }
+    }
+
+    /// Evicts the least recently used entries until `additional_entries` that occupy
+    /// `additional_bytes` fit into the budget.
+    fn evict_to_fit(&mut self, additional_entries: usize, additional_bytes: usize) {
+        let fits = |this: &Self| match this.budget {
+            RenderingCacheBudget::Unlimited => true,
+            RenderingCacheBudget::MaxEntries(max) => this.slab.len() + additional_entries <= max,
+            RenderingCacheBudget::MaxBytes(max) => this.size_in_bytes + additional_bytes <= max,
+        };
+
+        let mut evicted_any = false;
+        while !fits(self) {
+            let Some(index) = self.least_recently_used else {
+                break;
+            };
+            let data = self.remove(index).data;
+            self.evicted_entries += 1;
+            evicted_any = true;
+            if let Some(callback) = self.eviction_callback.as_mut() {
+                callback(data);
+            }
+        }
+
+        // The indices of evicted entries may be re-used, so make sure that the items that referred
+        // to them don't see the new entries.
+        if evicted_any {
+            self.generation += 1;
+        }
+    }
+
+    /// Removes the entry at `index` from the list of entries ordered by last use.
+    fn unlink(&mut self, index: usize) {
+        let entry = &mut self.slab[index];
+        let (less, more) = (entry.less_recently_used.take(), entry.more_recently_used.take());
+        match less {
+            Some(less) => self.slab[less].more_recently_used = more,
+            None => self.least_recently_used = more,
+        }
+        match more {
+            Some(more) => self.slab[more].less_recently_used = less,
+            None => self.most_recently_used = less,
+        }
+    }
+
+    /// Appends the unlinked entry at `index` to the end of the list of entries ordered by last use.
+    fn link_as_most_recently_used(&mut self, index: usize) {
+        self.slab[index].less_recently_used = self.most_recently_used;
+        match self.most_recently_used {
+            Some(previous) => self.slab[previous].more_recently_used = Some(index),
+            None => self.least_recently_used = Some(index),
+        }
+        self.most_recently_used = Some(index);
+    }
+}
+
+#[test]
+fn test_rendering_cache_budget() {
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    let evicted = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+    let mut cache = RenderingCache::default();
+    cache.set_eviction_callback({
+        let evicted = evicted.clone();
+        move |data| evicted.borrow_mut().push(data)
+    });
+    cache.set_budget(RenderingCacheBudget::MaxEntries(2));
+
+    let first_generation = cache.generation();
+    let a = cache.insert(CachedGraphicsData::new(|| 'a'));
+    let b = cache.insert(CachedGraphicsData::new(|| 'b'));
+    // Using `a` makes `b` the least recently used entry
+    assert_eq!(cache.get_mut(a).unwrap().data, 'a');
+    let c = cache.insert(CachedGraphicsData::new(|| 'c'));
+    assert_eq!(*evicted.borrow(), ['b']);
+    assert!(cache.is_valid(a, first_generation));
+    assert!(!cache.is_valid(b, first_generation));
+    assert!(cache.is_valid(c, cache.generation()));
+    assert_eq!(
+        cache.statistics(),
+        RenderingCacheStatistics { entries: 2, size_in_bytes: 0, evicted_entries: 1 }
+    );
+
+    cache.set_budget(RenderingCacheBudget::MaxBytes(100));
+    let d = cache.insert_with_size(CachedGraphicsData::new(|| 'd'), 60);
+    let d_generation = cache.generation();
+    assert_eq!(cache.statistics().size_in_bytes, 60);
+    // The least recently used entries are evicted until `e` fits
+    cache.insert_with_size(CachedGraphicsData::new(|| 'e'), 50);
+    assert_eq!(*evicted.borrow(), ['b', 'a', 'c', 'd']);
+    assert!(!cache.is_valid(d, d_generation));
+    assert_eq!(
+        cache.statistics(),
+        RenderingCacheStatistics { entries: 1, size_in_bytes: 50, evicted_entries: 4 }
+    );
+}
+
+#[test]
+fn test_rendering_cache_lru_order() {
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    let evicted = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+    let mut cache = RenderingCache::default();
+    cache.set_eviction_callback({
+        let evicted = evicted.clone();
+        move |data| evicted.borrow_mut().push(data)
+    });
+    cache.set_budget(RenderingCacheBudget::MaxEntries(3));
+
+    let a = cache.insert(CachedGraphicsData::new(|| 'a'));
+    let b = cache.insert(CachedGraphicsData::new(|| 'b'));
+    cache.insert(CachedGraphicsData::new(|| 'c'));
+    // The order of use is now c, a, b
+    cache.get_mut(a);
+    cache.get_mut(b);
+    let d = cache.insert(CachedGraphicsData::new(|| 'd'));
+    assert_eq!(*evicted.borrow(), ['c']);
+    // Removing an entry from the middle of the list keeps the order of the other entries
+    assert_eq!(cache.remove(a).data, 'a');
+    cache.insert(CachedGraphicsData::new(|| 'e'));
+    assert_eq!(*evicted.borrow(), ['c']);
+    cache.insert(CachedGraphicsData::new(|| 'f'));
+    assert_eq!(*evicted.borrow(), ['c', 'b']);
+    cache.get_mut(d);
+    cache.insert(CachedGraphicsData::new(|| 'g'));
+    assert_eq!(*evicted.borrow(), ['c', 'b', 'e']);
+
+    cache.clear();
+    assert_eq!(cache.statistics().entries, 0);
+    cache.insert(CachedGraphicsData::new(|| 'h'));
+    cache.insert(CachedGraphicsData::new(|| 'i'));
+    cache.insert(CachedGraphicsData::new(|| 'j'));
+    cache.insert(CachedGraphicsData::new(|| 'k'));
+    assert_eq!(*evicted.borrow(), ['c', 'b', 'e', 'h']);
 }
+
 /// FontRequest collects all the developer-configurable properties for fonts, such as family, weight, etc.
 /// It is submitted as a request to the platform font system (i.e. CoreText on macOS) and in exchange the
 /// backend returns a `Box<dyn Font>`.
@@ -148,18 +408,178 @@ pub struct FontRequest {
     /// The additional spacing (or shrinking if negative) between glyphs. This is usually not submitted to
     /// the font-subsystem but collected here for API convenience
     pub letter_spacing: Option<LogicalLength>,
-    /// Whether to select an italic face of the font family.
-    pub italic: bool,
+    /// The additional spacing (or shrinking if negative) after the spaces between words. Like
+    /// `letter_spacing`, this is usually applied by the text layout and not by the font-subsystem.
+    pub word_spacing: Option<LogicalLength>,
+    /// The distance between the tab stops that follow `tab_stops`. If None or zero, the tab stops
+    /// are eight spaces apart.
+    pub tab_width: Option<LogicalLength>,
+    /// The positions of the tab stops from the start of the line, in increasing order.
+    pub tab_stops: crate::SharedVector<LogicalLength>,
+    /// The distance between the tops of consecutive lines. If None or zero, it is the height
+    /// of the font multiplied by `line_height_factor`.
+    pub line_height: Option<LogicalLength>,
+    /// The factor by which the height of the font is multiplied for the distance between lines,
+    /// when there is no `line_height`. If None, it is 1.
+    pub line_height_factor: Option<f32>,
+    /// The additional distance between paragraphs, after the line breaks in the text.
+    pub paragraph_spacing: Option<LogicalLength>,
+    /// Whether to select an italic or oblique face of the font family.
+    pub style: FontStyle,
+    /// Whether to select a narrower or wider face of the font family.
+    pub stretch: crate::items::FontStretch,
+    /// Font families to look up glyphs in when they are missing in `family`, in order of preference,
+    /// before falling back to the fonts selected by the system.
+    pub fallback_families: crate::SharedVector<SharedString>,
+}
+
+/// The slant of the font face selected by a [`FontRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FontStyle {
+    /// An upright face.
+    #[default]
+    Normal,
+    /// A face designed with slanted, cursive glyphs.
+    Italic,
+    /// A slanted version of the upright face.
+    Oblique,
+}
+
+/// A face of a [`FontFamily`] that is available to the renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontFace {
+    /// The weight of the face, from 100 (thin) to 900 (black), where 400 is the normal weight.
+    pub weight: i32,
+    /// The slant of the face.
+    pub style: FontStyle,
+}
+
+/// A font family that is installed on the system or was registered with the application,
+/// as returned by [`Window::font_families()`](crate::api::Window::font_families).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontFamily {
+    /// The name of the family, to be used with the `font-family` property.
+    pub name: SharedString,
+    /// The faces of the family, sorted by style and weight.
+    pub faces: alloc::vec::Vec<FontFace>,
+}
+
+impl FontFamily {
+    /// Groups the faces by family name, for the implementation of
+    /// [`Renderer::font_families()`](crate::renderer::Renderer::font_families).
+    /// The returned families are sorted by name, and their faces by style and weight.
+    #[doc(hidden)]
+    pub fn collect_families(
+        faces: impl IntoIterator<Item = (SharedString, FontFace)>,
+    ) -> alloc::vec::Vec<FontFamily> {
+        let mut families: alloc::collections::BTreeMap<SharedString, alloc::vec::Vec<FontFace>> =
+            Default::default();
+        for (name, face) in faces {
+            if !name.is_empty() {
+                families.entry(name).or_default().push(face);
+            }
+        }
+        families
+            .into_iter()
+            .map(|(name, mut faces)| {
+                faces.sort_by_key(|face| (face.style, face.weight));
+                faces.dedup();
+                FontFamily { name, faces }
+            })
+            .collect()
+    }
+}
+
+impl FontRequest {
+    /// Returns the distance between the tops of consecutive lines in physical pixels, for a font
+    /// with the given height in physical pixels. Returns None if it is the height of the font.
+    pub fn physical_line_height(&self, font_height: f32, scale_factor: f32) -> Option<f32> {
+        self.line_height
+            .filter(|line_height| line_height.get() > 0 as Coord)
+            .map(|line_height| line_height.get() as f32 * scale_factor)
+            .or_else(|| {
+                self.line_height_factor
+                    .filter(|factor| *factor > 0. && *factor != 1.)
+                    .map(|factor| font_height * factor)
+            })
+    }
+
+    /// Returns the additional distance between paragraphs in physical pixels, or None if there is none.
+    pub fn physical_paragraph_spacing(&self, scale_factor: f32) -> Option<f32> {
+        self.paragraph_spacing
+            .filter(|spacing| spacing.get() != 0 as Coord)
+            .map(|spacing| spacing.get() as f32 * scale_factor)
+    }
+
+    /// Moves all but the first family of a comma separated list of families in `family`,
+    /// such as `"Inter, Noto Sans CJK JP, Noto Emoji"`, to the front of `fallback_families`.
+    pub fn split_family_list(&mut self) {
+        if !self.family.as_ref().is_some_and(|family| family.contains(',')) {
+            return;
+        }
+        let Some(family_list) = self.family.take() else { return };
+        let mut families =
+            family_list.split(',').map(str::trim).filter(|family| !family.is_empty());
+        self.family = families.next().map(SharedString::from);
+        let fallback_families = families
+            .map(SharedString::from)
+            .chain(self.fallback_families.iter().cloned())
+            .collect();
+        self.fallback_families = fallback_families;
+    }
+}
+
+#[cfg(feature = "shared-fontdb")]
+impl FontFamily {
+    /// Returns the families of the fonts in the shared font database.
+    #[doc(hidden)]
+    pub fn from_shared_fontdb() -> alloc::vec::Vec<FontFamily> {
+        use i_slint_common::sharedfontdb::fontdb::Style;
+        i_slint_common::sharedfontdb::FONT_DB.with(|db| {
+            let db = db.borrow();
+            // The first family name is the English one, the others are localized names
+            Self::collect_families(db.faces().filter_map(|face_info| {
+                let face = FontFace {
+                    weight: face_info.weight.0 as i32,
+                    style: match face_info.style {
+                        Style::Normal => FontStyle::Normal,
+                        Style::Italic => FontStyle::Italic,
+                        Style::Oblique => FontStyle::Oblique,
+                    },
+                };
+                face_info
+                    .families
+                    .first()
+                    .map(|(name, _)| (SharedString::from(name.as_str()), face))
+            }))
+        })
+    }
 }
 
 #[cfg(feature = "shared-fontdb")]
 impl FontRequest {
     /// Returns the relevant properties of this FontRequest propagated into a fontdb Query.
     pub fn to_fontdb_query(&self) -> i_slint_common::sharedfontdb::fontdb::Query<'_> {
-        use i_slint_common::sharedfontdb::fontdb::{Query, Style, Weight};
+        use crate::items::FontStretch;
+        use i_slint_common::sharedfontdb::fontdb::{Query, Stretch, Style, Weight};
         Query {
-            style: if self.italic { Style::Italic } else { Style::Normal },
+            style: match self.style {
+                FontStyle::Normal => Style::Normal,
+                FontStyle::Italic => Style::Italic,
+                FontStyle::Oblique => Style::Oblique,
+            },
             weight: Weight(self.weight.unwrap_or(/* CSS normal*/ 400) as _),
+            stretch: match self.stretch {
+                FontStretch::Normal => Stretch::Normal,
+                FontStretch::UltraCondensed => Stretch::UltraCondensed,
+                FontStretch::ExtraCondensed => Stretch::ExtraCondensed,
+                FontStretch::Condensed => Stretch::Condensed,
+                FontStretch::SemiCondensed => Stretch::SemiCondensed,
+                FontStretch::SemiExpanded => Stretch::SemiExpanded,
+                FontStretch::Expanded => Stretch::Expanded,
+                FontStretch::ExtraExpanded => Stretch::ExtraExpanded,
+                FontStretch::UltraExpanded => Stretch::UltraExpanded,
+            },
             ..Default::default()
         }
     }
@@ -5,7 +5,8 @@
 This module contains brush related types for the run-time library.
 */
 
-use super::Color;
+use super::{Color, Image};
+use crate::items::{ColorInterpolation, PatternTiling};
 use crate::properties::InterpolatedPropertyValue;
 use crate::SharedVector;
 use euclid::default::{Point2D, Size2D};
@@ -26,9 +27,14 @@ pub enum Brush {
     /// The linear gradient variant of a brush describes the gradient stops for a fill
     /// where all color stops are along a line that's rotated by the specified angle.
     LinearGradient(LinearGradientBrush),
-    /// The radial gradient variant of a brush describes a circle variant centered
-    /// in the middle
+    /// The radial gradient variant of a brush describes a circle variant with
+    /// a center and a radius.
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops for a fill
+    /// where the colors are interpolated around a center point, starting at the specified angle.
+    ConicGradient(ConicGradientBrush),
+    /// The image pattern variant of a brush fills the shape by repeating an image.
+    ImagePattern(ImagePatternBrush),
 }
 
 /// Construct a brush with transparent color
@@ -41,6 +47,7 @@ fn default() -> Self {
 impl Brush {
     /// If the brush is SolidColor, the contained color is returned.
     /// If the brush is a LinearGradient, the color of the first stop is returned.
+    /// If the brush is an ImagePattern, a transparent color is returned.
     pub fn color(&self) -> Color {
         match self {
             Brush::SolidColor(col) => *col,
@@ -50,9 +57,43 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
+            Brush::ImagePattern(_) => Color::default(),
         }
     }
 
+    /// Returns the color stops of a gradient brush, sorted by position. For a
+    /// SolidColor or an ImagePattern, no stops are returned.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::*;
+    /// let brush = Brush::LinearGradient(LinearGradientBrush::new(
+    ///     90.,
+    ///     [
+    ///         GradientStop { color: Color::from_rgb_u8(255, 0, 0), position: 0. },
+    ///         GradientStop { color: Color::from_rgb_u8(0, 0, 255), position: 1. },
+    ///     ],
+    /// ));
+    /// let positions: Vec<f32> = brush.stops().map(|stop| stop.position).collect();
+    /// assert_eq!(positions, [0., 1.]);
+    /// assert_eq!(Brush::SolidColor(Color::from_rgb_u8(255, 0, 0)).stops().count(), 0);
+    /// ```
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        let (stops, fake_stop_count) = match self {
+            Brush::LinearGradient(gradient) => (gradient.0.as_slice(), 1),
+            Brush::RadialGradient(gradient) => {
+                (gradient.0.as_slice(), RADIAL_GRADIENT_FAKE_STOP_COUNT)
+            }
+            Brush::ConicGradient(gradient) => {
+                (gradient.0.as_slice(), CONIC_GRADIENT_FAKE_STOP_COUNT)
+            }
+            Brush::SolidColor(_) | Brush::ImagePattern(_) => (&[][..], 0),
+        };
+        stops.iter().skip(fake_stop_count)
+    }
+
     /// Returns true if this brush contains a fully transparent color (alpha value is zero)
     ///
     /// ```
@@ -66,6 +107,8 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
+            Brush::ImagePattern(_) => false,
         }
     }
 
@@ -82,6 +125,8 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ImagePattern(_) => false,
         }
     }
 
@@ -99,11 +144,15 @@ pub fn brighter(&self, factor: f32) -> Self {
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.brighter(factor), position: s.position }
-                })))
-            }
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.brighter(factor),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.brighter(factor),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
         }
     }
 
@@ -119,10 +168,67 @@ pub fn darker(&self, factor: f32) -> Self {
                 g.stops()
                     .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
             )),
-            Brush::RadialGradient(g) => Brush::RadialGradient(RadialGradientBrush::new_circle(
-                g.stops()
-                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.darker(factor),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.darker(factor),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
+        }
+    }
+
+    /// Returns a new version of this brush that has the saturation increased
+    /// by the specified factor. This is done by calling [`Color::saturate`] on
+    /// all the colors of this brush.
+    #[must_use]
+    pub fn saturate(&self, factor: f32) -> Self {
+        match self {
+            Brush::SolidColor(c) => Brush::SolidColor(c.saturate(factor)),
+            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
+                g.angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.saturate(factor),
+                    position: s.position,
+                }),
+            )),
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.saturate(factor),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.saturate(factor),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
+        }
+    }
+
+    /// Returns a new version of this brush that has the saturation decreased
+    /// by the specified factor. This is done by calling [`Color::desaturate`] on
+    /// all the colors of this brush.
+    #[must_use]
+    pub fn desaturate(&self, factor: f32) -> Self {
+        match self {
+            Brush::SolidColor(c) => Brush::SolidColor(c.desaturate(factor)),
+            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
+                g.angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.desaturate(factor),
+                    position: s.position,
+                }),
             )),
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.desaturate(factor),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.desaturate(factor),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
         }
     }
 
@@ -142,11 +248,15 @@ pub fn transparentize(&self, amount: f32) -> Self {
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.transparentize(amount), position: s.position }
-                })))
-            }
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.transparentize(amount),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.transparentize(amount),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
         }
     }
 
@@ -163,11 +273,15 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.with_alpha(alpha), position: s.position }
-                })))
-            }
+            Brush::RadialGradient(g) => Brush::RadialGradient(g.map_stops(|s| GradientStop {
+                color: s.color.with_alpha(alpha),
+                position: s.position,
+            })),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_stops(|s| GradientStop {
+                color: s.color.with_alpha(alpha),
+                position: s.position,
+            })),
+            Brush::ImagePattern(p) => Brush::ImagePattern(p.clone()),
         }
     }
 }
@@ -204,20 +318,216 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
     }
 }
 
-/// The RadialGradientBrush describes a way of filling a shape with a circular gradient
+/// The RadialGradientBrush describes a way of filling a shape with a circular gradient.
+///
+/// The center of the circle is expressed relative to the size of the shape, where `(0.5, 0.5)`
+/// is the middle. The radius is expressed relative to the default radius, which is half the size
+/// of the shape.
 #[derive(Clone, PartialEq, Debug)]
 #[repr(transparent)]
 pub struct RadialGradientBrush(SharedVector<GradientStop>);
 
+/// The number of fake stops at the beginning of a [`RadialGradientBrush`] used to encode
+/// the center and the radius.
+const RADIAL_GRADIENT_FAKE_STOP_COUNT: usize = 3;
+
 impl RadialGradientBrush {
     /// Creates a new circle radial gradient, centered in the middle and described
     /// by the provided color stops.
     pub fn new_circle(stops: impl IntoIterator<Item = GradientStop>) -> Self {
-        Self(stops.into_iter().collect())
+        Self::new_circle_at(Point2D::new(0.5, 0.5), 1., stops)
     }
-    /// Returns the color stops of the linear gradient.
+
+    /// Creates a new circle radial gradient with the given center and radius, described by the
+    /// provided color stops.
+    ///
+    /// The `center` is relative to the size of the shape, so `(0.5, 0.5)` is the middle, and
+    /// the `radius` is relative to the default radius, so `1.0` is half the size of the shape.
+    pub fn new_circle_at(
+        center: Point2D<f32>,
+        radius: f32,
+        stops: impl IntoIterator<Item = GradientStop>,
+    ) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded =
+            SharedVector::with_capacity(stop_iter.size_hint().0 + RADIAL_GRADIENT_FAKE_STOP_COUNT);
+        // The gradient's first stops are fake stops to store the center and the radius
+        encoded.push(GradientStop { color: Default::default(), position: center.x });
+        encoded.push(GradientStop { color: Default::default(), position: center.y });
+        encoded.push(GradientStop { color: Default::default(), position: radius });
+        encoded.extend(stop_iter);
+        Self(encoded)
+    }
+
+    /// Returns the center of the circle, relative to the size of the shape.
+    pub fn center(&self) -> Point2D<f32> {
+        Point2D::new(self.0[0].position, self.0[1].position)
+    }
+
+    /// Returns the radius of the circle, relative to the default radius of half the size of
+    /// the shape.
+    pub fn radius(&self) -> f32 {
+        self.0[2].position
+    }
+
+    /// Returns the color stops of the radial gradient.
     pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
-        self.0.iter()
+        // skip the fake stops that just contain the center and radius
+        self.0.iter().skip(RADIAL_GRADIENT_FAKE_STOP_COUNT)
+    }
+
+    /// Returns the center and the radius of the circle in the coordinates of a shape of the given
+    /// size, with a default radius of `default_radius`.
+    pub fn circle_for_size(&self, size: Size2D<f32>, default_radius: f32) -> (Point2D<f32>, f32) {
+        let center = self.center();
+        (
+            Point2D::new(center.x * size.width, center.y * size.height),
+            self.radius() * default_radius,
+        )
+    }
+
+    fn map_stops(&self, f: impl Fn(&GradientStop) -> GradientStop) -> Self {
+        Self::new_circle_at(self.center(), self.radius(), self.stops().map(f))
+    }
+}
+
+/// The ConicGradientBrush describes a way of filling a shape with colors that are interpolated
+/// around a center point, like the hand of a clock.
+///
+/// The center is expressed relative to the size of the shape, where `(0.5, 0.5)` is the middle.
+/// The gradient starts at the given angle, measured in degrees clockwise from the top.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+/// The number of fake stops at the beginning of a [`ConicGradientBrush`] used to encode
+/// the start angle and the center.
+const CONIC_GRADIENT_FAKE_STOP_COUNT: usize = 3;
+
+impl ConicGradientBrush {
+    /// Creates a new conic gradient centered in the middle, starting at the specified angle
+    /// (in degrees) and described by the provided color stops.
+    pub fn new(from_angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        Self::new_at(from_angle, Point2D::new(0.5, 0.5), stops)
+    }
+
+    /// Creates a new conic gradient with the given center, starting at the specified angle
+    /// (in degrees) and described by the provided color stops.
+    ///
+    /// The `center` is relative to the size of the shape, so `(0.5, 0.5)` is the middle.
+    pub fn new_at(
+        from_angle: f32,
+        center: Point2D<f32>,
+        stops: impl IntoIterator<Item = GradientStop>,
+    ) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded =
+            SharedVector::with_capacity(stop_iter.size_hint().0 + CONIC_GRADIENT_FAKE_STOP_COUNT);
+        // The gradient's first stops are fake stops to store the angle and the center
+        encoded.push(GradientStop { color: Default::default(), position: from_angle });
+        encoded.push(GradientStop { color: Default::default(), position: center.x });
+        encoded.push(GradientStop { color: Default::default(), position: center.y });
+        encoded.extend(stop_iter);
+        Self(encoded)
+    }
+
+    /// Returns the angle, in degrees, at which the gradient starts.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+
+    /// Returns the center of the gradient, relative to the size of the shape.
+    pub fn center(&self) -> Point2D<f32> {
+        Point2D::new(self.0[1].position, self.0[2].position)
+    }
+
+    /// Returns the color stops of the conic gradient.
+    /// The position of the stops is a fraction of a full turn.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        // skip the fake stops that just contain the angle and the center
+        self.0.iter().skip(CONIC_GRADIENT_FAKE_STOP_COUNT)
+    }
+
+    /// Returns the color of the gradient at the given angle (in degrees, clockwise from the top).
+    ///
+    /// This can be used by renderers that don't support conic gradients natively.
+    pub fn color_at_angle(&self, angle: f32) -> Color {
+        let pos = (angle - self.from_angle()) / 360.;
+        let pos = pos - pos.floor();
+        let mut prev: Option<&GradientStop> = None;
+        for stop in self.stops() {
+            if pos <= stop.position {
+                return match prev {
+                    Some(prev) if stop.position > prev.position => prev.color.interpolate(
+                        &stop.color,
+                        (pos - prev.position) / (stop.position - prev.position),
+                    ),
+                    _ => stop.color,
+                };
+            }
+            prev = Some(stop);
+        }
+        prev.map(|s| s.color).unwrap_or_default()
+    }
+
+    fn map_stops(&self, f: impl Fn(&GradientStop) -> GradientStop) -> Self {
+        Self::new_at(self.from_angle(), self.center(), self.stops().map(f))
+    }
+}
+
+/// The ImagePatternBrush describes a way of filling a shape by repeating an image.
+///
+/// The tiles are aligned with the top-left corner of the shape, shifted by the pattern's
+/// offset. Each tile has the size of the image, multiplied by the pattern's scale.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct ImagePatternBrush {
+    image: Image,
+    tiling: PatternTiling,
+    offset_x: f32,
+    offset_y: f32,
+    scale: f32,
+}
+
+impl ImagePatternBrush {
+    /// Creates a new image pattern that repeats the given image according to `tiling`.
+    pub fn new(image: Image, tiling: PatternTiling) -> Self {
+        Self { image, tiling, offset_x: 0., offset_y: 0., scale: 1. }
+    }
+
+    /// Returns a copy of this pattern where the tiles are shifted by `offset` (in logical pixels)
+    /// and scaled by `scale`.
+    #[must_use]
+    pub fn with_transform(mut self, offset: Point2D<f32>, scale: f32) -> Self {
+        self.offset_x = offset.x;
+        self.offset_y = offset.y;
+        self.scale = scale;
+        self
+    }
+
+    /// Returns the image that is repeated by this pattern.
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the tiling mode of this pattern.
+    pub fn tiling(&self) -> PatternTiling {
+        self.tiling
+    }
+
+    /// Returns the offset, in logical pixels, of the first tile relative to the top-left corner of the shape.
+    pub fn offset(&self) -> Point2D<f32> {
+        Point2D::new(self.offset_x, self.offset_y)
+    }
+
+    /// Returns the factor by which the image is scaled.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the size of a single tile, in logical pixels.
+    pub fn tile_size(&self) -> Size2D<f32> {
+        self.image.size().cast::<f32>() * self.scale
     }
 }
 
@@ -265,23 +575,32 @@ pub fn line_for_angle(angle: f32, size: Size2D<f32>) -> (Point2D<f32>, Point2D<f
 
 impl InterpolatedPropertyValue for Brush {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+        self.interpolate_in_color_space(target_value, t, ColorInterpolation::Srgb)
+    }
+
+    fn interpolate_in_color_space(
+        &self,
+        target_value: &Self,
+        t: f32,
+        color_space: ColorInterpolation,
+    ) -> Self {
         match (self, target_value) {
             (Brush::SolidColor(source_col), Brush::SolidColor(target_col)) => {
-                Brush::SolidColor(source_col.interpolate(target_col, t))
+                Brush::SolidColor(source_col.interpolate_in_color_space(target_col, t, color_space))
             }
             (Brush::SolidColor(col), Brush::LinearGradient(grad)) => {
                 let mut new_grad = grad.clone();
                 for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
-                    x.color = col.interpolate(&x.color, t);
+                    x.color = col.interpolate_in_color_space(&x.color, t, color_space);
                 }
                 Brush::LinearGradient(new_grad)
             }
             (a @ Brush::LinearGradient(_), b @ Brush::SolidColor(_)) => {
-                Self::interpolate(b, a, 1. - t)
+                Self::interpolate_in_color_space(b, a, 1. - t, color_space)
             }
             (Brush::LinearGradient(lhs), Brush::LinearGradient(rhs)) => {
                 if lhs.0.len() < rhs.0.len() {
-                    Self::interpolate(target_value, self, 1. - t)
+                    Self::interpolate_in_color_space(target_value, self, 1. - t, color_space)
                 } else {
                     let mut new_grad = lhs.clone();
                     let mut iter = new_grad.0.make_mut_slice().iter_mut();
@@ -291,7 +610,7 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     }
                     for s2 in rhs.stops() {
                         let s1 = iter.next().unwrap();
-                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.color = s1.color.interpolate_in_color_space(&s2.color, t, color_space);
                         s1.position = s1.position.interpolate(&s2.position, t);
                     }
                     for x in iter {
@@ -301,49 +620,132 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                 }
             }
             (Brush::SolidColor(col), Brush::RadialGradient(grad)) => {
-                let mut new_grad = grad.clone();
-                for x in new_grad.0.make_mut_slice().iter_mut() {
-                    x.color = col.interpolate(&x.color, t);
-                }
-                Brush::RadialGradient(new_grad)
+                Brush::RadialGradient(RadialGradientBrush(interpolate_color_to_stops(
+                    col,
+                    &grad.0,
+                    RADIAL_GRADIENT_FAKE_STOP_COUNT,
+                    t,
+                    color_space,
+                )))
             }
             (a @ Brush::RadialGradient(_), b @ Brush::SolidColor(_)) => {
-                Self::interpolate(b, a, 1. - t)
+                Self::interpolate_in_color_space(b, a, 1. - t, color_space)
             }
             (Brush::RadialGradient(lhs), Brush::RadialGradient(rhs)) => {
                 if lhs.0.len() < rhs.0.len() {
-                    Self::interpolate(target_value, self, 1. - t)
+                    Self::interpolate_in_color_space(target_value, self, 1. - t, color_space)
                 } else {
-                    let mut new_grad = lhs.clone();
-                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
-                    let mut last_color = Color::default();
-                    for s2 in rhs.stops() {
-                        let s1 = iter.next().unwrap();
-                        last_color = s2.color;
-                        s1.color = s1.color.interpolate(&s2.color, t);
-                        s1.position = s1.position.interpolate(&s2.position, t);
-                    }
-                    for x in iter {
-                        x.position = x.position.interpolate(&1.0, t);
-                        x.color = x.color.interpolate(&last_color, t);
-                    }
-                    Brush::RadialGradient(new_grad)
+                    Brush::RadialGradient(RadialGradientBrush(interpolate_stops(
+                        &lhs.0,
+                        &rhs.0,
+                        RADIAL_GRADIENT_FAKE_STOP_COUNT,
+                        t,
+                        color_space,
+                    )))
+                }
+            }
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                Brush::ConicGradient(ConicGradientBrush(interpolate_color_to_stops(
+                    col,
+                    &grad.0,
+                    CONIC_GRADIENT_FAKE_STOP_COUNT,
+                    t,
+                    color_space,
+                )))
+            }
+            (a @ Brush::ConicGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate_in_color_space(b, a, 1. - t, color_space)
+            }
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate_in_color_space(target_value, self, 1. - t, color_space)
+                } else {
+                    Brush::ConicGradient(ConicGradientBrush(interpolate_stops(
+                        &lhs.0,
+                        &rhs.0,
+                        CONIC_GRADIENT_FAKE_STOP_COUNT,
+                        t,
+                        color_space,
+                    )))
+                }
+            }
+            (Brush::ImagePattern(_), _) | (_, Brush::ImagePattern(_)) => {
+                // Images can't be blended, so switch half-way through.
+                if t < 0.5 {
+                    self.clone()
+                } else {
+                    target_value.clone()
                 }
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
+            (a, b) => {
                 // Just go to an intermediate color.
-                let color = Color::interpolate(&b.color(), &a.color(), t);
+                let color =
+                    Color::interpolate_in_color_space(&b.color(), &a.color(), t, color_space);
                 if t < 0.5 {
-                    Self::interpolate(a, &Brush::SolidColor(color), t * 2.)
+                    Self::interpolate_in_color_space(
+                        a,
+                        &Brush::SolidColor(color),
+                        t * 2.,
+                        color_space,
+                    )
                 } else {
-                    Self::interpolate(&Brush::SolidColor(color), b, (t - 0.5) * 2.)
+                    Self::interpolate_in_color_space(
+                        &Brush::SolidColor(color),
+                        b,
+                        (t - 0.5) * 2.,
+                        color_space,
+                    )
                 }
             }
         }
     }
 }
 
+/// Interpolates the stops of a gradient from a solid color, leaving the first `fake_stop_count`
+/// stops untouched.
+fn interpolate_color_to_stops(
+    col: &Color,
+    stops: &SharedVector<GradientStop>,
+    fake_stop_count: usize,
+    t: f32,
+    color_space: ColorInterpolation,
+) -> SharedVector<GradientStop> {
+    let mut new_stops = stops.clone();
+    for x in new_stops.make_mut_slice().iter_mut().skip(fake_stop_count) {
+        x.color = col.interpolate_in_color_space(&x.color, t, color_space);
+    }
+    new_stops
+}
+
+/// Interpolates the stops of two gradients of the same kind, where `lhs` has at least as many
+/// stops as `rhs`. The first `fake_stop_count` stops only have their position interpolated.
+fn interpolate_stops(
+    lhs: &SharedVector<GradientStop>,
+    rhs: &SharedVector<GradientStop>,
+    fake_stop_count: usize,
+    t: f32,
+    color_space: ColorInterpolation,
+) -> SharedVector<GradientStop> {
+    let mut new_stops = lhs.clone();
+    let mut iter = new_stops.make_mut_slice().iter_mut();
+    for (fake, target) in iter.by_ref().take(fake_stop_count).zip(rhs.iter().take(fake_stop_count))
+    {
+        fake.position = fake.position.interpolate(&target.position, t);
+    }
+    let mut last_color = Color::default();
+    for s2 in rhs.iter().skip(fake_stop_count) {
+        let s1 = iter.next().unwrap();
+        last_color = s2.color;
+        s1.color = s1.color.interpolate_in_color_space(&s2.color, t, color_space);
+        s1.position = s1.position.interpolate(&s2.position, t);
+    }
+    for x in iter {
+        x.position = x.position.interpolate(&1.0, t);
+        x.color = x.color.interpolate_in_color_space(&last_color, t, color_space);
+    }
+    new_stops
+}
+
 #[test]
 #[allow(clippy::float_cmp)] // We want bit-wise equality here
 fn test_linear_gradient_encoding() {
@@ -357,3 +759,70 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_radial_gradient_encoding() {
+    let stops: SharedVector<GradientStop> = [
+        GradientStop { position: 0.0, color: Color::from_argb_u8(255, 255, 0, 0) },
+        GradientStop { position: 1.0, color: Color::from_argb_u8(255, 0, 0, 255) },
+    ]
+    .into();
+    let grad = RadialGradientBrush::new_circle(stops.clone());
+    assert_eq!(grad.center(), Point2D::new(0.5, 0.5));
+    assert_eq!(grad.radius(), 1.);
+    assert!(grad.stops().eq(stops.iter()));
+
+    let grad = RadialGradientBrush::new_circle_at(Point2D::new(0.25, 0.75), 0.5, stops.clone());
+    assert_eq!(grad.center(), Point2D::new(0.25, 0.75));
+    assert_eq!(grad.radius(), 0.5);
+    assert!(grad.stops().eq(stops.iter()));
+    assert_eq!(grad.circle_for_size(Size2D::new(200., 100.), 75.), (Point2D::new(50., 75.), 37.5));
+    let darker = Brush::RadialGradient(grad).darker(0.5);
+    let Brush::RadialGradient(darker) = darker else { unreachable!() };
+    assert_eq!(darker.center(), Point2D::new(0.25, 0.75));
+    assert_eq!(darker.stops().count(), 2);
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_conic_gradient() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    let stops: SharedVector<GradientStop> =
+        [GradientStop { position: 0.0, color: red }, GradientStop { position: 1.0, color: blue }]
+            .into();
+    let grad = ConicGradientBrush::new_at(90., Point2D::new(0.25, 0.75), stops.clone());
+    assert_eq!(grad.from_angle(), 90.);
+    assert_eq!(grad.center(), Point2D::new(0.25, 0.75));
+    assert!(grad.stops().eq(stops.iter()));
+
+    assert_eq!(grad.color_at_angle(90.), red);
+    assert_eq!(grad.color_at_angle(270.), red.interpolate(&blue, 0.5));
+    assert_eq!(grad.color_at_angle(-90.), red.interpolate(&blue, 0.5));
+
+    let brighter = Brush::ConicGradient(grad).brighter(0.5);
+    let Brush::ConicGradient(brighter) = brighter else { unreachable!() };
+    assert_eq!(brighter.from_angle(), 90.);
+    assert_eq!(brighter.center(), Point2D::new(0.25, 0.75));
+    assert_eq!(brighter.stops().count(), 2);
+}
+
+#[test]
+fn test_image_pattern() {
+    let image = Image::from_rgba8(crate::graphics::SharedPixelBuffer::new(20, 10));
+    let pattern = ImagePatternBrush::new(image.clone(), PatternTiling::RepeatX)
+        .with_transform(Point2D::new(5., -5.), 2.);
+    assert_eq!(pattern.tiling(), PatternTiling::RepeatX);
+    assert_eq!(pattern.offset(), Point2D::new(5., -5.));
+    assert_eq!(pattern.tile_size(), Size2D::new(40., 20.));
+
+    let brush = Brush::ImagePattern(pattern);
+    assert!(!brush.is_transparent());
+    assert!(!brush.is_opaque());
+    assert_eq!(brush.darker(0.5), brush);
+
+    let red = Brush::SolidColor(Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(brush.interpolate(&red, 0.25), brush);
+    assert_eq!(brush.interpolate(&red, 0.75), red);
+}
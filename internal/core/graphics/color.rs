@@ -5,6 +5,7 @@
 This module contains color related types for the run-time library.
 */
 
+use crate::items::ColorInterpolation;
 use crate::properties::InterpolatedPropertyValue;
 
 #[cfg(not(feature = "std"))]
@@ -212,6 +213,31 @@ pub fn darker(&self, factor: f32) -> Self {
         rgba.into()
     }
 
+    /// Returns a new version of this color that has the saturation increased
+    /// by the specified factor. This is done by converting the color to the HSV
+    /// color space and multiplying the saturation with (1 + factor), clamped to 1.
+    /// The result is converted back to RGB and the alpha channel is unchanged.
+    /// So for example `saturate(0.2)` will increase the saturation by 20%, and
+    /// calling `saturate(-0.5)` will return a color that's 50% less saturated.
+    #[must_use]
+    pub fn saturate(&self, factor: f32) -> Self {
+        let mut hsva = self.to_hsva();
+        hsva.saturation = (hsva.saturation * (1. + factor)).clamp(0., 1.);
+        hsva.into()
+    }
+
+    /// Returns a new version of this color that has the saturation decreased
+    /// by the specified factor. This is done by converting the color to the HSV
+    /// color space and dividing the saturation by (1 + factor). The result is
+    /// converted back to RGB and the alpha channel is unchanged.
+    /// So for example `desaturate(0.3)` will decrease the saturation by 30%.
+    #[must_use]
+    pub fn desaturate(&self, factor: f32) -> Self {
+        let mut hsva = self.to_hsva();
+        hsva.saturation = (hsva.saturation / (1. + factor)).clamp(0., 1.);
+        hsva.into()
+    }
+
     /// Returns a new version of this color with the opacity decreased by `factor`.
     ///
     /// The transparency is obtained by multiplying the alpha channel by `(1 - factor)`.
@@ -321,6 +347,130 @@ impl InterpolatedPropertyValue for Color {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         target_value.mix(self, t)
     }
+
+    fn interpolate_in_color_space(
+        &self,
+        target_value: &Self,
+        t: f32,
+        color_space: ColorInterpolation,
+    ) -> Self {
+        match color_space {
+            ColorInterpolation::Srgb => self.interpolate(target_value, t),
+            ColorInterpolation::LinearSrgb => interpolate_premultiplied(
+                self,
+                target_value,
+                t,
+                |c| [c.red, c.green, c.blue].map(srgb_to_linear),
+                |[red, green, blue]| [red, green, blue].map(linear_to_srgb),
+            ),
+            ColorInterpolation::Oklab => interpolate_premultiplied(
+                self,
+                target_value,
+                t,
+                |c| linear_srgb_to_oklab([c.red, c.green, c.blue].map(srgb_to_linear)),
+                |lab| oklab_to_linear_srgb(lab).map(linear_to_srgb),
+            ),
+            ColorInterpolation::Hsv => {
+                let mut from = self.to_hsva();
+                let mut to = target_value.to_hsva();
+                // A fully transparent color has no visible components, so only the alpha changes
+                if from.alpha == 0. {
+                    from = HsvaColor { alpha: 0., ..to };
+                } else if to.alpha == 0. {
+                    to = HsvaColor { alpha: 0., ..from };
+                }
+                let lerp = |a: f32, b: f32| a + (b - a) * t;
+                // The hue of a gray is meaningless, so take the one of the other color
+                let from_hue = if from.saturation == 0. { to.hue } else { from.hue };
+                let to_hue = if to.saturation == 0. { from.hue } else { to.hue };
+                let mut delta = (to_hue - from_hue) % 360.;
+                if delta > 180. {
+                    delta -= 360.;
+                } else if delta < -180. {
+                    delta += 360.;
+                }
+                let mut hue = (from_hue + delta * t) % 360.;
+                if hue < 0. {
+                    hue += 360.;
+                }
+                HsvaColor {
+                    hue,
+                    saturation: lerp(from.saturation, to.saturation).clamp(0., 1.),
+                    value: lerp(from.value, to.value).clamp(0., 1.),
+                    alpha: lerp(from.alpha, to.alpha).clamp(0., 1.),
+                }
+                .into()
+            }
+        }
+    }
+}
+
+/// Interpolates `from` and `to` with their three color components mapped to another color space
+/// by `to_space` and back by `from_space`. The components are premultiplied by the alpha, so that
+/// a transparent color doesn't tint the result.
+fn interpolate_premultiplied(
+    from: &Color,
+    to: &Color,
+    t: f32,
+    to_space: impl Fn(RgbaColor<f32>) -> [f32; 3],
+    from_space: impl Fn([f32; 3]) -> [f32; 3],
+) -> Color {
+    let from = RgbaColor::<f32>::from(*from);
+    let to = RgbaColor::<f32>::from(*to);
+    let alpha = from.alpha + (to.alpha - from.alpha) * t;
+    if alpha <= 0. {
+        return Color::default();
+    }
+    let from_components = to_space(from);
+    let to_components = to_space(to);
+    let components = core::array::from_fn(|i| {
+        let a = from_components[i] * from.alpha;
+        let b = to_components[i] * to.alpha;
+        (a + (b - a) * t) / alpha
+    });
+    let [red, green, blue] = from_space(components).map(|c| c.clamp(0., 1.));
+    RgbaColor { alpha: alpha.min(1.), red, green, blue }.into()
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1. / 2.4) - 0.055
+    }
+}
+
+// Conversion matrices from https://bottosson.github.io/posts/oklab/
+#[allow(clippy::excessive_precision)]
+fn linear_srgb_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+#[allow(clippy::excessive_precision)]
+fn oklab_to_linear_srgb([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
 }
 
 impl core::fmt::Display for Color {
@@ -441,6 +591,18 @@ fn test_brighter_darker() {
     assert_eq!(blue.darker(0.5), Color::from_rgb_u8(0, 0, 85));
 }
 
+#[test]
+fn test_saturate_desaturate() {
+    let muted = Color::from_rgb_u8(128, 64, 64);
+    assert_eq!(muted.saturate(0.5), Color::from_rgb_u8(128, 32, 32));
+    assert_eq!(muted.saturate(2.), Color::from_rgb_u8(128, 0, 0));
+    assert_eq!(muted.desaturate(1.), Color::from_rgb_u8(128, 96, 96));
+    assert_eq!(muted.saturate(-1.), Color::from_rgb_u8(128, 128, 128));
+
+    let grey = Color::from_argb_u8(100, 50, 50, 50);
+    assert_eq!(grey.saturate(0.5), grey);
+}
+
 #[test]
 fn test_transparent_transition() {
     let color = Color::from_argb_u8(0, 0, 0, 0);
@@ -452,6 +614,52 @@ fn test_transparent_transition() {
     assert_eq!(interpolated, Color::from_argb_u8(191, 211, 211, 211));
 }
 
+#[test]
+fn test_interpolate_in_color_space() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let green = Color::from_rgb_u8(0, 255, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+
+    assert_eq!(
+        red.interpolate_in_color_space(&green, 0.5, ColorInterpolation::Srgb),
+        red.interpolate(&green, 0.5)
+    );
+    // Linear light keeps the midpoint bright instead of the muddy #808000
+    assert_eq!(
+        red.interpolate_in_color_space(&green, 0.5, ColorInterpolation::LinearSrgb),
+        Color::from_rgb_u8(188, 188, 0)
+    );
+    // Going around the hue circle stays fully saturated
+    assert_eq!(
+        red.interpolate_in_color_space(&green, 0.5, ColorInterpolation::Hsv),
+        Color::from_rgb_u8(255, 255, 0)
+    );
+    // ... the shortest way
+    assert_eq!(
+        red.interpolate_in_color_space(&blue, 0.5, ColorInterpolation::Hsv),
+        Color::from_rgb_u8(255, 0, 255)
+    );
+
+    for space in [
+        ColorInterpolation::Srgb,
+        ColorInterpolation::LinearSrgb,
+        ColorInterpolation::Oklab,
+        ColorInterpolation::Hsv,
+    ] {
+        assert_eq!(red.interpolate_in_color_space(&blue, 0., space), red);
+        assert_eq!(red.interpolate_in_color_space(&blue, 1., space), blue);
+        // Fading in from transparent doesn't go through black
+        let transparent = Color::default();
+        assert_eq!(transparent.interpolate_in_color_space(&blue, 0.5, space).blue(), 255);
+    }
+
+    let oklab_mid = red.interpolate_in_color_space(&green, 0.5, ColorInterpolation::Oklab);
+    assert!(
+        oklab_mid.red() > 128 && oklab_mid.green() > 128 && oklab_mid.blue() < 30,
+        "{oklab_mid}"
+    );
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -467,6 +675,16 @@ pub(crate) mod ffi {
         core::ptr::write(out, col.darker(factor))
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_color_saturate(col: &Color, factor: f32, out: *mut Color) {
+        core::ptr::write(out, col.saturate(factor))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_color_desaturate(col: &Color, factor: f32, out: *mut Color) {
+        core::ptr::write(out, col.desaturate(factor))
+    }
+
     #[no_mangle]
     pub unsafe extern "C" fn slint_color_transparentize(col: &Color, factor: f32, out: *mut Color) {
         core::ptr::write(out, col.transparentize(factor))
@@ -10,12 +10,17 @@
 use crate::{SharedString, SharedVector};
 
 use super::{IntRect, IntSize};
-use crate::items::{ImageFit, ImageHorizontalAlignment, ImageTiling, ImageVerticalAlignment};
+use crate::items::{
+    ImageFit, ImageHorizontalAlignment, ImageLoadingStatus, ImageTiling, ImageVerticalAlignment,
+};
 
+mod animated;
 #[cfg(feature = "image-decoders")]
 pub mod cache;
 #[cfg(target_arch = "wasm32")]
 mod htmlimage;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+mod remote;
 #[cfg(feature = "svg")]
 mod svg;
 
@@ -43,11 +48,26 @@ pub struct OpaqueImageVTable {
     pub static HTML_IMAGE_VT for htmlimage::HTMLImage
 }
 
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped image loaded from a URL.
+    pub static REMOTE_IMAGE_VT for remote::RemoteImage
+}
+
 OpaqueImageVTable_static! {
     /// VTable for RC wrapped SVG helper struct.
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped frame of an animated image.
+    pub static ANIMATED_IMAGE_FRAME_VT for AnimatedImageFrame
+}
+
+pub use animated::{AnimatedImage, AnimatedImageFrame, AnimationFrame};
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use remote::{register_image_provider, ImageProviderError};
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -151,6 +171,115 @@ pub fn clone_from_slice<SourcePixelType>(
 /// encoded as u8.
 pub type Rgba8Pixel = rgb::RGBA8;
 
+/// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "software-renderer", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Rgb565Pixel(pub u16);
+
+impl Rgb565Pixel {
+    pub(crate) const R_MASK: u16 = 0b1111_1000_0000_0000;
+    pub(crate) const G_MASK: u16 = 0b0000_0111_1110_0000;
+    pub(crate) const B_MASK: u16 = 0b0000_0000_0001_1111;
+
+    /// Creates a pixel from 8 bit red, green and blue components. The least significant bits
+    /// of each component that don't fit into 16 bits are dropped.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self(((r as u16 & 0b11111000) << 8) | ((g as u16 & 0b11111100) << 3) | (b as u16 >> 3))
+    }
+
+    /// Return the red component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    pub fn red(self) -> u8 {
+        ((self.0 & Self::R_MASK) >> 8) as u8
+    }
+    /// Return the green component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    pub fn green(self) -> u8 {
+        ((self.0 & Self::G_MASK) >> 3) as u8
+    }
+    /// Return the blue component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    pub fn blue(self) -> u8 {
+        ((self.0 & Self::B_MASK) << 3) as u8
+    }
+}
+
+impl From<Rgb8Pixel> for Rgb565Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self::new(p.r, p.g, p.b)
+    }
+}
+
+impl From<Rgb565Pixel> for Rgb8Pixel {
+    fn from(p: Rgb565Pixel) -> Self {
+        Rgb8Pixel { r: p.red(), g: p.green(), b: p.blue() }
+    }
+}
+
+impl From<Rgb565Pixel> for Rgba8Pixel {
+    fn from(p: Rgb565Pixel) -> Self {
+        Rgba8Pixel { r: p.red(), g: p.green(), b: p.blue(), a: 255 }
+    }
+}
+
+/// A pixel with a single gray level channel, encoded as u8.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "software-renderer", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Gray8Pixel(pub u8);
+
+impl From<Rgb8Pixel> for Gray8Pixel {
+    /// Converts to gray using the Rec. 601 luma weights.
+    fn from(p: Rgb8Pixel) -> Self {
+        Self(((p.r as u32 * 299 + p.g as u32 * 587 + p.b as u32 * 114 + 500) / 1000) as u8)
+    }
+}
+
+impl From<Gray8Pixel> for Rgb8Pixel {
+    fn from(p: Gray8Pixel) -> Self {
+        Rgb8Pixel { r: p.0, g: p.0, b: p.0 }
+    }
+}
+
+impl From<Gray8Pixel> for Rgba8Pixel {
+    fn from(p: Gray8Pixel) -> Self {
+        Rgba8Pixel { r: p.0, g: p.0, b: p.0, a: 255 }
+    }
+}
+
+/// A pixel that only has an alpha channel, encoded as u8. This is typically used for masks
+/// and glyph atlases. When rendered without colorization, such pixels appear black.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "software-renderer", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Alpha8Pixel(pub u8);
+
+impl From<Alpha8Pixel> for Rgba8Pixel {
+    fn from(p: Alpha8Pixel) -> Self {
+        Rgba8Pixel { r: 0, g: 0, b: 0, a: p.0 }
+    }
+}
+
+impl<Pixel: Copy> SharedPixelBuffer<Pixel> {
+    /// Creates a new SharedPixelBuffer of the same size where each pixel is converted
+    /// to the `Target` pixel type, for example to convert a buffer of [`Rgb565Pixel`]
+    /// into a buffer of [`Rgba8Pixel`].
+    pub fn convert<Target>(&self) -> SharedPixelBuffer<Target>
+    where
+        Target: From<Pixel>,
+    {
+        SharedPixelBuffer {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(|p| Target::from(*p)).collect(),
+        }
+    }
+}
+
 /// SharedImageBuffer is a container for images that are stored in CPU accessible memory.
 ///
 /// The SharedImageBuffer's variants represent the different common formats for encoding
@@ -172,6 +301,15 @@ pub enum SharedImageBuffer {
     /// Only construct this format if you know that your pixels are encoded this way. It is more efficient
     /// for rendering.
     RGBA8Premultiplied(SharedPixelBuffer<Rgba8Pixel>),
+    /// This variant holds the data for an image where each pixel is stored in 16 bits, with
+    /// 5 bits for red, 6 bits for green and 5 bits for blue.
+    RGB565(SharedPixelBuffer<Rgb565Pixel>),
+    /// This variant holds the data for an image where each pixel has a single gray level
+    /// channel encoded as unsigned byte.
+    Gray8(SharedPixelBuffer<Gray8Pixel>),
+    /// This variant holds the data for an image where each pixel only has an alpha channel
+    /// encoded as unsigned byte.
+    Alpha8(SharedPixelBuffer<Alpha8Pixel>),
 }
 
 impl SharedImageBuffer {
@@ -182,6 +320,9 @@ pub fn width(&self) -> u32 {
             Self::RGB8(buffer) => buffer.width(),
             Self::RGBA8(buffer) => buffer.width(),
             Self::RGBA8Premultiplied(buffer) => buffer.width(),
+            Self::RGB565(buffer) => buffer.width(),
+            Self::Gray8(buffer) => buffer.width(),
+            Self::Alpha8(buffer) => buffer.width(),
         }
     }
 
@@ -192,6 +333,9 @@ pub fn height(&self) -> u32 {
             Self::RGB8(buffer) => buffer.height(),
             Self::RGBA8(buffer) => buffer.height(),
             Self::RGBA8Premultiplied(buffer) => buffer.height(),
+            Self::RGB565(buffer) => buffer.height(),
+            Self::Gray8(buffer) => buffer.height(),
+            Self::Alpha8(buffer) => buffer.height(),
         }
     }
 
@@ -202,6 +346,22 @@ pub fn size(&self) -> IntSize {
             Self::RGB8(buffer) => buffer.size(),
             Self::RGBA8(buffer) => buffer.size(),
             Self::RGBA8Premultiplied(buffer) => buffer.size(),
+            Self::RGB565(buffer) => buffer.size(),
+            Self::Gray8(buffer) => buffer.size(),
+            Self::Alpha8(buffer) => buffer.size(),
+        }
+    }
+
+    /// Returns the image converted to [`Self::RGB8`] or [`Self::RGBA8`] if it uses one of the
+    /// compact pixel formats ([`Self::RGB565`], [`Self::Gray8`] or [`Self::Alpha8`]), or None if
+    /// the image is already stored in a 24 or 32 bit format. This is meant for renderers that
+    /// can't upload the compact formats as is.
+    pub fn to_rgb8_or_rgba8(&self) -> Option<SharedImageBuffer> {
+        match self {
+            Self::RGB8(_) | Self::RGBA8(_) | Self::RGBA8Premultiplied(_) => None,
+            Self::RGB565(buffer) => Some(Self::RGB8(buffer.convert())),
+            Self::Gray8(buffer) => Some(Self::RGB8(buffer.convert())),
+            Self::Alpha8(buffer) => Some(Self::RGBA8(buffer.convert())),
         }
     }
 }
@@ -218,6 +378,15 @@ fn eq(&self, other: &Self) -> bool {
             Self::RGBA8Premultiplied(lhs_buffer) => {
                 matches!(other, Self::RGBA8Premultiplied(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
             }
+            Self::RGB565(lhs_buffer) => {
+                matches!(other, Self::RGB565(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
+            }
+            Self::Gray8(lhs_buffer) => {
+                matches!(other, Self::Gray8(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
+            }
+            Self::Alpha8(lhs_buffer) => {
+                matches!(other, Self::Alpha8(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
+            }
         }
     }
 }
@@ -337,6 +506,9 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => return None,
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
+            ImageInner::AnimatedFrame(frame) => vtable::VRc::borrow(frame).cache_key(),
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            ImageInner::Remote(remote) => vtable::VRc::borrow(remote).cache_key(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -394,6 +566,9 @@ pub enum ImageInner {
     #[cfg(not(target_arch = "wasm32"))]
     BorrowedOpenGLTexture(BorrowedOpenGLTexture) = 6,
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
+    AnimatedFrame(vtable::VRc<OpaqueImageVTable, AnimatedImageFrame>) = 8,
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    Remote(vtable::VRc<OpaqueImageVTable, remote::RemoteImage>) = 9,
 }
 
 impl ImageInner {
@@ -479,6 +654,13 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::AnimatedFrame(frame) => {
+                Some(SharedImageBuffer::RGBA8(frame.frame().buffer.clone()))
+            }
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            ImageInner::Remote(remote) => {
+                remote.image().render_to_buffer(_target_size_for_scalable_source)
+            }
             _ => None,
         }
     }
@@ -490,6 +672,8 @@ pub fn is_svg(&self) -> bool {
             Self::Svg(_) => true,
             #[cfg(target_arch = "wasm32")]
             Self::HTMLImage(html_image) => html_image.is_svg(),
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            Self::Remote(remote) => remote.image().is_svg(),
             _ => false,
         }
     }
@@ -508,6 +692,25 @@ pub fn size(&self) -> IntSize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(BorrowedOpenGLTexture { size, .. }) => *size,
             ImageInner::NineSlice(nine) => nine.0.size(),
+            ImageInner::AnimatedFrame(frame) => frame.frame().buffer.size(),
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            ImageInner::Remote(remote) => remote.image().size(),
+        }
+    }
+
+    /// Returns whether the image is available. Images loaded from a URL are pending until their data
+    /// has arrived, and this function registers a dependency so that bindings are re-evaluated then.
+    pub fn loading_status(&self) -> ImageLoadingStatus {
+        match self {
+            #[cfg(target_arch = "wasm32")]
+            ImageInner::HTMLImage(htmlimage) => match htmlimage.size() {
+                Some(_) => ImageLoadingStatus::Ready,
+                None => ImageLoadingStatus::Pending,
+            },
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            ImageInner::Remote(remote) => remote.loading_status(),
+            ImageInner::NineSlice(nine) => nine.0.loading_status(),
+            _ => ImageLoadingStatus::Ready,
         }
     }
 }
@@ -528,6 +731,11 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::AnimatedFrame(l), Self::AnimatedFrame(r)) => {
+                alloc::rc::Rc::ptr_eq(l.animation(), r.animation()) && l.index() == r.index()
+            }
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            (Self::Remote(l0), Self::Remote(r0)) => vtable::VRc::ptr_eq(l0, r0),
             _ => false,
         }
     }
@@ -692,17 +900,60 @@ pub fn from_rgba8_premultiplied(buffer: SharedPixelBuffer<Rgba8Pixel>) -> Self {
         })
     }
 
+    /// Creates a new Image from the specified shared pixel buffer, where each pixel is stored in
+    /// 16 bits with 5 bits for red, 6 bits for green and 5 bits for blue.
+    pub fn from_rgb565(buffer: SharedPixelBuffer<Rgb565Pixel>) -> Self {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: SharedImageBuffer::RGB565(buffer),
+        })
+    }
+
+    /// Creates a new Image from the specified shared pixel buffer, where each pixel has a single
+    /// gray level channel encoded as u8.
+    pub fn from_gray8(buffer: SharedPixelBuffer<Gray8Pixel>) -> Self {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: SharedImageBuffer::Gray8(buffer),
+        })
+    }
+
+    /// Creates a new Image from the specified shared pixel buffer, where each pixel only has an
+    /// alpha channel encoded as u8. Use the `colorize` property of the `Image` element to
+    /// choose the color the image is drawn with.
+    pub fn from_alpha8(buffer: SharedPixelBuffer<Alpha8Pixel>) -> Self {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: SharedImageBuffer::Alpha8(buffer),
+        })
+    }
+
     /// Returns the pixel buffer for the Image if available in RGB format without alpha.
     /// Returns None if the pixels cannot be obtained, for example when the image was created from borrowed OpenGL textures.
     pub fn to_rgb8(&self) -> Option<SharedPixelBuffer<Rgb8Pixel>> {
         self.0.render_to_buffer(None).and_then(|image| match image {
             SharedImageBuffer::RGB8(buffer) => Some(buffer),
+            SharedImageBuffer::RGB565(buffer) => Some(buffer.convert()),
+            SharedImageBuffer::Gray8(buffer) => Some(buffer.convert()),
             _ => None,
         })
     }
 
     /// Returns the pixel buffer for the Image if available in RGBA format.
+    ///
+    /// This works for images created from pixel buffers, loaded from a path, embedded with
+    /// `@image-url`, and for SVG images, which are rendered at their natural size. Premultiplied
+    /// pixels are converted to straight alpha.
     /// Returns None if the pixels cannot be obtained, for example when the image was created from borrowed OpenGL textures.
+    ///
+    /// ```no_run
+    /// # use i_slint_core::graphics::Image;
+    /// let image = Image::load_from_path(std::path::Path::new("photo.png")).unwrap();
+    /// let pixels = image.to_rgba8().unwrap();
+    /// let (width, height) = (pixels.width(), pixels.height());
+    /// let bytes: &[u8] = pixels.as_bytes();
+    /// assert_eq!(bytes.len(), (width * height * 4) as usize);
+    /// ```
     pub fn to_rgba8(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
         self.0.render_to_buffer(None).and_then(|image| match image {
             SharedImageBuffer::RGB8(buffer) => Some(SharedPixelBuffer::<Rgba8Pixel> {
@@ -711,6 +962,9 @@ pub fn to_rgba8(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                 data: buffer.data.into_iter().map(Into::into).collect(),
             }),
             SharedImageBuffer::RGBA8(buffer) => Some(buffer),
+            SharedImageBuffer::RGB565(buffer) => Some(buffer.convert()),
+            SharedImageBuffer::Gray8(buffer) => Some(buffer.convert()),
+            SharedImageBuffer::Alpha8(buffer) => Some(buffer.convert()),
             SharedImageBuffer::RGBA8Premultiplied(buffer) => {
                 Some(SharedPixelBuffer::<Rgba8Pixel> {
                     width: buffer.width,
@@ -719,16 +973,18 @@ pub fn to_rgba8(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                         .data
                         .into_iter()
                         .map(|rgba_premul| {
-                            if rgba_premul.a == 0 {
-                                Rgba8Pixel::new(0, 0, 0, 0)
-                            } else {
-                                let af = rgba_premul.a as f32 / 255.0;
-                                Rgba8Pixel {
-                                    r: (rgba_premul.r as f32 * 255. / af) as u8,
-                                    g: (rgba_premul.g as f32 * 255. / af) as u8,
-                                    b: (rgba_premul.b as f32 * 255. / af) as u8,
+                            let a = rgba_premul.a as u16;
+                            let unpremultiply =
+                                |c: u8| ((c as u16 * 255 + a / 2) / a).min(255) as u8;
+                            match rgba_premul.a {
+                                0 => Rgba8Pixel::new(0, 0, 0, 0),
+                                255 => rgba_premul,
+                                _ => Rgba8Pixel {
+                                    r: unpremultiply(rgba_premul.r),
+                                    g: unpremultiply(rgba_premul.g),
+                                    b: unpremultiply(rgba_premul.b),
                                     a: rgba_premul.a,
-                                }
+                                },
                             }
                         })
                         .collect(),
@@ -754,14 +1010,14 @@ pub fn to_rgba8_premultiplied(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                     .data
                     .into_iter()
                     .map(|rgba| {
+                        let premultiply = |c: u8| ((c as u16 * rgba.a as u16 + 127) / 255) as u8;
                         if rgba.a == 255 {
                             rgba
                         } else {
-                            let af = rgba.a as f32 / 255.0;
                             Rgba8Pixel {
-                                r: (rgba.r as f32 * af / 255.) as u8,
-                                g: (rgba.g as f32 * af / 255.) as u8,
-                                b: (rgba.b as f32 * af / 255.) as u8,
+                                r: premultiply(rgba.r),
+                                g: premultiply(rgba.g),
+                                b: premultiply(rgba.b),
                                 a: rgba.a,
                             }
                         }
@@ -769,6 +1025,10 @@ pub fn to_rgba8_premultiplied(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                     .collect(),
             }),
             SharedImageBuffer::RGBA8Premultiplied(buffer) => Some(buffer),
+            SharedImageBuffer::RGB565(buffer) => Some(buffer.convert()),
+            SharedImageBuffer::Gray8(buffer) => Some(buffer.convert()),
+            // Black with alpha is the same premultiplied or not
+            SharedImageBuffer::Alpha8(buffer) => Some(buffer.convert()),
         })
     }
 
@@ -861,6 +1121,12 @@ pub fn path(&self) -> Option<&std::path::Path> {
                 } => Some(std::path::Path::new(path.as_str())),
                 _ => None,
             },
+            ImageInner::AnimatedFrame(frame) => match frame.animation().cache_key() {
+                ImageCacheKey::Path(CachedPath { path, .. }) => {
+                    Some(std::path::Path::new(path.as_str()))
+                }
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -911,6 +1177,26 @@ impl BorrowedOpenGLTextureBuilder {
     /// This is different from the default OpenGL coordinate system. Use the `mirror_vertically` function
     /// to reconfigure this.
     ///
+    /// The texture is used as is, without copying it. Only renderers that render with OpenGL can draw it:
+    /// check that the [`GraphicsAPI`](crate::api::GraphicsAPI) passed to the rendering notifier is
+    /// `NativeOpenGL` before creating such an image. Other renderers, such as the software renderer,
+    /// don't draw borrowed textures at all, and log a warning the first time they encounter one.
+    ///
+    /// ## Lifetime and synchronization
+    ///
+    /// Slint doesn't take ownership of the texture. It must stay valid as long as the returned image
+    /// is set on an element, and until the next frame was rendered after that image was replaced.
+    /// [`RenderingState::AfterRendering`](crate::api::RenderingState::AfterRendering) is a good place to delete
+    /// textures that are no longer in use.
+    ///
+    /// Slint reads the texture when it renders a frame, in the same context and on the same thread
+    /// as the rendering notifier. Commands issued in the notifier, for example in
+    /// [`RenderingState::BeforeRendering`](crate::api::RenderingState::BeforeRendering), are executed in order and
+    /// need no further synchronization. If the texture is written by a different, shared context
+    /// (for example a video decoder thread), that context must create a fence with `glFenceSync` after writing,
+    /// and the rendering notifier must wait on it with `glWaitSync` before Slint renders the frame.
+    /// Don't modify the texture while a frame is being rendered.
+    ///
     /// # Safety
     ///
     /// This function is unsafe because invalid texture ids may lead to undefined behavior in OpenGL
@@ -961,7 +1247,75 @@ fn test_image_size_from_buffer_without_backend() {
     }
 }
 
+#[test]
+fn test_compact_pixel_formats() {
+    let mut buffer = SharedPixelBuffer::<Rgb565Pixel>::new(2, 1);
+    buffer.make_mut_slice()[0] = Rgb8Pixel::new(0xff, 0, 0).into();
+    buffer.make_mut_slice()[1] = Rgb565Pixel::new(0, 0xff, 0);
+    let image = Image::from_rgb565(buffer);
+    assert_eq!(image.size(), [2, 1].into());
+    assert_eq!(
+        image.to_rgb8().unwrap().as_slice(),
+        &[Rgb8Pixel::new(0xf8, 0, 0), Rgb8Pixel::new(0, 0xfc, 0)]
+    );
+
+    let mut buffer = SharedPixelBuffer::<Gray8Pixel>::new(1, 1);
+    buffer.make_mut_slice()[0] = Gray8Pixel(0x40);
+    assert_eq!(Gray8Pixel::from(Rgb8Pixel::new(0x40, 0x40, 0x40)), Gray8Pixel(0x40));
+    let image = Image::from_gray8(buffer);
+    assert_eq!(image.to_rgba8().unwrap().as_slice(), &[Rgba8Pixel::new(0x40, 0x40, 0x40, 0xff)]);
+
+    let mut buffer = SharedPixelBuffer::<Alpha8Pixel>::new(1, 1);
+    buffer.make_mut_slice()[0] = Alpha8Pixel(0x80);
+    let image = Image::from_alpha8(buffer);
+    assert!(image.to_rgb8().is_none());
+    assert_eq!(
+        image.to_rgba8_premultiplied().unwrap().as_slice(),
+        &[Rgba8Pixel::new(0, 0, 0, 0x80)]
+    );
+}
+
 #[cfg(feature = "svg")]
+#[test]
+fn test_premultiplied_alpha_conversion() {
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(3, 1);
+    buffer.make_mut_slice().copy_from_slice(&[
+        Rgba8Pixel::new(64, 32, 0, 128),
+        Rgba8Pixel::new(10, 20, 30, 255),
+        Rgba8Pixel::new(0, 0, 0, 0),
+    ]);
+    let image = Image::from_rgba8_premultiplied(buffer);
+    assert_eq!(
+        image.to_rgba8().unwrap().as_slice(),
+        &[
+            Rgba8Pixel::new(128, 64, 0, 128),
+            Rgba8Pixel::new(10, 20, 30, 255),
+            Rgba8Pixel::new(0, 0, 0, 0)
+        ]
+    );
+
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 1);
+    buffer
+        .make_mut_slice()
+        .copy_from_slice(&[Rgba8Pixel::new(128, 64, 255, 128), Rgba8Pixel::new(10, 20, 30, 255)]);
+    let image = Image::from_rgba8(buffer);
+    assert_eq!(
+        image.to_rgba8_premultiplied().unwrap().as_slice(),
+        &[Rgba8Pixel::new(64, 32, 128, 128), Rgba8Pixel::new(10, 20, 30, 255)]
+    );
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_pixels_of_image_loaded_from_path() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../logo/slint-logo-square-light-128x128.png");
+    let image = Image::load_from_path(&path).unwrap();
+    let pixels = image.to_rgba8().unwrap();
+    assert_eq!(pixels.size(), image.size());
+    assert!(pixels.as_slice().iter().any(|p| p.a == 255));
+}
+
 #[test]
 fn test_image_size_from_svg() {
     let simple_svg = r#"<svg width="320" height="200" xmlns="http://www.w3.org/2000/svg"></svg>"#;
@@ -970,6 +1324,21 @@ fn test_image_size_from_svg() {
     assert_eq!(image.to_rgba8().unwrap().size(), image.size());
 }
 
+#[cfg(feature = "svg")]
+#[test]
+fn test_svg_rendered_at_target_size() {
+    let simple_svg = r#"<svg width="32" height="20" xmlns="http://www.w3.org/2000/svg"><rect width="32" height="20" fill="red"/></svg>"#;
+    let image = Image::load_from_svg_data(simple_svg.as_bytes()).unwrap();
+    let inner: &ImageInner = (&image).into();
+    let buffer = inner.render_to_buffer(Some(euclid::size2(64, 40))).unwrap();
+    assert_eq!(buffer.size(), [64, 40].into());
+    // The aspect ratio is preserved
+    let buffer = inner.render_to_buffer(Some(euclid::size2(64, 64))).unwrap();
+    assert_eq!(buffer.size(), [64, 40].into());
+    // The intrinsic size is still the size of the image
+    assert_eq!(image.size(), [32, 20].into());
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_invalid_svg() {
@@ -1296,6 +1665,10 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
                 },
                 _ => None,
             },
+            ImageInner::AnimatedFrame(frame) => match frame.animation().cache_key() {
+                ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -0,0 +1,458 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the types for animated images (GIF or APNG) and their decoding.
+*/
+
+use super::{Image, ImageCacheKey, ImageInner, OpaqueImage, Rgba8Pixel, SharedPixelBuffer};
+use crate::graphics::{IntRect, IntSize};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// One frame of an [`AnimatedImage`]
+#[derive(Clone, Debug)]
+pub struct AnimationFrame {
+    /// The pixels of the whole frame, already composed with the previous frames.
+    pub buffer: SharedPixelBuffer<Rgba8Pixel>,
+    /// How long the frame is shown.
+    pub duration: Duration,
+    /// The region that differs from the previous frame (the last frame for the first one).
+    pub dirty_rect: IntRect,
+}
+
+/// The decoded frames of an animated image
+#[derive(Debug)]
+pub struct AnimatedImage {
+    frames: Vec<AnimationFrame>,
+    cache_key: ImageCacheKey,
+    generation: usize,
+}
+
+impl AnimatedImage {
+    /// Creates an animated image from the frames. The dirty region of each frame is computed
+    /// by comparing it with the previous frame.
+    ///
+    /// Returns None if there are no frames, or if the frames don't all have the same size.
+    pub fn new(
+        frames: impl IntoIterator<Item = (SharedPixelBuffer<Rgba8Pixel>, Duration)>,
+        cache_key: ImageCacheKey,
+    ) -> Option<Self> {
+        let mut frames = frames
+            .into_iter()
+            .map(|(buffer, duration)| AnimationFrame {
+                buffer,
+                duration,
+                dirty_rect: Default::default(),
+            })
+            .collect::<Vec<_>>();
+        let size = frames.first()?.buffer.size();
+        if frames.iter().any(|frame| frame.buffer.size() != size) {
+            return None;
+        }
+        for i in 0..frames.len() {
+            let previous = if i == 0 { frames.len() - 1 } else { i - 1 };
+            frames[i].dirty_rect = changed_region(&frames[previous].buffer, &frames[i].buffer);
+        }
+        static NEXT_GENERATION: portable_atomic::AtomicUsize = portable_atomic::AtomicUsize::new(1);
+        let generation = NEXT_GENERATION.fetch_add(1, portable_atomic::Ordering::Relaxed);
+        Some(Self { frames, cache_key, generation })
+    }
+
+    /// Returns the frames of the animation.
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+
+    /// Returns the key identifying the source of the animation in the image cache.
+    pub fn cache_key(&self) -> &ImageCacheKey {
+        &self.cache_key
+    }
+
+    /// Returns a number that identifies this animation among all the animations created by the
+    /// process. Unlike the address of the animation, it is not reused once the animation is dropped.
+    /// It is never 0.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Returns the index of the frame to show after `elapsed` time since the start of the animation,
+    /// and whether the animation has finished.
+    ///
+    /// The animation is played `loop_count` times, or forever if `loop_count` is 0.
+    pub fn frame_at(&self, elapsed: Duration, loop_count: u32) -> (usize, bool) {
+        let total = self.frames.iter().map(|f| f.duration.as_millis()).sum::<u128>();
+        if total == 0 || self.frames.len() < 2 {
+            return (0, true);
+        }
+        let elapsed = elapsed.as_millis();
+        if loop_count > 0 && elapsed >= total * loop_count as u128 {
+            return (self.frames.len() - 1, true);
+        }
+        let mut time_in_loop = elapsed % total;
+        for (index, frame) in self.frames.iter().enumerate() {
+            let duration = frame.duration.as_millis();
+            if time_in_loop < duration {
+                return (index, false);
+            }
+            time_in_loop -= duration;
+        }
+        (self.frames.len() - 1, false)
+    }
+
+    /// Returns the region of the image that changes when going from the frame `from` to the frame `to`
+    pub fn dirty_rect_between(&self, from: usize, to: usize) -> IntRect {
+        let mut rect = IntRect::default();
+        let mut index = from;
+        while index != to {
+            index = (index + 1) % self.frames.len();
+            rect = rect.union(&self.frames[index].dirty_rect);
+        }
+        rect
+    }
+}
+
+/// A given frame of an [`AnimatedImage`], as stored in [`ImageInner::AnimatedFrame`]
+pub struct AnimatedImageFrame {
+    animation: Rc<AnimatedImage>,
+    index: usize,
+}
+
+impl AnimatedImageFrame {
+    /// Creates the frame with the given index of the animation.
+    ///
+    /// Returns None if the animation doesn't have a frame with that index.
+    pub fn new(animation: Rc<AnimatedImage>, index: usize) -> Option<Self> {
+        (index < animation.frames.len()).then_some(Self { animation, index })
+    }
+
+    /// Returns the animation this frame is part of.
+    pub fn animation(&self) -> &Rc<AnimatedImage> {
+        &self.animation
+    }
+
+    /// Returns the index of this frame in the animation.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the content of this frame.
+    pub fn frame(&self) -> &AnimationFrame {
+        &self.animation.frames[self.index]
+    }
+}
+
+impl OpaqueImage for AnimatedImageFrame {
+    fn size(&self) -> IntSize {
+        self.frame().buffer.size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        // Only the first frame is what is stored in the image cache
+        if self.index == 0 {
+            self.animation.cache_key.clone()
+        } else {
+            ImageCacheKey::Invalid
+        }
+    }
+}
+
+impl Image {
+    /// Returns the animation if this image is a frame of an animated image.
+    pub(crate) fn animation(&self) -> Option<&Rc<AnimatedImage>> {
+        match &self.0 {
+            ImageInner::AnimatedFrame(frame) => Some(frame.animation()),
+            ImageInner::NineSlice(nine) => match &nine.0 {
+                ImageInner::AnimatedFrame(frame) => Some(frame.animation()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the same image, but showing the frame with the given index of the animation.
+    /// The image must be a frame of an animated image.
+    pub(crate) fn with_animation_frame(&self, index: usize) -> Image {
+        let select = |inner: &ImageInner| match inner {
+            ImageInner::AnimatedFrame(frame) if frame.index == index => inner.clone(),
+            ImageInner::AnimatedFrame(frame) => AnimatedImageFrame::new(
+                frame.animation.clone(),
+                index,
+            )
+            .map_or_else(|| inner.clone(), |f| ImageInner::AnimatedFrame(vtable::VRc::new(f))),
+            _ => inner.clone(),
+        };
+        Image(match &self.0 {
+            ImageInner::NineSlice(nine) => ImageInner::NineSlice(vtable::VRc::new(
+                super::NineSliceImage(select(&nine.0), nine.1),
+            )),
+            inner => select(inner),
+        })
+    }
+}
+
+/// Returns the bounding rectangle of the pixels that differ between two buffers of the same size
+fn changed_region(
+    previous: &SharedPixelBuffer<Rgba8Pixel>,
+    current: &SharedPixelBuffer<Rgba8Pixel>,
+) -> IntRect {
+    if previous.size() != current.size() {
+        return IntRect::from_size(current.size().cast());
+    }
+    let width = current.width() as usize;
+    let rows =
+        previous.as_slice().chunks(width.max(1)).zip(current.as_slice().chunks(width.max(1)));
+    let mut changed = euclid::default::Box2D::<i32>::zero();
+    for (y, (previous_row, current_row)) in rows.enumerate() {
+        let Some(first) = previous_row.iter().zip(current_row).position(|(p, c)| p != c) else {
+            continue;
+        };
+        let last = width
+            - 1
+            - previous_row
+                .iter()
+                .rev()
+                .zip(current_row.iter().rev())
+                .position(|(p, c)| p != c)
+                .unwrap();
+        let row = euclid::Box2D::new(
+            euclid::point2(first as i32, y as i32),
+            euclid::point2(last as i32 + 1, y as i32 + 1),
+        );
+        changed = changed.union(&row);
+    }
+    changed.to_rect()
+}
+
+/// Decodes the frames of an animated GIF or APNG.
+///
+/// Returns None if the data is not an animated image, so that it can be loaded as a still image.
+#[cfg(feature = "image-decoders")]
+pub(crate) fn load_from_data(
+    data: &[u8],
+    format: image::ImageFormat,
+    cache_key: ImageCacheKey,
+) -> Option<AnimatedImage> {
+    use image::AnimationDecoder;
+    let frames = match format {
+        image::ImageFormat::Gif => {
+            image::codecs::gif::GifDecoder::new(std::io::Cursor::new(data)).ok()?.into_frames()
+        }
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(std::io::Cursor::new(data)).ok()?;
+            if !decoder.is_apng() {
+                return None;
+            }
+            decoder.apng().into_frames()
+        }
+        _ => return None,
+    };
+    let frames = frames
+        .collect_frames()
+        .map_err(|decode_err| eprintln!("Error decoding animated image: {}", decode_err))
+        .ok()?;
+    if frames.len() < 2 {
+        return None;
+    }
+    AnimatedImage::new(
+        frames.into_iter().map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let millis = if denom == 0 { 0 } else { numer / denom };
+            // Like web browsers, use a default delay for frames with no (or a very short) delay
+            let duration = Duration::from_millis(if millis <= 10 { 100 } else { millis as u64 });
+            let buffer = frame.into_buffer();
+            (
+                SharedPixelBuffer::clone_from_slice(
+                    buffer.as_raw(),
+                    buffer.width(),
+                    buffer.height(),
+                ),
+                duration,
+            )
+        }),
+        cache_key,
+    )
+}
+
+#[cfg(all(test, feature = "image-decoders"))]
+mod tests {
+    use super::*;
+    use crate::graphics::euclid;
+
+    fn encode_gif(frames: &[(image::RgbaImage, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut encoder = image::codecs::gif::GifEncoder::new(&mut data);
+            encoder
+                .encode_frames(frames.iter().map(|(buffer, delay)| {
+                    image::Frame::from_parts(
+                        buffer.clone(),
+                        0,
+                        0,
+                        image::Delay::from_numer_denom_ms(*delay, 1),
+                    )
+                }))
+                .unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn decode_animated_gif() {
+        let red = image::RgbaImage::from_pixel(10, 10, image::Rgba([255, 0, 0, 255]));
+        let mut green_square = red.clone();
+        for x in 2..5 {
+            for y in 3..7 {
+                green_square.put_pixel(x, y, image::Rgba([0, 255, 0, 255]));
+            }
+        }
+        let data = encode_gif(&[(red.clone(), 100), (green_square, 200), (red.clone(), 0)]);
+
+        let animation =
+            load_from_data(&data, image::ImageFormat::Gif, ImageCacheKey::Invalid).unwrap();
+        assert_eq!(animation.frames().len(), 3);
+        assert_eq!(animation.frames()[0].duration, Duration::from_millis(100));
+        assert_eq!(animation.frames()[1].duration, Duration::from_millis(200));
+        // No delay uses the default delay
+        assert_eq!(animation.frames()[2].duration, Duration::from_millis(100));
+
+        assert_eq!(animation.frames()[1].buffer.as_slice()[0], Rgba8Pixel::new(255, 0, 0, 255));
+        assert_eq!(
+            animation.frames()[1].buffer.as_slice()[3 * 10 + 2],
+            Rgba8Pixel::new(0, 255, 0, 255)
+        );
+
+        // The first and last frames are the same
+        assert!(animation.frames()[0].dirty_rect.is_empty());
+        assert_eq!(animation.frames()[1].dirty_rect, euclid::rect(2, 3, 3, 4));
+        assert_eq!(animation.frames()[2].dirty_rect, euclid::rect(2, 3, 3, 4));
+        assert_eq!(animation.dirty_rect_between(0, 1), euclid::rect(2, 3, 3, 4));
+        assert!(animation.dirty_rect_between(2, 0).is_empty());
+        assert!(animation.dirty_rect_between(1, 1).is_empty());
+
+        // A still image is not an animation
+        assert!(load_from_data(
+            &encode_gif(&[(red, 100)]),
+            image::ImageFormat::Gif,
+            ImageCacheKey::Invalid
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn animation_frame_at() {
+        let buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 2);
+        let animation = AnimatedImage::new(
+            [
+                (buffer.clone(), Duration::from_millis(100)),
+                (buffer.clone(), Duration::from_millis(50)),
+                (buffer, Duration::from_millis(100)),
+            ],
+            ImageCacheKey::Invalid,
+        )
+        .unwrap();
+        let frame_at = |ms, loop_count| animation.frame_at(Duration::from_millis(ms), loop_count);
+        assert_eq!(frame_at(0, 0), (0, false));
+        assert_eq!(frame_at(99, 0), (0, false));
+        assert_eq!(frame_at(100, 0), (1, false));
+        assert_eq!(frame_at(150, 0), (2, false));
+        assert_eq!(frame_at(260, 0), (0, false));
+        assert_eq!(frame_at(2510, 0), (0, false));
+        assert_eq!(frame_at(360, 2), (1, false));
+        assert_eq!(frame_at(500, 2), (2, true));
+        assert_eq!(frame_at(5000, 1), (2, true));
+    }
+
+    #[test]
+    fn invalid_animation() {
+        let buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 2);
+        let duration = Duration::from_millis(100);
+        assert!(AnimatedImage::new([], ImageCacheKey::Invalid).is_none());
+        assert!(AnimatedImage::new(
+            [(buffer.clone(), duration), (SharedPixelBuffer::new(3, 2), duration)],
+            ImageCacheKey::Invalid
+        )
+        .is_none());
+
+        let animation = Rc::new(
+            AnimatedImage::new(
+                [(buffer.clone(), duration), (buffer.clone(), duration)],
+                ImageCacheKey::Invalid,
+            )
+            .unwrap(),
+        );
+        assert!(AnimatedImageFrame::new(animation.clone(), 1).is_some());
+        assert!(AnimatedImageFrame::new(animation.clone(), 2).is_none());
+
+        // Each animation has its own generation, even when it takes the place of a dropped one
+        let generation = animation.generation();
+        drop(animation);
+        let other = AnimatedImage::new([(buffer, duration)], ImageCacheKey::Invalid).unwrap();
+        assert_ne!(other.generation(), generation);
+    }
+
+    #[test]
+    fn image_item_playback() {
+        use crate::item_rendering::RenderImage;
+        use crate::tests::slint_mock_elapsed_time;
+
+        let frame = |red| {
+            (
+                SharedPixelBuffer::clone_from_slice(&[red, 0, 0, 255], 1, 1),
+                Duration::from_millis(100),
+            )
+        };
+        let animation =
+            AnimatedImage::new([frame(0), frame(1), frame(2)], ImageCacheKey::Invalid).unwrap();
+        let image = Image(ImageInner::AnimatedFrame(vtable::VRc::new(
+            AnimatedImageFrame::new(Rc::new(animation), 0).unwrap(),
+        )));
+
+        let item = alloc::boxed::Box::pin(crate::items::ImageItem::default());
+        item.source.set(image.clone());
+        item.playing.set(true);
+        let current_frame = || match &RenderImage::source(item.as_ref()).0 {
+            ImageInner::AnimatedFrame(frame) => frame.index(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(current_frame(), 0);
+        slint_mock_elapsed_time(150);
+        assert_eq!(current_frame(), 1);
+
+        // Pausing keeps the current frame, and resuming continues from there
+        item.playing.set(false);
+        assert_eq!(current_frame(), 1);
+        slint_mock_elapsed_time(500);
+        assert_eq!(current_frame(), 1);
+        item.playing.set(true);
+        assert_eq!(current_frame(), 1);
+        slint_mock_elapsed_time(100);
+        assert_eq!(current_frame(), 2);
+
+        // After the last loop, the last frame stays
+        item.loop_count.set(1);
+        slint_mock_elapsed_time(1000);
+        assert_eq!(current_frame(), 2);
+
+        // Another animation starts from the first frame
+        let other =
+            AnimatedImage::new([frame(3), frame(4), frame(5)], ImageCacheKey::Invalid).unwrap();
+        item.source.set(Image(ImageInner::AnimatedFrame(vtable::VRc::new(
+            AnimatedImageFrame::new(Rc::new(other), 0).unwrap(),
+        ))));
+        assert_eq!(current_frame(), 0);
+        slint_mock_elapsed_time(150);
+        assert_eq!(current_frame(), 1);
+
+        // Also when it was paused
+        item.playing.set(false);
+        slint_mock_elapsed_time(100);
+        item.source.set(image);
+        assert_eq!(current_frame(), 0);
+        item.playing.set(true);
+        assert_eq!(current_frame(), 0);
+        slint_mock_elapsed_time(100);
+        assert_eq!(current_frame(), 1);
+    }
+}
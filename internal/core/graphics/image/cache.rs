@@ -18,6 +18,9 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
                 SharedImageBuffer::RGB8(pixels) => pixels.as_bytes().len(),
                 SharedImageBuffer::RGBA8(pixels) => pixels.as_bytes().len(),
                 SharedImageBuffer::RGBA8Premultiplied(pixels) => pixels.as_bytes().len(),
+                SharedImageBuffer::RGB565(pixels) => core::mem::size_of_val(pixels.as_slice()),
+                SharedImageBuffer::Gray8(pixels) => pixels.as_slice().len(),
+                SharedImageBuffer::Alpha8(pixels) => pixels.as_slice().len(),
             },
             #[cfg(feature = "svg")]
             ImageInner::Svg(_) => 512, // Don't know how to measure the size of the parsed SVG tree...
@@ -28,6 +31,12 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => 0, // Assume storage in GPU memory
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
+            ImageInner::AnimatedFrame(frame) => {
+                frame.animation().frames().iter().map(|f| f.buffer.as_bytes().len()).sum()
+            }
+            // The weight is computed when the image is put in the cache, while it is still pending
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            ImageInner::Remote(_) => 512,
         }
     }
 }
@@ -54,6 +63,18 @@ pub(crate) struct ImageCache(
 );
 
 impl ImageCache {
+    /// Removes the image cached for `cache_key` if `predicate` returns true for it.
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    pub(crate) fn remove_if(
+        &mut self,
+        cache_key: &ImageCacheKey,
+        predicate: impl FnOnce(&ImageInner) -> bool,
+    ) {
+        if self.0.peek(cache_key).is_some_and(predicate) {
+            self.0.pop(cache_key);
+        }
+    }
+
     // Look up the given image cache key in the image cache and upgrade the weak reference to a strong one if found,
     // otherwise a new image is created/loaded from the given callback.
     fn lookup_image_in_cache_or_create(
@@ -83,6 +104,11 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
         });
         #[cfg(not(target_arch = "wasm32"))]
         return self.lookup_image_in_cache_or_create(cache_key, |cache_key| {
+            #[cfg(feature = "std")]
+            if let Some(remote) = super::remote::RemoteImage::load(path) {
+                return Some(ImageInner::Remote(vtable::VRc::new(remote)));
+            }
+
             if cfg!(feature = "svg") && (path.ends_with(".svg") || path.ends_with(".svgz")) {
                 return Some(ImageInner::Svg(vtable::VRc::new(
                     super::svg::load_from_path(path, cache_key).map_or_else(
@@ -95,6 +121,10 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
+            if let Some(animation) = load_animation_from_path(path, &cache_key) {
+                return Some(animation);
+            }
+
             image::open(std::path::Path::new(&path.as_str())).map_or_else(
                 |decode_err| {
                     eprintln!("Error loading image from {}: {}", &path, decode_err);
@@ -133,6 +163,14 @@ pub(crate) fn load_image_from_embedded_data(
             let format = std::str::from_utf8(format.as_slice())
                 .ok()
                 .and_then(image::ImageFormat::from_extension);
+            if let Some(animation) = format
+                .and_then(|format| {
+                    super::animated::load_from_data(data.as_slice(), format, cache_key.clone())
+                })
+                .and_then(animated_image_inner)
+            {
+                return Some(animation);
+            }
             let maybe_image = if let Some(format) = format {
                 image::load_from_memory_with_format(data.as_slice(), format)
             } else {
@@ -153,7 +191,24 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
-fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
+fn load_animation_from_path(path: &SharedString, cache_key: &ImageCacheKey) -> Option<ImageInner> {
+    let path = std::path::Path::new(path.as_str());
+    let format = image::ImageFormat::from_path(path).ok()?;
+    if !matches!(format, image::ImageFormat::Gif | image::ImageFormat::Png) {
+        return None;
+    }
+    let data = std::fs::read(path).ok()?;
+    super::animated::load_from_data(&data, format, cache_key.clone()).and_then(animated_image_inner)
+}
+
+fn animated_image_inner(animation: super::AnimatedImage) -> Option<ImageInner> {
+    super::AnimatedImageFrame::new(std::rc::Rc::new(animation), 0)
+        .map(|frame| ImageInner::AnimatedFrame(vtable::VRc::new(frame)))
+}
+
+pub(super) fn dynamic_image_to_shared_image_buffer(
+    dynamic_image: image::DynamicImage,
+) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
         SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
@@ -0,0 +1,254 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the support for images that are loaded in the background from a URL,
+using an image provider registered by the application for the scheme of the URL.
+*/
+
+use super::{CachedPath, ImageCacheKey, ImageInner};
+use crate::graphics::IntSize;
+use crate::items::ImageLoadingStatus;
+use crate::{Property, SharedString};
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
+use std::sync::Mutex;
+
+/// The error type returned by an image provider registered with [`register_image_provider()`].
+pub type ImageProviderError = Box<dyn std::error::Error + Send + Sync>;
+
+type ImageProvider = Arc<dyn Fn(&str) -> Result<Vec<u8>, ImageProviderError> + Send + Sync>;
+
+static IMAGE_PROVIDERS: Mutex<BTreeMap<String, ImageProvider>> = Mutex::new(BTreeMap::new());
+
+/// Registers a function that provides the encoded data of images whose URL starts with `scheme://`,
+/// for example `https`, or a custom scheme of the application.
+///
+/// When the source of an `Image` element is such a URL, the provider is called with the URL from a
+/// background thread, so it can block while downloading the data. At most four images are fetched at
+/// the same time, the other ones wait in a queue. The image is then decoded and shown, and the
+/// `loading-status` property of the element changes from `pending` to `ready` or `error`. An image
+/// that failed to load is requested again the next time its URL is used.
+/// The result is delivered through [`invoke_from_event_loop()`](crate::api::invoke_from_event_loop),
+/// so the event loop must be running for the image to appear.
+///
+/// Registering a provider for a scheme replaces the previous provider for that scheme.
+///
+/// ```no_run
+/// # use i_slint_core as slint;
+/// slint::graphics::register_image_provider("https", |url| {
+///     // Use the HTTP client of your choice to download the data
+///     Ok(std::fs::read(url.trim_start_matches("https://"))?)
+/// });
+/// ```
+pub fn register_image_provider(
+    scheme: &str,
+    provider: impl Fn(&str) -> Result<Vec<u8>, ImageProviderError> + Send + Sync + 'static,
+) {
+    IMAGE_PROVIDERS.lock().unwrap().insert(scheme.to_ascii_lowercase(), Arc::new(provider));
+}
+
+/// Returns the provider registered for the scheme of the given URL, if any.
+fn provider_for_url(url: &str) -> Option<ImageProvider> {
+    let (scheme, _) = url.split_once("://")?;
+    IMAGE_PROVIDERS.lock().unwrap().get(&scheme.to_ascii_lowercase()).cloned()
+}
+
+#[derive(Clone, Default, PartialEq)]
+enum RemoteImageState {
+    #[default]
+    Pending,
+    Ready(ImageInner),
+    Error,
+}
+
+/// The data fetched and decoded by the background thread
+enum FetchedImage {
+    Raster(image::DynamicImage),
+    #[cfg(feature = "svg")]
+    Svg(Vec<u8>),
+}
+
+/// The maximum number of threads that fetch images at the same time
+const MAX_FETCH_THREADS: usize = 4;
+
+type FetchJob = Box<dyn FnOnce() + Send>;
+
+struct FetchQueue {
+    jobs: VecDeque<FetchJob>,
+    // The number of threads that run the jobs
+    threads: usize,
+}
+
+static FETCH_QUEUE: Mutex<FetchQueue> =
+    Mutex::new(FetchQueue { jobs: VecDeque::new(), threads: 0 });
+
+/// Queues `job` to run on one of the fetch threads, starting a thread if less than
+/// [`MAX_FETCH_THREADS`] are running.
+fn queue_fetch_job(job: FetchJob) {
+    let mut queue = FETCH_QUEUE.lock().unwrap();
+    queue.jobs.push_back(job);
+    if queue.threads < MAX_FETCH_THREADS {
+        queue.threads += 1;
+        std::thread::spawn(run_fetch_jobs);
+    }
+}
+
+/// The body of a fetch thread: runs the queued jobs and exits when there are none left.
+fn run_fetch_jobs() {
+    loop {
+        let job = {
+            let mut queue = FETCH_QUEUE.lock().unwrap();
+            match queue.jobs.pop_front() {
+                Some(job) => job,
+                None => {
+                    queue.threads -= 1;
+                    return;
+                }
+            }
+        };
+        // A panicking provider must not stop the thread, as it is counted as running
+        let _ = std::panic::catch_unwind(core::panic::AssertUnwindSafe(job));
+    }
+}
+
+thread_local! {
+    /// The images that wait for the data from the background thread, by id.
+    static PENDING_IMAGES: RefCell<BTreeMap<usize, Pin<Rc<Property<RemoteImageState>>>>> =
+        RefCell::default();
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The ids of the images whose result couldn't be sent to the event loop, so they are removed
+/// from `PENDING_IMAGES` the next time it's used.
+static UNDELIVERED_IMAGES: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+/// Removes the images whose result was never delivered from `PENDING_IMAGES` and marks them as
+/// failed.
+fn remove_undelivered_images() {
+    let undelivered = core::mem::take(&mut *UNDELIVERED_IMAGES.lock().unwrap());
+    for id in undelivered {
+        if let Some(state) = PENDING_IMAGES.with(|pending| pending.borrow_mut().remove(&id)) {
+            state.as_ref().set(RemoteImageState::Error);
+        }
+    }
+}
+
+/// An image that is loaded in the background from a URL.
+pub struct RemoteImage {
+    url: SharedString,
+    id: usize,
+    state: Pin<Rc<Property<RemoteImageState>>>,
+}
+
+impl RemoteImage {
+    /// Starts loading the image at `url` if a provider is registered for its scheme.
+    pub(crate) fn load(url: &SharedString) -> Option<Self> {
+        let provider = provider_for_url(url)?;
+        remove_undelivered_images();
+        let state = Rc::pin(Property::new(RemoteImageState::Pending));
+        let id = NEXT_ID.with(|next_id| next_id.replace(next_id.get().wrapping_add(1)));
+        PENDING_IMAGES.with(|pending| pending.borrow_mut().insert(id, state.clone()));
+
+        let thread_url = url.to_string();
+        queue_fetch_job(Box::new(move || {
+            let result = fetch_image(&provider, &thread_url);
+            let delivered = crate::api::invoke_from_event_loop(move || {
+                let Some(state) = PENDING_IMAGES.with(|pending| pending.borrow_mut().remove(&id))
+                else {
+                    return;
+                };
+                let cache_key = ImageCacheKey::Path(CachedPath::new(thread_url.as_str()));
+                match result.and_then(|fetched| decode(fetched, cache_key.clone())) {
+                    Ok(image) => state.as_ref().set(RemoteImageState::Ready(image)),
+                    Err(err) => {
+                        eprintln!("Error loading image from {}: {}", thread_url, err);
+                        state.as_ref().set(RemoteImageState::Error);
+                        // Don't keep the failed image in the cache, so that it's loaded again
+                        // the next time the URL is used
+                        super::cache::IMAGE_CACHE.with(|cache| {
+                            cache.borrow_mut().remove_if(&cache_key, |cached| {
+                                matches!(cached, ImageInner::Remote(remote) if remote.id == id)
+                            })
+                        });
+                    }
+                }
+            });
+            if delivered.is_err() {
+                UNDELIVERED_IMAGES.lock().unwrap().push(id);
+            }
+        }));
+
+        Some(Self { url: url.clone(), id, state })
+    }
+
+    /// Returns the loaded image, or [`ImageInner::None`] while it is pending or if it failed to load.
+    pub fn image(&self) -> ImageInner {
+        match self.state.as_ref().get() {
+            RemoteImageState::Ready(image) => image,
+            _ => ImageInner::None,
+        }
+    }
+
+    /// Returns whether the image is loaded, still pending or failed to load.
+    pub fn loading_status(&self) -> ImageLoadingStatus {
+        match self.state.as_ref().get() {
+            RemoteImageState::Pending => ImageLoadingStatus::Pending,
+            RemoteImageState::Ready(_) => ImageLoadingStatus::Ready,
+            RemoteImageState::Error => ImageLoadingStatus::Error,
+        }
+    }
+
+    /// Returns the URL the image is loaded from.
+    pub fn url(&self) -> &SharedString {
+        &self.url
+    }
+}
+
+impl Drop for RemoteImage {
+    fn drop(&mut self) {
+        // The thread local may already be gone when the image is dropped at thread exit
+        let _ = PENDING_IMAGES.try_with(|pending| pending.borrow_mut().remove(&self.id));
+    }
+}
+
+impl super::OpaqueImage for RemoteImage {
+    fn size(&self) -> IntSize {
+        self.image().size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        ImageCacheKey::new(&self.image()).unwrap_or(ImageCacheKey::Invalid)
+    }
+}
+
+/// Called from the background thread to fetch the data with the provider and decode raster images.
+fn fetch_image(provider: &ImageProvider, url: &str) -> Result<FetchedImage, String> {
+    let data = provider(url).map_err(|err| err.to_string())?;
+    #[cfg(feature = "svg")]
+    {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        if path.ends_with(".svg") || path.ends_with(".svgz") {
+            return Ok(FetchedImage::Svg(data));
+        }
+    }
+    image::load_from_memory(&data).map(FetchedImage::Raster).map_err(|err| err.to_string())
+}
+
+fn decode(fetched: FetchedImage, cache_key: ImageCacheKey) -> Result<ImageInner, String> {
+    match fetched {
+        FetchedImage::Raster(image) => Ok(ImageInner::EmbeddedImage {
+            cache_key,
+            buffer: super::cache::dynamic_image_to_shared_image_buffer(image),
+        }),
+        #[cfg(feature = "svg")]
+        FetchedImage::Svg(data) => super::svg::load_from_data(&data, cache_key)
+            .map(|svg| ImageInner::Svg(vtable::VRc::new(svg)))
+            .map_err(|err| err.to_string()),
+    }
+}
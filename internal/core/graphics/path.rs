@@ -5,13 +5,16 @@
 This module contains path related types and functions for the run-time library.
 */
 
-use crate::items::PathEvent;
+use crate::api::LogicalPosition;
+use crate::items::{FillRule, PathEvent, PathOperation};
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use auto_enums::auto_enum;
 use const_field_offset::FieldOffsets;
 use i_slint_core_macros::*;
 
+mod combine;
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement, Clone, Debug, PartialEq)]
 #[pin]
@@ -135,11 +138,77 @@ pub enum PathElement {
     Close,
 }
 
+/// PathDataEvent is one of the low-level events of a path, as returned by [`PathDataIterator::iter()`].
+/// The positions are in the coordinates of the path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathDataEvent {
+    /// Begin starts a new sub-path at the given position.
+    Begin {
+        /// The position where the sub-path starts.
+        at: LogicalPosition,
+    },
+    /// Line describes a straight line.
+    Line {
+        /// The start of the line.
+        from: LogicalPosition,
+        /// The end of the line.
+        to: LogicalPosition,
+    },
+    /// Quadratic describes a Bézier curve with one control point.
+    Quadratic {
+        /// The start of the curve.
+        from: LogicalPosition,
+        /// The control point of the curve.
+        ctrl: LogicalPosition,
+        /// The end of the curve.
+        to: LogicalPosition,
+    },
+    /// Cubic describes a Bézier curve with two control points.
+    Cubic {
+        /// The start of the curve.
+        from: LogicalPosition,
+        /// The first control point of the curve.
+        ctrl1: LogicalPosition,
+        /// The second control point of the curve.
+        ctrl2: LogicalPosition,
+        /// The end of the curve.
+        to: LogicalPosition,
+    },
+    /// End ends the current sub-path.
+    End {
+        /// The last position of the sub-path.
+        last: LogicalPosition,
+        /// The position where the sub-path started.
+        first: LogicalPosition,
+        /// Whether the sub-path is closed with a line from `last` to `first`.
+        close: bool,
+    },
+}
+
+impl PathDataEvent {
+    fn from_lyon(event: lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>) -> Self {
+        let p = |point: lyon_path::math::Point| LogicalPosition::new(point.x, point.y);
+        match event {
+            lyon_path::Event::Begin { at } => Self::Begin { at: p(at) },
+            lyon_path::Event::Line { from, to } => Self::Line { from: p(from), to: p(to) },
+            lyon_path::Event::Quadratic { from, ctrl, to } => {
+                Self::Quadratic { from: p(from), ctrl: p(ctrl), to: p(to) }
+            }
+            lyon_path::Event::Cubic { from, ctrl1, ctrl2, to } => {
+                Self::Cubic { from: p(from), ctrl1: p(ctrl1), ctrl2: p(ctrl2), to: p(to) }
+            }
+            lyon_path::Event::End { last, first, close } => {
+                Self::End { last: p(last), first: p(first), close }
+            }
+        }
+    }
+}
+
 struct ToLyonPathEventIterator<'a> {
     events_it: core::slice::Iter<'a, PathEvent>,
-    coordinates_it: core::slice::Iter<'a, lyon_path::math::Point>,
-    first: Option<&'a lyon_path::math::Point>,
-    last: Option<&'a lyon_path::math::Point>,
+    coordinates_it: core::slice::Iter<'a, LogicalPosition>,
+    first: Option<&'a LogicalPosition>,
+    last: Option<&'a LogicalPosition>,
 }
 
 impl<'a> Iterator for ToLyonPathEventIterator<'a> {
@@ -147,29 +216,25 @@ impl<'a> Iterator for ToLyonPathEventIterator<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         use lyon_path::Event;
 
+        let p = |position: &LogicalPosition| lyon_path::math::point(position.x, position.y);
+        let mut next = || p(self.coordinates_it.next().unwrap());
         self.events_it.next().map(|event| match event {
-            PathEvent::Begin => Event::Begin { at: *self.coordinates_it.next().unwrap() },
-            PathEvent::Line => Event::Line {
-                from: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
-            },
-            PathEvent::Quadratic => Event::Quadratic {
-                from: *self.coordinates_it.next().unwrap(),
-                ctrl: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
+            PathEvent::Begin => Event::Begin { at: next() },
+            PathEvent::Line => Event::Line { from: next(), to: next() },
+            PathEvent::Quadratic => Event::Quadratic { from: next(), ctrl: next(), to: next() },
+            PathEvent::Cubic => {
+                Event::Cubic { from: next(), ctrl1: next(), ctrl2: next(), to: next() }
+            }
+            PathEvent::EndOpen => Event::End {
+                first: p(self.first.unwrap()),
+                last: p(self.last.unwrap()),
+                close: false,
             },
-            PathEvent::Cubic => Event::Cubic {
-                from: *self.coordinates_it.next().unwrap(),
-                ctrl1: *self.coordinates_it.next().unwrap(),
-                ctrl2: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
+            PathEvent::EndClosed => Event::End {
+                first: p(self.first.unwrap()),
+                last: p(self.last.unwrap()),
+                close: true,
             },
-            PathEvent::EndOpen => {
-                Event::End { first: *self.first.unwrap(), last: *self.last.unwrap(), close: false }
-            }
-            PathEvent::EndClosed => {
-                Event::End { first: *self.first.unwrap(), last: *self.last.unwrap(), close: true }
-            }
         })
     }
 
@@ -216,13 +281,18 @@ pub struct PathDataIterator {
 
 enum LyonPathIteratorVariant {
     FromPath(lyon_path::Path),
-    FromEvents(crate::SharedVector<PathEvent>, crate::SharedVector<lyon_path::math::Point>),
+    FromEvents(crate::SharedVector<PathEvent>, crate::SharedVector<LogicalPosition>),
 }
 
 impl PathDataIterator {
     /// Create a new iterator for path traversal.
+    pub fn iter(&self) -> impl Iterator<Item = PathDataEvent> + '_ {
+        self.lyon_iter().map(PathDataEvent::from_lyon)
+    }
+
+    /// Create a new iterator for path traversal, with the events of lyon, for the algorithms of lyon.
     #[auto_enum(Iterator)]
-    pub fn iter(
+    pub(crate) fn lyon_iter(
         &self,
     ) -> impl Iterator<Item = lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>> + '_
     {
@@ -247,10 +317,10 @@ pub fn iter(
     /// Applies a transformation on the elements this iterator provides that tries to fit everything
     /// into the specified width/height, respecting the provided viewbox. If no viewbox is specified,
     /// the bounding rectangle of the path is used.
-    pub fn fit(&mut self, width: f32, height: f32, viewbox: Option<lyon_path::math::Box2D>) {
+    pub(crate) fn fit(&mut self, width: f32, height: f32, viewbox: Option<lyon_path::math::Box2D>) {
         if width > 0. || height > 0. {
             let viewbox =
-                viewbox.unwrap_or_else(|| lyon_algorithms::aabb::bounding_box(self.iter()));
+                viewbox.unwrap_or_else(|| lyon_algorithms::aabb::bounding_box(self.lyon_iter()));
             self.transform = lyon_algorithms::fit::fit_box(
                 &viewbox,
                 &lyon_path::math::Box2D::from_size(lyon_path::math::Size::new(width, height)),
@@ -258,6 +328,121 @@ pub fn fit(&mut self, width: f32, height: f32, viewbox: Option<lyon_path::math::
             );
         }
     }
+
+    /// Returns an iterator over the dashes of this path, as described by the lengths in
+    /// `dash_pattern`, which alternate between dashes and gaps. The pattern is repeated along
+    /// each sub-path, starting at `dash_offset` into the pattern. As in SVG, a pattern with an
+    /// odd number of lengths is repeated to yield an even number of lengths.
+    ///
+    /// Returns None if the pattern results in a solid line, for example if it is empty,
+    /// contains negative lengths, or if all its lengths are zero.
+    pub fn dashed(&self, dash_pattern: &[f32], dash_offset: f32) -> Option<PathDataIterator> {
+        use lyon_path::iterator::PathIterator;
+
+        if !dash_pattern.iter().all(|length| length.is_finite() && *length >= 0.)
+            || dash_pattern.iter().all(|length| *length == 0.)
+        {
+            return None;
+        }
+        let dash_pattern = if dash_pattern.len() % 2 == 1 {
+            dash_pattern.repeat(2)
+        } else {
+            dash_pattern.to_vec()
+        };
+        let pattern_length = dash_pattern.iter().sum::<f32>();
+
+        struct Dasher<'a> {
+            builder: lyon_path::path::Builder,
+            dash_pattern: &'a [f32],
+            index: usize,
+            remaining: f32,
+            /// True if the current part of the pattern is a dash, false if it's a gap.
+            in_dash: bool,
+            /// True if a sub-path for the current dash was begun in the builder.
+            dash_open: bool,
+            position: lyon_path::math::Point,
+        }
+
+        impl<'a> Dasher<'a> {
+            fn start(&mut self, dash_offset: f32) {
+                self.index = 0;
+                self.remaining = self.dash_pattern[0];
+                let mut offset = dash_offset;
+                while offset >= self.remaining {
+                    offset -= self.remaining;
+                    self.index = (self.index + 1) % self.dash_pattern.len();
+                    self.remaining = self.dash_pattern[self.index];
+                }
+                self.remaining -= offset;
+                self.in_dash = self.index % 2 == 0;
+            }
+
+            fn line(&mut self, from: lyon_path::math::Point, to: lyon_path::math::Point) {
+                self.position = from;
+                let mut length = (to - from).length();
+                let direction = (to - from).normalize();
+                while length > 0. {
+                    let step = self.remaining.min(length);
+                    if self.in_dash && !self.dash_open && step > 0. {
+                        self.builder.begin(self.position);
+                        self.dash_open = true;
+                    }
+                    self.position += direction * step;
+                    length -= step;
+                    self.remaining -= step;
+                    if self.dash_open {
+                        self.builder.line_to(self.position);
+                    }
+                    if self.remaining <= 0. {
+                        self.end();
+                        self.index = (self.index + 1) % self.dash_pattern.len();
+                        self.remaining = self.dash_pattern[self.index];
+                        self.in_dash = self.index % 2 == 0;
+                    }
+                }
+            }
+
+            fn end(&mut self) {
+                if self.dash_open {
+                    self.builder.end(false);
+                    self.dash_open = false;
+                }
+            }
+        }
+
+        let mut dasher = Dasher {
+            builder: lyon_path::Path::builder(),
+            dash_pattern: &dash_pattern,
+            index: 0,
+            remaining: 0.,
+            in_dash: false,
+            dash_open: false,
+            position: Default::default(),
+        };
+
+        for event in self.lyon_iter().flattened(0.1) {
+            match event {
+                lyon_path::Event::Begin { .. } => {
+                    dasher.start(dash_offset.rem_euclid(pattern_length))
+                }
+                lyon_path::Event::Line { from, to } => dasher.line(from, to),
+                lyon_path::Event::End { last, first, close } => {
+                    if close {
+                        dasher.line(last, first);
+                    }
+                    dasher.end();
+                }
+                lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {
+                    unreachable!("flattened paths only consist of lines")
+                }
+            }
+        }
+
+        Some(PathDataIterator {
+            it: LyonPathIteratorVariant::FromPath(dasher.builder.build()),
+            transform: Default::default(),
+        })
+    }
 }
 
 #[repr(C)]
@@ -271,11 +456,113 @@ pub enum PathData {
     Elements(crate::SharedVector<PathElement>),
     /// The Events variant describes the path as a series of low-level events and
     /// associated coordinates.
-    Events(crate::SharedVector<PathEvent>, crate::SharedVector<lyon_path::math::Point>),
+    Events(crate::SharedVector<PathEvent>, crate::SharedVector<LogicalPosition>),
     /// The Commands variant describes the path as a series of SVG encoded path commands.
     Commands(crate::SharedString),
 }
 
+/// Measures the length of a path and locates points along it, for example to move an object along
+/// the path or to draw only a part of its stroke.
+///
+/// Curves are measured by approximating them with lines. The segments that close sub-paths are
+/// part of the path. The coordinates are the ones of the path commands, before the path is fitted
+/// into the view-box of the `Path` element.
+///
+/// ```
+/// # use i_slint_core::api::{LogicalPosition, PathMeasure};
+/// let measure = PathMeasure::from_svg_commands("M 0 0 L 10 0 L 10 10");
+/// assert_eq!(measure.length(), 20.);
+/// let point = measure.point_at(15.).unwrap();
+/// assert_eq!(point.position, LogicalPosition::new(10., 5.));
+/// assert_eq!(point.angle, 90.);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathMeasure {
+    segments: alloc::vec::Vec<lyon_geom::LineSegment<f32>>,
+    length: f32,
+}
+
+impl PathMeasure {
+    /// Creates a measure of the path described by the given SVG path commands, in the format of
+    /// the `commands` property of the `Path` element. Invalid commands result in an empty path.
+    pub fn from_svg_commands(commands: &str) -> Self {
+        Self::from(&PathData::Commands(commands.into()))
+    }
+
+    /// Returns the length of the path.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// Returns the point at the specified distance from the start of the path. The distance is
+    /// clamped to the length of the path.
+    ///
+    /// Returns None if the path is empty.
+    pub fn point_at(&self, distance: f32) -> Option<PathPoint> {
+        let point = |segment: &lyon_geom::LineSegment<f32>, t: f32| {
+            let position = segment.sample(t);
+            let direction = segment.to_vector();
+            PathPoint {
+                position: crate::api::LogicalPosition::new(position.x, position.y),
+                angle: direction.y.atan2(direction.x).to_degrees(),
+            }
+        };
+        let mut remaining = distance.max(0.);
+        for segment in &self.segments {
+            let length = segment.length();
+            if remaining <= length {
+                return Some(point(segment, remaining / length));
+            }
+            remaining -= length;
+        }
+        self.segments.last().map(|segment| point(segment, 1.))
+    }
+}
+
+impl From<&PathData> for PathMeasure {
+    fn from(path: &PathData) -> Self {
+        use lyon_path::iterator::PathIterator;
+
+        let Some(iterator) = path.clone().iter() else { return Default::default() };
+        let mut segments = alloc::vec::Vec::new();
+        let mut first = lyon_path::math::Point::default();
+        let mut current = first;
+        for event in iterator.lyon_iter().flattened(0.01) {
+            let to = match event {
+                lyon_path::Event::Begin { at } => {
+                    first = at;
+                    current = at;
+                    continue;
+                }
+                lyon_path::Event::Line { to, .. } => to,
+                lyon_path::Event::End { close: true, .. } => first,
+                lyon_path::Event::End { close: false, .. } => continue,
+                lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {
+                    unreachable!("flattened paths only consist of lines")
+                }
+            };
+            if to != current {
+                segments.push(lyon_geom::LineSegment { from: current, to });
+                current = to;
+            }
+        }
+        let length = segments.iter().map(|segment| segment.length()).sum();
+        Self { segments, length }
+    }
+}
+
+/// A point on a path, as returned by [`PathMeasure::point_at()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct PathPoint {
+    /// The position of the point, in the coordinates of the path.
+    pub position: crate::api::LogicalPosition,
+    /// The direction of the path at this point, as an angle in degrees. It ranges from -180 to 180,
+    /// where 0 points to the right and the angle increases clockwise, like the `rotation-angle`
+    /// property of elements.
+    pub angle: f32,
+}
+
 impl Default for PathData {
     fn default() -> Self {
         Self::None
@@ -283,7 +570,98 @@ fn default() -> Self {
 }
 
 impl PathData {
-    /// This function returns an iterator that allows traversing the path by means of lyon events.
+    /// Combines the shape of this path with the shape of `other`, for example to cut out the
+    /// shape of `other` with [`PathOperation::Difference`]. The inside of both paths is
+    /// determined using `fill_rule`. Curves are approximated with lines in the returned path.
+    pub fn combine(self, other: PathData, operation: PathOperation, fill_rule: FillRule) -> Self {
+        combine::combine(self, other, operation, fill_rule)
+    }
+
+    /// Returns the path as a string of SVG path commands, as accepted by the `commands` property
+    /// of the `Path` element.
+    pub fn to_svg_commands(&self) -> crate::SharedString {
+        use core::fmt::Write;
+
+        let mut commands = alloc::string::String::new();
+        match self {
+            PathData::None => {}
+            PathData::Elements(elements) => {
+                for element in elements.iter() {
+                    let _ = match element {
+                        PathElement::MoveTo(PathMoveTo { x, y }) => write!(commands, "M {x} {y} "),
+                        PathElement::LineTo(PathLineTo { x, y }) => write!(commands, "L {x} {y} "),
+                        PathElement::ArcTo(PathArcTo {
+                            x,
+                            y,
+                            radius_x,
+                            radius_y,
+                            x_rotation,
+                            large_arc,
+                            sweep,
+                        }) => write!(
+                            commands,
+                            "A {radius_x} {radius_y} {x_rotation} {} {} {x} {y} ",
+                            *large_arc as u8, *sweep as u8
+                        ),
+                        PathElement::CubicTo(PathCubicTo {
+                            x,
+                            y,
+                            control_1_x,
+                            control_1_y,
+                            control_2_x,
+                            control_2_y,
+                        }) => write!(
+                            commands,
+                            "C {control_1_x} {control_1_y} {control_2_x} {control_2_y} {x} {y} "
+                        ),
+                        PathElement::QuadraticTo(PathQuadraticTo {
+                            x,
+                            y,
+                            control_x,
+                            control_y,
+                        }) => {
+                            write!(commands, "Q {control_x} {control_y} {x} {y} ")
+                        }
+                        PathElement::Close => write!(commands, "Z "),
+                    };
+                }
+            }
+            PathData::Events(events, coordinates) => {
+                let mut coordinates = coordinates.iter();
+                let mut next = || coordinates.next().copied().unwrap_or_default();
+                for event in events.iter() {
+                    let _ = match event {
+                        PathEvent::Begin => {
+                            let at = next();
+                            write!(commands, "M {} {} ", at.x, at.y)
+                        }
+                        PathEvent::Line => {
+                            let (_, to) = (next(), next());
+                            write!(commands, "L {} {} ", to.x, to.y)
+                        }
+                        PathEvent::Quadratic => {
+                            let (_, ctrl, to) = (next(), next(), next());
+                            write!(commands, "Q {} {} {} {} ", ctrl.x, ctrl.y, to.x, to.y)
+                        }
+                        PathEvent::Cubic => {
+                            let (_, ctrl1, ctrl2, to) = (next(), next(), next(), next());
+                            write!(
+                                commands,
+                                "C {} {} {} {} {} {} ",
+                                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                            )
+                        }
+                        PathEvent::EndOpen => Ok(()),
+                        PathEvent::EndClosed => write!(commands, "Z "),
+                    };
+                }
+            }
+            PathData::Commands(commands) => return commands.clone(),
+        }
+        commands.trim_end().into()
+    }
+
+    /// This function returns an iterator that allows traversing the path by means of low-level events.
     pub fn iter(self) -> Option<PathDataIterator> {
         PathDataIterator {
             it: match self {
@@ -386,6 +764,292 @@ fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
     }
 }
 
+impl crate::properties::InterpolatedPropertyValue for PathData {
+    /// Paths with the same sub-paths and segments are morphed into each other by interpolating
+    /// their points. Lines and quadratic curves are converted to cubic curves to morph them into
+    /// other kinds of segments. Paths with a different structure change at the end of the animation.
+    fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+        morph_paths(self, target_value, t).unwrap_or_else(|| {
+            if t < 1. {
+                self.clone()
+            } else {
+                target_value.clone()
+            }
+        })
+    }
+}
+
+/// Returns the path in between `from` and `to` at the progress `t`, or None if the two paths
+/// don't have the same structure.
+fn morph_paths(from: &PathData, to: &PathData, t: f32) -> Option<PathData> {
+    use lyon_path::math::Point;
+    use lyon_path::Event;
+
+    fn as_cubic(event: Event<Point, Point>) -> Option<[Point; 4]> {
+        match event {
+            Event::Line { from, to } => {
+                Some([from, from.lerp(to, 1. / 3.), from.lerp(to, 2. / 3.), to])
+            }
+            Event::Quadratic { from, ctrl, to } => {
+                Some([from, from.lerp(ctrl, 2. / 3.), to.lerp(ctrl, 2. / 3.), to])
+            }
+            Event::Cubic { from, ctrl1, ctrl2, to } => Some([from, ctrl1, ctrl2, to]),
+            Event::Begin { .. } | Event::End { .. } => None,
+        }
+    }
+
+    let from = from.clone().iter()?.lyon_iter().collect::<alloc::vec::Vec<_>>();
+    let to = to.clone().iter()?.lyon_iter().collect::<alloc::vec::Vec<_>>();
+    if from.len() != to.len() {
+        return None;
+    }
+
+    let mut events = crate::SharedVector::default();
+    let mut points = crate::SharedVector::default();
+    let mut lerp = |a: Point, b: Point| {
+        let p = a.lerp(b, t);
+        points.push(LogicalPosition::new(p.x, p.y))
+    };
+    for (a, b) in from.into_iter().zip(to) {
+        events.push(match (a, b) {
+            (Event::Begin { at: a }, Event::Begin { at: b }) => {
+                lerp(a, b);
+                PathEvent::Begin
+            }
+            (Event::Line { from: from_a, to: to_a }, Event::Line { from: from_b, to: to_b }) => {
+                lerp(from_a, from_b);
+                lerp(to_a, to_b);
+                PathEvent::Line
+            }
+            (
+                Event::Quadratic { from: from_a, ctrl: ctrl_a, to: to_a },
+                Event::Quadratic { from: from_b, ctrl: ctrl_b, to: to_b },
+            ) => {
+                lerp(from_a, from_b);
+                lerp(ctrl_a, ctrl_b);
+                lerp(to_a, to_b);
+                PathEvent::Quadratic
+            }
+            (Event::End { close: close_a, .. }, Event::End { close: close_b, .. })
+                if close_a == close_b =>
+            {
+                if close_a {
+                    PathEvent::EndClosed
+                } else {
+                    PathEvent::EndOpen
+                }
+            }
+            (a, b) => {
+                for (a, b) in as_cubic(a)?.into_iter().zip(as_cubic(b)?) {
+                    lerp(a, b);
+                }
+                PathEvent::Cubic
+            }
+        });
+    }
+    Some(PathData::Events(events, points))
+}
+
+#[test]
+fn svg_commands() {
+    let point = LogicalPosition::new;
+
+    assert_eq!(PathData::None.to_svg_commands(), "");
+    assert_eq!(PathData::Commands("M 0 0 L 1 1".into()).to_svg_commands(), "M 0 0 L 1 1");
+
+    let elements = PathData::Elements(
+        [
+            PathElement::MoveTo(PathMoveTo { x: 0., y: 10. }),
+            PathElement::LineTo(PathLineTo { x: 5.5, y: 10. }),
+            PathElement::ArcTo(PathArcTo {
+                x: 20.,
+                y: 10.,
+                radius_x: 5.,
+                radius_y: 6.,
+                x_rotation: 45.,
+                large_arc: true,
+                sweep: false,
+            }),
+            PathElement::CubicTo(PathCubicTo {
+                x: 0.,
+                y: 0.,
+                control_1_x: 1.,
+                control_1_y: 2.,
+                control_2_x: 3.,
+                control_2_y: 4.,
+            }),
+            PathElement::QuadraticTo(PathQuadraticTo {
+                x: 7.,
+                y: 8.,
+                control_x: 5.,
+                control_y: 6.,
+            }),
+            PathElement::Close,
+        ]
+        .as_slice()
+        .into(),
+    );
+    assert_eq!(
+        elements.to_svg_commands(),
+        "M 0 10 L 5.5 10 A 5 6 45 1 0 20 10 C 1 2 3 4 0 0 Q 5 6 7 8 Z"
+    );
+
+    let events = PathData::Events(
+        [PathEvent::Begin, PathEvent::Line, PathEvent::Quadratic, PathEvent::EndClosed]
+            .as_slice()
+            .into(),
+        [point(0., 0.), point(0., 0.), point(1., 0.), point(1., 0.), point(2., 1.), point(2., 2.)]
+            .as_slice()
+            .into(),
+    );
+    assert_eq!(events.to_svg_commands(), "M 0 0 L 1 0 Q 2 1 2 2 Z");
+    // The commands describe the same path
+    assert_eq!(
+        PathData::Commands(events.to_svg_commands()).iter().unwrap().iter().collect::<Vec<_>>(),
+        events.iter().unwrap().iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn path_measurement() {
+    use crate::api::LogicalPosition;
+
+    let empty = PathMeasure::from(&PathData::None);
+    assert_eq!(empty.length(), 0.);
+    assert_eq!(empty.point_at(1.), None);
+
+    let triangle = PathMeasure::from_svg_commands("M 0 0 L 10 0 L 10 10 Z");
+    assert!((triangle.length() - (20. + 200f32.sqrt())).abs() < 0.001);
+    let at = |distance| triangle.point_at(distance).map(|p| (p.position, p.angle));
+    assert_eq!(at(-1.), Some((LogicalPosition::new(0., 0.), 0.)));
+    assert_eq!(at(5.), Some((LogicalPosition::new(5., 0.), 0.)));
+    assert_eq!(at(15.), Some((LogicalPosition::new(10., 5.), 90.)));
+    let (p, angle) = at(20. + 50f32.sqrt()).unwrap();
+    assert!((p.x - 5.).abs() < 0.001 && (p.y - 5.).abs() < 0.001);
+    assert!((angle + 135.).abs() < 0.001);
+    let (p, _) = at(100.).unwrap();
+    assert!(p.x.abs() < 0.001 && p.y.abs() < 0.001);
+
+    let circle = PathMeasure::from_svg_commands("M 0 10 A 10 10 0 1 1 20 10 A 10 10 0 1 1 0 10 Z");
+    // Arcs are approximated with Bézier curves
+    assert!((circle.length() - 20. * core::f32::consts::PI).abs() < 0.1);
+    let p = circle.point_at(5. * core::f32::consts::PI).unwrap();
+    assert!((p.position.x - 10.).abs() < 0.05 && p.position.y.abs() < 0.05);
+    assert!(p.angle.abs() < 3.);
+}
+
+#[test]
+fn dashed_path() {
+    let point = LogicalPosition::new;
+
+    let path = PathData::Events(
+        [PathEvent::Begin, PathEvent::Line, PathEvent::EndOpen].as_slice().into(),
+        [point(0., 0.), point(0., 0.), point(10., 0.)].as_slice().into(),
+    )
+    .iter()
+    .unwrap();
+
+    assert!(path.dashed(&[], 0.).is_none());
+    assert!(path.dashed(&[0., 0.], 0.).is_none());
+    assert!(path.dashed(&[2., -1.], 0.).is_none());
+
+    let dashes = |dash_pattern: &[f32], dash_offset: f32| {
+        path.dashed(dash_pattern, dash_offset)
+            .unwrap()
+            .iter()
+            .filter_map(|event| match event {
+                PathDataEvent::End { first, last, .. } => Some((first.x, last.x)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(dashes(&[3., 1.], 0.), vec![(0., 3.), (4., 7.), (8., 10.)]);
+    assert_eq!(dashes(&[3., 1.], 2.), vec![(0., 1.), (2., 5.), (6., 9.)]);
+    assert_eq!(dashes(&[3., 1.], -1.), vec![(1., 4.), (5., 8.), (9., 10.)]);
+    // An odd number of lengths is repeated
+    assert_eq!(dashes(&[4.], 0.), vec![(0., 4.), (8., 10.)]);
+}
+
+#[test]
+fn arc_to_path() {
+    use lyon_path::iterator::PathIterator;
+
+    let arc = |radius: f32, large_arc: bool, sweep: bool| {
+        let path = PathData::Elements(
+            [
+                PathElement::MoveTo(PathMoveTo { x: 0., y: 0. }),
+                PathElement::ArcTo(PathArcTo {
+                    x: 20.,
+                    y: 0.,
+                    radius_x: radius,
+                    radius_y: radius,
+                    x_rotation: 0.,
+                    large_arc,
+                    sweep,
+                }),
+            ]
+            .as_slice()
+            .into(),
+        );
+        let points = path
+            .iter()
+            .unwrap()
+            .lyon_iter()
+            .flattened(0.01)
+            .filter_map(|event| match event {
+                lyon_path::Event::Line { to, .. } => Some(to),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let min_y = points.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+        let max_y = points.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max);
+        (*points.last().unwrap(), min_y, max_y)
+    };
+
+    // As in SVG, a sweep goes in the direction of positive angles, which is clockwise with y pointing down
+    let (end, min_y, max_y) = arc(10., false, true);
+    assert!((end.x - 20.).abs() < 0.01 && end.y.abs() < 0.01);
+    assert!((min_y + 10.).abs() < 0.01 && max_y.abs() < 0.01);
+    let (_, min_y, max_y) = arc(10., false, false);
+    assert!(min_y.abs() < 0.01 && (max_y - 10.).abs() < 0.01);
+
+    // With a larger radius, large_arc selects the longer of the two arcs
+    let (_, min_y, _) = arc(20., false, true);
+    assert!(min_y > -10.);
+    let (end, min_y, _) = arc(20., true, true);
+    assert!((end.x - 20.).abs() < 0.01 && end.y.abs() < 0.01);
+    assert!(min_y < -30.);
+
+    // A zero radius yields a straight line
+    let (end, min_y, max_y) = arc(0., false, true);
+    assert!((end.x - 20.).abs() < 0.01 && min_y.abs() < 0.01 && max_y.abs() < 0.01);
+}
+
+#[test]
+fn morph_path() {
+    use crate::properties::InterpolatedPropertyValue;
+
+    let line = PathData::Commands("M 0 0 L 10 0".into());
+    let line2 = PathData::Commands("M 0 10 L 20 10".into());
+    assert_eq!(line.interpolate(&line2, 0.5).to_svg_commands(), "M 0 5 L 15 5");
+    assert_eq!(line.interpolate(&line2, 1.).to_svg_commands(), "M 0 10 L 20 10");
+
+    // A line is morphed into a curve
+    let curve = PathData::Commands("M 0 0 C 0 10 10 10 10 0".into());
+    let half = line.interpolate(&curve, 0.5);
+    let PathData::Events(events, points) = &half else { panic!("expected events") };
+    assert_eq!(events.as_slice(), [PathEvent::Begin, PathEvent::Cubic, PathEvent::EndOpen]);
+    assert!((points[2].y - 5.).abs() < 0.001 && (points[3].y - 5.).abs() < 0.001);
+    assert!((points[4].x - 10.).abs() < 0.001 && points[4].y.abs() < 0.001);
+
+    // Paths with a different structure are not morphed
+    let triangle = PathData::Commands("M 0 0 L 10 0 L 10 10 Z".into());
+    assert_eq!(line.interpolate(&triangle, 0.5), line);
+    assert_eq!(line.interpolate(&triangle, 1.), triangle);
+    assert_eq!(PathData::None.interpolate(&triangle, 0.5), PathData::None);
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -414,7 +1078,7 @@ pub(crate) mod ffi {
         out_coordinates: *mut c_void,
         first_event: *const PathEvent,
         event_count: usize,
-        first_coordinate: *const Point,
+        first_coordinate: *const LogicalPosition,
         coordinate_count: usize,
     ) {
         let events =
@@ -424,6 +1088,6 @@ pub(crate) mod ffi {
             first_coordinate,
             coordinate_count,
         ));
-        core::ptr::write(out_coordinates as *mut crate::SharedVector<Point>, coordinates);
+        core::ptr::write(out_coordinates as *mut crate::SharedVector<LogicalPosition>, coordinates);
     }
 }
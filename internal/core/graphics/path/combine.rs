@@ -0,0 +1,383 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Boolean operations on paths.
+
+Both paths are flattened into polygons, and their edges are split at all the points where they
+intersect. The winding numbers of both paths on either side of each of the split edges then tell
+whether the edge is part of the outline of the combined shape. The resulting edges are oriented
+so that the combined shape lies on their left, and are chained into closed sub-paths.
+*/
+
+use super::{PathData, PathElement, PathLineTo, PathMoveTo};
+use crate::items::{FillRule, PathOperation};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use euclid::default::{Point2D, Vector2D};
+
+type Point = Point2D<f64>;
+/// A point snapped to the grid, in units of the grid size.
+type GridPoint = (i64, i64);
+
+struct Edge {
+    from: Point,
+    to: Point,
+    /// 0 for the first path, 1 for the second path.
+    operand: usize,
+}
+
+pub(super) fn combine(
+    a: PathData,
+    b: PathData,
+    operation: PathOperation,
+    fill_rule: FillRule,
+) -> PathData {
+    let polygons = [polygons(a), polygons(b)];
+
+    let bounding_box = euclid::default::Box2D::from_points(
+        polygons.iter().flatten().flatten().flat_map(|event| match *event {
+            lyon_path::Event::Begin { at } => [at, at, at, at],
+            lyon_path::Event::Line { from, to } => [from, to, to, to],
+            lyon_path::Event::Quadratic { from, ctrl, to } => [from, ctrl, to, to],
+            lyon_path::Event::Cubic { from, ctrl1, ctrl2, to } => [from, ctrl1, ctrl2, to],
+            lyon_path::Event::End { last, first, .. } => [last, first, first, first],
+        }),
+    );
+    let size = bounding_box.width().max(bounding_box.height());
+    if !(size > 0.) {
+        return PathData::None;
+    }
+    // Points closer than this are considered to be the same point
+    let grid_size = size * 1e-5;
+    let tolerance = size * 2e-4;
+
+    let mut edges = Vec::new();
+    for (operand, polygons) in IntoIterator::into_iter(polygons).enumerate() {
+        for polygon in polygons.into_iter().map(|p| flatten(p, tolerance)) {
+            // Sub-paths are implicitly closed when filling them
+            for (i, from) in polygon.iter().enumerate() {
+                let to = polygon[(i + 1) % polygon.len()];
+                if *from != to {
+                    edges.push(Edge { from: *from, to, operand });
+                }
+            }
+        }
+    }
+
+    let edges = split_edges(&edges, grid_size);
+    let inside = |winding: [i32; 2]| {
+        let [a, b] = winding.map(|w| match fill_rule {
+            FillRule::Nonzero => w != 0,
+            FillRule::Evenodd => w % 2 != 0,
+        });
+        match operation {
+            PathOperation::Union => a || b,
+            PathOperation::Intersection => a && b,
+            PathOperation::Difference => a && !b,
+            PathOperation::Xor => a != b,
+        }
+    };
+
+    // The edges of the outline of the combined shape, with the shape on their left
+    let mut outline = BTreeMap::<GridPoint, Vec<GridPoint>>::new();
+    let mut outline_edges = Vec::new();
+    for (index, &(from, to, _)) in edges.iter().enumerate() {
+        let (left, right) = winding_numbers(&edges, index, grid_size);
+        let edge = match (inside(left), inside(right)) {
+            (true, false) => (from, to),
+            (false, true) => (to, from),
+            _ => continue,
+        };
+        let targets = outline.entry(edge.0).or_default();
+        // Coincident edges of the two paths would otherwise be part of the outline twice
+        if !targets.contains(&edge.1) {
+            targets.push(edge.1);
+            outline_edges.push(edge);
+        }
+    }
+
+    let to_point = |(x, y): GridPoint| PathMoveTo {
+        x: (x as f64 * grid_size) as f32,
+        y: (y as f64 * grid_size) as f32,
+    };
+    let mut elements = Vec::new();
+    for (start, _) in outline_edges {
+        let Some(next) = outline.get_mut(&start).and_then(|targets| targets.pop()) else {
+            // Already part of a previous sub-path
+            continue;
+        };
+        elements.push(PathElement::MoveTo(to_point(start)));
+        let mut current = next;
+        while current != start {
+            let PathMoveTo { x, y } = to_point(current);
+            elements.push(PathElement::LineTo(PathLineTo { x, y }));
+            match outline.get_mut(&current).and_then(|targets| targets.pop()) {
+                Some(next) => current = next,
+                // Can only happen with rounding errors, the sub-path is closed implicitly
+                None => break,
+            }
+        }
+        elements.push(PathElement::Close);
+    }
+
+    PathData::Elements(elements.as_slice().into())
+}
+
+/// Returns the sub-paths of the path, in path coordinates. Curves are kept as they are, so that
+/// the caller can flatten them once the size of the path is known.
+fn polygons(path: PathData) -> Vec<Vec<lyon_path::Event<Point, Point>>> {
+    let Some(iterator) = path.iter() else { return Vec::new() };
+    let mut polygons = Vec::new();
+    let mut polygon = Vec::new();
+    for event in iterator.lyon_iter() {
+        match event {
+            lyon_path::Event::End { .. } => {
+                polygons.push(core::mem::take(&mut polygon));
+            }
+            lyon_path::Event::Begin { at } => {
+                polygon.push(lyon_path::Event::Begin { at: at.to_f64() })
+            }
+            lyon_path::Event::Line { from, to } => {
+                polygon.push(lyon_path::Event::Line { from: from.to_f64(), to: to.to_f64() })
+            }
+            lyon_path::Event::Quadratic { from, ctrl, to } => {
+                polygon.push(lyon_path::Event::Quadratic {
+                    from: from.to_f64(),
+                    ctrl: ctrl.to_f64(),
+                    to: to.to_f64(),
+                })
+            }
+            lyon_path::Event::Cubic { from, ctrl1, ctrl2, to } => {
+                polygon.push(lyon_path::Event::Cubic {
+                    from: from.to_f64(),
+                    ctrl1: ctrl1.to_f64(),
+                    ctrl2: ctrl2.to_f64(),
+                    to: to.to_f64(),
+                })
+            }
+        }
+    }
+    polygons
+}
+
+fn flatten(polygon: Vec<lyon_path::Event<Point, Point>>, tolerance: f64) -> Vec<Point> {
+    use lyon_path::geom::{CubicBezierSegment, QuadraticBezierSegment};
+
+    let mut points = Vec::new();
+    for event in polygon {
+        match event {
+            lyon_path::Event::Begin { at } => points.push(at),
+            lyon_path::Event::Line { to, .. } => points.push(to),
+            lyon_path::Event::Quadratic { from, ctrl, to } => {
+                QuadraticBezierSegment { from, ctrl, to }
+                    .for_each_flattened(tolerance, &mut |segment| points.push(segment.to));
+            }
+            lyon_path::Event::Cubic { from, ctrl1, ctrl2, to } => {
+                CubicBezierSegment { from, ctrl1, ctrl2, to }
+                    .for_each_flattened(tolerance, &mut |segment| points.push(segment.to));
+            }
+            lyon_path::Event::End { .. } => {}
+        }
+    }
+    points
+}
+
+/// Splits the edges at the points where they intersect with or touch other edges, and snaps the
+/// resulting points to the grid. Returns the edges as (from, to, operand).
+fn split_edges(edges: &[Edge], grid_size: f64) -> Vec<(GridPoint, GridPoint, usize)> {
+    // The points at which each edge is split, as the position along the edge and the point
+    let mut splits: Vec<Vec<(f64, Point)>> = edges.iter().map(|_| Vec::new()).collect();
+
+    let position_on = |edge: &Edge, p: Point| {
+        let d = edge.to - edge.from;
+        (p - edge.from).dot(d) / d.square_length()
+    };
+
+    for (i, e1) in edges.iter().enumerate() {
+        for (j, e2) in edges.iter().enumerate().skip(i + 1) {
+            let d1 = e1.to - e1.from;
+            let d2 = e2.to - e2.from;
+            let denominator = d1.cross(d2);
+            let offset = e2.from - e1.from;
+            if denominator.abs() <= f64::EPSILON * d1.length() * d2.length() {
+                // Parallel edges only need to be split if they overlap
+                if (offset.cross(d1) / d1.length()).abs() > grid_size {
+                    continue;
+                }
+                for (e, split, points) in [(e1, i, [e2.from, e2.to]), (e2, j, [e1.from, e1.to])] {
+                    for p in points {
+                        let t = position_on(e, p);
+                        if t > 0. && t < 1. {
+                            splits[split].push((t, p));
+                        }
+                    }
+                }
+                continue;
+            }
+            let t1 = offset.cross(d2) / denominator;
+            let t2 = offset.cross(d1) / denominator;
+            if !(0. ..=1.).contains(&t1) || !(0. ..=1.).contains(&t2) {
+                continue;
+            }
+            // Prefer the existing end points over the computed intersection
+            let p = if t2 == 0. {
+                e2.from
+            } else if t2 == 1. {
+                e2.to
+            } else {
+                e1.from + d1 * t1
+            };
+            if t1 > 0. && t1 < 1. {
+                splits[i].push((t1, p));
+            }
+            if t2 > 0. && t2 < 1. {
+                splits[j].push((t2, p));
+            }
+        }
+    }
+
+    let snap = |p: Point| ((p.x / grid_size).round() as i64, (p.y / grid_size).round() as i64);
+    let mut result = Vec::new();
+    for (edge, mut splits) in edges.iter().zip(splits) {
+        splits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut from = snap(edge.from);
+        for to in splits.into_iter().map(|(_, p)| snap(p)).chain(core::iter::once(snap(edge.to))) {
+            if to != from {
+                result.push((from, to, edge.operand));
+                from = to;
+            }
+        }
+    }
+    result
+}
+
+/// Returns the winding numbers of both paths on the left and on the right of the edge at `index`.
+fn winding_numbers(
+    edges: &[(GridPoint, GridPoint, usize)],
+    index: usize,
+    grid_size: f64,
+) -> ([i32; 2], [i32; 2]) {
+    let to_point = |(x, y): GridPoint| Point::new(x as f64 * grid_size, y as f64 * grid_size);
+    let (from, to, _) = edges[index];
+    let (from_point, to_point_) = (to_point(from), to_point(to));
+    let middle = from_point.lerp(to_point_, 0.5);
+    // A ray cast from the middle of the edge towards its left side
+    let direction: Vector2D<f64> = {
+        let d = (to_point_ - from_point).normalize();
+        Vector2D::new(-d.y, d.x)
+    };
+    // Coordinates in a rotated space where the ray points towards positive x
+    let local = |p: Point| {
+        let v = p - middle;
+        (v.dot(direction), direction.cross(v))
+    };
+
+    let mut left = [0; 2];
+    let mut right = [0; 2];
+    for &(edge_from, edge_to, operand) in edges {
+        if (edge_from, edge_to) == (from, to) {
+            // The edge itself, or a coincident edge of the same direction
+            right[operand] -= 1;
+            continue;
+        }
+        if (edge_from, edge_to) == (to, from) {
+            right[operand] += 1;
+            continue;
+        }
+        let (x1, y1) = local(to_point(edge_from));
+        let (x2, y2) = local(to_point(edge_to));
+        let crossing = if y1 <= 0. && y2 > 0. {
+            1
+        } else if y2 <= 0. && y1 > 0. {
+            -1
+        } else {
+            continue;
+        };
+        if x1 + (x2 - x1) * (-y1 / (y2 - y1)) > 0. {
+            left[operand] += crossing;
+        }
+    }
+    for operand in 0..2 {
+        right[operand] += left[operand];
+    }
+    (left, right)
+}
+
+#[test]
+fn combine_paths() {
+    use super::PathArcTo;
+    use lyon_path::iterator::PathIterator;
+
+    let rectangle = |x: f32, y: f32, width: f32, height: f32| {
+        PathData::Commands(format!("M {x} {y} h {width} v {height} h {} Z", -width).into())
+    };
+    // The signed area, which is only correct if the sub-paths are oriented consistently
+    let area = |path: PathData| {
+        let mut area = 0.;
+        let mut first = lyon_path::math::point(0., 0.);
+        for event in path.iter().unwrap().lyon_iter().flattened(0.01) {
+            match event {
+                lyon_path::Event::Begin { at } => first = at,
+                lyon_path::Event::Line { from, to } => {
+                    area += from.to_vector().cross(to.to_vector())
+                }
+                lyon_path::Event::End { last, .. } => {
+                    area += last.to_vector().cross(first.to_vector())
+                }
+                _ => unreachable!(),
+            }
+        }
+        (area / 2.).abs()
+    };
+    let combined_area =
+        |a: PathData, b: PathData, operation| area(combine(a, b, operation, FillRule::Nonzero));
+
+    let a = rectangle(0., 0., 10., 10.);
+    let b = rectangle(5., 5., 10., 10.);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Union) - 175.).abs() < 0.01);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Intersection) - 25.).abs() < 0.01);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Difference) - 75.).abs() < 0.01);
+    assert!((combined_area(a, b, PathOperation::Xor) - 150.).abs() < 0.01);
+
+    // Edges that partially coincide
+    let a = rectangle(0., 0., 10., 10.);
+    let b = rectangle(0., 5., 10., 10.);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Union) - 150.).abs() < 0.01);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Intersection) - 50.).abs() < 0.01);
+    assert!((combined_area(a.clone(), b.clone(), PathOperation::Difference) - 50.).abs() < 0.01);
+    assert!((combined_area(a, b, PathOperation::Xor) - 100.).abs() < 0.01);
+
+    // Punch a hole into a shape
+    let arc = |x, y| {
+        PathElement::ArcTo(PathArcTo {
+            x,
+            y,
+            radius_x: 5.,
+            radius_y: 5.,
+            x_rotation: 0.,
+            large_arc: false,
+            sweep: true,
+        })
+    };
+    let circle = PathData::Elements(
+        [
+            PathElement::MoveTo(PathMoveTo { x: 5., y: 10. }),
+            arc(15., 10.),
+            arc(5., 10.),
+            PathElement::Close,
+        ]
+        .as_slice()
+        .into(),
+    );
+    let circle_area = combined_area(circle.clone(), PathData::None, PathOperation::Union);
+    assert!((circle_area - 25. * core::f32::consts::PI).abs() < 0.5);
+    let hole = combined_area(rectangle(0., 0., 20., 20.), circle, PathOperation::Difference);
+    // Curves are flattened with a tolerance that depends on the size of both paths
+    assert!((hole - (400. - circle_area)).abs() < 0.2);
+
+    assert_eq!(
+        combine(PathData::None, PathData::None, PathOperation::Union, FillRule::Nonzero),
+        PathData::None
+    );
+}
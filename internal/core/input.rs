@@ -21,6 +21,9 @@
 use core::cell::Cell;
 use core::pin::Pin;
 use core::time::Duration;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 
 /// A mouse or touch event
 ///
@@ -65,15 +68,25 @@ pub fn position(&self) -> Option<LogicalPoint> {
 
     /// Translate the position by the given value
     pub fn translate(&mut self, vec: LogicalVector) {
-        let pos = match self {
+        if let Some(pos) = self.position_mut() {
+            *pos += vec;
+        }
+    }
+
+    fn position_mut(&mut self) -> Option<&mut LogicalPoint> {
+        match self {
             MouseEvent::Pressed { position, .. } => Some(position),
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
-        };
-        if let Some(pos) = pos {
-            *pos += vec;
+        }
+    }
+
+    /// Map the position from the coordinates of `item` to the coordinates of its children
+    fn map_to_children(&mut self, item: &ItemRc) {
+        if let Some(pos) = self.position_mut() {
+            *pos = map_to_children(item, *pos);
         }
     }
 
@@ -276,6 +289,10 @@ pub enum KeyEventType {
     UpdateComposition = 2,
     /// The input method replaces the currently composed text with the final result of the composition.
     CommitComposition = 3,
+    /// Sent to the item that has the focus before a key press activates a `Shortcut`. The item
+    /// returns [`KeyEventResult::EventAccepted`] to receive the key press instead, for example for
+    /// the keys that edit the text of a text input.
+    ShortcutOverride = 4,
 }
 
 impl KeyEvent {
@@ -295,14 +312,13 @@ pub fn shortcut(&self) -> Option<StandardShortcut> {
                 "s" => Some(StandardShortcut::Save),
                 "p" => Some(StandardShortcut::Print),
                 "z" => Some(StandardShortcut::Undo),
-                #[cfg(target_os = "windows")]
+                #[cfg(not(target_os = "macos"))]
                 "y" => Some(StandardShortcut::Redo),
                 "r" => Some(StandardShortcut::Refresh),
                 _ => None,
             }
         } else if self.modifiers.control && self.modifiers.shift {
             match self.text.as_str() {
-                #[cfg(not(target_os = "windows"))]
                 "z" => Some(StandardShortcut::Redo),
                 _ => None,
             }
@@ -533,6 +549,136 @@ pub fn check_repeat(&self, mouse_event: MouseEvent, click_interval: Duration) ->
     }
 }
 
+/// A touch event, with the position of the touch point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TouchPhase {
+    Pressed(LogicalPoint),
+    Moved(LogicalPoint),
+    Released(LogicalPoint),
+    Cancelled,
+}
+
+/// The state which a window should hold for the touch input
+#[derive(Default)]
+pub(crate) struct TouchInputState {
+    /// The touch points on the screen with their last position, in the order they were pressed
+    points: Vec<(u64, LogicalPoint)>,
+    /// The touch point that is handled like the mouse
+    mouse_touch: Option<u64>,
+    /// The pinch gesture of the two first touch points
+    pinch: Option<PinchGesture>,
+}
+
+struct PinchGesture {
+    recognizer: ItemWeak,
+    /// The distance between the touch points when the gesture started
+    start_distance: f32,
+    /// The angle of the line between the touch points when the gesture started, in degrees
+    start_angle: f32,
+}
+
+/// Returns the center, the length, and the angle in degrees of the line between `a` and `b`
+fn touch_line(a: LogicalPoint, b: LogicalPoint) -> (LogicalPoint, f32, f32) {
+    let v = (b - a).cast::<f32>();
+    ((a.cast::<f32>() + v / 2.).cast(), v.length(), v.y.atan2(v.x).to_degrees())
+}
+
+/// Process a touch event: the first touch point is sent as a mouse event, and the two first
+/// touch points make a pinch gesture for the `PinchGestureRecognizer` under their center.
+pub(crate) fn process_touch_input(
+    id: u64,
+    phase: TouchPhase,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    touch_input_state: &mut TouchInputState,
+) {
+    let window = WindowInner::from_pub(window_adapter.window());
+    let pinch_index = touch_input_state.points.iter().take(2).position(|(i, _)| *i == id);
+    match phase {
+        TouchPhase::Pressed(position) => {
+            touch_input_state.points.retain(|(i, _)| *i != id);
+            touch_input_state.points.push((id, position));
+            match touch_input_state.points.as_slice() {
+                [_] => {
+                    touch_input_state.mouse_touch = Some(id);
+                    window.process_mouse_input(MouseEvent::Pressed {
+                        position,
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    });
+                }
+                [(_, a), (_, b)] if touch_input_state.pinch.is_none() => {
+                    let (center, distance, angle) = touch_line(*a, *b);
+                    let Some(recognizer) =
+                        crate::items::pinch_gesture_recognizer_at(window_adapter, center)
+                    else {
+                        return;
+                    };
+                    if touch_input_state.mouse_touch.take().is_some() {
+                        // The pinch gesture replaces what the first touch point was doing
+                        window.process_mouse_input(MouseEvent::Exit);
+                    }
+                    touch_input_state.pinch = Some(PinchGesture {
+                        recognizer: recognizer.downgrade(),
+                        start_distance: distance,
+                        start_angle: angle,
+                    });
+                    if let Some(r) = recognizer.downcast::<crate::items::PinchGestureRecognizer>() {
+                        r.as_pin_ref().start_pinch(&recognizer, center);
+                    }
+                }
+                _ => {}
+            }
+        }
+        TouchPhase::Moved(position) => {
+            if let Some(point) = touch_input_state.points.iter_mut().find(|(i, _)| *i == id) {
+                point.1 = position;
+            }
+            if let (Some(pinch), Some(_), [(_, a), (_, b), ..]) =
+                (touch_input_state.pinch.as_ref(), pinch_index, touch_input_state.points.as_slice())
+            {
+                let (center, distance, angle) = touch_line(*a, *b);
+                let scale =
+                    if pinch.start_distance > 0. { distance / pinch.start_distance } else { 1. };
+                // Between -180 and 180 degrees
+                let rotation = (angle - pinch.start_angle + 540.) % 360. - 180.;
+                if let Some(recognizer) = pinch.recognizer.upgrade() {
+                    if let Some(r) = recognizer.downcast::<crate::items::PinchGestureRecognizer>() {
+                        r.as_pin_ref().update_pinch(&recognizer, scale, rotation, center);
+                    }
+                }
+            } else if touch_input_state.mouse_touch == Some(id) {
+                window.process_mouse_input(MouseEvent::Moved { position });
+            }
+        }
+        TouchPhase::Released(_) | TouchPhase::Cancelled => {
+            touch_input_state.points.retain(|(i, _)| *i != id);
+            if pinch_index.is_some() {
+                if let Some(pinch) = touch_input_state.pinch.take() {
+                    if let Some(recognizer) = pinch.recognizer.upgrade() {
+                        if let Some(r) =
+                            recognizer.downcast::<crate::items::PinchGestureRecognizer>()
+                        {
+                            r.as_pin_ref().end_pinch(phase == TouchPhase::Cancelled);
+                        }
+                    }
+                }
+            }
+            if touch_input_state.mouse_touch == Some(id) {
+                touch_input_state.mouse_touch = None;
+                if let TouchPhase::Released(position) = phase {
+                    window.process_mouse_input(MouseEvent::Released {
+                        position,
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    });
+                }
+                // There is no hover with touch input
+                window.process_mouse_input(MouseEvent::Exit);
+            }
+        }
+    }
+}
+
 /// The state which a window should hold for the mouse input
 #[derive(Default)]
 pub struct MouseInputState {
@@ -557,6 +703,28 @@ fn top_item(&self) -> Option<ItemRc> {
     pub fn top_item_including_delayed(&self) -> Option<ItemRc> {
         self.delayed_exit_items.last().and_then(|x| x.upgrade()).or_else(|| self.top_item())
     }
+
+    /// Reads the geometry of the items of the stack, so that the current property tracker
+    /// gets dirty when they move. Nothing is read while the mouse is grabbed.
+    pub(crate) fn track_item_geometry(&self) {
+        if self.grabbed || self.delayed.is_some() {
+            return;
+        }
+        for (item, _) in &self.item_stack {
+            if let Some(item) = item.upgrade() {
+                item.geometry();
+            }
+        }
+    }
+}
+
+/// Maps a position in the coordinates of `item` to the coordinates of its children.
+/// This only differs for items that transform their children, like the `Rotate` element.
+fn map_to_children(item: &ItemRc, position: LogicalPoint) -> LogicalPoint {
+    match ItemRef::downcast_pin::<crate::items::Rotate>(item.borrow()) {
+        Some(rotate) => rotate.map_to_children(position),
+        None => position,
+    }
 }
 
 /// Try to handle the mouse grabber. Return None if the event has been handled, otherwise
@@ -575,6 +743,7 @@ pub(crate) fn handle_mouse_grab(
     let mut invalid = false;
 
     event.translate(-mouse_input_state.offset.to_vector());
+    let mut parent: Option<ItemRc> = None;
 
     mouse_input_state.item_stack.retain(|it| {
         if invalid {
@@ -590,6 +759,9 @@ pub(crate) fn handle_mouse_grab(
             item.borrow().as_ref().input_event(MouseEvent::Exit, window_adapter, &item);
             return false;
         }
+        if let Some(parent) = parent.replace(item.clone()) {
+            event.map_to_children(&parent);
+        }
         let g = item.geometry();
         event.translate(-g.origin.to_vector());
 
@@ -646,7 +818,7 @@ pub(crate) fn send_exit_events(
         let g = item.geometry();
         let contains = pos.map_or(false, |p| g.contains(p));
         if let Some(p) = pos.as_mut() {
-            *p -= g.origin.to_vector();
+            *p = map_to_children(&item, *p - g.origin.to_vector());
         }
         if !contains || clipped {
             if crate::item_rendering::is_clipping_item(item.borrow()) {
@@ -769,6 +941,8 @@ fn send_mouse_event_to_item(
         InputEventFilterResult::ForwardAndIgnore
     };
 
+    event_for_children.map_to_children(&item_rc);
+
     let (forward_to_children, ignore) = match filter_result {
         InputEventFilterResult::ForwardEvent => (true, false),
         InputEventFilterResult::ForwardAndIgnore => (true, true),
@@ -21,6 +21,8 @@
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 #[cfg(feature = "std")]
+use euclid::num::Zero;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
 use vtable::VRc;
 
@@ -42,7 +44,7 @@ impl CachedRenderingData {
     /// exists, i.e. if any data was ever cached. This is typically called by the graphics backend's
     /// implementation of the release_item_graphics_cache function.
     pub fn release<T>(&self, cache: &mut RenderingCache<T>) -> Option<T> {
-        if self.cache_generation.get() == cache.generation() {
+        if cache.is_valid(self.cache_index.get(), self.cache_generation.get()) {
             let index = self.cache_index.get();
             self.cache_generation.set(0);
             Some(cache.remove(index).data)
@@ -57,7 +59,7 @@ pub fn get_entry<'a, T>(
         cache: &'a mut RenderingCache<T>,
     ) -> Option<&'a mut crate::graphics::CachedGraphicsData<T>> {
         let index = self.cache_index.get();
-        if self.cache_generation.get() == cache.generation() {
+        if cache.is_valid(index, self.cache_generation.get()) {
             cache.get_mut(index)
         } else {
             None
@@ -73,10 +75,17 @@ pub fn get_entry<'a, T>(
 /// cache is automatically invalided when the property gets dirty.
 /// [`ItemCache::component_destroyed`] must be called to clear the cache for that
 /// component.
+///
+/// The entries are stored in a [`RenderingCache`], so the cache can be limited with
+/// [`ItemCache::set_budget`], in which case the least recently used entries are dropped.
 #[cfg(feature = "std")]
 pub struct ItemCache<T> {
-    /// The pointer is a pointer to a component
-    map: RefCell<HashMap<*const vtable::Dyn, HashMap<u32, CachedGraphicsData<T>>>>,
+    /// The pointer is a pointer to a component. The values are the index and the generation
+    /// of the entry in `entries`.
+    map: RefCell<HashMap<*const vtable::Dyn, HashMap<u32, (usize, usize)>>>,
+    entries: RefCell<RenderingCache<T>>,
+    /// Returns the number of bytes that an entry occupies, for the budget of the cache.
+    entry_size: fn(&T) -> usize,
     /// Track if the window scale factor changes; used to clear the cache if necessary.
     window_scale_factor_tracker: Pin<Box<PropertyTracker>>,
 }
@@ -84,7 +93,50 @@ pub struct ItemCache<T> {
 #[cfg(feature = "std")]
 impl<T> Default for ItemCache<T> {
     fn default() -> Self {
-        Self { map: Default::default(), window_scale_factor_tracker: Box::pin(Default::default()) }
+        Self::with_entry_size(|_| 0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> ItemCache<T> {
+    /// Creates a new cache, that uses `entry_size` to determine how many bytes an entry occupies,
+    /// when the cache is limited with [`crate::graphics::RenderingCacheBudget::MaxBytes`].
+    pub fn with_entry_size(entry_size: fn(&T) -> usize) -> Self {
+        Self {
+            map: Default::default(),
+            entries: Default::default(),
+            entry_size,
+            window_scale_factor_tracker: Box::pin(Default::default()),
+        }
+    }
+
+    /// Sets the budget of the cache. The least recently used entries that exceed the budget are dropped.
+    pub fn set_budget(&self, budget: crate::graphics::RenderingCacheBudget) {
+        self.entries.borrow_mut().set_budget(budget);
+    }
+
+    /// Returns statistics about the current usage of the cache.
+    pub fn statistics(&self) -> crate::graphics::RenderingCacheStatistics {
+        self.entries.borrow().statistics()
+    }
+
+    /// Returns the index and generation of the entry of the given item, if it is in the cache.
+    fn lookup(&self, component: *const vtable::Dyn, index: u32) -> Option<(usize, usize)> {
+        let entry = *self.map.borrow().get(&component)?.get(&index)?;
+        self.entries.borrow().is_valid(entry.0, entry.1).then_some(entry)
+    }
+
+    fn insert(&self, component: *const vtable::Dyn, index: u32, entry: CachedGraphicsData<T>) {
+        let size = (self.entry_size)(&entry.data);
+        let mut entries = self.entries.borrow_mut();
+        let entry_index = entries.insert_with_size(entry, size);
+        let generation = entries.generation();
+        drop(entries);
+        self.map
+            .borrow_mut()
+            .entry(component)
+            .or_default()
+            .insert(index, (entry_index, generation));
     }
 }
 
@@ -95,34 +147,40 @@ impl<T: Clone> ItemCache<T> {
     /// so it is automatically invalided when property becomes dirty.
     pub fn get_or_update_cache_entry(&self, item_rc: &ItemRc, update_fn: impl FnOnce() -> T) -> T {
         let component = &(**item_rc.item_tree()) as *const _;
-        let mut borrowed = self.map.borrow_mut();
-        match borrowed.entry(component).or_default().entry(item_rc.index()) {
-            std::collections::hash_map::Entry::Occupied(mut entry) => {
-                let mut tracker = entry.get_mut().dependency_tracker.take();
-                drop(borrowed);
+        match self.lookup(component, item_rc.index()) {
+            Some(existing) => {
+                let (mut tracker, data) = {
+                    let mut entries = self.entries.borrow_mut();
+                    let entry = entries.get_mut(existing.0).unwrap();
+                    (entry.dependency_tracker.take(), entry.data.clone())
+                };
                 let maybe_new_data = tracker
                     .get_or_insert_with(|| Box::pin(Default::default()))
                     .as_ref()
                     .evaluate_if_dirty(update_fn);
-                let mut borrowed = self.map.borrow_mut();
-                let e = borrowed.get_mut(&component).unwrap().get_mut(&item_rc.index()).unwrap();
-                e.dependency_tracker = tracker;
+                // The update function may have released or evicted the entry
+                let still_cached = self.lookup(component, item_rc.index()) == Some(existing);
                 if let Some(new_data) = maybe_new_data {
-                    e.data = new_data.clone();
+                    // Re-insert the entry, as its size may have changed
+                    if still_cached {
+                        self.entries.borrow_mut().remove(existing.0);
+                    }
+                    let new_entry =
+                        CachedGraphicsData { data: new_data.clone(), dependency_tracker: tracker };
+                    self.insert(component, item_rc.index(), new_entry);
                     new_data
                 } else {
-                    e.data.clone()
+                    if still_cached {
+                        self.entries.borrow_mut().get_mut(existing.0).unwrap().dependency_tracker =
+                            tracker;
+                    }
+                    data
                 }
             }
-            std::collections::hash_map::Entry::Vacant(_) => {
-                drop(borrowed);
+            None => {
                 let new_entry = CachedGraphicsData::new(update_fn);
                 let data = new_entry.data.clone();
-                self.map
-                    .borrow_mut()
-                    .get_mut(&component)
-                    .unwrap()
-                    .insert(item_rc.index(), new_entry);
+                self.insert(component, item_rc.index(), new_entry);
                 data
             }
         }
@@ -136,11 +194,8 @@ pub fn with_entry<U>(
         callback: impl FnOnce(&T) -> Option<U>,
     ) -> Option<U> {
         let component = &(**item_rc.item_tree()) as *const _;
-        self.map
-            .borrow()
-            .get(&component)
-            .and_then(|per_component_entries| per_component_entries.get(&item_rc.index()))
-            .and_then(|entry| callback(&entry.data))
+        let (index, _) = self.lookup(component, item_rc.index())?;
+        self.entries.borrow().get(index).and_then(|entry| callback(&entry.data))
     }
 
     /// Clears the cache if the window's scale factor has changed since the last call.
@@ -156,6 +211,7 @@ pub fn clear_cache_if_scale_factor_changed(&self, window: &crate::api::Window) {
     /// free the whole cache
     pub fn clear_all(&self) {
         self.map.borrow_mut().clear();
+        self.entries.borrow_mut().clear();
     }
 
     /// Function that must be called when a component is destroyed.
@@ -164,14 +220,25 @@ pub fn clear_all(&self) {
     pub fn component_destroyed(&self, component: crate::item_tree::ItemTreeRef) {
         let component_ptr: *const _ =
             crate::item_tree::ItemTreeRef::as_ptr(component).cast().as_ptr();
-        self.map.borrow_mut().remove(&component_ptr);
+        let Some(sub) = self.map.borrow_mut().remove(&component_ptr) else { return };
+        let mut entries = self.entries.borrow_mut();
+        for (index, generation) in sub.into_values() {
+            if entries.is_valid(index, generation) {
+                entries.remove(index);
+            }
+        }
     }
 
     /// free the cache for a given item
     pub fn release(&self, item_rc: &ItemRc) {
         let component = &(**item_rc.item_tree()) as *const _;
-        if let Some(sub) = self.map.borrow_mut().get_mut(&component) {
-            sub.remove(&item_rc.index());
+        let removed =
+            self.map.borrow_mut().get_mut(&component).and_then(|sub| sub.remove(&item_rc.index()));
+        if let Some((index, generation)) = removed {
+            let mut entries = self.entries.borrow_mut();
+            if entries.is_valid(index, generation) {
+                entries.remove(index);
+            }
         }
     }
 
@@ -206,6 +273,7 @@ pub fn render_item_children(renderer: &mut dyn ItemRenderer, component: &ItemTre
                || is_clipping_item(item)
                // HACK, the geometry of the box shadow does not include the shadow, because when the shadow is the root for repeated elements it would translate the children
                || ItemRef::downcast_pin::<BoxShadow>(item).is_some()
+               || ItemRef::downcast_pin::<DropShadow>(item).is_some()
             {
                 item.as_ref().render(
                     &mut (renderer as &mut dyn ItemRenderer),
@@ -290,6 +358,7 @@ pub trait RenderBorderRectangle {
     fn border_width(self: Pin<&Self>) -> LogicalLength;
     fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius;
     fn border_color(self: Pin<&Self>) -> Brush;
+    fn blend_mode(self: Pin<&Self>) -> BlendMode;
 }
 
 /// Trait for an item that represents an Image towards the renderer
@@ -303,6 +372,7 @@ pub trait RenderImage {
     fn colorize(self: Pin<&Self>) -> Brush;
     fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignment);
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling);
+    fn blend_mode(self: Pin<&Self>) -> BlendMode;
 }
 
 /// Trait for an item that represents an Text towards the renderer
@@ -317,6 +387,13 @@ pub trait RenderText {
     fn overflow(self: Pin<&Self>) -> TextOverflow;
     fn letter_spacing(self: Pin<&Self>) -> LogicalLength;
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle);
+    fn text_format(self: Pin<&Self>) -> TextFormat;
+
+    /// Returns the text without its markup and the style of its parts, if the text is markup.
+    fn styled_text(self: Pin<&Self>) -> Option<crate::textlayout::StyledText> {
+        (self.text_format() == TextFormat::Markup)
+            .then(|| crate::textlayout::parse_markup(&self.text()))
+    }
 }
 
 /// Trait used to render each items.
@@ -379,6 +456,72 @@ fn visit_layer(
         // Not supported
         RenderingResult::ContinueRenderingChildren
     }
+    /// Rotates and scales the children of the Rotate item around its rotation origin. The default
+    /// implementation transforms the coordinate system.
+    fn visit_rotate(
+        &mut self,
+        rotate: Pin<&Rotate>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let origin =
+            LogicalVector::from_lengths(rotate.rotation_origin_x(), rotate.rotation_origin_y());
+        self.translate(origin);
+        self.rotate(rotate.rotation_angle());
+        let scale = rotate.scale();
+        if scale != 1. {
+            self.scale(scale, scale);
+        }
+        self.translate(-origin);
+        RenderingResult::ContinueRenderingChildren
+    }
+    /// Draws the shadow of the children of the DropShadow item. The default implementation renders
+    /// the children without a shadow, and warns once that the shadow isn't supported.
+    fn visit_drop_shadow(
+        &mut self,
+        _drop_shadow: Pin<&DropShadow>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        static WARNED: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+        if !WARNED.load(core::sync::atomic::Ordering::Relaxed) {
+            WARNED.store(true, core::sync::atomic::Ordering::Relaxed);
+            crate::debug_log!(
+                "Warning: drop-shadow is only supported on Rectangle elements with this renderer"
+            );
+        }
+        RenderingResult::ContinueRenderingChildren
+    }
+    /// Draws a blurred version of what was rendered behind the Blur item. The default implementation
+    /// doesn't draw anything.
+    fn visit_blur(
+        &mut self,
+        _blur: Pin<&Blur>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        // Not supported
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    /// Draws the path and clips its children to the fill shape of the path. The default
+    /// implementation clips the children to the geometry of the path instead.
+    #[cfg(feature = "std")]
+    fn visit_path_clip(
+        &mut self,
+        path: Pin<&Path>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let clip_region_valid =
+            self.combine_clip(size.into(), LogicalBorderRadius::zero(), LogicalLength::zero());
+        self.draw_path(path, self_rc, size);
+        if clip_region_valid {
+            RenderingResult::ContinueRenderingChildren
+        } else {
+            RenderingResult::ContinueRenderingWithoutChildren
+        }
+    }
 
     // Apply the bounds of the Clip element, if enabled. The default implementation calls
     // combine_clip, but the render may choose an alternate way of implementing the clip.
@@ -426,6 +569,8 @@ fn translation(&self) -> LogicalVector {
         unimplemented!()
     }
     fn rotate(&mut self, angle_in_degrees: f32);
+    /// Scale the coordinate system by the given factors for all following items until the next call to restore_state.
+    fn scale(&mut self, x_factor: f32, y_factor: f32);
     /// Apply the opacity (between 0 and 1) for all following items until the next call to restore_state.
     fn apply_opacity(&mut self, opacity: f32);
 
@@ -582,6 +727,69 @@ fn draw_intersects(&self, clipped_geom: LogicalRect) -> bool {
     }
 }
 
+/// Remembers the dirty regions of the previous frames, for renderers that render into back buffers
+/// that still contain the content of an earlier frame, such as OpenGL surfaces that report their
+/// buffer age.
+#[derive(Default, Clone, Debug)]
+pub struct DirtyRegionHistory {
+    /// The dirty regions of the previous frames, the most recent first.
+    previous_frames: alloc::collections::VecDeque<DirtyRegion>,
+}
+
+impl DirtyRegionHistory {
+    /// The maximum age of a back buffer for which the region to repaint can be computed.
+    const MAX_BUFFER_AGE: usize = 4;
+
+    /// Records the dirty region of the current frame and returns the region that needs to be repainted
+    /// in a back buffer of the given age, i.e. that contains what was rendered `buffer_age` frames ago.
+    ///
+    /// Returns None if the entire buffer needs to be repainted, because its age is zero (the content
+    /// is undefined) or because the buffer is older than the recorded history.
+    pub fn add_frame(&mut self, dirty_region: DirtyRegion, buffer_age: u32) -> Option<DirtyRegion> {
+        let buffer_age = buffer_age as usize;
+        let repaint_region =
+            (buffer_age > 0 && buffer_age <= self.previous_frames.len()).then(|| {
+                self.previous_frames
+                    .iter()
+                    .take(buffer_age - 1)
+                    .fold(dirty_region.clone(), |region, previous| region.union(previous))
+            });
+        self.previous_frames.push_front(dirty_region);
+        self.previous_frames.truncate(Self::MAX_BUFFER_AGE);
+        repaint_region
+    }
+
+    /// Forgets the dirty regions of the previous frames, so that buffers rendered until now are
+    /// repainted entirely.
+    pub fn clear(&mut self) {
+        self.previous_frames.clear();
+    }
+}
+
+#[test]
+fn dirty_region_history() {
+    let rect = |x: i32| {
+        LogicalRect::new(
+            LogicalPoint::new(x as Coord, 0 as Coord),
+            LogicalSize::new(1 as Coord, 1 as Coord),
+        )
+    };
+    let mut history = DirtyRegionHistory::default();
+
+    // Nothing is known about the first frame
+    assert!(history.add_frame(rect(1).into(), 1).is_none());
+    assert_eq!(history.add_frame(rect(2).into(), 1).unwrap().bounding_rect(), rect(2));
+    // A buffer that's two frames old also needs the changes of the previous frame
+    assert_eq!(
+        history.add_frame(rect(3).into(), 2).unwrap().bounding_rect(),
+        rect(2).union(&rect(3))
+    );
+    assert!(history.add_frame(rect(4).into(), 0).is_none());
+
+    history.clear();
+    assert!(history.add_frame(rect(5).into(), 1).is_none());
+}
+
 impl From<LogicalRect> for DirtyRegion {
     fn from(value: LogicalRect) -> Self {
         let mut s = Self::default();
@@ -622,6 +830,8 @@ struct ComputeDirtyRegionState {
             old_offset: euclid::Vector2D<Coord, LogicalPx>,
             clipped: LogicalRect,
             must_refresh_children: bool,
+            /// True if the item is rotated, so its geometry doesn't describe where it's drawn.
+            transformed: bool,
         }
 
         crate::item_tree::visit_items(
@@ -631,6 +841,9 @@ struct ComputeDirtyRegionState {
                 let mut new_state = *state;
                 let mut borrowed = self.cache.borrow_mut();
                 let item_rc = ItemRc::new(component.clone(), index);
+                let transformed =
+                    state.transformed || ItemRef::downcast_pin::<Rotate>(item).is_some();
+                new_state.transformed = transformed;
 
                 match item.cached_rendering_data_offset().get_entry(&mut borrowed) {
                     Some(CachedGraphicsData {
@@ -643,8 +856,20 @@ struct ComputeDirtyRegionState {
                             let geom =
                                 crate::properties::evaluate_no_tracking(|| item_rc.geometry());
 
-                            self.mark_dirty_rect(old_geom, state.old_offset, &state.clipped);
-                            self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                            self.mark_dirty_item_rect(
+                                item,
+                                old_geom,
+                                state.old_offset,
+                                &state.clipped,
+                                transformed,
+                            );
+                            self.mark_dirty_item_rect(
+                                item,
+                                geom,
+                                state.offset,
+                                &state.clipped,
+                                transformed,
+                            );
 
                             new_state.offset += geom.origin.to_vector();
                             new_state.old_offset += old_geom.origin.to_vector();
@@ -663,17 +888,29 @@ struct ComputeDirtyRegionState {
                             if state.must_refresh_children
                                 || new_state.offset != new_state.old_offset
                             {
-                                self.mark_dirty_rect(
+                                self.mark_dirty_item_rect(
+                                    item,
                                     *cached_geom,
                                     state.old_offset,
                                     &state.clipped,
+                                    transformed,
+                                );
+                                self.mark_dirty_item_rect(
+                                    item,
+                                    *cached_geom,
+                                    state.offset,
+                                    &state.clipped,
+                                    transformed,
                                 );
-                                self.mark_dirty_rect(*cached_geom, state.offset, &state.clipped);
                             }
 
                             new_state.offset += cached_geom.origin.to_vector();
                             new_state.old_offset += cached_geom.origin.to_vector();
-                            if crate::properties::evaluate_no_tracking(|| is_clipping_item(item)) {
+                            if !transformed
+                                && crate::properties::evaluate_no_tracking(|| {
+                                    is_clipping_item(item)
+                                })
+                            {
                                 new_state.clipped = new_state
                                     .clipped
                                     .intersection(
@@ -692,7 +929,7 @@ struct ComputeDirtyRegionState {
                             let geom = item_rc.geometry();
                             new_state.offset += geom.origin.to_vector();
                             new_state.old_offset += geom.origin.to_vector();
-                            if is_clipping_item(item) {
+                            if !transformed && is_clipping_item(item) {
                                 new_state.clipped = new_state
                                     .clipped
                                     .intersection(&geom.translate(state.offset))
@@ -700,7 +937,13 @@ struct ComputeDirtyRegionState {
                             }
                             geom
                         });
-                        self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                        self.mark_dirty_item_rect(
+                            item,
+                            geom,
+                            state.offset,
+                            &state.clipped,
+                            transformed,
+                        );
                         ItemVisitorResult::Continue(new_state)
                     }
                 }
@@ -710,10 +953,53 @@ struct ComputeDirtyRegionState {
                 old_offset: origin.to_vector(),
                 clipped: LogicalRect::from_size(size),
                 must_refresh_children: false,
+                transformed: false,
             },
         );
     }
 
+    /// Marks the area covered by the item with the given geometry as dirty. That area can be bigger
+    /// than the geometry, for example for shadows.
+    fn mark_dirty_item_rect(
+        &mut self,
+        item: Pin<ItemRef>,
+        geometry: LogicalRect,
+        offset: euclid::Vector2D<Coord, LogicalPx>,
+        clip_rect: &LogicalRect,
+        transformed: bool,
+    ) {
+        if transformed {
+            // We don't know where a rotated item is drawn, so the whole visible area is dirty.
+            if !geometry.is_empty() {
+                self.dirty_region.add_rect(*clip_rect);
+            }
+            return;
+        }
+
+        let shadow = crate::properties::evaluate_no_tracking(|| {
+            if let Some(box_shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+                Some((box_shadow.offset_x(), box_shadow.offset_y(), box_shadow.blur()))
+            } else {
+                ItemRef::downcast_pin::<DropShadow>(item).map(|drop_shadow| {
+                    (drop_shadow.offset_x(), drop_shadow.offset_y(), drop_shadow.blur())
+                })
+            }
+        });
+        let rect = match shadow {
+            Some((offset_x, offset_y, blur)) => {
+                let blur = blur.get().max(0 as Coord);
+                geometry.union(
+                    &geometry
+                        .translate(LogicalVector::from_lengths(offset_x, offset_y))
+                        .inflate(blur, blur),
+                )
+            }
+            None => geometry,
+        };
+
+        self.mark_dirty_rect(rect, offset, clip_rect)
+    }
+
     fn mark_dirty_rect(
         &mut self,
         rect: LogicalRect,
@@ -732,15 +1018,20 @@ fn do_rendering(
         rendering_data: &CachedRenderingData,
         render_fn: impl FnOnce() -> LogicalRect,
     ) {
-        let mut cache = cache.borrow_mut();
-        if let Some(entry) = rendering_data.get_entry(&mut cache) {
-            entry
-                .dependency_tracker
-                .get_or_insert_with(|| Box::pin(PropertyTracker::default()))
-                .as_ref()
-                .evaluate(render_fn);
+        // The cache must not stay borrowed while rendering, since rendering an item into a layer
+        // goes through the cache again for its children.
+        let tracker = rendering_data
+            .get_entry(&mut cache.borrow_mut())
+            .map(|entry| entry.dependency_tracker.take());
+        if let Some(tracker) = tracker {
+            let tracker = tracker.unwrap_or_else(|| Box::pin(PropertyTracker::default()));
+            tracker.as_ref().evaluate(render_fn);
+            if let Some(entry) = rendering_data.get_entry(&mut cache.borrow_mut()) {
+                entry.dependency_tracker = Some(tracker);
+            }
         } else {
             let cache_entry = crate::graphics::CachedGraphicsData::new(render_fn);
+            let mut cache = cache.borrow_mut();
             rendering_data.cache_index.set(cache.insert(cache_entry));
             rendering_data.cache_generation.set(cache.generation());
         }
@@ -824,10 +1115,16 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
     forward_rendering_call!(fn draw_text_input(TextInput));
     #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
+    #[cfg(feature = "std")]
+    forward_rendering_call!(fn visit_path_clip(Path) -> RenderingResult);
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
 
     forward_rendering_call!(fn visit_clip(Clip) -> RenderingResult);
     forward_rendering_call!(fn visit_opacity(Opacity) -> RenderingResult);
+    forward_rendering_call!(fn visit_layer(Layer) -> RenderingResult);
+    forward_rendering_call!(fn visit_rotate(Rotate) -> RenderingResult);
+    forward_rendering_call!(fn visit_drop_shadow(DropShadow) -> RenderingResult);
+    forward_rendering_call!(fn visit_blur(Blur) -> RenderingResult);
 
     fn combine_clip(
         &mut self,
@@ -853,6 +1150,10 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         self.actual_renderer.rotate(angle_in_degrees)
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.actual_renderer.scale(x_factor, y_factor)
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.actual_renderer.apply_opacity(opacity)
     }
@@ -892,4 +1193,8 @@ fn window(&self) -> &crate::window::WindowInner {
     fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
         self.actual_renderer.as_any()
     }
+
+    fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetrics {
+        self.actual_renderer.metrics()
+    }
 }
@@ -38,7 +38,11 @@
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
+use euclid::num::Zero;
 use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 use vtable::*;
 
 mod component_container;
@@ -65,7 +69,9 @@
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
+type DropEventArg = (DropEvent,);
 type PointArg = (Point,);
+type StringArg = (SharedString,);
 
 #[cfg(all(feature = "ffi", windows))]
 #[macro_export]
@@ -408,6 +414,9 @@ fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
     fn border_color(self: Pin<&Self>) -> Brush {
         self.border_color()
     }
+    fn blend_mode(self: Pin<&Self>) -> BlendMode {
+        BlendMode::Normal
+    }
 }
 
 impl ItemConsts for BasicBorderRectangle {
@@ -434,6 +443,7 @@ pub struct BorderRectangle {
     pub border_bottom_left_radius: Property<LogicalLength>,
     pub border_bottom_right_radius: Property<LogicalLength>,
     pub border_color: Property<Brush>,
+    pub blend_mode: Property<BlendMode>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -513,6 +523,9 @@ fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
     fn border_color(self: Pin<&Self>) -> Brush {
         self.border_color()
     }
+    fn blend_mode(self: Pin<&Self>) -> BlendMode {
+        self.blend_mode()
+    }
 }
 
 impl ItemConsts for BorderRectangle {
@@ -538,6 +551,22 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureRecognizerVTable() -> SwipeGestureRecognizerVTable for SwipeGestureRecognizer
 }
 
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_PinchGestureRecognizerVTable() -> PinchGestureRecognizerVTable for PinchGestureRecognizer
+}
+
+declare_item_vtable! {
+    fn slint_get_ShortcutVTable() -> ShortcutVTable for Shortcut
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -825,9 +854,177 @@ fn slint_get_LayerVTable() -> LayerVTable for Layer
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
-/// The implementation of the `Rotate` element
+/// The DropShadow Item is not meant to be used directly by the .slint code, instead, the `drop-shadow-*` properties
+/// should be used on elements that are not a `Rectangle`. The shadow is computed from the rendered children.
+pub struct DropShadow {
+    pub offset_x: Property<LogicalLength>,
+    pub offset_y: Property<LogicalLength>,
+    pub color: Property<Color>,
+    pub blur: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropShadow {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_drop_shadow(self, self_rc, size)
+    }
+}
+
+impl DropShadow {
+    /// Returns true if the shadow is visible, i.e. it has a color and is either blurred or offset.
+    pub fn is_visible(self: Pin<&Self>) -> bool {
+        self.color().alpha() != 0
+            && (self.blur() != LogicalLength::zero()
+                || self.offset_x() != LogicalLength::zero()
+                || self.offset_y() != LogicalLength::zero())
+    }
+}
+
+impl ItemConsts for DropShadow {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DropShadow,
+        CachedRenderingData,
+    > = DropShadow::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_DropShadowVTable() -> DropShadowVTable for DropShadow
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `Blur` element, which blurs everything that is rendered behind it.
+pub struct Blur {
+    pub blur_radius: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for Blur {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_blur(self, self_rc, size)
+    }
+}
+
+impl ItemConsts for Blur {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Blur, CachedRenderingData> =
+        Blur::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_BlurVTable() -> BlurVTable for Blur
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `Rotate` element, which rotates and scales its children
+/// around the rotation origin
 pub struct Rotate {
     pub rotation_angle: Property<f32>,
+    pub scale: Property<f32>,
     pub rotation_origin_x: Property<LogicalLength>,
     pub rotation_origin_y: Property<LogicalLength>,
     pub cached_rendering_data: CachedRenderingData,
@@ -883,15 +1080,28 @@ fn focus_event(
     fn render(
         self: Pin<&Self>,
         backend: &mut ItemRendererRef,
-        _self_rc: &ItemRc,
-        _size: LogicalSize,
+        self_rc: &ItemRc,
+        size: LogicalSize,
     ) -> RenderingResult {
+        (*backend).visit_rotate(self, self_rc, size)
+    }
+}
+
+impl Rotate {
+    /// Maps a position in the coordinates of this item to the coordinates of its children,
+    /// by applying the inverse of the rotation and the scale.
+    pub fn map_to_children(
+        self: Pin<&Self>,
+        position: crate::lengths::LogicalPoint,
+    ) -> crate::lengths::LogicalPoint {
         let origin =
             LogicalVector::from_lengths(self.rotation_origin_x(), self.rotation_origin_y());
-        (*backend).translate(origin);
-        (*backend).rotate(self.rotation_angle());
-        (*backend).translate(-origin);
-        RenderingResult::ContinueRenderingChildren
+        let (sin, cos) = (-self.rotation_angle()).to_radians().sin_cos();
+        let scale = self.scale();
+        let p = position - origin;
+        let rotated = LogicalVector::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos);
+        let scaled = if scale != 0. { rotated / scale } else { rotated };
+        (scaled + origin).to_point()
     }
 }
 
@@ -923,13 +1133,26 @@ pub struct PropertyAnimation {
     pub iteration_count: f32,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    #[rtti_field]
+    pub color_interpolation: ColorInterpolation,
+    /// Pairs of the progress of the animation and of the progress of the value, between 0 and 1,
+    /// in increasing order of the progress of the animation
+    #[rtti_field]
+    pub keyframes: crate::SharedVector<f32>,
 }
 
 impl Default for PropertyAnimation {
     fn default() -> Self {
         // Defaults for PropertyAnimation are defined here (for internal Rust code doing programmatic animations)
         // as well as in `builtins.slint` (for generated C++ and Rust code)
-        Self { delay: 0, duration: 0, iteration_count: 1., easing: Default::default() }
+        Self {
+            delay: 0,
+            duration: 0,
+            iteration_count: 1.,
+            easing: Default::default(),
+            color_interpolation: Default::default(),
+            keyframes: Default::default(),
+        }
     }
 }
 
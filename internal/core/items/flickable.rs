@@ -17,14 +17,15 @@
 use crate::items::PropertyAnimation;
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
-    PointLengths, RectLengths,
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
+    LogicalVector, PointLengths, RectLengths,
 };
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
 use crate::window::WindowAdapter;
 use crate::Callback;
-use crate::Property;
+use crate::{Coord, Property};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
@@ -51,8 +52,15 @@ pub struct Flickable {
     pub viewport_height: Property<LogicalLength>,
 
     pub interactive: Property<bool>,
+    /// The deceleration of the viewport after a flick, in logical pixels per second squared
+    pub friction: Property<f32>,
+    pub overshoot: Property<bool>,
+    /// When not zero, the viewport can be pulled down past its top edge, and releasing it further
+    /// than this distance invokes `refresh_requested`
+    pub refresh_threshold: Property<LogicalLength>,
 
     pub flicked: Callback<VoidArg>,
+    pub refresh_requested: Callback<VoidArg>,
 
     data: FlickableDataBox,
 
@@ -185,6 +193,18 @@ fn deref(&self) -> &Self::Target {
 pub(super) const DURATION_THRESHOLD: Duration = Duration::from_millis(500);
 /// The delay to which press are forwarded to the inner item
 pub(super) const FORWARD_DELAY: Duration = Duration::from_millis(100);
+/// The maximum speed of a flick, in logical pixels per millisecond
+const MAX_VELOCITY: f32 = 8.;
+/// Movements of the pointer older than that are not taken into account for the velocity
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+/// How stiff the rubber band is when dragging the viewport past its edges. The smaller, the stiffer.
+const RUBBER_BAND_FACTOR: f32 = 0.55;
+/// How much faster the viewport decelerates once it went past its edges
+const OVERSHOOT_FRICTION_FACTOR: f32 = 16.;
+/// The duration of the animation that brings the viewport back within its bounds after an overshoot
+const OVERSHOOT_RETURN_DURATION: i32 = 300;
+/// A quadratic ease out: the curve of a motion with a constant deceleration
+const DECELERATION_CURVE: EasingCurve = EasingCurve::CubicBezier([1. / 3., 2. / 3., 2. / 3., 1.]);
 
 #[derive(Default, Debug)]
 struct FlickableDataInner {
@@ -194,11 +214,17 @@ struct FlickableDataInner {
     pressed_viewport_pos: LogicalPoint,
     /// Set to true if the flickable is flicking and capturing all mouse event, not forwarding back to the children
     capture_events: bool,
+    /// The time and position of the last pointer movement taken into account for the velocity
+    last_sample: Option<(Instant, LogicalPoint)>,
+    /// The velocity of the pointer, in logical pixels per millisecond
+    velocity: euclid::Vector2D<f32, LogicalPx>,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default)]
 pub struct FlickableData {
     inner: RefCell<FlickableDataInner>,
+    /// Brings the viewport back within its bounds once a flick overshot them
+    overshoot_timer: Timer,
 }
 
 impl FlickableData {
@@ -211,11 +237,20 @@ fn handle_mouse_filter(
         let mut inner = self.inner.borrow_mut();
         match event {
             MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.overshoot_timer.stop();
                 inner.pressed_pos = position;
                 inner.pressed_time = Some(crate::animations::current_tick());
-                inner.pressed_viewport_pos = LogicalPoint::from_lengths(
-                    (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
-                    (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
+                inner.last_sample = inner.pressed_time.map(|t| (t, position));
+                inner.velocity = Default::default();
+                // When the viewport is past its edges, start from the position the rubber band comes from
+                let [bx, by] = axis_bounds(flick, flick_rc);
+                inner.pressed_viewport_pos = LogicalPoint::new(
+                    bx.undamp(
+                        (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get().get() as f32
+                    ) as Coord,
+                    by.undamp(
+                        (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get().get() as f32
+                    ) as Coord,
                 );
                 if inner.capture_events {
                     InputEventFilterResult::Intercept
@@ -225,7 +260,7 @@ fn handle_mouse_filter(
             }
             MouseEvent::Exit | MouseEvent::Released { button: PointerEventButton::Left, .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event, flick_rc);
+                self.mouse_released(&mut inner, flick, event, flick_rc);
                 if was_capturing {
                     InputEventFilterResult::Intercept
                 } else {
@@ -233,6 +268,9 @@ fn handle_mouse_filter(
                 }
             }
             MouseEvent::Moved { position } => {
+                if inner.pressed_time.is_some() {
+                    Self::track_velocity(&mut inner, position);
+                }
                 let do_intercept = inner.capture_events
                     || inner.pressed_time.map_or(false, |pressed_time| {
                         if crate::animations::current_tick() - pressed_time > DURATION_THRESHOLD {
@@ -249,8 +287,12 @@ fn handle_mouse_filter(
                         let x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get();
                         let y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get();
                         let zero = LogicalLength::zero();
+                        let refresh =
+                            (Flickable::FIELD_OFFSETS.refresh_threshold).apply_pin(flick).get()
+                                > zero;
                         ((vw > w || x != zero) && abs(diff.x_length()) > DISTANCE_THRESHOLD)
                             || ((vh > h || y != zero) && abs(diff.y_length()) > DISTANCE_THRESHOLD)
+                            || (refresh && diff.y_length() > DISTANCE_THRESHOLD)
                     });
                 if do_intercept {
                     InputEventFilterResult::Intercept
@@ -283,7 +325,7 @@ fn handle_mouse(
             }
             MouseEvent::Exit | MouseEvent::Released { .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event, flick_rc);
+                self.mouse_released(&mut inner, flick, event, flick_rc);
                 if was_capturing {
                     InputEventResult::EventAccepted
                 } else {
@@ -292,6 +334,7 @@ fn handle_mouse(
             }
             MouseEvent::Moved { position } => {
                 if inner.pressed_time.is_some() {
+                    Self::track_velocity(&mut inner, position);
                     let new_pos = inner.pressed_viewport_pos + (position - inner.pressed_pos);
                     let x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
                     let y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
@@ -302,14 +345,22 @@ fn handle_mouse(
                         let vw = (Flickable::FIELD_OFFSETS.viewport_width).apply_pin(flick).get();
                         let vh = (Flickable::FIELD_OFFSETS.viewport_height).apply_pin(flick).get();
                         let zero = LogicalLength::zero();
+                        let refresh =
+                            (Flickable::FIELD_OFFSETS.refresh_threshold).apply_pin(flick).get()
+                                > zero;
                         ((vw > w || x.get() != zero)
                             && abs(x.get() - new_pos.x_length()) > DISTANCE_THRESHOLD)
                             || ((vh > h || y.get() != zero)
                                 && abs(y.get() - new_pos.y_length()) > DISTANCE_THRESHOLD)
+                            || (refresh && new_pos.y_length() - y.get() > DISTANCE_THRESHOLD)
                     };
 
                     if inner.capture_events || should_capture() {
-                        let new_pos = ensure_in_bound(flick, new_pos, flick_rc);
+                        let [bx, by] = axis_bounds(flick, flick_rc);
+                        let new_pos = LogicalPoint::new(
+                            bx.damp(new_pos.x as f32) as Coord,
+                            by.damp(new_pos.y as f32) as Coord,
+                        );
 
                         let old_pos = (x.get(), y.get());
                         x.set(new_pos.x_length());
@@ -357,46 +408,225 @@ fn handle_mouse(
     }
 
     fn mouse_released(
+        &self,
         inner: &mut FlickableDataInner,
         flick: Pin<&Flickable>,
         event: MouseEvent,
         flick_rc: &ItemRc,
     ) {
-        if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
-            let dist = (pos - inner.pressed_pos).cast::<f32>();
+        if let (Some(_), Some(pos)) = (inner.pressed_time, event.position()) {
+            if inner.capture_events {
+                let refresh_threshold =
+                    (Flickable::FIELD_OFFSETS.refresh_threshold).apply_pin(flick).get();
+                let pulled = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get();
+                Self::track_velocity(inner, pos);
+                self.start_flick(flick, inner.velocity, flick_rc);
+                if refresh_threshold > LogicalLength::zero() && pulled >= refresh_threshold {
+                    (Flickable::FIELD_OFFSETS.refresh_requested).apply_pin(flick).call(&());
+                }
+            }
+        }
+        inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
+        inner.pressed_time = None;
+        inner.last_sample = None;
+    }
 
-            let millis = (crate::animations::current_tick() - pressed_time).as_millis();
-            if inner.capture_events
-                && dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
-                && millis > 1
-            {
-                let speed = dist / (millis as f32);
+    /// Update the velocity of the pointer with its new position
+    fn track_velocity(inner: &mut FlickableDataInner, position: LogicalPoint) {
+        let now = crate::animations::current_tick();
+        let Some((time, last_pos)) = inner.last_sample else { return };
+        let elapsed = (now - time).as_millis() as f32;
+        if elapsed == 0. {
+            // Accumulate with the next movement
+            return;
+        }
+        let velocity = (position - last_pos).cast::<f32>() / elapsed;
+        inner.velocity = if elapsed > VELOCITY_TIMEOUT.as_millis() as f32 {
+            velocity
+        } else {
+            velocity * 0.8 + inner.velocity * 0.2
+        };
+        inner.last_sample = Some((now, position));
+    }
 
-                let duration = 250;
-                let final_pos = ensure_in_bound(
-                    flick,
-                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
-                    flick_rc,
-                );
+    /// Animate the viewport as it decelerates from the given velocity, and overshoots its edges
+    /// if allowed to.
+    fn start_flick(
+        &self,
+        flick: Pin<&Flickable>,
+        velocity: euclid::Vector2D<f32, LogicalPx>,
+        flick_rc: &ItemRc,
+    ) {
+        let speed = velocity.length();
+        let velocity =
+            if speed > MAX_VELOCITY { velocity * (MAX_VELOCITY / speed) } else { velocity };
+        // friction is in px/s², convert to px/ms²
+        let deceleration =
+            (Flickable::FIELD_OFFSETS.friction).apply_pin(flick).get().max(1.) / 1_000_000.;
+        let [bx, by] = axis_bounds(flick, flick_rc);
+        let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+        let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+
+        let mut return_delay = None;
+        let mut flicked = false;
+        let mut animate_axis = |prop: Pin<&Property<LogicalLength>>, bounds: AxisBounds, v: f32| {
+            let start = prop.get().get() as f32;
+            let (end, anim) = if start != bounds.clamp(start) {
+                // Dragged past the edge: go back
+                (bounds.clamp(start), overshoot_return_animation())
+            } else if v != 0. {
+                // Decelerate along the direction of the velocity
+                let (end, duration) =
+                    flick_axis(start, v, deceleration * v.abs() / velocity.length(), bounds);
+                if end != bounds.clamp(end) {
+                    return_delay = return_delay.max(Some(duration as u64));
+                }
                 let anim = PropertyAnimation {
-                    duration,
-                    easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+                    duration: duration as _,
+                    easing: DECELERATION_CURVE,
                     ..PropertyAnimation::default()
                 };
+                (end, anim)
+            } else {
+                return;
+            };
+            let end = LogicalLength::new(end as Coord);
+            flicked |= prop.get() != end;
+            prop.set_animated_value(end, anim);
+        };
+        animate_axis(viewport_x, bx, velocity.x);
+        animate_axis(viewport_y, by, velocity.y);
+
+        if flicked {
+            (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+        }
 
-                let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
-                let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
-                let old_pos = (viewport_x.get(), viewport_y.get());
-                viewport_x.set_animated_value(final_pos.x_length(), anim.clone());
-                viewport_y.set_animated_value(final_pos.y_length(), anim);
-                if old_pos.0 != final_pos.x_length() || old_pos.1 != final_pos.y_length() {
-                    (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
-                }
+        if let Some(delay) = return_delay {
+            let weak = flick_rc.downgrade();
+            self.overshoot_timer.start(
+                TimerMode::SingleShot,
+                Duration::from_millis(delay),
+                move || {
+                    let Some(flick_rc) = weak.upgrade() else { return };
+                    let Some(flick) = flick_rc.downcast::<Flickable>() else { return };
+                    let flick = flick.as_pin_ref();
+                    let pos = LogicalPoint::from_lengths(
+                        (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
+                        (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
+                    );
+                    let final_pos = ensure_in_bound(flick, pos, &flick_rc);
+                    if final_pos != pos {
+                        let anim = overshoot_return_animation();
+                        (Flickable::FIELD_OFFSETS.viewport_x)
+                            .apply_pin(flick)
+                            .set_animated_value(final_pos.x_length(), anim.clone());
+                        (Flickable::FIELD_OFFSETS.viewport_y)
+                            .apply_pin(flick)
+                            .set_animated_value(final_pos.y_length(), anim);
+                        (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+                    }
+                },
+            );
+        }
+    }
+}
+
+fn overshoot_return_animation() -> PropertyAnimation {
+    PropertyAnimation {
+        duration: OVERSHOOT_RETURN_DURATION,
+        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+        ..PropertyAnimation::default()
+    }
+}
+
+/// Computes where a flick on one axis stops, starting at `start` (within the bounds) with the
+/// velocity `v` in px/ms, and decelerating by `deceleration` px/ms².
+/// Returns the final position and the duration of the motion in milliseconds.
+fn flick_axis(start: f32, v: f32, deceleration: f32, bounds: AxisBounds) -> (f32, f32) {
+    let mut end = start + v * v.abs() / (2. * deceleration);
+    let edge = if v > 0. { bounds.max } else { bounds.min };
+    if (end - edge) * v > 0. {
+        // Going past the edge: keep going with the remaining speed, but decelerate faster
+        end = match bounds.overshoot_size {
+            Some(size) => {
+                let remaining = v * v - 2. * deceleration * (edge - start).abs();
+                let excess = remaining / (2. * deceleration * OVERSHOOT_FRICTION_FACTOR);
+                edge + v.signum() * rubber_band(excess, size)
             }
+            None => edge,
+        };
+    }
+    (end, (2. * (end - start).abs() / deceleration).sqrt())
+}
+
+/// Damps the distance by which the viewport is dragged past its edge, so that it never exceeds `size`
+fn rubber_band(excess: f32, size: f32) -> f32 {
+    size * (1. - 1. / (excess * RUBBER_BAND_FACTOR / size + 1.))
+}
+
+/// The range of positions of the viewport on one axis
+#[derive(Clone, Copy, Debug)]
+struct AxisBounds {
+    min: f32,
+    max: f32,
+    /// The size of the flickable on that axis, if the viewport can overshoot
+    overshoot_size: Option<f32>,
+    /// The size of the flickable on that axis, if the viewport can be pulled past its start to refresh
+    pull_size: Option<f32>,
+}
+
+impl AxisBounds {
+    fn clamp(&self, p: f32) -> f32 {
+        p.max(self.min).min(self.max)
+    }
+
+    /// The position of the viewport when dragged to `p`
+    fn damp(&self, p: f32) -> f32 {
+        match (self.overshoot_size.or(self.pull_size), self.overshoot_size) {
+            (Some(size), _) if p > self.max => self.max + rubber_band(p - self.max, size),
+            (_, Some(size)) if p < self.min => self.min - rubber_band(self.min - p, size),
+            _ => self.clamp(p),
         }
-        inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
-        inner.pressed_time = None;
     }
+
+    /// The inverse of [`Self::damp`]
+    fn undamp(&self, p: f32) -> f32 {
+        let undamp =
+            |damped: f32, size: f32| damped / (RUBBER_BAND_FACTOR * (1. - damped / size).max(0.01));
+        match (self.overshoot_size.or(self.pull_size), self.overshoot_size) {
+            (Some(size), _) if p > self.max => self.max + undamp(p - self.max, size),
+            (_, Some(size)) if p < self.min => self.min - undamp(self.min - p, size),
+            _ => self.clamp(p),
+        }
+    }
+}
+
+fn axis_bounds(flick: Pin<&Flickable>, flick_rc: &ItemRc) -> [AxisBounds; 2] {
+    let geo = flick_rc.geometry();
+    let overshoot = (Flickable::FIELD_OFFSETS.overshoot).apply_pin(flick).get();
+    let refresh =
+        (Flickable::FIELD_OFFSETS.refresh_threshold).apply_pin(flick).get() > LogicalLength::zero();
+    let axis = |size: LogicalLength, viewport_size: LogicalLength, pull: bool| {
+        let (size, viewport_size) = (size.get() as f32, viewport_size.get() as f32);
+        AxisBounds {
+            min: (size - viewport_size).min(0.),
+            max: 0.,
+            overshoot_size: (overshoot && viewport_size > size && size > 0.).then_some(size),
+            pull_size: (pull && size > 0.).then_some(size),
+        }
+    };
+    [
+        axis(
+            geo.width_length(),
+            (Flickable::FIELD_OFFSETS.viewport_width).apply_pin(flick).get(),
+            false,
+        ),
+        axis(
+            geo.height_length(),
+            (Flickable::FIELD_OFFSETS.viewport_height).apply_pin(flick).get(),
+            refresh,
+        ),
+    ]
 }
 
 fn abs(l: LogicalLength) -> LogicalLength {
@@ -8,9 +8,10 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
-    ItemConsts, ItemRc, RenderingResult,
+    BlendMode, ImageFit, ImageHorizontalAlignment, ImageLoadingStatus, ImageRendering, ImageTiling,
+    ImageVerticalAlignment, Item, ItemConsts, ItemRc, RenderingResult,
 };
+use crate::animations::Instant;
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, MouseEvent,
@@ -25,6 +26,7 @@
 use crate::{Brush, Coord, Property};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
+use core::cell::Cell;
 use core::pin::Pin;
 use i_slint_core_macros::*;
 
@@ -39,6 +41,14 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub loop_count: Property<i32>,
+    /// The generation of the animation that is played, to start over when the source changes
+    animation_id: Cell<usize>,
+    animation_start: Cell<Instant>,
+    animation_paused_at: Cell<Instant>,
+    animation_started: Cell<bool>,
+    animation_paused: Cell<bool>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -116,7 +126,16 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        current_animation_frame(
+            self.source(),
+            self.playing(),
+            self.loop_count(),
+            &self.animation_id,
+            &self.animation_start,
+            &self.animation_paused_at,
+            &self.animation_started,
+            &self.animation_paused,
+        )
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -142,6 +161,10 @@ fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignm
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
         Default::default()
     }
+
+    fn blend_mode(self: Pin<&Self>) -> BlendMode {
+        BlendMode::Normal
+    }
 }
 
 impl ItemConsts for ImageItem {
@@ -162,21 +185,47 @@ pub struct ClippedImage {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub loop_count: Property<i32>,
     pub source_clip_x: Property<i32>,
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
     pub source_clip_height: Property<i32>,
+    pub nine_slice_top: Property<i32>,
+    pub nine_slice_right: Property<i32>,
+    pub nine_slice_bottom: Property<i32>,
+    pub nine_slice_left: Property<i32>,
 
     pub horizontal_alignment: Property<ImageHorizontalAlignment>,
     pub vertical_alignment: Property<ImageVerticalAlignment>,
     pub horizontal_tiling: Property<ImageTiling>,
     pub vertical_tiling: Property<ImageTiling>,
+    pub blend_mode: Property<BlendMode>,
+    pub loading_status: Property<ImageLoadingStatus>,
+
+    /// The generation of the animation that is played, to start over when the source changes
+    animation_id: Cell<usize>,
+    animation_start: Cell<Instant>,
+    animation_paused_at: Cell<Instant>,
+    animation_started: Cell<bool>,
+    animation_paused: Cell<bool>,
 
     pub cached_rendering_data: CachedRenderingData,
 }
 
 impl Item for ClippedImage {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let weak = self_rc.downgrade();
+        self.loading_status.set_binding(move || {
+            weak.upgrade()
+                .and_then(|self_rc| {
+                    let image = self_rc.downcast::<ClippedImage>()?;
+                    let source = image.as_pin_ref().source();
+                    Some(<&crate::ImageInner>::from(&source).loading_status())
+                })
+                .unwrap_or_default()
+        });
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -253,7 +302,28 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        let mut source = current_animation_frame(
+            self.source(),
+            self.playing(),
+            self.loop_count(),
+            &self.animation_id,
+            &self.animation_start,
+            &self.animation_paused_at,
+            &self.animation_started,
+            &self.animation_paused,
+        );
+        let edges = [
+            self.nine_slice_top(),
+            self.nine_slice_right(),
+            self.nine_slice_bottom(),
+            self.nine_slice_left(),
+        ];
+        // Keep the nine-slice edges of the source (from `@image-url`) unless the properties are set
+        if edges.iter().any(|e| *e > 0) {
+            let [top, right, bottom, left] = edges.map(|e| e.clamp(0, u16::MAX as i32) as u16);
+            source.set_nine_slice_edges(top, right, bottom, left);
+        }
+        source
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -284,6 +354,10 @@ fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignm
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
         (self.horizontal_tiling(), self.vertical_tiling())
     }
+
+    fn blend_mode(self: Pin<&Self>) -> BlendMode {
+        self.blend_mode()
+    }
 }
 
 impl ItemConsts for ClippedImage {
@@ -292,3 +366,53 @@ impl ItemConsts for ClippedImage {
         CachedRenderingData,
     > = ClippedImage::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
+
+/// Returns the frame of `source` to show, if it is an animated image.
+///
+/// The animation is driven by the animation tick: the time elapsed since the start of the animation
+/// is tracked in the `start` and `paused_at` instants of the item, so that it resumes where it was
+/// paused when `playing` is set back to true. When the source changes to another animation, it
+/// starts from the first frame.
+#[allow(clippy::too_many_arguments)]
+fn current_animation_frame(
+    source: crate::graphics::Image,
+    playing: bool,
+    loop_count: i32,
+    animation_id: &Cell<usize>,
+    start: &Cell<Instant>,
+    paused_at: &Cell<Instant>,
+    started: &Cell<bool>,
+    paused: &Cell<bool>,
+) -> crate::graphics::Image {
+    let animation = source.animation().filter(|animation| animation.frames().len() >= 2);
+    let id = animation.map_or(0, |animation| animation.generation());
+    if animation_id.replace(id) != id {
+        started.set(false);
+        paused.set(false);
+    }
+    let Some(animation) = animation else { return source };
+    let elapsed = if playing {
+        let now = crate::animations::current_tick();
+        if !started.replace(true) {
+            start.set(now);
+        } else if paused.get() {
+            start.set(now - (paused_at.get() - start.get()));
+        }
+        paused.set(false);
+        now - start.get()
+    } else if started.get() {
+        if !paused.replace(true) {
+            // Don't register a dependency on the tick: a paused animation doesn't need updates
+            paused_at.set(crate::properties::evaluate_no_tracking(crate::animations::current_tick));
+        }
+        paused_at.get() - start.get()
+    } else {
+        Default::default()
+    };
+    let (index, finished) = animation.frame_at(elapsed, loop_count.max(0) as u32);
+    if playing && !finished {
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.set_has_active_animations());
+    }
+    source.with_animation_frame(index)
+}
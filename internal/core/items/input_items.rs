@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::{
-    EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointerEvent,
-    PointerEventArg, PointerEventButton, PointerEventKind, PointerScrollEvent,
-    PointerScrollEventArg, RenderingResult, VoidArg,
+    DropEvent, DropEventArg, EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg,
+    KeyboardModifiers, MouseCursor, PointerEvent, PointerEventArg, PointerEventButton,
+    PointerEventKind, PointerScrollEvent, PointerScrollEventArg, RenderingResult, VoidArg,
 };
 use crate::animations::Instant;
 use crate::api::LogicalPosition;
@@ -18,8 +18,10 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowInner};
-use crate::{Callback, Coord, Property};
+use crate::{Callback, Coord, Property, SharedString};
+use alloc::boxed::Box;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
@@ -46,6 +48,8 @@ pub struct TouchArea {
     pub clicked: Callback<VoidArg>,
     pub double_clicked: Callback<VoidArg>,
     pub moved: Callback<VoidArg>,
+    pub pointer_entered: Callback<VoidArg>,
+    pub pointer_exited: Callback<VoidArg>,
     pub pointer_event: Callback<PointerEventArg>,
     pub scroll_event: Callback<PointerScrollEventArg, EventResult>,
     /// FIXME: remove this
@@ -79,7 +83,7 @@ fn input_event_filter_before_children(
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y_length());
         }
         let hovering = !matches!(event, MouseEvent::Exit);
-        Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(hovering);
+        self.set_has_hover(hovering);
         if hovering {
             if let Some(x) = window_adapter.internal(crate::InternalToken) {
                 x.set_mouse_cursor(self.mouse_cursor());
@@ -95,7 +99,7 @@ fn input_event(
         self_rc: &ItemRc,
     ) -> InputEventResult {
         if matches!(event, MouseEvent::Exit) {
-            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+            self.set_has_hover(false);
             if let Some(x) = window_adapter.internal(crate::InternalToken) {
                 x.set_mouse_cursor(MouseCursor::Default);
             }
@@ -112,22 +116,19 @@ fn input_event(
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Down,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.call_pointer_event(button, PointerEventKind::Down, window_adapter, self_rc);
 
                 InputEventResult::GrabMouse
             }
             MouseEvent::Exit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 if self.grabbed.replace(false) {
-                    Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                        button: PointerEventButton::Other,
-                        kind: PointerEventKind::Cancel,
-                        modifiers: window_adapter.window().0.modifiers.get().into(),
-                    },));
+                    self.call_pointer_event(
+                        PointerEventButton::Other,
+                        PointerEventKind::Cancel,
+                        window_adapter,
+                        self_rc,
+                    );
                 }
 
                 InputEventResult::EventAccepted
@@ -149,20 +150,17 @@ fn input_event(
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Up,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.call_pointer_event(button, PointerEventKind::Up, window_adapter, self_rc);
 
                 InputEventResult::EventAccepted
             }
             MouseEvent::Moved { .. } => {
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Move,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.call_pointer_event(
+                    PointerEventButton::Other,
+                    PointerEventKind::Move,
+                    window_adapter,
+                    self_rc,
+                );
                 return if self.grabbed.get() {
                     Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                     InputEventResult::GrabMouse
@@ -184,7 +182,7 @@ fn input_event(
                             // We are ignoring the event, so we will be removed from the item_stack,
                             // therefore we must remove the has_hover flag as there might be a scroll under us.
                             // It will be put back later.
-                            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+                            self.set_has_hover(false);
                             InputEventResult::EventIgnored
                         }
                         EventResult::Accept => InputEventResult::EventAccepted,
@@ -229,6 +227,44 @@ impl ItemConsts for TouchArea {
     > = TouchArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+impl TouchArea {
+    /// Invokes `pointer-event` at the last position of the mouse
+    fn call_pointer_event(
+        self: Pin<&Self>,
+        button: PointerEventButton,
+        kind: PointerEventKind,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        let position = LogicalPoint::from_lengths(self.mouse_x(), self.mouse_y());
+        let window_position =
+            self_rc.map_to_window(self_rc.geometry().origin + position.to_vector());
+        Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+            button,
+            kind,
+            modifiers: window_adapter.window().0.modifiers.get().into(),
+            x: position.x,
+            y: position.y,
+            window_x: window_position.x,
+            window_y: window_position.y,
+        },));
+    }
+
+    /// Sets `has-hover`, and invokes `pointer-entered` or `pointer-exited` when it changes
+    fn set_has_hover(self: Pin<&Self>, has_hover: bool) {
+        let property = Self::FIELD_OFFSETS.has_hover.apply_pin(self);
+        if property.get_untracked() == has_hover {
+            return;
+        }
+        property.set(has_hover);
+        if has_hover {
+            Self::FIELD_OFFSETS.pointer_entered.apply_pin(self).call(&());
+        } else {
+            Self::FIELD_OFFSETS.pointer_exited.apply_pin(self).call(&());
+        }
+    }
+}
+
 /// A runtime item that exposes key
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -236,6 +272,11 @@ impl ItemConsts for TouchArea {
 pub struct FocusScope {
     pub enabled: Property<bool>,
     pub has_focus: Property<bool>,
+    /// The position in the tab focus chain: positive values come first in increasing order,
+    /// then the items with 0, and negative values are skipped
+    pub tab_index: Property<i32>,
+    /// True when the focus was received with the keyboard
+    pub focus_visible: Property<bool>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
     pub focus_changed_event: Callback<VoidArg>,
@@ -290,9 +331,9 @@ fn key_event(
             KeyEventType::KeyReleased => {
                 Self::FIELD_OFFSETS.key_released.apply_pin(self).call(&(event.clone(),))
             }
-            KeyEventType::UpdateComposition | KeyEventType::CommitComposition => {
-                EventResult::Reject
-            }
+            KeyEventType::UpdateComposition
+            | KeyEventType::CommitComposition
+            | KeyEventType::ShortcutOverride => EventResult::Reject,
         };
         match r {
             EventResult::Accept => KeyEventResult::EventAccepted,
@@ -303,7 +344,7 @@ fn key_event(
     fn focus_event(
         self: Pin<&Self>,
         event: &FocusEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
+        window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
         if !self.enabled() {
@@ -312,10 +353,17 @@ fn focus_event(
 
         match event {
             FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
+                if *event == FocusEvent::FocusIn {
+                    let window = WindowInner::from_pub(window_adapter.window());
+                    self.focus_visible.set(window.focus_from_keyboard.get());
+                }
                 self.has_focus.set(true);
                 Self::FIELD_OFFSETS.focus_changed_event.apply_pin(self).call(&());
             }
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
+                if *event == FocusEvent::FocusOut {
+                    self.focus_visible.set(false);
+                }
                 self.has_focus.set(false);
                 Self::FIELD_OFFSETS.focus_changed_event.apply_pin(self).call(&());
             }
@@ -349,6 +397,9 @@ pub struct SwipeGestureRecognizer {
     pub swipe_right: Property<bool>,
     pub swipe_up: Property<bool>,
     pub swipe_down: Property<bool>,
+    pub swipe_distance_threshold: Property<LogicalLength>,
+    /// In milliseconds
+    pub swipe_duration_threshold: Property<i64>,
 
     pub moved: Callback<VoidArg>,
     pub swiped: Callback<VoidArg>,
@@ -420,7 +471,7 @@ fn input_event_filter_before_children(
                 } else if !self.pressed.get() {
                     InputEventFilterResult::ForwardEvent
                 } else if crate::animations::current_tick() - self.pressed_time.get()
-                    > super::flickable::DURATION_THRESHOLD
+                    > core::time::Duration::from_millis(self.swipe_duration_threshold().max(0) as _)
                 {
                     self.pressed.set(false);
                     InputEventFilterResult::ForwardAndIgnore
@@ -428,7 +479,7 @@ fn input_event_filter_before_children(
                     let pressed_pos = self.pressed_position();
                     let dx = position.x - pressed_pos.x as Coord;
                     let dy = position.y - pressed_pos.y as Coord;
-                    let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+                    let threshold = self.swipe_distance_threshold().get();
                     if (self.swipe_down() && dy > threshold)
                         || (self.swipe_up() && dy < -threshold)
                         || (self.swipe_left() && dx < -threshold)
@@ -479,7 +530,7 @@ fn input_event(
                     let pressed_pos = self.pressed_position();
                     let dx = position.x - pressed_pos.x as Coord;
                     let dy = position.y - pressed_pos.y as Coord;
-                    let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+                    let threshold = self.swipe_distance_threshold().get();
                     let start_swipe = if dy > threshold {
                         self.swipe_down()
                     } else if dy < -threshold {
@@ -554,3 +605,755 @@ fn cancel_impl(self: Pin<&Self>) {
         }
     }
 }
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pub dragging: Property<bool>,
+    /// The distance the pointer moved since the drag started
+    pub drag_x: Property<LogicalLength>,
+    pub drag_y: Property<LogicalLength>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+    pressed_position: Cell<LogicalPoint>,
+    // true when the left button is pressed on the area
+    pressed: Cell<bool>,
+    // true while the backend runs the drag, see WindowAdapterInternal::start_drag
+    platform_drag: Cell<bool>,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_impl(window_adapter, self_rc);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(position);
+                self.pressed.set(true);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl(window_adapter, self_rc);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Moved { position } => {
+                if self.dragging() || (self.pressed.get() && self.exceeds_threshold(position)) {
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                if self.dragging() {
+                    InputEventFilterResult::Intercept
+                } else {
+                    self.pressed.set(false);
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Pressed { .. } | MouseEvent::Released { .. } | MouseEvent::Wheel { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(position);
+                self.pressed.set(true);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl(window_adapter, self_rc);
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Moved { position } => {
+                if !self.pressed.get() {
+                    return InputEventResult::EventIgnored;
+                }
+                if self.platform_drag.get() {
+                    return InputEventResult::EventAccepted;
+                }
+                if !self.dragging() {
+                    if !self.exceeds_threshold(position) {
+                        return InputEventResult::GrabMouse;
+                    }
+                    Self::FIELD_OFFSETS.dragging.apply_pin(self).set(true);
+                    if self.start_platform_drag(window_adapter, self_rc) {
+                        return InputEventResult::EventAccepted;
+                    }
+                }
+                let pressed_position = self.pressed_position.get();
+                Self::FIELD_OFFSETS
+                    .drag_x
+                    .apply_pin(self)
+                    .set(position.x_length() - pressed_position.x_length());
+                Self::FIELD_OFFSETS
+                    .drag_y
+                    .apply_pin(self)
+                    .set(position.y_length() - pressed_position.y_length());
+                self.update_drop_areas(window_adapter, self_rc, Some(position));
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                self.pressed.set(false);
+                if !self.dragging() {
+                    return InputEventResult::EventIgnored;
+                }
+                if self.platform_drag.get() {
+                    // The backend delivers the drop
+                    return InputEventResult::EventAccepted;
+                }
+                Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+                drop_on_drop_area(
+                    window_adapter,
+                    Some(self_rc.map_to_window(self_rc.geometry().origin + position.to_vector())),
+                    self.drop_event(),
+                );
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { .. } | MouseEvent::Released { .. } | MouseEvent::Wheel { .. } => {
+                InputEventResult::EventIgnored
+            }
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    fn exceeds_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+        (position - self.pressed_position.get()).square_length() > threshold * threshold
+    }
+
+    fn drop_event(self: Pin<&Self>) -> DropEvent {
+        DropEvent { mime_type: self.mime_type(), data: self.data() }
+    }
+
+    fn cancel_impl(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        if self.platform_drag.get() {
+            // The pointer leaves the window during the drag: the backend tells when it's over
+            return;
+        }
+        self.pressed.set(false);
+        if self.dragging() {
+            Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+            self.update_drop_areas(window_adapter, self_rc, None);
+        }
+    }
+
+    /// Hands the drag over to the backend so that it can leave the window. Returns false if the
+    /// backend doesn't support that.
+    fn start_platform_drag(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> bool {
+        let Some(internal) = window_adapter.internal(crate::InternalToken) else {
+            return false;
+        };
+        let self_weak = self_rc.downgrade();
+        let finished = Box::new(move || {
+            let Some(drag_area) = self_weak.upgrade() else { return };
+            let Some(drag_area) = drag_area.downcast::<DragArea>() else { return };
+            let drag_area = drag_area.as_pin_ref();
+            drag_area.platform_drag.set(false);
+            drag_area.pressed.set(false);
+            Self::FIELD_OFFSETS.dragging.apply_pin(drag_area).set(false);
+        });
+        self.platform_drag.set(true);
+        if internal.start_drag(self.drop_event(), finished) {
+            true
+        } else {
+            self.platform_drag.set(false);
+            false
+        }
+    }
+
+    /// Updates the `DropArea`s for a drag of this item to `position`, in item coordinates
+    fn update_drop_areas(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        position: Option<LogicalPoint>,
+    ) {
+        let window_position =
+            position.map(|p| self_rc.map_to_window(self_rc.geometry().origin + p.to_vector()));
+        update_drop_areas(window_adapter, window_position, &self.drop_event());
+    }
+}
+
+/// Sets `contains-drag` on the topmost `DropArea` under `window_position` that accepts `event`, and
+/// unsets it on all the other ones. Returns that DropArea.
+///
+/// This is used for drags within the window as well as for drags from other applications.
+pub(crate) fn update_drop_areas(
+    window_adapter: &Rc<dyn WindowAdapter>,
+    window_position: Option<LogicalPoint>,
+    event: &DropEvent,
+) -> Option<ItemRc> {
+    let mut target = None;
+    // The last visited items are on top
+    for item in window_items_of_type::<DropArea>(window_adapter).into_iter().rev() {
+        let drop_area = item.downcast::<DropArea>().unwrap();
+        let drop_area = drop_area.as_pin_ref();
+        let geometry = item.geometry();
+        let local_position = window_position
+            .filter(|_| target.is_none() && drop_area.enabled() && item.is_visible())
+            .map(|p| p - item.map_to_window(geometry.origin).to_vector())
+            .filter(|p| LogicalRect::from_size(geometry.size).contains(*p));
+        let accepted = local_position.map_or(false, |p| {
+            DropArea::FIELD_OFFSETS.mouse_x.apply_pin(drop_area).set(p.x_length());
+            DropArea::FIELD_OFFSETS.mouse_y.apply_pin(drop_area).set(p.y_length());
+            !drop_area.can_drop.has_handler()
+                || DropArea::FIELD_OFFSETS.can_drop.apply_pin(drop_area).call(&(event.clone(),))
+        });
+        DropArea::FIELD_OFFSETS.contains_drag.apply_pin(drop_area).set(accepted);
+        if accepted {
+            target = Some(item);
+        }
+    }
+    target
+}
+
+/// Returns the items of type `T` of the window, in the order they are visited: the last ones are on top.
+fn window_items_of_type<T: vtable::HasStaticVTable<super::ItemVTable>>(
+    window_adapter: &Rc<dyn WindowAdapter>,
+) -> Vec<ItemRc> {
+    let Some(root) = window_adapter.window().0.try_component() else { return Vec::new() };
+    let mut items = Vec::new();
+    ItemRc::new(root, 0).visit_descendants(|item| {
+        if item.downcast::<T>().is_some() {
+            items.push(item.clone());
+        }
+        core::ops::ControlFlow::<()>::Continue(())
+    });
+    items
+}
+
+/// Drops `event` on the `DropArea` under `window_position` that accepts it, and unsets
+/// `contains-drag` on all DropAreas. Returns true if a DropArea accepted the drop.
+pub(crate) fn drop_on_drop_area(
+    window_adapter: &Rc<dyn WindowAdapter>,
+    window_position: Option<LogicalPoint>,
+    event: DropEvent,
+) -> bool {
+    let target = update_drop_areas(window_adapter, window_position, &event);
+    update_drop_areas(window_adapter, None, &event);
+    let Some(target) = target.as_ref().and_then(|t| t.downcast::<DropArea>()) else {
+        return false;
+    };
+    DropArea::FIELD_OFFSETS.dropped.apply_pin(target.as_pin_ref()).call(&(event,));
+    true
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub mouse_x: Property<LogicalLength>,
+    pub mouse_y: Property<LogicalLength>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        // The drag events are sent by the DragArea
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The implementation of the `PinchGestureRecognizer` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct PinchGestureRecognizer {
+    pub enabled: Property<bool>,
+    pub pinching: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub center: Property<LogicalPosition>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureRecognizer {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        // The pinch gestures are sent by the window when two fingers touch the screen
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for PinchGestureRecognizer {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureRecognizer {
+    /// Starts a pinch gesture centered on `window_center`, in window coordinates
+    pub(crate) fn start_pinch(self: Pin<&Self>, self_rc: &ItemRc, window_center: LogicalPoint) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+        self.set_center(self_rc, window_center);
+        Self::FIELD_OFFSETS.pinching.apply_pin(self).set(true);
+        Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+    }
+
+    /// Updates the pinch gesture. `scale` and `rotation`, in degrees, are relative to the start
+    /// of the gesture.
+    pub(crate) fn update_pinch(
+        self: Pin<&Self>,
+        self_rc: &ItemRc,
+        scale: f32,
+        rotation: f32,
+        window_center: LogicalPoint,
+    ) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+        self.set_center(self_rc, window_center);
+        Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+    }
+
+    /// Ends the pinch gesture, when a finger was lifted or when the system cancelled the touch
+    pub(crate) fn end_pinch(self: Pin<&Self>, cancelled: bool) {
+        Self::FIELD_OFFSETS.pinching.apply_pin(self).set(false);
+        if cancelled {
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        } else {
+            Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+        }
+    }
+
+    fn set_center(self: Pin<&Self>, self_rc: &ItemRc, window_center: LogicalPoint) {
+        let origin = self_rc.map_to_window(self_rc.geometry().origin);
+        Self::FIELD_OFFSETS
+            .center
+            .apply_pin(self)
+            .set(LogicalPosition::from_euclid(window_center - origin.to_vector()));
+    }
+}
+
+/// Returns the topmost enabled `PinchGestureRecognizer` under `window_position`
+pub(crate) fn pinch_gesture_recognizer_at(
+    window_adapter: &Rc<dyn WindowAdapter>,
+    window_position: LogicalPoint,
+) -> Option<ItemRc> {
+    window_items_of_type::<PinchGestureRecognizer>(window_adapter).into_iter().rev().find(|item| {
+        let recognizer = item.downcast::<PinchGestureRecognizer>().unwrap();
+        let geometry = item.geometry();
+        let local_position = window_position - item.map_to_window(geometry.origin).to_vector();
+        recognizer.as_pin_ref().enabled()
+            && item.is_visible()
+            && LogicalRect::from_size(geometry.size).contains(local_position)
+    })
+}
+
+/// The implementation of the `Shortcut` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct Shortcut {
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub activated: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+thread_local! {
+    /// The `Shortcut` items that were created, in all windows, so that a key press doesn't need
+    /// to visit the whole item tree
+    static SHORTCUTS: core::cell::RefCell<Vec<crate::item_tree::ItemWeak>> = Default::default();
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let weak = self_rc.downgrade();
+        SHORTCUTS.with(|shortcuts| {
+            let mut shortcuts = shortcuts.borrow_mut();
+            // Forget the shortcuts of the item trees that were destroyed
+            shortcuts.retain(|shortcut| shortcut.upgrade().is_some());
+            if !shortcuts.contains(&weak) {
+                shortcuts.push(weak);
+            }
+        });
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        // The key events are handled by the window, see `activate_shortcut()`
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl Shortcut {
+    fn matches(self: Pin<&Self>, event: &KeyEvent) -> bool {
+        parse_key_sequence(&self.keys()).is_some_and(|(modifiers, key)| {
+            event.modifiers == modifiers && event.key_code.to_lowercase() == key.as_str()
+        })
+    }
+}
+
+/// Parses a key sequence such as "Ctrl+Shift+S" into its modifiers and the lowercase text of
+/// its key. "Ctrl" and "Cmd" are the same modifier, which is the Command key on macOS.
+fn parse_key_sequence(keys: &str) -> Option<(KeyboardModifiers, SharedString)> {
+    let (modifiers, key) = match keys.rsplit_once('+') {
+        // The key itself is `+`, as in "Ctrl++"
+        Some((modifiers, "")) => (modifiers.strip_suffix('+').unwrap_or(modifiers), "+"),
+        Some((modifiers, key)) => (modifiers, key),
+        None => ("", keys),
+    };
+
+    let mut result = KeyboardModifiers::default();
+    for modifier in modifiers.split('+').map(str::trim).filter(|m| !m.is_empty()) {
+        match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" | "cmd" | "command" => result.control = true,
+            "shift" => result.shift = true,
+            "alt" | "option" => result.alt = true,
+            "meta" | "super" | "win" => result.meta = true,
+            _ => return None,
+        }
+    }
+
+    let key = if key == " " { key } else { key.trim() };
+    let mut chars = key.chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(c), None) => c.to_lowercase().collect::<alloc::string::String>().into(),
+        _ => special_key_from_name(key)?.into(),
+    };
+    Some((result, key))
+}
+
+/// The character code of a special key from its name in the `Key` namespace, such as "F1" or
+/// "Escape", or from a common alias, such as "Esc"
+fn special_key_from_name(name: &str) -> Option<char> {
+    use crate::input::key_codes;
+    macro_rules! match_special_key_name {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|*;)*) => {
+            $(if name.eq_ignore_ascii_case(stringify!($name)) {
+                return Some($char);
+            })*
+        };
+    }
+    i_slint_common::for_each_special_keys!(match_special_key_name);
+
+    match name.to_lowercase().as_str() {
+        "enter" => Some(key_codes::Return),
+        "esc" => Some(key_codes::Escape),
+        "del" => Some(key_codes::Delete),
+        "up" => Some(key_codes::UpArrow),
+        "down" => Some(key_codes::DownArrow),
+        "left" => Some(key_codes::LeftArrow),
+        "right" => Some(key_codes::RightArrow),
+        _ => None,
+    }
+}
+
+/// Invokes the `activated` callback of the enabled and visible `Shortcut` that matches the key
+/// event, and returns true if there is one.
+///
+/// If several shortcuts match, the one that is closest to `focus_item` in the item tree wins,
+/// that is the one whose nearest common ancestor with `focus_item` is the deepest. If there is
+/// still more than one, the last one created wins.
+pub(crate) fn activate_shortcut(
+    window_adapter: &Rc<dyn WindowAdapter>,
+    event: &KeyEvent,
+    focus_item: Option<ItemRc>,
+) -> bool {
+    if event.event_type != KeyEventType::KeyPressed {
+        return false;
+    }
+    let focus_ancestors: Vec<ItemRc> =
+        core::iter::successors(focus_item, ItemRc::parent_item).collect();
+    let distance_to_focus = |item: &ItemRc| {
+        core::iter::successors(Some(item.clone()), ItemRc::parent_item)
+            .find_map(|ancestor| focus_ancestors.iter().position(|f| *f == ancestor))
+            .unwrap_or(usize::MAX)
+    };
+
+    let shortcuts: Vec<ItemRc> = SHORTCUTS.with(|shortcuts| {
+        shortcuts
+            .borrow()
+            .iter()
+            .filter_map(|shortcut| shortcut.upgrade())
+            .filter(|item| item.window_adapter().is_some_and(|w| Rc::ptr_eq(&w, window_adapter)))
+            .collect()
+    });
+    let Some(shortcut) = shortcuts
+        .into_iter()
+        .rev()
+        .filter(|item| {
+            let shortcut = item.downcast::<Shortcut>().unwrap();
+            let shortcut = shortcut.as_pin_ref();
+            shortcut.enabled() && shortcut.matches(event) && item.is_visible()
+        })
+        .min_by_key(distance_to_focus)
+    else {
+        return false;
+    };
+    let shortcut = shortcut.downcast::<Shortcut>().unwrap();
+    Shortcut::FIELD_OFFSETS.activated.apply_pin(shortcut.as_pin_ref()).call(&());
+    true
+}
+
+#[test]
+fn test_parse_key_sequence() {
+    use crate::input::key_codes;
+    let modifiers = |control, shift, alt, meta| KeyboardModifiers { control, shift, alt, meta };
+    assert_eq!(
+        parse_key_sequence("Ctrl+S"),
+        Some((modifiers(true, false, false, false), "s".into()))
+    );
+    assert_eq!(
+        parse_key_sequence("cmd + shift + Z"),
+        Some((modifiers(true, true, false, false), "z".into()))
+    );
+    assert_eq!(
+        parse_key_sequence("Alt+F4"),
+        Some((modifiers(false, false, true, false), key_codes::F4.into()))
+    );
+    assert_eq!(
+        parse_key_sequence("Esc"),
+        Some((modifiers(false, false, false, false), key_codes::Escape.into()))
+    );
+    assert_eq!(
+        parse_key_sequence("Ctrl++"),
+        Some((modifiers(true, false, false, false), "+".into()))
+    );
+    assert_eq!(parse_key_sequence("+"), Some((modifiers(false, false, false, false), "+".into())));
+    assert_eq!(parse_key_sequence("Hyper+S"), None);
+    assert_eq!(parse_key_sequence("Ctrl+Foo"), None);
+    assert_eq!(parse_key_sequence(""), None);
+}
@@ -8,7 +8,7 @@
 Lookup the [`crate::items`] module documentation.
 */
 
-use super::{FillRule, Item, ItemConsts, ItemRc, ItemRendererRef, RenderingResult};
+use super::{FillRule, Item, ItemConsts, ItemRc, ItemRendererRef, PathOperation, RenderingResult};
 use crate::graphics::{Brush, PathData, PathDataIterator};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
@@ -23,7 +23,7 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::WindowAdapter;
-use crate::{Coord, Property};
+use crate::{Coord, Property, SharedString, SharedVector};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
@@ -40,16 +40,42 @@ pub struct Path {
     pub fill_rule: Property<FillRule>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
+    pub dash_pattern: Property<SharedVector<Coord>>,
+    pub dash_offset: Property<LogicalLength>,
+    pub combine_commands: Property<SharedString>,
+    pub combine_operation: Property<PathOperation>,
     pub viewbox_x: Property<f32>,
     pub viewbox_y: Property<f32>,
     pub viewbox_width: Property<f32>,
     pub viewbox_height: Property<f32>,
     pub clip: Property<bool>,
+    pub clip_children: Property<bool>,
+    /// The elements combined with `combine-commands`. Combining is expensive, so this binding
+    /// is only evaluated again when one of the properties it depends on changes.
+    combined_elements: Property<PathData>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
 impl Item for Path {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let weak = self_rc.downgrade();
+        self.combined_elements.set_binding(move || {
+            weak.upgrade()
+                .and_then(|self_rc| {
+                    let path = self_rc.downcast::<Path>()?;
+                    let path = path.as_pin_ref();
+                    let combine_commands = path.combine_commands();
+                    (!combine_commands.is_empty()).then(|| {
+                        path.elements().combine(
+                            PathData::Commands(combine_commands),
+                            path.combine_operation(),
+                            path.fill_rule(),
+                        )
+                    })
+                })
+                .unwrap_or_default()
+        });
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -112,6 +138,9 @@ fn render(
         self_rc: &ItemRc,
         size: LogicalSize,
     ) -> RenderingResult {
+        if self.clip_children() {
+            return (*backend).visit_path_clip(self, self_rc, size);
+        }
         let clip = self.clip();
         if clip {
             (*backend).save_state();
@@ -132,12 +161,17 @@ fn render(
 impl Path {
     /// Returns an iterator of the events of the path and an offset, so that the
     /// shape fits into the width/height of the path while respecting the stroke
-    /// width.
+    /// width. If `combine-commands` is set, the path is combined with it first.
     pub fn fitted_path_events(
         self: Pin<&Self>,
         self_rc: &ItemRc,
     ) -> Option<(LogicalVector, PathDataIterator)> {
-        let mut elements_iter = self.elements().iter()?;
+        let elements = if self.combine_commands().is_empty() {
+            self.elements()
+        } else {
+            self.combined_elements()
+        };
+        let mut elements_iter = elements.iter()?;
 
         let stroke_width = self.stroke_width();
         let geometry = self_rc.geometry();
@@ -8,11 +8,12 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    InputType, Item, ItemConsts, ItemRc, KeyEventResult, KeyEventType, PointArg,
-    PointerEventButton, RenderingResult, TextHorizontalAlignment, TextOverflow, TextStrokeStyle,
-    TextVerticalAlignment, TextWrap, VoidArg,
+    FontMetrics, FontStretch, InputType, Item, ItemConsts, ItemRc, ItemVTable, KeyEventResult,
+    KeyEventType, MouseCursor, PointArg, PointerEventButton, RenderingResult, StringArg,
+    TextFormat, TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment,
+    TextWrap, VoidArg,
 };
-use crate::graphics::{Brush, Color, FontRequest};
+use crate::graphics::{Brush, Color, FontRequest, FontStyle};
 use crate::input::{
     key_codes, FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
@@ -28,6 +29,8 @@
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
@@ -52,17 +55,41 @@ pub struct ComplexText {
 
     pub font_family: Property<SharedString>,
     pub font_italic: Property<bool>,
+    pub font_stretch: Property<FontStretch>,
     pub wrap: Property<TextWrap>,
     pub overflow: Property<TextOverflow>,
     pub letter_spacing: Property<LogicalLength>,
+    pub word_spacing: Property<LogicalLength>,
+    pub tab_width: Property<LogicalLength>,
+    pub tab_stops: Property<SharedVector<Coord>>,
+    pub line_height: Property<LogicalLength>,
+    pub line_height_factor: Property<f32>,
+    pub paragraph_spacing: Property<LogicalLength>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub text_format: Property<TextFormat>,
+    pub font_metrics: Property<FontMetrics>,
+    pub link_color: Property<Color>,
+    pub link_activated: Callback<StringArg>,
     pub cached_rendering_data: CachedRenderingData,
+    /// true when the mouse cursor was changed because the mouse is over a link
+    link_hovered: Cell<bool>,
+    /// true when the left button was pressed on a link, at `link_pressed_position`
+    link_pressed: Cell<bool>,
+    link_pressed_position: Cell<LogicalPoint>,
 }
 
 impl Item for ComplexText {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        set_font_metrics_binding(
+            &self.font_metrics,
+            self_rc,
+            |text: Pin<&Self>, window_adapter| {
+                text.font_request(WindowInner::from_pub(window_adapter.window()))
+            },
+        );
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -83,16 +110,56 @@ fn input_event_filter_before_children(
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventFilterResult {
-        InputEventFilterResult::ForwardAndIgnore
+        if self.text_format() == TextFormat::Markup {
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
     }
 
     fn input_event(
         self: Pin<&Self>,
-        _: MouseEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventResult {
-        InputEventResult::EventIgnored
+        let link = event.position().and_then(|pos| self.link_at(window_adapter, pos));
+        let hovered = link.is_some() && !matches!(event, MouseEvent::Exit);
+        if self.link_hovered.replace(hovered) != hovered {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(if hovered {
+                    MouseCursor::Pointer
+                } else {
+                    MouseCursor::Default
+                });
+            }
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. }
+                if link.is_some() =>
+            {
+                self.link_pressed.set(true);
+                self.link_pressed_position.set(position);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. }
+                if self.link_pressed.replace(false) =>
+            {
+                let pressed_link = self.link_at(window_adapter, self.link_pressed_position.get());
+                if let Some(link) = link.filter(|link| Some(link) == pressed_link.as_ref()) {
+                    Self::FIELD_OFFSETS.link_activated.apply_pin(self).call(&(link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                self.link_pressed.set(false);
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { .. } if self.link_pressed.get() => InputEventResult::GrabMouse,
+            _ if link.is_some() => InputEventResult::EventAccepted,
+            _ => InputEventResult::EventIgnored,
+        }
     }
 
     fn key_event(
@@ -143,7 +210,7 @@ fn text(self: Pin<&Self>) -> SharedString {
     fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
         let window_item = window.window_item();
 
-        FontRequest {
+        let mut request = FontRequest {
             family: {
                 let maybe_family = self.font_family();
                 if !maybe_family.is_empty() {
@@ -169,8 +236,22 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
                 }
             },
             letter_spacing: Some(self.letter_spacing()),
-            italic: self.font_italic(),
-        }
+            word_spacing: Some(self.word_spacing()),
+            tab_width: Some(self.tab_width()),
+            tab_stops: self
+                .tab_stops()
+                .iter()
+                .map(|position| LogicalLength::new(*position))
+                .collect(),
+            line_height: Some(self.line_height()),
+            line_height_factor: Some(self.line_height_factor()),
+            paragraph_spacing: Some(self.paragraph_spacing()),
+            style: if self.font_italic() { FontStyle::Italic } else { FontStyle::Normal },
+            stretch: self.font_stretch(),
+            ..Default::default()
+        };
+        request.split_family_list();
+        request
     }
 
     fn color(self: Pin<&Self>) -> Brush {
@@ -198,6 +279,47 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         (self.stroke(), self.stroke_width(), self.stroke_style())
     }
+
+    fn text_format(self: Pin<&Self>) -> TextFormat {
+        self.text_format()
+    }
+
+    fn styled_text(self: Pin<&Self>) -> Option<crate::textlayout::StyledText> {
+        let mut styled_text = (self.text_format() == TextFormat::Markup)
+            .then(|| crate::textlayout::parse_markup(&self.text()))?;
+        let link_color = self.link_color();
+        for span in styled_text.spans.iter_mut().filter(|span| span.style.link.is_some()) {
+            span.style.underline = true;
+            span.style.color.get_or_insert(link_color);
+        }
+        Some(styled_text)
+    }
+}
+
+impl ComplexText {
+    /// Returns the `href` of the link under `pos`, relative to the item.
+    fn link_at(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        pos: LogicalPoint,
+    ) -> Option<SharedString> {
+        let styled_text = self.styled_text()?;
+        let offset = text_byte_offset_for_position(self, window_adapter, pos);
+        // The offset is the nearest cursor position, so the character under the mouse is either
+        // the one before or the one after it.
+        let before = styled_text.text.get(..offset)?.chars().next_back().map(char::len_utf8);
+        let after = styled_text.text.get(offset..)?.chars().next().map(char::len_utf8);
+        [before.map(|len| offset - len..offset), after.map(|len| offset..offset + len)]
+            .into_iter()
+            .flatten()
+            .find_map(|range| {
+                let link = styled_text.style_at(range.start)?.link.clone()?;
+                text_rects_for_byte_range(self, window_adapter, range)
+                    .iter()
+                    .any(|rect| rect.contains(pos))
+                    .then_some(link)
+            })
+    }
 }
 
 /// The implementation of the `Text` element
@@ -299,7 +421,7 @@ fn text(self: Pin<&Self>) -> SharedString {
     fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
         let window_item = window.window_item();
 
-        FontRequest {
+        let mut request = FontRequest {
             family: window_item.as_ref().and_then(|item| item.as_pin_ref().font_family()),
             weight: {
                 let weight = self.font_weight();
@@ -318,8 +440,10 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
                 }
             },
             letter_spacing: None,
-            italic: false,
-        }
+            ..Default::default()
+        };
+        request.split_family_list();
+        request
     }
 
     fn color(self: Pin<&Self>) -> Brush {
@@ -347,6 +471,10 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         Default::default()
     }
+
+    fn text_format(self: Pin<&Self>) -> TextFormat {
+        TextFormat::default()
+    }
 }
 
 fn text_layout_info(
@@ -356,7 +484,7 @@ fn text_layout_info(
     width: Pin<&Property<LogicalLength>>,
 ) -> LayoutInfo {
     let window_inner = WindowInner::from_pub(window_adapter.window());
-    let text_string = text.text();
+    let text_string = text.styled_text().map_or_else(|| text.text(), |styled| styled.text.into());
     let font_request = text.font_request(window_inner);
     let scale_factor = ScaleFactor::new(window_inner.scale_factor());
     let implicit_size = |max_width, text_wrap| {
@@ -376,12 +504,14 @@ fn text_layout_info(
         Orientation::Horizontal => {
             let implicit_size = implicit_size(None, TextWrap::NoWrap);
             let min = match text.overflow() {
-                TextOverflow::Elide => implicit_size.width.min(
-                    window_adapter
-                        .renderer()
-                        .text_size(font_request, "…", None, scale_factor, TextWrap::NoWrap)
-                        .width,
-                ),
+                TextOverflow::Elide | TextOverflow::ElideLeft | TextOverflow::ElideMiddle => {
+                    implicit_size.width.min(
+                        window_adapter
+                            .renderer()
+                            .text_size(font_request, "…", None, scale_factor, TextWrap::NoWrap)
+                            .width,
+                    )
+                }
                 TextOverflow::Clip => match text.wrap() {
                     TextWrap::NoWrap => implicit_size.width,
                     TextWrap::WordWrap | TextWrap::CharWrap => 0 as Coord,
@@ -405,6 +535,51 @@ fn text_layout_info(
     }
 }
 
+/// Returns the (UTF-8) byte offset in the text of the `Text` item of the character that's visually
+/// nearest to `pos`, relative to the item. For text with markup, the offset is in the text without
+/// the markup.
+pub fn text_byte_offset_for_position(
+    text: Pin<&dyn RenderText>,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    pos: LogicalPoint,
+) -> usize {
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    let font_request = text.font_request(window_inner);
+    let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+    window_adapter.renderer().text_byte_offset_for_position(text, pos, font_request, scale_factor)
+}
+
+/// Returns the rectangles, relative to the `Text` item, that cover the glyphs of the given (UTF-8)
+/// byte range of its text. Offsets past the end of the text or within a character are moved back
+/// to the previous character boundary.
+pub fn text_rects_for_byte_range(
+    text: Pin<&dyn RenderText>,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    byte_range: core::ops::Range<usize>,
+) -> Vec<LogicalRect> {
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    let text_string = text.styled_text().map_or_else(|| text.text(), |styled| styled.text.into());
+    let char_boundary = |offset: usize| {
+        let mut offset = offset.min(text_string.len());
+        while !text_string.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    };
+    let byte_range = char_boundary(byte_range.start)..char_boundary(byte_range.end);
+    if byte_range.is_empty() {
+        return Vec::new();
+    }
+    let font_request = text.font_request(window_inner);
+    let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+    window_adapter.renderer().text_rects_for_byte_range(
+        text,
+        byte_range,
+        font_request,
+        scale_factor,
+    )
+}
+
 #[repr(C)]
 #[derive(Default, Clone, Copy, PartialEq)]
 /// Similar as `Option<core::ops::Range<i32>>` but `repr(C)`
@@ -445,6 +620,35 @@ struct UndoItem {
     kind: UndoItemKind,
 }
 
+/// Sets a binding on the `font-metrics` property of a text item, that asks the renderer for the metrics
+/// of the font selected by the font properties of the item.
+fn set_font_metrics_binding<T: vtable::HasStaticVTable<ItemVTable> + 'static>(
+    font_metrics: &Property<FontMetrics>,
+    self_rc: &ItemRc,
+    font_request: impl Fn(Pin<&T>, &Rc<dyn WindowAdapter>) -> FontRequest + 'static,
+) {
+    let weak = self_rc.downgrade();
+    font_metrics.set_binding(move || {
+        weak.upgrade()
+            .and_then(|self_rc| {
+                let item = self_rc.downcast::<T>()?;
+                let mut window_adapter = None;
+                vtable::VRc::borrow_pin(self_rc.item_tree())
+                    .as_ref()
+                    .window_adapter(true, &mut window_adapter);
+                let window_adapter = window_adapter?;
+                let scale_factor = ScaleFactor::new(window_adapter.window().scale_factor());
+                Some(
+                    window_adapter.renderer().font_metrics(
+                        font_request(item.as_pin_ref(), &window_adapter),
+                        scale_factor,
+                    ),
+                )
+            })
+            .unwrap_or_default()
+    });
+}
+
 /// The implementation of the `TextInput` element
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -455,6 +659,7 @@ pub struct TextInput {
     pub font_size: Property<LogicalLength>,
     pub font_weight: Property<i32>,
     pub font_italic: Property<bool>,
+    pub font_stretch: Property<FontStretch>,
     pub color: Property<Brush>,
     pub selection_foreground_color: Property<Color>,
     pub selection_background_color: Property<Color>,
@@ -462,7 +667,14 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub input_mask: Property<SharedString>,
     pub letter_spacing: Property<LogicalLength>,
+    pub word_spacing: Property<LogicalLength>,
+    pub tab_width: Property<LogicalLength>,
+    pub tab_stops: Property<SharedVector<Coord>>,
+    pub line_height: Property<LogicalLength>,
+    pub line_height_factor: Property<f32>,
+    pub paragraph_spacing: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cursor_position_byte_offset: Property<i32>,
@@ -470,15 +682,24 @@ pub struct TextInput {
     pub text_cursor_width: Property<LogicalLength>,
     pub cursor_visible: Property<bool>,
     pub has_focus: Property<bool>,
+    /// The position in the tab focus chain, like `FocusScope::tab_index`
+    pub tab_index: Property<i32>,
+    /// True when the focus was received with the keyboard
+    pub focus_visible: Property<bool>,
     pub enabled: Property<bool>,
     pub accepted: Callback<VoidArg>,
     pub cursor_position_changed: Callback<PointArg>,
+    pub selection_changed: Callback<VoidArg>,
     pub edited: Callback<VoidArg>,
+    pub rejected: Callback<StringArg>,
     pub single_line: Property<bool>,
     pub read_only: Property<bool>,
     pub preedit_text: Property<SharedString>,
     /// A selection within the preedit (cursor and anchor)
     preedit_selection: Property<PreEditSelection>,
+    pub font_metrics: Property<FontMetrics>,
+    pub can_undo: Property<bool>,
+    pub can_redo: Property<bool>,
     pub cached_rendering_data: CachedRenderingData,
     // The x position where the cursor wants to be.
     // It is not updated when moving up and down even when the line is shorter.
@@ -490,7 +711,13 @@ pub struct TextInput {
 }
 
 impl Item for TextInput {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        set_font_metrics_binding(
+            &self.font_metrics,
+            self_rc,
+            |text_input: Pin<&Self>, window_adapter| text_input.font_request(window_adapter),
+        );
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -667,6 +894,20 @@ fn key_event(
                 match event.text_shortcut() {
                     Some(text_shortcut) if !self.read_only() => match text_shortcut {
                         TextShortcut::Move(direction) => {
+                            // The left and right arrow keys move the cursor visually, which is
+                            // backwards through the text where it runs right-to-left.
+                            let text = self.text();
+                            let direction = if matches!(
+                                event.text.chars().next(),
+                                Some(key_codes::LeftArrow | key_codes::RightArrow)
+                            ) && crate::textlayout::is_rtl_at(
+                                &text,
+                                self.cursor_position(&text),
+                            ) {
+                                direction.mirrored()
+                            } else {
+                                direction
+                            };
                             TextInput::move_cursor(
                                 self,
                                 direction,
@@ -729,12 +970,7 @@ fn key_event(
                 }
 
                 // Only insert/interpreter non-control character strings
-                if event.text.is_empty()
-                    || event.text.as_str().chars().any(|ch| {
-                        // exclude the private use area as we encode special keys into it
-                        ('\u{f700}'..='\u{f7ff}').contains(&ch) || (ch.is_control() && ch != '\n')
-                    })
-                {
+                if !is_insertable_text(&event.text) {
                     return KeyEventResult::EventIgnored;
                 }
 
@@ -771,23 +1007,29 @@ fn key_event(
                     }
                 }
 
+                if self.read_only() || event.modifiers.control {
+                    return KeyEventResult::EventIgnored;
+                }
+
                 let input_type = self.input_type();
                 if input_type == InputType::Number
                     && !event.text.as_str().chars().all(|ch| ch.is_ascii_digit())
                 {
+                    Self::FIELD_OFFSETS.rejected.apply_pin(self).call(&(event.text.clone(),));
                     return KeyEventResult::EventIgnored;
                 }
                 if input_type == InputType::Decimal {
                     let text = self.text().clone() + event.text.as_str();
                     if text.as_str() != "." && text.as_str() != "-" && text.parse::<f64>().is_err()
                     {
+                        Self::FIELD_OFFSETS.rejected.apply_pin(self).call(&(event.text.clone(),));
                         return KeyEventResult::EventIgnored;
                     }
                 }
 
-                if self.read_only() || event.modifiers.control {
+                let Some(text_to_insert) = self.apply_input_mask(&event.text) else {
                     return KeyEventResult::EventIgnored;
-                }
+                };
 
                 // save real anchor/cursor for undo/redo
                 let (real_cursor, real_anchor) = {
@@ -801,18 +1043,18 @@ fn key_event(
 
                 // FIXME: respect grapheme boundaries
                 let insert_pos = self.selection_anchor_and_cursor().1;
-                text.insert_str(insert_pos, &event.text);
+                text.insert_str(insert_pos, &text_to_insert);
 
                 self.add_undo_item(UndoItem {
                     pos: insert_pos,
-                    text: event.text.clone(),
+                    text: text_to_insert.clone(),
                     cursor: real_cursor,
                     anchor: real_anchor,
                     kind: UndoItemKind::TextInsert,
                 });
 
                 self.as_ref().text.set(text.into());
-                let new_cursor_pos = (insert_pos + event.text.len()) as i32;
+                let new_cursor_pos = (insert_pos + text_to_insert.len()) as i32;
                 self.as_ref().anchor_position_byte_offset.set(new_cursor_pos);
                 self.set_cursor_position(
                     new_cursor_pos,
@@ -865,6 +1107,13 @@ fn key_event(
                 }
                 KeyEventResult::EventAccepted
             }
+            KeyEventType::ShortcutOverride => {
+                if self.handles_key_press(event) {
+                    KeyEventResult::EventAccepted
+                } else {
+                    KeyEventResult::EventIgnored
+                }
+            }
             _ => KeyEventResult::EventIgnored,
         }
     }
@@ -877,6 +1126,10 @@ fn focus_event(
     ) -> FocusEventResult {
         match event {
             FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
+                if *event == FocusEvent::FocusIn {
+                    let window = WindowInner::from_pub(window_adapter.window());
+                    self.focus_visible.set(window.focus_from_keyboard.get());
+                }
                 self.has_focus.set(true);
                 self.show_cursor(window_adapter);
                 WindowInner::from_pub(window_adapter.window()).set_text_input_focused(true);
@@ -890,12 +1143,13 @@ fn focus_event(
                 }
             }
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
+                if *event == FocusEvent::FocusOut {
+                    self.focus_visible.set(false);
+                }
                 self.has_focus.set(false);
                 self.hide_cursor();
                 if matches!(event, FocusEvent::FocusOut) {
-                    self.as_ref()
-                        .anchor_position_byte_offset
-                        .set(self.as_ref().cursor_position_byte_offset());
+                    self.clear_selection(window_adapter, self_rc);
                 }
                 WindowInner::from_pub(window_adapter.window()).set_text_input_focused(false);
                 if !self.read_only() {
@@ -972,6 +1226,21 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
     }
 }
 
+impl TextCursorDirection {
+    /// Returns the direction of the opposite arrow key.
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+            Self::ForwardByWord => Self::BackwardByWord,
+            Self::BackwardByWord => Self::ForwardByWord,
+            Self::StartOfLine => Self::EndOfLine,
+            Self::EndOfLine => Self::StartOfLine,
+            direction => direction,
+        }
+    }
+}
+
 enum AnchorMode {
     KeepAnchor,
     MoveAnchor,
@@ -1145,7 +1414,15 @@ fn move_cursor(
 
                 cursor_xy_pos.y += font_height;
                 cursor_xy_pos.x = self.preferred_x_pos.get();
-                self.byte_offset_for_position(cursor_xy_pos, window_adapter)
+                let new_pos = self.byte_offset_for_position(cursor_xy_pos, window_adapter);
+                let same_line = self.cursor_rect_for_byte_offset(new_pos, window_adapter).origin.y
+                    == cursor_rect.origin.y;
+                // On the last line of a multi-line text, go to the end
+                if same_line && !self.single_line() {
+                    text.len()
+                } else {
+                    new_pos
+                }
             }
             TextCursorDirection::PreviousLine => {
                 reset_preferred_x_pos = false;
@@ -1155,7 +1432,15 @@ fn move_cursor(
 
                 cursor_xy_pos.y -= font_height;
                 cursor_xy_pos.x = self.preferred_x_pos.get();
-                self.byte_offset_for_position(cursor_xy_pos, window_adapter)
+                let new_pos = self.byte_offset_for_position(cursor_xy_pos, window_adapter);
+                let same_line = self.cursor_rect_for_byte_offset(new_pos, window_adapter).origin.y
+                    == cursor_rect.origin.y;
+                // On the first line of a multi-line text, go to the start
+                if same_line && !self.single_line() {
+                    0
+                } else {
+                    new_pos
+                }
             }
             TextCursorDirection::PreviousCharacter => {
                 let mut i = last_cursor_pos;
@@ -1235,6 +1520,7 @@ pub fn set_cursor_position(
             }
             if trigger_callbacks == TextChangeNotify::TriggerCallbacks {
                 Self::FIELD_OFFSETS.cursor_position_changed.apply_pin(self).call(&(pos,));
+                Self::FIELD_OFFSETS.selection_changed.apply_pin(self).call(&());
                 self.update_ime(window_adapter, self_rc);
             }
         }
@@ -1379,6 +1665,49 @@ pub fn has_selection(self: Pin<&Self>) -> bool {
         anchor_pos != cursor_pos
     }
 
+    /// Returns the text to insert at the current selection when `text_to_insert` is typed or pasted,
+    /// with the literal characters of the `input-mask` filled in. Returns None and invokes the
+    /// `rejected` callback if the resulting text wouldn't match the mask.
+    /// Returns true if the key press of `event` is used for editing, so that it takes precedence
+    /// over the shortcuts. This follows the key press handling in `key_event`.
+    fn handles_key_press(self: Pin<&Self>, event: &KeyEvent) -> bool {
+        let read_only = self.read_only();
+        if event.text_shortcut().is_some() {
+            return !read_only;
+        }
+        if event.text.starts_with(key_codes::Return) && !read_only && self.single_line() {
+            return true;
+        }
+        if !is_insertable_text(&event.text) {
+            return false;
+        }
+        match event.shortcut() {
+            Some(
+                StandardShortcut::SelectAll
+                | StandardShortcut::Copy
+                | StandardShortcut::Undo
+                | StandardShortcut::Redo,
+            ) => return true,
+            Some(StandardShortcut::Paste | StandardShortcut::Cut) => return !read_only,
+            _ => (),
+        }
+        !read_only && !event.modifiers.control
+    }
+
+    fn apply_input_mask(self: Pin<&Self>, text_to_insert: &str) -> Option<SharedString> {
+        let mask = self.input_mask();
+        if mask.is_empty() {
+            return Some(text_to_insert.into());
+        }
+        let text = self.text();
+        let (start, end) = self.selection_anchor_and_cursor();
+        let result = apply_input_mask(&mask, &text[..start], text_to_insert, &text[end..]);
+        if result.is_none() {
+            Self::FIELD_OFFSETS.rejected.apply_pin(self).call(&(text_to_insert.into(),));
+        }
+        result.map(Into::into)
+    }
+
     fn insert(
         self: Pin<&Self>,
         text_to_insert: &str,
@@ -1389,6 +1718,15 @@ fn insert(
             return;
         }
 
+        let inserted_text = if text_to_insert.contains('\n') && self.single_line() {
+            text_to_insert.replace('\n', " ")
+        } else {
+            text_to_insert.into()
+        };
+        let Some(inserted_text) = self.apply_input_mask(&inserted_text) else {
+            return;
+        };
+
         let (real_cursor, real_anchor) = {
             let text = self.text();
             (self.cursor_position(&text), self.anchor_position(&text))
@@ -1397,23 +1735,17 @@ fn insert(
         self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
         let mut text: String = self.text().into();
         let cursor_pos = self.selection_anchor_and_cursor().1;
-        let mut inserted_text: SharedString = text_to_insert.into();
-        if text_to_insert.contains('\n') && self.single_line() {
-            inserted_text = text_to_insert.replace('\n', " ").into();
-            text.insert_str(cursor_pos, &inserted_text);
-        } else {
-            text.insert_str(cursor_pos, text_to_insert);
-        }
+        text.insert_str(cursor_pos, &inserted_text);
 
         self.add_undo_item(UndoItem {
             pos: cursor_pos,
-            text: inserted_text,
+            text: inserted_text.clone(),
             cursor: real_cursor,
             anchor: real_anchor,
             kind: UndoItemKind::TextInsert,
         });
 
-        let cursor_pos = cursor_pos + text_to_insert.len();
+        let cursor_pos = cursor_pos + inserted_text.len();
         self.text.set(text.into());
         self.anchor_position_byte_offset.set(cursor_pos as i32);
         self.set_cursor_position(
@@ -1427,6 +1759,10 @@ fn insert(
     }
 
     pub fn cut(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        // The text of a password can't be copied, so cutting it would lose it
+        if self.input_type() == InputType::Password {
+            return;
+        }
         self.copy(window_adapter, self_rc);
         self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
     }
@@ -1470,10 +1806,18 @@ pub fn select_all(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self
     }
 
     pub fn clear_selection(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
-        self.as_ref().anchor_position_byte_offset.set(self.as_ref().cursor_position_byte_offset());
+        let cursor = self.as_ref().cursor_position_byte_offset();
+        if self.as_ref().anchor_position_byte_offset() != cursor {
+            self.as_ref().anchor_position_byte_offset.set(cursor);
+            Self::FIELD_OFFSETS.selection_changed.apply_pin(self).call(&());
+        }
     }
 
     pub fn select_word(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        // Selecting a word would reveal where the spaces of a password are
+        if self.input_type() == InputType::Password {
+            return self.select_all(window_adapter, self_rc);
+        }
         let text = self.text();
         let anchor = self.anchor_position(&text);
         let cursor = self.cursor_position(&text);
@@ -1525,7 +1869,7 @@ fn copy_clipboard(
         clipboard: Clipboard,
     ) {
         let (anchor, cursor) = self.selection_anchor_and_cursor();
-        if anchor == cursor {
+        if anchor == cursor || self.input_type() == InputType::Password {
             return;
         }
         let text = self.text();
@@ -1557,7 +1901,7 @@ fn paste_clipboard(
     pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) -> FontRequest {
         let window_item = WindowInner::from_pub(window_adapter.window()).window_item();
 
-        FontRequest {
+        let mut request = FontRequest {
             family: {
                 let maybe_family = self.font_family();
                 if !maybe_family.is_empty() {
@@ -1583,8 +1927,22 @@ pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
                 }
             },
             letter_spacing: Some(self.letter_spacing()),
-            italic: self.font_italic(),
-        }
+            word_spacing: Some(self.word_spacing()),
+            tab_width: Some(self.tab_width()),
+            tab_stops: self
+                .tab_stops()
+                .iter()
+                .map(|position| LogicalLength::new(*position))
+                .collect(),
+            line_height: Some(self.line_height()),
+            line_height_factor: Some(self.line_height_factor()),
+            paragraph_spacing: Some(self.paragraph_spacing()),
+            style: if self.font_italic() { FontStyle::Italic } else { FontStyle::Normal },
+            stretch: self.font_stretch(),
+            ..Default::default()
+        };
+        request.split_family_list();
+        request
     }
 
     /// Returns a [`TextInputVisualRepresentation`] struct that contains all the fields necessary for rendering the text input,
@@ -1732,9 +2090,22 @@ fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
         }
 
         self.undo_items.set(items);
+        // A new edit can't be followed by the edits that were undone before it
+        self.redo_items.take();
+        self.update_undo_state();
+    }
+
+    /// Updates `can-undo` and `can-redo` from the undo and redo stacks.
+    fn update_undo_state(self: Pin<&Self>) {
+        let undo_items = self.undo_items.take();
+        self.can_undo.set(!undo_items.is_empty());
+        self.undo_items.set(undo_items);
+        let redo_items = self.redo_items.take();
+        self.can_redo.set(!redo_items.is_empty());
+        self.redo_items.set(redo_items);
     }
 
-    fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+    pub fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         let mut items = self.undo_items.take();
         let Some(last) = items.pop() else {
             return;
@@ -1776,9 +2147,11 @@ fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &Item
         let mut redo = self.redo_items.take();
         redo.push(last);
         self.redo_items.set(redo);
+        self.update_undo_state();
+        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
     }
 
-    fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+    pub fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         let mut items = self.redo_items.take();
         let Some(last) = items.pop() else {
             return;
@@ -1821,9 +2194,100 @@ fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &Item
         let mut undo_items = self.undo_items.take();
         undo_items.push(last);
         self.undo_items.set(undo_items);
+        self.update_undo_state();
+        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
     }
 }
 
+/// One character position of a TextInput's `input-mask`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InputMaskEntry {
+    /// `9`: an ASCII digit
+    Digit,
+    /// `a`: a letter
+    Letter,
+    /// `x`: a letter or a digit
+    Alphanumeric,
+    /// `*`: any character
+    Any,
+    /// Any other character, or a character escaped with `\`
+    Literal(char),
+}
+
+impl InputMaskEntry {
+    fn matches(self, ch: char) -> bool {
+        match self {
+            InputMaskEntry::Digit => ch.is_ascii_digit(),
+            InputMaskEntry::Letter => ch.is_alphabetic(),
+            InputMaskEntry::Alphanumeric => ch.is_alphanumeric(),
+            InputMaskEntry::Any => true,
+            InputMaskEntry::Literal(literal) => ch == literal,
+        }
+    }
+}
+
+fn input_mask_entries(mask: &str) -> impl Iterator<Item = InputMaskEntry> + '_ {
+    let mut chars = mask.chars();
+    core::iter::from_fn(move || {
+        Some(match chars.next()? {
+            '9' => InputMaskEntry::Digit,
+            'a' => InputMaskEntry::Letter,
+            'x' => InputMaskEntry::Alphanumeric,
+            '*' => InputMaskEntry::Any,
+            '\\' => InputMaskEntry::Literal(chars.next().unwrap_or('\\')),
+            ch => InputMaskEntry::Literal(ch),
+        })
+    })
+}
+
+/// Returns the text to insert between `before` and `after` so that the whole text matches `mask`.
+/// Literal characters of the mask that the inserted text skips over are filled in.
+/// Returns None if the text can't match the mask.
+/// Returns true if `text` is typed text, rather than a special key or a control character
+fn is_insertable_text(text: &str) -> bool {
+    !text.is_empty()
+        && !text.chars().any(|ch| {
+            // exclude the private use area as we encode special keys into it
+            ('\u{f700}'..='\u{f7ff}').contains(&ch) || (ch.is_control() && ch != '\n')
+        })
+}
+
+fn apply_input_mask(mask: &str, before: &str, text_to_insert: &str, after: &str) -> Option<String> {
+    let mut entries = input_mask_entries(mask);
+    for _ in before.chars() {
+        entries.next()?;
+    }
+    let mut result = String::with_capacity(text_to_insert.len());
+    for ch in text_to_insert.chars() {
+        loop {
+            match entries.next()? {
+                entry if entry.matches(ch) => {
+                    result.push(ch);
+                    break;
+                }
+                InputMaskEntry::Literal(literal) => result.push(literal),
+                _ => return None,
+            }
+        }
+    }
+    after.chars().all(|ch| entries.next().is_some_and(|entry| entry.matches(ch))).then_some(result)
+}
+
+#[test]
+fn test_apply_input_mask() {
+    assert_eq!(apply_input_mask("999-999", "", "12", "").as_deref(), Some("12"));
+    assert_eq!(apply_input_mask("999-999", "123", "4", "").as_deref(), Some("-4"));
+    assert_eq!(apply_input_mask("999-999", "123", "-4", "").as_deref(), Some("-4"));
+    assert_eq!(apply_input_mask("999-999", "", "123456", "").as_deref(), Some("123-456"));
+    assert_eq!(apply_input_mask("999-999", "", "1a", ""), None);
+    assert_eq!(apply_input_mask("999-999", "123-45", "67", ""), None);
+    assert_eq!(apply_input_mask("999-999", "1", "2", "3-4").as_deref(), Some("2"));
+    assert_eq!(apply_input_mask("999-999", "1", "-", "3"), None);
+    assert_eq!(apply_input_mask("xxxxx-xxxxx", "", "ab1D3e", "").as_deref(), Some("ab1D3-e"));
+    assert_eq!(apply_input_mask("aa\\9*", "", "zz9!", "").as_deref(), Some("zz9!"));
+    assert_eq!(apply_input_mask("aa\\9*", "", "zz!0", ""), None);
+}
+
 fn next_paragraph_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text.as_bytes()
         .iter()
@@ -1944,3 +2408,29 @@ fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     text_input.paste(window_adapter, &self_rc);
 }
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_undo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.undo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_redo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.redo(window_adapter, &self_rc);
+}
@@ -25,7 +25,24 @@
 use pin_project::pin_project;
 
 mod adapters;
+mod lazy;
 mod model_peer;
+mod selection;
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+mod sender;
+#[cfg(all(feature = "futures", target_has_atomic = "ptr"))]
+mod stream;
+mod table;
+mod tree;
+
+pub use lazy::LazyModel;
+pub use selection::{SelectionMode, SelectionModel};
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+pub use sender::VecModelSender;
+#[cfg(all(feature = "futures", target_has_atomic = "ptr"))]
+pub use stream::StreamModel;
+pub use table::{SortRole, TableModel, TableViewModel};
+pub use tree::*;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -153,6 +170,14 @@ fn set_row_data(&self, _row: usize, _data: Self::Data) {
     /// You can return `&()` if you your `Model` is constant and does not have a ModelNotify field.
     fn model_tracker(&self) -> &dyn ModelTracker;
 
+    /// Called by the `ListView` with the rows that it's about to show: the visible rows, and the
+    /// rows of the previous and next pages.
+    ///
+    /// Models that load their data on demand, for example from a database, can start fetching
+    /// these rows so that they are ready when the user scrolls to them. See [`LazyModel`].
+    /// The default implementation does nothing.
+    fn fetch_rows(&self, _rows: core::ops::Range<usize>) {}
+
     /// Returns an iterator visiting all elements of the model.
     fn iter(&self) -> ModelIterator<Self::Data>
     where
@@ -311,6 +336,9 @@ fn as_any(&self) -> &dyn core::any::Any {
     fn set_row_data(&self, row: usize, data: Self::Data) {
         (**self).set_row_data(row, data)
     }
+    fn fetch_rows(&self, rows: core::ops::Range<usize>) {
+        (**self).fetch_rows(rows)
+    }
 }
 
 /// A [`Model`] backed by a `Vec<T>`, using interior mutability.
@@ -377,6 +405,55 @@ pub fn clear(&self) {
         self.notify.reset();
     }
 
+    /// Removes the rows in `range` and replaces them with the content of the iterator,
+    /// notifying the views once for the removed rows and once for the added ones.
+    ///
+    /// Returns the removed rows. Similar to [`Vec::splice`]
+    pub fn splice<I: IntoIterator<Item = T>>(
+        &self,
+        range: core::ops::Range<usize>,
+        replace_with: I,
+    ) -> Vec<T> {
+        let index = range.start;
+        let mut array = self.array.borrow_mut();
+        let old_len = array.len();
+        let removed = array.splice(range, replace_with).collect::<Vec<_>>();
+        let added = array.len() + removed.len() - old_len;
+        drop(array);
+        if !removed.is_empty() {
+            self.notify.row_removed(index, removed.len());
+        }
+        if added > 0 {
+            self.notify.row_added(index, added);
+        }
+        removed
+    }
+
+    /// Retains only the rows for which the function returns true, notifying the views
+    /// once for each range of consecutive removed rows.
+    ///
+    /// Similar to [`Vec::retain`]
+    pub fn retain(&self, mut f: impl FnMut(&T) -> bool) {
+        // (index in the model once the previous ranges are removed, count)
+        let mut removed: Vec<(usize, usize)> = Vec::new();
+        let mut kept = 0;
+        self.array.borrow_mut().retain(|value| {
+            let keep = f(value);
+            if keep {
+                kept += 1;
+            } else {
+                match removed.last_mut() {
+                    Some((index, count)) if *index == kept => *count += 1,
+                    _ => removed.push((kept, 1)),
+                }
+            }
+            keep
+        });
+        for (index, count) in removed {
+            self.notify.row_removed(index, count);
+        }
+    }
+
     /// Swaps two elements in the model.
     pub fn swap(&self, a: usize, b: usize) {
         if a == b {
@@ -387,6 +464,22 @@ pub fn swap(&self, a: usize, b: usize) {
         self.notify.row_changed(a);
         self.notify.row_changed(b);
     }
+
+    /// Moves `count` rows starting at `old_index` so that they start at `new_index`
+    /// once moved. The views keep the state of the moved rows.
+    ///
+    /// Panics if one of the ranges is out of bounds.
+    pub fn move_rows(&self, old_index: usize, new_index: usize, count: usize) {
+        let mut array = self.array.borrow_mut();
+        assert!(old_index + count <= array.len() && new_index + count <= array.len());
+        if old_index < new_index {
+            array[old_index..new_index + count].rotate_left(count);
+        } else {
+            array[new_index..old_index + count].rotate_right(count);
+        }
+        drop(array);
+        self.notify.row_moved(old_index, new_index, count);
+    }
 }
 
 impl<T: Clone + 'static> VecModel<T> {
@@ -436,6 +529,26 @@ fn as_any(&self) -> &dyn core::any::Any {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for VecModel<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.array.borrow().as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for VecModel<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
+
 /// A model backed by a `SharedVector<T>`
 #[derive(Default)]
 pub struct SharedVectorModel<T> {
@@ -449,6 +562,36 @@ pub fn push(&self, value: T) {
         self.array.borrow_mut().push(value);
         self.notify.row_added(self.array.borrow().len() - 1, 1)
     }
+
+    /// Inserts a row at position index. All rows after that are shifted.
+    /// This function panics if index is > row_count().
+    pub fn insert(&self, index: usize, value: T) {
+        let array = self.array.borrow().clone();
+        assert!(index <= array.len(), "insertion index {index} out of range");
+        *self.array.borrow_mut() = array
+            .iter()
+            .take(index)
+            .cloned()
+            .chain(core::iter::once(value))
+            .chain(array.iter().skip(index).cloned())
+            .collect();
+        self.notify.row_added(index, 1)
+    }
+
+    /// Remove the row at the given index from the model
+    ///
+    /// Returns the removed row
+    pub fn remove(&self, index: usize) -> T {
+        let array = self.array.borrow().clone();
+        let removed = array[index].clone();
+        *self.array.borrow_mut() = array
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| (i != index).then(|| row.clone()))
+            .collect();
+        self.notify.row_removed(index, 1);
+        removed
+    }
 }
 
 impl<T> SharedVectorModel<T> {
@@ -670,6 +813,33 @@ fn from(slice: &[T]) -> Self {
     }
 }
 
+/// Serializes the rows of the model as a sequence
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for ModelRc<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.row_count()))?;
+        for row in self.iter() {
+            seq.serialize_element(&row)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a sequence into a [`VecModel`]
+#[cfg(feature = "serde")]
+impl<'de, T: Clone + serde::Deserialize<'de> + 'static> serde::Deserialize<'de> for ModelRc<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        VecModel::<T>::deserialize(deserializer).map(ModelRc::new)
+    }
+}
+
 impl<T> TryInto<Rc<dyn Model<Data = T>>> for ModelRc<T> {
     type Error = ();
 
@@ -695,6 +865,12 @@ fn set_row_data(&self, row: usize, data: Self::Data) {
         }
     }
 
+    fn fetch_rows(&self, rows: core::ops::Range<usize>) {
+        if let Some(model) = self.0.as_ref() {
+            model.fetch_rows(rows);
+        }
+    }
+
     fn model_tracker(&self) -> &dyn ModelTracker {
         self.0.as_ref().map_or(&(), |model| model.model_tracker())
     }
@@ -772,93 +948,188 @@ fn default() -> Self {
     }
 }
 
-/// This struct is put in a component when using the `for` syntax
-/// It helps instantiating the ItemTree `T`
-#[pin_project]
-pub struct RepeaterTracker<T: RepeatedItemTree> {
-    inner: RefCell<RepeaterInner<T>>,
-    #[pin]
-    model: Property<ModelRc<T::Data>>,
-    #[pin]
-    is_dirty: Property<bool>,
-    /// Only used for the list view to track if the scrollbar has changed and item needs to be laid out again.
-    #[pin]
-    listview_geometry_tracker: crate::properties::PropertyTracker,
-}
-
-impl<T: RepeatedItemTree> ModelChangeListener for RepeaterTracker<T> {
-    /// Notify the peers that a specific row was changed
-    fn row_changed(self: Pin<&Self>, row: usize) {
-        let mut inner = self.inner.borrow_mut();
-        let inner = &mut *inner;
-        if let Some(c) = inner.instances.get_mut(row.wrapping_sub(inner.offset)) {
-            if !self.model.is_dirty() {
-                if let Some(comp) = c.1.as_ref() {
-                    let model = self.project_ref().model.get_untracked();
-                    if let Some(data) = model.row_data(row) {
-                        comp.update(row, data);
-                    }
-                    c.0 = RepeatedInstanceState::Clean;
+impl<C: RepeatedItemTree> RepeaterInner<C> {
+    fn apply_change(&mut self, change: PendingChange) {
+        match change {
+            PendingChange::Changed(row) => {
+                if let Some(c) = self.instances.get_mut(row.wrapping_sub(self.offset)) {
+                    c.0 = RepeatedInstanceState::Dirty;
                 }
-            } else {
-                c.0 = RepeatedInstanceState::Dirty;
             }
+            PendingChange::Added { index, count } => self.row_added(index, count),
+            PendingChange::Removed { index, count } => self.row_removed(index, count),
+            PendingChange::Moved { old_index, new_index, count } => {
+                self.row_moved(old_index, new_index, count)
+            }
+            PendingChange::Reset => self.instances.clear(),
         }
     }
-    /// Notify the peers that rows were added
-    fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
-        let mut inner = self.inner.borrow_mut();
-        if index < inner.offset {
-            if index + count < inner.offset {
+
+    fn row_added(&mut self, mut index: usize, mut count: usize) {
+        if index < self.offset {
+            if index + count < self.offset {
                 return;
             }
-            count -= inner.offset - index;
+            count -= self.offset - index;
             index = 0;
         } else {
-            index -= inner.offset;
+            index -= self.offset;
         }
-        if count == 0 || index > inner.instances.len() {
+        if count == 0 || index > self.instances.len() {
             return;
         }
-        self.is_dirty.set(true);
-        inner.instances.splice(
+        self.instances.splice(
             index..index,
             core::iter::repeat((RepeatedInstanceState::Dirty, None)).take(count),
         );
-        for c in inner.instances[index + count..].iter_mut() {
+        for c in self.instances[index + count..].iter_mut() {
             // Because all the indexes are dirty
             c.0 = RepeatedInstanceState::Dirty;
         }
     }
-    /// Notify the peers that rows were removed
-    fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
-        let mut inner = self.inner.borrow_mut();
-        if index < inner.offset {
-            if index + count < inner.offset {
+
+    fn row_removed(&mut self, mut index: usize, mut count: usize) {
+        if index < self.offset {
+            if index + count < self.offset {
                 return;
             }
-            count -= inner.offset - index;
+            count -= self.offset - index;
             index = 0;
         } else {
-            index -= inner.offset;
+            index -= self.offset;
         }
-        if count == 0 || index >= inner.instances.len() {
+        if count == 0 || index >= self.instances.len() {
             return;
         }
-        if (index + count) > inner.instances.len() {
-            count = inner.instances.len() - index;
+        if (index + count) > self.instances.len() {
+            count = self.instances.len() - index;
         }
-        self.is_dirty.set(true);
-        inner.instances.drain(index..(index + count));
-        for c in inner.instances[index..].iter_mut() {
+        self.instances.drain(index..(index + count));
+        for c in self.instances[index..].iter_mut() {
             // Because all the indexes are dirty
             c.0 = RepeatedInstanceState::Dirty;
         }
     }
 
-    fn reset(self: Pin<&Self>) {
+    fn row_moved(&mut self, old_index: usize, new_index: usize, count: usize) {
+        let offset = self.offset;
+        let len = self.instances.len();
+        let instantiated = |index: usize| index >= offset && index - offset + count <= len;
+        if !instantiated(old_index) || !instantiated(new_index) {
+            self.row_removed(old_index, count);
+            self.row_added(new_index, count);
+            return;
+        }
+        let (old_index, new_index) = (old_index - offset, new_index - offset);
+        let range = if old_index < new_index {
+            self.instances[old_index..new_index + count].rotate_left(count);
+            old_index..new_index + count
+        } else {
+            self.instances[new_index..old_index + count].rotate_right(count);
+            new_index..old_index + count
+        };
+        for c in self.instances[range].iter_mut() {
+            // The instances are kept, but their index changed
+            c.0 = RepeatedInstanceState::Dirty;
+        }
+    }
+}
+
+/// A change of the model that was not yet applied to the instances of a repeater
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum PendingChange {
+    Changed(usize),
+    Added { index: usize, count: usize },
+    Removed { index: usize, count: usize },
+    Moved { old_index: usize, new_index: usize, count: usize },
+    Reset,
+}
+
+/// This struct is put in a component when using the `for` syntax
+/// It helps instantiating the ItemTree `T`
+///
+/// The notifications of the model are queued, and merged when possible, until the repeater is
+/// updated before the next layout or rendering. So changing many rows during one event only
+/// updates each instance once.
+#[pin_project]
+pub struct RepeaterTracker<T: RepeatedItemTree> {
+    inner: RefCell<RepeaterInner<T>>,
+    /// The changes of the model since the last update
+    pending_changes: RefCell<Vec<PendingChange>>,
+    #[pin]
+    model: Property<ModelRc<T::Data>>,
+    #[pin]
+    is_dirty: Property<bool>,
+    /// Only used for the list view to track if the scrollbar has changed and item needs to be laid out again.
+    #[pin]
+    listview_geometry_tracker: crate::properties::PropertyTracker,
+}
+
+impl<T: RepeatedItemTree> RepeaterTracker<T> {
+    /// Queues a change, merging it into the previous one when they are contiguous
+    fn push_change(self: Pin<&Self>, change: PendingChange) {
+        let mut pending = self.pending_changes.borrow_mut();
+        if change == PendingChange::Reset {
+            pending.clear();
+        }
+        let merged = match (pending.last_mut(), change) {
+            (Some(PendingChange::Changed(last)), PendingChange::Changed(row)) => *last == row,
+            (
+                Some(PendingChange::Added { index, count }),
+                PendingChange::Added { index: new_index, count: new_count },
+            ) if (*index..=*index + *count).contains(&new_index) => {
+                *count += new_count;
+                true
+            }
+            (
+                Some(PendingChange::Removed { index, count }),
+                PendingChange::Removed { index: new_index, count: new_count },
+            ) if new_index == *index || new_index + new_count == *index => {
+                *index = new_index;
+                *count += new_count;
+                true
+            }
+            _ => false,
+        };
+        if !merged {
+            pending.push(change);
+        }
+        drop(pending);
         self.is_dirty.set(true);
-        self.inner.borrow_mut().instances.clear();
+    }
+
+    /// Applies the queued changes to the instances
+    fn apply_pending_changes(&self) {
+        let pending = core::mem::take(&mut *self.pending_changes.borrow_mut());
+        if !pending.is_empty() {
+            let mut inner = self.inner.borrow_mut();
+            for change in pending {
+                inner.apply_change(change);
+            }
+        }
+    }
+}
+
+impl<T: RepeatedItemTree> ModelChangeListener for RepeaterTracker<T> {
+    /// Notify the peers that a specific row was changed
+    fn row_changed(self: Pin<&Self>, row: usize) {
+        self.push_change(PendingChange::Changed(row));
+    }
+    /// Notify the peers that rows were added
+    fn row_added(self: Pin<&Self>, index: usize, count: usize) {
+        self.push_change(PendingChange::Added { index, count });
+    }
+    /// Notify the peers that rows were removed
+    fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
+        self.push_change(PendingChange::Removed { index, count });
+    }
+    /// Notify the peers that rows were moved
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        self.push_change(PendingChange::Moved { old_index, new_index, count });
+    }
+
+    fn reset(self: Pin<&Self>) {
+        self.push_change(PendingChange::Reset);
     }
 }
 
@@ -866,6 +1137,7 @@ impl<C: RepeatedItemTree> Default for RepeaterTracker<C> {
     fn default() -> Self {
         Self {
             inner: Default::default(),
+            pending_changes: Default::default(),
             model: Property::new_named(ModelRc::default(), "i_slint_core::Repeater::model"),
             is_dirty: Property::new_named(false, "i_slint_core::Repeater::is_dirty"),
             listview_geometry_tracker: Default::default(),
@@ -893,12 +1165,14 @@ fn model(self: Pin<&Self>) -> ModelRc<C::Data> {
 
         if model.is_dirty() {
             *self.data().inner.borrow_mut() = RepeaterInner::default();
+            self.data().pending_changes.borrow_mut().clear();
             self.data().is_dirty.set(true);
             let m = model.get();
             let peer = self.project_ref().0.model_peer();
             m.model_tracker().attach_peer(peer);
             m
         } else {
+            self.data().apply_pending_changes();
             model.get()
         }
     }
@@ -1160,6 +1434,12 @@ pub fn ensure_updated_listview(
             let new_viewport_y = -inner.anchor_y + vp_y + new_offset_y;
             viewport_y.set(new_viewport_y);
             inner.previous_viewport_y = new_viewport_y;
+
+            // Let lazy models load the previous and next pages before they are shown
+            let page = inner.instances.len();
+            let prefetch = inner.offset.saturating_sub(page)..(idx + page).min(row_count);
+            drop(inner);
+            model.fetch_rows(prefetch);
             break;
         }
     }
@@ -1338,6 +1618,7 @@ struct TestView {
         changed_rows: RefCell<Vec<(usize, usize)>>,
         added_rows: RefCell<Vec<(usize, usize, usize)>>,
         removed_rows: RefCell<Vec<(usize, usize, usize)>>,
+        moved_rows: RefCell<Vec<(usize, usize, usize)>>,
         reset: RefCell<usize>,
         model: RefCell<Option<std::rc::Weak<dyn Model<Data = i32>>>>,
     }
@@ -1346,6 +1627,7 @@ fn clear(&self) {
             self.changed_rows.borrow_mut().clear();
             self.added_rows.borrow_mut().clear();
             self.removed_rows.borrow_mut().clear();
+            self.moved_rows.borrow_mut().clear();
             *self.reset.borrow_mut() = 0;
         }
         fn row_count(&self) -> usize {
@@ -1368,6 +1650,10 @@ fn row_added(self: Pin<&Self>, index: usize, count: usize) {
         fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
             self.removed_rows.borrow_mut().push((index, count, self.row_count()));
         }
+
+        fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+            self.moved_rows.borrow_mut().push((old_index, new_index, count));
+        }
         fn reset(self: Pin<&Self>) {
             *self.reset.borrow_mut() += 1;
         }
@@ -1466,4 +1752,69 @@ fn test_vecmodel_swap() {
         assert_eq!(*view.reset.borrow(), 0);
         view.clear();
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_deserialize_model() {
+        let model = ModelRc::new(VecModel::from(vec![1, 2, 3]));
+        let serialized = serde_json::to_string(&model).unwrap();
+        assert_eq!(serialized, "[1,2,3]");
+        let deserialized: ModelRc<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        let vec_model: VecModel<i32> = serde_json::from_str("[4,5]").unwrap();
+        assert_eq!(serde_json::to_string(&vec_model).unwrap(), "[4,5]");
+    }
+
+    #[test]
+    fn test_vecmodel_splice_and_retain() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        assert_eq!(model.splice(1..3, [10, 11, 12]), vec![2, 3]);
+        assert_eq!(&*view.removed_rows.borrow(), &[(1, 2, 6)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(1, 3, 6)]);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![1, 10, 11, 12, 4, 5]);
+        view.clear();
+
+        assert_eq!(model.splice(2..2, []), vec![]);
+        assert!(view.removed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+
+        model.retain(|x| *x != 10 && *x != 11 && *x != 5);
+        assert_eq!(&*view.removed_rows.borrow(), &[(1, 2, 3), (3, 1, 3)]);
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![1, 12, 4]);
+    }
+
+    #[test]
+    fn test_vecmodel_move_rows() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        model.move_rows(0, 3, 2);
+        assert_eq!(&*view.moved_rows.borrow(), &[(0, 3, 2)]);
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 4, 5, 1, 2]);
+        view.clear();
+
+        model.move_rows(4, 1, 1);
+        assert_eq!(&*view.moved_rows.borrow(), &[(4, 1, 1)]);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 2, 4, 5, 1]);
+        view.clear();
+
+        model.move_rows(2, 2, 3);
+        assert!(view.moved_rows.borrow().is_empty());
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 2, 4, 5, 1]);
+    }
 }
@@ -14,6 +14,7 @@ struct TestView {
     changed_rows: RefCell<Vec<usize>>,
     added_rows: RefCell<Vec<(usize, usize)>>,
     removed_rows: RefCell<Vec<(usize, usize)>>,
+    moved_rows: RefCell<Vec<(usize, usize, usize)>>,
     reset: RefCell<usize>,
 }
 
@@ -23,6 +24,7 @@ fn clear(&self) {
         self.changed_rows.borrow_mut().clear();
         self.added_rows.borrow_mut().clear();
         self.removed_rows.borrow_mut().clear();
+        self.moved_rows.borrow_mut().clear();
     }
 }
 
@@ -39,13 +41,17 @@ fn row_added(self: Pin<&Self>, index: usize, count: usize) {
     fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         self.removed_rows.borrow_mut().push((index, count));
     }
+
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        self.moved_rows.borrow_mut().push((old_index, new_index, count));
+    }
     fn reset(self: Pin<&Self>) {
         *self.reset.borrow_mut() += 1;
     }
 }
 
 #[cfg(test)]
-struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
+pub(super) struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
     model: Rc<dyn Model<Data = Data>>,
     rows_copy: RefCell<Vec<Data>>,
 }
@@ -202,6 +208,10 @@ fn row_data(&self, row: usize) -> Option<Self::Data> {
         self.wrapped_model.row_data(row).map(|x| (self.map_function)(x))
     }
 
+    fn fetch_rows(&self, rows: core::ops::Range<usize>) {
+        self.wrapped_model.fetch_rows(rows)
+    }
+
     fn model_tracker(&self) -> &dyn ModelTracker {
         self.wrapped_model.model_tracker()
     }
@@ -243,6 +253,19 @@ fn test_map_model() {
     assert_eq!(map.row_data(1).unwrap(), "2");
 }
 
+/// Returns the index of `row` after moving `count` rows from `old_index` to `new_index`
+fn moved_row(row: usize, old_index: usize, new_index: usize, count: usize) -> usize {
+    if (old_index..old_index + count).contains(&row) {
+        return row - old_index + new_index;
+    }
+    let row = if row >= old_index + count { row - count } else { row };
+    if row >= new_index {
+        row + count
+    } else {
+        row
+    }
+}
+
 struct FilterModelInner<M, F>
 where
     M: Model + 'static,
@@ -347,6 +370,17 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         }
     }
 
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        let mut mapping = self.mapping.borrow_mut();
+        let start = mapping.partition_point(|i| *i < old_index);
+        let end = mapping.partition_point(|i| *i < old_index + count);
+        mapping.iter_mut().for_each(|i| *i = moved_row(*i, old_index, new_index, count));
+        mapping.sort_unstable();
+        let new_start = mapping.partition_point(|i| *i < new_index);
+        drop(mapping);
+        self.notify.row_moved(start, new_start, end - start);
+    }
+
     fn reset(self: Pin<&Self>) {
         self.build_mapping_vec();
         self.notify.reset();
@@ -453,6 +487,12 @@ pub fn unfiltered_row(&self, filtered_row: usize) -> usize {
         self.0.mapping.borrow()[filtered_row]
     }
 
+    /// Gets the filtered row index for a given row index of the underlying unfiltered model,
+    /// or `None` if that row is filtered out.
+    pub fn filtered_row(&self, unfiltered_row: usize) -> Option<usize> {
+        self.0.mapping.borrow().binary_search(&unfiltered_row).ok()
+    }
+
     /// Returns a reference to the inner model
     pub fn source_model(&self) -> &M {
         &self.0.as_ref().get().get_ref().wrapped_model
@@ -552,6 +592,63 @@ fn test_filter_model_source_model() {
     }
 }
 
+#[test]
+fn test_filter_model_move() {
+    let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+    let model = Rc::new(FilterModel::new(wrapped_rc.clone(), |x| x % 2 == 0));
+    let _checker = ModelChecker::new(model.clone());
+
+    let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+    model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+    wrapped_rc.move_rows(1, 4, 1);
+    assert_eq!(&*observer.moved_rows.borrow(), &[(0, 1, 1)]);
+    assert!(observer.added_rows.borrow().is_empty());
+    assert!(observer.removed_rows.borrow().is_empty());
+    observer.clear();
+
+    let expected = &[4, 2, 6];
+    assert_eq!(model.row_count(), expected.len());
+    for (i, v) in expected.iter().enumerate() {
+        assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+    }
+
+    // Moving rows that are filtered out doesn't move anything
+    wrapped_rc.move_rows(0, 1, 1);
+    assert!(observer.moved_rows.borrow().is_empty());
+}
+
+#[test]
+fn test_filter_model_reset() {
+    let threshold = Rc::new(Cell::new(2));
+    let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5]));
+    let model = Rc::new(FilterModel::new(wrapped_rc.clone(), {
+        let threshold = threshold.clone();
+        move |x| *x > threshold.get()
+    }));
+    let _checker = ModelChecker::new(model.clone());
+
+    let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+    model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+    assert_eq!(model.row_count(), 3);
+    assert_eq!(model.unfiltered_row(0), 2);
+    assert_eq!(model.filtered_row(3), Some(1));
+    assert_eq!(model.filtered_row(1), None);
+
+    threshold.set(3);
+    model.reset();
+    assert_eq!(*observer.reset.borrow(), 1);
+
+    let expected = &[4, 5];
+    assert_eq!(model.row_count(), expected.len());
+    for (i, v) in expected.iter().enumerate() {
+        assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+    }
+    assert_eq!(model.filtered_row(3), Some(0));
+    assert_eq!(model.filtered_row(2), None);
+}
+
 pub trait SortHelper<D> {
     fn cmp(&mut self, lhs: &D, rhs: &D) -> core::cmp::Ordering;
 }
@@ -719,6 +816,13 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         }
     }
 
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        // The order of the data doesn't change, only the rows of the source model
+        for row in self.mapping.borrow_mut().iter_mut() {
+            *row = moved_row(*row, old_index, new_index, count);
+        }
+    }
+
     fn reset(self: Pin<&Self>) {
         self.sorted_rows_dirty.set(true);
         self.notify.reset();
@@ -848,11 +952,6 @@ pub fn new(wrapped_model: M, sort_function: F) -> Self
 
         Self(container)
     }
-
-    /// Returns a reference to the inner model
-    pub fn source_model(&self) -> &M {
-        &self.0.as_ref().get().get_ref().wrapped_model
-    }
 }
 
 impl<M> SortModel<M, AscendingSortHelper>
@@ -880,7 +979,13 @@ pub fn new_ascending(wrapped_model: M) -> Self
 
         Self(container)
     }
+}
 
+impl<M, S> SortModel<M, S>
+where
+    M: Model + 'static,
+    S: SortHelper<M::Data>,
+{
     /// Manually reapply the sorting. You need to run this e.g. if the sort function depends
     /// on mutable state and it has changed.
     pub fn reset(&self) {
@@ -892,6 +997,18 @@ pub fn unsorted_row(&self, sorted_row: usize) -> usize {
         self.0.build_mapping_vec();
         self.0.mapping.borrow()[sorted_row]
     }
+
+    /// Gets the sorted row index for a given row index of the underlying unsorted model,
+    /// or `None` if the row is out of bounds.
+    pub fn sorted_row(&self, unsorted_row: usize) -> Option<usize> {
+        self.0.build_mapping_vec();
+        self.0.mapping.borrow().iter().position(|r| *r == unsorted_row)
+    }
+
+    /// Returns a reference to the inner model
+    pub fn source_model(&self) -> &M {
+        &self.0.as_ref().get().get_ref().wrapped_model
+    }
 }
 
 impl<M, S> Model for SortModel<M, S>
@@ -1066,6 +1183,60 @@ fn test_sorted_model_source_model() {
             assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
         }
     }
+
+    #[test]
+    fn test_sorted_model_move() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![3, 4, 1, 2]));
+        let model = Rc::new(SortModel::new_ascending(wrapped_rc.clone()));
+        let _checker = ModelChecker::new(model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        assert_eq!(model.unsorted_row(0), 2);
+        wrapped_rc.move_rows(0, 2, 2);
+        assert!(observer.moved_rows.borrow().is_empty());
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.removed_rows.borrow().is_empty());
+        assert!(observer.changed_rows.borrow().is_empty());
+
+        let expected = &[1, 2, 3, 4];
+        for (i, v) in expected.iter().enumerate() {
+            assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+        }
+        assert_eq!(model.unsorted_row(0), 0);
+    }
+
+    #[test]
+    fn test_sorted_model_reset() {
+        let descending = Rc::new(Cell::new(false));
+        let wrapped_rc = Rc::new(VecModel::from(vec![3, 4, 1, 2]));
+        let model = Rc::new(SortModel::new(wrapped_rc.clone(), {
+            let descending = descending.clone();
+            move |lhs, rhs| if descending.get() { rhs.cmp(lhs) } else { lhs.cmp(rhs) }
+        }));
+        let _checker = ModelChecker::new(model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        assert_eq!(model.row_data(0), Some(1));
+        assert_eq!(model.unsorted_row(0), 2);
+        assert_eq!(model.sorted_row(2), Some(0));
+        assert_eq!(model.sorted_row(4), None);
+
+        descending.set(true);
+        model.reset();
+        assert_eq!(*observer.reset.borrow(), 1);
+
+        let expected = &[4, 3, 2, 1];
+        assert_eq!(model.row_count(), expected.len());
+        for (i, v) in expected.iter().enumerate() {
+            assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+        }
+        assert_eq!(model.unsorted_row(0), 1);
+        assert_eq!(model.sorted_row(2), Some(3));
+    }
 }
 
 /// Provides a reversed view of another [`Model`].
@@ -1163,6 +1334,11 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         self.notify.row_removed(row_count - index, count);
     }
 
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        let row_count = self.wrapped_model.row_count();
+        self.notify.row_moved(row_count - old_index - count, row_count - new_index - count, count);
+    }
+
     fn reset(self: Pin<&Self>) {
         self.notify.reset()
     }
@@ -1206,6 +1382,12 @@ fn set_row_data(&self, row: usize, data: Self::Data) {
         self.0.wrapped_model.set_row_data(count - row - 1, data);
     }
 
+    fn fetch_rows(&self, rows: core::ops::Range<usize>) {
+        let count = self.0.wrapped_model.row_count();
+        let end = count.saturating_sub(rows.start);
+        self.0.wrapped_model.fetch_rows(count.saturating_sub(rows.end)..end)
+    }
+
     fn model_tracker(&self) -> &dyn ModelTracker {
         &self.0.notify
     }
@@ -1265,6 +1447,22 @@ fn test_reversed_model_insert() {
         }
     }
 
+    #[test]
+    fn test_reversed_model_move() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let model = Rc::new(ReverseModel::new(wrapped_rc.clone()));
+        let _checker = ModelChecker::new(model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        wrapped_rc.move_rows(0, 2, 2);
+        assert_eq!(&*observer.moved_rows.borrow(), &[(2, 0, 2)]);
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.removed_rows.borrow().is_empty());
+        check_content(&model, &[2, 1, 4, 3]);
+    }
+
     #[test]
     fn test_reversed_model_remove() {
         for (idx, mapped_idx) in [(0, 3), (1, 2), (2, 1), (3, 0)] {
@@ -0,0 +1,255 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`LazyModel`], a model that loads its rows on demand.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use core::ops::Range;
+
+/// A [`Model`] with a known number of rows, whose data is fetched on demand in blocks of rows.
+///
+/// This is meant for data sets that are too large to be loaded in memory at once, like a log
+/// with millions of lines. When a view reads a row that isn't loaded yet, the `LazyModel` returns
+/// a placeholder value and calls the fetch function with the range of the block of rows to load.
+/// The application loads these rows and passes them to [`Self::set_rows()`], which replaces the
+/// placeholders in the view. A `ListView` also calls [`Model::fetch_rows()`] with the rows of the
+/// previous and next pages, so that they are usually loaded before the user scrolls to them.
+///
+/// Only the rows around the last requested ones are kept in memory: when more than the cache size
+/// are loaded, the rows furthest away are discarded, and fetched again when they are needed.
+///
+/// The fetch function is called while the view reads the model, so it must not call `set_rows()`
+/// before returning. Instead, it should start loading the rows, for example in a future spawned with
+/// `slint::spawn_local()` or in a thread that calls `slint::invoke_from_event_loop()` when done.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{Model, LazyModel, SharedString};
+/// # use std::{cell::RefCell, rc::Rc};
+/// let requests = Rc::new(RefCell::new(Vec::new()));
+/// let model = LazyModel::new(2_000_000, SharedString::from("loading..."), {
+///     let requests = requests.clone();
+///     move |rows| requests.borrow_mut().push(rows)
+/// });
+///
+/// assert_eq!(model.row_data(1234).unwrap(), "loading...");
+/// assert_eq!(requests.borrow()[0], 1200..1300);
+///
+/// // Later, when the rows are loaded:
+/// model.set_rows(1200, (1200..1300).map(|i| slint::format!("line {i}")));
+/// assert_eq!(model.row_data(1234).unwrap(), "line 1234");
+/// ```
+pub struct LazyModel<T> {
+    row_count: Cell<usize>,
+    placeholder: T,
+    /// The loaded rows, and `None` for the rows that are being fetched
+    rows: RefCell<BTreeMap<usize, Option<T>>>,
+    fetch: Box<dyn Fn(Range<usize>)>,
+    block_size: usize,
+    cache_size: usize,
+    notify: ModelNotify,
+}
+
+impl<T: Clone + 'static> LazyModel<T> {
+    /// Creates a new LazyModel with `row_count` rows, that shows `placeholder` for the rows
+    /// that are not loaded yet, and calls `fetch` with the ranges of rows to load.
+    pub fn new(row_count: usize, placeholder: T, fetch: impl Fn(Range<usize>) + 'static) -> Self {
+        Self {
+            row_count: Cell::new(row_count),
+            placeholder,
+            rows: Default::default(),
+            fetch: Box::new(fetch),
+            block_size: 100,
+            cache_size: 10_000,
+            notify: Default::default(),
+        }
+    }
+
+    /// Sets the number of rows that are fetched together. The default is 100.
+    #[must_use]
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size.max(1);
+        self
+    }
+
+    /// Sets the number of rows that are kept in memory. The default is 10000.
+    #[must_use]
+    pub fn with_cache_size(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    /// Provides the data of the rows starting at `start`, that were requested by the fetch function.
+    ///
+    /// Rows that were discarded from the cache in the meantime are ignored.
+    pub fn set_rows(&self, start: usize, rows: impl IntoIterator<Item = T>) {
+        let mut changed = Vec::new();
+        {
+            let mut loaded = self.rows.borrow_mut();
+            for (row, data) in (start..).zip(rows) {
+                if let Some(entry) = loaded.get_mut(&row) {
+                    *entry = Some(data);
+                    changed.push(row);
+                }
+            }
+        }
+        for row in changed {
+            self.notify.row_changed(row);
+        }
+    }
+
+    /// Returns true if the data of the row was loaded, false if the row shows the placeholder.
+    pub fn is_loaded(&self, row: usize) -> bool {
+        self.rows.borrow().get(&row).is_some_and(|data| data.is_some())
+    }
+
+    /// Changes the number of rows, for example when lines are appended to a log.
+    pub fn set_row_count(&self, row_count: usize) {
+        let old_count = self.row_count.replace(row_count);
+        if row_count > old_count {
+            self.notify.row_added(old_count, row_count - old_count);
+        } else if row_count < old_count {
+            self.rows.borrow_mut().split_off(&row_count);
+            self.notify.row_removed(row_count, old_count - row_count);
+        }
+    }
+
+    /// Discards all the loaded rows, so that they are fetched again when they are shown.
+    pub fn reset(&self) {
+        self.rows.borrow_mut().clear();
+        self.notify.reset();
+    }
+
+    /// Fetches the blocks of rows that overlap `rows` and were not requested yet.
+    fn request(&self, rows: Range<usize>) {
+        let end = rows.end.min(self.row_count.get());
+        if rows.start >= end {
+            return;
+        }
+        let block_size = self.block_size;
+        for block in rows.start / block_size..=(end - 1) / block_size {
+            let block = block * block_size..((block + 1) * block_size).min(self.row_count.get());
+            {
+                let mut loaded = self.rows.borrow_mut();
+                if loaded.range(block.clone()).count() == block.len() {
+                    continue;
+                }
+                for row in block.clone() {
+                    loaded.entry(row).or_insert(None);
+                }
+            }
+            (self.fetch)(block);
+        }
+
+        let mut loaded = self.rows.borrow_mut();
+        if loaded.len() > self.cache_size {
+            let center = (rows.start + end) / 2 / block_size;
+            let keep = self.cache_size / block_size / 2;
+            loaded.retain(|row, _| (row / block_size).abs_diff(center) <= keep);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Model for LazyModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.row_count.get() {
+            return None;
+        }
+        if let Some(Some(data)) = self.rows.borrow().get(&row) {
+            return Some(data.clone());
+        }
+        self.request(row..row + 1);
+        Some(self.placeholder.clone())
+    }
+
+    fn fetch_rows(&self, rows: Range<usize>) {
+        self.request(rows)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_model(
+        row_count: usize,
+        cache_size: usize,
+    ) -> (Rc<LazyModel<i32>>, Rc<RefCell<Vec<Range<usize>>>>) {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let model = LazyModel::new(row_count, -1, {
+            let requests = requests.clone();
+            move |rows| requests.borrow_mut().push(rows)
+        })
+        .with_block_size(10)
+        .with_cache_size(cache_size);
+        (Rc::new(model), requests)
+    }
+
+    fn load(model: &LazyModel<i32>, rows: Range<usize>) {
+        model.set_rows(rows.start, rows.map(|r| r as i32));
+    }
+
+    #[test]
+    fn test_lazy_model_fetch() {
+        let (model, requests) = make_model(45, 100);
+        let checker = adapters::ModelChecker::new(model.clone());
+        assert_eq!(*requests.borrow(), vec![0..10, 10..20, 20..30, 30..40, 40..45]);
+        requests.borrow_mut().clear();
+
+        load(&model, 10..20);
+        assert!(model.is_loaded(15));
+        assert!(!model.is_loaded(5));
+        assert_eq!(model.row_data(15), Some(15));
+        assert_eq!(model.row_data(25), Some(-1));
+        assert_eq!(model.row_data(45), None);
+        // Blocks that are already requested are not fetched again
+        model.fetch_rows(0..45);
+        assert!(requests.borrow().is_empty());
+        checker.check();
+
+        model.set_row_count(55);
+        assert_eq!(model.row_data(52), Some(-1));
+        // The block of the last rows is fetched again to get the new rows
+        assert_eq!(*requests.borrow(), vec![40..50, 50..55]);
+        model.set_row_count(12);
+        assert_eq!(model.row_data(11), Some(11));
+        checker.check();
+
+        model.reset();
+        assert!(!model.is_loaded(11));
+    }
+
+    #[test]
+    fn test_lazy_model_cache() {
+        let (model, requests) = make_model(1000, 40);
+        model.fetch_rows(0..20);
+        load(&model, 0..20);
+        assert_eq!(*requests.borrow(), vec![0..10, 10..20]);
+        requests.borrow_mut().clear();
+
+        // Requesting rows far away discards the first rows
+        model.fetch_rows(500..540);
+        assert_eq!(*requests.borrow(), vec![500..510, 510..520, 520..530, 530..540]);
+        assert!(!model.is_loaded(5));
+        load(&model, 0..20);
+        assert!(!model.is_loaded(5));
+        load(&model, 500..540);
+        assert_eq!(model.row_data(535), Some(535));
+    }
+}
@@ -85,6 +85,23 @@ pub fn row_removed(&self, index: usize, count: usize) {
         }
     }
 
+    /// Notify the peers that `count` rows starting at `old_index` were moved, so that they now
+    /// start at `new_index`. The views keep the state of the moved rows, for example the
+    /// instances created by a `for` loop.
+    pub fn row_moved(&self, old_index: usize, new_index: usize, count: usize) {
+        if old_index == new_index || count == 0 {
+            return;
+        }
+        if let Some(inner) = self.inner.get() {
+            inner.tracked_rows.borrow_mut().clear();
+            inner.model_row_data_dirty_property.mark_dirty();
+            inner.as_ref().project_ref().peers.for_each(|p| {
+                // Safety: The peers contain a list of pinned ModelChangedListener
+                unsafe { Pin::new_unchecked(&**p) }.row_moved(old_index, new_index, count)
+            })
+        }
+    }
+
     /// Notify the peer that the model has been changed in some way and
     /// everything needs to be reloaded
     pub fn reset(&self) {
@@ -128,6 +145,11 @@ pub trait ModelChangeListener {
     fn row_changed(self: Pin<&Self>, row: usize);
     fn row_added(self: Pin<&Self>, index: usize, count: usize);
     fn row_removed(self: Pin<&Self>, index: usize, count: usize);
+    /// The default implementation reports the move as a removal followed by an insertion.
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        self.row_removed(old_index, count);
+        self.row_added(new_index, count);
+    }
     fn reset(self: Pin<&Self>);
 }
 
@@ -0,0 +1,391 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`SelectionModel`], which keeps the selected rows of a model.
+
+use super::*;
+
+/// How many rows of a [`SelectionModel`] can be selected
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SelectionMode {
+    /// At most one row is selected. Selecting a row deselects the other one.
+    #[default]
+    Single,
+    /// Any number of rows can be selected
+    Multi,
+}
+
+struct SelectionModelInner<M: Model + 'static> {
+    source_model: M,
+    mode: Cell<SelectionMode>,
+    /// Whether each row of the source model is selected
+    selected: RefCell<Vec<bool>>,
+    current_row: Cell<Option<usize>>,
+    selection_changed: RefCell<Option<Box<dyn Fn()>>>,
+    notify: ModelNotify,
+}
+
+impl<M: Model + 'static> SelectionModelInner<M> {
+    fn emit_selection_changed(&self) {
+        if let Some(callback) = self.selection_changed.borrow().as_ref() {
+            callback();
+        }
+    }
+}
+
+impl<M: Model + 'static> ModelChangeListener for SelectionModelInner<M> {
+    fn row_changed(self: Pin<&Self>, _row: usize) {}
+
+    fn row_added(self: Pin<&Self>, index: usize, count: usize) {
+        {
+            let mut selected = self.selected.borrow_mut();
+            let index = index.min(selected.len());
+            selected.splice(index..index, core::iter::repeat(false).take(count));
+        }
+        if let Some(current) = self.current_row.get().filter(|current| *current >= index) {
+            self.current_row.set(Some(current + count));
+        }
+        self.notify.row_added(index, count);
+    }
+
+    fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
+        let had_selected_rows = {
+            let mut selected = self.selected.borrow_mut();
+            let range = index.min(selected.len())..(index + count).min(selected.len());
+            selected.drain(range).any(|s| s)
+        };
+        let current_changed = match self.current_row.get() {
+            Some(current) if current >= index + count => {
+                self.current_row.set(Some(current - count));
+                false
+            }
+            Some(current) if current >= index => {
+                self.current_row.set(None);
+                true
+            }
+            _ => false,
+        };
+        self.notify.row_removed(index, count);
+        if had_selected_rows || current_changed {
+            self.emit_selection_changed();
+        }
+    }
+
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        {
+            let mut selected = self.selected.borrow_mut();
+            if old_index < new_index {
+                selected[old_index..new_index + count].rotate_left(count);
+            } else {
+                selected[new_index..old_index + count].rotate_right(count);
+            }
+        }
+        if let Some(current) = self.current_row.get() {
+            let current = if (old_index..old_index + count).contains(&current) {
+                current - old_index + new_index
+            } else if old_index < new_index
+                && (old_index + count..new_index + count).contains(&current)
+            {
+                current - count
+            } else if new_index < old_index && (new_index..old_index).contains(&current) {
+                current + count
+            } else {
+                current
+            };
+            self.current_row.set(Some(current));
+        }
+        self.notify.row_moved(old_index, new_index, count);
+    }
+
+    fn reset(self: Pin<&Self>) {
+        let had_selection = {
+            let mut selected = self.selected.borrow_mut();
+            let had_selected_rows = selected.contains(&true);
+            *selected = vec![false; self.source_model.row_count()];
+            had_selected_rows || self.current_row.take().is_some()
+        };
+        self.notify.reset();
+        if had_selection {
+            self.emit_selection_changed();
+        }
+    }
+}
+
+/// Keeps track of the selected rows, and of the current row, of a model.
+///
+/// The selection can be shared between several views and the application code, instead of
+/// storing a `selected` field in the data of each row. The selection follows the rows when rows
+/// of the source model are added, removed, or moved.
+///
+/// The SelectionModel is itself a [`Model`] with a `bool` for each row of the source model,
+/// `true` when the row is selected. It can be set to a property of type `[bool]`, and changing a
+/// row with `set_row_data` selects or deselects the row.
+///
+/// ## Example
+///
+/// ```
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{Model, SelectionMode, SelectionModel, VecModel};
+/// use std::rc::Rc;
+///
+/// slint::slint! {
+///     export component FileList inherits Window {
+///         in property <[string]> files;
+///         in property <[bool]> selection;
+///         callback toggle(int);
+///         VerticalLayout {
+///             for file[index] in files: TouchArea {
+///                 clicked => { root.toggle(index); }
+///                 Text {
+///                     text: file;
+///                     color: root.selection[index] ? blue : black;
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// let files = Rc::new(VecModel::from(vec![slint::SharedString::from("a.txt"), "b.txt".into()]));
+/// let selection = Rc::new(SelectionModel::new(files.clone()));
+/// selection.set_mode(SelectionMode::Multi);
+///
+/// let ui = FileList::new().unwrap();
+/// ui.set_files(files.clone().into());
+/// ui.set_selection(selection.clone().into());
+/// ui.on_toggle({
+///     let selection = selection.clone();
+///     move |row| selection.toggle(row as usize)
+/// });
+///
+/// selection.select(1);
+/// files.insert(0, "new.txt".into());
+/// assert_eq!(selection.selected_rows(), vec![2]);
+/// ```
+pub struct SelectionModel<M: Model + 'static>(
+    Pin<Box<ModelChangeListenerContainer<SelectionModelInner<M>>>>,
+);
+
+impl<M: Model + 'static> SelectionModel<M> {
+    /// Creates a new SelectionModel for the rows of `source_model`, with nothing selected
+    pub fn new(source_model: M) -> Self {
+        let inner = SelectionModelInner {
+            mode: Cell::new(SelectionMode::default()),
+            selected: RefCell::new(vec![false; source_model.row_count()]),
+            current_row: Cell::new(None),
+            selection_changed: RefCell::new(None),
+            notify: Default::default(),
+            source_model,
+        };
+        let container = Box::pin(ModelChangeListenerContainer::new(inner));
+        container.source_model.model_tracker().attach_peer(container.as_ref().model_peer());
+        Self(container)
+    }
+
+    /// Returns how many rows can be selected
+    pub fn mode(&self) -> SelectionMode {
+        self.0.mode.get()
+    }
+
+    /// Sets how many rows can be selected. When changing to [`SelectionMode::Single`],
+    /// only the first selected row stays selected.
+    pub fn set_mode(&self, mode: SelectionMode) {
+        self.0.mode.set(mode);
+        if mode == SelectionMode::Single {
+            if let Some(first) = self.selected_rows().first() {
+                self.select(*first);
+            }
+        }
+    }
+
+    /// Returns true if the row is selected
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.0.selected.borrow().get(row).copied().unwrap_or_default()
+    }
+
+    /// Returns the selected rows, in increasing order
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.0.selected.borrow().iter().enumerate().filter_map(|(i, s)| s.then_some(i)).collect()
+    }
+
+    /// Selects the row. With [`SelectionMode::Single`], the other rows are deselected.
+    pub fn select(&self, row: usize) {
+        if row >= self.0.selected.borrow().len() {
+            return;
+        }
+        let mut changed_rows = Vec::new();
+        {
+            let mut selected = self.0.selected.borrow_mut();
+            if self.mode() == SelectionMode::Single {
+                for (i, s) in selected.iter_mut().enumerate() {
+                    if *s && i != row {
+                        *s = false;
+                        changed_rows.push(i);
+                    }
+                }
+            }
+            if !selected[row] {
+                selected[row] = true;
+                changed_rows.push(row);
+            }
+        }
+        self.notify_rows_changed(changed_rows);
+    }
+
+    /// Deselects the row
+    pub fn deselect(&self, row: usize) {
+        let was_selected = self
+            .0
+            .selected
+            .borrow_mut()
+            .get_mut(row)
+            .map_or(false, |s| core::mem::replace(s, false));
+        if was_selected {
+            self.notify_rows_changed(vec![row]);
+        }
+    }
+
+    /// Selects the row if it is not selected, and deselects it otherwise
+    pub fn toggle(&self, row: usize) {
+        if self.is_selected(row) {
+            self.deselect(row);
+        } else {
+            self.select(row);
+        }
+    }
+
+    /// Selects all the rows. With [`SelectionMode::Single`], this does nothing.
+    pub fn select_all(&self) {
+        if self.mode() == SelectionMode::Single {
+            return;
+        }
+        let changed_rows = self
+            .0
+            .selected
+            .borrow_mut()
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, s)| (!core::mem::replace(s, true)).then_some(i))
+            .collect();
+        self.notify_rows_changed(changed_rows);
+    }
+
+    /// Deselects all the rows
+    pub fn clear_selection(&self) {
+        let changed_rows = self
+            .0
+            .selected
+            .borrow_mut()
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, s)| core::mem::replace(s, false).then_some(i))
+            .collect();
+        self.notify_rows_changed(changed_rows);
+    }
+
+    /// Returns the current row, for example the row that has the keyboard focus in a view
+    pub fn current_row(&self) -> Option<usize> {
+        self.0.current_row.get()
+    }
+
+    /// Sets the current row. This doesn't change the selected rows.
+    pub fn set_current_row(&self, row: Option<usize>) {
+        let row = row.filter(|row| *row < self.0.selected.borrow().len());
+        if self.0.current_row.replace(row) != row {
+            self.0.emit_selection_changed();
+        }
+    }
+
+    /// Sets a callback that is called when the selected rows or the current row changed,
+    /// including when a selected row was removed from the source model.
+    pub fn on_selection_changed(&self, callback: impl Fn() + 'static) {
+        *self.0.selection_changed.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Returns a reference to the source model
+    pub fn source_model(&self) -> &M {
+        &self.0.as_ref().get().get_ref().source_model
+    }
+
+    fn notify_rows_changed(&self, changed_rows: Vec<usize>) {
+        if changed_rows.is_empty() {
+            return;
+        }
+        for row in changed_rows {
+            self.0.notify.row_changed(row);
+        }
+        self.0.emit_selection_changed();
+    }
+}
+
+impl<M: Model + 'static> Model for SelectionModel<M> {
+    type Data = bool;
+
+    fn row_count(&self) -> usize {
+        self.0.selected.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<bool> {
+        self.0.selected.borrow().get(row).copied()
+    }
+
+    fn set_row_data(&self, row: usize, data: bool) {
+        if data {
+            self.select(row);
+        } else {
+            self.deselect(row);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[test]
+fn test_selection_model() {
+    let source = Rc::new(VecModel::from(vec![10, 20, 30, 40]));
+    let selection = Rc::new(SelectionModel::new(source.clone()));
+    let _checker = adapters::ModelChecker::new(selection.clone());
+    let changes = Rc::new(Cell::new(0));
+    selection.on_selection_changed({
+        let changes = changes.clone();
+        move || changes.set(changes.get() + 1)
+    });
+
+    selection.select(1);
+    selection.select(2);
+    assert_eq!(selection.selected_rows(), vec![2]);
+    assert_eq!(changes.get(), 2);
+
+    selection.set_mode(SelectionMode::Multi);
+    selection.select(0);
+    selection.set_row_data(3, true);
+    assert_eq!(selection.iter().collect::<Vec<_>>(), vec![true, false, true, true]);
+    selection.set_current_row(Some(2));
+    assert_eq!(changes.get(), 5);
+
+    // The selection follows the rows of the source model
+    source.insert(1, 15);
+    assert_eq!(selection.selected_rows(), vec![0, 3, 4]);
+    assert_eq!(selection.current_row(), Some(3));
+    source.move_rows(3, 0, 1);
+    assert_eq!(selection.selected_rows(), vec![0, 1, 4]);
+    assert_eq!(selection.current_row(), Some(0));
+    assert_eq!(changes.get(), 5);
+    source.remove(0);
+    assert_eq!(selection.selected_rows(), vec![0, 3]);
+    assert_eq!(selection.current_row(), None);
+    assert_eq!(changes.get(), 6);
+
+    selection.toggle(0);
+    selection.set_mode(SelectionMode::Single);
+    assert_eq!(selection.selected_rows(), vec![3]);
+    selection.clear_selection();
+    assert_eq!(selection.selected_rows(), Vec::<usize>::new());
+    assert_eq!(changes.get(), 8);
+}
@@ -0,0 +1,175 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`VecModelSender`], to modify a [`VecModel`] from other threads.
+
+use super::*;
+use crate::api::EventLoopError;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+enum Update<T> {
+    Push(T),
+    Clear,
+    Apply(Box<dyn FnOnce(&VecModel<T>) + Send>),
+}
+
+struct Channel<T> {
+    updates: Vec<Update<T>>,
+    /// The waker of the future that applies the updates in the event loop
+    waker: Option<Waker>,
+    senders: usize,
+    /// Set when the model was dropped
+    closed: bool,
+}
+
+/// A handle to add rows to a [`VecModel`], or to modify it, from any thread.
+///
+/// Create it with [`VecModel::sender()`]. The updates are queued and applied in the event loop
+/// in the order they were sent. The rows that are pushed between two iterations of the event loop
+/// are added with a single notification, so that views are updated efficiently even when a
+/// background task produces a lot of rows.
+///
+/// The sender can be cloned and sent to other threads. Updates sent after the model was dropped
+/// are discarded.
+///
+/// ## Example
+///
+/// ```rust
+/// # i_slint_backend_testing::init_integration_test_with_mock_time();
+/// use slint::VecModel;
+/// use std::rc::Rc;
+///
+/// let model = Rc::new(VecModel::<slint::SharedString>::default());
+/// let sender = model.sender().unwrap();
+/// std::thread::spawn(move || {
+///     for i in 0..1000 {
+///         sender.push(slint::format!("line {i}"));
+///     }
+/// });
+/// # return; // don't run the event loop in examples
+/// slint::run_event_loop().unwrap();
+/// ```
+pub struct VecModelSender<T>(Arc<Mutex<Channel<T>>>);
+
+impl<T> VecModelSender<T> {
+    fn send(&self, update: Update<T>) {
+        let mut channel = self.0.lock().unwrap();
+        if channel.closed {
+            return;
+        }
+        channel.updates.push(update);
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Appends a row at the end of the model
+    pub fn push(&self, value: T) {
+        self.send(Update::Push(value))
+    }
+
+    /// Appends the rows at the end of the model
+    pub fn extend(&self, iter: impl IntoIterator<Item = T>) {
+        let mut channel = self.0.lock().unwrap();
+        if channel.closed {
+            return;
+        }
+        channel.updates.extend(iter.into_iter().map(Update::Push));
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Removes all the rows of the model
+    pub fn clear(&self) {
+        self.send(Update::Clear)
+    }
+
+    /// Calls `f` with the model in the event loop, to modify it in other ways
+    pub fn apply(&self, f: impl FnOnce(&VecModel<T>) + Send + 'static) {
+        self.send(Update::Apply(Box::new(f)))
+    }
+
+    /// Returns true if the model was dropped, so updates are discarded
+    pub fn is_closed(&self) -> bool {
+        self.0.lock().unwrap().closed
+    }
+}
+
+impl<T> Clone for VecModelSender<T> {
+    fn clone(&self) -> Self {
+        self.0.lock().unwrap().senders += 1;
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Drop for VecModelSender<T> {
+    fn drop(&mut self) {
+        let mut channel = self.0.lock().unwrap();
+        channel.senders -= 1;
+        if channel.senders == 0 {
+            if let Some(waker) = channel.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T: Send + 'static> VecModel<T> {
+    /// Returns a [`VecModelSender`] that can add rows to this model, or modify it, from any thread.
+    ///
+    /// This must be called from the thread that runs the event loop, after the platform was
+    /// initialized, for example after creating a component. Otherwise it returns
+    /// [`EventLoopError::NoEventLoopProvider`].
+    pub fn sender(self: &Rc<Self>) -> Result<VecModelSender<T>, EventLoopError> {
+        let channel = Arc::new(Mutex::new(Channel {
+            updates: Vec::new(),
+            waker: None,
+            senders: 1,
+            closed: false,
+        }));
+        let model = Rc::downgrade(self);
+        let receiver = channel.clone();
+        let fut = core::future::poll_fn(move |cx| {
+            let (updates, finished) = {
+                let mut channel = receiver.lock().unwrap();
+                channel.waker = Some(cx.waker().clone());
+                (core::mem::take(&mut channel.updates), channel.senders == 0)
+            };
+            let Some(model) = model.upgrade() else {
+                receiver.lock().unwrap().closed = true;
+                return Poll::Ready(());
+            };
+            // Consecutive rows are added together, with a single notification
+            let mut rows = Vec::new();
+            let flush = |rows: &mut Vec<T>| {
+                if !rows.is_empty() {
+                    model.extend(core::mem::take(rows));
+                }
+            };
+            for update in updates {
+                match update {
+                    Update::Push(value) => rows.push(value),
+                    Update::Clear => {
+                        rows.clear();
+                        model.clear();
+                    }
+                    Update::Apply(f) => {
+                        flush(&mut rows);
+                        f(&model);
+                    }
+                }
+            }
+            flush(&mut rows);
+            if finished {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        });
+        crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().ok_or(EventLoopError::NoEventLoopProvider)?.spawn_local(fut))?;
+        Ok(VecModelSender(channel))
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`StreamModel`], a model that shows the items of a `Stream`.
+
+use super::*;
+use crate::api::EventLoopError;
+use crate::future::JoinHandle;
+use core::task::Poll;
+use futures_core::Stream;
+
+struct StreamModelInner<T> {
+    rows: VecModel<T>,
+    max_rows: Cell<Option<usize>>,
+    /// The number of rows removed by `trim()` whose keys were not removed yet
+    trimmed: Cell<usize>,
+    finished: Cell<bool>,
+}
+
+impl<T: Clone + 'static> StreamModelInner<T> {
+    /// Removes the oldest rows if there are more than the maximum
+    fn trim(&self) {
+        let excess = self.max_rows.get().map_or(0, |max| self.rows.row_count().saturating_sub(max));
+        if excess > 0 {
+            self.rows.splice(0..excess, core::iter::empty());
+            self.trimmed.set(self.trimmed.get() + excess);
+        }
+    }
+}
+
+/// A [`Model`] that shows the items of a [`Stream`](futures_core::Stream) as they arrive.
+///
+/// The stream is polled in the event loop, and the items that are ready together are added with
+/// a single notification. The model can be created in two ways:
+///  - [`StreamModel::new()`] appends each item of the stream as a new row, for example the lines of a log.
+///  - [`StreamModel::new_keyed()`] takes a stream of `(key, row)` pairs: an item replaces the row that
+///    was added with the same key, or is appended if the key is new, for example the last value of
+///    each sensor of a dashboard.
+///
+/// With [`Self::set_max_rows()`], the oldest rows are removed when there are too many. The stream is
+/// dropped when the model is dropped.
+///
+/// This type is only available with the `futures` feature.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// # fn log_lines() -> impl futures_core::Stream<Item = slint::SharedString> { unimplemented!() }
+/// slint::slint! { export component LogView inherits Window { in property <[string]> lines; } }
+/// let ui = LogView::new().unwrap();
+/// let model = std::rc::Rc::new(slint::StreamModel::new(log_lines()).unwrap());
+/// model.set_max_rows(Some(10_000));
+/// ui.set_lines(model.into());
+/// ui.run().unwrap();
+/// ```
+pub struct StreamModel<T: 'static> {
+    inner: Rc<StreamModelInner<T>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl<T: Clone + 'static> StreamModel<T> {
+    /// Creates a model that appends each item of the stream as a new row.
+    ///
+    /// This must be called from the thread that runs the event loop, after the platform was
+    /// initialized. Otherwise it returns [`EventLoopError::NoEventLoopProvider`].
+    pub fn new(stream: impl Stream<Item = T> + 'static) -> Result<Self, EventLoopError> {
+        Self::spawn(stream, |row| (None::<()>, row))
+    }
+
+    /// Creates a model from a stream of `(key, row)` pairs. Each item replaces the row with the
+    /// same key, or is appended if there is no row with that key.
+    ///
+    /// This must be called from the thread that runs the event loop, after the platform was
+    /// initialized. Otherwise it returns [`EventLoopError::NoEventLoopProvider`].
+    pub fn new_keyed<K: PartialEq + 'static>(
+        stream: impl Stream<Item = (K, T)> + 'static,
+    ) -> Result<Self, EventLoopError> {
+        Self::spawn(stream, |(key, row)| (Some(key), row))
+    }
+
+    fn spawn<S: Stream + 'static, K: PartialEq + 'static>(
+        stream: S,
+        split: impl Fn(S::Item) -> (Option<K>, T) + 'static,
+    ) -> Result<Self, EventLoopError> {
+        let inner = Rc::new(StreamModelInner {
+            rows: VecModel::default(),
+            max_rows: Cell::new(None),
+            trimmed: Cell::new(0),
+            finished: Cell::new(false),
+        });
+        let weak = Rc::downgrade(&inner);
+        let mut stream = Box::pin(stream);
+        // The key of each row, for keyed streams
+        let mut keys = Vec::<K>::new();
+        let fut = core::future::poll_fn(move |cx| {
+            let Some(inner) = weak.upgrade() else { return Poll::Ready(()) };
+            let drain_keys = |keys: &mut Vec<K>| {
+                keys.drain(..inner.trimmed.take().min(keys.len()));
+            };
+            drain_keys(&mut keys);
+            let mut new_rows = Vec::new();
+            let result = loop {
+                match stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => match split(item) {
+                        (Some(key), row) => match keys.iter().position(|k| *k == key) {
+                            Some(pos) => match pos.checked_sub(inner.rows.row_count()) {
+                                Some(new_pos) => new_rows[new_pos] = row,
+                                None => inner.rows.set_row_data(pos, row),
+                            },
+                            None => {
+                                keys.push(key);
+                                new_rows.push(row);
+                            }
+                        },
+                        (None, row) => new_rows.push(row),
+                    },
+                    Poll::Ready(None) => {
+                        inner.finished.set(true);
+                        break Poll::Ready(());
+                    }
+                    Poll::Pending => break Poll::Pending,
+                }
+            };
+            if !new_rows.is_empty() {
+                inner.rows.extend(new_rows);
+            }
+            inner.trim();
+            drain_keys(&mut keys);
+            result
+        });
+        let task = crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().ok_or(EventLoopError::NoEventLoopProvider)?.spawn_local(fut))?;
+        Ok(Self { inner, task: Some(task) })
+    }
+
+    /// Sets the maximum number of rows. When there are more rows, the oldest ones are removed.
+    /// `None` means that the number of rows is not limited, which is the default.
+    pub fn set_max_rows(&self, max_rows: Option<usize>) {
+        self.inner.max_rows.set(max_rows);
+        self.inner.trim();
+    }
+
+    /// Returns true if the stream ended
+    pub fn is_finished(&self) -> bool {
+        self.inner.finished.get()
+    }
+}
+
+impl<T: 'static> Drop for StreamModel<T> {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl<T: Clone + 'static> Model for StreamModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.inner.rows.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.inner.rows.row_data(row)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.inner.rows.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
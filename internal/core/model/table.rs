@@ -0,0 +1,374 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`TableModel`] trait and the [`TableViewModel`] adapter that shows
+//! a table in a `StandardTableView`.
+
+use super::*;
+use crate::items::SortOrder;
+use crate::SharedString;
+use core::cmp::Ordering;
+
+/// How the rows of a [`TableModel`] are compared when they are sorted by a column
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortRole {
+    /// The cells are compared by their text
+    #[default]
+    Text,
+    /// The text of the cells is parsed as a number. The cells that are not numbers are sorted
+    /// after the numbers.
+    Numeric,
+    /// The column can't be sorted
+    None,
+}
+
+/// A TableModel provides data organized in rows and columns, with a title for each column.
+///
+/// Rows are added, removed, and changed with the notifications of a [`ModelNotify`], like for a
+/// [`Model`]. Use a [`TableViewModel`] to show the table in a `StandardTableView`.
+pub trait TableModel {
+    /// The number of rows of the table
+    fn row_count(&self) -> usize;
+
+    /// The number of columns of the table
+    fn column_count(&self) -> usize;
+
+    /// The title of the column, shown in its header
+    fn column_title(&self, column: usize) -> SharedString;
+
+    /// Returns how the rows are compared when they are sorted by `column`.
+    ///
+    /// The default implementation returns [`SortRole::Text`].
+    fn sort_role(&self, _column: usize) -> SortRole {
+        SortRole::Text
+    }
+
+    /// Returns the data of a cell, or `None` if the row or the column is out of range.
+    fn cell_data(&self, row: usize, column: usize) -> Option<StandardListViewItem>;
+
+    /// The implementation should return a reference to its [`ModelNotify`] field.
+    ///
+    /// You can return `&()` if your table is constant and does not have a ModelNotify field.
+    fn model_tracker(&self) -> &dyn ModelTracker;
+}
+
+impl<M: TableModel> TableModel for Rc<M> {
+    fn row_count(&self) -> usize {
+        (**self).row_count()
+    }
+
+    fn column_count(&self) -> usize {
+        (**self).column_count()
+    }
+
+    fn column_title(&self, column: usize) -> SharedString {
+        (**self).column_title(column)
+    }
+
+    fn sort_role(&self, column: usize) -> SortRole {
+        (**self).sort_role(column)
+    }
+
+    fn cell_data(&self, row: usize, column: usize) -> Option<StandardListViewItem> {
+        (**self).cell_data(row, column)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        (**self).model_tracker()
+    }
+}
+
+struct TableViewModelInner<M> {
+    table: M,
+    /// The column and the direction (true for ascending) of the sort
+    sort: Cell<Option<(usize, bool)>>,
+    /// The row of the table for each row, when sorted
+    mapping: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<M: TableModel> TableViewModelInner<M> {
+    fn source_row(&self, row: usize) -> Option<usize> {
+        match self.sort.get() {
+            Some(_) => self.mapping.borrow().get(row).copied(),
+            None => (row < self.table.row_count()).then_some(row),
+        }
+    }
+
+    fn update_mapping(&self) {
+        let Some((column, ascending)) = self.sort.get() else { return };
+        let text = |row| self.table.cell_data(row, column).unwrap_or_default().text;
+        let mut keys = (0..self.table.row_count()).map(|row| (text(row), row)).collect::<Vec<_>>();
+        let role = self.table.sort_role(column);
+        keys.sort_by(|(a, _), (b, _)| {
+            let ordering = compare_cells(role, a, b);
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        *self.mapping.borrow_mut() = keys.into_iter().map(|(_, row)| row).collect();
+    }
+}
+
+fn compare_cells(role: SortRole, a: &str, b: &str) -> Ordering {
+    match role {
+        SortRole::Text => a.cmp(b),
+        SortRole::Numeric => match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => a.cmp(b),
+        },
+        SortRole::None => Ordering::Equal,
+    }
+}
+
+impl<M: TableModel> ModelChangeListener for TableViewModelInner<M> {
+    fn row_changed(self: Pin<&Self>, row: usize) {
+        if self.sort.get().is_some() {
+            self.reset();
+        } else {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(self: Pin<&Self>, index: usize, count: usize) {
+        if self.sort.get().is_some() {
+            self.reset();
+        } else {
+            self.notify.row_added(index, count);
+        }
+    }
+
+    fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
+        if self.sort.get().is_some() {
+            self.reset();
+        } else {
+            self.notify.row_removed(index, count);
+        }
+    }
+
+    fn row_moved(self: Pin<&Self>, old_index: usize, new_index: usize, count: usize) {
+        if self.sort.get().is_some() {
+            self.reset();
+        } else {
+            self.notify.row_moved(old_index, new_index, count);
+        }
+    }
+
+    fn reset(self: Pin<&Self>) {
+        self.update_mapping();
+        self.notify.reset()
+    }
+}
+
+/// Provides the rows of a [`TableModel`] to the `rows` property of a `StandardTableView`, sorted
+/// by a column.
+///
+/// Each row is a model with the data of the cells of the row. Pass [`Self::columns()`] to the
+/// `columns` property of the table view, and call [`Self::sort_by_column()`] from the
+/// `sort-ascending` and `sort-descending` callbacks. The rows are compared according to the
+/// [`SortRole`] of the column. When the table changes while it's sorted, the rows are sorted again.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{Model, SharedString, StandardListViewItem, TableModel, TableViewModel, ModelTracker};
+/// struct Planets;
+/// const PLANETS: [(&str, &str); 3] = [("Mercury", "0.39"), ("Venus", "0.72"), ("Earth", "1")];
+/// impl TableModel for Planets {
+///     fn row_count(&self) -> usize { PLANETS.len() }
+///     fn column_count(&self) -> usize { 2 }
+///     fn column_title(&self, column: usize) -> SharedString {
+///         ["Name", "Distance"][column].into()
+///     }
+///     fn sort_role(&self, column: usize) -> slint::SortRole {
+///         if column == 1 { slint::SortRole::Numeric } else { slint::SortRole::Text }
+///     }
+///     fn cell_data(&self, row: usize, column: usize) -> Option<StandardListViewItem> {
+///         let planet = PLANETS.get(row)?;
+///         Some([planet.0, planet.1].get(column)?.to_owned().into())
+///     }
+///     fn model_tracker(&self) -> &dyn ModelTracker { &() }
+/// }
+///
+/// let model = std::rc::Rc::new(TableViewModel::new(Planets));
+/// model.sort_by_column(1, false);
+/// let first_row = model.row_data(0).unwrap();
+/// assert_eq!(first_row.row_data(0).unwrap().text, "Earth");
+/// // With a `StandardTableView` in the UI:
+/// // ui.set_columns(model.columns());
+/// // ui.set_rows(model.clone().into());
+/// // ui.on_sort_ascending({ let model = model.clone(); move |column| model.sort_by_column(column as usize, true) });
+/// ```
+pub struct TableViewModel<M: TableModel + 'static>(
+    Pin<Box<ModelChangeListenerContainer<TableViewModelInner<M>>>>,
+);
+
+impl<M: TableModel + 'static> TableViewModel<M> {
+    /// Creates a new TableViewModel that shows the rows of `table` in their order
+    pub fn new(table: M) -> Self {
+        let inner = TableViewModelInner {
+            table,
+            sort: Cell::new(None),
+            mapping: Default::default(),
+            notify: Default::default(),
+        };
+        let container = Box::pin(ModelChangeListenerContainer::new(inner));
+        container.table.model_tracker().attach_peer(container.as_ref().model_peer());
+        Self(container)
+    }
+
+    /// Returns a model with the columns of the table, for the `columns` property of a
+    /// `StandardTableView`.
+    pub fn columns(&self) -> ModelRc<TableColumn> {
+        let table = &self.0.table;
+        let sort = self.0.sort.get();
+        let columns = (0..table.column_count())
+            .map(|column| {
+                let mut c = TableColumn::default();
+                c.title = table.column_title(column);
+                c.sort_order = match sort {
+                    Some((col, true)) if col == column => SortOrder::Ascending,
+                    Some((col, false)) if col == column => SortOrder::Descending,
+                    _ => SortOrder::Unsorted,
+                };
+                c
+            })
+            .collect::<Vec<_>>();
+        ModelRc::new(VecModel::from(columns))
+    }
+
+    /// Sorts the rows by `column`, in ascending or descending order. Nothing happens if the
+    /// [`SortRole`] of the column is [`SortRole::None`].
+    pub fn sort_by_column(&self, column: usize, ascending: bool) {
+        if column >= self.0.table.column_count() || self.0.table.sort_role(column) == SortRole::None
+        {
+            return;
+        }
+        self.0.sort.set(Some((column, ascending)));
+        self.0.as_ref().get().reset();
+    }
+
+    /// Shows the rows in the order of the table
+    pub fn clear_sort(&self) {
+        if self.0.sort.take().is_some() {
+            self.0.mapping.borrow_mut().clear();
+            self.0.notify.reset();
+        }
+    }
+
+    /// Returns the column and the direction of the sort (true for ascending), if the rows are sorted
+    pub fn sort_column(&self) -> Option<(usize, bool)> {
+        self.0.sort.get()
+    }
+
+    /// Returns the row of the table that is shown at `row`
+    pub fn source_row(&self, row: usize) -> Option<usize> {
+        self.0.source_row(row)
+    }
+
+    /// Returns a reference to the table
+    pub fn source_model(&self) -> &M {
+        &self.0.as_ref().get().get_ref().table
+    }
+}
+
+impl<M: TableModel + 'static> Model for TableViewModel<M> {
+    type Data = ModelRc<StandardListViewItem>;
+
+    fn row_count(&self) -> usize {
+        self.0.table.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let source_row = self.0.source_row(row)?;
+        let table = &self.0.table;
+        let cells = (0..table.column_count())
+            .map(|column| table.cell_data(source_row, column).unwrap_or_default())
+            .collect::<Vec<_>>();
+        Some(ModelRc::new(VecModel::from(cells)))
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestTable {
+        rows: RefCell<Vec<[&'static str; 2]>>,
+        notify: ModelNotify,
+    }
+
+    impl TableModel for TestTable {
+        fn row_count(&self) -> usize {
+            self.rows.borrow().len()
+        }
+
+        fn column_count(&self) -> usize {
+            2
+        }
+
+        fn column_title(&self, column: usize) -> SharedString {
+            ["Name", "Size"][column].into()
+        }
+
+        fn sort_role(&self, column: usize) -> SortRole {
+            [SortRole::Text, SortRole::Numeric][column]
+        }
+
+        fn cell_data(&self, row: usize, column: usize) -> Option<StandardListViewItem> {
+            Some(StandardListViewItem::from(*self.rows.borrow().get(row)?.get(column)?))
+        }
+
+        fn model_tracker(&self) -> &dyn ModelTracker {
+            &self.notify
+        }
+    }
+
+    fn names(model: &TableViewModel<Rc<TestTable>>) -> Vec<SharedString> {
+        model.iter().map(|row| row.row_data(0).unwrap().text).collect()
+    }
+
+    #[test]
+    fn test_table_view_model_sort() {
+        let table = Rc::new(TestTable::default());
+        *table.rows.borrow_mut() = vec![["b", "10"], ["c", "9"], ["a", "n/a"]];
+        let model = TableViewModel::new(table.clone());
+        assert_eq!(names(&model), ["b", "c", "a"]);
+        assert_eq!(model.columns().row_data(1).unwrap().title, "Size");
+
+        model.sort_by_column(0, true);
+        assert_eq!(names(&model), ["a", "b", "c"]);
+        assert_eq!(model.source_row(0), Some(2));
+        assert_eq!(model.columns().row_data(0).unwrap().sort_order, SortOrder::Ascending);
+
+        // Numbers are not compared as text, and cells that are not numbers come last
+        model.sort_by_column(1, true);
+        assert_eq!(names(&model), ["c", "b", "a"]);
+        model.sort_by_column(1, false);
+        assert_eq!(names(&model), ["a", "b", "c"]);
+        assert_eq!(model.sort_column(), Some((1, false)));
+
+        // The rows are sorted again when the table changes
+        table.rows.borrow_mut().push(["d", "100"]);
+        table.notify.row_added(3, 1);
+        assert_eq!(names(&model), ["a", "d", "b", "c"]);
+
+        model.clear_sort();
+        assert_eq!(names(&model), ["b", "c", "a", "d"]);
+        assert_eq!(model.source_row(4), None);
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`TreeModel`] trait and the [`FlattenedTreeModel`] adapter
+//! that shows the expanded nodes of a tree with a `for` loop.
+
+use super::*;
+use alloc::rc::Weak;
+use alloc::vec;
+
+/// A TreeModel provides data organized as a tree of nodes.
+///
+/// A node is identified by its index path: the row of each of its ancestors among their siblings,
+/// starting with the top-level node, followed by its own row. The empty path `[]` identifies the
+/// invisible root whose children are the top-level nodes.
+///
+/// Use a [`FlattenedTreeModel`] to show the tree in a `for` loop, for example to build a tree view
+/// where nodes can be expanded and collapsed.
+pub trait TreeModel {
+    /// The model data of a node of the tree
+    type Data;
+
+    /// Returns the number of children of the node at `parent`.
+    fn row_count(&self, parent: &[usize]) -> usize;
+
+    /// Returns the data of the node at `index`, or `None` if there is no such node.
+    fn row_data(&self, index: &[usize]) -> Option<Self::Data>;
+
+    /// Returns true if the node at `index` has children.
+    ///
+    /// The default implementation calls [`Self::row_count()`]. Implement this function if
+    /// counting the children is expensive, for example if they are loaded lazily when the
+    /// node is expanded.
+    fn has_children(&self, index: &[usize]) -> bool {
+        self.row_count(index) > 0
+    }
+
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// You can return `&()` if your tree model is constant and does not have a TreeModelNotify field.
+    fn model_tracker(&self) -> &dyn TreeModelTracker;
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn row_count(&self, parent: &[usize]) -> usize {
+        (**self).row_count(parent)
+    }
+
+    fn row_data(&self, index: &[usize]) -> Option<Self::Data> {
+        (**self).row_data(index)
+    }
+
+    fn has_children(&self, index: &[usize]) -> bool {
+        (**self).has_children(index)
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).model_tracker()
+    }
+}
+
+/// This trait is implemented by [`TreeModelNotify`], to attach the views that listen
+/// to the changes of a [`TreeModel`].
+pub trait TreeModelTracker {
+    /// Attach one listener. It will be notified when the tree changes, as long as it's alive.
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>);
+}
+
+impl TreeModelTracker for () {
+    fn attach_listener(&self, _listener: Weak<dyn TreeModelChangeListener>) {}
+}
+
+/// The notifications sent by a [`TreeModel`] through its [`TreeModelNotify`].
+pub trait TreeModelChangeListener {
+    /// The data of the node at `index` changed
+    fn row_changed(&self, index: &[usize]);
+    /// `count` children were inserted in the node at `parent`, starting at row `index`
+    fn row_added(&self, parent: &[usize], index: usize, count: usize);
+    /// `count` children were removed from the node at `parent`, starting at row `index`
+    fn row_removed(&self, parent: &[usize], index: usize, count: usize);
+    /// The whole tree changed
+    fn reset(&self);
+}
+
+/// Dispatch notifications from a [`TreeModel`] to the views that show it.
+/// Typically, you would put this in the implementation of the TreeModel.
+#[derive(Default)]
+pub struct TreeModelNotify {
+    listeners: RefCell<Vec<Weak<dyn TreeModelChangeListener>>>,
+}
+
+impl TreeModelNotify {
+    fn for_each_listener(&self, f: impl Fn(&dyn TreeModelChangeListener)) {
+        let listeners = {
+            let mut listeners = self.listeners.borrow_mut();
+            listeners.retain(|l| l.strong_count() > 0);
+            listeners.iter().filter_map(|l| l.upgrade()).collect::<Vec<_>>()
+        };
+        for listener in listeners {
+            f(&*listener);
+        }
+    }
+
+    /// Notify the views that the data of the node at `index` was changed
+    pub fn row_changed(&self, index: &[usize]) {
+        self.for_each_listener(|l| l.row_changed(index))
+    }
+
+    /// Notify the views that `count` children were inserted in the node at `parent`,
+    /// starting at row `index`
+    pub fn row_added(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.row_added(parent, index, count))
+    }
+
+    /// Notify the views that `count` children were removed from the node at `parent`,
+    /// starting at row `index`
+    pub fn row_removed(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.row_removed(parent, index, count))
+    }
+
+    /// Notify the views that the tree has been changed in some way and
+    /// everything needs to be reloaded
+    pub fn reset(&self) {
+        self.for_each_listener(|l| l.reset())
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>) {
+        self.listeners.borrow_mut().push(listener);
+    }
+}
+
+/// A row of a [`FlattenedTreeModel`]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TreeRow<T> {
+    /// The number of ancestors of the node: 0 for the top-level nodes
+    pub depth: usize,
+    /// True if the children of the node are shown
+    pub expanded: bool,
+    /// True if the node has children
+    pub has_children: bool,
+    /// The data of the node in the tree model
+    pub data: T,
+}
+
+struct FlattenedTreeModelInner<M> {
+    tree: M,
+    /// The index path in the tree of each row, and whether the row is expanded
+    rows: RefCell<Vec<(Vec<usize>, bool)>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeModelInner<M> {
+    fn top_level_rows(&self) -> Vec<(Vec<usize>, bool)> {
+        (0..self.tree.row_count(&[])).map(|i| (vec![i], false)).collect()
+    }
+
+    /// Returns the row of the node at `index`, if it's visible
+    fn row_of(&self, index: &[usize]) -> Option<usize> {
+        self.rows.borrow().iter().position(|(i, _)| i == index)
+    }
+
+    /// Returns true if the children of `parent` are visible
+    fn shows_children(&self, parent: &[usize]) -> bool {
+        parent.is_empty() || self.rows.borrow().iter().any(|(i, e)| i == parent && *e)
+    }
+}
+
+fn is_descendant(index: &[usize], parent: &[usize]) -> bool {
+    index.len() > parent.len() && index.starts_with(parent)
+}
+
+impl<M: TreeModel> TreeModelChangeListener for FlattenedTreeModelInner<M> {
+    fn row_changed(&self, index: &[usize]) {
+        if let Some(row) = self.row_of(index) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(&self, parent: &[usize], index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let depth = parent.len();
+        let shows_children = self.shows_children(parent);
+        let mut rows = self.rows.borrow_mut();
+        for (i, _) in rows.iter_mut() {
+            if is_descendant(i, parent) && i[depth] >= index {
+                i[depth] += count;
+            }
+        }
+        let parent_row = rows.iter().position(|(i, _)| i == parent);
+        let added = if shows_children {
+            let mut row = parent_row.map_or(0, |r| r + 1);
+            while row < rows.len()
+                && is_descendant(&rows[row].0, parent)
+                && rows[row].0[depth] < index
+            {
+                row += 1;
+            }
+            rows.splice(
+                row..row,
+                (index..index + count).map(|i| ([parent, &[i][..]].concat(), false)),
+            );
+            Some(row)
+        } else {
+            None
+        };
+        drop(rows);
+        if let Some(row) = added {
+            self.notify.row_added(row, count);
+        }
+        if let Some(parent_row) = parent_row {
+            // has_children might have changed
+            self.notify.row_changed(parent_row);
+        }
+    }
+
+    fn row_removed(&self, parent: &[usize], index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let depth = parent.len();
+        let mut rows = self.rows.borrow_mut();
+        let is_removed =
+            |i: &[usize]| is_descendant(i, parent) && (index..index + count).contains(&i[depth]);
+        let start = rows.iter().position(|(i, _)| is_removed(i));
+        let removed = start.map_or(0, |start| {
+            let len = rows[start..].iter().take_while(|(i, _)| is_removed(i)).count();
+            rows.drain(start..start + len);
+            len
+        });
+        for (i, _) in rows.iter_mut() {
+            if is_descendant(i, parent) && i[depth] >= index + count {
+                i[depth] -= count;
+            }
+        }
+        let parent_row = rows.iter().position(|(i, _)| i == parent);
+        drop(rows);
+        if let (Some(start), true) = (start, removed > 0) {
+            self.notify.row_removed(start, removed);
+        }
+        if let Some(parent_row) = parent_row {
+            // has_children might have changed
+            self.notify.row_changed(parent_row);
+        }
+    }
+
+    fn reset(&self) {
+        *self.rows.borrow_mut() = self.top_level_rows();
+        self.notify.reset();
+    }
+}
+
+/// Provides the visible nodes of a [`TreeModel`] as the rows of a [`Model`], so that they
+/// can be shown with a `for` loop.
+///
+/// Initially, only the top-level nodes are visible. Call [`Self::set_expanded()`] to show or hide
+/// the children of a node. The children are only queried from the tree model when their parent is
+/// expanded, so they can be loaded lazily.
+///
+/// Each row is a [`TreeRow`] with the depth of the node, whether it's expanded, and its data. Use
+/// [`ModelExt::map`] to convert it into a struct declared in the `.slint` file. When nodes are
+/// expanded, collapsed, or when the tree model changes, the `FlattenedTreeModel` notifies the rows
+/// that were inserted or removed, so the other rows keep their state.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{Model, TreeModel, TreeModelTracker, FlattenedTreeModel};
+/// struct Files;
+/// impl TreeModel for Files {
+///     type Data = String;
+///     fn row_count(&self, parent: &[usize]) -> usize {
+///         match parent {
+///             [] => 2,
+///             [0] => 3,
+///             _ => 0,
+///         }
+///     }
+///     fn row_data(&self, index: &[usize]) -> Option<String> {
+///         Some(format!("file {index:?}"))
+///     }
+///     fn model_tracker(&self) -> &dyn TreeModelTracker {
+///         &()
+///     }
+/// }
+///
+/// let model = FlattenedTreeModel::new(Files);
+/// assert_eq!(model.row_count(), 2);
+/// model.set_expanded(0, true);
+/// assert_eq!(model.row_count(), 5);
+/// let row = model.row_data(1).unwrap();
+/// assert_eq!((row.depth, row.data), (1, "file [0, 0]".to_string()));
+/// ```
+pub struct FlattenedTreeModel<M>(Rc<FlattenedTreeModelInner<M>>);
+
+impl<M: TreeModel + 'static> FlattenedTreeModel<M> {
+    /// Creates a new FlattenedTreeModel that shows the top-level nodes of `tree`
+    pub fn new(tree: M) -> Self {
+        let inner = Rc::new(FlattenedTreeModelInner {
+            tree,
+            rows: Default::default(),
+            notify: Default::default(),
+        });
+        *inner.rows.borrow_mut() = inner.top_level_rows();
+        let listener: Rc<dyn TreeModelChangeListener> = inner.clone();
+        inner.tree.model_tracker().attach_listener(Rc::downgrade(&listener));
+        Self(inner)
+    }
+
+    /// Shows or hides the children of the node at `row`
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        let mut rows = self.0.rows.borrow_mut();
+        let Some((index, is_expanded)) = rows.get_mut(row) else { return };
+        if *is_expanded == expanded {
+            return;
+        }
+        *is_expanded = expanded;
+        let index = index.clone();
+        let (added, removed) = if expanded {
+            let count = self.0.tree.row_count(&index);
+            rows.splice(
+                row + 1..row + 1,
+                (0..count).map(|i| ([index.as_slice(), &[i][..]].concat(), false)),
+            );
+            (count, 0)
+        } else {
+            let count =
+                rows[row + 1..].iter().take_while(|(i, _)| is_descendant(i, &index)).count();
+            rows.drain(row + 1..row + 1 + count);
+            (0, count)
+        };
+        drop(rows);
+        self.0.notify.row_changed(row);
+        if added > 0 {
+            self.0.notify.row_added(row + 1, added);
+        }
+        if removed > 0 {
+            self.0.notify.row_removed(row + 1, removed);
+        }
+    }
+
+    /// Returns true if the children of the node at `row` are shown
+    pub fn is_expanded(&self, row: usize) -> bool {
+        self.0.rows.borrow().get(row).is_some_and(|(_, expanded)| *expanded)
+    }
+
+    /// Returns the index path in the tree model of the node at `row`
+    pub fn tree_index(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|(index, _)| index.clone())
+    }
+
+    /// Returns the row of the node at the index path `index` in the tree model,
+    /// or `None` if the node is not visible
+    pub fn row_of(&self, index: &[usize]) -> Option<usize> {
+        self.0.row_of(index)
+    }
+
+    /// Returns a reference to the tree model
+    pub fn source_model(&self) -> &M {
+        &self.0.tree
+    }
+}
+
+impl<M: TreeModel + 'static> Model for FlattenedTreeModel<M> {
+    type Data = TreeRow<M::Data>;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let (index, expanded) = self.0.rows.borrow().get(row)?.clone();
+        Some(TreeRow {
+            depth: index.len() - 1,
+            expanded,
+            has_children: self.0.tree.has_children(&index),
+            data: self.0.tree.row_data(&index)?,
+        })
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A tree of strings where the children are stored in a `Vec`
+    #[derive(Default)]
+    struct TestTree {
+        nodes: RefCell<Vec<(&'static str, Vec<&'static str>)>>,
+        notify: TreeModelNotify,
+    }
+
+    impl TreeModel for TestTree {
+        type Data = &'static str;
+
+        fn row_count(&self, parent: &[usize]) -> usize {
+            match parent {
+                [] => self.nodes.borrow().len(),
+                [i] => self.nodes.borrow()[*i].1.len(),
+                _ => 0,
+            }
+        }
+
+        fn row_data(&self, index: &[usize]) -> Option<Self::Data> {
+            match index {
+                [i] => self.nodes.borrow().get(*i).map(|n| n.0),
+                [i, j] => self.nodes.borrow().get(*i)?.1.get(*j).copied(),
+                _ => None,
+            }
+        }
+
+        fn model_tracker(&self) -> &dyn TreeModelTracker {
+            &self.notify
+        }
+    }
+
+    fn content(model: &FlattenedTreeModel<Rc<TestTree>>) -> Vec<(usize, &'static str)> {
+        model.iter().map(|r| (r.depth, r.data)).collect()
+    }
+
+    #[test]
+    fn test_flattened_tree_model() {
+        let tree = Rc::new(TestTree::default());
+        *tree.nodes.borrow_mut() = vec![("a", vec!["a1", "a2"]), ("b", vec![]), ("c", vec!["c1"])];
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let checker = adapters::ModelChecker::new(model.clone());
+
+        assert_eq!(content(&model), vec![(0, "a"), (0, "b"), (0, "c")]);
+        assert!(model.row_data(0).unwrap().has_children);
+        assert!(!model.row_data(1).unwrap().has_children);
+
+        model.set_expanded(2, true);
+        model.set_expanded(0, true);
+        assert!(model.is_expanded(0));
+        assert_eq!(
+            content(&model),
+            vec![(0, "a"), (1, "a1"), (1, "a2"), (0, "b"), (0, "c"), (1, "c1")]
+        );
+        checker.check();
+        assert_eq!(model.tree_index(5), Some(vec![2, 0]));
+        assert_eq!(model.row_of(&[0, 1]), Some(2));
+
+        tree.nodes.borrow_mut()[0].1.insert(1, "a3");
+        tree.notify.row_added(&[0], 1, 1);
+        assert_eq!(
+            content(&model),
+            vec![(0, "a"), (1, "a1"), (1, "a3"), (1, "a2"), (0, "b"), (0, "c"), (1, "c1")]
+        );
+
+        tree.nodes.borrow_mut().remove(1);
+        tree.notify.row_removed(&[], 1, 1);
+        assert_eq!(
+            content(&model),
+            vec![(0, "a"), (1, "a1"), (1, "a3"), (1, "a2"), (0, "c"), (1, "c1")]
+        );
+        checker.check();
+        assert_eq!(model.tree_index(5), Some(vec![1, 0]));
+
+        model.set_expanded(0, false);
+        assert_eq!(content(&model), vec![(0, "a"), (0, "c"), (1, "c1")]);
+
+        tree.nodes.borrow_mut()[0].0 = "A";
+        tree.notify.row_changed(&[0]);
+        tree.nodes.borrow_mut().insert(0, ("z", vec![]));
+        tree.notify.row_added(&[], 0, 1);
+        assert_eq!(content(&model), vec![(0, "z"), (0, "A"), (0, "c"), (1, "c1")]);
+        checker.check();
+
+        tree.notify.reset();
+        assert_eq!(content(&model), vec![(0, "z"), (0, "A"), (0, "c")]);
+    }
+}
@@ -110,6 +110,22 @@ fn click_interval(&self) -> core::time::Duration {
         core::time::Duration::from_millis(500)
     }
 
+    /// Returns true if the user asked the system to minimize non-essential motion.
+    ///
+    /// This is exposed to `.slint` files as `SystemSettings.reduced-motion`. Call
+    /// [`system_settings_changed()`] when the value changes.
+    fn prefers_reduced_motion(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the user asked the system for an increased contrast.
+    ///
+    /// This is exposed to `.slint` files as `SystemSettings.high-contrast`. Call
+    /// [`system_settings_changed()`] when the value changes.
+    fn prefers_high_contrast(&self) -> bool {
+        false
+    }
+
     /// Sends the given text into the system clipboard.
     ///
     /// If the platform doesn't support the specified clipboard, this function should do nothing
@@ -237,6 +253,16 @@ pub fn update_timers_and_animations() {
     crate::properties::ChangeTracker::run_change_handlers();
 }
 
+/// Call this function when the settings of the system, such as the ones returned by
+/// [`Platform::prefers_reduced_motion()`] or [`Platform::prefers_high_contrast()`], have changed,
+/// so that the bindings that depend on them get re-evaluated.
+pub fn system_settings_changed() {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return };
+        ctx.0.system_settings_dirty.mark_dirty();
+    });
+}
+
 /// Returns the duration before the next timer is expected to be activated. This is the
 /// largest amount of time that you can wait before calling [`update_timers_and_animations()`].
 ///
@@ -332,6 +358,17 @@ pub enum WindowEvent {
         /// ```
         text: SharedString,
     },
+    /// A key was pressed, auto-repeated, or released, with the key code and the scan code of the
+    /// key when the windowing system provides them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slint::platform::{Key, KeyInput, WindowEvent};
+    /// let _ = WindowEvent::KeyInput(
+    ///     KeyInput::press(Key::UpArrow.into()).with_key_code(Key::UpArrow.into()).with_scan_code(103),
+    /// );
+    /// ```
+    KeyInput(KeyInput),
     /// The window's scale factor has changed. This can happen for example when the display's resolution
     /// changes, the user selects a new scale factor in the system settings, or the window is moved to a
     /// different screen.
@@ -362,6 +399,57 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+
+    /// Data from another application is dragged over the window.
+    ///
+    /// The backend should dispatch this event whenever the position of the drag changes. The
+    /// `DropArea` under the position is asked whether it accepts the data.
+    DragMoved {
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dragged data. For `text/uri-list`, one URL per line.
+        data: SharedString,
+    },
+    /// The drag from another application left the window or was cancelled.
+    DragExited,
+    /// Data from another application was dropped on the window.
+    ///
+    /// This invokes the `dropped` callback of the `DropArea` under the position that accepts the data.
+    Dropped {
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dropped data. For `text/uri-list`, one URL per line.
+        data: SharedString,
+    },
+
+    /// A finger touched the screen.
+    ///
+    /// The first finger is handled like the mouse. When a second finger touches the screen,
+    /// the two fingers make a pinch gesture for the `PinchGestureRecognizer` under them.
+    TouchPressed {
+        /// Identifies the touch point in the next touch events.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger moved on the screen.
+    TouchMoved {
+        /// Identifies the touch point.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger was lifted from the screen.
+    TouchReleased {
+        /// Identifies the touch point.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// The system cancelled the touch, for example because it recognized a system gesture.
+    TouchCancelled {
+        /// Identifies the touch point.
+        id: u64,
+    },
 }
 
 impl WindowEvent {
@@ -372,11 +460,65 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::DragMoved { position, .. } => Some(*position),
+            WindowEvent::Dropped { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
             _ => None,
         }
     }
 }
 
+/// A key event with the key code and the scan code of the key, dispatched with
+/// [`WindowEvent::KeyInput`].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+#[repr(C)]
+pub struct KeyInput {
+    /// The unicode representation of the key.
+    pub text: SharedString,
+    /// The key, without the effect of the modifiers. For example, when pressing Shift+A, `text`
+    /// is "A" and `key_code` is "a". It's empty if the windowing system doesn't provide it, then
+    /// `text` is used.
+    pub key_code: SharedString,
+    /// The platform specific code of the physical key, which doesn't depend on the keyboard
+    /// layout, or 0 if the windowing system doesn't provide it.
+    pub scan_code: i32,
+    /// True if the key was released, false if it was pressed.
+    pub released: bool,
+    /// True if the key press was auto-repeated.
+    pub repeat: bool,
+}
+
+impl KeyInput {
+    /// Creates the event for a key that was pressed, with the unicode representation of the key.
+    pub fn press(text: SharedString) -> Self {
+        Self { text, ..Default::default() }
+    }
+
+    /// Creates the event for a key press that was auto-repeated, with the unicode representation
+    /// of the key.
+    pub fn press_repeat(text: SharedString) -> Self {
+        Self { text, repeat: true, ..Default::default() }
+    }
+
+    /// Creates the event for a key that was released, with the unicode representation of the key.
+    pub fn release(text: SharedString) -> Self {
+        Self { text, released: true, ..Default::default() }
+    }
+
+    /// Returns the event with the given key code.
+    pub fn with_key_code(self, key_code: SharedString) -> Self {
+        Self { key_code, ..self }
+    }
+
+    /// Returns the event with the given scan code.
+    pub fn with_scan_code(self, scan_code: i32) -> Self {
+        Self { scan_code, ..self }
+    }
+}
+
 /**
  * Test the animation tick is updated when a platform is set
 ```rust
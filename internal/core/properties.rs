@@ -267,6 +267,8 @@ fn drop(&mut self) {
 use core::marker::PhantomPinned;
 use core::pin::Pin;
 
+use crate::animations::AnimationControl;
+
 /// if a DependencyListHead points to that value, it is because the property is actually
 /// constant and cannot have dependencies
 static CONSTANT_PROPERTY_SENTINEL: u32 = 0;
@@ -288,6 +290,7 @@ struct BindingVTable {
     intercept_set: unsafe fn(_self: *const BindingHolder, value: *const ()) -> bool,
     intercept_set_binding:
         unsafe fn(_self: *const BindingHolder, new_binding: *mut BindingHolder) -> bool,
+    control_animation: unsafe fn(_self: *const BindingHolder, control: AnimationControl) -> bool,
 }
 
 /// A binding trait object can be used to dynamically produces values for a property.
@@ -320,6 +323,13 @@ unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHold
         false
     }
 
+    /// Apply the control to the animation run by this binding.
+    /// The default implementation returns false, meaning the binding is not animated.
+    /// When returning true, the binding will be evaluated again.
+    fn control_animation(self: Pin<&Self>, _control: AnimationControl) -> bool {
+        false
+    }
+
     /// Set to true if and only if Self is a TwoWayBinding<T>
     const IS_TWO_WAY_BINDING: bool = false;
 }
@@ -428,9 +438,21 @@ unsafe fn evaluate<B: BindingCallable>(
         value: *mut (),
     ) -> BindingResult {
         let pinned_holder = Pin::new_unchecked(&*_self);
-        CURRENT_BINDING.set(Some(pinned_holder), || {
-            Pin::new_unchecked(&((*(_self as *mut BindingHolder<B>)).binding)).evaluate(value)
-        })
+        let evaluate = || {
+            CURRENT_BINDING.set(Some(pinned_holder), || {
+                Pin::new_unchecked(&((*(_self as *mut BindingHolder<B>)).binding)).evaluate(value)
+            })
+        };
+        #[cfg(feature = "binding-profiler")]
+        {
+            #[cfg(slint_debug_property)]
+            let name = (*_self).debug_name.as_str();
+            #[cfg(not(slint_debug_property))]
+            let name = core::any::type_name::<B>();
+            binding_profiler::record_evaluation(_self as usize, name, evaluate)
+        }
+        #[cfg(not(feature = "binding-profiler"))]
+        evaluate()
     }
 
     /// Safety: _self must be a pointer to a `BindingHolder<B>`
@@ -454,6 +476,19 @@ unsafe fn intercept_set_binding<B: BindingCallable>(
             .intercept_set_binding(new_binding)
     }
 
+    /// Safety: _self must be a pointer to a `BindingHolder<B>`
+    unsafe fn control_animation<B: BindingCallable>(
+        _self: *const BindingHolder,
+        control: AnimationControl,
+    ) -> bool {
+        let controlled = Pin::new_unchecked(&((*(_self as *const BindingHolder<B>)).binding))
+            .control_animation(control);
+        if controlled {
+            (*_self).dirty.set(true);
+        }
+        controlled
+    }
+
     trait HasBindingVTable {
         const VT: &'static BindingVTable;
     }
@@ -464,6 +499,7 @@ impl<B: BindingCallable> HasBindingVTable for B {
             mark_dirty: mark_dirty::<B>,
             intercept_set: intercept_set::<B>,
             intercept_set_binding: intercept_set_binding::<B>,
+            control_animation: control_animation::<B>,
         };
     }
 
@@ -608,6 +644,27 @@ fn set_binding_impl(&self, binding: *mut BindingHolder) {
         }
     }
 
+    /// Forward the control to the binding, and mark the property dirty if it controlled an animation.
+    fn control_animation(
+        &self,
+        control: AnimationControl,
+        #[cfg(slint_debug_property)] debug_name: &str,
+    ) -> bool {
+        let controlled = self.access(|b| {
+            b.map_or(false, |b| unsafe {
+                // Safety: b is a BindingHolder
+                (b.vtable.control_animation)(&*b as *const BindingHolder, control)
+            })
+        });
+        if controlled {
+            self.mark_dirty(
+                #[cfg(slint_debug_property)]
+                debug_name,
+            );
+        }
+        controlled
+    }
+
     fn dependencies(&self) -> *mut DependencyListHead {
         assert!(!self.lock_flag(), "Recursion detected");
         if (self.handle.get() & 0b10) != 0 {
@@ -679,6 +736,9 @@ fn mark_dirty(&self, #[cfg(slint_debug_property)] debug_name: &str) {
                 "Constant property being changed {}",
                 debug_name
             );
+            #[cfg(feature = "binding-profiler")]
+            binding_profiler::record_trigger(debug_name, || mark_dependencies_dirty(dependencies));
+            #[cfg(not(feature = "binding-profiler"))]
             mark_dependencies_dirty(dependencies)
         };
     }
@@ -718,6 +778,10 @@ unsafe fn mark_dependencies_dirty(dependencies: *mut DependencyListHead) {
     DependencyListHead::for_each(&*dependencies, |binding| {
         let binding: &BindingHolder = &**binding;
         let was_dirty = binding.dirty.replace(true);
+        #[cfg(feature = "binding-profiler")]
+        if !was_dirty {
+            binding_profiler::record_dirty(binding as *const BindingHolder as usize);
+        }
         (binding.vtable.mark_dirty)(binding as *const BindingHolder, was_dirty);
 
         assert!(
@@ -963,6 +1027,27 @@ pub fn mark_dirty(&self) {
         )
     }
 
+    /// Pause, resume, or seek the animation of the binding of this property.
+    ///
+    /// Returns false if the property doesn't have an animated binding. The control also applies to
+    /// an animation that is about to start: when the value changes while the animation is paused,
+    /// the new animation starts paused as well.
+    ///
+    /// ## Example
+    /// ```
+    /// use i_slint_core::animations::AnimationControl;
+    /// use i_slint_core::Property;
+    /// let prop = Property::new(100);
+    /// assert!(!prop.control_animation(AnimationControl::Pause));
+    /// ```
+    pub fn control_animation(&self, control: AnimationControl) -> bool {
+        self.handle.control_animation(
+            control,
+            #[cfg(slint_debug_property)]
+            self.debug_name.borrow().as_str(),
+        )
+    }
+
     /// Mark that this property will never be modified again and that no tracking should be done
     pub fn set_constant(&self) {
         self.handle.set_constant();
@@ -1033,6 +1118,10 @@ unsafe fn intercept_set_binding(
                 true
             }
 
+            fn control_animation(self: Pin<&Self>, control: AnimationControl) -> bool {
+                self.common_property.control_animation(control)
+            }
+
             const IS_TWO_WAY_BINDING: bool = true;
         }
 
@@ -1312,6 +1401,8 @@ fn property_two_ways_binding_of_two_two_way_bindings() {
     assert_eq!(p2_2.as_ref().get(), 9);
 }
 
+#[cfg(feature = "binding-profiler")]
+pub mod binding_profiler;
 mod change_tracker;
 pub use change_tracker::*;
 mod properties_animations;
@@ -1386,6 +1477,7 @@ fn default() -> Self {
             mark_dirty: |_, _| (),
             intercept_set: |_, _| false,
             intercept_set_binding: |_, _| false,
+            control_animation: |_, _| false,
         };
 
         let holder = BindingHolder {
@@ -1513,6 +1605,7 @@ impl<B: PropertyDirtyHandler> HasBindingVTable for B {
                 mark_dirty: mark_dirty::<B>,
                 intercept_set: |_, _| false,
                 intercept_set_binding: |_, _| false,
+                control_animation: |_, _| false,
             };
         }
 
@@ -0,0 +1,181 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Records which property bindings are evaluated, how long they take, and which property changes
+caused them to be re-evaluated. Only compiled with the `binding-profiler` feature.
+
+Each evaluation is also wrapped in a `binding` span of the [`tracing`] crate, at the trace level.
+*/
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+#[derive(Default)]
+struct Recorder {
+    bindings: HashMap<String, BindingProfileEntry>,
+    /// The trigger of the bindings that are dirty, by address of their BindingHolder
+    pending_triggers: HashMap<usize, String>,
+    /// The name of the property that is currently being marked as dirty
+    current_trigger: Option<String>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = RefCell::new(None);
+}
+
+/// The statistics of a property binding, part of a [`BindingProfile`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct BindingProfileEntry {
+    /// The name of the property of the binding, if compiled with `RUSTFLAGS='--cfg slint_debug_property'`,
+    /// or the type name of the binding's closure.
+    pub name: String,
+    /// The number of times the binding was evaluated.
+    pub evaluations: usize,
+    /// The time spent evaluating the binding, including the evaluation of the bindings it depends on.
+    pub total_duration: core::time::Duration,
+    /// The longest evaluation of the binding.
+    pub max_duration: core::time::Duration,
+    /// The names of the properties whose change caused the binding to be evaluated again, with the
+    /// number of times. Properties without a name are reported as `<unnamed>`.
+    pub triggers: Vec<(String, usize)>,
+}
+
+/// The report returned by [`stop_binding_profiling()`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct BindingProfile {
+    /// The bindings that were evaluated, sorted by decreasing total duration.
+    pub bindings: Vec<BindingProfileEntry>,
+}
+
+impl core::fmt::Display for BindingProfile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for entry in &self.bindings {
+            writeln!(
+                f,
+                "{}: {} evaluations, {:?} total, {:?} max",
+                entry.name, entry.evaluations, entry.total_duration, entry.max_duration
+            )?;
+            for (trigger, count) in &entry.triggers {
+                writeln!(f, "    triggered {count} times by {trigger}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Starts recording the evaluation of property bindings in the current thread.
+/// Any previously recorded data is discarded.
+pub fn start_binding_profiling() {
+    RECORDER.with(|r| *r.borrow_mut() = Some(Recorder::default()));
+}
+
+/// Stops recording the evaluation of property bindings, and returns the report
+/// of what was recorded since [`start_binding_profiling()`].
+pub fn stop_binding_profiling() -> BindingProfile {
+    let Some(recorder) = RECORDER.with(|r| r.borrow_mut().take()) else {
+        return BindingProfile::default();
+    };
+    let mut bindings = recorder.bindings.into_values().collect::<Vec<_>>();
+    for entry in &mut bindings {
+        entry.triggers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    }
+    bindings
+        .sort_by(|a, b| b.total_duration.cmp(&a.total_duration).then_with(|| a.name.cmp(&b.name)));
+    BindingProfile { bindings }
+}
+
+/// Calls `f`, which evaluates the binding at `address`, and records it.
+pub(super) fn record_evaluation<R>(address: usize, name: &str, f: impl FnOnce() -> R) -> R {
+    let _span = tracing::trace_span!("binding", name).entered();
+    if !RECORDER.with(|r| r.borrow().is_some()) {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    let duration = start.elapsed();
+    RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        let Some(recorder) = r.as_mut() else { return };
+        let trigger = recorder.pending_triggers.remove(&address);
+        let entry = recorder
+            .bindings
+            .entry(name.into())
+            .or_insert_with(|| BindingProfileEntry { name: name.into(), ..Default::default() });
+        entry.evaluations += 1;
+        entry.total_duration += duration;
+        entry.max_duration = entry.max_duration.max(duration);
+        if let Some(trigger) = trigger {
+            match entry.triggers.iter_mut().find(|(t, _)| *t == trigger) {
+                Some((_, count)) => *count += 1,
+                None => entry.triggers.push((trigger, 1)),
+            }
+        }
+    });
+    result
+}
+
+/// Calls `f`, which marks the dependencies of the property named `name` as dirty.
+pub(super) fn record_trigger(name: &str, f: impl FnOnce()) {
+    let previous = RECORDER.with(|r| {
+        r.borrow_mut().as_mut().map(|recorder| {
+            let name = if name.is_empty() { "<unnamed>" } else { name };
+            recorder.current_trigger.replace(name.into())
+        })
+    });
+    f();
+    if let Some(previous) = previous {
+        RECORDER.with(|r| {
+            if let Some(recorder) = r.borrow_mut().as_mut() {
+                recorder.current_trigger = previous;
+            }
+        });
+    }
+}
+
+/// The binding at `address` was just marked as dirty.
+pub(super) fn record_dirty(address: usize) {
+    RECORDER.with(|r| {
+        if let Some(recorder) = r.borrow_mut().as_mut() {
+            if let Some(trigger) = recorder.current_trigger.clone() {
+                recorder.pending_triggers.insert(address, trigger);
+            }
+        }
+    });
+}
+
+#[test]
+fn record_dependent_binding() {
+    use crate::Property;
+    use std::rc::Rc;
+
+    let source = Rc::pin(Property::new_named(1, "source"));
+    let result = Box::pin(Property::new_named(0, "result"));
+    result.set_binding({
+        let source = source.clone();
+        move || source.as_ref().get() * 2
+    });
+    assert_eq!(result.as_ref().get(), 2);
+
+    start_binding_profiling();
+    source.set(2);
+    assert_eq!(result.as_ref().get(), 4);
+    assert_eq!(result.as_ref().get(), 4);
+    let profile = stop_binding_profiling();
+    assert_eq!(profile.bindings.len(), 1);
+    let entry = &profile.bindings[0];
+    assert_eq!(entry.evaluations, 1);
+    assert_eq!(entry.triggers.len(), 1);
+    assert_eq!(entry.triggers[0].1, 1);
+    assert!(profile.to_string().contains("1 evaluations"));
+
+    source.set(3);
+    assert_eq!(result.as_ref().get(), 6);
+    assert_eq!(stop_binding_profiling(), BindingProfile::default());
+}
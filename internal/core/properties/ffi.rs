@@ -239,6 +239,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
 ) {
     let binding = core::mem::transmute::<
         extern "C" fn(*mut c_void, *mut T),
@@ -256,11 +258,19 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
                 | 0b10,
         ),
     };
-    let animation_data = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-        T::default(),
-        T::default(),
-        animation_data.cloned().unwrap_or_default(),
-    ));
+    // The callbacks are owned by the binding, so they are never called after the user data is dropped
+    let callbacks = properties_animations::AnimationCallbacks {
+        started: started.map(|started| Rc::new(move || started(user_data)) as Rc<dyn Fn()>),
+        finished: finished.map(|finished| Rc::new(move || finished(user_data)) as Rc<dyn Fn()>),
+    };
+    let animation_data = RefCell::new(
+        properties_animations::PropertyValueAnimationData::new(
+            T::default(),
+            T::default(),
+            animation_data.cloned().unwrap_or_default(),
+        )
+        .with_callbacks(callbacks),
+    );
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding,
@@ -294,6 +304,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
 ) {
     c_set_animated_binding(
         handle,
@@ -302,6 +314,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        started,
+        finished,
     );
 }
 
@@ -316,6 +330,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
 ) {
     c_set_animated_binding(
         handle,
@@ -324,6 +340,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        started,
+        finished,
     );
 }
 
@@ -338,6 +356,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
 ) {
     c_set_animated_binding(
         handle,
@@ -346,6 +366,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        started,
+        finished,
     );
 }
 
@@ -360,6 +382,35 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
+) {
+    c_set_animated_binding(
+        handle,
+        binding,
+        user_data,
+        drop_user_data,
+        animation_data,
+        transition_data,
+        started,
+        finished,
+    );
+}
+
+/// Internal function to set up a property animation between values produced by the specified binding for a path property.
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_set_animated_binding_path(
+    handle: &PropertyHandleOpaque,
+    binding: extern "C" fn(*mut c_void, *mut crate::graphics::PathData),
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    animation_data: Option<&PropertyAnimation>,
+    transition_data: Option<
+        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+    >,
+    started: Option<extern "C" fn(user_data: *mut c_void)>,
+    finished: Option<extern "C" fn(user_data: *mut c_void)>,
 ) {
     c_set_animated_binding(
         handle,
@@ -368,6 +419,8 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         drop_user_data,
         animation_data,
         transition_data,
+        started,
+        finished,
     );
 }
 
@@ -517,6 +570,7 @@ unsafe fn evaluate(_self: *mut BindingHolder, _value: *mut ()) -> BindingResult
         mark_dirty: ChangeTracker::mark_dirty,
         intercept_set: |_, _| false,
         intercept_set_binding: |_, _| false,
+        control_animation: |_, _| false,
     };
 
     ct.clear();
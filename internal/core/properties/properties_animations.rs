@@ -12,33 +12,135 @@ enum AnimationState {
     Done,
 }
 
+/// Callbacks that are invoked when an animation starts or finishes.
+///
+/// The callbacks are not invoked while the property is evaluated, but with the next
+/// timer update of the event loop. They are not invoked anymore once the property
+/// or its binding is destroyed.
+#[derive(Clone, Default)]
+pub struct AnimationCallbacks {
+    /// Invoked when the animation starts, after its delay
+    pub started: Option<Rc<dyn Fn()>>,
+    /// Invoked when the animation has reached its target value. An animation that is
+    /// interrupted because the value changes again doesn't finish, but starts again.
+    pub finished: Option<Rc<dyn Fn()>>,
+}
+
+impl AnimationCallbacks {
+    fn invoke(callback: &Option<Rc<dyn Fn()>>) {
+        if let Some(callback) = callback {
+            // The animation data holds the only strong reference, so the callback is
+            // not called if the binding was removed in the meantime.
+            let callback = Rc::downgrade(callback);
+            crate::timers::Timer::single_shot(Default::default(), move || {
+                if let Some(callback) = callback.upgrade() {
+                    callback()
+                }
+            });
+        }
+    }
+}
+
 pub(super) struct PropertyValueAnimationData<T> {
     from_value: T,
     to_value: T,
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// The velocity of the spring animation that was interrupted by a new target value, relative
+    /// to the distance between `from_value` and `to_value` per second. It replaces the initial
+    /// velocity of the spring, so that the motion continues smoothly.
+    spring_velocity: Option<f32>,
+    callbacks: AnimationCallbacks,
+    /// Milliseconds of the animation that elapsed before `start_time`, after seeking.
+    time_offset: u64,
+    /// The tick at which the animation was paused, used instead of the current tick.
+    paused_at: Option<crate::animations::Instant>,
+    /// The factor of the duration and delay when the animation started,
+    /// see [`crate::animations::set_animation_duration_factor`].
+    duration_factor: f32,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            state: AnimationState::Delaying,
+            spring_velocity: None,
+            callbacks: AnimationCallbacks::default(),
+            time_offset: 0,
+            paused_at: None,
+            duration_factor: crate::animations::animation_duration_factor(),
+        }
+    }
+
+    pub fn with_callbacks(self, callbacks: AnimationCallbacks) -> Self {
+        Self { callbacks, ..self }
+    }
+
+    fn start(&mut self) {
+        self.state = AnimationState::Animating { current_iteration: 0 };
+        AnimationCallbacks::invoke(&self.callbacks.started);
+    }
+
+    fn finish(&mut self) {
+        self.state = AnimationState::Done;
+        AnimationCallbacks::invoke(&self.callbacks.finished);
+    }
+
+    fn spring(&self) -> Option<crate::animations::Spring> {
+        let crate::animations::EasingCurve::Spring(parameters) = self.details.easing else {
+            return None;
+        };
+        let spring = crate::animations::Spring::new(parameters);
+        Some(match self.spring_velocity {
+            Some(velocity) => spring.with_initial_velocity(velocity),
+            None => spring,
+        })
+    }
+
+    /// The tick at which the animation is evaluated, which doesn't advance while paused.
+    fn now(&self) -> crate::animations::Instant {
+        self.paused_at.unwrap_or_else(crate::animations::current_tick)
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// The milliseconds elapsed since the start of the animation, or of its current iteration.
+    fn elapsed(&self, now: crate::animations::Instant) -> u64 {
+        now.0.saturating_sub(self.start_time.0) + self.time_offset
+    }
+
+    /// Scales a duration or delay of the animation details by the duration factor.
+    fn scaled(&self, millis: i32) -> i32 {
+        (millis as f32 * self.duration_factor).round() as i32
+    }
+
+    /// The time of the spring in seconds, which is stretched by the duration factor.
+    fn spring_time(&self, elapsed: u64) -> f32 {
+        elapsed as f32 / 1000. / self.duration_factor
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
-        let new_tick = crate::animations::current_tick();
-        let mut time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
+        let new_tick = self.now();
+        let mut time_progress = self.elapsed(new_tick);
 
         match self.state {
             AnimationState::Delaying => {
-                if self.details.delay <= 0 {
-                    self.state = AnimationState::Animating { current_iteration: 0 };
+                let delay = self.scaled(self.details.delay);
+                if delay <= 0 {
+                    self.start();
                     return self.compute_interpolated_value();
                 }
 
-                let delay = self.details.delay as u64;
+                let delay = delay as u64;
 
                 if time_progress < delay {
                     (self.from_value.clone(), false)
@@ -47,22 +149,39 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                         new_tick - core::time::Duration::from_millis(time_progress - delay);
 
                     // Decide on next state:
-                    self.state = AnimationState::Animating { current_iteration: 0 };
+                    self.start();
                     self.compute_interpolated_value()
                 }
             }
+            AnimationState::Animating { current_iteration: _ } if self.spring().is_some() => {
+                // Springs ignore the duration and iteration count, and run until they come to rest
+                let spring = self.spring().unwrap();
+                let time = self.spring_time(time_progress);
+                if self.duration_factor <= 0. || spring.is_at_rest(time) {
+                    self.finish();
+                    return self.compute_interpolated_value();
+                }
+                let val = self.from_value.interpolate_in_color_space(
+                    &self.to_value,
+                    spring.state(time).0,
+                    self.details.color_interpolation,
+                );
+                (val, false)
+            }
             AnimationState::Animating { mut current_iteration } => {
-                if self.details.duration <= 0 || self.details.iteration_count == 0. {
-                    self.state = AnimationState::Done;
+                let duration = self.scaled(self.details.duration);
+                if duration <= 0 || self.details.iteration_count == 0. {
+                    self.finish();
                     return self.compute_interpolated_value();
                 }
 
-                let duration = self.details.duration as u64;
+                let duration = duration as u64;
                 if time_progress >= duration {
                     // wrap around
                     current_iteration += time_progress / duration;
                     time_progress %= duration;
-                    self.start_time = new_tick - core::time::Duration::from_millis(time_progress);
+                    self.start_time = new_tick;
+                    self.time_offset = time_progress;
                 }
 
                 if (self.details.iteration_count < 0.)
@@ -71,14 +190,21 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 {
                     self.state = AnimationState::Animating { current_iteration };
 
-                    let progress =
-                        (time_progress as f32 / self.details.duration as f32).clamp(0., 1.);
-                    let t = crate::animations::easing_curve(&self.details.easing, progress);
-                    let val = self.from_value.interpolate(&self.to_value, t);
+                    let progress = (time_progress as f32 / duration as f32).clamp(0., 1.);
+                    let t = crate::animations::keyframes_curve(
+                        &self.details.keyframes,
+                        &self.details.easing,
+                        progress,
+                    );
+                    let val = self.from_value.interpolate_in_color_space(
+                        &self.to_value,
+                        t,
+                        self.details.color_interpolation,
+                    );
 
                     (val, false)
                 } else {
-                    self.state = AnimationState::Done;
+                    self.finish();
                     self.compute_interpolated_value()
                 }
             }
@@ -87,8 +213,71 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
     }
 
     fn reset(&mut self) {
+        // Keep the velocity of a spring that is still moving
+        self.spring_velocity = match (&self.state, self.spring()) {
+            (AnimationState::Animating { .. }, Some(spring)) => {
+                Some(spring.state(self.spring_time(self.elapsed(self.now()))).1)
+            }
+            _ => None,
+        };
         self.state = AnimationState::Delaying;
+        self.time_offset = 0;
+        self.duration_factor = crate::animations::animation_duration_factor();
         self.start_time = crate::animations::current_tick();
+        // A paused animation stays paused, and the new one starts paused
+        if self.is_paused() {
+            self.paused_at = Some(self.start_time);
+        }
+    }
+
+    /// Returns false if the animation is done and can't be controlled anymore.
+    fn control(&mut self, control: AnimationControl) -> bool {
+        if matches!(self.state, AnimationState::Done) {
+            return false;
+        }
+        let now = crate::animations::current_tick();
+        match control {
+            AnimationControl::Pause => {
+                if self.paused_at.is_none() {
+                    self.paused_at = Some(now);
+                }
+            }
+            AnimationControl::Resume => {
+                if let Some(paused_at) = self.paused_at.take() {
+                    self.start_time += now.duration_since(paused_at);
+                }
+            }
+            AnimationControl::Seek(progress) => {
+                let duration = match self.spring() {
+                    Some(spring) => spring.duration() * 1000. * self.duration_factor,
+                    None if self.details.iteration_count < 0. => {
+                        self.scaled(self.details.duration) as f32
+                    }
+                    None => {
+                        self.scaled(self.details.duration) as f32 * self.details.iteration_count
+                    }
+                };
+                if matches!(self.state, AnimationState::Delaying) {
+                    self.start();
+                }
+                self.state = AnimationState::Animating { current_iteration: 0 };
+                self.start_time = self.now();
+                self.time_offset = (progress.clamp(0., 1.) * duration.max(0.)) as u64;
+            }
+        }
+        true
+    }
+
+    /// Scales the velocity of an interrupted spring from the distance of the previous
+    /// animation to the distance of this one.
+    fn retarget_spring_velocity(&mut self, previous_distance: Option<f32>) {
+        let distance = self.from_value.distance(&self.to_value);
+        self.spring_velocity = match (self.spring_velocity, previous_distance, distance) {
+            (Some(velocity), Some(previous_distance), Some(distance)) => {
+                (distance != 0.).then(|| velocity * previous_distance / distance)
+            }
+            (velocity, _, _) => velocity,
+        };
     }
 }
 
@@ -119,11 +308,12 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         );
         match self.state.get() {
             AnimatedBindingState::Animating => {
-                let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+                let mut animation_data = self.animation_data.borrow_mut();
+                let (val, finished) = animation_data.compute_interpolated_value();
                 *(value as *mut T) = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
@@ -136,17 +326,20 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                 self.state.set(AnimatedBindingState::Animating);
                 let mut animation_data = self.animation_data.borrow_mut();
                 // animation_data.details.iteration_count = 1.;
+                let previous_distance =
+                    animation_data.from_value.distance(&animation_data.to_value);
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
                 if let Some((details, start_time)) = (self.compute_animation_details)() {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
                 }
+                animation_data.retarget_spring_velocity(previous_distance);
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
@@ -164,6 +357,35 @@ fn mark_dirty(self: Pin<&Self>) {
             self.animation_data.borrow_mut().reset();
         }
     }
+
+    fn control_animation(self: Pin<&Self>, control: AnimationControl) -> bool {
+        self.state.get() != AnimatedBindingState::NotAnimating
+            && self.animation_data.borrow_mut().control(control)
+    }
+}
+
+/// The binding that animates a property to a value that was set, and removes itself when done.
+pub(super) struct AnimatedValueCallable<T>(pub(super) RefCell<PropertyValueAnimationData<T>>);
+
+unsafe impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueCallable<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let mut animation_data = self.0.borrow_mut();
+        let (val, finished) = animation_data.compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            if !animation_data.is_paused() {
+                crate::animations::CURRENT_ANIMATION_DRIVER
+                    .with(|driver| driver.set_has_active_animations());
+            }
+            BindingResult::KeepBinding
+        }
+    }
+
+    fn control_animation(self: Pin<&Self>, control: AnimationControl) -> bool {
+        self.0.borrow_mut().control(control)
+    }
 }
 
 /// InterpolatedPropertyValue is a trait used to enable properties to be used with
@@ -175,24 +397,57 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Same as [`Self::interpolate`], but colors are interpolated in the given color space.
+    /// Values that don't contain colors ignore the color space.
+    #[must_use]
+    fn interpolate_in_color_space(
+        &self,
+        target_value: &Self,
+        t: f32,
+        color_space: crate::items::ColorInterpolation,
+    ) -> Self {
+        let _ = color_space;
+        self.interpolate(target_value, t)
+    }
+
+    /// Returns the signed distance from self to target_value, for values with a single dimension.
+    /// When the target value of a spring animation changes, its velocity is scaled by the ratio
+    /// of the distances, so that the value continues to move at the same speed.
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        let _ = target_value;
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some((*target_value as i64 - *self as i64) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -200,12 +455,20 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).round().clamp(0., 255.)
             as u8
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        self.get().distance(&target_value.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -216,26 +479,30 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
+        self.set_animated_value_with_callbacks(value, animation_data, Default::default())
+    }
+
+    /// Same as [`Self::set_animated_value`], but the callbacks are invoked when the
+    /// animation starts and finishes.
+    pub fn set_animated_value_with_callbacks(
+        &self,
+        value: T,
+        animation_data: PropertyAnimation,
+        callbacks: AnimationCallbacks,
+    ) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-            self.get_internal(),
-            value,
-            animation_data,
-        ));
+        let d = RefCell::new(
+            properties_animations::PropertyValueAnimationData::new(
+                self.get_internal(),
+                value,
+                animation_data,
+            )
+            .with_callbacks(callbacks),
+        );
         // Safety: the BindingCallable will cast its argument to T
         unsafe {
             self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+                properties_animations::AnimatedValueCallable(d),
                 #[cfg(slint_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -252,6 +519,17 @@ pub fn set_animated_binding(
         &self,
         binding: impl Binding<T> + 'static,
         animation_data: PropertyAnimation,
+    ) {
+        self.set_animated_binding_with_callbacks(binding, animation_data, Default::default())
+    }
+
+    /// Same as [`Self::set_animated_binding`], but the callbacks are invoked when an
+    /// animation starts and finishes.
+    pub fn set_animated_binding_with_callbacks(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        callbacks: AnimationCallbacks,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -265,11 +543,14 @@ pub fn set_animated_binding(
                 ),
             },
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                animation_data,
-            )),
+            animation_data: RefCell::new(
+                properties_animations::PropertyValueAnimationData::new(
+                    T::default(),
+                    T::default(),
+                    animation_data,
+                )
+                .with_callbacks(callbacks),
+            ),
             compute_animation_details: || -> properties_animations::AnimationDetail { None },
         };
 
@@ -294,6 +575,22 @@ pub fn set_animated_binding_for_transition(
         binding: impl Binding<T> + 'static,
         compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
             + 'static,
+    ) {
+        self.set_animated_binding_for_transition_with_callbacks(
+            binding,
+            compute_animation_details,
+            Default::default(),
+        )
+    }
+
+    /// Same as [`Self::set_animated_binding_for_transition`], but the callbacks are invoked
+    /// when an animation starts and finishes.
+    pub fn set_animated_binding_for_transition_with_callbacks(
+        &self,
+        binding: impl Binding<T> + 'static,
+        compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
+            + 'static,
+        callbacks: AnimationCallbacks,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -307,11 +604,14 @@ pub fn set_animated_binding_for_transition(
                 ),
             },
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                PropertyAnimation::default(),
-            )),
+            animation_data: RefCell::new(
+                properties_animations::PropertyValueAnimationData::new(
+                    T::default(),
+                    T::default(),
+                    PropertyAnimation::default(),
+                )
+                .with_callbacks(callbacks),
+            ),
             compute_animation_details: move || Some(compute_animation_details()),
         };
 
@@ -841,6 +1141,124 @@ fn properties_test_delayed_animation_triggered_by_binding() {
         assert_eq!(get_prop_value(&compo.width_times_two), 400);
     }
 
+    #[test]
+    fn animation_callbacks_triggered_by_binding() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            delay: DELAY.as_millis() as _,
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.0,
+            ..PropertyAnimation::default()
+        };
+
+        let started = Rc::new(Cell::new(0));
+        let finished = Rc::new(Cell::new(0));
+        let callbacks = AnimationCallbacks {
+            started: Some(Rc::new({
+                let started = started.clone();
+                move || started.set(started.get() + 1)
+            })),
+            finished: Some(Rc::new({
+                let finished = finished.clone();
+                move || finished.set(finished.get() + 1)
+            })),
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding_with_callbacks(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+            callbacks,
+        );
+
+        // Update the animations, evaluate the property, and run the deferred callbacks
+        let advance_to = |time| {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.update_animations(time));
+            let value = get_prop_value(&compo.width);
+            crate::timers::TimerList::maybe_activate_timers(time);
+            (value, started.get(), finished.get())
+        };
+
+        compo.feed_property.set(100);
+        assert_eq!(advance_to(start_time), (100, 0, 0));
+
+        compo.feed_property.set(200);
+        assert_eq!(advance_to(start_time + DELAY / 2), (100, 0, 0));
+        assert_eq!(advance_to(start_time + DELAY), (100, 1, 0));
+        assert_eq!(advance_to(start_time + DELAY + DURATION / 2), (150, 1, 0));
+        assert_eq!(advance_to(start_time + DELAY + DURATION), (200, 1, 1));
+        assert_eq!(advance_to(start_time + DELAY + DURATION * 2), (200, 1, 1));
+
+        // An interrupted animation starts again, but only finishes once
+        let restart_time = start_time + DELAY + DURATION * 2;
+        compo.feed_property.set(300);
+        assert_eq!(advance_to(restart_time + DELAY + DURATION / 2), (250, 2, 1));
+        compo.feed_property.set(400);
+        let restart_time = restart_time + DELAY + DURATION / 2;
+        assert_eq!(advance_to(restart_time + DELAY + DURATION / 2).1, 3);
+        assert_eq!(advance_to(restart_time + DELAY + DURATION), (400, 3, 2));
+    }
+
+    #[test]
+    fn spring_animation_retargeted_by_binding() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        // A critically damped spring
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            easing: crate::animations::EasingCurve::Spring([100., 20., 0.]),
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(0);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        compo.feed_property.set(1000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(200))
+        });
+        let width = get_prop_value(&compo.width);
+        assert_eq!(width, 594);
+
+        // The spring keeps its speed when the target changes while it's moving. Starting from
+        // rest, it would only move by 16 in the next frame.
+        compo.feed_property.set(2000);
+        assert_eq!(get_prop_value(&compo.width), width);
+        let retarget_time = crate::animations::current_tick();
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_millis(16))
+        });
+        assert!(get_prop_value(&compo.width) - width > 40);
+
+        // The duration is ignored, the animation ends when the spring comes to rest
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_secs(2))
+        });
+        assert_eq!(get_prop_value(&compo.width), 2000);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+    }
+
     #[test]
     fn test_loop() {
         let compo = Component::new_test_component();
@@ -965,4 +1383,167 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn animation_paused_resumed_and_seeked() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        assert!(!compo.width.control_animation(AnimationControl::Pause));
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details);
+
+        let advance_to = |time| {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.update_animations(time));
+            get_prop_value(&compo.width)
+        };
+
+        assert_eq!(advance_to(start_time + DURATION / 4), 125);
+        assert!(compo.width.control_animation(AnimationControl::Pause));
+        assert_eq!(advance_to(start_time + DURATION / 2), 125);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+
+        // Seeking a paused animation moves it, but it stays paused
+        assert!(compo.width.control_animation(AnimationControl::Seek(0.1)));
+        assert_eq!(get_prop_value(&compo.width), 110);
+        assert_eq!(get_prop_value(&compo.width_times_two), 220);
+        assert_eq!(advance_to(start_time + DURATION * 3 / 4), 110);
+
+        assert!(compo.width.control_animation(AnimationControl::Resume));
+        assert_eq!(advance_to(start_time + DURATION), 135);
+        assert!(compo.width.control_animation(AnimationControl::Seek(0.9)));
+        assert_eq!(get_prop_value(&compo.width), 190);
+        assert_eq!(advance_to(start_time + DURATION + DURATION / 10), 200);
+
+        // The animation is done and its binding removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+        assert!(!compo.width.control_animation(AnimationControl::Resume));
+    }
+
+    #[test]
+    fn animation_seek_covers_iterations() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            delay: DELAY.as_millis() as _,
+            duration: DURATION.as_millis() as _,
+            iteration_count: 2.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+        assert!(!compo.width.control_animation(AnimationControl::Seek(0.5)));
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        // Seeking skips the delay, and the progress spans both iterations
+        assert!(compo.width.control_animation(AnimationControl::Seek(0.75)));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        assert!(!compo.width.control_animation(AnimationControl::Pause));
+    }
+
+    #[test]
+    fn animation_driver_paused() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details);
+
+        let advance_to = |time| {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.update_animations(time));
+            get_prop_value(&compo.width)
+        };
+
+        assert_eq!(advance_to(start_time + DURATION / 4), 125);
+        crate::animations::set_animations_paused(true);
+        assert!(crate::animations::animations_paused());
+        assert_eq!(advance_to(start_time + DURATION), 125);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+
+        // The animation continues where it was, even though the time advanced
+        crate::animations::set_animations_paused(false);
+        assert_eq!(get_prop_value(&compo.width), 125);
+        assert!(crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+        assert_eq!(advance_to(start_time + DURATION + DURATION / 4), 150);
+        assert_eq!(crate::animations::current_tick(), start_time + DURATION / 2);
+    }
+
+    #[test]
+    fn animation_duration_factor() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            delay: 100,
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let advance_to = |time| {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.update_animations(time));
+            get_prop_value(&compo.width)
+        };
+
+        compo.width.set(100);
+        crate::animations::set_animation_duration_factor(2.);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details.clone());
+        assert_eq!(
+            advance_to(start_time + std::time::Duration::from_millis(200) + DURATION / 2),
+            125
+        );
+        assert_eq!(
+            advance_to(start_time + std::time::Duration::from_millis(200) + DURATION * 2),
+            200
+        );
+
+        // Animations are disabled with a factor of 0
+        crate::animations::set_animation_duration_factor(0.);
+        compo.width.set_animated_value(100, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+
+        crate::animations::set_animation_duration_factor(-1.);
+        assert_eq!(crate::animations::animation_duration_factor(), 0.);
+    }
 }
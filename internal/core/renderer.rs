@@ -4,10 +4,13 @@
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::pin::Pin;
 
 use crate::api::PlatformError;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use crate::item_rendering::RenderText;
 use crate::item_tree::ItemTreeRef;
 use crate::items::TextWrap;
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
@@ -38,6 +41,13 @@ fn text_size(
         text_wrap: TextWrap,
     ) -> LogicalSize;
 
+    /// Returns the metrics of the font selected by the given request, in logical pixels.
+    fn font_metrics(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> crate::items::FontMetrics;
+
     /// Returns the (UTF-8) byte offset in the text property that refers to the character that contributed to
     /// the glyph cluster that's visually nearest to the given coordinate. This is used for hit-testing,
     /// for example when receiving a mouse click into a text field. Then this function returns the "cursor"
@@ -61,6 +71,27 @@ fn text_input_cursor_rect_for_byte_offset(
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
+    /// Returns the (UTF-8) byte offset in the text of the given `Text` item that refers to the character
+    /// visually nearest to the given coordinate, relative to the item. For text with markup, the offset
+    /// is in the text without the markup.
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        pos: LogicalPoint,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize;
+
+    /// Returns the rectangles, relative to the given `Text` item, that cover the glyphs of the given
+    /// (UTF-8) byte range of its text. The range is on character boundaries of the text.
+    fn text_rects_for_byte_range(
+        &self,
+        text: Pin<&dyn RenderText>,
+        byte_range: core::ops::Range<usize>,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect>;
+
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
         &self,
@@ -77,11 +108,11 @@ fn mark_dirty_region(&self, _region: crate::item_rendering::DirtyRegion) {}
 
     #[cfg(feature = "std")] // FIXME: just because of the Error
     /// This function can be used to register a custom TrueType font with Slint,
-    /// for use with the `font-family` property. The provided slice must be a valid TrueType
+    /// for use with the `font-family` property. The provided data must be a valid TrueType
     /// font.
     fn register_font_from_memory(
         &self,
-        _data: &'static [u8],
+        _data: alloc::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         Err("This renderer does not support registering custom fonts.".into())
     }
@@ -97,6 +128,13 @@ fn register_font_from_path(
         Err("This renderer does not support registering custom fonts.".into())
     }
 
+    #[cfg(feature = "std")]
+    /// Returns the font families that can be used with the `font-family` property, including
+    /// the fonts registered with the application. The families are sorted by name.
+    fn font_families(&self) -> Vec<crate::graphics::FontFamily> {
+        Vec::new()
+    }
+
     fn register_bitmap_font(&self, _font_data: &'static crate::graphics::BitmapFont) {
         crate::debug_log!("Internal error: The current renderer cannot load fonts build with the `EmbedForSoftwareRenderer` option. Please use the software Renderer, or disable that option when building your slint files");
     }
@@ -110,6 +148,15 @@ fn set_rendering_notifier(
         Err(crate::api::SetRenderingNotifierError::Unsupported)
     }
 
+    /// This function is called through the public API to limit the memory of the caches of graphics
+    /// resources. Renderers that don't cache graphics resources ignore the budget.
+    fn set_rendering_cache_budget(&self, _budget: crate::graphics::RenderingCacheBudget) {}
+
+    /// Returns statistics about the caches of graphics resources of the renderer.
+    fn rendering_cache_statistics(&self) -> crate::graphics::RenderingCacheStatistics {
+        Default::default()
+    }
+
     fn default_font_size(&self) -> LogicalLength;
 
     fn set_window_adapter(&self, _window_adapter: &Rc<dyn WindowAdapter>);
@@ -123,4 +170,10 @@ fn resize(&self, _size: crate::api::PhysicalSize) -> Result<(), PlatformError> {
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         Err("WindowAdapter::take_snapshot is not implemented by the platform".into())
     }
+
+    /// Re-implement this function to support Window::render_to_image(), i.e. render the
+    /// current scene into a new image buffer, without presenting it on the screen.
+    fn render_to_image(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        Err("Rendering to an image is not supported by the renderer".into())
+    }
 }
@@ -9,7 +9,9 @@
 #![allow(clippy::result_unit_err)] // We have nothing better to report
 
 pub type FieldOffset<T, U> = const_field_offset::FieldOffset<T, U, const_field_offset::AllowPin>;
+use crate::animations::AnimationControl;
 use crate::items::PropertyAnimation;
+use crate::properties::AnimationCallbacks;
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use core::convert::{TryFrom, TryInto};
@@ -36,6 +38,7 @@ macro_rules! declare_ValueType_2 {
             crate::graphics::Image,
             crate::Color,
             crate::PathData,
+            crate::SharedVector<f32>,
             crate::animations::EasingCurve,
             crate::model::StandardListViewItem,
             crate::model::TableColumn,
@@ -44,9 +47,11 @@ macro_rules! declare_ValueType_2 {
             crate::graphics::Point,
             crate::items::PointerEvent,
             crate::items::PointerScrollEvent,
+            crate::items::DropEvent,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             crate::api::LogicalPosition,
+            crate::items::FontMetrics,
             $(crate::items::$Name,)*
         ];
     };
@@ -59,9 +64,12 @@ pub enum AnimatedBindingKind {
     /// No animation is on the binding
     NotAnimated,
     /// Single animation
-    Animation(PropertyAnimation),
+    Animation(PropertyAnimation, AnimationCallbacks),
     /// Transition
-    Transition(Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant)>),
+    Transition(
+        Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant)>,
+        AnimationCallbacks,
+    ),
 }
 
 impl AnimatedBindingKind {
@@ -69,8 +77,8 @@ impl AnimatedBindingKind {
     pub fn as_animation(self) -> Option<PropertyAnimation> {
         match self {
             AnimatedBindingKind::NotAnimated => None,
-            AnimatedBindingKind::Animation(a) => Some(a),
-            AnimatedBindingKind::Transition(_) => None,
+            AnimatedBindingKind::Animation(a, _) => Some(a),
+            AnimatedBindingKind::Transition(..) => None,
         }
     }
 }
@@ -109,6 +117,10 @@ fn as_property_info(&'static self) -> &'static dyn PropertyInfo<Item, Value>
     /// the property2 must be a pinned pointer to a Property of the same type
     #[allow(unsafe_code)]
     unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ());
+
+    /// Calls Property::control_animation with the property represented here.
+    /// Returns false if the property isn't being animated.
+    fn control_animation(&self, item: Pin<&Item>, control: AnimationControl) -> bool;
 }
 
 impl<Item, T: PartialEq + Clone + 'static, Value: 'static> PropertyInfo<Item, Value>
@@ -159,6 +171,10 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
         let p2 = Pin::new_unchecked((property2 as *const crate::Property<T>).as_ref().unwrap());
         crate::Property::link_two_way(p1, p2);
     }
+
+    fn control_animation(&self, item: Pin<&Item>, control: AnimationControl) -> bool {
+        self.apply_pin(item).control_animation(control)
+    }
 }
 
 /// Wrapper for a field offset that optionally implement PropertyInfo and uses
@@ -212,8 +228,8 @@ fn set_binding_impl<T, Value>(
                     });
                     Ok(())
                 }
-                AnimatedBindingKind::Animation(animation) => {
-                    p.set_animated_binding(
+                AnimatedBindingKind::Animation(animation, callbacks) => {
+                    p.set_animated_binding_with_callbacks(
                         move || {
                             binding()
                                 .try_into()
@@ -221,11 +237,12 @@ fn set_binding_impl<T, Value>(
                                 .expect("binding was of the wrong type")
                         },
                         animation,
+                        callbacks,
                     );
                     Ok(())
                 }
-                AnimatedBindingKind::Transition(tr) => {
-                    p.set_animated_binding_for_transition(
+                AnimatedBindingKind::Transition(tr, callbacks) => {
+                    p.set_animated_binding_for_transition_with_callbacks(
                         move || {
                             binding()
                                 .try_into()
@@ -233,6 +250,7 @@ fn set_binding_impl<T, Value>(
                                 .expect("binding was of the wrong type")
                         },
                         tr,
+                        callbacks,
                     );
                     Ok(())
                 }
@@ -251,6 +269,10 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
         let p2 = Pin::new_unchecked((property2 as *const crate::Property<T>).as_ref().unwrap());
         crate::Property::link_two_way(p1, p2);
     }
+
+    fn control_animation(&self, item: Pin<&Item>, control: AnimationControl) -> bool {
+        self.apply_pin(item).control_animation(control)
+    }
 }
 
 pub trait CallbackInfo<Item, Value> {
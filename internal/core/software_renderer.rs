@@ -18,7 +18,9 @@
     BorderRadius, PixelFormat, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
 };
 use crate::item_rendering::{CachedRenderingData, DirtyRegion, RenderBorderRectangle, RenderImage};
-use crate::items::{ItemRc, TextOverflow, TextWrap};
+use crate::items::{
+    BlendMode, ItemRc, Opacity, RenderingResult, Rotate, TextOverflow, TextStrokeStyle, TextWrap,
+};
 use crate::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
@@ -38,11 +40,21 @@
 use num_traits::Float;
 use num_traits::NumCast;
 
-pub use draw_functions::{PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
+pub use crate::graphics::Rgb565Pixel;
+pub use draw_functions::{PremultipliedRgbaColor, TargetPixel};
 
 type PhysicalLength = euclid::Length<i16, PhysicalPx>;
 type PhysicalRect = euclid::Rect<i16, PhysicalPx>;
 type PhysicalSize = euclid::Size2D<i16, PhysicalPx>;
+
+/// Cache for the layers rendered by items that need one, such as an `Opacity` with several children.
+/// Without `std`, layers are rendered again each time they are drawn.
+#[cfg(feature = "std")]
+type LayerCache = crate::item_rendering::ItemCache<
+    Option<(SharedImageBuffer, euclid::Vector2D<f32, PhysicalPx>)>,
+>;
+#[cfg(not(feature = "std"))]
+type LayerCache = ();
 type PhysicalPoint = euclid::Point2D<i16, PhysicalPx>;
 type PhysicalBorderRadius = BorderRadius<i16, PhysicalPx>;
 
@@ -400,6 +412,9 @@ pub struct SoftwareRenderer {
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
     rotation: Cell<RenderingRotation>,
     rendering_metrics_collector: Option<Rc<RenderingMetricsCollector>>,
+    layer_cache: LayerCache,
+    /// Set when a warning was shown because an image created from an OpenGL texture was drawn.
+    borrowed_opengl_texture_warned: Cell<bool>,
 }
 
 impl Default for SoftwareRenderer {
@@ -413,6 +428,8 @@ fn default() -> Self {
             maybe_window_adapter: Default::default(),
             rotation: Default::default(),
             rendering_metrics_collector: RenderingMetricsCollector::new("software"),
+            layer_cache: Default::default(),
+            borrowed_opengl_texture_warned: Default::default(),
         }
     }
 }
@@ -522,6 +539,8 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             },
             "buffer of size {} with stride {pixel_stride} is too small to handle a window of size {size:?}", buffer.len()
         );
+        #[cfg(feature = "std")]
+        self.layer_cache.clear_cache_if_scale_factor_changed(window.window());
         let buffer_renderer = SceneBuilder::new(
             size,
             factor,
@@ -533,6 +552,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 dirty_region: Default::default(),
             },
             rotation,
+            self,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -672,6 +692,14 @@ fn text_size(
         fonts::text_size(font_request, text, max_width, scale_factor, text_wrap)
     }
 
+    fn font_metrics(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> crate::items::FontMetrics {
+        fonts::font_metrics(font_request, scale_factor)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -797,14 +825,143 @@ fn text_input_cursor_rect_for_byte_offset(
             .cast()
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        pos: LogicalPoint,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let styled_text = text.styled_text();
+        let string =
+            styled_text.as_ref().map_or_else(|| text.text(), |styled| styled.text.as_str().into());
+
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        let max_size = (text.target_size().cast() * scale_factor).cast();
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let pos = (pos.cast() * scale_factor)
+            .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+            .cast();
+
+        match font {
+            fonts::Font::PixelFont(pf) => {
+                let layout = fonts::text_layout_for_font(&pf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+            #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+            fonts::Font::VectorFont(vf) => {
+                let layout = fonts::text_layout_for_font(&vf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+        }
+    }
+
+    fn text_rects_for_byte_range(
+        &self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        byte_range: core::ops::Range<usize>,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect> {
+        let styled_text = text.styled_text();
+        let string =
+            styled_text.as_ref().map_or_else(|| text.text(), |styled| styled.text.as_str().into());
+
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        let max_size = (text.target_size().cast() * scale_factor).cast();
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let rects = match font {
+            fonts::Font::PixelFont(pf) => {
+                let layout = fonts::text_layout_for_font(&pf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_range_rects(byte_range)
+            }
+            #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+            fonts::Font::VectorFont(vf) => {
+                let layout = fonts::text_layout_for_font(&vf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_range_rects(byte_range)
+            }
+        };
+
+        rects
+            .into_iter()
+            .map(|(x, y, width, height)| {
+                (PhysicalRect::new(
+                    PhysicalPoint::from_lengths(x, y),
+                    PhysicalSize::from_lengths(width, height),
+                )
+                .cast()
+                    / scale_factor)
+                    .cast()
+            })
+            .collect()
+    }
+
     fn free_graphics_resources(
         &self,
-        _component: crate::item_tree::ItemTreeRef,
+        #[allow(unused)] component: crate::item_tree::ItemTreeRef,
         items: &mut dyn Iterator<Item = Pin<crate::items::ItemRef<'_>>>,
     ) -> Result<(), crate::platform::PlatformError> {
         for item in items {
             item.cached_rendering_data_offset().release(&mut self.partial_cache.borrow_mut());
         }
+        #[cfg(feature = "std")]
+        self.layer_cache.component_destroyed(component);
         // We don't have a way to determine the screen region of the delete items, what's in the cache is relative. So
         // as a last resort, refresh everything.
         self.force_screen_refresh.set(true);
@@ -822,7 +979,7 @@ fn register_bitmap_font(&self, font_data: &'static crate::graphics::BitmapFont)
     #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
     fn register_font_from_memory(
         &self,
-        data: &'static [u8],
+        data: std::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         self::fonts::systemfonts::register_font_from_memory(data)
     }
@@ -835,6 +992,11 @@ fn register_font_from_path(
         self::fonts::systemfonts::register_font_from_path(path)
     }
 
+    #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+    fn font_families(&self) -> Vec<crate::graphics::FontFamily> {
+        crate::graphics::FontFamily::from_shared_fontdb()
+    }
+
     fn default_font_size(&self) -> LogicalLength {
         self::fonts::DEFAULT_FONT_SIZE
     }
@@ -845,6 +1007,10 @@ fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
     }
 
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        self.render_to_image()
+    }
+
+    fn render_to_image(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         let Some(window_adapter) =
             self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
         else {
@@ -859,15 +1025,20 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
         let Some((width, height)) = size.width.try_into().ok().zip(size.height.try_into().ok())
         else {
             // Nothing to render
-            return Err("take_snapshot() called on window with invalid size".into());
+            return Err("render_to_image() called on window with invalid size".into());
         };
 
         let mut target_buffer = SharedPixelBuffer::<crate::graphics::Rgb8Pixel>::new(width, height);
 
+        let previous_repaint_buffer_type = self.repaint_buffer_type();
+        let previous_rotation = self.rendering_rotation();
         self.set_repaint_buffer_type(RepaintBufferType::NewBuffer);
+        self.set_rendering_rotation(RenderingRotation::NoRotation);
         self.render(target_buffer.make_mut_slice(), width as usize);
-        // ensure that caches are clear for the next call
-        self.set_repaint_buffer_type(RepaintBufferType::NewBuffer);
+        self.set_rendering_rotation(previous_rotation);
+        // This clears the caches if the buffer type differs, so that the next frame is
+        // rendered entirely, as the dirty regions were consumed by this rendering.
+        self.set_repaint_buffer_type(previous_repaint_buffer_type);
 
         let mut target_buffer_with_alpha =
             SharedPixelBuffer::<Rgba8Pixel>::new(target_buffer.width(), target_buffer.height());
@@ -876,7 +1047,7 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
             .iter_mut()
             .zip(target_buffer.as_slice().iter())
         {
-            *target_pixel.rgb_mut() = *source_pixel;
+            *target_pixel = source_pixel.with_alpha(255);
         }
         Ok(target_buffer_with_alpha)
     }
@@ -1340,6 +1511,15 @@ fn as_texture(&self) -> SceneTexture<'_> {
                     extra: self.extra,
                 }
             }
+            SharedBufferData::SharedImage(SharedImageBuffer::Alpha8(b)) => SceneTexture {
+                data: &bytemuck::cast_slice(b.as_slice())[start..end],
+                pixel_stride: stride as u16,
+                format: PixelFormat::AlphaMap,
+                extra: self.extra,
+            },
+            SharedBufferData::SharedImage(
+                SharedImageBuffer::RGB565(_) | SharedImageBuffer::Gray8(_),
+            ) => unreachable!("converted to RGB8 before being added to the scene"),
             SharedBufferData::AlphaMap { data, width } => SceneTexture {
                 data: &data[start..end],
                 pixel_stride: *width,
@@ -1404,12 +1584,17 @@ fn prepare_scene(
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
+    #[cfg(feature = "std")]
+    software_renderer
+        .layer_cache
+        .clear_cache_if_scale_factor_changed(window.window_adapter().window());
     let prepare_scene = SceneBuilder::new(
         size,
         factor,
         window,
         PrepareScene::default(),
         software_renderer.rotation.get(),
+        software_renderer,
     );
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
@@ -1654,13 +1839,96 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
     }
 }
 
+/// Rotates a premultiplied RGBA buffer clockwise by `angle_in_degrees` around the `pivot`, in
+/// pixels of the buffer, with bilinear filtering.
+/// Returns the rotated buffer, which is large enough to contain the whole rotated content, and the
+/// position of its top left corner relative to the pivot.
+fn rotate_premultiplied_buffer(
+    source: &SharedPixelBuffer<Rgba8Pixel>,
+    angle_in_degrees: f32,
+    pivot: euclid::Point2D<f32, PhysicalPx>,
+) -> Option<(SharedPixelBuffer<Rgba8Pixel>, euclid::Vector2D<i32, PhysicalPx>)> {
+    let (sin, cos) = angle_in_degrees.to_radians().sin_cos();
+    let (width, height) = (source.width() as f32, source.height() as f32);
+    let corners = [(0., 0.), (width, 0.), (0., height), (width, height)]
+        .map(|(x, y): (f32, f32)| (x - pivot.x, y - pivot.y))
+        .map(|(x, y)| (x * cos - y * sin, x * sin + y * cos));
+    // Ignore the rounding errors of sin and cos, so that a right angle doesn't add a row of pixels
+    const EPSILON: f32 = 1. / 256.;
+    let min_x = (corners.iter().map(|c| c.0).fold(f32::MAX, f32::min) + EPSILON).floor();
+    let min_y = (corners.iter().map(|c| c.1).fold(f32::MAX, f32::min) + EPSILON).floor();
+    let max_x = (corners.iter().map(|c| c.0).fold(f32::MIN, f32::max) - EPSILON).ceil();
+    let max_y = (corners.iter().map(|c| c.1).fold(f32::MIN, f32::max) - EPSILON).ceil();
+    let (rotated_width, rotated_height) = ((max_x - min_x) as u32, (max_y - min_y) as u32);
+    if rotated_width == 0 || rotated_height == 0 {
+        return None;
+    }
+
+    let source_pixels = source.as_slice();
+    let source_stride = source.width() as i32;
+    let sample = |x: i32, y: i32| {
+        if x < 0 || y < 0 || x >= source_stride || y >= source.height() as i32 {
+            [0.; 4]
+        } else {
+            let p = source_pixels[(y * source_stride + x) as usize];
+            [p.r as f32, p.g as f32, p.b as f32, p.a as f32]
+        }
+    };
+
+    let mut rotated = SharedPixelBuffer::<Rgba8Pixel>::new(rotated_width, rotated_height);
+    for (i, pixel) in rotated.make_mut_slice().iter_mut().enumerate() {
+        // The center of the pixel, relative to the pivot, rotated back into the source buffer
+        let x = (i % rotated_width as usize) as f32 + min_x + 0.5;
+        let y = (i / rotated_width as usize) as f32 + min_y + 0.5;
+        let source_x = x * cos + y * sin + pivot.x - 0.5;
+        let source_y = -x * sin + y * cos + pivot.y - 0.5;
+        let (x0, y0) = (source_x.floor(), source_y.floor());
+        let (fx, fy) = (source_x - x0, source_y - y0);
+        let (x0, y0) = (x0 as i32, y0 as i32);
+        let mut color = [0.; 4];
+        for (x, y, weight) in [
+            (x0, y0, (1. - fx) * (1. - fy)),
+            (x0 + 1, y0, fx * (1. - fy)),
+            (x0, y0 + 1, (1. - fx) * fy),
+            (x0 + 1, y0 + 1, fx * fy),
+        ] {
+            for (c, s) in color.iter_mut().zip(sample(x, y)) {
+                *c += s * weight;
+            }
+        }
+        let [r, g, b, a] = color.map(|c| c.round() as u8);
+        *pixel = Rgba8Pixel { r, g, b, a };
+    }
+    Some((rotated, euclid::vec2(min_x as i32, min_y as i32)))
+}
+
+#[test]
+fn rotate_buffer() {
+    let [r, g, b, w] = [(255, 0, 0), (0, 255, 0), (0, 0, 255), (255, 255, 255)]
+        .map(|(r, g, b)| Rgba8Pixel { r, g, b, a: 255 });
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 2);
+    buffer.make_mut_slice().copy_from_slice(&[r, g, b, w]);
+
+    let (rotated, top_left) =
+        rotate_premultiplied_buffer(&buffer, 90., euclid::point2(1., 1.)).unwrap();
+    assert_eq!((rotated.width(), rotated.height()), (2, 2));
+    assert_eq!(top_left, euclid::vec2(-1, -1));
+    assert_eq!(rotated.as_slice(), &[b, r, w, g]);
+
+    // Rotating by 45° around the top left corner makes the buffer larger, and moves it to the left
+    let (rotated, top_left) =
+        rotate_premultiplied_buffer(&buffer, 45., euclid::point2(0., 0.)).unwrap();
+    assert_eq!((rotated.width(), rotated.height()), (4, 3));
+    assert_eq!(top_left, euclid::vec2(-2, 0));
+}
+
 struct SceneBuilder<'a, T> {
     processor: T,
     state_stack: Vec<RenderState>,
     current_state: RenderState,
-    scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    software_renderer: &'a SoftwareRenderer,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1670,6 +1938,7 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        software_renderer: &'a SoftwareRenderer,
     ) -> Self {
         Self {
             processor,
@@ -1681,10 +1950,130 @@ fn new(
                     LogicalPoint::default(),
                     (screen_size.cast() / scale_factor).cast(),
                 ),
+                scale_factor,
             },
-            scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            software_renderer,
+        }
+    }
+
+    /// Renders the children of `item_rc` into a premultiplied RGBA buffer, or returns the buffer
+    /// from the cache if none of the properties used to render it have changed.
+    ///
+    /// `subpixel_offset` is the fractional part of the physical position of the item, so that the
+    /// content of the layer is aligned with the pixels on the screen.
+    fn render_layer(
+        &self,
+        item_rc: &ItemRc,
+        layer_logical_size_fn: &dyn Fn() -> LogicalSize,
+        subpixel_offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) -> Option<SharedImageBuffer> {
+        let render = || {
+            let size: PhysicalSize = (layer_logical_size_fn().cast()
+                * self.current_state.scale_factor
+                + euclid::Size2D::new(subpixel_offset.x, subpixel_offset.y))
+            .ceil()
+            .try_cast()?;
+            if size.is_empty() {
+                return None;
+            }
+            let mut layer = SharedPixelBuffer::<Rgba8Pixel>::new(size.width as _, size.height as _);
+            let mut layer_renderer = SceneBuilder::new(
+                size,
+                self.current_state.scale_factor,
+                self.window,
+                RenderToBuffer {
+                    buffer: bytemuck::cast_slice_mut::<u8, PremultipliedRgbaColor>(
+                        layer.make_mut_bytes(),
+                    ),
+                    stride: size.width as usize,
+                    dirty_range_cache: vec![],
+                    dirty_region: PhysicalRegion {
+                        rectangles: core::array::from_fn(|i| {
+                            if i == 0 {
+                                PhysicalRect::from_size(size).to_box2d()
+                            } else {
+                                Default::default()
+                            }
+                        }),
+                        count: 1,
+                    },
+                },
+                RenderingRotation::NoRotation,
+                self.software_renderer,
+            );
+            crate::item_rendering::ItemRenderer::translate(
+                &mut layer_renderer,
+                (subpixel_offset / self.current_state.scale_factor).cast(),
+            );
+            // Go through the partial renderer so that the children get their entries in the
+            // partial rendering cache, and are not considered new (and dirty) on every frame.
+            let mut layer_renderer = crate::item_rendering::PartialRenderer::new(
+                &self.software_renderer.partial_cache,
+                LogicalRect::from_size((size.cast() / self.current_state.scale_factor).cast())
+                    .into(),
+                layer_renderer,
+            );
+            crate::item_rendering::render_item_children(
+                &mut layer_renderer,
+                item_rc.item_tree(),
+                item_rc.index() as isize,
+            );
+            drop(layer_renderer);
+            Some((SharedImageBuffer::RGBA8Premultiplied(layer), subpixel_offset))
+        };
+        #[cfg(feature = "std")]
+        {
+            let layer_cache = &self.software_renderer.layer_cache;
+            // The cache only tracks the properties, re-render if the item moved by a fraction of a pixel.
+            if layer_cache
+                .with_entry(item_rc, |layer| Some(layer.as_ref()?.1 != subpixel_offset))
+                .unwrap_or_default()
+            {
+                layer_cache.release(item_rc);
+            }
+            layer_cache.get_or_update_cache_entry(item_rc, render).map(|(layer, _)| layer)
+        }
+        #[cfg(not(feature = "std"))]
+        render().map(|(layer, _)| layer)
+    }
+
+    /// Blends a premultiplied RGBA buffer, whose top left corner is at the `origin` in physical pixels
+    /// on the screen, with the given alpha.
+    fn draw_premultiplied_buffer(
+        &mut self,
+        img: SharedImageBuffer,
+        origin: euclid::Point2D<i16, PhysicalPx>,
+        alpha: f32,
+    ) {
+        let physical_clip: PhysicalRect =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.current_state.scale_factor)
+                .round()
+                .cast();
+        let source_rect = euclid::rect(0, 0, img.width() as _, img.height() as _);
+
+        if let Some(geometry) =
+            source_rect.translate(origin.to_vector()).intersection(&physical_clip)
+        {
+            let clipped_src = geometry.translate(-origin.to_vector());
+            self.processor.process_shared_image_buffer(
+                geometry.transformed(self.rotation),
+                SharedBufferCommand {
+                    buffer: SharedBufferData::SharedImage(img),
+                    source_rect,
+                    extra: SceneTextureExtra {
+                        colorize: Default::default(),
+                        alpha: (alpha * 255.) as u8,
+                        rotation: self.rotation.orientation,
+                        dx: Fixed::from_integer(1),
+                        dy: Fixed::from_integer(1),
+                        off_x: Fixed::from_integer(clipped_src.min_x() as _),
+                        off_y: Fixed::from_integer(clipped_src.min_y() as _),
+                    },
+                },
+            );
         }
     }
 
@@ -1708,12 +2097,12 @@ fn draw_image_impl(
         colorize: Color,
     ) {
         let global_alpha_u16 = (self.current_state.alpha * 255.) as u16;
-        let offset =
-            self.current_state.offset.cast() * self.scale_factor + image_fit_offset.to_vector();
+        let offset = self.current_state.offset.cast() * self.current_state.scale_factor
+            + image_fit_offset.to_vector();
 
         let physical_clip =
             (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
-                * self.scale_factor)
+                * self.current_state.scale_factor)
                 .round()
                 .cast();
 
@@ -1803,12 +2192,24 @@ fn draw_image_impl(
             }
 
             ImageInner::NineSlice(..) => unreachable!(),
+            // GPU textures can't be read by the software renderer, so they are not drawn.
+            #[cfg(not(target_arch = "wasm32"))]
+            ImageInner::BorrowedOpenGLTexture(..) => {
+                if !self.software_renderer.borrowed_opengl_texture_warned.replace(true) {
+                    crate::debug_log!("The software renderer can't draw images created from OpenGL textures. They are only drawn by renderers that use OpenGL.");
+                }
+            }
             _ => {
                 let target_rect = euclid::Rect::new(offset, fit_size).round().cast();
                 let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
                     return;
                 };
                 if let Some(buffer) = image_inner.render_to_buffer(Some(target_rect.size.cast())) {
+                    // Alpha8 is drawn as alpha map, the other compact formats are converted
+                    let buffer = match buffer {
+                        SharedImageBuffer::Alpha8(_) => buffer,
+                        _ => buffer.to_rgb8_or_rgba8().unwrap_or(buffer),
+                    };
                     let buf_size = buffer.size().cast::<f32>();
                     let orig = image_inner.size().cast::<f32>();
                     let dx =
@@ -1871,6 +2272,7 @@ fn draw_text_paragraph<Font>(
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
         selection: Option<SelectionInfo>,
+        styling: TextStyling,
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
@@ -1892,90 +2294,83 @@ fn draw_text_paragraph<Font>(
                                 .process_rectangle(geometry, selection.selection_background.into());
                         }
                     }
+
+                    // The outlines of all glyphs of the line are drawn first, so that they don't
+                    // cover the neighboring glyphs
+                    let mut stroked_glyphs;
+                    let glyphs: &mut dyn Iterator<Item = _> = if let Some(stroke) = &styling.stroke
+                    {
+                        let line_glyphs = glyphs.collect::<Vec<_>>();
+                        for positioned_glyph in &line_glyphs {
+                            let glyph = paragraph
+                                .layout
+                                .font
+                                .render_glyph(positioned_glyph.glyph_id)
+                                .dilated(stroke.radius);
+                            let origin = PhysicalPoint::from_lengths(
+                                line_x + positioned_glyph.x,
+                                baseline_y,
+                            );
+                            self.draw_glyph(&glyph, origin, physical_clip, offset, stroke.color);
+                        }
+                        stroked_glyphs = line_glyphs.into_iter();
+                        &mut stroked_glyphs
+                    } else {
+                        glyphs
+                    };
+
                     for positioned_glyph in glyphs {
-                        let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
+                        let style = styling
+                            .styled_text
+                            .and_then(|styled| styled.style_at(positioned_glyph.text_byte_offset));
+                        if style.is_some_and(|style| style.underline) {
+                            let thickness = (paragraph.layout.font.height().get() / 16).max(1);
+                            let geometry = euclid::rect(
+                                line_x.get() + positioned_glyph.x.get(),
+                                baseline_y.get() + thickness,
+                                positioned_glyph.advance.get(),
+                                thickness,
+                            );
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                let color = style
+                                    .and_then(|style| style.color)
+                                    .map_or(color, |color| self.alpha_color(color));
+                                self.processor.process_rectangle(geometry, color.into());
+                            }
+                        }
 
-                        let src_rect = PhysicalRect::new(
-                            PhysicalPoint::from_lengths(
-                                line_x + positioned_glyph.x + glyph.x,
-                                baseline_y - glyph.y - glyph.height,
-                            ),
-                            glyph.size(),
-                        )
-                        .cast();
+                        let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
+                        let origin =
+                            PhysicalPoint::from_lengths(line_x + positioned_glyph.x, baseline_y);
 
                         let color = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
                                 s.selection_color
                             }
-                            _ => color,
+                            _ => style
+                                .and_then(|style| style.color)
+                                .map_or(color, |color| self.alpha_color(color)),
                         };
 
-                        if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
-                            let geometry = clipped_src.translate(offset).round();
-                            let origin = (geometry.origin - offset.round()).round().cast::<i16>();
-                            let actual_x = (origin.x - src_rect.origin.x as i16) as usize;
-                            let actual_y = (origin.y - src_rect.origin.y as i16) as usize;
-                            let pixel_stride = glyph.width.get() as u16;
-                            let mut geometry = geometry.cast();
-                            if geometry.size.width > glyph.width.get() - (actual_x as i16) {
-                                geometry.size.width = glyph.width.get() - (actual_x as i16)
-                            }
-                            if geometry.size.height > glyph.height.get() - (actual_y as i16) {
-                                geometry.size.height = glyph.height.get() - (actual_y as i16)
-                            }
-                            let source_size = geometry.size;
-                            if source_size.is_empty() {
-                                continue;
-                            }
-                            match &glyph.alpha_map {
-                                fonts::GlyphAlphaMap::Static(data) => {
-                                    self.processor.process_texture(
-                                        geometry.transformed(self.rotation),
-                                        SceneTexture {
-                                            data: &data
-                                                [actual_x + actual_y * pixel_stride as usize..],
-                                            pixel_stride,
-                                            format: PixelFormat::AlphaMap,
-                                            extra: SceneTextureExtra {
-                                                colorize: color,
-                                                // color already is mixed with global alpha
-                                                alpha: color.alpha(),
-                                                rotation: self.rotation.orientation,
-                                                dx: Fixed::from_integer(1),
-                                                dy: Fixed::from_integer(1),
-                                                off_x: Fixed::from_integer(0),
-                                                off_y: Fixed::from_integer(0),
-                                            },
-                                        },
-                                    );
-                                }
-                                fonts::GlyphAlphaMap::Shared(data) => {
-                                    self.processor.process_shared_image_buffer(
-                                        geometry.transformed(self.rotation),
-                                        SharedBufferCommand {
-                                            buffer: SharedBufferData::AlphaMap {
-                                                data: data.clone(),
-                                                width: pixel_stride,
-                                            },
-                                            source_rect: PhysicalRect::new(
-                                                PhysicalPoint::new(actual_x as _, actual_y as _),
-                                                source_size,
-                                            ),
-                                            extra: SceneTextureExtra {
-                                                colorize: color,
-                                                // color already is mixed with global alpha
-                                                alpha: color.alpha(),
-                                                rotation: self.rotation.orientation,
-                                                dx: Fixed::from_integer(1),
-                                                dy: Fixed::from_integer(1),
-                                                off_x: Fixed::from_integer(0),
-                                                off_y: Fixed::from_integer(0),
-                                            },
-                                        },
-                                    );
-                                }
-                            };
+                        self.draw_glyph(&glyph, origin, physical_clip, offset, color);
+
+                        // A centered stroke also covers the edge of the glyph itself
+                        if let Some(stroke) = styling
+                            .stroke
+                            .as_ref()
+                            .filter(|stroke| stroke.style == TextStrokeStyle::Center)
+                        {
+                            let inner_edge = glyph.inner_edge(stroke.radius);
+                            self.draw_glyph(
+                                &inner_edge,
+                                origin,
+                                physical_clip,
+                                offset,
+                                stroke.color,
+                            );
                         }
                     }
                     core::ops::ControlFlow::Continue(())
@@ -1985,6 +2380,90 @@ fn draw_text_paragraph<Font>(
             .ok();
     }
 
+    /// Draws the alpha map of the glyph, where the origin is the pen position on the baseline
+    fn draw_glyph(
+        &mut self,
+        glyph: &fonts::RenderableGlyph,
+        origin: PhysicalPoint,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+        color: Color,
+    ) {
+        let src_rect = PhysicalRect::new(
+            PhysicalPoint::from_lengths(
+                origin.x_length() + glyph.x,
+                origin.y_length() - glyph.y - glyph.height,
+            ),
+            glyph.size(),
+        )
+        .cast();
+
+        let Some(clipped_src) = src_rect.intersection(&physical_clip) else { return };
+        let geometry = clipped_src.translate(offset).round();
+        let origin = (geometry.origin - offset.round()).round().cast::<i16>();
+        let actual_x = (origin.x - src_rect.origin.x as i16) as usize;
+        let actual_y = (origin.y - src_rect.origin.y as i16) as usize;
+        let pixel_stride = glyph.width.get() as u16;
+        let mut geometry = geometry.cast();
+        if geometry.size.width > glyph.width.get() - (actual_x as i16) {
+            geometry.size.width = glyph.width.get() - (actual_x as i16)
+        }
+        if geometry.size.height > glyph.height.get() - (actual_y as i16) {
+            geometry.size.height = glyph.height.get() - (actual_y as i16)
+        }
+        let source_size = geometry.size;
+        if source_size.is_empty() {
+            return;
+        }
+        match &glyph.alpha_map {
+            fonts::GlyphAlphaMap::Static(data) => {
+                self.processor.process_texture(
+                    geometry.transformed(self.rotation),
+                    SceneTexture {
+                        data: &data[actual_x + actual_y * pixel_stride as usize..],
+                        pixel_stride,
+                        format: PixelFormat::AlphaMap,
+                        extra: SceneTextureExtra {
+                            colorize: color,
+                            // color already is mixed with global alpha
+                            alpha: color.alpha(),
+                            rotation: self.rotation.orientation,
+                            dx: Fixed::from_integer(1),
+                            dy: Fixed::from_integer(1),
+                            off_x: Fixed::from_integer(0),
+                            off_y: Fixed::from_integer(0),
+                        },
+                    },
+                );
+            }
+            fonts::GlyphAlphaMap::Shared(data) => {
+                self.processor.process_shared_image_buffer(
+                    geometry.transformed(self.rotation),
+                    SharedBufferCommand {
+                        buffer: SharedBufferData::AlphaMap {
+                            data: data.clone(),
+                            width: pixel_stride,
+                        },
+                        source_rect: PhysicalRect::new(
+                            PhysicalPoint::new(actual_x as _, actual_y as _),
+                            source_size,
+                        ),
+                        extra: SceneTextureExtra {
+                            colorize: color,
+                            // color already is mixed with global alpha
+                            alpha: color.alpha(),
+                            rotation: self.rotation.orientation,
+                            dx: Fixed::from_integer(1),
+                            dy: Fixed::from_integer(1),
+                            off_x: Fixed::from_integer(0),
+                            off_y: Fixed::from_integer(0),
+                        },
+                    },
+                );
+            }
+        };
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -2006,11 +2485,39 @@ struct SelectionInfo {
     selection: core::ops::Range<usize>,
 }
 
+/// The styling of a `Text` in addition to its color
+#[derive(Default)]
+struct TextStyling<'a> {
+    styled_text: Option<&'a crate::textlayout::StyledText>,
+    stroke: Option<TextStroke>,
+}
+
+struct TextStroke {
+    color: Color,
+    /// How far the outline extends from the edge of the glyphs
+    radius: i16,
+    style: TextStrokeStyle,
+}
+
 #[derive(Clone, Copy)]
 struct RenderState {
     alpha: f32,
     offset: LogicalPoint,
     clip: LogicalRect,
+    /// The scale factor of the window, multiplied by the scale of the `Rotate` elements
+    scale_factor: ScaleFactor,
+}
+
+/// The software renderer draws every element with the normal blend mode; warn once when another
+/// one is requested.
+fn warn_unsupported_blend_mode(blend_mode: BlendMode) {
+    static WARNED: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+    if blend_mode != BlendMode::Normal && !WARNED.load(core::sync::atomic::Ordering::Relaxed) {
+        WARNED.store(true, core::sync::atomic::Ordering::Relaxed);
+        crate::debug_log!(
+            "Warning: blend-mode is not supported by the software renderer and is drawn as normal"
+        );
+    }
 }
 
 impl<'a, T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'a, T> {
@@ -2030,10 +2537,12 @@ fn draw_rectangle(
 
             let background = rect.background();
             if let Brush::LinearGradient(g) = background {
-                let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
-                let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
+                let geom2 =
+                    (geom.cast() * self.current_state.scale_factor).transformed(self.rotation);
+                let clipped2 =
+                    (clipped.cast() * self.current_state.scale_factor).transformed(self.rotation);
                 let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
-                    * self.scale_factor)
+                    * self.current_state.scale_factor)
                     .round()
                     .cast()
                     .transformed(self.rotation);
@@ -2131,7 +2640,7 @@ fn draw_rectangle(
                 return;
             }
             let geometry = (clipped.translate(self.current_state.offset.to_vector()).cast()
-                * self.scale_factor)
+                * self.current_state.scale_factor)
                 .round()
                 .cast()
                 .transformed(self.rotation);
@@ -2148,6 +2657,7 @@ fn draw_border_rectangle(
         size: LogicalSize,
         _: &CachedRenderingData,
     ) {
+        warn_unsupported_blend_mode(rect.blend_mode());
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
@@ -2189,16 +2699,19 @@ fn draw_border_rectangle(
                     .min(LogicalBorderRadius::from_length(geom.width_length() / 2 as Coord))
                     .min(LogicalBorderRadius::from_length(geom.height_length() / 2 as Coord));
                 if let Some(clipped) = geom.intersection(&self.current_state.clip) {
-                    let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
-                    let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
+                    let geom2 =
+                        (geom.cast() * self.current_state.scale_factor).transformed(self.rotation);
+                    let clipped2 = (clipped.cast() * self.current_state.scale_factor)
+                        .transformed(self.rotation);
                     let geometry =
                         (clipped.translate(self.current_state.offset.to_vector()).cast()
-                            * self.scale_factor)
+                            * self.current_state.scale_factor)
                             .round()
                             .cast()
                             .transformed(self.rotation);
-                    let radius =
-                        (radius.cast() * self.scale_factor).cast().transformed(self.rotation);
+                    let radius = (radius.cast() * self.current_state.scale_factor)
+                        .cast()
+                        .transformed(self.rotation);
                     // Add a small value to make sure that the clip is always positive despite floating point shenanigans
                     const E: f32 = 0.00001;
 
@@ -2206,7 +2719,7 @@ fn draw_border_rectangle(
                         geometry,
                         RoundedRectangle {
                             radius,
-                            width: (border.cast() * self.scale_factor).cast(),
+                            width: (border.cast() * self.current_state.scale_factor).cast(),
                             border_color,
                             inner_color: color,
                             top_clip: PhysicalLength::new(
@@ -2233,7 +2746,7 @@ fn draw_border_rectangle(
                     .intersection(&self.current_state.clip)
                 {
                     let geometry = (r.translate(self.current_state.offset.to_vector()).cast()
-                        * self.scale_factor)
+                        * self.current_state.scale_factor)
                         .round()
                         .cast()
                         .transformed(self.rotation);
@@ -2246,7 +2759,7 @@ fn draw_border_rectangle(
                 let mut add_border = |r: LogicalRect| {
                     if let Some(r) = r.intersection(&self.current_state.clip) {
                         let geometry = (r.translate(self.current_state.offset.to_vector()).cast()
-                            * self.scale_factor)
+                            * self.current_state.scale_factor)
                             .round()
                             .cast()
                             .transformed(self.rotation);
@@ -2269,6 +2782,7 @@ fn draw_image(
         size: LogicalSize,
         _: &CachedRenderingData,
     ) {
+        warn_unsupported_blend_mode(image.blend_mode());
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
             let source = image.source();
@@ -2280,8 +2794,8 @@ fn draw_image(
                 for fit in crate::graphics::fit9slice(
                     source_size,
                     nine.1,
-                    size.cast() * self.scale_factor,
-                    self.scale_factor,
+                    size.cast() * self.current_state.scale_factor,
+                    self.current_state.scale_factor,
                     image.alignment(),
                     image.tiling(),
                 ) {
@@ -2298,12 +2812,12 @@ fn draw_image(
                 },
             );
 
-            let phys_size = geom.size_length().cast() * self.scale_factor;
+            let phys_size = geom.size_length().cast() * self.current_state.scale_factor;
             let fit = crate::graphics::fit(
                 image.image_fit(),
                 phys_size,
                 source_clip,
-                self.scale_factor,
+                self.current_state.scale_factor,
                 image.alignment(),
                 image.tiling(),
             );
@@ -2318,7 +2832,9 @@ fn draw_text(
         size: LogicalSize,
         _cache: &CachedRenderingData,
     ) {
-        let string = text.text();
+        let styled_text = text.styled_text();
+        let string =
+            styled_text.as_ref().map_or_else(|| text.text(), |styled| styled.text.as_str().into());
         if string.trim().is_empty() {
             return;
         }
@@ -2330,24 +2846,44 @@ fn draw_text(
         let font_request = text.font_request(self.window);
 
         let color = self.alpha_color(text.color().color());
-        let max_size = (geom.size.cast() * self.scale_factor).cast();
+        let max_size = (geom.size.cast() * self.current_state.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
         // of its boundaries (that breaks partial rendering and the cast to usize for the item relative coordinate below).
         // FIXME: we should allow drawing outside of the Text element's boundaries.
         let physical_clip = if let Some(logical_clip) = self.current_state.clip.intersection(&geom)
         {
-            logical_clip.cast() * self.scale_factor
+            logical_clip.cast() * self.current_state.scale_factor
         } else {
             return; // This should have been caught earlier already
         };
-        let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+        let offset = self.current_state.offset.to_vector().cast() * self.current_state.scale_factor;
+
+        let (stroke_brush, stroke_width, stroke_style) = text.stroke();
+        let stroke = (!stroke_brush.is_transparent()).then(|| {
+            let stroke_width: f32 = (stroke_width.cast() * self.current_state.scale_factor).get();
+            let radius = match stroke_style {
+                TextStrokeStyle::Outside => stroke_width,
+                TextStrokeStyle::Center => stroke_width / 2.,
+            };
+            TextStroke {
+                color: self.alpha_color(stroke_brush.color()),
+                // Without a width, the stroke is a hairline of one physical pixel
+                radius: ((radius + 0.5) as i16).max(1),
+                style: stroke_style,
+            }
+        });
+        let styling = TextStyling { styled_text: styled_text.as_ref(), stroke };
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
+        let font = fonts::match_font(&font_request, self.current_state.scale_factor);
 
         match font {
             fonts::Font::PixelFont(pf) => {
-                let layout = fonts::text_layout_for_font(&pf, &font_request, self.scale_factor);
+                let layout = fonts::text_layout_for_font(
+                    &pf,
+                    &font_request,
+                    self.current_state.scale_factor,
+                );
                 let (horizontal_alignment, vertical_alignment) = text.alignment();
 
                 let paragraph = TextParagraphLayout {
@@ -2362,11 +2898,15 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, styling);
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
-                let layout = fonts::text_layout_for_font(&vf, &font_request, self.scale_factor);
+                let layout = fonts::text_layout_for_font(
+                    &vf,
+                    &font_request,
+                    self.current_state.scale_factor,
+                );
                 let (horizontal_alignment, vertical_alignment) = text.alignment();
 
                 let paragraph = TextParagraphLayout {
@@ -2381,7 +2921,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, styling);
             }
         }
     }
@@ -2398,20 +2938,20 @@ fn draw_text_input(
         }
 
         let font_request = text_input.font_request(&self.window.window_adapter());
-        let max_size = (geom.size.cast() * self.scale_factor).cast();
+        let max_size = (geom.size.cast() * self.current_state.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
         // of its boundaries (that breaks partial rendering and the cast to usize for the item relative coordinate below).
         // FIXME: we should allow drawing outside of the Text element's boundaries.
         let physical_clip = if let Some(logical_clip) = self.current_state.clip.intersection(&geom)
         {
-            logical_clip.cast() * self.scale_factor
+            logical_clip.cast() * self.current_state.scale_factor
         } else {
             return; // This should have been caught earlier already
         };
-        let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+        let offset = self.current_state.offset.to_vector().cast() * self.current_state.scale_factor;
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
+        let font = fonts::match_font(&font_request, self.current_state.scale_factor);
 
         let text_visual_representation = text_input.visual_representation(None);
         let color = self.alpha_color(text_visual_representation.text_color.color());
@@ -2423,11 +2963,35 @@ fn draw_text_input(
                 selection: text_visual_representation.selection_range.clone(),
             });
 
+        // The text that the input method is composing is underlined
+        let preedit_text = (!text_visual_representation.preedit_range.is_empty()).then(|| {
+            let text = &text_visual_representation.text;
+            let preedit_range = text_visual_representation.preedit_range.clone();
+            let underline =
+                crate::textlayout::TextSpanStyle { underline: true, ..Default::default() };
+            crate::textlayout::StyledText {
+                text: text.clone(),
+                spans: [
+                    (0..preedit_range.start, Default::default()),
+                    (preedit_range.clone(), underline),
+                    (preedit_range.end..text.len(), Default::default()),
+                ]
+                .into_iter()
+                .filter(|(range, _)| !range.is_empty())
+                .map(|(range, style)| crate::textlayout::TextSpan { range, style })
+                .collect(),
+            }
+        });
+
         let cursor_pos_and_height = match font {
             fonts::Font::PixelFont(pf) => {
                 let paragraph = TextParagraphLayout {
                     string: &text_visual_representation.text,
-                    layout: fonts::text_layout_for_font(&pf, &font_request, self.scale_factor),
+                    layout: fonts::text_layout_for_font(
+                        &pf,
+                        &font_request,
+                        self.current_state.scale_factor,
+                    ),
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -2437,7 +3001,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    TextStyling { styled_text: preedit_text.as_ref(), stroke: None },
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -2447,7 +3018,11 @@ fn draw_text_input(
             fonts::Font::VectorFont(vf) => {
                 let paragraph = TextParagraphLayout {
                     string: &text_visual_representation.text,
-                    layout: fonts::text_layout_for_font(&vf, &font_request, self.scale_factor),
+                    layout: fonts::text_layout_for_font(
+                        &vf,
+                        &font_request,
+                        self.current_state.scale_factor,
+                    ),
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -2457,7 +3032,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    TextStyling { styled_text: preedit_text.as_ref(), stroke: None },
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -2469,7 +3051,8 @@ fn draw_text_input(
             let cursor_rect = PhysicalRect::new(
                 PhysicalPoint::from_lengths(cursor_x, cursor_y),
                 PhysicalSize::from_lengths(
-                    (text_input.text_cursor_width().cast() * self.scale_factor).cast(),
+                    (text_input.text_cursor_width().cast() * self.current_state.scale_factor)
+                        .cast(),
                     cursor_height,
                 ),
             );
@@ -2509,6 +3092,112 @@ fn draw_box_shadow(
         // TODO
     }
 
+    fn visit_rotate(
+        &mut self,
+        rotate: Pin<&Rotate>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let origin =
+            LogicalVector::from_lengths(rotate.rotation_origin_x(), rotate.rotation_origin_y());
+        let angle = rotate.rotation_angle() % 360.;
+        if angle == 0. {
+            // Scaling alone is done by changing the scale factor of the children
+            #[cfg(feature = "std")]
+            self.software_renderer.layer_cache.release(item_rc);
+            self.translate(origin);
+            self.scale(rotate.scale(), rotate.scale());
+            self.translate(-origin);
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        // The scene can only contain axis aligned primitives, so render the children, scaled,
+        // into a layer and draw that layer rotated.
+        let scale = rotate.scale();
+        if scale <= 0. || self.current_state.alpha <= 0.01 {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        }
+        let current_clip = self.current_state.clip;
+        let layer_size = || {
+            // Reading the scale registers a dependency, so that the layer is rendered again when it changes
+            let _ = rotate.scale();
+            crate::properties::evaluate_no_tracking(|| {
+                let geometry = item_rc.geometry();
+                geometry
+                    .union(&crate::item_rendering::item_children_bounding_rect(
+                        item_rc.item_tree(),
+                        item_rc.index() as isize,
+                        &current_clip.union(&geometry),
+                    ))
+                    .size
+            })
+        };
+        let scale_factor = self.current_state.scale_factor;
+        self.current_state.scale_factor = ScaleFactor::new(scale_factor.get() * scale);
+        let layer = self.render_layer(item_rc, &layer_size, Default::default());
+        self.current_state.scale_factor = scale_factor;
+
+        if let Some(SharedImageBuffer::RGBA8Premultiplied(layer)) = layer {
+            let pivot_on_layer = origin.cast::<f32>().to_point() * scale_factor.get() * scale;
+            if let Some((rotated, top_left)) = rotate_premultiplied_buffer(
+                &layer,
+                angle,
+                euclid::point2(pivot_on_layer.x, pivot_on_layer.y),
+            ) {
+                let pivot = ((self.current_state.offset.cast::<f32>() + origin.cast())
+                    * scale_factor.get())
+                .round();
+                let top_left = euclid::point2(pivot.x as i16, pivot.y as i16)
+                    + euclid::vec2(top_left.x as i16, top_left.y as i16);
+                self.draw_premultiplied_buffer(
+                    SharedImageBuffer::RGBA8Premultiplied(rotated),
+                    top_left,
+                    self.current_state.alpha,
+                );
+            }
+        }
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    fn visit_opacity(
+        &mut self,
+        opacity_item: Pin<&Opacity>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let opacity = opacity_item.opacity();
+        if Opacity::need_layer(item_rc, opacity) {
+            // Render the children into a layer first, so that overlapping children are faded as a group.
+            let alpha = self.current_state.alpha * opacity;
+            if alpha > 0.01 {
+                let current_clip = self.current_state.clip;
+                let layer_size = || {
+                    crate::properties::evaluate_no_tracking(|| {
+                        item_rc
+                            .geometry()
+                            .union(&crate::item_rendering::item_children_bounding_rect(
+                                item_rc.item_tree(),
+                                item_rc.index() as isize,
+                                &current_clip,
+                            ))
+                            .size
+                    })
+                };
+                let origin = self.current_state.offset.cast() * self.current_state.scale_factor;
+                let subpixel_offset = origin - origin.floor();
+                if let Some(layer) = self.render_layer(item_rc, &layer_size, subpixel_offset) {
+                    self.draw_premultiplied_buffer(layer, origin.floor().cast(), alpha);
+                }
+            }
+            RenderingResult::ContinueRenderingWithoutChildren
+        } else {
+            self.apply_opacity(opacity);
+            #[cfg(feature = "std")]
+            self.software_renderer.layer_cache.release(item_rc);
+            RenderingResult::ContinueRenderingChildren
+        }
+    }
+
     fn combine_clip(
         &mut self,
         other: LogicalRect,
@@ -2541,8 +3230,23 @@ fn translation(&self) -> LogicalVector {
         self.current_state.offset.to_vector()
     }
 
-    fn rotate(&mut self, _angle_in_degrees: f32) {
-        todo!()
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        // Only reached for angles that don't need a layer, see visit_rotate()
+        debug_assert!(angle_in_degrees % 360. == 0.);
+    }
+
+    /// Only uniform scaling is supported, `y_factor` is ignored.
+    fn scale(&mut self, x_factor: f32, _y_factor: f32) {
+        if x_factor <= 0. {
+            self.current_state.clip = LogicalRect::default();
+            return;
+        }
+        // The offset and the clip stay at the same physical position
+        self.current_state.offset = (self.current_state.offset.cast::<f32>() / x_factor).cast();
+        self.current_state.clip =
+            self.current_state.clip.cast::<f32>().scale(1. / x_factor, 1. / x_factor).cast();
+        self.current_state.scale_factor =
+            ScaleFactor::new(self.current_state.scale_factor.get() * x_factor);
     }
 
     fn apply_opacity(&mut self, opacity: f32) {
@@ -2558,7 +3262,7 @@ fn restore_state(&mut self) {
     }
 
     fn scale_factor(&self) -> f32 {
-        self.scale_factor.0
+        self.current_state.scale_factor.0
     }
 
     fn draw_cached_pixmap(
@@ -2571,45 +3275,24 @@ fn draw_cached_pixmap(
             let img = SharedImageBuffer::RGBA8Premultiplied(SharedPixelBuffer::clone_from_slice(
                 data, width, height,
             ));
-
-            let physical_clip = (self.current_state.clip.cast() * self.scale_factor).cast();
-            let source_rect = euclid::rect(0, 0, width as _, height as _);
-
-            if let Some(clipped_src) = source_rect.intersection(&physical_clip) {
-                let geometry = clipped_src
-                    .translate(
-                        (self.current_state.offset.cast() * self.scale_factor).to_vector().cast(),
-                    )
-                    .round_in();
-
-                self.processor.process_shared_image_buffer(
-                    geometry.cast().transformed(self.rotation),
-                    SharedBufferCommand {
-                        buffer: SharedBufferData::SharedImage(img),
-                        source_rect,
-                        extra: SceneTextureExtra {
-                            colorize: Default::default(),
-                            alpha: (self.current_state.alpha * 255.) as u8,
-                            rotation: self.rotation.orientation,
-                            dx: Fixed::from_integer(1),
-                            dy: Fixed::from_integer(1),
-                            off_x: Fixed::from_integer(clipped_src.min_x() as _),
-                            off_y: Fixed::from_integer(clipped_src.min_y() as _),
-                        },
-                    },
-                );
-            }
+            let origin =
+                (self.current_state.offset.cast() * self.current_state.scale_factor).round().cast();
+            self.draw_premultiplied_buffer(img, origin, self.current_state.alpha);
         });
     }
 
     fn draw_string(&mut self, string: &str, color: Color) {
         let font_request = Default::default();
-        let font = fonts::match_font(&font_request, self.scale_factor);
-        let clip = self.current_state.clip.cast() * self.scale_factor;
+        let font = fonts::match_font(&font_request, self.current_state.scale_factor);
+        let clip = self.current_state.clip.cast() * self.current_state.scale_factor;
 
         match font {
             fonts::Font::PixelFont(pf) => {
-                let layout = fonts::text_layout_for_font(&pf, &font_request, self.scale_factor);
+                let layout = fonts::text_layout_for_font(
+                    &pf,
+                    &font_request,
+                    self.current_state.scale_factor,
+                );
 
                 let paragraph = TextParagraphLayout {
                     string,
@@ -2623,11 +3306,22 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    color,
+                    None,
+                    Default::default(),
+                );
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
-                let layout = fonts::text_layout_for_font(&vf, &font_request, self.scale_factor);
+                let layout = fonts::text_layout_for_font(
+                    &vf,
+                    &font_request,
+                    self.current_state.scale_factor,
+                );
 
                 let paragraph = TextParagraphLayout {
                     string,
@@ -2641,7 +3335,14 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    color,
+                    None,
+                    Default::default(),
+                );
             }
         }
     }
@@ -13,7 +13,7 @@
 use crate::graphics::{BitmapFont, FontRequest};
 use crate::items::TextWrap;
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
-use crate::textlayout::TextLayout;
+use crate::textlayout::{FontMetrics, TextLayout};
 use crate::Coord;
 
 thread_local! {
@@ -35,10 +35,82 @@ pub struct RenderableGlyph {
     pub alpha_map: GlyphAlphaMap,
 }
 
+impl GlyphAlphaMap {
+    fn data(&self) -> &[u8] {
+        match self {
+            GlyphAlphaMap::Static(data) => data,
+            GlyphAlphaMap::Shared(data) => data,
+        }
+    }
+}
+
 impl RenderableGlyph {
     pub fn size(&self) -> PhysicalSize {
         PhysicalSize::from_lengths(self.width, self.height)
     }
+
+    /// Returns the glyph grown by the radius in all directions, which is drawn beneath the
+    /// glyph as its outline.
+    pub fn dilated(&self, radius: i16) -> RenderableGlyph {
+        let alpha_map = self.morphology(radius, true);
+        RenderableGlyph {
+            x: self.x - PhysicalLength::new(radius),
+            y: self.y - PhysicalLength::new(radius),
+            width: self.width + PhysicalLength::new(2 * radius),
+            height: self.height + PhysicalLength::new(2 * radius),
+            alpha_map: GlyphAlphaMap::Shared(alpha_map.into()),
+        }
+    }
+
+    /// Returns the part of the glyph that is closer than the radius to its edge.
+    pub fn inner_edge(&self, radius: i16) -> RenderableGlyph {
+        let eroded = self.morphology(radius, false);
+        let alpha_map = self
+            .alpha_map
+            .data()
+            .iter()
+            .zip(eroded)
+            .map(|(alpha, eroded)| alpha.saturating_sub(eroded))
+            .collect::<Vec<_>>();
+        RenderableGlyph { alpha_map: GlyphAlphaMap::Shared(alpha_map.into()), ..self.clone() }
+    }
+
+    /// Returns the alpha map with each pixel set to the maximum (when growing) or minimum alpha
+    /// within the radius. When growing, the alpha map gets a margin of the radius on all sides.
+    fn morphology(&self, radius: i16, grow: bool) -> Vec<u8> {
+        let (width, height) = (self.width.get() as isize, self.height.get() as isize);
+        let radius = radius as isize;
+        let margin = if grow { radius } else { 0 };
+        let data = self.alpha_map.data();
+        let alpha = |x: isize, y: isize| {
+            if x < 0 || y < 0 || x >= width || y >= height {
+                0
+            } else {
+                data[(y * width + x) as usize]
+            }
+        };
+        let mut result =
+            Vec::with_capacity(((width + 2 * margin) * (height + 2 * margin)) as usize);
+        for y in -margin..height + margin {
+            for x in -margin..width + margin {
+                let mut value = if grow { 0 } else { u8::MAX };
+                for dy in -radius..=radius {
+                    for dx in -radius..=radius {
+                        if dx * dx + dy * dy > radius * radius + radius {
+                            continue;
+                        }
+                        value = if grow {
+                            value.max(alpha(x + dx, y + dy))
+                        } else {
+                            value.min(alpha(x + dx, y + dy))
+                        };
+                    }
+                }
+                result.push(value);
+            }
+        }
+        result
+    }
 }
 
 pub trait GlyphRenderer {
@@ -76,7 +148,8 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
                 .filter(|bitmap_font| {
                     core::str::from_utf8(bitmap_font.family_name.as_slice()).unwrap()
                         == requested_family.as_str()
-                        && bitmap_font.italic == request.italic
+                        && bitmap_font.italic
+                            == (request.style != crate::graphics::FontStyle::Normal)
                 })
                 .min_by_key(|bitmap_font| bitmap_font.weight.abs_diff(requested_weight))
                 .copied()
@@ -95,7 +168,9 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
                 fonts
                     .iter()
                     .cloned()
-                    .filter(|bitmap_font| bitmap_font.italic == request.italic)
+                    .filter(|bitmap_font| {
+                        bitmap_font.italic == (request.style != crate::graphics::FontStyle::Normal)
+                    })
                     .min_by_key(|bitmap_font| bitmap_font.weight.abs_diff(requested_weight))
                     .or_else(|| fonts.first().cloned())
             }) {
@@ -135,8 +210,32 @@ pub fn text_layout_for_font<'a, Font>(
 {
     let letter_spacing =
         font_request.letter_spacing.map(|spacing| (spacing.cast() * scale_factor).cast());
+    let word_spacing =
+        font_request.word_spacing.map(|spacing| (spacing.cast() * scale_factor).cast());
+
+    let tab_stops = crate::textlayout::TabStops {
+        positions: font_request
+            .tab_stops
+            .iter()
+            .map(|position| (position.cast() * scale_factor).cast())
+            .collect(),
+        interval: font_request
+            .tab_width
+            .filter(|width| width.get() > 0 as Coord)
+            .map(|width| (width.cast() * scale_factor).cast()),
+    };
 
-    TextLayout { font, letter_spacing }
+    let scale = scale_factor.get();
+    let line_spacing = crate::textlayout::LineSpacing {
+        line_height: font_request
+            .physical_line_height(font.height().get() as f32, scale)
+            .map(|line_height| PhysicalLength::new(line_height.round() as _)),
+        paragraph_spacing: font_request
+            .physical_paragraph_spacing(scale)
+            .map(|spacing| PhysicalLength::new(spacing.round() as _)),
+    };
+
+    TextLayout { font, letter_spacing, word_spacing, tab_stops, line_spacing }
 }
 
 pub fn register_bitmap_font(font_data: &'static BitmapFont) {
@@ -173,3 +272,27 @@ pub fn text_size(
 
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
+
+pub fn font_metrics(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+) -> crate::items::FontMetrics {
+    fn to_logical(
+        metrics: &impl FontMetrics<PhysicalLength>,
+        scale_factor: ScaleFactor,
+    ) -> crate::items::FontMetrics {
+        let to_logical = |length: PhysicalLength| (length.cast() / scale_factor).get();
+        crate::items::FontMetrics {
+            ascent: to_logical(metrics.ascent()),
+            descent: to_logical(metrics.descent()),
+            x_height: to_logical(metrics.x_height()),
+            cap_height: to_logical(metrics.cap_height()),
+        }
+    }
+
+    match match_font(&font_request, scale_factor) {
+        Font::PixelFont(pf) => to_logical(&pf, scale_factor),
+        #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+        Font::VectorFont(vf) => to_logical(&vf, scale_factor),
+    }
+}
@@ -16,6 +16,12 @@ fn ascent(&self, font: &BitmapFont) -> PhysicalLength {
     fn descent(&self, font: &BitmapFont) -> PhysicalLength {
         (PhysicalLength::new(self.pixel_size).cast() * font.descent / font.units_per_em).cast()
     }
+    fn x_height(&self, font: &BitmapFont) -> PhysicalLength {
+        (PhysicalLength::new(self.pixel_size).cast() * font.x_height / font.units_per_em).cast()
+    }
+    fn cap_height(&self, font: &BitmapFont) -> PhysicalLength {
+        (PhysicalLength::new(self.pixel_size).cast() * font.cap_height / font.units_per_em).cast()
+    }
     fn height(&self, font: &BitmapFont) -> PhysicalLength {
         // The descent is negative (relative to the baseline)
         (PhysicalLength::new(self.pixel_size).cast() * (font.ascent - font.descent)
@@ -127,4 +133,12 @@ fn descent(&self) -> PhysicalLength {
     fn height(&self) -> PhysicalLength {
         self.glyphs.height(self.bitmap_font)
     }
+
+    fn x_height(&self) -> PhysicalLength {
+        self.glyphs.x_height(self.bitmap_font)
+    }
+
+    fn cap_height(&self) -> PhysicalLength {
+        self.glyphs.cap_height(self.bitmap_font)
+    }
 }
@@ -45,14 +45,15 @@ pub fn match_font(
     request: &super::FontRequest,
     scale_factor: super::ScaleFactor,
 ) -> Option<VectorFont> {
-    request.family.as_ref().and_then(|family_str| {
-        let query = request.to_fontdb_query();
+    let query = request.to_fontdb_query();
 
-        let requested_pixel_size: PhysicalLength =
-            (request.pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
+    let requested_pixel_size: PhysicalLength =
+        (request.pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
 
-        sharedfontdb::FONT_DB.with(|fonts| {
-            let borrowed_fontdb = fonts.borrow();
+    // Glyphs are not looked up in other fonts, so the first of the families that exists is used.
+    sharedfontdb::FONT_DB.with(|fonts| {
+        let borrowed_fontdb = fonts.borrow();
+        request.family.iter().chain(request.fallback_families.iter()).find_map(|family_str| {
             borrowed_fontdb.query_with_family(query, Some(family_str)).map(|font_id| {
                 let fontdue_font = get_or_create_fontdue_font(&borrowed_fontdb, font_id);
                 VectorFont::new(font_id, fontdue_font.clone(), requested_pixel_size)
@@ -77,10 +78,15 @@ pub fn fallbackfont(font_request: &super::FontRequest, scale_factor: ScaleFactor
     })
 }
 
-pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    sharedfontdb::FONT_DB.with_borrow_mut(|fonts| {
+pub fn register_font_from_memory(
+    data: std::borrow::Cow<'static, [u8]>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let face_ids = sharedfontdb::FONT_DB.with_borrow_mut(|fonts| {
         fonts.make_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
     });
+    if face_ids.is_empty() {
+        return Err("The data doesn't contain any font faces".into());
+    }
     Ok(())
 }
 
@@ -50,6 +50,8 @@ pub struct VectorFont {
     ascender: PhysicalLength,
     descender: PhysicalLength,
     height: PhysicalLength,
+    x_height: PhysicalLength,
+    cap_height: PhysicalLength,
     scale: FontScaleFactor,
     pixel_size: PhysicalLength,
 }
@@ -68,6 +70,9 @@ pub fn new(
                     let ascender = FontLength::new(face.ascender() as _);
                     let descender = FontLength::new(face.descender() as _);
                     let height = FontLength::new(face.height() as _);
+                    let x_height = FontLength::new(face.x_height().unwrap_or_default() as _);
+                    let cap_height =
+                        FontLength::new(face.capital_height().unwrap_or_default() as _);
                     let units_per_em = face.units_per_em();
                     let scale = FontScaleFactor::new(pixel_size.get() as f32 / units_per_em as f32);
                     Self {
@@ -76,6 +81,8 @@ pub fn new(
                         ascender: (ascender.cast() * scale).cast(),
                         descender: (descender.cast() * scale).cast(),
                         height: (height.cast() * scale).cast(),
+                        x_height: (x_height.cast() * scale).cast(),
+                        cap_height: (cap_height.cast() * scale).cast(),
                         scale,
                         pixel_size,
                     }
@@ -173,6 +180,14 @@ fn height(&self) -> PhysicalLength {
     fn descent(&self) -> PhysicalLength {
         self.descender
     }
+
+    fn x_height(&self) -> PhysicalLength {
+        self.x_height
+    }
+
+    fn cap_height(&self) -> PhysicalLength {
+        self.cap_height
+    }
 }
 
 impl super::GlyphRenderer for VectorFont {
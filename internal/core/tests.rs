@@ -16,7 +16,7 @@
 #[no_mangle]
 pub extern "C" fn slint_mock_elapsed_time(time_in_ms: u64) {
     let tick = crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
-        let mut tick = driver.current_tick();
+        let mut tick = driver.real_tick();
         tick += core::time::Duration::from_millis(time_in_ms);
         driver.update_animations(tick);
         tick
@@ -28,7 +28,7 @@ pub extern "C" fn slint_mock_elapsed_time(time_in_ms: u64) {
 /// Return the current mocked time.
 #[no_mangle]
 pub extern "C" fn slint_get_mocked_time() -> u64 {
-    crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.current_tick()).as_millis()
+    crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.real_tick()).as_millis()
 }
 
 /// Simulate a click on a position within the component.
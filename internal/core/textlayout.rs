@@ -24,6 +24,7 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+use auto_enums::auto_enum;
 use euclid::num::{One, Zero};
 
 use crate::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
@@ -38,9 +39,14 @@
 #[cfg(not(feature = "unicode-linebreak"))]
 use linebreak_simple::{BreakOpportunity, LineBreakIterator};
 
+mod bidi;
+use bidi::BidiParagraphs;
+pub use bidi::{is_rtl_at, is_rtl_paragraph};
 mod fragments;
 mod glyphclusters;
+mod markup;
 mod shaping;
+pub use markup::{parse_markup, StyledText, TextSpan, TextSpanStyle};
 use shaping::ShapeBuffer;
 pub use shaping::{AbstractFont, FontMetrics, Glyph, TextShaper};
 
@@ -52,9 +58,85 @@
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
+    pub word_spacing: Option<<Font as TextShaper>::Length>,
+    pub tab_stops: TabStops<<Font as TextShaper>::Length>,
+    pub line_spacing: LineSpacing<<Font as TextShaper>::Length>,
+}
+
+/// The vertical distances between the lines of the text.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LineSpacing<Length> {
+    /// The distance between the tops of consecutive lines. If None, this is the height of the font.
+    /// The difference to the height of the font is split evenly above and below the glyphs.
+    pub line_height: Option<Length>,
+    /// The additional distance before a line that follows a line break in the text.
+    pub paragraph_spacing: Option<Length>,
+}
+
+/// The positions where the text continues after a tab character, from the start of the line.
+#[derive(Clone, Debug, Default)]
+pub struct TabStops<Length> {
+    /// The positions of the explicit tab stops, in increasing order
+    pub positions: Vec<Length>,
+    /// The distance between the tab stops that follow the explicit ones, which are at multiples
+    /// of it. If None, this is eight times the advance of a space.
+    pub interval: Option<Length>,
+}
+
+impl<Length: Copy + PartialOrd + Zero + core::ops::Add<Output = Length>> TabStops<Length> {
+    /// Returns the first tab stop after `x`, with `default_interval` if there is no `interval`.
+    /// Returns `x` if there is no tab stop after it.
+    pub fn next_stop(&self, x: Length, default_interval: Length) -> Length {
+        if let Some(position) = self.positions.iter().find(|position| **position > x) {
+            return *position;
+        }
+        let interval = self.interval.unwrap_or(default_interval);
+        if interval > Length::zero() {
+            let mut stop = Length::zero();
+            while stop <= x {
+                stop = stop + interval;
+            }
+            stop
+        } else {
+            x
+        }
+    }
+}
+
+/// Returns true if the character separates words, and gets the word spacing added to its advance.
+pub fn is_word_separator(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}')
 }
 
 impl<'a, Font: AbstractFont> TextLayout<'a, Font> {
+    /// The distance between the tops of consecutive lines.
+    pub fn line_height(&self) -> Font::Length {
+        self.line_spacing.line_height.unwrap_or_else(|| self.font.height())
+    }
+
+    /// Returns the distance from the top of `line` to the top of the line that follows it.
+    fn line_advance(&self, text: &str, line: &TextLine<Font::Length>) -> Font::Length {
+        match self.line_spacing.paragraph_spacing {
+            Some(spacing) if line.ends_paragraph(text) => self.line_height() + spacing,
+            _ => self.line_height(),
+        }
+    }
+
+    /// Returns the number of lines whose glyphs fit into `max_height`.
+    fn max_lines(&self, max_height: Font::Length) -> usize {
+        let line_height = self.line_height();
+        if self.line_spacing.line_height.is_none() || line_height <= Font::Length::zero() {
+            return self.font.max_lines(max_height);
+        }
+        let mut lines = 0;
+        let mut bottom = self.font.height();
+        while bottom <= max_height {
+            lines += 1;
+            bottom = bottom + line_height;
+        }
+        lines
+    }
+
     // Measures the size of the given text when rendered with the specified font and optionally constrained
     // by the provided `max_width`.
     // Returns a tuple of the width of the longest line as well as height of all lines.
@@ -68,15 +150,22 @@ pub fn text_size(
         Font::Length: core::fmt::Debug,
     {
         let mut max_line_width = Font::Length::zero();
-        let mut line_count: i16 = 0;
+        let mut height = Font::Length::zero();
+        let mut previous_line_advance = None;
         let shape_buffer = ShapeBuffer::new(self, text);
 
         for line in TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap) {
             max_line_width = euclid::approxord::max(max_line_width, line.text_width);
-            line_count += 1;
+            if let Some(advance) = previous_line_advance.replace(self.line_advance(text, &line)) {
+                height += advance;
+            }
+        }
+        if previous_line_advance.is_some() {
+            // The last line has no paragraph spacing after it
+            height += self.line_height();
         }
 
-        (max_line_width, self.font.height() * line_count.into())
+        (max_line_width, height)
     }
 }
 
@@ -116,7 +205,7 @@ pub fn layout_lines<R>(
         selection: Option<core::ops::Range<usize>>,
     ) -> Result<Font::Length, R> {
         let wrap = self.wrap != TextWrap::NoWrap;
-        let elide = self.overflow == TextOverflow::Elide;
+        let elide = self.overflow != TextOverflow::Clip;
         let elide_glyph = if elide {
             self.layout.font.glyph_for_char('…').filter(|glyph| glyph.glyph_id.is_some())
         } else {
@@ -126,13 +215,15 @@ pub fn layout_lines<R>(
         let max_width_without_elision = self.max_width - elide_width;
 
         let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
+        let bidi = BidiParagraphs::new(self.string);
 
+        let max_lines = elide.then(|| self.layout.max_lines(self.max_height));
         let new_line_break_iter = || {
             TextLineBreaker::<Font>::new(
                 self.string,
                 &shape_buffer,
                 if wrap { Some(self.max_width) } else { None },
-                if elide { Some(self.layout.font.max_lines(self.max_height)) } else { None },
+                max_lines,
                 self.wrap,
             )
         };
@@ -140,14 +231,24 @@ pub fn layout_lines<R>(
 
         let mut text_height = || {
             if self.single_line {
-                self.layout.font.height()
+                self.layout.line_height()
             } else {
-                text_lines = Some(new_line_break_iter().collect::<Vec<_>>());
-                self.layout.font.height() * (text_lines.as_ref().unwrap().len() as i16).into()
+                let lines = new_line_break_iter().collect::<Vec<_>>();
+                let mut height = Font::Length::zero();
+                if let Some((_, previous_lines)) = lines.split_last() {
+                    for line in previous_lines {
+                        height += self.layout.line_advance(self.string, line);
+                    }
+                    height += self.layout.line_height();
+                }
+                text_lines = Some(lines);
+                height
             }
         };
 
         let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        // The glyphs are centered vertically in the line
+        let half_leading = (self.layout.line_height() - self.layout.font.height()) / two;
 
         let baseline_y = match self.vertical_alignment {
             TextVerticalAlignment::Top => Font::Length::zero(),
@@ -156,18 +257,73 @@ pub fn layout_lines<R>(
         };
 
         let mut y = baseline_y;
+        let mut line_index = 0;
 
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
+            let visual_order = bidi.visual_glyph_order(
+                line.byte_range.start..line.byte_range.end + line.trailing_whitespace_bytes,
+                line.glyph_range.clone(),
+                glyphs,
+            );
+            let line_glyphs = || {
+                glyphs_in_visual_order(glyphs, line.glyph_range.clone(), visual_order.as_deref())
+            };
+
             let elide_long_line =
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
-            let elide_last_line = elide
-                && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+            // The line is the last that fits, but there are more glyphs to show
+            let elide_last_line = line.glyph_range.end < glyphs.len()
+                && max_lines.is_some_and(|max_lines| line_index + 1 >= max_lines);
+
+            // When eliding at the left or in the middle, the glyphs at both ends of the line are kept and
+            // this is the range of the glyphs (in visual order) that are replaced, and the width of the kept ones.
+            let elided_glyphs = (elide_long_line
+                && !elide_last_line
+                && self.overflow != TextOverflow::Elide)
+                .then(|| {
+                    let start_width = if self.overflow == TextOverflow::ElideMiddle {
+                        max_width_without_elision / two
+                    } else {
+                        Font::Length::zero()
+                    };
+                    let mut kept_start_width = Font::Length::zero();
+                    let mut total_width = Font::Length::zero();
+                    let mut start = None;
+                    for (index, glyph) in line_glyphs().enumerate() {
+                        if start.is_none() && kept_start_width + glyph.advance > start_width {
+                            start = Some(index);
+                        }
+                        if start.is_none() {
+                            kept_start_width += glyph.advance;
+                        }
+                        total_width += glyph.advance;
+                    }
+                    let start = start.unwrap_or(line.glyph_range.len());
+
+                    let mut end = line.glyph_range.len();
+                    let mut kept_end_width = Font::Length::zero();
+                    let mut glyph_x = Font::Length::zero();
+                    for (index, glyph) in line_glyphs().enumerate() {
+                        if index > start
+                            && kept_start_width + total_width - glyph_x <= max_width_without_elision
+                        {
+                            end = index;
+                            kept_end_width = total_width - glyph_x;
+                            break;
+                        }
+                        glyph_x += glyph.advance;
+                    }
+                    (start..end, kept_start_width + kept_end_width)
+                });
+            let elide_long_line = elide_long_line && elided_glyphs.is_none();
 
             let text_width = || {
+                if let Some((_, kept_width)) = &elided_glyphs {
+                    return *kept_width + elide_width;
+                }
                 if elide_long_line || elide_last_line {
                     let mut text_width = Font::Length::zero();
-                    for glyph in &glyphs[line.glyph_range.clone()] {
+                    for glyph in line_glyphs() {
                         if text_width + glyph.advance > max_width_without_elision {
                             break;
                         }
@@ -178,10 +334,13 @@ pub fn layout_lines<R>(
                 euclid::approxord::min(self.max_width, line.text_width)
             };
 
-            let x = match self.horizontal_alignment {
-                TextHorizontalAlignment::Left => Font::Length::zero(),
+            let x = match self
+                .horizontal_alignment
+                .resolve(bidi.is_rtl_paragraph_at(line.byte_range.start))
+            {
                 TextHorizontalAlignment::Center => self.max_width / two - text_width() / two,
                 TextHorizontalAlignment::Right => self.max_width - text_width(),
+                _ => Font::Length::zero(),
             };
 
             let mut elide_glyph = elide_glyph.as_ref();
@@ -192,31 +351,45 @@ pub fn layout_lines<R>(
                     line.byte_range.start < selection.end && selection.start < line.byte_range.end
                 })
                 .map(|selection| {
-                    let mut begin = Font::Length::zero();
-                    let mut end = Font::Length::zero();
-                    for glyph in glyphs[line.glyph_range.clone()].iter() {
-                        if glyph.text_byte_offset < selection.start {
-                            begin += glyph.advance;
+                    // With mixed directions, this is the range from the leftmost to the rightmost selected glyph
+                    let mut selection_x: Option<core::ops::Range<Font::Length>> = None;
+                    let mut glyph_x = Font::Length::zero();
+                    for glyph in line_glyphs() {
+                        if selection.contains(&glyph.text_byte_offset) {
+                            let start = selection_x.map_or(glyph_x, |range| range.start);
+                            selection_x = Some(start..glyph_x + glyph.advance);
                         }
-                        if glyph.text_byte_offset >= selection.end {
-                            break;
-                        }
-                        end += glyph.advance;
+                        glyph_x += glyph.advance;
                     }
-                    begin..end
+                    selection_x.unwrap_or_default()
                 });
 
-            let glyph_it = glyphs[line.glyph_range.clone()].iter();
+            let glyph_count = line.glyph_range.len();
             let mut glyph_x = Font::Length::zero();
-            let mut positioned_glyph_it = glyph_it.enumerate().filter_map(|(index, glyph)| {
+            let mut positioned_glyph_it = line_glyphs().enumerate().filter_map(|(index, glyph)| {
                 // TODO: cut off at grapheme boundaries
-                if glyph_x > self.max_width {
+                if let Some((elided, _)) = &elided_glyphs {
+                    if index == elided.start {
+                        return elide_glyph.take().map(|elide_glyph| {
+                            let x = glyph_x;
+                            glyph_x += elide_glyph.advance;
+                            PositionedGlyph {
+                                x,
+                                y: Font::Length::zero(),
+                                advance: elide_glyph.advance,
+                                glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
+                                text_byte_offset: glyph.text_byte_offset,
+                            }
+                        });
+                    } else if elided.contains(&index) {
+                        return None;
+                    }
+                } else if glyph_x > self.max_width {
                     return None;
                 }
                 let elide_long_line = (elide_long_line || elide_last_line)
                     && x + glyph_x + glyph.advance > max_width_without_elision;
-                let elide_last_line =
-                    elide_last_line && line.glyph_range.start + index == line.glyph_range.end - 1;
+                let elide_last_line = elide_last_line && index == glyph_count - 1;
                 if elide_long_line || elide_last_line {
                     if let Some(elide_glyph) = elide_glyph.take() {
                         let x = glyph_x;
@@ -245,11 +418,12 @@ pub fn layout_lines<R>(
             });
 
             if let core::ops::ControlFlow::Break(break_val) =
-                line_callback(&mut positioned_glyph_it, x, y, line, selection)
+                line_callback(&mut positioned_glyph_it, x, y + half_leading, line, selection)
             {
                 return core::ops::ControlFlow::Break(break_val);
             }
-            y += self.layout.font.height();
+            y += self.layout.line_advance(self.string, line);
+            line_index += 1;
 
             core::ops::ControlFlow::Continue(())
         };
@@ -272,39 +446,59 @@ pub fn layout_lines<R>(
             }
         }
 
-        Ok(baseline_y)
+        Ok(baseline_y + half_leading)
     }
 
     /// Returns the leading edge of the glyph at the given byte offset
     pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, Font::Length) {
-        let mut last_glyph_right_edge = Font::Length::zero();
+        let bidi = BidiParagraphs::new(self.string);
+        let rtl = bidi.is_rtl_at(byte_offset);
+        let mut last_line_end_x = Font::Length::zero();
         let mut last_line_y = Font::Length::zero();
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                last_glyph_right_edge = euclid::approxord::min(
-                    self.max_width,
-                    line_x + line.width_including_trailing_whitespace(),
-                );
+                last_line_end_x = if bidi.is_rtl_paragraph_at(line.byte_range.start) {
+                    line_x
+                } else {
+                    euclid::approxord::min(
+                        self.max_width,
+                        line_x + line.width_including_trailing_whitespace(),
+                    )
+                };
                 last_line_y = line_y;
                 if byte_offset >= line.byte_range.end + line.trailing_whitespace_bytes {
                     return core::ops::ControlFlow::Continue(());
                 }
 
+                // The leading edge of right-to-left glyphs is their right edge, which is the
+                // right edge of the last glyph of the cluster.
+                let mut rtl_leading_edge = None;
                 for positioned_glyph in glyphs {
                     if positioned_glyph.text_byte_offset == byte_offset {
-                        return core::ops::ControlFlow::Break((
-                            euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
-                            last_line_y,
-                        ));
+                        if !rtl {
+                            return core::ops::ControlFlow::Break((
+                                euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
+                                last_line_y,
+                            ));
+                        }
+                        rtl_leading_edge = Some(positioned_glyph.x + positioned_glyph.advance);
+                    } else if rtl_leading_edge.is_some() {
+                        break;
                     }
                 }
+                if let Some(edge) = rtl_leading_edge {
+                    return core::ops::ControlFlow::Break((
+                        euclid::approxord::min(self.max_width, line_x + edge),
+                        last_line_y,
+                    ));
+                }
 
-                core::ops::ControlFlow::Break((last_glyph_right_edge, last_line_y))
+                core::ops::ControlFlow::Break((last_line_end_x, last_line_y))
             },
             None,
         ) {
-            Ok(_) => (last_glyph_right_edge, last_line_y),
+            Ok(_) => (last_line_end_x, last_line_y),
             Err(position) => position,
         }
     }
@@ -313,10 +507,21 @@ pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, F
     pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Length)) -> usize {
         let mut byte_offset = 0;
         let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        let bidi = BidiParagraphs::new(self.string);
+        let next_grapheme_boundary = |byte_offset: usize| {
+            unicode_segmentation::GraphemeCursor::new(byte_offset, self.string.len(), true)
+                .next_boundary(self.string, 0)
+                .ok()
+                .flatten()
+                .unwrap_or(self.string.len())
+        };
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                if pos_y >= line_y + self.layout.font.height() {
+                // The bottom of the line, including the lower half of the spacing between lines
+                let line_bottom =
+                    line_y + (self.layout.font.height() + self.layout.line_height()) / two;
+                if pos_y >= line_bottom {
                     byte_offset = line.byte_range.end;
                     return core::ops::ControlFlow::Continue(());
                 }
@@ -325,21 +530,53 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                     return core::ops::ControlFlow::Break(line.byte_range.start);
                 }
 
+                // The byte offset of the closest glyph to the left that belongs to another cluster
+                let mut left_neighbor = None;
+                let mut last_byte_offset = None;
+                let mut glyphs = glyphs.peekable();
                 while let Some(positioned_glyph) = glyphs.next() {
+                    let glyph_byte_offset = positioned_glyph.text_byte_offset;
+                    if last_byte_offset.is_some_and(|last| last != glyph_byte_offset) {
+                        left_neighbor = last_byte_offset;
+                    }
+                    last_byte_offset = Some(glyph_byte_offset);
+
                     if pos_x >= line_x + positioned_glyph.x
                         && pos_x <= line_x + positioned_glyph.x + positioned_glyph.advance
                     {
-                        if pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two {
+                        let left_half =
+                            pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two;
+                        if bidi.is_rtl_at(glyph_byte_offset) {
+                            // Right-to-left glyphs are followed in the text by the glyph to their left
+                            if !left_half {
+                                return core::ops::ControlFlow::Break(glyph_byte_offset);
+                            }
                             return core::ops::ControlFlow::Break(
-                                positioned_glyph.text_byte_offset,
+                                left_neighbor
+                                    .filter(|left| *left > glyph_byte_offset)
+                                    .unwrap_or_else(|| next_grapheme_boundary(glyph_byte_offset)),
                             );
-                        } else if let Some(next_glyph) = glyphs.next() {
-                            return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
+                        } else if left_half {
+                            return core::ops::ControlFlow::Break(glyph_byte_offset);
+                        } else if let Some(next_glyph) = glyphs.peek() {
+                            if next_glyph.text_byte_offset > glyph_byte_offset
+                                && !bidi.is_rtl_at(next_glyph.text_byte_offset)
+                            {
+                                return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
+                            }
+                            return core::ops::ControlFlow::Break(next_grapheme_boundary(
+                                glyph_byte_offset,
+                            ));
                         }
                     }
                 }
 
-                core::ops::ControlFlow::Break(line.byte_range.end)
+                // Left of the text is the start of left-to-right lines, and the end of right-to-left lines
+                if (pos_x < line_x) != bidi.is_rtl_paragraph_at(line.byte_range.start) {
+                    core::ops::ControlFlow::Break(line.byte_range.start)
+                } else {
+                    core::ops::ControlFlow::Break(line.byte_range.end)
+                }
             },
             None,
         ) {
@@ -347,6 +584,65 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
             Err(position) => position,
         }
     }
+
+    /// Returns the rectangles that cover the glyphs of the given byte range, one for each line that
+    /// shows a part of it, as `(x, y, width, height)`. With mixed text directions, a rectangle spans
+    /// from the leftmost to the rightmost glyph of the range within the line.
+    pub fn byte_range_rects(
+        &self,
+        byte_range: core::ops::Range<usize>,
+    ) -> Vec<(Font::Length, Font::Length, Font::Length, Font::Length)> {
+        let mut rects = Vec::new();
+        let _ = self.layout_lines::<()>(
+            |_, line_x, line_y, _, selection| {
+                if let Some(selection) =
+                    selection.filter(|selection| selection.start < selection.end)
+                {
+                    rects.push((
+                        line_x + selection.start,
+                        line_y,
+                        selection.end - selection.start,
+                        self.layout.font.height(),
+                    ));
+                }
+                core::ops::ControlFlow::Continue(())
+            },
+            Some(byte_range),
+        );
+        rects
+    }
+}
+
+/// Returns the glyphs of a line in the order in which they are displayed.
+#[auto_enum(Iterator)]
+fn glyphs_in_visual_order<'a, Length>(
+    glyphs: &'a [Glyph<Length>],
+    glyph_range: core::ops::Range<usize>,
+    visual_order: Option<&'a [usize]>,
+) -> impl Iterator<Item = &'a Glyph<Length>> + 'a {
+    match visual_order {
+        Some(order) => order.iter().map(move |index| &glyphs[*index]),
+        None => glyphs[glyph_range].iter(),
+    }
+}
+
+impl TextHorizontalAlignment {
+    /// Resolves `Start` and `End` to `Left` or `Right`, for a paragraph with the given direction.
+    pub fn resolve(self, rtl: bool) -> Self {
+        match (self, rtl) {
+            (Self::Start, false) | (Self::End, true) => Self::Left,
+            (Self::Start, true) | (Self::End, false) => Self::Right,
+            (alignment, _) => alignment,
+        }
+    }
+
+    /// Resolves `Start` and `End` to `Left` or `Right`, for the direction of the first paragraph of the text.
+    pub fn resolve_for_text(self, text: &str) -> Self {
+        match self {
+            Self::Start | Self::End => self.resolve(is_rtl_paragraph(text)),
+            alignment => alignment,
+        }
+    }
 }
 
 #[test]
@@ -413,6 +709,14 @@ fn ascent(&self) -> f32 {
     fn descent(&self) -> f32 {
         -5.
     }
+
+    fn x_height(&self) -> f32 {
+        3.
+    }
+
+    fn cap_height(&self) -> f32 {
+        4.
+    }
 }
 
 #[test]
@@ -424,7 +728,13 @@ fn test_elision() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -457,6 +767,48 @@ fn test_elision() {
     debug_assert_eq!(rendered_text, "This is a lo…")
 }
 
+#[test]
+fn test_elision_left_and_middle() {
+    let font = FixedTestFont;
+    let text = "This is a longer piece of text";
+
+    let elided_text = |overflow| {
+        let paragraph = TextParagraphLayout {
+            string: text,
+            layout: TextLayout {
+                font: &font,
+                letter_spacing: None,
+                word_spacing: None,
+                tab_stops: Default::default(),
+                line_spacing: Default::default(),
+            },
+            max_width: 13. * 10.,
+            max_height: 10.,
+            horizontal_alignment: TextHorizontalAlignment::Left,
+            vertical_alignment: TextVerticalAlignment::Top,
+            wrap: TextWrap::NoWrap,
+            overflow,
+            single_line: true,
+        };
+        let mut rendered_text = String::new();
+        paragraph
+            .layout_lines::<()>(
+                |glyphs, _, _, _, _| {
+                    rendered_text.extend(glyphs.map(|positioned_glyph| {
+                        char::from_u32(positioned_glyph.glyph_id.get() as u32).unwrap()
+                    }));
+                    core::ops::ControlFlow::Continue(())
+                },
+                None,
+            )
+            .unwrap();
+        rendered_text
+    };
+
+    assert_eq!(elided_text(TextOverflow::ElideLeft), "…iece of text");
+    assert_eq!(elided_text(TextOverflow::ElideMiddle), "This i…f text");
+}
+
 #[test]
 fn test_exact_fit() {
     let font = FixedTestFont;
@@ -466,7 +818,13 @@ fn test_exact_fit() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 4. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -508,7 +866,13 @@ fn test_no_line_separators_characters_rendered() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -553,7 +917,13 @@ fn test_cursor_position() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -593,7 +963,13 @@ fn test_cursor_position_with_newline() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -613,7 +989,13 @@ fn byte_offset_for_empty_line() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -635,7 +1017,13 @@ fn test_byte_offset() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -692,3 +1080,74 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+fn test_byte_range_rects() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        max_width: 100. * 10.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    assert_eq!(paragraph.byte_range_rects(1..4), vec![(10., 0., 30., 10.)]);
+    assert_eq!(paragraph.byte_range_rects(7..11), vec![(10., 10., 40., 10.)]);
+    assert_eq!(paragraph.byte_range_rects(3..3), vec![]);
+}
+
+#[test]
+fn test_line_spacing() {
+    let font = FixedTestFont;
+    let text = "ab\ncd ef";
+
+    let layout = TextLayout {
+        font: &font,
+        letter_spacing: None,
+        word_spacing: None,
+        tab_stops: Default::default(),
+        line_spacing: LineSpacing { line_height: Some(16.), paragraph_spacing: Some(4.) },
+    };
+    assert_eq!(layout.text_size(text, Some(30.), TextWrap::WordWrap), (20., 52.));
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout,
+        max_width: 30.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    let mut line_ys = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |_, _, line_y, _, _| {
+                line_ys.push(line_y);
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    // The glyphs are 3 pixels below the top of the 16 pixels high lines
+    assert_eq!(line_ys, vec![3., 23., 39.]);
+
+    assert_eq!(paragraph.byte_offset_for_position((0., 21.)), 3);
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (0., 39.));
+}
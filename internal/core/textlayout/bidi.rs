@@ -0,0 +1,154 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for bidirectional text, based on the Unicode Bidirectional Algorithm.
+//!
+//! The text is shaped and broken into lines in logical order. Only when a line is positioned,
+//! the runs of the line are reordered for display. Without the `unicode-bidi` feature, all
+//! text is treated as left-to-right.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::Glyph;
+
+/// The direction of the paragraphs and characters of a text.
+pub struct BidiParagraphs<'a> {
+    /// None if the text doesn't contain any right-to-left characters
+    #[cfg(feature = "unicode-bidi")]
+    info: Option<unicode_bidi::BidiInfo<'a>>,
+    #[cfg(not(feature = "unicode-bidi"))]
+    marker: core::marker::PhantomData<&'a str>,
+}
+
+impl<'a> BidiParagraphs<'a> {
+    pub fn new(text: &'a str) -> Self {
+        #[cfg(feature = "unicode-bidi")]
+        {
+            let info = unicode_bidi::BidiInfo::new(text, None);
+            Self { info: info.has_rtl().then_some(info) }
+        }
+        #[cfg(not(feature = "unicode-bidi"))]
+        {
+            let _ = text;
+            Self { marker: Default::default() }
+        }
+    }
+
+    /// Returns true if the paragraph that contains the byte offset has a right-to-left base direction.
+    pub fn is_rtl_paragraph_at(&self, byte_offset: usize) -> bool {
+        #[cfg(feature = "unicode-bidi")]
+        if let Some(info) = &self.info {
+            return info
+                .paragraphs
+                .iter()
+                .find(|paragraph| byte_offset < paragraph.range.end)
+                .or(info.paragraphs.last())
+                .is_some_and(|paragraph| paragraph.level.is_rtl());
+        }
+        let _ = byte_offset;
+        false
+    }
+
+    /// Returns true if the character at the byte offset is displayed right-to-left.
+    pub fn is_rtl_at(&self, byte_offset: usize) -> bool {
+        #[cfg(feature = "unicode-bidi")]
+        if let Some(info) = &self.info {
+            return info.levels.get(byte_offset).is_some_and(|level| level.is_rtl());
+        }
+        let _ = byte_offset;
+        false
+    }
+
+    /// Returns the indices of the glyphs of a line in the order in which they are displayed, from
+    /// left to right. Returns None if that's the order of the glyphs in the text.
+    ///
+    /// The glyphs must be in logical order and their byte offsets must be relative to the start
+    /// of the text.
+    pub fn visual_glyph_order<Length>(
+        &self,
+        line_byte_range: Range<usize>,
+        glyph_range: Range<usize>,
+        glyphs: &[Glyph<Length>],
+    ) -> Option<Vec<usize>> {
+        #[cfg(feature = "unicode-bidi")]
+        if let Some(info) = &self.info {
+            let paragraph = info
+                .paragraphs
+                .iter()
+                .find(|paragraph| paragraph.range.contains(&line_byte_range.start))?;
+            let line_byte_range =
+                line_byte_range.start..line_byte_range.end.min(paragraph.range.end);
+            if line_byte_range.is_empty() {
+                return None;
+            }
+            let (levels, runs) = info.visual_runs(paragraph, line_byte_range);
+            if runs.len() <= 1 && !levels.iter().any(|level| level.is_rtl()) {
+                return None;
+            }
+
+            let line_glyphs = &glyphs[glyph_range.clone()];
+            let mut order = Vec::with_capacity(line_glyphs.len());
+            for run in runs {
+                let start = line_glyphs.partition_point(|glyph| glyph.text_byte_offset < run.start);
+                let end = line_glyphs.partition_point(|glyph| glyph.text_byte_offset < run.end);
+                let run_glyphs = (glyph_range.start + start)..(glyph_range.start + end);
+                if levels[run.start].is_rtl() {
+                    order.extend(run_glyphs.rev());
+                } else {
+                    order.extend(run_glyphs);
+                }
+            }
+            return Some(order);
+        }
+        let _ = (line_byte_range, glyph_range, glyphs);
+        None
+    }
+}
+
+/// Returns true if the first paragraph of the text has a right-to-left base direction, because its
+/// first letter with a strong direction is right-to-left.
+pub fn is_rtl_paragraph(text: &str) -> bool {
+    BidiParagraphs::new(text).is_rtl_paragraph_at(0)
+}
+
+/// Returns true if the text at the byte offset is displayed right-to-left. At the end of the
+/// text, the character before the offset decides.
+pub fn is_rtl_at(text: &str, byte_offset: usize) -> bool {
+    let paragraphs = BidiParagraphs::new(text);
+    if byte_offset < text.len() {
+        paragraphs.is_rtl_at(byte_offset)
+    } else {
+        text[..byte_offset.min(text.len())]
+            .char_indices()
+            .next_back()
+            .is_some_and(|(offset, _)| paragraphs.is_rtl_at(offset))
+    }
+}
+
+#[cfg(feature = "unicode-bidi")]
+#[test]
+fn test_visual_glyph_order() {
+    let text = "ab \u{5d0}\u{5d1} cd";
+    let glyphs = text
+        .char_indices()
+        .map(|(text_byte_offset, _)| Glyph::<f32> { text_byte_offset, ..Default::default() })
+        .collect::<Vec<_>>();
+    let paragraphs = BidiParagraphs::new(text);
+    assert!(!paragraphs.is_rtl_paragraph_at(0));
+    assert!(paragraphs.is_rtl_at(3));
+    assert!(!paragraphs.is_rtl_at(0));
+    assert_eq!(
+        paragraphs.visual_glyph_order(0..text.len(), 0..glyphs.len(), &glyphs),
+        Some(vec![0, 1, 2, 4, 3, 5, 6, 7])
+    );
+
+    assert!(BidiParagraphs::new("abc").visual_glyph_order(0..3, 0..3, &glyphs[..3]).is_none());
+
+    let text = "\u{5d0}\u{5d1} ab";
+    assert!(BidiParagraphs::new(text).is_rtl_paragraph_at(0));
+    assert!(is_rtl_at(text, 0));
+    assert!(!is_rtl_at(text, text.len()));
+    assert!(is_rtl_at("\u{5d0}\u{5d1}", 4));
+}
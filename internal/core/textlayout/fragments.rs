@@ -121,7 +121,16 @@ fn next(&mut self) -> Option<Self::Item> {
 fn fragment_iterator_simple() {
     let font = FixedTestFont;
     let text = "H WX";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     let expected = vec![
         TextFragment {
@@ -148,7 +157,16 @@ fn fragment_iterator_simple() {
 fn fragment_iterator_simple_v2() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     let expected = vec![
         TextFragment {
@@ -175,7 +193,16 @@ fn fragment_iterator_simple_v2() {
 fn fragment_iterator_forced_break() {
     let font = FixedTestFont;
     let text = "H\nW";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     assert_eq!(
         fragments,
@@ -204,7 +231,16 @@ fn fragment_iterator_forced_break() {
 fn fragment_iterator_forced_break_multi() {
     let font = FixedTestFont;
     let text = "H\n\n\nW";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     assert_eq!(
         fragments,
@@ -249,7 +285,16 @@ fn fragment_iterator_forced_break_multi() {
 fn fragment_iterator_nbsp() {
     let font = FixedTestFont;
     let text = "X H\u{00a0}W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     assert_eq!(
         fragments,
@@ -278,7 +323,16 @@ fn fragment_iterator_nbsp() {
 fn fragment_iterator_break_anywhere() {
     let font = FixedTestFont;
     let text = "AB\nCD\nEF";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let mut fragments = TextFragmentIterator::new(text, &shape_buffer);
     assert_eq!(
         fragments.next(),
@@ -331,7 +385,16 @@ fn fragment_iterator_break_anywhere() {
 fn fragment_iterator_leading_nbsp() {
     let font = FixedTestFont;
     let text = "A\n\u{00a0}\u{00a0}AB";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        },
+        text,
+    );
     let fragments = TextFragmentIterator::new(text, &shape_buffer).collect::<Vec<_>>();
     assert_eq!(
         fragments,
@@ -0,0 +1,250 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Parsing of the markup of a `Text` with `text-format: markup`.
+//!
+//! The markup is a small subset of HTML: `<b>`, `<i>`, `<u>`, `<span color="#rrggbb">`,
+//! `<a href="...">`, `<br>`, and the entities `&lt;`, `&gt;`, `&amp;`, `&quot;`, `&apos;`, as well
+//! as numeric character references. Anything else is shown as it is written.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::graphics::{Color, FontRequest, FontStyle};
+use crate::SharedString;
+
+/// The style that the markup gives to a part of the text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// None if the span has the color of the `Text`
+    pub color: Option<Color>,
+    /// The `href` of the link the span is part of
+    pub link: Option<SharedString>,
+}
+
+impl TextSpanStyle {
+    /// Returns the font request for the span, based on the font request of the `Text`.
+    pub fn font_request(&self, font_request: &FontRequest) -> FontRequest {
+        let mut font_request = font_request.clone();
+        if self.bold {
+            font_request.weight = Some(font_request.weight.unwrap_or(400).max(700));
+        }
+        if self.italic {
+            font_request.style = FontStyle::Italic;
+        }
+        font_request
+    }
+}
+
+/// A part of the text with the same style
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpan {
+    pub range: Range<usize>,
+    pub style: TextSpanStyle,
+}
+
+/// The text of some markup without the tags, and the style of its parts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledText {
+    pub text: String,
+    /// The spans follow each other and cover the whole text
+    pub spans: Vec<TextSpan>,
+}
+
+impl StyledText {
+    /// Returns the style of the text at the given byte offset.
+    pub fn style_at(&self, byte_offset: usize) -> Option<&TextSpanStyle> {
+        let index = self.spans.partition_point(|span| span.range.end <= byte_offset);
+        self.spans.get(index).map(|span| &span.style)
+    }
+
+    /// Returns the spans that overlap the byte range, cut to the range.
+    pub fn spans_in(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, &TextSpanStyle)> + '_ {
+        let first = self.spans.partition_point(|span| span.range.end <= range.start);
+        self.spans[first..].iter().take_while(move |span| span.range.start < range.end).map(
+            move |span| {
+                (span.range.start.max(range.start)..span.range.end.min(range.end), &span.style)
+            },
+        )
+    }
+
+    fn push_str(&mut self, text: &str, style: &TextSpanStyle) {
+        if text.is_empty() {
+            return;
+        }
+        let start = self.text.len();
+        self.text.push_str(text);
+        match self.spans.last_mut() {
+            Some(span) if span.style == *style => span.range.end = self.text.len(),
+            _ => self.spans.push(TextSpan { range: start..self.text.len(), style: style.clone() }),
+        }
+    }
+}
+
+/// Parses the markup into the text to show and the style of its parts.
+pub fn parse_markup(markup: &str) -> StyledText {
+    let mut styled_text = StyledText::default();
+    // The tags that are open, with the style of the text inside of them
+    let mut open_tags: Vec<(&str, TextSpanStyle)> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        let style = open_tags.last().map(|(_, style)| style.clone()).unwrap_or_default();
+        let consumed = match c {
+            '<' => rest[1..].find('>').and_then(|end| {
+                let tag = &rest[1..end + 1];
+                if tag.starts_with(char::is_whitespace) || tag.contains('<') {
+                    return None;
+                }
+                let tag = tag.trim_end();
+                let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                let name = name.trim_end_matches('/');
+                if let Some(name) = name.strip_prefix('/') {
+                    let index =
+                        open_tags.iter().rposition(|(open, _)| open.eq_ignore_ascii_case(name))?;
+                    open_tags.truncate(index);
+                } else if name.eq_ignore_ascii_case("br") {
+                    styled_text.push_str("\n", &style);
+                } else {
+                    let mut style = style.clone();
+                    if name.eq_ignore_ascii_case("b") {
+                        style.bold = true;
+                    } else if name.eq_ignore_ascii_case("i") {
+                        style.italic = true;
+                    } else if name.eq_ignore_ascii_case("u") {
+                        style.underline = true;
+                    } else if name.eq_ignore_ascii_case("span") {
+                        if let Some(color) = attribute(attributes, "color").and_then(parse_color) {
+                            style.color = Some(color);
+                        }
+                    } else if name.eq_ignore_ascii_case("a") {
+                        style.link = Some(attribute(attributes, "href").unwrap_or_default().into());
+                    } else {
+                        return None;
+                    }
+                    open_tags.push((name, style));
+                }
+                Some(end + 2)
+            }),
+            '&' => rest.find(';').and_then(|end| {
+                let decoded = match &rest[1..end] {
+                    "lt" => '<',
+                    "gt" => '>',
+                    "amp" => '&',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    entity => {
+                        let code = entity.strip_prefix('#')?;
+                        let code = match code.strip_prefix(['x', 'X']) {
+                            Some(hex) => u32::from_str_radix(hex, 16),
+                            None => code.parse(),
+                        };
+                        char::from_u32(code.ok()?)?
+                    }
+                };
+                styled_text.push_str(decoded.encode_utf8(&mut [0; 4]), &style);
+                Some(end + 1)
+            }),
+            _ => None,
+        };
+        let consumed = consumed.unwrap_or_else(|| {
+            // Copy the text up to the next tag or entity, or the character that wasn't markup
+            let end = rest[1..].find(['<', '&']).map_or(rest.len(), |end| end + 1);
+            styled_text.push_str(&rest[..end], &style);
+            end
+        });
+        rest = &rest[consumed..];
+    }
+    styled_text
+}
+
+/// Returns the value of the attribute in a list of attributes like `color="#f00" other='x'`
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes.trim();
+    while !rest.is_empty() {
+        let (key, value) = rest.split_once('=')?;
+        let value = value.trim_start();
+        let (value, after) = match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].split_once(quote)?,
+            _ => value.split_once(char::is_whitespace).unwrap_or((value, "")),
+        };
+        if key.trim().eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+        rest = after.trim_start();
+    }
+    None
+}
+
+/// Parses a color in one of the forms `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`
+fn parse_color(color: &str) -> Option<Color> {
+    let hex = color.trim().strip_prefix('#')?;
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let expand = |nibble: u32| (nibble * 0x11) as u8;
+    Some(match hex.len() {
+        3 => {
+            Color::from_rgb_u8(expand(value >> 8), expand((value >> 4) & 0xf), expand(value & 0xf))
+        }
+        4 => Color::from_argb_u8(
+            expand(value & 0xf),
+            expand(value >> 12),
+            expand((value >> 8) & 0xf),
+            expand((value >> 4) & 0xf),
+        ),
+        6 => Color::from_argb_encoded(0xff000000 | value),
+        8 => Color::from_argb_encoded(value.rotate_right(8)),
+        _ => return None,
+    })
+}
+
+#[test]
+fn test_parse_markup() {
+    let styled_text = parse_markup("Hello <b>bold <i>and italic</i></b> &amp; <u>under</u>line");
+    assert_eq!(styled_text.text, "Hello bold and italic & underline");
+    let bold = TextSpanStyle { bold: true, ..Default::default() };
+    let bold_italic = TextSpanStyle { italic: true, ..bold.clone() };
+    let underline = TextSpanStyle { underline: true, ..Default::default() };
+    assert_eq!(
+        styled_text.spans,
+        vec![
+            TextSpan { range: 0..6, style: TextSpanStyle::default() },
+            TextSpan { range: 6..11, style: bold.clone() },
+            TextSpan { range: 11..21, style: bold_italic },
+            TextSpan { range: 21..24, style: TextSpanStyle::default() },
+            TextSpan { range: 24..29, style: underline },
+            TextSpan { range: 29..33, style: TextSpanStyle::default() },
+        ]
+    );
+    assert_eq!(styled_text.style_at(8), Some(&bold));
+    assert_eq!(
+        styled_text.spans_in(8..12).map(|(range, _)| range).collect::<Vec<_>>(),
+        vec![8..11, 11..12]
+    );
+
+    let styled_text = parse_markup("<span color=\"#ff0000\">red</span><br/>a < b &unknown; <x>");
+    assert_eq!(styled_text.text, "red\na < b &unknown; <x>");
+    assert_eq!(styled_text.spans[0].style.color, Some(Color::from_rgb_u8(0xff, 0, 0)));
+    assert_eq!(styled_text.spans[0].range, 0..3);
+    assert_eq!(styled_text.spans.len(), 2);
+
+    assert_eq!(parse_color("#0f08"), Some(Color::from_argb_u8(0x88, 0, 0xff, 0)));
+    assert_eq!(parse_color("#01020304"), Some(Color::from_argb_u8(4, 1, 2, 3)));
+    assert_eq!(parse_markup("&#65;&#x42;").text, "AB");
+
+    let styled_text = parse_markup("See <a href='terms.html'>the <b>terms</b></a>.");
+    assert_eq!(styled_text.text, "See the terms.");
+    assert_eq!(styled_text.style_at(2).unwrap().link, None);
+    assert_eq!(styled_text.style_at(4).unwrap().link.as_deref(), Some("terms.html"));
+    assert_eq!(styled_text.style_at(10).unwrap().link.as_deref(), Some("terms.html"));
+    assert!(styled_text.style_at(10).unwrap().bold);
+    assert_eq!(styled_text.style_at(13).unwrap().link, None);
+}
@@ -4,7 +4,9 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use core::ops::Range;
+use euclid::num::Zero;
 
+use super::bidi::BidiParagraphs;
 use super::TextLayout;
 
 /// This struct describes a glyph from shaping to rendering. This includes the relative shaping
@@ -74,6 +76,10 @@ fn height(&self) -> Length {
     }
     fn ascent(&self) -> Length;
     fn descent(&self) -> Length;
+    /// The height of lower case letters above the baseline, or zero if the font doesn't specify it.
+    fn x_height(&self) -> Length;
+    /// The height of upper case letters above the baseline, or zero if the font doesn't specify it.
+    fn cap_height(&self) -> Length;
 }
 
 pub trait AbstractFont: TextShaper + FontMetrics<<Self as TextShaper>::Length> {}
@@ -84,8 +90,8 @@ pub struct ShapeBoundaries<'a> {
     text: &'a str,
     #[cfg(feature = "unicode-script")]
     // TODO: We should do a better analysis to find boundaries for text shaping; including
-    // boundaries when the bidi level changes or an explicit separator like
-    // paragraph/lineseparator/space is encountered.
+    // boundaries when an explicit separator like paragraph/lineseparator/space is encountered.
+    // Boundaries where the direction changes are added by the ShapeBuffer.
     chars: core::str::CharIndices<'a>,
     next_boundary_start: Option<usize>,
     #[cfg(feature = "unicode-script")]
@@ -162,7 +168,6 @@ fn next(&mut self) -> Option<Self::Item> {
 pub struct TextRun {
     pub byte_range: Range<usize>,
     pub glyph_range: Range<usize>,
-    // TODO: direction, etc.
 }
 
 pub struct ShapeBuffer<Length> {
@@ -174,14 +179,55 @@ impl<Length> ShapeBuffer<Length> {
     pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
     where
         Font: AbstractFont<Length = Length>,
-        Length: Copy + core::ops::AddAssign,
+        Length: Copy
+            + core::ops::AddAssign
+            + core::ops::Add<Output = Length>
+            + core::ops::Sub<Output = Length>
+            + core::ops::Mul<Font::LengthPrimitive, Output = Length>
+            + PartialOrd
+            + Zero,
     {
+        // Text of different direction is shaped separately, so that the glyphs of a run can be
+        // reordered for display as a whole.
+        let bidi = BidiParagraphs::new(text);
+        let mut run_ranges = Vec::new();
+        let mut run_start = 0;
+        for run_end in ShapeBoundaries::new(text) {
+            let mut start = run_start;
+            let mut rtl = bidi.is_rtl_at(start);
+            for (offset, _) in text[run_start..run_end].char_indices() {
+                let offset = run_start + offset;
+                if bidi.is_rtl_at(offset) != rtl {
+                    run_ranges.push(start..offset);
+                    start = offset;
+                    rtl = !rtl;
+                }
+            }
+            run_ranges.push(start..run_end);
+            run_start = run_end;
+        }
+
         let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
-            .scan(0, |run_start, run_end| {
+        let text_runs = run_ranges
+            .into_iter()
+            .map(|byte_range| {
                 let glyphs_start = glyphs.len();
 
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
+                layout.font.shape_text(&text[byte_range.clone()], &mut glyphs);
+
+                // Shapers return right-to-left text in visual order, but the glyphs are kept in the
+                // order of the text, with byte offsets relative to the start of the text.
+                let run_glyphs = &mut glyphs[glyphs_start..];
+                if run_glyphs
+                    .first()
+                    .zip(run_glyphs.last())
+                    .is_some_and(|(first, last)| first.text_byte_offset > last.text_byte_offset)
+                {
+                    run_glyphs.reverse();
+                }
+                for glyph in run_glyphs.iter_mut() {
+                    glyph.text_byte_offset += byte_range.start;
+                }
 
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
@@ -199,16 +245,44 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                     }
                 }
 
-                let run = TextRun {
-                    byte_range: Range { start: *run_start, end: run_end },
-                    glyph_range: Range { start: glyphs_start, end: glyphs.len() },
-                };
-                *run_start = run_end;
+                if let Some(word_spacing) = layout.word_spacing {
+                    for glyph in &mut glyphs[glyphs_start..] {
+                        if text[glyph.text_byte_offset..].starts_with(super::is_word_separator) {
+                            glyph.advance += word_spacing;
+                        }
+                    }
+                }
 
-                Some(run)
+                TextRun {
+                    byte_range,
+                    glyph_range: Range { start: glyphs_start, end: glyphs.len() },
+                }
             })
             .collect();
 
+        // Tabs advance to the next tab stop, measured from the start of the line in the text
+        if text.contains('\t') {
+            let default_interval = layout
+                .font
+                .glyph_for_char(' ')
+                .map_or(Length::zero(), |space| space.advance * Font::LengthPrimitive::from(8));
+            let mut x = Length::zero();
+            for glyph in &mut glyphs {
+                match text[glyph.text_byte_offset..].chars().next() {
+                    Some('\n') => {
+                        x = Length::zero();
+                        continue;
+                    }
+                    Some('\t') => {
+                        glyph.advance = layout.tab_stops.next_stop(x, default_interval) - x;
+                        glyph.glyph_id = None;
+                    }
+                    _ => {}
+                }
+                x += glyph.advance;
+            }
+        }
+
         Self { glyphs, text_runs }
     }
 }
@@ -280,8 +354,10 @@ fn shape_text<GlyphStorage: std::iter::Extend<Glyph<f32>>>(
         glyphs.extend(output_glyph_generator);
     }
 
-    fn glyph_for_char(&self, _ch: char) -> Option<Glyph<f32>> {
-        todo!()
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<f32>> {
+        let mut glyphs = Vec::new();
+        self.shape_text(ch.encode_utf8(&mut [0; 4]), &mut glyphs);
+        glyphs.into_iter().next()
     }
 
     fn max_lines(&self, max_height: f32) -> usize {
@@ -298,6 +374,14 @@ fn ascent(&self) -> f32 {
     fn descent(&self) -> f32 {
         self.descender() as _
     }
+
+    fn x_height(&self) -> f32 {
+        rustybuzz::ttf_parser::Face::x_height(self).unwrap_or_default() as _
+    }
+
+    fn cap_height(&self) -> f32 {
+        self.capital_height().unwrap_or_default() as _
+    }
 }
 
 #[cfg(test)]
@@ -371,7 +455,13 @@ fn test_letter_spacing() {
             shaped_glyphs.iter().map(|g| g.advance).collect::<Vec<_>>()
         };
 
-        let layout = TextLayout { font: &face, letter_spacing: Some(20.) };
+        let layout = TextLayout {
+            font: &face,
+            letter_spacing: Some(20.),
+            word_spacing: None,
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        };
         let buffer = ShapeBuffer::new(&layout, text);
 
         assert_eq!(buffer.glyphs.len(), advances.len());
@@ -386,3 +476,62 @@ fn test_letter_spacing() {
         );
     });
 }
+
+#[test]
+fn test_word_spacing() {
+    use TextShaper;
+
+    with_dejavu_font(|face| {
+        let text = "a b\u{a0}c";
+        let advances = {
+            let mut shaped_glyphs = Vec::new();
+            face.shape_text(text, &mut shaped_glyphs);
+            shaped_glyphs.iter().map(|g| g.advance).collect::<Vec<_>>()
+        };
+        assert_eq!(advances.len(), 5);
+
+        let layout = TextLayout {
+            font: &face,
+            letter_spacing: None,
+            word_spacing: Some(10.),
+            tab_stops: Default::default(),
+            line_spacing: Default::default(),
+        };
+        let buffer = ShapeBuffer::new(&layout, text);
+
+        let mut expected_advances = advances;
+        expected_advances[1] += 10.;
+        expected_advances[3] += 10.;
+
+        assert_eq!(
+            buffer.glyphs.iter().map(|glyph| glyph.advance).collect::<Vec<_>>(),
+            expected_advances
+        );
+    });
+}
+
+#[test]
+fn test_tab_stops() {
+    use TextShaper;
+
+    with_dejavu_font(|face| {
+        let a = face.glyph_for_char('a').unwrap().advance;
+        let tab_stops = super::TabStops { positions: vec![2. * a], interval: Some(3. * a) };
+        let layout = TextLayout {
+            font: &face,
+            letter_spacing: None,
+            word_spacing: None,
+            tab_stops,
+            line_spacing: Default::default(),
+        };
+        let buffer = ShapeBuffer::new(&layout, "a\ta\ta\n\ta");
+
+        assert_eq!(buffer.glyphs.len(), 8);
+        // The first tab goes to the explicit stop, the second to the next multiple of the interval
+        assert_eq!(buffer.glyphs[1].advance, a);
+        assert_eq!(buffer.glyphs[3].advance, 3. * a);
+        assert!(buffer.glyphs[1].glyph_id.is_none());
+        // The tab stops of the next line are measured from its start
+        assert_eq!(buffer.glyphs[6].advance, 2. * a);
+    });
+}
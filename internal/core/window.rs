@@ -13,11 +13,11 @@
 use crate::graphics::Point;
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, MouseEvent,
-    MouseInputState, TextCursorBlinker,
+    MouseInputState, TextCursorBlinker, TouchInputState, TouchPhase,
 };
 use crate::item_tree::ItemRc;
 use crate::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak};
-use crate::items::{ColorScheme, InputType, ItemRef, MouseCursor};
+use crate::items::{ColorScheme, DropEvent, InputType, ItemRef, MouseCursor};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
@@ -38,6 +38,53 @@ fn previous_focus_item(item: ItemRc) -> ItemRc {
     item.previous_focus_item()
 }
 
+/// The `tab-index` of a `FocusScope` or a `TextInput`, or 0 for the other items
+fn tab_index(item: &ItemRc) -> i32 {
+    if let Some(focus_scope) = item.downcast::<crate::items::FocusScope>() {
+        focus_scope.as_pin_ref().tab_index()
+    } else if let Some(text_input) = item.downcast::<crate::items::TextInput>() {
+        text_input.as_pin_ref().tab_index()
+    } else {
+        0
+    }
+}
+
+/// The items of the component that can be reached with the keyboard, in the order given by
+/// their `tab-index`: first the positive ones in increasing order, then the ones that
+/// have none in declaration order.
+/// Returns None if no item has a `tab-index`, in which case the declaration order is used.
+fn tab_focus_chain(component: &ItemTreeRc) -> Option<alloc::vec::Vec<ItemRc>> {
+    let root = ItemRc::new(component.clone(), 0);
+    let mut chain = alloc::vec![root.clone()];
+    let mut current = root.next_focus_item();
+    while current != root {
+        chain.push(current.clone());
+        current = current.next_focus_item();
+    }
+    if chain.iter().all(|item| tab_index(item) == 0) {
+        return None;
+    }
+    chain.retain(|item| tab_index(item) >= 0);
+    // The sort is stable, so items with the same index keep the declaration order
+    chain.sort_by_key(|item| {
+        let index = tab_index(item);
+        (index == 0, index)
+    });
+    Some(chain)
+}
+
+/// The item after (or before, if `forward` is false) `item` in the chain, or the first (or last)
+/// item if `item` is not part of it
+fn step_in_focus_chain(chain: &[ItemRc], item: Option<&ItemRc>, forward: bool) -> ItemRc {
+    let len = chain.len();
+    match (chain.iter().position(|i| Some(i) == item), forward) {
+        (Some(pos), true) => chain[(pos + 1) % len].clone(),
+        (Some(pos), false) => chain[(pos + len - 1) % len].clone(),
+        (None, true) => chain[0].clone(),
+        (None, false) => chain[len - 1].clone(),
+    }
+}
+
 /// This trait represents the adaptation layer between the [`Window`] API and then
 /// windowing specific window representation, such as a Win32 `HWND` handle or a `wayland_surface_t`.
 ///
@@ -190,6 +237,15 @@ fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
     /// This method allow editable input field to communicate with the platform about input methods
     fn input_method_request(&self, _: InputMethodRequest) {}
 
+    /// Start a drag of `event` that can be dropped on other applications. `finished` must be
+    /// called once the drop happened or the drag was cancelled.
+    ///
+    /// If this function returns false (the default implementation), then the drag only
+    /// reaches the `DropArea`s of the window itself.
+    fn start_drag(&self, _event: DropEvent, _finished: Box<dyn FnOnce()>) -> bool {
+        false
+    }
+
     /// Return self as any so the backend can upcast
     // TODO: consider using the as_any crate, or deriving the traint from Any to provide a better default
     fn as_any(&self) -> &dyn core::any::Any {
@@ -364,6 +420,23 @@ fn notify(self: Pin<&Self>) {
     }
 }
 
+struct WindowHoverTracker {
+    window_adapter_weak: Weak<dyn WindowAdapter>,
+}
+
+impl crate::properties::PropertyDirtyHandler for WindowHoverTracker {
+    fn notify(self: Pin<&Self>) {
+        // The items under the mouse cursor moved: send a move event so that they update their
+        // hover state. Don't do it right away, as we might be in the middle of a binding evaluation.
+        let window_adapter_weak = self.window_adapter_weak.clone();
+        crate::timers::Timer::single_shot(Default::default(), move || {
+            if let Some(window_adapter) = window_adapter_weak.upgrade() {
+                WindowInner::from_pub(window_adapter.window()).update_hover();
+            }
+        });
+    }
+}
+
 /// This enum describes the different ways a popup can be rendered by the back-end.
 enum PopupWindowLocation {
     /// The popup is rendered in its own top-level window that is know to the windowing system.
@@ -391,6 +464,12 @@ struct WindowPinnedFields {
     /// Gets dirty when the layout restrictions, or some other property of the windows change
     #[pin]
     window_properties_tracker: PropertyTracker<WindowPropertiesTracker>,
+    /// Gets dirty when the items under the mouse cursor move
+    #[pin]
+    hover_tracker: PropertyTracker<WindowHoverTracker>,
+    /// Gets dirty when a `tab-index` or the structure of the item tree changes
+    #[pin]
+    tab_focus_chain_tracker: PropertyTracker,
     #[pin]
     scale_factor: Property<f32>,
     #[pin]
@@ -406,6 +485,9 @@ pub struct WindowInner {
     /// When the window is visible, keep a strong reference
     strong_component_ref: RefCell<Option<ItemTreeRc>>,
     mouse_input_state: Cell<MouseInputState>,
+    /// The position of the last mouse event, or None if the mouse is outside of the window
+    last_mouse_position: Cell<Option<LogicalPoint>>,
+    touch_input_state: Cell<TouchInputState>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// ItemRC that currently have the focus. (possibly a, instance of TextInput)
@@ -417,6 +499,12 @@ pub struct WindowInner {
     /// init code for the component might have code that sets the focus, but we don't want that
     /// for the ComponentContainer
     pub(crate) prevent_focus_change: Cell<bool>,
+    /// Set while the focus is moved with the keyboard, so that the item that receives the focus
+    /// knows it should show a focus indicator
+    pub(crate) focus_from_keyboard: Cell<bool>,
+    /// The result of `tab_focus_chain` for the component, valid until `tab_focus_chain_tracker`
+    /// gets dirty
+    tab_focus_chain: RefCell<Option<alloc::vec::Vec<crate::item_tree::ItemWeak>>>,
     cursor_blinker: RefCell<pin_weak::rc::PinWeak<crate::input::TextCursorBlinker>>,
 
     pinned_fields: Pin<Box<WindowPinnedFields>>,
@@ -453,11 +541,16 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             window_adapter_weak: window_adapter_weak.clone(),
         });
 
+        let mut hover_tracker = PropertyTracker::new_with_dirty_handler(WindowHoverTracker {
+            window_adapter_weak: window_adapter_weak.clone(),
+        });
+
         #[cfg(slint_debug_property)]
         {
             window_properties_tracker
                 .set_debug_name("i_slint_core::Window::window_properties_tracker".into());
             redraw_tracker.set_debug_name("i_slint_core::Window::redraw_tracker".into());
+            hover_tracker.set_debug_name("i_slint_core::Window::hover_tracker".into());
         }
 
         Self {
@@ -465,10 +558,14 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             component: Default::default(),
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
+            last_mouse_position: Default::default(),
+            touch_input_state: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
                 window_properties_tracker,
+                hover_tracker,
+                tab_focus_chain_tracker: Default::default(),
                 scale_factor: Property::new_named(1., "i_slint_core::Window::scale_factor"),
                 active: Property::new_named(false, "i_slint_core::Window::active"),
                 text_input_focused: Property::new_named(
@@ -490,6 +587,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             close_requested: Default::default(),
             click_state: ClickState::default(),
             prevent_focus_change: Default::default(),
+            focus_from_keyboard: Default::default(),
+            tab_focus_chain: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
             ctx: once_cell::unsync::Lazy::new(|| {
@@ -504,9 +603,11 @@ pub fn set_component(&self, component: &ItemTreeRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ItemTreeRc::downgrade(component));
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
+        self.pinned_fields.tab_focus_chain_tracker.set_dirty();
         let window_adapter = self.window_adapter();
         window_adapter.renderer().set_window_adapter(&window_adapter);
         {
@@ -558,6 +659,7 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
+        self.last_mouse_position.set(event.position());
 
         let window_adapter = self.window_adapter();
         let mut mouse_input_state = self.mouse_input_state.take();
@@ -628,6 +730,14 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.click_state.check_repeat(event, self.ctx.platform().click_interval());
         }
 
+        // Track the geometry of the items under the mouse cursor, to update their hover state
+        // when they move while the mouse cursor doesn't
+        self.pinned_fields.as_ref().project_ref().hover_tracker.evaluate_as_dependency_root(|| {
+            if event.position().is_some() {
+                mouse_input_state.track_item_geometry();
+            }
+        });
+
         self.mouse_input_state.set(mouse_input_state);
 
         if close_popup_on_click
@@ -640,6 +750,29 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
+    /// Receive a touch event and pass it to the items of the component. The first touch point
+    /// is handled like the mouse, and the two first touch points make a pinch gesture.
+    pub(crate) fn process_touch_input(&self, id: u64, phase: TouchPhase) {
+        let mut touch_input_state = self.touch_input_state.take();
+        crate::input::process_touch_input(
+            id,
+            phase,
+            &self.window_adapter(),
+            &mut touch_input_state,
+        );
+        self.touch_input_state.set(touch_input_state);
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
+    /// Sends a move event at the position of the last mouse event if the items under the mouse
+    /// cursor moved since then.
+    fn update_hover(&self) {
+        let Some(position) = self.last_mouse_position.get() else { return };
+        if self.pinned_fields.as_ref().project_ref().hover_tracker.is_dirty() {
+            self.process_mouse_input(MouseEvent::Moved { position });
+        }
+    }
+
     /// Called by the input code's internal timer to send an event that was delayed
     pub(crate) fn process_delayed_event(&self) {
         self.mouse_input_state.set(crate::input::process_delayed_event(
@@ -648,6 +781,27 @@ pub(crate) fn process_delayed_event(&self) {
         ));
     }
 
+    /// Receive a drag from another application and update the `DropArea`s under the drag.
+    /// Returns true if a `DropArea` accepts the data.
+    ///
+    /// Arguments:
+    /// * `position`: The position of the drag in window logical coordinates, or `None` if the drag
+    ///   left the window or was cancelled.
+    /// * `event`: The dragged data.
+    pub fn process_external_drag(&self, position: Option<LogicalPoint>, event: &DropEvent) -> bool {
+        crate::items::update_drop_areas(&self.window_adapter(), position, event).is_some()
+    }
+
+    /// Receive data dropped by another application and pass it to the `DropArea` under the
+    /// position that accepts it. Returns true if the data was accepted.
+    ///
+    /// Arguments:
+    /// * `position`: The position of the drop in window logical coordinates.
+    /// * `event`: The dropped data.
+    pub fn process_external_drop(&self, position: LogicalPoint, event: DropEvent) -> bool {
+        crate::items::drop_on_drop_area(&self.window_adapter(), Some(position), event)
+    }
+
     /// Receive a key event and pass it to the items of the component to
     /// change their state.
     ///
@@ -666,7 +820,36 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
 
         event.modifiers = self.modifiers.get().into();
 
-        let mut item = self.focus_item.borrow().clone().upgrade();
+        if event.key_code.is_empty()
+            && matches!(event.event_type, KeyEventType::KeyPressed | KeyEventType::KeyReleased)
+        {
+            event.key_code = event.text.clone();
+        }
+
+        let focus_item = self.focus_item.borrow().upgrade();
+
+        // Shortcuts take precedence over the item that has the focus, unless it accepts the
+        // ShortcutOverride event, like a text input does for the keys it uses for editing.
+        let focus_item_overrides_shortcuts = event.event_type == KeyEventType::KeyPressed
+            && focus_item.as_ref().is_some_and(|item| {
+                let override_event =
+                    KeyEvent { event_type: KeyEventType::ShortcutOverride, ..event.clone() };
+                item.is_visible()
+                    && item.borrow().as_ref().key_event(
+                        &override_event,
+                        &self.window_adapter(),
+                        item,
+                    ) == crate::input::KeyEventResult::EventAccepted
+            });
+        if !focus_item_overrides_shortcuts
+            && crate::items::activate_shortcut(&self.window_adapter(), &event, focus_item.clone())
+        {
+            crate::properties::ChangeTracker::run_change_handlers();
+            return;
+        }
+
+        let mut item = focus_item;
+
         while let Some(focus_item) = item {
             if !focus_item.is_visible() {
                 // Reset the focus... not great, but better than keeping it.
@@ -798,30 +981,77 @@ fn move_focus(&self, start_item: ItemRc, forward: impl Fn(ItemRc) -> ItemRc) ->
         }
     }
 
+    /// Returns the [`tab_focus_chain`] of the component. It is only computed again when a
+    /// `tab-index` or a repeater changes, instead of visiting all the items on every key press.
+    fn tab_focus_chain(&self, component: &ItemTreeRc) -> Option<alloc::vec::Vec<ItemRc>> {
+        let tracker = self.pinned_fields.as_ref().project_ref().tab_focus_chain_tracker;
+        if tracker.is_dirty() {
+            let chain = tracker.evaluate_as_dependency_root(|| tab_focus_chain(component));
+            *self.tab_focus_chain.borrow_mut() =
+                chain.as_ref().map(|chain| chain.iter().map(ItemRc::downgrade).collect());
+            return chain;
+        }
+        self.tab_focus_chain
+            .borrow()
+            .as_ref()
+            .map(|chain| chain.iter().filter_map(|item| item.upgrade()).collect())
+    }
+
+    /// Move the focus along the chain given by the `tab-index` of the items.
+    /// Returns the item where the search started and the item that got the focus.
+    fn move_focus_in_chain(
+        &self,
+        chain: &[ItemRc],
+        focus_item: Option<ItemRc>,
+        forward: bool,
+    ) -> (Option<ItemRc>, Option<ItemRc>) {
+        if chain.is_empty() {
+            return (focus_item, None);
+        }
+        let start_item = step_in_focus_chain(chain, focus_item.as_ref(), forward);
+        let end_item = self.move_focus(start_item.clone(), |item| {
+            step_in_focus_chain(chain, Some(&item), forward)
+        });
+        (Some(start_item), end_item)
+    }
+
     /// Move keyboard focus to the next item
     pub fn focus_next_item(&self) {
         let component = self.component();
-        let start_item = self
-            .take_focus_item()
-            .map(next_focus_item)
-            .unwrap_or_else(|| ItemRc::new(component, 0));
-        let end_item = self.move_focus(start_item.clone(), next_focus_item);
+        let focus_item = self.take_focus_item();
+        self.focus_from_keyboard.set(true);
+        let (start_item, end_item) = match self.tab_focus_chain(&component) {
+            Some(chain) => self.move_focus_in_chain(&chain, focus_item, true),
+            None => {
+                let start_item =
+                    focus_item.map(next_focus_item).unwrap_or_else(|| ItemRc::new(component, 0));
+                (Some(start_item.clone()), self.move_focus(start_item, next_focus_item))
+            }
+        };
+        self.focus_from_keyboard.set(false);
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
-            window_adapter.handle_focus_change(Some(start_item), end_item);
+            window_adapter.handle_focus_change(start_item, end_item);
         }
     }
 
     /// Move keyboard focus to the previous item.
     pub fn focus_previous_item(&self) {
         let component = self.component();
-        let start_item = previous_focus_item(
-            self.take_focus_item().unwrap_or_else(|| ItemRc::new(component, 0)),
-        );
-        let end_item = self.move_focus(start_item.clone(), previous_focus_item);
+        let focus_item = self.take_focus_item();
+        self.focus_from_keyboard.set(true);
+        let (start_item, end_item) = match self.tab_focus_chain(&component) {
+            Some(chain) => self.move_focus_in_chain(&chain, focus_item, false),
+            None => {
+                let start_item =
+                    previous_focus_item(focus_item.unwrap_or_else(|| ItemRc::new(component, 0)));
+                (Some(start_item.clone()), self.move_focus(start_item, previous_focus_item))
+            }
+        };
+        self.focus_from_keyboard.set(false);
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
-            window_adapter.handle_focus_change(Some(start_item), end_item);
+            window_adapter.handle_focus_change(start_item, end_item);
         }
     }
 
@@ -1512,11 +1742,15 @@ fn call(&self) -> CloseRequestResponse {
         handle: *const WindowAdapterRcOpaque,
         event_type: crate::input::KeyEventType,
         text: &SharedString,
+        key_code: &SharedString,
+        scan_code: i32,
         repeat: bool,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
         window_adapter.window().0.process_key_input(crate::items::KeyEvent {
             text: text.clone(),
+            key_code: key_code.clone(),
+            scan_code,
             repeat,
             event_type,
             ..Default::default()
@@ -6,7 +6,7 @@
 #[cfg(feature = "internal")]
 use i_slint_core::component_factory::FactoryContext;
 use i_slint_core::graphics::euclid::approxeq::ApproxEq as _;
-use i_slint_core::model::{Model, ModelRc};
+use i_slint_core::model::{Model, ModelRc, SharedVectorModel, VecModel};
 #[cfg(feature = "internal")]
 use i_slint_core::window::WindowInner;
 use i_slint_core::{PathData, SharedVector};
@@ -21,8 +21,13 @@
 
 pub use i_slint_core::api::*;
 // keep in sync with api/rs/slint/lib.rs
+#[cfg(not(target_arch = "wasm32"))]
+pub use i_slint_core::graphics::{register_image_provider, ImageProviderError};
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Alpha8Pixel, Brush, Color, ConicGradientBrush, FontFace, FontFamily, FontStyle, GradientStop,
+    Gray8Pixel, Image, LinearGradientBrush, LoadImageError, RadialGradientBrush,
+    RenderingCacheBudget, RenderingCacheStatistics, Rgb565Pixel, Rgb8Pixel, Rgba8Pixel, RgbaColor,
+    SharedPixelBuffer,
 };
 use i_slint_core::items::*;
 
@@ -114,8 +119,8 @@ pub enum Value {
     Struct(Struct) = 6,
     /// Correspond to `brush` or `color` type in .slint.  For color, this is then a [`Brush::SolidColor`]
     Brush(Brush) = 7,
-    #[doc(hidden)]
-    /// The elements of a path
+    /// The elements of a path. When setting a `string` property, this is converted to SVG path
+    /// commands, so that the property can be used for the `commands` of a `Path` element.
     PathData(PathData) = 8,
     #[doc(hidden)]
     /// An easing curve
@@ -146,6 +151,71 @@ pub fn value_type(&self) -> ValueType {
             _ => ValueType::Other,
         }
     }
+
+    /// Returns the number of rows of a [`Value::Model`]
+    pub fn row_count(&self) -> Result<usize, ModelRowError> {
+        Ok(self.as_model()?.row_count())
+    }
+
+    /// Returns the data of a row of a [`Value::Model`]
+    pub fn row_data(&self, row: usize) -> Result<Value, ModelRowError> {
+        self.as_model()?.row_data(row).ok_or(ModelRowError::OutOfRange)
+    }
+
+    /// Changes the data of a row of a [`Value::Model`], with [`Model::set_row_data`].
+    /// The views showing the model are notified.
+    pub fn set_row_data(&self, row: usize, data: Value) -> Result<(), ModelRowError> {
+        let model = self.as_model()?;
+        if row >= model.row_count() {
+            return Err(ModelRowError::OutOfRange);
+        }
+        model.set_row_data(row, data);
+        Ok(())
+    }
+
+    /// Inserts a row at `index` in a [`Value::Model`], shifting the rows after it. The views
+    /// showing the model are notified.
+    ///
+    /// This works for the arrays declared in .slint files and for a [`VecModel`].
+    pub fn insert_row(&self, index: usize, data: Value) -> Result<(), ModelRowError> {
+        let model = self.as_model()?;
+        if index > model.row_count() {
+            return Err(ModelRowError::OutOfRange);
+        }
+        if let Some(model) = model.as_any().downcast_ref::<SharedVectorModel<Value>>() {
+            model.insert(index, data);
+        } else if let Some(model) = model.as_any().downcast_ref::<VecModel<Value>>() {
+            model.insert(index, data);
+        } else {
+            return Err(ModelRowError::NotMutable);
+        }
+        Ok(())
+    }
+
+    /// Removes the row at `index` from a [`Value::Model`] and returns it. The views showing the
+    /// model are notified.
+    ///
+    /// This works for the arrays declared in .slint files and for a [`VecModel`].
+    pub fn remove_row(&self, index: usize) -> Result<Value, ModelRowError> {
+        let model = self.as_model()?;
+        if index >= model.row_count() {
+            return Err(ModelRowError::OutOfRange);
+        }
+        if let Some(model) = model.as_any().downcast_ref::<SharedVectorModel<Value>>() {
+            Ok(model.remove(index))
+        } else if let Some(model) = model.as_any().downcast_ref::<VecModel<Value>>() {
+            Ok(model.remove(index))
+        } else {
+            Err(ModelRowError::NotMutable)
+        }
+    }
+
+    fn as_model(&self) -> Result<&ModelRc<Value>, ModelRowError> {
+        match self {
+            Value::Model(model) => Ok(model),
+            _ => Err(ModelRowError::NotAModel),
+        }
+    }
 }
 
 impl PartialEq for Value {
@@ -1204,8 +1274,156 @@ pub fn get_property(&self, name: &str) -> Result<Value, GetPropertyError> {
             .map_err(|()| GetPropertyError::NoSuchProperty)
     }
 
+    /// Pause, resume, or seek the running animation of a public property of this component.
+    ///
+    /// Returns `Ok(false)` if the property isn't being animated.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{AnimationControl, Compiler, Value};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in property <bool> toggled;
+    ///         out property <int> my_property: toggled ? 100 : 0;
+    ///         animate my_property { duration: 1s; }
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// let result = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// assert_eq!(instance.control_property_animation("my_property", AnimationControl::Pause), Ok(false));
+    /// instance.set_property("toggled", Value::Bool(true)).unwrap();
+    /// assert_eq!(instance.get_property("my_property").unwrap(), Value::from(0));
+    /// assert_eq!(instance.control_property_animation("my_property", AnimationControl::Seek(0.5)), Ok(true));
+    /// assert_eq!(instance.get_property("my_property").unwrap(), Value::from(50));
+    /// ```
+    pub fn control_property_animation(
+        &self,
+        name: &str,
+        control: AnimationControl,
+    ) -> Result<bool, GetPropertyError> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let name = normalize_identifier(name);
+
+        if comp
+            .description()
+            .original
+            .root_element
+            .borrow()
+            .property_declarations
+            .get(name.as_ref())
+            .map_or(true, |d| !d.expose_in_public_api)
+        {
+            return Err(GetPropertyError::NoSuchProperty);
+        }
+
+        comp.description()
+            .control_property_animation(comp.borrow(), &name, control)
+            .map_err(|()| GetPropertyError::NoSuchProperty)
+    }
+
+    /// Set a handler that is called with the new value of a public property of this component
+    /// when the property changes, as long as the returned subscription is kept alive.
+    ///
+    /// The handler is invoked from the event loop after the property changed, so changing the
+    /// property several times in a row only invokes it once with the last value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Value};
+    /// use std::{cell::RefCell, rc::Rc};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <int> my_property;
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// let result = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// let last_value = Rc::new(RefCell::new(None));
+    /// let last_value_clone = last_value.clone();
+    /// let subscription = instance
+    ///     .on_property_changed("my_property", move |value| *last_value_clone.borrow_mut() = Some(value))
+    ///     .unwrap();
+    /// instance.set_property("my_property", Value::from(42)).unwrap();
+    /// i_slint_backend_testing::mock_elapsed_time(std::time::Duration::ZERO);
+    /// assert_eq!(*last_value.borrow(), Some(Value::from(42)));
+    /// ```
+    pub fn on_property_changed(
+        &self,
+        name: &str,
+        handler: impl Fn(Value) + 'static,
+    ) -> Result<PropertyChangeSubscription, GetPropertyError> {
+        // Check that the property exists and is public
+        self.get_property(name)?;
+        let name = normalize_identifier(name).into_owned();
+        Ok(PropertyChangeSubscription::new(
+            self.as_weak(),
+            move |instance: &Weak<ComponentInstance>| {
+                instance.upgrade().and_then(|instance| instance.get_property(&name).ok())
+            },
+            move |_, value| {
+                if let Some(value) = value {
+                    handler(value.clone())
+                }
+            },
+        ))
+    }
+
     /// Set the value for a public property of this component.
+    ///
+    /// If an `animate` block is declared for the property, the property is animated to the new value.
     pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyError> {
+        self.set_property_impl(name, value, None)
+    }
+
+    /// Set the value for a public property of this component, and animate the property to that value
+    /// with the given animation instead of the `animate` block declared for the property, if any.
+    ///
+    /// Properties of a type that can't be animated, such as `string`, are set without animation.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Animation, Compiler, Value};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <int> my_property;
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// let result = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// let animation = Animation::new(std::time::Duration::from_secs(1));
+    /// instance.set_property_with_animation("my_property", Value::from(100), animation).unwrap();
+    /// assert_eq!(instance.get_property("my_property").unwrap(), Value::from(0));
+    /// i_slint_backend_testing::mock_elapsed_time(std::time::Duration::from_millis(500));
+    /// assert_eq!(instance.get_property("my_property").unwrap(), Value::from(50));
+    /// ```
+    pub fn set_property_with_animation(
+        &self,
+        name: &str,
+        value: Value,
+        animation: Animation,
+    ) -> Result<(), SetPropertyError> {
+        self.set_property_impl(name, value, Some(animation.into()))
+    }
+
+    fn set_property_impl(
+        &self,
+        name: &str,
+        value: Value,
+        animation: Option<i_slint_core::items::PropertyAnimation>,
+    ) -> Result<(), SetPropertyError> {
         let name = normalize_identifier(name);
         generativity::make_guard!(guard);
         let comp = self.inner.unerase(guard);
@@ -1222,7 +1440,7 @@ pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyEr
             return Err(SetPropertyError::AccessDenied);
         }
 
-        d.set_property(comp.borrow(), &name, value)
+        d.set_property_with_animation(comp.borrow(), &name, value, animation)
     }
 
     /// Set a handler for the callback with the given name. A callback with that
@@ -1424,6 +1642,118 @@ pub fn invoke_global(
         }
     }
 
+    /// Renders the current state of the component into a new RGBA8 encoded pixel buffer,
+    /// without the need for the window to be shown on the screen.
+    ///
+    /// This is a shortcut for calling [`Window::render_to_image()`] on the component's window.
+    pub fn snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        self.window().render_to_image()
+    }
+
+    /// Returns the (UTF-8) byte offset in the text of the `Text` element with the given id of the
+    /// character that's visually nearest to `position`, which is relative to the element.
+    ///
+    /// Use this to find out which part of a paragraph the user clicked on. For text with markup,
+    /// the offset is in the text without the markup. Returns `None` if the component has no `Text`
+    /// element with this id outside of repeated or conditional elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, LogicalPosition};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         label := Text { x: 0; y: 0; text: "Hello World"; }
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// let result = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// // The testing backend lays out the text with ten pixels per byte
+    /// let offset = instance.text_byte_offset_for_position("label", LogicalPosition::new(62., 5.));
+    /// assert_eq!(offset, Some(6));
+    /// ```
+    pub fn text_byte_offset_for_position(
+        &self,
+        element_id: &str,
+        position: LogicalPosition,
+    ) -> Option<usize> {
+        self.with_text_element(element_id, |text, window_adapter| {
+            i_slint_core::items::text_byte_offset_for_position(
+                text,
+                window_adapter,
+                i_slint_core::lengths::logical_point_from_api(position),
+            )
+        })
+    }
+
+    /// Returns the rectangles that cover the glyphs of the given (UTF-8) byte range of the text of
+    /// the `Text` element with the given id, relative to the element. Each rectangle is returned
+    /// as its position and size.
+    ///
+    /// Use this to underline or highlight words, or to place a popup next to a part of the text.
+    /// For text with markup, the offsets are in the text without the markup. Returns `None` if the
+    /// component has no `Text` element with this id outside of repeated or conditional elements.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, LogicalPosition, LogicalSize};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         label := Text { x: 0; y: 0; text: "Hello World"; }
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// let result = spin_on::spin_on(
+    ///     compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// // The testing backend lays out the text with ten pixels per byte
+    /// let rects = instance.text_rects_for_byte_range("label", 6..11).unwrap();
+    /// assert_eq!(rects, vec![(LogicalPosition::new(60., 0.), LogicalSize::new(50., 10.))]);
+    /// ```
+    pub fn text_rects_for_byte_range(
+        &self,
+        element_id: &str,
+        byte_range: core::ops::Range<usize>,
+    ) -> Option<Vec<(LogicalPosition, LogicalSize)>> {
+        self.with_text_element(element_id, |text, window_adapter| {
+            i_slint_core::items::text_rects_for_byte_range(text, window_adapter, byte_range)
+                .into_iter()
+                .map(|rect| {
+                    (
+                        i_slint_core::lengths::logical_position_to_api(rect.origin),
+                        i_slint_core::lengths::logical_size_to_api(rect.size),
+                    )
+                })
+                .collect()
+        })
+    }
+
+    fn with_text_element<R>(
+        &self,
+        element_id: &str,
+        f: impl FnOnce(
+            core::pin::Pin<&dyn i_slint_core::item_rendering::RenderText>,
+            &i_slint_core::window::WindowAdapterRc,
+        ) -> R,
+    ) -> Option<R> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let description = comp.description();
+        let item_info = description.items.get(normalize_identifier(element_id).as_ref())?;
+        let item = unsafe { item_info.item_from_item_tree(comp.borrow_instance().as_ptr()) };
+        let window_adapter = self.inner.window_adapter_ref().ok()?;
+        if let Some(text) = ItemRef::downcast_pin::<ComplexText>(item) {
+            Some(f(text, window_adapter))
+        } else {
+            ItemRef::downcast_pin::<SimpleText>(item).map(|text| f(text, window_adapter))
+        }
+    }
+
     /// Find all positions of the components which are pointed by a given source location.
     ///
     /// WARNING: this is not part of the public API
@@ -1560,6 +1890,22 @@ pub enum InvokeError {
     NoSuchCallable,
 }
 
+/// Error returned by the functions of [`Value`] that access the rows of a model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ModelRowError {
+    /// The value is not a [`Value::Model`]
+    #[error("not a model")]
+    NotAModel,
+    /// The row is out of the range of the model
+    #[error("row out of range")]
+    OutOfRange,
+    /// Rows can only be inserted or removed in the arrays declared in .slint files and in a
+    /// [`VecModel`]
+    #[error("rows can't be inserted in or removed from this model")]
+    NotMutable,
+}
+
 /// Enters the main event loop. This is necessary in order to receive
 /// events from the windowing system in order to render to the screen
 /// and react to user input.
@@ -1946,6 +2292,51 @@ fn component_definition_struct_properties() {
     );
 }
 
+#[test]
+fn component_definition_path_data_properties() {
+    use crate::{PathElement, PathLineTo, PathMoveTo};
+    i_slint_backend_testing::init_no_event_loop();
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export component Dummy {
+        in-out property <string> shape;
+        in-out property <int> number;
+        Path {
+            commands: root.shape;
+        }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .component("Dummy")
+    .unwrap();
+
+    let instance = comp_def.create().unwrap();
+
+    assert_eq!(instance.set_property("shape", Value::String("M 0 0 L 10 10".into())), Ok(()));
+    assert_eq!(instance.get_property("shape"), Ok(Value::String("M 0 0 L 10 10".into())));
+
+    let elements = PathData::Elements(
+        [
+            PathElement::MoveTo(PathMoveTo { x: 0., y: 0. }),
+            PathElement::LineTo(PathLineTo { x: 10., y: 0. }),
+            PathElement::Close,
+        ]
+        .into(),
+    );
+    assert_eq!(instance.set_property("shape", Value::PathData(elements.clone())), Ok(()));
+    assert_eq!(instance.get_property("shape"), Ok(Value::String("M 0 0 L 10 0 Z".into())));
+
+    assert_eq!(
+        instance.set_property("number", Value::PathData(elements)),
+        Err(SetPropertyError::WrongType)
+    );
+}
+
 #[test]
 fn component_definition_model_properties() {
     use i_slint_core::model::*;
@@ -2002,6 +2393,46 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn model_rows() {
+    i_slint_backend_testing::init_no_event_loop();
+    let compiler = Compiler::default();
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"export component Dummy {
+            in-out property <[int]> list: [1, 2, 3];
+            out property <int> sum: list[0] + list[1] + list[2];
+            out property <int> count: list.length;
+        }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .component("Dummy")
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    let list = instance.get_property("list").unwrap();
+    assert_eq!(list.row_count(), Ok(3));
+    assert_eq!(list.row_data(1), Ok(Value::Number(2.)));
+    assert_eq!(list.row_data(3), Err(ModelRowError::OutOfRange));
+
+    list.set_row_data(0, Value::Number(10.)).unwrap();
+    assert_eq!(instance.get_property("sum").unwrap(), Value::Number(15.));
+
+    list.insert_row(0, Value::Number(100.)).unwrap();
+    assert_eq!(instance.get_property("sum").unwrap(), Value::Number(112.));
+    assert_eq!(instance.get_property("count").unwrap(), Value::Number(4.));
+    assert_eq!(list.remove_row(3), Ok(Value::Number(3.)));
+    assert_eq!(list.remove_row(3), Err(ModelRowError::OutOfRange));
+    assert_eq!(instance.get_property("count").unwrap(), Value::Number(3.));
+
+    assert_eq!(Value::Number(1.).row_count(), Err(ModelRowError::NotAModel));
+    let reversed = i_slint_core::model::ModelExt::reverse(Rc::new(VecModel::<Value>::default()));
+    let constant = Value::Model(ModelRc::new(reversed));
+    assert_eq!(constant.insert_row(0, Value::Number(1.)), Err(ModelRowError::NotMutable));
+}
+
 #[test]
 fn lang_type_to_value_type() {
     use std::collections::BTreeMap;
@@ -520,6 +520,21 @@ pub fn set_property(
         component: ItemTreeRefPin,
         name: &str,
         value: Value,
+    ) -> Result<(), crate::api::SetPropertyError> {
+        self.set_property_with_animation(component, name, value, None)
+    }
+
+    /// Set a value to property, animated with the given animation instead of the declared one.
+    ///
+    /// Return an error if the property with this name does not exist,
+    /// or if the value is the wrong type.
+    /// Panics if the component is not an instance corresponding to this ItemTreeDescription,
+    pub fn set_property_with_animation(
+        &self,
+        component: ItemTreeRefPin,
+        name: &str,
+        value: Value,
+        animation: Option<PropertyAnimation>,
     ) -> Result<(), crate::api::SetPropertyError> {
         if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
             panic!("mismatch instance and vtable");
@@ -534,9 +549,15 @@ pub fn set_property(
             .get(name)
             .and_then(|d| d.is_alias.as_ref())
         {
-            eval::store_property(c, &alias.element(), alias.name(), value)
+            eval::store_property_with_animation(c, &alias.element(), alias.name(), value, animation)
         } else {
-            eval::store_property(c, &self.original.root_element, name, value)
+            eval::store_property_with_animation(
+                c,
+                &self.original.root_element,
+                name,
+                value,
+                animation,
+            )
         }
     }
 
@@ -592,6 +613,36 @@ pub fn get_property(&self, component: ItemTreeRefPin, name: &str) -> Result<Valu
         }
     }
 
+    /// Pause, resume, or seek the animation of a property
+    ///
+    /// Returns an error if the component is not an instance corresponding to this ItemTreeDescription,
+    /// or if a property with this name does not exist
+    pub fn control_property_animation(
+        &self,
+        component: ItemTreeRefPin,
+        name: &str,
+        control: i_slint_core::animations::AnimationControl,
+    ) -> Result<bool, ()> {
+        if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
+            return Err(());
+        }
+        generativity::make_guard!(guard);
+        // Safety: we just verified that the component has the right vtable
+        let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        if let Some(alias) = self
+            .original
+            .root_element
+            .borrow()
+            .property_declarations
+            .get(name)
+            .and_then(|d| d.is_alias.as_ref())
+        {
+            eval::control_property_animation(c, &alias.element(), alias.name(), control)
+        } else {
+            eval::control_property_animation(c, &self.original.root_element, name, control)
+        }
+    }
+
     /// Sets an handler for a callback
     ///
     /// Returns an error if the component is not an instance corresponding to this ItemTreeDescription,
@@ -934,6 +985,10 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<TouchArea>(),
                 rtti_for::<FocusScope>(),
                 rtti_for::<SwipeGestureRecognizer>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
+                rtti_for::<PinchGestureRecognizer>(),
+                rtti_for::<Shortcut>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
                 rtti_for::<WindowItem>(),
@@ -943,6 +998,8 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<Rotate>(),
                 rtti_for::<Opacity>(),
                 rtti_for::<Layer>(),
+                rtti_for::<DropShadow>(),
+                rtti_for::<Blur>(),
             ]
             .iter()
             .cloned(),
@@ -1164,7 +1221,7 @@ macro_rules! match_enum_type {
                     i_slint_common::for_each_enums!(match_enum_type)
                 }
             }
-            Type::LayoutCache => property_info::<SharedVector<f32>>(),
+            Type::LayoutCache | Type::LengthArray => property_info::<SharedVector<f32>>(),
             Type::Function { .. } | Type::Callback { .. } => return None,
 
             // These can't be used in properties
@@ -1304,21 +1361,25 @@ pub fn animation_for_property(
 ) -> AnimatedBindingKind {
     match animation {
         Some(i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem)) => {
-            AnimatedBindingKind::Animation(eval::new_struct_with_bindings(
-                &anim_elem.borrow().bindings,
-                &mut eval::EvalLocalContext::from_component_instance(component),
-            ))
+            AnimatedBindingKind::Animation(
+                eval::new_struct_with_bindings(
+                    &anim_elem.borrow().bindings,
+                    &mut eval::EvalLocalContext::from_component_instance(component),
+                ),
+                animation_callbacks(component, animation.as_ref().unwrap()),
+            )
         }
         Some(i_slint_compiler::object_tree::PropertyAnimation::Transition {
             animations,
             state_ref,
         }) => {
+            let callbacks = animation_callbacks(component, animation.as_ref().unwrap());
             let component_ptr = component.as_ptr();
             let vtable = NonNull::from(&component.description.ct).cast();
             let animations = animations.clone();
             let state_ref = state_ref.clone();
-            AnimatedBindingKind::Transition(Box::new(
-                move || -> (PropertyAnimation, i_slint_core::animations::Instant) {
+            AnimatedBindingKind::Transition(
+                Box::new(move || -> (PropertyAnimation, i_slint_core::animations::Instant) {
                     generativity::make_guard!(guard);
                     let component = unsafe {
                         InstanceRef::from_pin_ref(
@@ -1334,9 +1395,7 @@ pub fn animation_for_property(
                     let state = eval::eval_expression(&state_ref, &mut context);
                     let state_info: i_slint_core::properties::StateInfo = state.try_into().unwrap();
                     for a in &animations {
-                        if (a.is_out && a.state_id == state_info.previous_state)
-                            || (!a.is_out && a.state_id == state_info.current_state)
-                        {
+                        if a.is_active(state_info.current_state, state_info.previous_state) {
                             return (
                                 eval::new_struct_with_bindings(
                                     &a.animation.borrow().bindings,
@@ -1347,13 +1406,69 @@ pub fn animation_for_property(
                         }
                     }
                     Default::default()
-                },
-            ))
+                }),
+                callbacks,
+            )
         }
         None => AnimatedBindingKind::NotAnimated,
     }
 }
 
+/// Returns the `started` and `finished` callbacks of the animation
+fn animation_callbacks(
+    component: InstanceRef,
+    animation: &i_slint_compiler::object_tree::PropertyAnimation,
+) -> i_slint_core::properties::AnimationCallbacks {
+    let self_weak = component.self_weak().get().unwrap();
+    let handler = |anim: &ElementRc, name: &str| {
+        anim.borrow().bindings.get(name).map(|b| b.borrow().expression.clone())
+    };
+    let callback = |name: &str| -> Option<Rc<dyn Fn()>> {
+        match animation {
+            i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem) => {
+                let handler = make_binding_eval_closure(handler(anim_elem, name)?, self_weak);
+                Some(Rc::new(move || {
+                    handler();
+                }))
+            }
+            i_slint_compiler::object_tree::PropertyAnimation::Transition {
+                animations,
+                state_ref,
+            } => {
+                let handlers = animations
+                    .iter()
+                    .map(|a| (a.clone(), handler(&a.animation, name)))
+                    .collect::<Vec<_>>();
+                if handlers.iter().all(|(.., handler)| handler.is_none()) {
+                    return None;
+                }
+                let state_ref = state_ref.clone();
+                let self_weak = self_weak.clone();
+                Some(Rc::new(move || {
+                    let self_rc = self_weak.upgrade().unwrap();
+                    generativity::make_guard!(guard);
+                    let self_ = self_rc.unerase(guard);
+                    let instance_ref = self_.borrow_instance();
+                    let mut context = eval::EvalLocalContext::from_component_instance(instance_ref);
+                    let state = eval::eval_expression(&state_ref, &mut context);
+                    let state_info: i_slint_core::properties::StateInfo = state.try_into().unwrap();
+                    // Only the handler of the transition that is used for the current state is called
+                    let handler = handlers.iter().find(|(a, _)| {
+                        a.is_active(state_info.current_state, state_info.previous_state)
+                    });
+                    if let Some((.., Some(handler))) = handler {
+                        eval::eval_expression(handler, &mut context);
+                    }
+                }))
+            }
+        }
+    };
+    i_slint_core::properties::AnimationCallbacks {
+        started: callback("started"),
+        finished: callback("finished"),
+    }
+}
+
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
@@ -4,7 +4,11 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::InstanceRef;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
+use corelib::animations::AnimationControl;
+use corelib::graphics::{
+    ConicGradientBrush, GradientStop, ImagePatternBrush, LinearGradientBrush, PathElement,
+    RadialGradientBrush,
+};
 use corelib::items::{ColorScheme, ItemRef, PropertyAnimation};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
 use corelib::rtti::AnimatedBindingKind;
@@ -38,6 +42,8 @@ fn set_binding(
     /// Safety: Property2 must be a (pinned) pointer to a `Property<T>`
     /// where T is the same T as the one represented by this property.
     unsafe fn link_two_ways(&self, item: Pin<ItemRef>, property2: *const ());
+
+    fn control_animation(&self, item: Pin<ItemRef>, control: AnimationControl) -> bool;
 }
 
 impl<Item: vtable::HasStaticVTable<corelib::items::ItemVTable>> ErasedPropertyInfo
@@ -69,6 +75,9 @@ unsafe fn link_two_ways(&self, item: Pin<ItemRef>, property2: *const ()) {
         // Safety: ErasedPropertyInfo::link_two_ways and PropertyInfo::link_two_ways have the same safety requirement
         (*self).link_two_ways(ItemRef::downcast_pin(item).unwrap(), property2)
     }
+    fn control_animation(&self, item: Pin<ItemRef>, control: AnimationControl) -> bool {
+        (*self).control_animation(ItemRef::downcast_pin(item).unwrap(), control)
+    }
 }
 
 pub trait ErasedCallbackInfo {
@@ -192,6 +201,14 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
                 (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
                 (Value::Brush(brush), Type::Color) => brush.color().into(),
+                (Value::Model(model), Type::LengthArray) => {
+                    model.model_tracker().track_row_count_changes();
+                    Value::LayoutCache(
+                        (0..model.row_count())
+                            .filter_map(|row| f32::try_from(model.row_data_tracked(row)?).ok())
+                            .collect(),
+                    )
+                }
                 (v, _) => v,
             }
         }
@@ -338,6 +355,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
             EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
             EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
+            EasingCurve::Spring(stiffness, damping, velocity) => {
+                corelib::animations::EasingCurve::Spring([*stiffness, *damping, *velocity])
+            }
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
@@ -350,8 +370,21 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 GradientStop{ color, position }
             }))))
         }
-        Expression::RadialGradient{stops} => {
-            Value::Brush(Brush::RadialGradient(RadialGradientBrush::new_circle(stops.iter().map(|(color, stop)| {
+        Expression::RadialGradient{center_x, center_y, radius, stops} => {
+            let center_x = eval_expression(center_x, local_context).try_into().unwrap();
+            let center_y = eval_expression(center_y, local_context).try_into().unwrap();
+            let radius = eval_expression(radius, local_context).try_into().unwrap();
+            Value::Brush(Brush::RadialGradient(RadialGradientBrush::new_circle_at([center_x, center_y].into(), radius, stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            }))))
+        }
+        Expression::ConicGradient{from_angle, center_x, center_y, stops} => {
+            let from_angle = eval_expression(from_angle, local_context).try_into().unwrap();
+            let center_x = eval_expression(center_x, local_context).try_into().unwrap();
+            let center_y = eval_expression(center_y, local_context).try_into().unwrap();
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new_at(from_angle, [center_x, center_y].into(), stops.iter().map(|(color, stop)| {
                 let color = eval_expression(color, local_context).try_into().unwrap();
                 let position = eval_expression(stop, local_context).try_into().unwrap();
                 GradientStop{ color, position }
@@ -731,6 +764,8 @@ fn call_builtin_function(
                         "cut" => textinput.cut(&window_adapter, &item_rc),
                         "copy" => textinput.copy(&window_adapter, &item_rc),
                         "paste" => textinput.paste(&window_adapter, &item_rc),
+                        "undo" => textinput.undo(&window_adapter, &item_rc),
+                        "redo" => textinput.redo(&window_adapter, &item_rc),
                         _ => panic!("internal: Unknown member function {name} called on TextInput"),
                     }
                 } else {
@@ -829,6 +864,34 @@ fn call_builtin_function(
                 panic!("First argument not a color");
             }
         }
+        BuiltinFunction::ColorSaturate => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ColorSaturate")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                if let Value::Number(factor) = eval_expression(&arguments[1], local_context) {
+                    brush.saturate(factor as _).into()
+                } else {
+                    panic!("Second argument not a number");
+                }
+            } else {
+                panic!("First argument not a color");
+            }
+        }
+        BuiltinFunction::ColorDesaturate => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ColorDesaturate")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                if let Value::Number(factor) = eval_expression(&arguments[1], local_context) {
+                    brush.desaturate(factor as _).into()
+                } else {
+                    panic!("Second argument not a number");
+                }
+            } else {
+                panic!("First argument not a color");
+            }
+        }
         BuiltinFunction::ColorTransparentize => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorFaded")
@@ -887,6 +950,19 @@ fn call_builtin_function(
                 panic!("First argument not a color");
             }
         }
+        BuiltinFunction::ImagePattern => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ImagePattern")
+            }
+            if let Value::Image(image) = eval_expression(&arguments[0], local_context) {
+                let tiling = eval_expression(&arguments[1], local_context)
+                    .try_into()
+                    .expect("Second argument not a PatternTiling");
+                Value::Brush(Brush::ImagePattern(ImagePatternBrush::new(image, tiling)))
+            } else {
+                panic!("First argument not an image");
+            }
+        }
         BuiltinFunction::ImageSize => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ImageSize")
@@ -1123,6 +1199,12 @@ fn from_index(&self, index: usize) -> Option<SharedString> {
             ))
         }
         BuiltinFunction::Use24HourFormat => Value::Bool(corelib::date_time::use_24_hour_format()),
+        BuiltinFunction::PrefersReducedMotion => {
+            Value::Bool(corelib::context::prefers_reduced_motion())
+        }
+        BuiltinFunction::PrefersHighContrast => {
+            Value::Bool(corelib::context::prefers_high_contrast())
+        }
         BuiltinFunction::UpdateTimers => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 crate::dynamic_item_tree::update_timers(component);
@@ -1304,11 +1386,66 @@ fn load_property_helper(
     }
 }
 
+/// Pause, resume, or seek the animation of a property. Returns Ok(false) if the property
+/// isn't being animated, and an error if the property doesn't exist or is in a global.
+pub fn control_property_animation(
+    component_instance: InstanceRef,
+    element: &ElementRc,
+    name: &str,
+    control: AnimationControl,
+) -> Result<bool, ()> {
+    generativity::make_guard!(guard);
+    match enclosing_component_instance_for_element(
+        element,
+        ComponentInstance::InstanceRef(component_instance),
+        guard,
+    ) {
+        ComponentInstance::InstanceRef(enclosing_component) => {
+            let element = element.borrow();
+            if element.id == element.enclosing_component.upgrade().unwrap().root_element.borrow().id
+            {
+                if let Some(x) = enclosing_component.description.custom_properties.get(name) {
+                    return Ok(unsafe {
+                        x.prop.control_animation(
+                            Pin::new_unchecked(&*enclosing_component.as_ptr().add(x.offset)),
+                            control,
+                        )
+                    });
+                } else if enclosing_component.description.original.is_global() {
+                    return Err(());
+                }
+            };
+            let item_info = enclosing_component
+                .description
+                .items
+                .get(element.id.as_str())
+                .unwrap_or_else(|| panic!("Unknown element for {}.{}", element.id, name));
+            core::mem::drop(element);
+            let item = unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
+            Ok(item_info.rtti.properties.get(name).ok_or(())?.control_animation(item, control))
+        }
+        ComponentInstance::GlobalComponent(_) => Err(()),
+    }
+}
+
 pub fn store_property(
     component_instance: InstanceRef,
     element: &ElementRc,
     name: &str,
     value: Value,
+) -> Result<(), SetPropertyError> {
+    store_property_with_animation(component_instance, element, name, value, None)
+}
+
+/// Same as [`store_property`], but the property is animated with the given animation instead
+/// of the animation declared for it. Properties of a type that can't be animated are set
+/// without animation.
+pub fn store_property_with_animation(
+    component_instance: InstanceRef,
+    element: &ElementRc,
+    name: &str,
+    value: Value,
+    animation: Option<PropertyAnimation>,
 ) -> Result<(), SetPropertyError> {
     generativity::make_guard!(guard);
     match enclosing_component_instance_for_element(
@@ -1317,19 +1454,24 @@ pub fn store_property(
         guard,
     ) {
         ComponentInstance::InstanceRef(enclosing_component) => {
-            let maybe_animation = match element.borrow().bindings.get(name) {
-                Some(b) => crate::dynamic_item_tree::animation_for_property(
-                    enclosing_component,
-                    &b.borrow().animation,
-                ),
-                None => {
-                    crate::dynamic_item_tree::animation_for_property(enclosing_component, &None)
+            let is_explicit_animation = animation.is_some();
+            let maybe_animation = animation.or_else(|| {
+                match element.borrow().bindings.get(name) {
+                    Some(b) => crate::dynamic_item_tree::animation_for_property(
+                        enclosing_component,
+                        &b.borrow().animation,
+                    ),
+                    None => {
+                        crate::dynamic_item_tree::animation_for_property(enclosing_component, &None)
+                    }
                 }
-            };
+                .as_animation()
+            });
 
             let component = element.borrow().enclosing_component.upgrade().unwrap();
             if element.borrow().id == component.root_element.borrow().id {
                 if let Some(x) = enclosing_component.description.custom_properties.get(name) {
+                    let mut value = value;
                     if let Some(orig_decl) = enclosing_component
                         .description
                         .original
@@ -1338,6 +1480,12 @@ pub fn store_property(
                         .property_declarations
                         .get(name)
                     {
+                        // Path data is set as SVG commands, so that it can be used for the commands of a Path
+                        if let (Value::PathData(path), Type::String) =
+                            (&value, &orig_decl.property_type)
+                        {
+                            value = Value::String(path.to_svg_commands());
+                        }
                         // Do an extra type checking because PropertyInfo::set won't do it for custom structures or array
                         if !check_value_type(&value, &orig_decl.property_type) {
                             return Err(SetPropertyError::WrongType);
@@ -1345,10 +1493,14 @@ pub fn store_property(
                     }
                     unsafe {
                         let p = Pin::new_unchecked(&*enclosing_component.as_ptr().add(x.offset));
-                        return x
-                            .prop
-                            .set(p, value, maybe_animation.as_animation())
-                            .map_err(|()| SetPropertyError::WrongType);
+                        return if is_explicit_animation {
+                            x.prop
+                                .set(p, value.clone(), maybe_animation)
+                                .or_else(|()| x.prop.set(p, value, None))
+                        } else {
+                            x.prop.set(p, value, maybe_animation)
+                        }
+                        .map_err(|()| SetPropertyError::WrongType);
                     }
                 } else if enclosing_component.description.original.is_global() {
                     return Err(SetPropertyError::NoSuchProperty);
@@ -1357,8 +1509,12 @@ pub fn store_property(
             let item_info = &enclosing_component.description.items[element.borrow().id.as_str()];
             let item = unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
             let p = &item_info.rtti.properties.get(name).ok_or(SetPropertyError::NoSuchProperty)?;
-            p.set(item, value, maybe_animation.as_animation())
-                .map_err(|()| SetPropertyError::WrongType)?;
+            if is_explicit_animation {
+                p.set(item, value.clone(), maybe_animation).or_else(|()| p.set(item, value, None))
+            } else {
+                p.set(item, value, maybe_animation)
+            }
+            .map_err(|()| SetPropertyError::WrongType)?;
         }
         ComponentInstance::GlobalComponent(glob) => {
             glob.as_ref().set_property(name, value)?;
@@ -1405,7 +1561,8 @@ fn check_value_type(value: &Value, ty: &Type) -> bool {
         Type::Enumeration(en) => {
             matches!(value, Value::EnumerationValue(name, _) if name == en.name.as_str())
         }
-        Type::LayoutCache => matches!(value, Value::LayoutCache(_)),
+        // A SharedVector<f32> is represented as Value::LayoutCache
+        Type::LayoutCache | Type::LengthArray => matches!(value, Value::LayoutCache(_)),
         Type::ComponentFactory => matches!(value, Value::ComponentFactory(_)),
     }
 }
@@ -1568,12 +1725,9 @@ fn convert_from_lyon_path<'a>(
         .map(|point_expr| {
             let point_value = eval_expression(point_expr, local_context);
             let point_struct: Struct = point_value.try_into().unwrap();
-            let mut point = i_slint_core::graphics::Point::default();
             let x: f64 = point_struct.get_field("x").unwrap().clone().try_into().unwrap();
             let y: f64 = point_struct.get_field("y").unwrap().clone().try_into().unwrap();
-            point.x = x as _;
-            point.y = y as _;
-            point
+            i_slint_core::api::LogicalPosition::new(x as _, y as _)
         })
         .collect::<SharedVector<_>>();
 
@@ -1654,7 +1808,7 @@ pub fn default_value_for_type(ty: &Type) -> Value {
         Type::Void | Type::Invalid => Value::Void,
         Type::UnitProduct(_) => Value::Number(0.),
         Type::PathData => Value::PathData(Default::default()),
-        Type::LayoutCache => Value::LayoutCache(Default::default()),
+        Type::LayoutCache | Type::LengthArray => Value::LayoutCache(Default::default()),
         Type::ComponentFactory => Value::ComponentFactory(Default::default()),
         Type::InferredProperty
         | Type::InferredCallback
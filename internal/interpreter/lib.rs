@@ -85,6 +85,8 @@
 #[cfg(feature = "highlight")]
 pub mod highlight;
 mod value_model;
+#[cfg(feature = "serde")]
+mod value_serde;
 
 #[doc(inline)]
 pub use api::*;
@@ -97,5 +99,11 @@
 #[doc(inline)]
 pub use i_slint_core::{Brush, Color, SharedString, SharedVector};
 
+/// (Re-export from corelib.)
+#[doc(inline)]
+pub use i_slint_core::graphics::{
+    PathArcTo, PathCubicTo, PathData, PathElement, PathLineTo, PathMoveTo, PathQuadraticTo,
+};
+
 #[cfg(test)]
 mod tests;
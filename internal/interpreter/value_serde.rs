@@ -0,0 +1,168 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Implementation of `serde::Serialize` and `serde::Deserialize` for [`Value`] and [`Struct`]
+
+use crate::api::{Struct, Value};
+use i_slint_core::model::{Model, ModelRc, VecModel};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Numbers without a fractional part are serialized as integers, as long as they can be
+/// represented exactly.
+const MAX_EXACT_INTEGER: f64 = (1u64 << 53) as f64;
+
+/// Serializes the data of the value: `null` for [`Value::Void`], numbers, strings, booleans,
+/// sequences for models, and maps for structs. Enumeration values are serialized as the string
+/// of their value. Images, brushes, and the other types can't be serialized.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Void => serializer.serialize_unit(),
+            Value::Number(n) if n.fract() == 0. && n.abs() <= MAX_EXACT_INTEGER => {
+                serializer.serialize_i64(*n as i64)
+            }
+            Value::Number(n) => serializer.serialize_f64(*n),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Model(model) => {
+                let mut seq = serializer.serialize_seq(Some(model.row_count()))?;
+                for row in model.iter() {
+                    seq.serialize_element(&row)?;
+                }
+                seq.end()
+            }
+            Value::Struct(s) => s.serialize(serializer),
+            Value::EnumerationValue(_, value) => serializer.serialize_str(value),
+            _ => Err(S::Error::custom(format!(
+                "values of type {:?} can't be serialized",
+                self.value_type()
+            ))),
+        }
+    }
+}
+
+/// Serializes the fields of the struct as a map, sorted by name
+impl Serialize for Struct {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields = self.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|(name, _)| *name);
+        let mut map = serializer.serialize_map(Some(fields.len()))?;
+        for (name, value) in fields {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a null, number, string, boolean, sequence or map")
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Void)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Void)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Number(v))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.into()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut rows = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(row) = seq.next_element::<Value>()? {
+            rows.push(row);
+        }
+        Ok(Value::Model(ModelRc::new(VecModel::from(rows))))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Value, A::Error> {
+        StructVisitor.visit_map(map).map(Value::Struct)
+    }
+}
+
+struct StructVisitor;
+
+impl<'de> Visitor<'de> for StructVisitor {
+    type Value = Struct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Struct, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some((name, value)) = map.next_entry::<String, Value>()? {
+            fields.push((name, value));
+        }
+        Ok(fields.into_iter().collect())
+    }
+}
+
+/// Deserializes `null` as [`Value::Void`], sequences as models, and maps as structs.
+///
+/// The type of the value is the one of the data: a string is deserialized as [`Value::String`]
+/// even when it's meant for a property of an enumeration type.
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Struct {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(StructVisitor)
+    }
+}
+
+#[test]
+fn serialize_deserialize_value() {
+    let value: Value = serde_json::from_str(
+        r#"[{"name": "apple", "count": 3, "price": 1.5, "ripe": true, "origin": null}, {"tags": ["a"]}]"#,
+    )
+    .unwrap();
+    let Value::Model(model) = &value else { panic!("expected a model, got {value:?}") };
+    assert_eq!(model.row_count(), 2);
+    let Some(Value::Struct(first)) = model.row_data(0) else { panic!("expected a struct") };
+    assert_eq!(first.get_field("name"), Some(&Value::String("apple".into())));
+    assert_eq!(first.get_field("count"), Some(&Value::Number(3.)));
+    assert_eq!(first.get_field("origin"), Some(&Value::Void));
+
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"[{"count":3,"name":"apple","origin":null,"price":1.5,"ripe":true},{"tags":["a"]}]"#
+    );
+    assert!(serde_json::to_string(&Value::Brush(i_slint_core::Brush::SolidColor(
+        Default::default()
+    )))
+    .is_err());
+}
@@ -0,0 +1,252 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Small images are packed into a few large textures, the atlas pages, instead of getting a
+//! texture each. Icon-heavy scenes then draw from the same texture over and over, instead of
+//! switching the bound texture for every image.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use i_slint_core::graphics::{euclid, IntSize, Rgba8Pixel, SharedImageBuffer};
+use i_slint_core::items::ImageRendering;
+
+use super::itemrenderer::{Canvas, CanvasRc};
+
+/// Width and height of an atlas page, in pixels.
+const PAGE_SIZE: u32 = 1024;
+/// Images larger than this in either direction get a texture of their own.
+pub const MAX_IMAGE_SIZE: u32 = 128;
+/// Each image is surrounded by a copy of its edge pixels, so that linear filtering at the
+/// edges of the image doesn't pick up the colors of its neighbors.
+const PADDING: u32 = 1;
+
+/// A rectangle in the pixels of an atlas page
+pub type AtlasRect = euclid::default::Rect<u32>;
+
+/// A row of the page, where images of a similar height are placed next to each other.
+struct Shelf {
+    y: u32,
+    height: u32,
+    /// The x coordinate and width of the allocated spans, sorted by x.
+    spans: Vec<(u32, u32)>,
+}
+
+impl Shelf {
+    fn allocate(&mut self, width: u32) -> Option<u32> {
+        let mut cursor = 0;
+        let mut index = 0;
+        for (x, span_width) in self.spans.iter() {
+            if x - cursor >= width {
+                break;
+            }
+            cursor = x + span_width;
+            index += 1;
+        }
+        if index == self.spans.len() && PAGE_SIZE - cursor < width {
+            return None;
+        }
+        self.spans.insert(index, (cursor, width));
+        Some(cursor)
+    }
+}
+
+/// A texture that holds many small images, with a shelf allocator for its area.
+pub struct AtlasPage {
+    id: femtovg::ImageId,
+    canvas: CanvasRc,
+    shelves: Vec<Shelf>,
+}
+
+impl AtlasPage {
+    fn new(canvas_rc: &CanvasRc, canvas: &mut Canvas, rendering: ImageRendering) -> Option<Self> {
+        let flags = match rendering {
+            ImageRendering::Smooth => femtovg::ImageFlags::PREMULTIPLIED,
+            ImageRendering::Pixelated => {
+                femtovg::ImageFlags::PREMULTIPLIED | femtovg::ImageFlags::NEAREST
+            }
+        };
+        let id = canvas
+            .create_image_empty(
+                PAGE_SIZE as usize,
+                PAGE_SIZE as usize,
+                femtovg::PixelFormat::Rgba8,
+                flags,
+            )
+            .ok()?;
+        Some(Self { id, canvas: canvas_rc.clone(), shelves: Vec::new() })
+    }
+
+    pub fn id(&self) -> femtovg::ImageId {
+        self.id
+    }
+
+    /// Reserves an area of the given size, including the padding.
+    fn allocate(&mut self, size: IntSize) -> Option<AtlasRect> {
+        // Reuse a shelf that isn't much higher than the image, or any empty shelf
+        for shelf in self.shelves.iter_mut().filter(|shelf| {
+            shelf.height >= size.height
+                && (shelf.height <= size.height * 2 || shelf.spans.is_empty())
+        }) {
+            if let Some(x) = shelf.allocate(size.width) {
+                return Some(AtlasRect::new([x, shelf.y].into(), size));
+            }
+        }
+        let y = self.shelves.last().map_or(0, |shelf| shelf.y + shelf.height);
+        if PAGE_SIZE - y < size.height {
+            return None;
+        }
+        let mut shelf = Shelf { y, height: size.height, spans: Vec::new() };
+        let x = shelf.allocate(size.width)?;
+        self.shelves.push(shelf);
+        Some(AtlasRect::new([x, y].into(), size))
+    }
+
+    fn free(&mut self, rect: AtlasRect) {
+        if let Some(shelf) = self.shelves.iter_mut().find(|shelf| shelf.y == rect.origin.y) {
+            shelf.spans.retain(|(x, _)| *x != rect.origin.x);
+        }
+        // Empty shelves at the bottom are given back, so that the area can be used for other heights
+        while self.shelves.last().is_some_and(|shelf| shelf.spans.is_empty()) {
+            self.shelves.pop();
+        }
+    }
+}
+
+impl Drop for AtlasPage {
+    fn drop(&mut self) {
+        self.canvas.borrow_mut().delete_image(self.id);
+    }
+}
+
+/// The area of an atlas page used by one image. The area is freed when this is dropped.
+pub struct AtlasAllocation {
+    page: Rc<RefCell<AtlasPage>>,
+    /// The area including the padding
+    rect: AtlasRect,
+}
+
+impl AtlasAllocation {
+    /// The texture of the atlas page
+    pub fn id(&self) -> femtovg::ImageId {
+        self.page.borrow().id()
+    }
+
+    /// The area of the image in the atlas page, without the padding
+    pub fn image_rect(&self) -> AtlasRect {
+        AtlasRect::new(
+            self.rect.origin + euclid::vec2(PADDING, PADDING),
+            self.rect.size - IntSize::new(2 * PADDING, 2 * PADDING),
+        )
+    }
+}
+
+impl Drop for AtlasAllocation {
+    fn drop(&mut self) {
+        self.page.borrow_mut().free(self.rect);
+    }
+}
+
+/// The atlas pages, separately for smooth and pixelated scaling.
+#[derive(Default)]
+pub struct TextureAtlas {
+    pages: Vec<(ImageRendering, Rc<RefCell<AtlasPage>>)>,
+}
+
+impl TextureAtlas {
+    /// Returns true if the image is small enough to be placed into the atlas.
+    pub fn accepts(size: IntSize) -> bool {
+        size.width > 0
+            && size.height > 0
+            && size.width <= MAX_IMAGE_SIZE
+            && size.height <= MAX_IMAGE_SIZE
+    }
+
+    /// Copies the image into one of the atlas pages, creating a new page if they are all full.
+    /// `canvas` is the borrowed `canvas_rc`.
+    pub fn insert(
+        &mut self,
+        canvas_rc: &CanvasRc,
+        canvas: &mut Canvas,
+        buffer: &SharedImageBuffer,
+        rendering: ImageRendering,
+    ) -> Option<AtlasAllocation> {
+        let size = buffer.size();
+        if !Self::accepts(size) {
+            return None;
+        }
+        let padded_size = size + IntSize::new(2 * PADDING, 2 * PADDING);
+
+        let allocation = self
+            .pages
+            .iter()
+            .filter(|(page_rendering, _)| *page_rendering == rendering)
+            .find_map(|(_, page)| {
+                let rect = page.borrow_mut().allocate(padded_size)?;
+                Some(AtlasAllocation { page: page.clone(), rect })
+            })
+            .or_else(|| {
+                let page = Rc::new(RefCell::new(AtlasPage::new(canvas_rc, canvas, rendering)?));
+                let rect = page.borrow_mut().allocate(padded_size)?;
+                self.pages.push((rendering, page.clone()));
+                Some(AtlasAllocation { page, rect })
+            })?;
+
+        let pixels = padded_premultiplied_pixels(buffer);
+        canvas
+            .update_image(
+                allocation.id(),
+                imgref::ImgRef::new(
+                    &pixels,
+                    padded_size.width as usize,
+                    padded_size.height as usize,
+                ),
+                allocation.rect.origin.x as usize,
+                allocation.rect.origin.y as usize,
+            )
+            .ok()?;
+        Some(allocation)
+    }
+
+    /// Releases the pages that don't hold any image anymore.
+    pub fn drain(&mut self) {
+        self.pages.retain(|(_, page)| Rc::strong_count(page) > 1);
+    }
+
+    pub fn clear(&mut self) {
+        self.pages.clear();
+    }
+}
+
+/// Returns the pixels of the image with premultiplied alpha, surrounded by the padding.
+fn padded_premultiplied_pixels(buffer: &SharedImageBuffer) -> Vec<Rgba8Pixel> {
+    let buffer = buffer.to_rgb8_or_rgba8().unwrap_or_else(|| buffer.clone());
+    let (width, height) = (buffer.width() as usize, buffer.height() as usize);
+    let pixel = |x: usize, y: usize| -> Rgba8Pixel {
+        match &buffer {
+            SharedImageBuffer::RGB8(b) => {
+                let p = b.as_slice()[y * width + x];
+                Rgba8Pixel { r: p.r, g: p.g, b: p.b, a: 255 }
+            }
+            SharedImageBuffer::RGBA8(b) => {
+                let p = b.as_slice()[y * width + x];
+                let premultiply = |c: u8| ((c as u16 * p.a as u16 + 127) / 255) as u8;
+                Rgba8Pixel { r: premultiply(p.r), g: premultiply(p.g), b: premultiply(p.b), a: p.a }
+            }
+            SharedImageBuffer::RGBA8Premultiplied(b) => b.as_slice()[y * width + x],
+            _ => unreachable!("compact formats are converted with to_rgb8_or_rgba8() first"),
+        }
+    };
+
+    let padding = PADDING as usize;
+    let padded_width = width + 2 * padding;
+    let padded_height = height + 2 * padding;
+    let mut pixels = Vec::with_capacity(padded_width * padded_height);
+    for y in 0..padded_height {
+        let source_y = y.saturating_sub(padding).min(height - 1);
+        for x in 0..padded_width {
+            pixels.push(pixel(x.saturating_sub(padding).min(width - 1), source_y));
+        }
+    }
+    pixels
+}
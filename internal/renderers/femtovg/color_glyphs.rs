@@ -0,0 +1,238 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+// cspell:ignore CBDT CBLC COLR CPAL sbix
+
+//! femtovg draws the outlines of the glyphs with the color of the text, so emoji come out
+//! as monochrome outlines, or not at all when the font only has bitmaps. The glyphs of fonts
+//! with colored layers (COLR/CPAL tables) or with bitmaps (CBDT/CBLC or sbix tables) are drawn
+//! from here instead: the layers as paths filled with the colors of the palette, and the bitmaps
+//! as images.
+
+use i_slint_common::sharedfontdb::fontdb;
+use i_slint_core::graphics::{euclid, SharedImageBuffer, SharedPixelBuffer};
+use ttf_parser::{GlyphId, Tag};
+
+/// The data of a font face, shared with femtovg.
+pub type FontData = std::sync::Arc<dyn AsRef<[u8]>>;
+
+/// A font face that has color glyphs.
+pub struct ColorFont {
+    data: FontData,
+    face_index: u32,
+    fontdb_face_id: fontdb::ID,
+    femtovg_font_id: femtovg::FontId,
+}
+
+/// The representation of a color glyph, in the coordinates of the text.
+pub enum ColorGlyph {
+    /// Paths filled with the color of the palette, or with the paint of the text for `None`
+    Layers(Vec<(femtovg::Path, Option<femtovg::Color>)>),
+    /// A bitmap that covers the rectangle
+    Bitmap(BitmapGlyph, euclid::default::Rect<f32>),
+}
+
+/// Identifies the bitmap of a glyph, at the size of one of the strikes of the font.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BitmapGlyphKey {
+    face: fontdb::ID,
+    glyph_id: u16,
+    pixels_per_em: u16,
+}
+
+/// The bitmap of a glyph, which is decoded when it is not in the texture cache yet.
+pub struct BitmapGlyph {
+    pub key: BitmapGlyphKey,
+    data: FontData,
+    face_index: u32,
+}
+
+impl BitmapGlyph {
+    pub fn decode(&self) -> Option<SharedImageBuffer> {
+        let face = ttf_parser::Face::parse(self.data.as_ref().as_ref(), self.face_index).ok()?;
+        let image = face.glyph_raster_image(GlyphId(self.key.glyph_id), self.key.pixels_per_em)?;
+        if image.format != ttf_parser::RasterImageFormat::PNG {
+            return None;
+        }
+        let image = image::load_from_memory_with_format(image.data, image::ImageFormat::Png)
+            .ok()?
+            .into_rgba8();
+        Some(SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+        )))
+    }
+}
+
+impl ColorFont {
+    /// Returns None if the face has no color glyphs.
+    pub fn new(
+        data: FontData,
+        face_index: u32,
+        fontdb_face_id: fontdb::ID,
+        femtovg_font_id: femtovg::FontId,
+    ) -> Option<Self> {
+        let face = ttf_parser::Face::parse(data.as_ref().as_ref(), face_index).ok()?;
+        let has_table = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag)).is_some();
+        let has_color_glyphs =
+            (has_table(b"COLR") && has_table(b"CPAL")) || has_table(b"CBDT") || has_table(b"sbix");
+        has_color_glyphs.then_some(Self { data, face_index, fontdb_face_id, femtovg_font_id })
+    }
+
+    pub fn femtovg_font_id(&self) -> femtovg::FontId {
+        self.femtovg_font_id
+    }
+
+    /// Returns the color representation of the glyph drawn at `origin` on the baseline, or None
+    /// if it is an ordinary glyph that femtovg can draw.
+    pub fn glyph(
+        &self,
+        glyph_id: u16,
+        pixel_size: f32,
+        origin: euclid::default::Point2D<f32>,
+    ) -> Option<ColorGlyph> {
+        let face = ttf_parser::Face::parse(self.data.as_ref().as_ref(), self.face_index).ok()?;
+        let glyph_id = GlyphId(glyph_id);
+
+        if let Some(layers) = colr_layers(&face, glyph_id) {
+            let scale = pixel_size / face.units_per_em() as f32;
+            let layers = layers
+                .into_iter()
+                .filter_map(|(layer_glyph, palette_index)| {
+                    let mut builder = PathBuilder { path: femtovg::Path::new(), origin, scale };
+                    face.outline_glyph(layer_glyph, &mut builder)?;
+                    // 0xFFFF stands for the color of the text
+                    let color = if palette_index == 0xFFFF {
+                        None
+                    } else {
+                        cpal_color(&face, palette_index)
+                    };
+                    Some((builder.path, color))
+                })
+                .collect();
+            return Some(ColorGlyph::Layers(layers));
+        }
+
+        let image = face.glyph_raster_image(glyph_id, pixel_size.ceil() as u16)?;
+        if image.format != ttf_parser::RasterImageFormat::PNG || image.pixels_per_em == 0 {
+            return None;
+        }
+        let scale = pixel_size / image.pixels_per_em as f32;
+        // The y offset is the distance from the baseline to the bottom of the image, upwards
+        let rect = euclid::rect(
+            origin.x + image.x as f32 * scale,
+            origin.y - (image.y as f32 + image.height as f32) * scale,
+            image.width as f32 * scale,
+            image.height as f32 * scale,
+        );
+        let key = BitmapGlyphKey {
+            face: self.fontdb_face_id,
+            glyph_id: glyph_id.0,
+            pixels_per_em: image.pixels_per_em,
+        };
+        Some(ColorGlyph::Bitmap(
+            BitmapGlyph { key, data: self.data.clone(), face_index: self.face_index },
+            rect,
+        ))
+    }
+}
+
+/// Builds the path of an outline in font units, scaled to pixels and placed at the origin.
+struct PathBuilder {
+    path: femtovg::Path,
+    origin: euclid::default::Point2D<f32>,
+    scale: f32,
+}
+
+impl PathBuilder {
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        // The y axis of the font goes up
+        (self.origin.x + x * self.scale, self.origin.y - y * self.scale)
+    }
+}
+
+impl ttf_parser::OutlineBuilder for PathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.path.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.path.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x, y) = self.point(x, y);
+        self.path.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x2, y2) = self.point(x2, y2);
+        let (x, y) = self.point(x, y);
+        self.path.bezier_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.path.close();
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+/// Returns the layers of the glyph in the version 0 records of the COLR table, from bottom to
+/// top, with the index of their color in the palette.
+fn colr_layers(face: &ttf_parser::Face, glyph_id: GlyphId) -> Option<Vec<(GlyphId, u16)>> {
+    let colr = face.raw_face().table(Tag::from_bytes(b"COLR"))?;
+    let base_glyph_count = read_u16(colr, 2)? as usize;
+    let base_glyphs_offset = read_u32(colr, 4)? as usize;
+    let layers_offset = read_u32(colr, 8)? as usize;
+
+    // The base glyph records are sorted by glyph id, and are 6 bytes each
+    let base_glyph = |index: usize| read_u16(colr, base_glyphs_offset + index * 6);
+    let (mut low, mut high) = (0, base_glyph_count);
+    while low < high {
+        let middle = (low + high) / 2;
+        match base_glyph(middle)?.cmp(&glyph_id.0) {
+            core::cmp::Ordering::Less => low = middle + 1,
+            core::cmp::Ordering::Greater => high = middle,
+            core::cmp::Ordering::Equal => {
+                let record = base_glyphs_offset + middle * 6;
+                let first_layer = read_u16(colr, record + 2)? as usize;
+                let layer_count = read_u16(colr, record + 4)? as usize;
+                // The layer records are 4 bytes each
+                return (first_layer..first_layer + layer_count)
+                    .map(|layer| {
+                        let record = layers_offset + layer * 4;
+                        Some((GlyphId(read_u16(colr, record)?), read_u16(colr, record + 2)?))
+                    })
+                    .collect();
+            }
+        }
+    }
+    None
+}
+
+/// Returns the color at the index of the first palette of the CPAL table.
+fn cpal_color(face: &ttf_parser::Face, palette_index: u16) -> Option<femtovg::Color> {
+    let cpal = face.raw_face().table(Tag::from_bytes(b"CPAL"))?;
+    let palette_entry_count = read_u16(cpal, 2)?;
+    if palette_index >= palette_entry_count {
+        return None;
+    }
+    let color_records_offset = read_u32(cpal, 8)? as usize;
+    let first_color_index = read_u16(cpal, 12)? as usize;
+    // The color records are blue, green, red, and alpha bytes
+    let record = color_records_offset + (first_color_index + palette_index as usize) * 4;
+    let [blue, green, red, alpha]: [u8; 4] = cpal.get(record..record + 4)?.try_into().ok()?;
+    Some(femtovg::Color::rgba(red, green, blue, alpha))
+}
@@ -11,14 +11,23 @@
 use i_slint_core::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
 use i_slint_core::lengths::PointLengths;
 use i_slint_core::lengths::{LogicalLength, LogicalSize, ScaleFactor, SizeLengths};
+use i_slint_core::textlayout::{is_word_separator, TabStops};
 use i_slint_core::{SharedString, SharedVector};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
 
+use super::color_glyphs::{BitmapGlyph, ColorFont, ColorGlyph};
 use super::{PhysicalLength, PhysicalPoint, PhysicalSize};
 
 pub const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12.);
 
+/// The emoji fonts of the platforms, which are used as fallbacks after the ones of the system.
+const EMOJI_FONT_FAMILIES: &[&str] =
+    &["Apple Color Emoji", "Segoe UI Emoji", "Noto Color Emoji", "Twemoji Mozilla", "Emoji One"];
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 struct FontCacheKey {
     family: SharedString,
@@ -31,9 +40,31 @@ struct FontCacheKey {
 pub struct Font {
     fonts: SharedVector<femtovg::FontId>,
     pixel_size: PhysicalLength,
+    /// femtovg has neither word spacing nor tab stops, so the words are drawn and measured
+    /// separately, and moved by the spacing and the tabs before them.
+    word_spacing: PhysicalLength,
+    /// The tab stops, with the interval that applies if the request has none
+    tab_stops: TabStops<f32>,
+    /// The fonts of `fonts` that have color glyphs, which femtovg can't draw
+    color_fonts: Vec<Rc<ColorFont>>,
+    /// The distance between the tops of consecutive lines, if it isn't the height of the font
+    line_height: Option<PhysicalLength>,
+    /// The additional distance before a line that follows a line break
+    paragraph_spacing: PhysicalLength,
     text_context: TextContext,
 }
 
+/// Returns true if the line of text ends with a line break, so that the next line starts a new
+/// paragraph.
+fn ends_paragraph(line: &str) -> bool {
+    line.ends_with(['\n', '\r', '\u{2029}'])
+}
+
+/// Returns true for the characters between the words that [`Font::fill_text`] draws separately.
+fn is_word_boundary(c: char) -> bool {
+    c == '\t' || is_word_separator(c)
+}
+
 impl Font {
     pub fn init_paint(
         &self,
@@ -54,35 +85,179 @@ pub fn text_size(
         max_width: Option<PhysicalLength>,
     ) -> PhysicalSize {
         let paint = self.init_paint(letter_spacing, femtovg::Paint::default());
-        let font_metrics = self.text_context.measure_font(&paint).unwrap();
-        let mut lines = 0;
+        let font_height =
+            PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().height());
+        let mut height = PhysicalLength::default();
+        let mut previous_line_advance = None;
         let mut width = 0.;
         let mut start = 0;
         if let Some(max_width) = max_width {
             while start < text.len() {
                 let max_line_index = text[start..].find('\n').map_or(text.len(), |i| i + 1 + start);
-                let index = self
-                    .text_context
-                    .break_text(max_width.get(), &text[start..max_line_index], &paint)
-                    .unwrap();
+                let index = self.break_text(max_width.get(), &text[start..max_line_index], &paint);
                 if index == 0 {
                     break;
                 }
                 let index = start + index;
-                let measure =
-                    self.text_context.measure_text(0., 0., &text[start..index], &paint).unwrap();
+                let line_width = self.text_width(&text[start..index], &paint);
+                let advance = self.line_advance(&text[start..index], font_height);
+                if let Some(previous_line_advance) = previous_line_advance.replace(advance) {
+                    height += previous_line_advance;
+                }
                 start = index;
-                lines += 1;
-                width = measure.width().max(width);
+                width = line_width.max(width);
             }
         } else {
-            for line in text.lines() {
-                let measure = self.text_context.measure_text(0., 0., line, &paint).unwrap();
-                lines += 1;
-                width = measure.width().max(width);
+            for line in text.split_inclusive('\n') {
+                let advance = self.line_advance(line, font_height);
+                if let Some(previous_line_advance) = previous_line_advance.replace(advance) {
+                    height += previous_line_advance;
+                }
+                width = self.text_width(line.trim_end_matches(['\n', '\r']), &paint).max(width);
+            }
+        }
+        if previous_line_advance.is_some() {
+            // The last line has no paragraph spacing after it
+            height += self.line_height(font_height);
+        }
+        PhysicalSize::from_lengths(PhysicalLength::new(width), height)
+    }
+
+    /// Returns the width of the text, including the word spacing and the tabs.
+    pub fn text_width(&self, text: &str, paint: &femtovg::Paint) -> f32 {
+        let metrics = self.text_context.measure_text(0., 0., text, paint).unwrap();
+        ShapedLine::new(text, &metrics, paint, self).width()
+    }
+
+    /// Returns true if the text needs to be drawn word by word.
+    fn has_word_boundaries(&self, text: &str) -> bool {
+        if self.word_spacing.get() == 0. {
+            text.contains('\t')
+        } else {
+            text.contains(is_word_boundary)
+        }
+    }
+
+    /// Returns the byte offset at which to break the text so that it fits into the width,
+    /// like `TextContext::break_text`, but taking the word spacing and the tabs into account.
+    fn break_text(&self, max_width: f32, text: &str, paint: &femtovg::Paint) -> usize {
+        let mut index = self.text_context.break_text(max_width, text, paint).unwrap();
+        if self.has_word_boundaries(text) {
+            // Break again with the width that is left besides the spacing that femtovg doesn't
+            // know about, until the line doesn't change anymore.
+            for _ in 0..4 {
+                let line = text[..index].trim_end();
+                let metrics = self.text_context.measure_text(0., 0., line, paint).unwrap();
+                let added_width =
+                    ShapedLine::new(line, &metrics, paint, self).width() - metrics.width();
+                let next =
+                    self.text_context.break_text(max_width - added_width, text, paint).unwrap();
+                if next == index || next == 0 {
+                    break;
+                }
+                index = next;
             }
         }
-        euclid::size2(width, lines as f32 * font_metrics.height())
+        index
+    }
+
+    /// Fills the text like `Canvas::fill_text`, with the word spacing and tab stops of the font,
+    /// and with the colors of the color glyphs. `bitmap_glyph_paint` returns the paint that fills
+    /// the rectangle of the glyphs that are bitmaps with their image.
+    pub fn fill_text<R: femtovg::Renderer>(
+        &self,
+        canvas: &mut femtovg::Canvas<R>,
+        x: f32,
+        y: f32,
+        text: &str,
+        paint: &femtovg::Paint,
+        bitmap_glyph_paint: &mut dyn FnMut(
+            &mut femtovg::Canvas<R>,
+            &BitmapGlyph,
+            euclid::default::Rect<f32>,
+        ) -> Option<femtovg::Paint>,
+    ) {
+        let color_glyphs = self.draw_runs(x, y, text, paint, |x, run| {
+            canvas.fill_text(x, y, run, paint).unwrap();
+        });
+        for color_glyph in color_glyphs {
+            match color_glyph {
+                ColorGlyph::Layers(layers) => {
+                    for (path, color) in layers {
+                        match color {
+                            Some(color) => canvas.fill_path(&path, &femtovg::Paint::color(color)),
+                            None => canvas.fill_path(&path, paint),
+                        }
+                    }
+                }
+                ColorGlyph::Bitmap(bitmap, rect) => {
+                    let Some(image_paint) = bitmap_glyph_paint(canvas, &bitmap, rect) else {
+                        continue;
+                    };
+                    let mut path = femtovg::Path::new();
+                    path.rect(rect.min_x(), rect.min_y(), rect.width(), rect.height());
+                    canvas.fill_path(&path, &image_paint);
+                }
+            }
+        }
+    }
+
+    /// Strokes the text like `Canvas::stroke_text`, with the word spacing and tab stops of the font.
+    /// Color glyphs are not stroked.
+    pub fn stroke_text<R: femtovg::Renderer>(
+        &self,
+        canvas: &mut femtovg::Canvas<R>,
+        x: f32,
+        y: f32,
+        text: &str,
+        paint: &femtovg::Paint,
+    ) {
+        self.draw_runs(x, y, text, paint, |x, run| {
+            canvas.stroke_text(x, y, run, paint).unwrap();
+        });
+    }
+
+    /// Calls `draw` with the x position of each word of the text, or once with the whole text
+    /// if there is no word spacing, no tab, and no color font. The words keep the position they
+    /// have in the shaped line. The color glyphs are left out of the words, and returned at
+    /// their position instead.
+    fn draw_runs(
+        &self,
+        x: f32,
+        y: f32,
+        text: &str,
+        paint: &femtovg::Paint,
+        mut draw: impl FnMut(f32, &str),
+    ) -> Vec<ColorGlyph> {
+        let split_at_words = self.has_word_boundaries(text);
+        if !split_at_words && self.color_fonts.is_empty() {
+            draw(x, text);
+            return Vec::new();
+        }
+        let metrics = self.text_context.measure_text(0., 0., text, paint).unwrap();
+        let line = ShapedLine::new(text, &metrics, paint, self);
+        let baseline = y + self.text_context.measure_font(paint).unwrap().ascender();
+        let color_glyphs = line.color_glyphs(x, baseline);
+        let color_clusters =
+            color_glyphs.iter().map(|(byte_index, _)| *byte_index).collect::<Vec<_>>();
+        for (run, run_x) in line.runs(split_at_words, &color_clusters) {
+            draw(x + run_x, &text[run]);
+        }
+        color_glyphs.into_iter().map(|(_, color_glyph)| color_glyph).collect()
+    }
+
+    /// The distance between the tops of consecutive lines, for the height of the font.
+    pub fn line_height(&self, font_height: PhysicalLength) -> PhysicalLength {
+        self.line_height.unwrap_or(font_height)
+    }
+
+    /// Returns the distance from the top of the line of text to the top of the next line.
+    fn line_advance(&self, line: &str, font_height: PhysicalLength) -> PhysicalLength {
+        if ends_paragraph(line) {
+            self.line_height(font_height) + self.paragraph_spacing
+        } else {
+            self.line_height(font_height)
+        }
     }
 
     pub fn height(&self) -> PhysicalLength {
@@ -107,6 +282,30 @@ pub(crate) fn text_size(
         / scale_factor
 }
 
+pub(crate) fn font_metrics(font_request: &FontRequest) -> i_slint_core::items::FontMetrics {
+    let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE);
+    let primary_font = FONT_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .load_single_font(font_request.family.as_ref(), font_request.to_fontdb_query())
+    });
+    sharedfontdb::FONT_DB
+        .with_borrow(|db| {
+            db.with_face_data(primary_font.fontdb_face_id, |face_data, face_index| {
+                let face = ttf_parser::Face::parse(face_data, face_index).ok()?;
+                let scale = pixel_size.get() / face.units_per_em() as f32;
+                Some(i_slint_core::items::FontMetrics {
+                    ascent: face.ascender() as f32 * scale,
+                    descent: face.descender() as f32 * scale,
+                    x_height: face.x_height().unwrap_or_default() as f32 * scale,
+                    cap_height: face.capital_height().unwrap_or_default() as f32 * scale,
+                })
+            })
+        })
+        .flatten()
+        .unwrap_or_default()
+}
+
 #[derive(Copy, Clone)]
 struct LoadedFont {
     femtovg_font_id: femtovg::FontId,
@@ -142,20 +341,26 @@ pub struct FontCache {
     // for a given fontdb face id, this tells us what we've learned about the script
     // coverage of the font.
     loaded_font_coverage: HashMap<fontdb::ID, GlyphCoverage>,
+    /// The loaded fonts that have color glyphs
+    color_fonts: Vec<Rc<ColorFont>>,
     pub(crate) text_context: TextContext,
     available_families: HashSet<SharedString>,
 }
 
+fn available_families() -> HashSet<SharedString> {
+    sharedfontdb::FONT_DB.with(|db| {
+        db.borrow()
+            .faces()
+            .filter_map(|face_info| {
+                face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
+            })
+            .collect()
+    })
+}
+
 impl Default for FontCache {
     fn default() -> Self {
-        let available_families = sharedfontdb::FONT_DB.with(|db| {
-            db.borrow()
-                .faces()
-                .filter_map(|face_info| {
-                    face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
-                })
-                .collect()
-        });
+        let available_families = available_families();
 
         let text_context = TextContext::default();
         text_context.resize_shaped_words_cache(NonZeroUsize::new(10_000_000).unwrap());
@@ -164,6 +369,7 @@ fn default() -> Self {
         Self {
             loaded_fonts: HashMap::new(),
             loaded_font_coverage: HashMap::new(),
+            color_fonts: Vec::new(),
             text_context,
             available_families,
         }
@@ -234,9 +440,15 @@ fn load_single_font(
         });
 
         let femtovg_font_id = text_context
-            .add_shared_font_with_index(SharedFontData(shared_data), face_index)
+            .add_shared_font_with_index(SharedFontData(shared_data.clone()), face_index)
             .unwrap();
 
+        if let Some(color_font) =
+            ColorFont::new(shared_data, face_index, fontdb_face_id, femtovg_font_id)
+        {
+            self.color_fonts.push(Rc::new(color_font));
+        }
+
         //println!("Loaded {:#?} in {}ms.", request, now.elapsed().as_millis());
         let new_font = LoadedFont { femtovg_font_id, fontdb_face_id };
         self.loaded_fonts.insert(cache_key, new_font);
@@ -284,12 +496,25 @@ pub fn font(
         //);
 
         let fallbacks = if !matches!(coverage_result, GlyphCoverageCheckResult::Complete) {
-            self.font_fallbacks_for_request(
+            // The fallbacks requested by the application take precedence over the ones of the system
+            let mut fallbacks = font_request
+                .fallback_families
+                .iter()
+                .filter(|family| self.is_known_family(family))
+                .cloned()
+                .collect::<Vec<_>>();
+            fallbacks.extend(self.font_fallbacks_for_request(
                 font_request.family.as_ref(),
                 pixel_size,
                 &primary_font,
                 reference_text,
-            )
+            ));
+            for family in EMOJI_FONT_FAMILIES {
+                if self.is_known_family(family) && !fallbacks.iter().any(|f| f == family) {
+                    fallbacks.push((*family).into());
+                }
+            }
+            fallbacks
         } else {
             Vec::new()
         };
@@ -319,7 +544,51 @@ pub fn font(
             }))
             .collect::<SharedVector<_>>();
 
-        Font { fonts, text_context: self.text_context.clone(), pixel_size }
+        let word_spacing = font_request.word_spacing.unwrap_or_default() * scale_factor;
+
+        let tab_width = font_request.tab_width.filter(|width| width.get() > 0.);
+        let tab_interval = match tab_width {
+            Some(tab_width) => (tab_width * scale_factor).get(),
+            None => {
+                let mut paint = femtovg::Paint::default();
+                paint.set_font(&fonts);
+                paint.set_font_size(pixel_size.get());
+                let space = self.text_context.measure_text(0., 0., " ", &paint).unwrap();
+                space.glyphs.first().map_or(0., |glyph| glyph.advance_x * 8.)
+            }
+        };
+        let tab_stops = TabStops {
+            positions: font_request
+                .tab_stops
+                .iter()
+                .map(|position| (*position * scale_factor).get())
+                .collect(),
+            interval: Some(tab_interval),
+        };
+
+        let color_fonts = self
+            .color_fonts
+            .iter()
+            .filter(|color_font| fonts.contains(&color_font.femtovg_font_id()))
+            .cloned()
+            .collect();
+
+        let mut font = Font {
+            fonts,
+            text_context: self.text_context.clone(),
+            pixel_size,
+            word_spacing,
+            tab_stops,
+            color_fonts,
+            line_height: None,
+            paragraph_spacing: PhysicalLength::new(
+                font_request.physical_paragraph_spacing(scale_factor.get()).unwrap_or_default(),
+            ),
+        };
+        font.line_height = font_request
+            .physical_line_height(font.height().get(), scale_factor.get())
+            .map(PhysicalLength::new);
+        font
     }
 
     #[cfg(target_os = "macos")]
@@ -463,6 +732,11 @@ fn font_fallbacks_for_request(
             .collect()
     }
 
+    /// Called after registering fonts, so that they can be used as fallbacks.
+    pub fn update_available_families(&mut self) {
+        self.available_families = available_families();
+    }
+
     fn is_known_family(&self, family: &str) -> bool {
         self.available_families.contains(family)
     }
@@ -560,10 +834,11 @@ pub(crate) fn layout_text_lines(
     single_line: bool,
     cursor_byte_offset: Option<usize>,
     paint: &femtovg::Paint,
-    mut layout_line: impl FnMut(&str, PhysicalPoint, usize, &femtovg::TextMetrics),
+    mut layout_line: impl FnMut(&str, PhysicalPoint, usize, &ShapedLine),
 ) -> Option<PhysicalPoint> {
     let wrap = wrap != TextWrap::NoWrap;
-    let elide = overflow == TextOverflow::Elide;
+    let elide = overflow != TextOverflow::Clip;
+    let horizontal_alignment = horizontal_alignment.resolve_for_text(string);
 
     let max_width = max_size.width_length();
     let max_height = max_size.height_length();
@@ -571,12 +846,15 @@ pub(crate) fn layout_text_lines(
     let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
     let font_metrics = text_context.measure_font(paint).unwrap();
     let font_height = PhysicalLength::new(font_metrics.height());
+    let line_height = font.line_height(font_height);
+    // The glyphs are centered vertically in the line
+    let half_leading = (line_height - font_height) / 2.;
 
     let mut cursor_point: Option<PhysicalPoint> = None;
 
     let text_height = || {
         if single_line {
-            font_height
+            line_height
         } else {
             // Note: this is kind of doing twice the layout because text_size also does it
             let text_height = font
@@ -591,56 +869,46 @@ pub(crate) fn layout_text_lines(
                 // If the full text doesn't fit into max_height and eliding is
                 // enabled, calculate the height of the max number of lines that
                 // fit to ensure correct vertical alignment when elided.
-                let max_lines = (max_height.get() / font_height.get()).floor();
-                font_height * max_lines
+                let max_lines = ((max_height - font_height).get() / line_height.get()).floor() + 1.;
+                line_height * max_lines.max(0.)
             } else {
                 text_height
             }
         }
     };
 
-    let mut process_line =
-        |text_span: &str, y: PhysicalLength, start: usize, line_metrics: &femtovg::TextMetrics| {
-            let x = match horizontal_alignment {
-                TextHorizontalAlignment::Left => PhysicalLength::default(),
-                TextHorizontalAlignment::Center => {
-                    max_width / 2. - max_width.min(PhysicalLength::new(line_metrics.width())) / 2.
-                }
-                TextHorizontalAlignment::Right => {
-                    max_width - max_width.min(PhysicalLength::new(line_metrics.width()))
-                }
-            };
-            let line_pos = PhysicalPoint::from_lengths(x, y);
-            layout_line(text_span, line_pos, start, line_metrics);
+    let mut process_line = |text_span: &str, y: PhysicalLength, start: usize, line: &ShapedLine| {
+        let y = y + half_leading;
+        let x = match horizontal_alignment {
+            TextHorizontalAlignment::Center => {
+                max_width / 2. - max_width.min(PhysicalLength::new(line.width())) / 2.
+            }
+            TextHorizontalAlignment::Right => {
+                max_width - max_width.min(PhysicalLength::new(line.width()))
+            }
+            _ => PhysicalLength::default(),
+        };
+        let line_pos = PhysicalPoint::from_lengths(x, y);
+        layout_line(text_span, line_pos, start, line);
 
-            if let Some(cursor_byte_offset) = cursor_byte_offset {
-                let text_span_range = start..(start + text_span.len());
+        if let Some(cursor_byte_offset) = cursor_byte_offset {
+            let text_span_range = start..(start + text_span.len());
 
-                if text_span_range.contains(&cursor_byte_offset)
-                    || (cursor_byte_offset == text_span_range.end
-                        && cursor_byte_offset == string.len()
-                        && !string.ends_with('\n'))
-                {
-                    let cursor_x = PhysicalLength::new(
-                        line_metrics
-                            .glyphs
-                            .iter()
-                            .find_map(|glyph| {
-                                if glyph.byte_index == (cursor_byte_offset - start) {
-                                    Some(glyph.x)
-                                } else {
-                                    None
-                                }
-                            })
-                            .unwrap_or_else(|| line_metrics.width()),
-                    );
-                    cursor_point = Some(PhysicalPoint::from_lengths(
-                        line_pos.x_length() + cursor_x,
-                        line_pos.y_length(),
-                    ));
-                }
+            if text_span_range.contains(&cursor_byte_offset)
+                || (cursor_byte_offset == text_span_range.end
+                    && cursor_byte_offset == string.len()
+                    && !string.ends_with('\n'))
+            {
+                let cursor_x = PhysicalLength::new(
+                    line.cursor_x(cursor_byte_offset - start).unwrap_or_else(|| line.width()),
+                );
+                cursor_point = Some(PhysicalPoint::from_lengths(
+                    line_pos.x_length() + cursor_x,
+                    line_pos.y_length(),
+                ));
             }
-        };
+        }
+    };
 
     let baseline_y = match vertical_alignment {
         TextVerticalAlignment::Top => PhysicalLength::default(),
@@ -649,12 +917,12 @@ pub(crate) fn layout_text_lines(
     };
     let mut y = baseline_y;
     let mut start = 0;
-    'lines: while start < string.len() && y + font_height <= max_height {
-        if wrap && (!elide || y + font_height * 2. <= max_height) {
+    // Whether the glyphs of a line that starts at the given y fit into the height
+    let fits = |y: PhysicalLength| y + half_leading + font_height <= max_height;
+    'lines: while start < string.len() && fits(y) {
+        if wrap && (!elide || fits(y + line_height)) {
             let max_line_index = string[start..].find('\n').map_or(string.len(), |i| i + 1 + start);
-            let index = text_context
-                .break_text(max_width.get(), &string[start..max_line_index], paint)
-                .unwrap();
+            let index = font.break_text(max_width.get(), &string[start..max_line_index], paint);
             if index == 0 {
                 // FIXME the word is too big to be shown, but we should still break, ideally
                 break;
@@ -662,8 +930,8 @@ pub(crate) fn layout_text_lines(
             let index = start + index;
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
-            process_line(line, y, start, &text_metrics);
-            y += font_height;
+            process_line(line, y, start, &ShapedLine::new(line, &text_metrics, paint, font));
+            y += font.line_advance(line, font_height);
             start = index;
         } else {
             let index = if single_line {
@@ -673,9 +941,9 @@ pub(crate) fn layout_text_lines(
             };
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
-            let elide_last_line =
-                elide && index < string.len() && y + font_height * 2. > max_height;
-            if text_metrics.width() > max_width.get() || elide_last_line {
+            let shaped_line = ShapedLine::new(line, &text_metrics, paint, font);
+            let elide_last_line = elide && index < string.len() && !fits(y + line_height);
+            if shaped_line.width() > max_width.get() || elide_last_line {
                 let w = max_width
                     - if elide {
                         PhysicalLength::new(
@@ -684,32 +952,47 @@ pub(crate) fn layout_text_lines(
                     } else {
                         PhysicalLength::default()
                     };
-                let mut current_x = 0.;
-                for glyph in &text_metrics.glyphs {
-                    current_x += glyph.advance_x;
-                    if current_x >= w.get() {
-                        let txt = &line[..glyph.byte_index];
-                        if elide {
-                            let elided = format!("{}…", txt);
-                            process_line(&elided, y, start, &text_metrics);
-                        } else {
-                            process_line(txt, y, start, &text_metrics);
-                        }
-                        y += font_height;
+                if matches!(overflow, TextOverflow::ElideLeft | TextOverflow::ElideMiddle)
+                    && !elide_last_line
+                {
+                    let start_width =
+                        if overflow == TextOverflow::ElideMiddle { w.get() / 2. } else { 0. };
+                    if let Some(elided_range) =
+                        shaped_line.byte_range_to_elide(w.get(), start_width)
+                    {
+                        let elided = format!(
+                            "{}…{}",
+                            &line[..elided_range.start],
+                            line[elided_range.end..].trim_end_matches('\n')
+                        );
+                        process_line(&elided, y, start, &shaped_line);
+                        y += font.line_advance(line, font_height);
                         start = index;
                         continue 'lines;
                     }
                 }
+                if let Some(cut) = shaped_line.byte_offset_exceeding_width(w.get()) {
+                    let txt = &line[..cut];
+                    if elide {
+                        let elided = format!("{}…", txt);
+                        process_line(&elided, y, start, &shaped_line);
+                    } else {
+                        process_line(txt, y, start, &shaped_line);
+                    }
+                    y += font.line_advance(line, font_height);
+                    start = index;
+                    continue 'lines;
+                }
                 if elide_last_line {
                     let elided = format!("{}…", line.strip_suffix('\n').unwrap_or(line));
-                    process_line(&elided, y, start, &text_metrics);
-                    y += font_height;
+                    process_line(&elided, y, start, &shaped_line);
+                    y += font.line_advance(line, font_height);
                     start = index;
                     continue 'lines;
                 }
             }
-            process_line(line, y, start, &text_metrics);
-            y += font_height;
+            process_line(line, y, start, &shaped_line);
+            y += font.line_advance(line, font_height);
             start = index;
         }
     }
@@ -717,11 +1000,296 @@ pub(crate) fn layout_text_lines(
     cursor_point.or_else(|| {
         cursor_byte_offset.map(|_| {
             let x = match horizontal_alignment {
-                TextHorizontalAlignment::Left => PhysicalLength::default(),
                 TextHorizontalAlignment::Center => max_size.width_length() / 2.,
                 TextHorizontalAlignment::Right => max_size.width_length(),
+                _ => PhysicalLength::default(),
             };
-            PhysicalPoint::from_lengths(x, y)
+            PhysicalPoint::from_lengths(x, y + half_leading)
         })
     })
 }
+
+/// A line of text that was shaped by femtovg, as passed to the callback of [`layout_text_lines`].
+///
+/// The glyphs of the line are in visual order. For right-to-left text, and for glyphs that
+/// the shaper produced for several characters, the position in the text can't be derived from
+/// the order of the glyphs, so the line is looked at in clusters of glyphs instead.
+pub(crate) struct ShapedLine<'a> {
+    /// The text that was measured, which may extend past the text that is drawn
+    pub text: &'a str,
+    pub metrics: &'a femtovg::TextMetrics,
+    letter_spacing: f32,
+    font: &'a Font,
+}
+
+impl<'a> ShapedLine<'a> {
+    pub fn new(
+        text: &'a str,
+        metrics: &'a femtovg::TextMetrics,
+        paint: &femtovg::Paint,
+        font: &'a Font,
+    ) -> Self {
+        Self { text, metrics, letter_spacing: paint.letter_spacing(), font }
+    }
+
+    /// Returns the width of the line, including the word spacing and the tabs.
+    pub fn width(&self) -> f32 {
+        self.metrics.width() + self.glyph_extents().1
+    }
+
+    /// Returns the byte index of the glyphs in visual order with their left and right edge, after adding the word
+    /// spacing and moving the glyphs after a tab to the next tab stop, together with the width
+    /// that this added to the line.
+    fn glyph_extents(&self) -> (Vec<(usize, f32, f32)>, f32) {
+        let word_spacing = self.font.word_spacing.get();
+        let mut added_width = 0.;
+        let extents = self
+            .metrics
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let left = glyph.x - glyph.offset_x - glyph.bearing_x + added_width;
+                let advance = glyph.advance_x + self.letter_spacing;
+                let right = if glyph.c == '\t' {
+                    self.font.tab_stops.next_stop(left, 0.)
+                } else if is_word_separator(glyph.c) {
+                    left + advance + word_spacing
+                } else {
+                    left + advance
+                };
+                added_width += right - left - advance;
+                (glyph.byte_index, left, right)
+            })
+            .collect();
+        (extents, added_width)
+    }
+
+    /// Returns the byte ranges of the runs of the line, with the x position of their left edge.
+    /// The line is split at the word boundaries if `split_at_words` is true, and around the
+    /// clusters that start at the byte offsets of `skipped_clusters`, which are left out.
+    fn runs(&self, split_at_words: bool, skipped_clusters: &[usize]) -> Vec<(Range<usize>, f32)> {
+        let clusters = self.clusters();
+        let mut separators = clusters
+            .iter()
+            .map(|cluster| cluster.byte_range.clone())
+            .filter(|range| skipped_clusters.contains(&range.start))
+            .collect::<Vec<_>>();
+        if split_at_words {
+            separators.extend(
+                self.text
+                    .match_indices(is_word_boundary)
+                    .map(|(index, separator)| index..index + separator.len()),
+            );
+        }
+        separators.sort_by_key(|separator| separator.start);
+
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for separator in separators.into_iter().chain(core::iter::once(self.text.len()..0)) {
+            let run = start..separator.start;
+            start = start.max(separator.end);
+            let left = clusters
+                .iter()
+                .filter(|cluster| run.contains(&cluster.byte_range.start))
+                .map(|cluster| cluster.x)
+                .min_by(f32::total_cmp);
+            if let Some(left) = left {
+                runs.push((run, left));
+            }
+        }
+        runs
+    }
+
+    /// Returns the glyphs of the line that have a color representation, with the byte index of
+    /// their cluster, for the line drawn at `x` with its baseline at `baseline`.
+    fn color_glyphs(&self, x: f32, baseline: f32) -> Vec<(usize, ColorGlyph)> {
+        if self.font.color_fonts.is_empty() {
+            return Vec::new();
+        }
+        let pixel_size = self.font.pixel_size.get();
+        self.glyph_extents()
+            .0
+            .into_iter()
+            .zip(&self.metrics.glyphs)
+            .filter_map(|((byte_index, left, _), glyph)| {
+                let color_font = self
+                    .font
+                    .color_fonts
+                    .iter()
+                    .find(|color_font| color_font.femtovg_font_id() == glyph.font_id)?;
+                let origin = euclid::point2(x + left + glyph.offset_x, baseline);
+                let color_glyph = color_font.glyph(glyph.codepoint, pixel_size, origin)?;
+                Some((byte_index, color_glyph))
+            })
+            .collect()
+    }
+
+    /// Groups the glyphs by the part of the text they were produced for, in visual order.
+    fn clusters(&self) -> Vec<GlyphCluster> {
+        // Same paragraph direction as femtovg uses for shaping
+        let bidi_info = unicode_bidi::BidiInfo::new(self.text, Some(unicode_bidi::Level::ltr()));
+
+        let mut clusters: Vec<GlyphCluster> = Vec::new();
+        for (byte_index, left, right) in self.glyph_extents().0 {
+            match clusters.last_mut() {
+                Some(cluster) if cluster.byte_range.start == byte_index => {
+                    let cluster_right = (cluster.x + cluster.width).max(right);
+                    cluster.x = cluster.x.min(left);
+                    cluster.width = cluster_right - cluster.x;
+                }
+                _ => clusters.push(GlyphCluster {
+                    byte_range: byte_index..byte_index,
+                    x: left,
+                    width: right - left,
+                    rtl: bidi_info.levels.get(byte_index).is_some_and(|level| level.is_rtl()),
+                }),
+            }
+        }
+
+        // A cluster ends where the cluster that follows it in the text starts
+        let mut starts =
+            clusters.iter().map(|cluster| cluster.byte_range.start).collect::<Vec<_>>();
+        starts.sort_unstable();
+        for cluster in clusters.iter_mut() {
+            let next = starts.partition_point(|start| *start <= cluster.byte_range.start);
+            cluster.byte_range.end = starts.get(next).copied().unwrap_or(self.text.len());
+        }
+        clusters
+    }
+
+    /// Returns the x position of the cursor placed before the given byte offset of the line,
+    /// or None if the line has no glyphs for it.
+    pub fn cursor_x(&self, byte_offset: usize) -> Option<f32> {
+        let clusters = self.clusters();
+        if let Some(cluster) =
+            clusters.iter().find(|cluster| cluster.byte_range.contains(&byte_offset))
+        {
+            return Some(cluster.x_for_byte_offset(self.text, byte_offset));
+        }
+        // After the last character, the cursor is at the trailing edge of that character
+        clusters
+            .iter()
+            .max_by_key(|cluster| cluster.byte_range.start)
+            .filter(|cluster| cluster.byte_range.end == byte_offset)
+            .map(|cluster| cluster.x_for_fraction(1.))
+    }
+
+    /// Returns the byte offset of the line for a cursor placed at the x position, which is
+    /// always at a grapheme boundary.
+    pub fn byte_offset_for_x(&self, x: f32) -> usize {
+        let distance =
+            |cluster: &GlyphCluster| (cluster.x - x).max(x - (cluster.x + cluster.width)).max(0.);
+        self.clusters()
+            .iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .map_or(0, |cluster| cluster.byte_offset_for_x(self.text, x))
+    }
+
+    /// Returns the horizontal ranges that show the given byte range of the line, from left to
+    /// right. Right-to-left text within left-to-right text splits a range into several ones.
+    pub fn x_ranges_for_byte_range(&self, byte_range: Range<usize>) -> Vec<Range<f32>> {
+        let mut ranges: Vec<Range<f32>> = Vec::new();
+        for cluster in self.clusters() {
+            let start = byte_range.start.max(cluster.byte_range.start);
+            let end = byte_range.end.min(cluster.byte_range.end);
+            if start >= end {
+                continue;
+            }
+            let a = cluster.x_for_byte_offset(self.text, start);
+            let b = cluster.x_for_byte_offset(self.text, end);
+            let range = a.min(b)..a.max(b);
+            match ranges.last_mut() {
+                Some(last) if (range.start - last.end).abs() < 0.5 => last.end = range.end,
+                _ => ranges.push(range),
+            }
+        }
+        ranges
+    }
+
+    /// Returns the byte offset of the first character, in the order of the text, that doesn't
+    /// fit into the width anymore. Returns None if the whole line fits.
+    pub fn byte_offset_exceeding_width(&self, width: f32) -> Option<usize> {
+        let mut clusters = self.clusters();
+        clusters.sort_by_key(|cluster| cluster.byte_range.start);
+        let mut current_x = 0.;
+        clusters.iter().find_map(|cluster| {
+            current_x += cluster.width;
+            (current_x >= width).then_some(cluster.byte_range.start)
+        })
+    }
+
+    /// Returns the byte range of the line to replace with an ellipsis, so that the text before and
+    /// after it fits into the width, keeping at most `start_width` of the text before it.
+    /// Returns None if the whole line fits. A trailing newline is never part of the result.
+    pub fn byte_range_to_elide(&self, width: f32, start_width: f32) -> Option<Range<usize>> {
+        let text_len = self.text.trim_end_matches('\n').len();
+        let mut clusters = self.clusters();
+        clusters.retain(|cluster| cluster.byte_range.start < text_len);
+        clusters.sort_by_key(|cluster| cluster.byte_range.start);
+        let total_width: f32 = clusters.iter().map(|cluster| cluster.width).sum();
+        if total_width <= width {
+            return None;
+        }
+
+        let mut kept_start_width = 0.;
+        let start = clusters
+            .iter()
+            .position(|cluster| {
+                if kept_start_width + cluster.width > start_width {
+                    return true;
+                }
+                kept_start_width += cluster.width;
+                false
+            })
+            .unwrap_or(clusters.len());
+        let mut kept_end_width = total_width - kept_start_width;
+        let end = (start..clusters.len())
+            .find(|index| {
+                kept_end_width -= clusters[*index].width;
+                kept_start_width + kept_end_width <= width
+            })
+            .map_or(clusters.len(), |index| index + 1);
+
+        let byte_offset =
+            |index: usize| clusters.get(index).map_or(text_len, |cluster| cluster.byte_range.start);
+        Some(byte_offset(start)..byte_offset(end))
+    }
+}
+
+/// The glyphs that the shaper produced for one part of the text, such as a ligature, or a
+/// character together with its combining marks.
+struct GlyphCluster {
+    byte_range: Range<usize>,
+    x: f32,
+    width: f32,
+    /// Right-to-left clusters start at their right edge
+    rtl: bool,
+}
+
+impl GlyphCluster {
+    /// Returns the x position of the byte offset of the line, which must be within the cluster or
+    /// at its end. Clusters that cover several graphemes are divided evenly between them.
+    fn x_for_byte_offset(&self, line: &str, byte_offset: usize) -> f32 {
+        let graphemes = line[self.byte_range.clone()].grapheme_indices(true);
+        let count = graphemes.clone().count().max(1);
+        let before =
+            graphemes.take_while(|(index, _)| self.byte_range.start + index < byte_offset).count();
+        self.x_for_fraction(before as f32 / count as f32)
+    }
+
+    fn x_for_fraction(&self, fraction: f32) -> f32 {
+        let fraction = if self.rtl { 1. - fraction } else { fraction };
+        self.x + self.width * fraction
+    }
+
+    /// Returns the byte offset of the grapheme boundary within the cluster that is the closest
+    /// to the x position.
+    fn byte_offset_for_x(&self, line: &str, x: f32) -> usize {
+        let fraction = if self.width > 0. { ((x - self.x) / self.width).clamp(0., 1.) } else { 0. };
+        let fraction = if self.rtl { 1. - fraction } else { fraction };
+        let text = &line[self.byte_range.clone()];
+        let index = (fraction * text.graphemes(true).count() as f32).round() as usize;
+        self.byte_range.start
+            + text.grapheme_indices(true).nth(index).map_or(text.len(), |(offset, _)| offset)
+    }
+}
@@ -7,19 +7,38 @@
 use i_slint_core::graphics::euclid;
 #[cfg(not(target_arch = "wasm32"))]
 use i_slint_core::graphics::BorrowedOpenGLTexture;
-use i_slint_core::graphics::{ImageCacheKey, IntSize, SharedImageBuffer};
+use i_slint_core::graphics::{
+    AnimatedImageFrame, ImageCacheKey, IntSize, OpaqueImageVTable, SharedImageBuffer,
+};
 use i_slint_core::lengths::PhysicalPx;
 use i_slint_core::{items::ImageRendering, ImageInner};
 
-use super::itemrenderer::CanvasRc;
+use super::atlas::{AtlasAllocation, TextureAtlas};
+use super::color_glyphs::{BitmapGlyph, BitmapGlyphKey};
+use super::itemrenderer::{Canvas, CanvasRc};
 
 pub struct Texture {
     pub id: femtovg::ImageId,
     canvas: CanvasRc,
+    /// Set when the texture is an area of an atlas page, and `id` is the page.
+    atlas_allocation: Option<AtlasAllocation>,
 }
 
 impl Texture {
     pub fn size(&self) -> Option<IntSize> {
+        match &self.atlas_allocation {
+            Some(allocation) => Some(allocation.image_rect().size),
+            None => self.storage_size(),
+        }
+    }
+
+    /// The number of bytes of GPU memory that the texture occupies, assuming four bytes per pixel.
+    pub fn size_in_bytes(&self) -> usize {
+        self.size().map_or(0, |size| size.area() as usize * 4)
+    }
+
+    /// The size of the femtovg image `id`, which is larger than the texture for atlas textures.
+    pub fn storage_size(&self) -> Option<IntSize> {
         self.canvas
             .borrow()
             .image_info(self.id)
@@ -27,12 +46,20 @@ pub fn size(&self) -> Option<IntSize> {
             .ok()
     }
 
+    /// The position of the texture in the femtovg image `id`. Image paints must be moved
+    /// by the negated offset.
+    pub fn atlas_offset(&self) -> euclid::default::Vector2D<f32> {
+        self.atlas_allocation.as_ref().map_or_else(Default::default, |allocation| {
+            allocation.image_rect().origin.to_vector().cast()
+        })
+    }
+
     pub fn as_render_target(&self) -> femtovg::RenderTarget {
         femtovg::RenderTarget::Image(self.id)
     }
 
     pub fn adopt(canvas: &CanvasRc, image_id: femtovg::ImageId) -> Rc<Texture> {
-        Texture { id: image_id, canvas: canvas.clone() }.into()
+        Texture { id: image_id, canvas: canvas.clone(), atlas_allocation: None }.into()
     }
 
     pub fn new_empty_on_gpu(canvas: &CanvasRc, width: u32, height: u32) -> Option<Rc<Texture>> {
@@ -48,10 +75,11 @@ pub fn new_empty_on_gpu(canvas: &CanvasRc, width: u32, height: u32) -> Option<Rc
                 femtovg::ImageFlags::PREMULTIPLIED | femtovg::ImageFlags::FLIP_Y,
             )
             .unwrap();
-        Some(Self { canvas: canvas.clone(), id: image_id }.into())
+        Some(Self { canvas: canvas.clone(), id: image_id, atlas_allocation: None }.into())
     }
 
     pub(crate) fn filter(&self, filter: femtovg::ImageFilter) -> Rc<Self> {
+        debug_assert!(self.atlas_allocation.is_none(), "atlas textures can't be filtered");
         let size = self.size().unwrap();
         let filtered_image = Self::new_empty_on_gpu(&self.canvas, size.width, size.height).expect(
             "internal error: this can only fail if the filtered image was zero width or height",
@@ -62,18 +90,45 @@ pub(crate) fn filter(&self, filter: femtovg::ImageFilter) -> Rc<Self> {
         filtered_image
     }
 
+    /// Returns the paint that fills the rectangle with the texture, for a canvas that is already
+    /// borrowed.
+    pub fn as_paint_for_rect(
+        &self,
+        canvas: &Canvas,
+        rect: euclid::default::Rect<f32>,
+    ) -> Option<femtovg::Paint> {
+        let info = canvas.image_info(self.id).ok()?;
+        let storage_size = euclid::size2(info.width() as f32, info.height() as f32);
+        let size = self
+            .atlas_allocation
+            .as_ref()
+            .map_or(storage_size, |allocation| allocation.image_rect().size.cast());
+        let scale = euclid::vec2(rect.width() / size.width, rect.height() / size.height);
+        let offset = self.atlas_offset().component_mul(scale);
+        Some(femtovg::Paint::image(
+            self.id,
+            rect.min_x() - offset.x,
+            rect.min_y() - offset.y,
+            storage_size.width * scale.x,
+            storage_size.height * scale.y,
+            0.,
+            1.,
+        ))
+    }
+
     pub fn as_paint(&self) -> femtovg::Paint {
         self.as_paint_with_alpha(1.0)
     }
 
     pub fn as_paint_with_alpha(&self, alpha_tint: f32) -> femtovg::Paint {
         let size = self
-            .size()
+            .storage_size()
             .expect("internal error: CachedImage::as_paint() called on zero-sized texture");
+        let offset = self.atlas_offset();
         femtovg::Paint::image(
             self.id,
-            0.,
-            0.,
+            -offset.x,
+            -offset.y,
             size.width as f32,
             size.height as f32,
             0.,
@@ -151,6 +206,7 @@ pub fn new_from_image(
             }
             _ => {
                 let buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+                let buffer = buffer.to_rgb8_or_rgba8().unwrap_or(buffer);
                 let (image_source, flags) = image_buffer_to_image_source(&buffer);
                 canvas.borrow_mut().create_image(image_source, image_flags | flags).unwrap()
             }
@@ -158,11 +214,37 @@ pub fn new_from_image(
 
         Some(Self::adopt(canvas, image_id))
     }
+
+    /// Places the image into an atlas page, if it is small enough and its pixels are available.
+    fn new_in_atlas(
+        image: &ImageInner,
+        canvas: &CanvasRc,
+        atlas: &mut TextureAtlas,
+        target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
+        scaling: ImageRendering,
+    ) -> Option<Rc<Self>> {
+        if !TextureAtlas::accepts(
+            target_size_for_scalable_source
+                .map_or(image.size(), |size| IntSize::new(size.width, size.height)),
+        ) {
+            return None;
+        }
+        let buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+        let allocation = atlas.insert(canvas, &mut canvas.borrow_mut(), &buffer, scaling)?;
+        Some(Rc::new(Self {
+            id: allocation.id(),
+            canvas: canvas.clone(),
+            atlas_allocation: Some(allocation),
+        }))
+    }
 }
 
 impl Drop for Texture {
     fn drop(&mut self) {
-        self.canvas.borrow_mut().delete_image(self.id);
+        // The atlas page is deleted by the atlas, once all its textures are gone
+        if self.atlas_allocation.is_none() {
+            self.canvas.borrow_mut().delete_image(self.id);
+        }
     }
 }
 
@@ -187,10 +269,53 @@ pub fn new(
     }
 }
 
+// Texture showing a frame of an animated image. When switching to another frame, only the
+// region that changed is uploaded.
+struct AnimationTexture {
+    texture: Rc<Texture>,
+    frame: usize,
+}
+
 // Cache used to avoid repeatedly decoding images from disk. Entries with a count
 // of 1 are drained after flushing the renderer commands to the screen.
+// The second map holds the textures of animated images, by animation generation.
+// The third map holds the textures of small images that are placed in the atlas.
+// The last field holds the bitmaps of color glyphs, which are also placed in the atlas.
+#[derive(Default)]
+pub struct TextureCache(
+    HashMap<TextureCacheKey, Rc<Texture>>,
+    HashMap<(usize, ImageRendering), AnimationTexture>,
+    HashMap<TextureCacheKey, Rc<Texture>>,
+    TextureAtlas,
+    BitmapGlyphCache,
+);
+
+/// The number of bitmap glyphs that are kept in the atlas, before the least recently used ones
+/// are evicted.
+const MAX_BITMAP_GLYPHS: usize = 512;
+
+/// The textures of the bitmaps of color glyphs. As the same glyphs tend to be drawn again, they
+/// are kept after they are drawn, until there are too many of them.
 #[derive(Default)]
-pub struct TextureCache(HashMap<TextureCacheKey, Rc<Texture>>);
+pub struct BitmapGlyphCache {
+    /// The textures with the frame in which they were last drawn
+    textures: HashMap<BitmapGlyphKey, (Rc<Texture>, u64)>,
+    frame: u64,
+}
+
+impl BitmapGlyphCache {
+    /// Removes the least recently used glyphs when there are too many, and starts the next frame.
+    /// This must be called after flushing, when the canvas isn't borrowed.
+    fn drain(&mut self) {
+        if self.textures.len() > MAX_BITMAP_GLYPHS {
+            let mut frames: Vec<u64> = self.textures.values().map(|(_, frame)| *frame).collect();
+            frames.sort_unstable();
+            let oldest_kept = frames[frames.len() - MAX_BITMAP_GLYPHS];
+            self.textures.retain(|_, (_, frame)| *frame >= oldest_kept);
+        }
+        self.frame += 1;
+    }
+}
 
 impl TextureCache {
     // Look up the given image cache key in the image cache and upgrade the weak reference to a strong one if found,
@@ -212,6 +337,115 @@ pub(crate) fn lookup_image_in_cache_or_create(
         })
     }
 
+    // Returns a texture in the atlas for small images, shared with other users of the same image.
+    // Returns None if the image doesn't fit into the atlas.
+    pub(crate) fn lookup_atlas_texture_or_create(
+        &mut self,
+        image: &ImageInner,
+        canvas: &CanvasRc,
+        target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
+        scaling: ImageRendering,
+    ) -> Option<Rc<Texture>> {
+        let Some(cache_key) = TextureCacheKey::new(image, target_size_for_scalable_source, scaling)
+        else {
+            return Texture::new_in_atlas(
+                image,
+                canvas,
+                &mut self.3,
+                target_size_for_scalable_source,
+                scaling,
+            );
+        };
+        if let Some(texture) = self.2.get(&cache_key) {
+            return Some(texture.clone());
+        }
+        let texture = Texture::new_in_atlas(
+            image,
+            canvas,
+            &mut self.3,
+            target_size_for_scalable_source,
+            scaling,
+        )?;
+        self.2.insert(cache_key, texture.clone());
+        Some(texture)
+    }
+
+    // Returns the texture of the animation, updated to show the given frame.
+    pub(crate) fn animation_frame_texture(
+        &mut self,
+        frame: &vtable::VRc<OpaqueImageVTable, AnimatedImageFrame>,
+        canvas: &CanvasRc,
+        scaling: ImageRendering,
+    ) -> Option<Rc<Texture>> {
+        let animation = frame.animation();
+        let key = (animation.generation(), scaling);
+        if let Some(entry) = self.1.get_mut(&key) {
+            if entry.frame != frame.index() {
+                let dirty_rect = animation.dirty_rect_between(entry.frame, frame.index());
+                if !dirty_rect.is_empty() {
+                    let rect = dirty_rect.cast::<usize>();
+                    let buffer = &frame.frame().buffer;
+                    let stride = buffer.width() as usize;
+                    let pixels = rect
+                        .y_range()
+                        .flat_map(|y| &buffer.as_slice()[y * stride..][rect.x_range()])
+                        .copied()
+                        .collect::<Vec<_>>();
+                    canvas
+                        .borrow_mut()
+                        .update_image(
+                            entry.texture.id,
+                            imgref::ImgRef::new(&pixels, rect.width(), rect.height()),
+                            rect.min_x(),
+                            rect.min_y(),
+                        )
+                        .ok()?;
+                }
+                entry.frame = frame.index();
+            }
+            return Some(entry.texture.clone());
+        }
+
+        let texture = Texture::new_from_image(
+            &ImageInner::AnimatedFrame(frame.clone()),
+            canvas,
+            None,
+            scaling,
+        )?;
+        self.1.insert(key, AnimationTexture { texture: texture.clone(), frame: frame.index() });
+        Some(texture)
+    }
+
+    // Returns the texture with the bitmap of a color glyph. This is called while drawing text,
+    // when the canvas is already borrowed.
+    pub(crate) fn bitmap_glyph_texture(
+        &mut self,
+        glyph: &BitmapGlyph,
+        canvas_rc: &CanvasRc,
+        canvas: &mut Canvas,
+    ) -> Option<Rc<Texture>> {
+        let glyphs = &mut self.4;
+        if let Some((texture, frame)) = glyphs.textures.get_mut(&glyph.key) {
+            *frame = glyphs.frame;
+            return Some(texture.clone());
+        }
+        let buffer = glyph.decode()?;
+        let texture = match self.3.insert(canvas_rc, canvas, &buffer, ImageRendering::Smooth) {
+            Some(allocation) => Rc::new(Texture {
+                id: allocation.id(),
+                canvas: canvas_rc.clone(),
+                atlas_allocation: Some(allocation),
+            }),
+            // Glyphs larger than the images of the atlas get a texture of their own
+            None => {
+                let (image_source, flags) = image_buffer_to_image_source(&buffer);
+                Texture::adopt(canvas_rc, canvas.create_image(image_source, flags).ok()?)
+            }
+        };
+        glyphs.textures.insert(glyph.key, (texture.clone(), glyphs.frame));
+        Some(texture)
+    }
+
     pub(crate) fn drain(&mut self) {
         self.0.retain(|_, cached_image| {
             // * Retain images that are used by elements, so that they can be effectively
@@ -224,10 +458,19 @@ pub(crate) fn drain(&mut self) {
             // image again, etc.
             Rc::strong_count(cached_image) > 1 || cached_image.size().is_none()
         });
+        self.1.retain(|_, animation| Rc::strong_count(&animation.texture) > 1);
+        self.2.retain(|_, cached_image| Rc::strong_count(cached_image) > 1);
+        // Evicted glyphs free their area of the atlas, so they go before draining the atlas
+        self.4.drain();
+        self.3.drain();
     }
 
     pub(crate) fn clear(&mut self) {
         self.0.clear();
+        self.1.clear();
+        self.2.clear();
+        self.3.clear();
+        self.4.textures.clear();
     }
 }
 
@@ -256,5 +499,10 @@ fn image_buffer_to_image_source(
             },
             femtovg::ImageFlags::PREMULTIPLIED,
         ),
+        SharedImageBuffer::RGB565(_)
+        | SharedImageBuffer::Gray8(_)
+        | SharedImageBuffer::Alpha8(_) => {
+            unreachable!("compact formats are converted with to_rgb8_or_rgba8() first")
+        }
     }
 }
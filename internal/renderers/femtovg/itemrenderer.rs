@@ -6,24 +6,28 @@
 use std::rc::Rc;
 
 use euclid::approxeq::ApproxEq;
+use i_slint_core::api::LogicalPosition;
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{IntRect, Point, Size};
+use i_slint_core::graphics::{IntRect, PathDataEvent, PathDataIterator, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
 };
 use i_slint_core::items::{
-    self, Clip, FillRule, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextStrokeStyle,
+    self, BlendMode, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity,
+    PatternTiling, RenderingResult, TextStrokeStyle,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     RectLengths, ScaleFactor, SizeLengths,
 };
+use i_slint_core::textlayout::StyledText;
 use i_slint_core::window::WindowInner;
 use i_slint_core::{Brush, Color, ImageInner, SharedString};
 
+use super::color_glyphs::BitmapGlyph;
 use super::images::{Texture, TextureCacheKey};
 use super::PhysicalSize;
 use super::{fonts, PhysicalBorderRadius, PhysicalLength, PhysicalPoint, PhysicalRect};
@@ -42,6 +46,14 @@ pub enum ItemGraphicsCacheEntry {
         _original_image: Rc<Texture>,
         colorized_image: Rc<Texture>,
     },
+    DropShadow {
+        // The rendered children of the DropShadow item
+        layer: Rc<Texture>,
+        // The blurred shadow, padded by the blur radius on each side
+        shadow: Rc<Texture>,
+    },
+    // The lines of a Text item, which are reused until its text, font, or size changes
+    TextLayout(Rc<TextLayout>),
 }
 
 impl ItemGraphicsCacheEntry {
@@ -49,17 +61,58 @@ fn as_texture(&self) -> &Rc<Texture> {
         match self {
             ItemGraphicsCacheEntry::Texture(image) => image,
             ItemGraphicsCacheEntry::ColorizedImage { colorized_image, .. } => colorized_image,
+            ItemGraphicsCacheEntry::DropShadow { layer, .. } => layer,
+            ItemGraphicsCacheEntry::TextLayout(_) => {
+                unreachable!("text layouts are only cached for Text items, which have no texture")
+            }
         }
     }
     fn is_colorized_image(&self) -> bool {
         matches!(self, ItemGraphicsCacheEntry::ColorizedImage { .. })
     }
+    /// The number of bytes of GPU memory that the textures of the entry occupy. The original image of
+    /// a colorized image is shared through the texture cache, so it doesn't count.
+    pub(super) fn size_in_bytes(entry: &Option<Self>) -> usize {
+        match entry {
+            Some(ItemGraphicsCacheEntry::Texture(texture))
+            | Some(ItemGraphicsCacheEntry::ColorizedImage { colorized_image: texture, .. }) => {
+                texture.size_in_bytes()
+            }
+            Some(ItemGraphicsCacheEntry::DropShadow { layer, shadow }) => {
+                layer.size_in_bytes() + shadow.size_in_bytes()
+            }
+            Some(ItemGraphicsCacheEntry::TextLayout(_)) | None => 0,
+        }
+    }
 }
 
 pub(super) type ItemGraphicsCache = ItemCache<Option<ItemGraphicsCacheEntry>>;
 
+/// The text of a Text item laid out in lines, with the fonts to draw it.
+pub struct TextLayout {
+    /// The physical size of the item that the text was laid out in
+    size: PhysicalSize,
+    text: SharedString,
+    styled_text: Option<StyledText>,
+    font: fonts::Font,
+    /// The font of each span of the styled text
+    span_fonts: Vec<fonts::Font>,
+    lines: Vec<TextLayoutLine>,
+}
+
+struct TextLayoutLine {
+    /// The text of the line, without trailing white space
+    text: String,
+    pos: PhysicalPoint,
+    /// The byte offset of the line in the text
+    start: usize,
+}
+
 const KAPPA90: f32 = 0.55228;
 
+/// Conic gradients are rendered into a texture; this is the maximum width or height of that texture.
+const CONIC_GRADIENT_MAX_TEXTURE_SIZE: f32 = 256.;
+
 #[derive(Clone)]
 struct State {
     scissor: LogicalRect,
@@ -76,11 +129,17 @@ pub struct GLItemRenderer<'a> {
     // because that can only happen after calling `flush`. Otherwise femtovg ends up processing
     // `set_render_target` commands with image ids that have been deleted.
     textures_to_delete_after_flush: RefCell<Vec<Rc<super::images::Texture>>>,
+    // The graphics caches used to render the backdrop of `Blur` items hold textures, so they
+    // must be kept alive until after calling `flush` as well.
+    backdrop_caches_to_delete_after_flush: Vec<ItemGraphicsCache>,
     window: &'a i_slint_core::api::Window,
     scale_factor: ScaleFactor,
     /// track the state manually since femtovg don't have accessor for its state
     state: Vec<State>,
     metrics: RenderingMetrics,
+    /// When rendering the backdrop of a `Blur` item, this is the item at which the rendering stops,
+    /// together with whether that item was reached already.
+    backdrop_of: Option<(ItemRc, bool)>,
 }
 
 fn rect_with_radius_to_path(
@@ -147,6 +206,49 @@ fn path_bounding_box(canvas: &CanvasRc, path: &femtovg::Path) -> euclid::default
     )
 }
 
+/// Returns the size of a layer that covers the item and its children.
+fn children_layer_size(item_rc: &ItemRc, current_clip: &LogicalRect) -> LogicalSize {
+    // We don't need to include the size of the layer item itself, since it has no content.
+    let children_rect = i_slint_core::properties::evaluate_no_tracking(|| {
+        item_rc.geometry().union(&i_slint_core::item_rendering::item_children_bounding_rect(
+            item_rc.item_tree(),
+            item_rc.index() as isize,
+            current_clip,
+        ))
+    });
+    children_rect.size
+}
+
+/// Sets the blend function of the canvas for the given blend mode. The colors are premultiplied.
+/// The modes that can't be expressed with a blend function (overlay, darken and lighten) are drawn
+/// like `BlendMode::Normal`, with a warning the first time.
+fn set_blend_mode(canvas: &mut Canvas, blend_mode: BlendMode) {
+    use femtovg::BlendFactor;
+    match blend_mode {
+        // src * dst + dst * (1 - src_alpha)
+        BlendMode::Multiply => canvas.global_composite_blend_func_separate(
+            BlendFactor::DstColor,
+            BlendFactor::OneMinusSrcAlpha,
+            BlendFactor::One,
+            BlendFactor::OneMinusSrcAlpha,
+        ),
+        // src + dst - src * dst
+        BlendMode::Screen => {
+            canvas.global_composite_blend_func(BlendFactor::One, BlendFactor::OneMinusSrcColor)
+        }
+        BlendMode::Normal => {}
+        BlendMode::Overlay | BlendMode::Darken | BlendMode::Lighten => {
+            static WARNED: std::sync::atomic::AtomicBool =
+                std::sync::atomic::AtomicBool::new(false);
+            if !WARNED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                i_slint_core::debug_log!(
+                    "Warning: blend-mode '{blend_mode}' is not supported by the FemtoVG renderer and is drawn as normal"
+                );
+            }
+        }
+    }
+}
+
 // Return a femtovg::Path (in physical pixels) that represents the clip_rect, radius and border_width (all logical!)
 fn clip_path_for_rect_alike_item(
     clip_rect: LogicalRect,
@@ -176,6 +278,85 @@ pub fn global_alpha_transparent(&self) -> bool {
         self.state.last().unwrap().global_alpha == 0.0
     }
 
+    /// Converts the events of a path in logical coordinates into a femtovg::Path in physical pixels.
+    fn path_events_to_femtovg_path(&self, path_events: &PathDataIterator) -> femtovg::Path {
+        let mut femtovg_path = femtovg::Path::new();
+
+        /// Contrary to the SVG spec, femtovg does not use the orientation of the path to
+        /// know if it needs to fill or not some part, it uses its own Solidity enum.
+        /// We must then compute ourself the orientation and set the Solidity accordingly.
+        #[derive(Default)]
+        struct OrientationCalculator {
+            area: f32,
+            prev: LogicalPosition,
+        }
+
+        impl OrientationCalculator {
+            fn add_point(&mut self, p: LogicalPosition) {
+                self.area += (p.x - self.prev.x) * (p.y + self.prev.y);
+                self.prev = p;
+            }
+        }
+
+        use femtovg::Solidity;
+
+        let mut orient = OrientationCalculator::default();
+
+        for x in path_events.iter() {
+            match x {
+                PathDataEvent::Begin { at } => {
+                    femtovg_path.solidity(if orient.area < 0. {
+                        Solidity::Hole
+                    } else {
+                        Solidity::Solid
+                    });
+                    femtovg_path
+                        .move_to(at.x * self.scale_factor.get(), at.y * self.scale_factor.get());
+                    orient.area = 0.;
+                    orient.prev = at;
+                }
+                PathDataEvent::Line { from: _, to } => {
+                    femtovg_path
+                        .line_to(to.x * self.scale_factor.get(), to.y * self.scale_factor.get());
+                    orient.add_point(to);
+                }
+                PathDataEvent::Quadratic { from: _, ctrl, to } => {
+                    femtovg_path.quad_to(
+                        ctrl.x * self.scale_factor.get(),
+                        ctrl.y * self.scale_factor.get(),
+                        to.x * self.scale_factor.get(),
+                        to.y * self.scale_factor.get(),
+                    );
+                    orient.add_point(to);
+                }
+
+                PathDataEvent::Cubic { from: _, ctrl1, ctrl2, to } => {
+                    femtovg_path.bezier_to(
+                        ctrl1.x * self.scale_factor.get(),
+                        ctrl1.y * self.scale_factor.get(),
+                        ctrl2.x * self.scale_factor.get(),
+                        ctrl2.y * self.scale_factor.get(),
+                        to.x * self.scale_factor.get(),
+                        to.y * self.scale_factor.get(),
+                    );
+                    orient.add_point(to);
+                }
+                PathDataEvent::End { last: _, first: _, close } => {
+                    femtovg_path.solidity(if orient.area < 0. {
+                        Solidity::Hole
+                    } else {
+                        Solidity::Solid
+                    });
+                    if close {
+                        femtovg_path.close()
+                    }
+                }
+            }
+        }
+
+        femtovg_path
+    }
+
     /// Draws a `Rectangle` using the `GLItemRenderer`.
     pub fn draw_rect(&mut self, size: LogicalSize, brush: Brush) {
         let geometry = PhysicalRect::from(size * self.scale_factor);
@@ -271,16 +452,18 @@ fn draw_border_rectangle(
                 paint
             });
 
-        let mut canvas = self.canvas.borrow_mut();
-        if let Some(paint) = fill_paint {
-            canvas.fill_path(&background_path, &paint);
-        }
-        if let Some(border_paint) = border_paint {
-            canvas.stroke_path(
-                maybe_border_path.as_mut().unwrap_or(&mut background_path),
-                &border_paint,
-            );
-        }
+        self.canvas.borrow_mut().save_with(|canvas| {
+            set_blend_mode(canvas, rect.blend_mode());
+            if let Some(paint) = &fill_paint {
+                canvas.fill_path(&background_path, paint);
+            }
+            if let Some(border_paint) = &border_paint {
+                canvas.stroke_path(
+                    maybe_border_path.as_mut().unwrap_or(&mut background_path),
+                    border_paint,
+                );
+            }
+        });
     }
 
     fn draw_image(
@@ -296,7 +479,7 @@ fn draw_image(
     fn draw_text(
         &mut self,
         text: Pin<&dyn RenderText>,
-        _: &ItemRc,
+        item_rc: &ItemRc,
         size: LogicalSize,
         _cache: &CachedRenderingData,
     ) {
@@ -311,19 +494,15 @@ fn draw_text(
             return;
         }
 
-        let string = text.text();
-        let string = string.as_str();
-        let font = fonts::FONT_CACHE.with(|cache| {
-            cache.borrow_mut().font(
-                text.font_request(WindowInner::from_pub(self.window)),
-                self.scale_factor,
-                &text.text(),
-            )
-        });
+        let layout =
+            self.text_layout(text, item_rc, PhysicalSize::from_lengths(max_width, max_height));
+        let string = layout.text.as_str();
+        let font = &layout.font;
 
         let text_path = rect_to_path((size * self.scale_factor).into());
+        let letter_spacing = text.letter_spacing() * self.scale_factor;
         let paint = match self.brush_to_paint(text.color(), &text_path) {
-            Some(paint) => font.init_paint(text.letter_spacing() * self.scale_factor, paint),
+            Some(paint) => font.init_paint(letter_spacing, paint),
             None => return,
         };
 
@@ -338,45 +517,110 @@ fn draw_text(
             TextStrokeStyle::Outside => stroke_width * 2.0,
             TextStrokeStyle::Center => stroke_width,
         };
-        let stroke_paint = match self.brush_to_paint(stroke_brush.clone(), &text_path) {
-            Some(mut paint) => {
-                if stroke_brush.is_transparent() {
-                    None
-                } else {
-                    paint.set_line_width(stroke_width);
-                    Some(font.init_paint(text.letter_spacing() * self.scale_factor, paint))
+        let stroke_paint =
+            |font: &fonts::Font| match self.brush_to_paint(stroke_brush.clone(), &text_path) {
+                Some(mut paint) => {
+                    if stroke_brush.is_transparent() {
+                        None
+                    } else {
+                        paint.set_line_width(stroke_width);
+                        Some(font.init_paint(letter_spacing, paint))
+                    }
                 }
-            }
-            None => None,
-        };
+                None => None,
+            };
 
+        // The font for the style of each span of markup, with its fill and stroke paints
+        let span_paints = layout.styled_text.as_ref().map_or_else(Vec::new, |styled| {
+            styled
+                .spans
+                .iter()
+                .zip(&layout.span_fonts)
+                .map(|(span, font)| {
+                    let brush = span.style.color.map_or_else(|| text.color(), Brush::SolidColor);
+                    // Transparent spans still take their space in the line
+                    let paint = self
+                        .brush_to_paint(brush, &text_path)
+                        .unwrap_or_else(|| femtovg::Paint::color(femtovg::Color::rgba(0, 0, 0, 0)));
+                    let paint = font.init_paint(letter_spacing, paint);
+                    let stroke_paint = stroke_paint(font);
+                    (font, paint, stroke_paint)
+                })
+                .collect::<Vec<_>>()
+        });
+        let stroke_paint = stroke_paint(font);
+
+        let mut bitmap_glyph_paint = self.bitmap_glyph_paint_fn();
         let mut canvas = self.canvas.borrow_mut();
-        fonts::layout_text_lines(
-            string,
-            &font,
-            PhysicalSize::from_lengths(max_width, max_height),
-            text.alignment(),
-            text.wrap(),
-            text.overflow(),
-            false,
-            None,
-            &paint,
-            |to_draw, pos, _, _| {
-                match (stroke_style, &stroke_paint) {
+        let mut draw_text_run =
+            |canvas: &mut Canvas,
+             font: &fonts::Font,
+             x: f32,
+             y: f32,
+             to_draw: &str,
+             paint: &femtovg::Paint,
+             stroke_paint: Option<&femtovg::Paint>| {
+                match (stroke_style, stroke_paint) {
                     (TextStrokeStyle::Outside, Some(stroke_paint)) => {
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        font.stroke_text(canvas, x, y, to_draw, stroke_paint);
+                        font.fill_text(canvas, x, y, to_draw, paint, &mut bitmap_glyph_paint);
                     }
                     (TextStrokeStyle::Center, Some(stroke_paint)) => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
+                        font.fill_text(canvas, x, y, to_draw, paint, &mut bitmap_glyph_paint);
+                        font.stroke_text(canvas, x, y, to_draw, stroke_paint);
                     }
                     _ => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        font.fill_text(canvas, x, y, to_draw, paint, &mut bitmap_glyph_paint);
                     }
                 };
-            },
-        );
+            };
+        for line in &layout.lines {
+            let (to_draw, pos, start) = (line.text.as_str(), line.pos, line.start);
+            let Some(styled) = &layout.styled_text else {
+                draw_text_run(
+                    &mut canvas,
+                    font,
+                    pos.x,
+                    pos.y,
+                    to_draw,
+                    &paint,
+                    stroke_paint.as_ref(),
+                );
+                continue;
+            };
+            // Elided lines are not part of the text, and get the style of the start of the line
+            let elided = string.get(start..start + to_draw.len()) != Some(to_draw);
+            let line_range = if elided { start..start + 1 } else { start..start + to_draw.len() };
+            let mut x = pos.x;
+            for (span, (span_font, span_paint, span_stroke_paint)) in
+                styled.spans.iter().zip(&span_paints)
+            {
+                let range =
+                    span.range.start.max(line_range.start)..span.range.end.min(line_range.end);
+                if range.is_empty() {
+                    continue;
+                }
+                let run = if elided { to_draw } else { &string[range] };
+                draw_text_run(
+                    &mut canvas,
+                    span_font,
+                    x,
+                    pos.y,
+                    run,
+                    span_paint,
+                    span_stroke_paint.as_ref(),
+                );
+                let width = span_font.text_width(run, span_paint);
+                if span.style.underline {
+                    let font_metrics = canvas.measure_font(span_paint).unwrap();
+                    let thickness = (font_metrics.height() / 16.).max(1.);
+                    let mut path = femtovg::Path::new();
+                    path.rect(x, pos.y + font_metrics.ascender() + thickness, width, thickness);
+                    canvas.fill_path(&path, span_paint);
+                }
+                x += width;
+            }
+        }
     }
 
     fn draw_text_input(
@@ -413,11 +657,11 @@ fn draw_text_input(
             None => return,
         };
 
-        let (min_select, max_select) = if !visual_representation.preedit_range.is_empty() {
-            (visual_representation.preedit_range.start, visual_representation.preedit_range.end)
-        } else {
-            (visual_representation.selection_range.start, visual_representation.selection_range.end)
-        };
+        let (min_select, max_select) = (
+            visual_representation.selection_range.start,
+            visual_representation.selection_range.end,
+        );
+        let preedit_range = visual_representation.preedit_range.clone();
 
         let (cursor_visible, cursor_pos) =
             if let Some(cursor_pos) = visual_representation.cursor_position {
@@ -426,6 +670,7 @@ fn draw_text_input(
                 (false, 0)
             };
 
+        let mut bitmap_glyph_paint = self.bitmap_glyph_paint_fn();
         let mut canvas = self.canvas.borrow_mut();
         let font_height = font.height();
         let text: SharedString = visual_representation.text.into();
@@ -440,87 +685,94 @@ fn draw_text_input(
             text_input.single_line(),
             cursor_visible.then_some(cursor_pos),
             &paint,
-            |to_draw: &str, pos: PhysicalPoint, start, metrics: &femtovg::TextMetrics| {
+            |to_draw: &str, pos: PhysicalPoint, start, line: &fonts::ShapedLine| {
                 let range = start..(start + to_draw.len());
                 if min_select != max_select
                     && (range.contains(&min_select)
                         || range.contains(&max_select)
                         || (min_select..max_select).contains(&start))
                 {
-                    let mut selection_start_x = PhysicalLength::default();
-                    let mut selection_end_x = PhysicalLength::default();
-                    let mut after_selection_x = PhysicalLength::default();
-                    // Determine the first and last (inclusive) glyph of the selection. The anchor
-                    // will always be at the start of a grapheme boundary, so there's at ShapedGlyph
-                    // that has a matching byte index. For the selection end we have to look for the
-                    // visual end of glyph before the cursor, because due to for example ligatures
-                    // (or generally glyph substitution) there may not be a dedicated glyph.
-                    // FIXME: in the case of ligature, there is currently no way to know the exact
-                    // position of the split. When we know it, we might need to draw in two
-                    // steps with clip to draw each part of the ligature in a different color
-                    for glyph in &metrics.glyphs {
-                        if glyph.byte_index == min_select.saturating_sub(start) {
-                            selection_start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                        }
-                        if glyph.byte_index == max_select - start
-                            || glyph.byte_index >= to_draw.len()
-                        {
-                            after_selection_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                            break;
-                        }
-                        selection_end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
-                    }
-
-                    let selection_rect = PhysicalRect::new(
-                        pos + PhysicalPoint::from_lengths(
-                            selection_start_x,
-                            PhysicalLength::default(),
-                        )
-                        .to_vector(),
-                        PhysicalSize::from_lengths(
-                            selection_end_x - selection_start_x,
-                            font_height,
-                        ),
-                    );
-                    canvas.fill_path(
-                        &rect_to_path(selection_rect),
-                        &femtovg::Paint::color(to_femtovg_color(
-                            &text_input.selection_background_color(),
-                        )),
+                    // The line is shaped and drawn as a whole, so that characters still join
+                    // across the selection boundary, also within ligatures. The selected part is
+                    // then drawn again in the selection color, clipped to the selection. That part
+                    // may consist of several ranges when the line mixes text directions.
+                    font.fill_text(
+                        &mut canvas,
+                        pos.x,
+                        pos.y,
+                        to_draw.trim_end(),
+                        &paint,
+                        &mut bitmap_glyph_paint,
                     );
                     let mut selected_paint = paint.clone();
                     selected_paint
                         .set_color(to_femtovg_color(&text_input.selection_foreground_color()));
-                    canvas
-                        .fill_text(
+                    let selected_range = min_select.saturating_sub(start)
+                        ..(max_select - start).min(to_draw.trim_end().len());
+                    for x_range in line.x_ranges_for_byte_range(selected_range) {
+                        let selection_rect = PhysicalRect::new(
+                            pos + PhysicalPoint::from_lengths(
+                                PhysicalLength::new(x_range.start),
+                                PhysicalLength::default(),
+                            )
+                            .to_vector(),
+                            PhysicalSize::from_lengths(
+                                PhysicalLength::new(x_range.end - x_range.start),
+                                font_height,
+                            ),
+                        );
+                        canvas.save();
+                        canvas.intersect_scissor(
+                            selection_rect.origin.x,
+                            selection_rect.origin.y,
+                            selection_rect.size.width,
+                            selection_rect.size.height,
+                        );
+                        canvas.fill_path(
+                            &rect_to_path(selection_rect),
+                            &femtovg::Paint::color(to_femtovg_color(
+                                &text_input.selection_background_color(),
+                            )),
+                        );
+                        font.fill_text(
+                            &mut canvas,
                             pos.x,
                             pos.y,
-                            to_draw[..min_select.saturating_sub(start)].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + selection_start_x.get(),
-                            pos.y,
-                            to_draw[min_select.saturating_sub(start)
-                                ..(max_select - start).min(to_draw.len())]
-                                .trim_end(),
+                            to_draw.trim_end(),
                             &selected_paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + after_selection_x.get(),
-                            pos.y,
-                            to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
+                            &mut bitmap_glyph_paint,
+                        );
+                        canvas.restore();
+                    }
                 } else {
                     // no selection on this line
-                    canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                    font.fill_text(
+                        &mut canvas,
+                        pos.x,
+                        pos.y,
+                        to_draw.trim_end(),
+                        &paint,
+                        &mut bitmap_glyph_paint,
+                    );
                 };
+
+                // The text that the input method is composing is underlined
+                if preedit_range.start < range.end && preedit_range.end > start {
+                    let font_metrics = canvas.measure_font(&paint).unwrap();
+                    let thickness = (font_metrics.height() / 16.).max(1.);
+                    let preedit_range_in_line = preedit_range.start.saturating_sub(start)
+                        ..(preedit_range.end - start).min(to_draw.trim_end().len());
+                    let mut path = femtovg::Path::new();
+                    for x_range in line.x_ranges_for_byte_range(preedit_range_in_line) {
+                        path.rect(
+                            pos.x + x_range.start,
+                            pos.y + font_metrics.ascender() + thickness,
+                            x_range.end - x_range.start,
+                            thickness,
+                        );
+                    }
+                    canvas.fill_path(&path, &paint);
+                }
             },
         );
 
@@ -549,79 +801,7 @@ fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, _size: Logica
             None => return,
         };
 
-        let mut femtovg_path = femtovg::Path::new();
-
-        /// Contrary to the SVG spec, femtovg does not use the orientation of the path to
-        /// know if it needs to fill or not some part, it uses its own Solidity enum.
-        /// We must then compute ourself the orientation and set the Solidity accordingly.
-        #[derive(Default)]
-        struct OrientationCalculator {
-            area: f32,
-            prev: Point,
-        }
-
-        impl OrientationCalculator {
-            fn add_point(&mut self, p: Point) {
-                self.area += (p.x - self.prev.x) * (p.y + self.prev.y);
-                self.prev = p;
-            }
-        }
-
-        use femtovg::Solidity;
-
-        let mut orient = OrientationCalculator::default();
-
-        for x in path_events.iter() {
-            match x {
-                lyon_path::Event::Begin { at } => {
-                    femtovg_path.solidity(if orient.area < 0. {
-                        Solidity::Hole
-                    } else {
-                        Solidity::Solid
-                    });
-                    femtovg_path
-                        .move_to(at.x * self.scale_factor.get(), at.y * self.scale_factor.get());
-                    orient.area = 0.;
-                    orient.prev = at;
-                }
-                lyon_path::Event::Line { from: _, to } => {
-                    femtovg_path
-                        .line_to(to.x * self.scale_factor.get(), to.y * self.scale_factor.get());
-                    orient.add_point(to);
-                }
-                lyon_path::Event::Quadratic { from: _, ctrl, to } => {
-                    femtovg_path.quad_to(
-                        ctrl.x * self.scale_factor.get(),
-                        ctrl.y * self.scale_factor.get(),
-                        to.x * self.scale_factor.get(),
-                        to.y * self.scale_factor.get(),
-                    );
-                    orient.add_point(to);
-                }
-
-                lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
-                    femtovg_path.bezier_to(
-                        ctrl1.x * self.scale_factor.get(),
-                        ctrl1.y * self.scale_factor.get(),
-                        ctrl2.x * self.scale_factor.get(),
-                        ctrl2.y * self.scale_factor.get(),
-                        to.x * self.scale_factor.get(),
-                        to.y * self.scale_factor.get(),
-                    );
-                    orient.add_point(to);
-                }
-                lyon_path::Event::End { last: _, first: _, close } => {
-                    femtovg_path.solidity(if orient.area < 0. {
-                        Solidity::Hole
-                    } else {
-                        Solidity::Solid
-                    });
-                    if close {
-                        femtovg_path.close()
-                    }
-                }
-            }
-        }
+        let femtovg_path = self.path_events_to_femtovg_path(&path_events);
 
         let fill_paint = self.brush_to_paint(path.fill(), &femtovg_path).map(|mut fill_paint| {
             fill_paint.set_fill_rule(match path.fill_rule() {
@@ -636,13 +816,30 @@ fn add_point(&mut self, p: Point) {
             paint
         });
 
+        // femtovg can only stroke solid lines, so dashes are stroked as separate sub-paths
+        let dashed_path = border_paint.as_ref().and_then(|_| {
+            let dashes =
+                path_events.dashed(path.dash_pattern().as_slice(), path.dash_offset().get())?;
+            let mut dashed_path = femtovg::Path::new();
+            for x in dashes.iter() {
+                match x {
+                    PathDataEvent::Begin { at } => dashed_path
+                        .move_to(at.x * self.scale_factor.get(), at.y * self.scale_factor.get()),
+                    PathDataEvent::Line { from: _, to } => dashed_path
+                        .line_to(to.x * self.scale_factor.get(), to.y * self.scale_factor.get()),
+                    _ => {}
+                }
+            }
+            Some(dashed_path)
+        });
+
         self.canvas.borrow_mut().save_with(|canvas| {
             canvas.translate(offset.x, offset.y);
             if let Some(fill_paint) = &fill_paint {
                 canvas.fill_path(&femtovg_path, fill_paint);
             }
             if let Some(border_paint) = &border_paint {
-                canvas.stroke_path(&femtovg_path, border_paint);
+                canvas.stroke_path(dashed_path.as_ref().unwrap_or(&femtovg_path), border_paint);
             }
         })
     }
@@ -661,7 +858,8 @@ fn draw_box_shadow(
         item_rc: &ItemRc,
         _size: LogicalSize,
     ) {
-        if box_shadow.color().alpha() == 0
+        if self.backdrop_item_reached()
+            || box_shadow.color().alpha() == 0
             || (box_shadow.blur() == LogicalLength::zero()
                 && box_shadow.offset_x() == LogicalLength::zero()
                 && box_shadow.offset_y() == LogicalLength::zero())
@@ -785,7 +983,7 @@ fn draw_box_shadow(
             let blur = box_shadow.blur() * self.scale_factor;
             let offset = LogicalPoint::from_lengths(box_shadow.offset_x(), box_shadow.offset_y())
                 * self.scale_factor;
-            canvas.translate(offset.x - blur.get(), offset.y - blur.get());
+            canvas.translate(offset.x - padding_x, offset.y - padding_y);
             canvas.fill_path(&shadow_image_rect, &shadow_image_paint);
         });
     }
@@ -820,6 +1018,161 @@ fn visit_layer(
         }
     }
 
+    fn visit_blur(
+        &mut self,
+        blur: Pin<&items::Blur>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        // Blur items that are part of the backdrop of another Blur item are not blurred themselves.
+        if self.backdrop_of.is_some() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        let radius = blur.blur_radius() * self.scale_factor;
+        if radius <= PhysicalLength::zero() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        let Some(backdrop) = self.render_backdrop(item_rc, size, radius) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let blurred_backdrop =
+            backdrop.filter(femtovg::ImageFilter::GaussianBlur { sigma: radius.get() / 2. });
+        self.textures_to_delete_after_flush.borrow_mut().push(backdrop);
+
+        let physical_size = size * self.scale_factor;
+        let backdrop_size = blurred_backdrop.size().unwrap_or_default().cast::<f32>();
+
+        let mut path = femtovg::Path::new();
+        path.rect(0., 0., physical_size.width, physical_size.height);
+        self.canvas.borrow_mut().fill_path(
+            &path,
+            &femtovg::Paint::image(
+                blurred_backdrop.id,
+                -radius.get(),
+                -radius.get(),
+                backdrop_size.width,
+                backdrop_size.height,
+                0.,
+                1.,
+            )
+            .with_anti_alias(false),
+        );
+        self.textures_to_delete_after_flush.borrow_mut().push(blurred_backdrop);
+
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn visit_drop_shadow(
+        &mut self,
+        drop_shadow: Pin<&items::DropShadow>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        if self.backdrop_item_reached() {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        }
+        if !drop_shadow.is_visible() {
+            self.graphics_cache.release(item_rc);
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        let current_clip = self.get_current_clip();
+        let cache_entry = self.render_layer_into_cache_entry(
+            item_rc,
+            &|| children_layer_size(item_rc, &current_clip),
+            |this, layer| {
+                // Negative blur values are ignored
+                let blur = drop_shadow.blur().max(LogicalLength::zero()) * this.scale_factor;
+                let shadow = this.render_drop_shadow(&layer, blur, drop_shadow.color());
+                ItemGraphicsCacheEntry::DropShadow { layer, shadow }
+            },
+        );
+
+        let Some(ItemGraphicsCacheEntry::DropShadow { layer, shadow }) = cache_entry else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+
+        let (Some(layer_size), Some(shadow_size)) = (layer.size(), shadow.size()) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+
+        let offset = LogicalPoint::from_lengths(drop_shadow.offset_x(), drop_shadow.offset_y())
+            * self.scale_factor;
+        // The shadow is padded on all sides to make room for the blur it was rendered with
+        let padding_x = (shadow_size.width as f32 - layer_size.width as f32) / 2.;
+        let padding_y = (shadow_size.height as f32 - layer_size.height as f32) / 2.;
+
+        // On the paints, we don't need anti-aliasing on the fringes, since we are just blitting
+        // textures. This saves a triangle strip for the stroke.
+        let mut shadow_rect = femtovg::Path::new();
+        shadow_rect.rect(0., 0., shadow_size.width as f32, shadow_size.height as f32);
+        let mut layer_rect = femtovg::Path::new();
+        layer_rect.rect(0., 0., layer_size.width as f32, layer_size.height as f32);
+
+        self.canvas.borrow_mut().save_with(|canvas| {
+            canvas.translate(offset.x - padding_x, offset.y - padding_y);
+            canvas.fill_path(&shadow_rect, &shadow.as_paint().with_anti_alias(false));
+        });
+        self.canvas.borrow_mut().fill_path(&layer_rect, &layer.as_paint().with_anti_alias(false));
+
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    fn visit_path_clip(
+        &mut self,
+        path: Pin<&items::Path>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        if path.clip() {
+            self.save_state();
+            self.combine_clip(size.into(), LogicalBorderRadius::zero(), LogicalLength::zero());
+            self.draw_path(path, item_rc, size);
+            self.restore_state();
+        } else {
+            self.draw_path(path, item_rc, size);
+        }
+
+        if !self.get_current_clip().intersects(&LogicalRect::from(size)) {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        }
+
+        // femtovg can only clip to rectangles, so the children are rendered into a layer, which
+        // is then used to fill the shape of the path with anti-aliased edges.
+        let Some(layer_image) = self.render_layer(item_rc, &|| size) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+        let Some((offset, path_events)) = path.fitted_path_events(item_rc) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+        let clip_path = self.path_events_to_femtovg_path(&path_events);
+        let layer_size = layer_image.size().unwrap_or_default().cast::<f32>();
+        // The paint is transformed along with the path, so undo the offset of the path for the layer
+        let mut layer_image_paint = femtovg::Paint::image(
+            layer_image.id,
+            -offset.x,
+            -offset.y,
+            layer_size.width,
+            layer_size.height,
+            0.,
+            1.,
+        );
+        layer_image_paint.set_fill_rule(match path.fill_rule() {
+            FillRule::Nonzero => femtovg::FillRule::NonZero,
+            FillRule::Evenodd => femtovg::FillRule::EvenOdd,
+        });
+
+        self.canvas.borrow_mut().save_with(|canvas| {
+            canvas.translate(offset.x, offset.y);
+            canvas.fill_path(&clip_path, &layer_image_paint);
+        });
+
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
     fn visit_clip(
         &mut self,
         clip_item: Pin<&Clip>,
@@ -905,6 +1258,18 @@ fn get_current_clip(&self) -> LogicalRect {
         self.state.last().unwrap().scissor
     }
 
+    fn filter_item(&mut self, item: &ItemRc) -> (bool, LogicalRect) {
+        let item_geometry = item.geometry();
+        if let Some((backdrop_item, reached)) = &mut self.backdrop_of {
+            // Everything from the Blur item on is in front of it
+            *reached |= backdrop_item == item;
+            if *reached {
+                return (false, item_geometry);
+            }
+        }
+        (self.get_current_clip().intersects(&item_geometry), item_geometry)
+    }
+
     fn save_state(&mut self) {
         self.canvas.borrow_mut().save();
         self.state.push(self.state.last().unwrap().clone());
@@ -942,7 +1307,10 @@ fn draw_cached_pixmap(
         });
         let image_id = match cache_entry {
             Some(ItemGraphicsCacheEntry::Texture(image)) => image.id,
-            Some(ItemGraphicsCacheEntry::ColorizedImage { .. }) => unreachable!(),
+            Some(
+                ItemGraphicsCacheEntry::ColorizedImage { .. }
+                | ItemGraphicsCacheEntry::DropShadow { .. },
+            ) => unreachable!(),
             None => return,
         };
         let mut canvas = self.canvas.borrow_mut();
@@ -977,16 +1345,34 @@ fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
         }
 
         let image_inner: &ImageInner = (&image).into();
-
-        let Some(cached_image) = TextureCacheKey::new(image_inner, None, Default::default())
-            .and_then(|cache_key| {
-                self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(cache_key, || {
-                    Texture::new_from_image(image_inner, &self.canvas, None, Default::default())
+        // Rasterize SVGs at the physical size, so that they stay sharp on high DPI screens
+        let target_size_for_scalable_source = image_inner
+            .is_svg()
+            .then(|| euclid::size2(target_w.get().ceil() as u32, target_h.get().ceil() as u32));
+
+        let Some(cached_image) =
+            TextureCacheKey::new(image_inner, target_size_for_scalable_source, Default::default())
+                .and_then(|cache_key| {
+                    self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
+                        cache_key,
+                        || {
+                            Texture::new_from_image(
+                                image_inner,
+                                &self.canvas,
+                                target_size_for_scalable_source,
+                                Default::default(),
+                            )
+                        },
+                    )
+                })
+                .or_else(|| {
+                    Texture::new_from_image(
+                        image_inner,
+                        &self.canvas,
+                        target_size_for_scalable_source,
+                        Default::default(),
+                    )
                 })
-            })
-            .or_else(|| {
-                Texture::new_from_image(image_inner, &self.canvas, None, Default::default())
-            })
         else {
             return;
         };
@@ -1029,8 +1415,8 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         let angle_in_radians = angle_in_degrees.to_radians();
         self.canvas.borrow_mut().rotate(angle_in_radians);
         let clip = &mut self.state.last_mut().unwrap().scissor;
-        // Compute the bounding box of the rotated rectangle
-        let (sin, cos) = angle_in_radians.sin_cos();
+        // Compute the bounding box of the clip rectangle in the rotated coordinates
+        let (sin, cos) = (-angle_in_radians).sin_cos();
         let rotate_point = |p: LogicalPoint| (p.x * cos - p.y * sin, p.x * sin + p.y * cos);
         let corners = [
             rotate_point(clip.origin),
@@ -1051,6 +1437,22 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         *clip = LogicalRect::new(origin, (end - origin).into());
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.canvas.borrow_mut().scale(x_factor, y_factor);
+        let clip = &mut self.state.last_mut().unwrap().scissor;
+        *clip = if x_factor != 0. && y_factor != 0. {
+            // Negative factors mirror the clip, so normalize the corners
+            let b = clip.to_box2d();
+            let (p1, p2) = (
+                euclid::point2(b.min.x / x_factor, b.min.y / y_factor),
+                euclid::point2(b.max.x / x_factor, b.max.y / y_factor),
+            );
+            euclid::Box2D::from_points([p1, p2]).to_rect()
+        } else {
+            LogicalRect::default()
+        };
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let state = &mut self.state.last_mut().unwrap().global_alpha;
         *state *= opacity;
@@ -1078,6 +1480,7 @@ pub(super) fn new(
             box_shadow_cache: Default::default(),
             canvas: canvas.clone(),
             textures_to_delete_after_flush: Default::default(),
+            backdrop_caches_to_delete_after_flush: Default::default(),
             window,
             scale_factor,
             state: vec![State {
@@ -1089,22 +1492,126 @@ pub(super) fn new(
                 current_render_target: femtovg::RenderTarget::Screen,
             }],
             metrics: RenderingMetrics { layers_created: Some(0) },
+            backdrop_of: None,
+        }
+    }
+
+    /// Returns the layout of the text of the item, from the graphics cache unless the text, its
+    /// font, or the size of the item changed.
+    fn text_layout(
+        &self,
+        text: Pin<&dyn RenderText>,
+        item_rc: &ItemRc,
+        size: PhysicalSize,
+    ) -> Rc<TextLayout> {
+        let layout_text = || {
+            let styled_text = text.styled_text();
+            let string = match &styled_text {
+                Some(styled) => styled.text.as_str().into(),
+                None => text.text(),
+            };
+            let font_request = text.font_request(WindowInner::from_pub(self.window));
+            let font = fonts::FONT_CACHE.with(|cache| {
+                cache.borrow_mut().font(font_request.clone(), self.scale_factor, &string)
+            });
+            let span_fonts = styled_text.as_ref().map_or_else(Vec::new, |styled| {
+                styled
+                    .spans
+                    .iter()
+                    .map(|span| {
+                        fonts::FONT_CACHE.with(|cache| {
+                            cache.borrow_mut().font(
+                                span.style.font_request(&font_request),
+                                self.scale_factor,
+                                &string[span.range.clone()],
+                            )
+                        })
+                    })
+                    .collect()
+            });
+            let letter_spacing = text.letter_spacing() * self.scale_factor;
+            let paint = font.init_paint(letter_spacing, femtovg::Paint::default());
+            let mut lines = Vec::new();
+            fonts::layout_text_lines(
+                &string,
+                &font,
+                size,
+                text.alignment(),
+                text.wrap(),
+                text.overflow(),
+                false,
+                None,
+                &paint,
+                |to_draw, pos, start, _| {
+                    lines.push(TextLayoutLine { text: to_draw.trim_end().into(), pos, start })
+                },
+            );
+            let layout = TextLayout { size, text: string, styled_text, font, span_fonts, lines };
+            Some(ItemGraphicsCacheEntry::TextLayout(Rc::new(layout)))
+        };
+
+        match self.graphics_cache.get_or_update_cache_entry(item_rc, &layout_text) {
+            Some(ItemGraphicsCacheEntry::TextLayout(layout)) if layout.size == size => layout,
+            _ => {
+                // The size isn't tracked by the cache, as it is given by the caller
+                self.graphics_cache.release(item_rc);
+                match self.graphics_cache.get_or_update_cache_entry(item_rc, &layout_text) {
+                    Some(ItemGraphicsCacheEntry::TextLayout(layout)) => layout,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Returns the function that gives `Font::fill_text` the paints of the bitmap glyphs, from
+    /// the texture cache.
+    fn bitmap_glyph_paint_fn(
+        &self,
+    ) -> impl FnMut(&mut Canvas, &BitmapGlyph, euclid::default::Rect<f32>) -> Option<femtovg::Paint> + 'a
+    {
+        let texture_cache = self.texture_cache;
+        let canvas_rc = self.canvas.clone();
+        move |canvas, glyph, rect| {
+            let texture =
+                texture_cache.borrow_mut().bitmap_glyph_texture(glyph, &canvas_rc, canvas)?;
+            texture.as_paint_for_rect(canvas, rect)
         }
     }
 
+    /// Directs all subsequent rendering into the given texture instead of the screen.
+    pub(super) fn set_render_target(&mut self, texture: &Texture) {
+        self.canvas.borrow_mut().set_render_target(texture.as_render_target());
+        self.state.last_mut().unwrap().current_render_target = texture.as_render_target();
+    }
+
     fn render_layer(
         &mut self,
         item_rc: &ItemRc,
         layer_logical_size_fn: &dyn Fn() -> LogicalSize,
     ) -> Option<Rc<Texture>> {
+        self.render_layer_into_cache_entry(item_rc, layer_logical_size_fn, |_, layer_image| {
+            ItemGraphicsCacheEntry::Texture(layer_image)
+        })
+        .map(|item_cache_entry| item_cache_entry.as_texture().clone())
+    }
+
+    /// Renders the children of the item into a texture and stores the cache entry created by
+    /// `make_cache_entry` from that texture in the graphics cache.
+    fn render_layer_into_cache_entry(
+        &mut self,
+        item_rc: &ItemRc,
+        layer_logical_size_fn: &dyn Fn() -> LogicalSize,
+        make_cache_entry: impl FnOnce(&mut Self, Rc<Texture>) -> ItemGraphicsCacheEntry,
+    ) -> Option<ItemGraphicsCacheEntry> {
         let existing_layer_texture =
             self.graphics_cache.with_entry(item_rc, |cache_entry| match cache_entry {
                 Some(ItemGraphicsCacheEntry::Texture(texture)) => Some(texture.clone()),
+                Some(ItemGraphicsCacheEntry::DropShadow { layer, .. }) => Some(layer.clone()),
                 _ => None,
             });
 
-        let cache_entry = self.graphics_cache.get_or_update_cache_entry(item_rc, || {
-            ItemGraphicsCacheEntry::Texture({
+        self.graphics_cache.get_or_update_cache_entry(item_rc, || {
+            let layer_image = {
                 let size = (layer_logical_size_fn() * self.scale_factor).ceil().try_cast()?;
 
                 let layer_image = existing_layer_texture
@@ -1168,29 +1675,15 @@ fn render_layer(
                 }
 
                 layer_image
-            })
-            .into()
-        });
-
-        cache_entry.map(|item_cache_entry| item_cache_entry.as_texture().clone())
+            };
+            Some(make_cache_entry(self, layer_image))
+        })
     }
 
     fn render_and_blend_layer(&mut self, alpha_tint: f32, item_rc: &ItemRc) -> RenderingResult {
         let current_clip = self.get_current_clip();
         if let Some((layer_image, layer_size)) = self
-            .render_layer(item_rc, &|| {
-                // We don't need to include the size of the opacity item itself, since it has no content.
-                let children_rect = i_slint_core::properties::evaluate_no_tracking(|| {
-                    item_rc.geometry().union(
-                        &i_slint_core::item_rendering::item_children_bounding_rect(
-                            item_rc.item_tree(),
-                            item_rc.index() as isize,
-                            &current_clip,
-                        ),
-                    )
-                });
-                children_rect.size
-            })
+            .render_layer(item_rc, &|| children_layer_size(item_rc, &current_clip))
             .and_then(|image| image.size().map(|size| (image, size)))
         {
             let mut layer_path = femtovg::Path::new();
@@ -1205,6 +1698,170 @@ fn render_and_blend_layer(&mut self, alpha_tint: f32, item_rc: &ItemRc) -> Rende
         RenderingResult::ContinueRenderingWithoutChildren
     }
 
+    /// Returns a texture with the shadow of the given layer: The layer is padded by `blur` on each
+    /// side, filled with `color` where it is not transparent, and blurred.
+    fn render_drop_shadow(
+        &self,
+        layer: &Texture,
+        blur: PhysicalLength,
+        color: Color,
+    ) -> Rc<Texture> {
+        let layer_size = layer.size().unwrap_or_default().cast::<f32>();
+        let shadow_width = (layer_size.width + blur.get() * 2.).ceil() as u32;
+        let shadow_height = (layer_size.height + blur.get() * 2.).ceil() as u32;
+
+        let shadow_image = Texture::new_empty_on_gpu(&self.canvas, shadow_width, shadow_height)
+            .expect("unable to create drop shadow texture");
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.save();
+
+            canvas.set_render_target(shadow_image.as_render_target());
+
+            canvas.reset();
+
+            canvas.clear_rect(0, 0, shadow_width, shadow_height, femtovg::Color::rgba(0, 0, 0, 0));
+
+            let mut layer_rect = femtovg::Path::new();
+            layer_rect.rect(blur.get(), blur.get(), layer_size.width, layer_size.height);
+            canvas.fill_path(
+                &layer_rect,
+                &femtovg::Paint::image(
+                    layer.id,
+                    blur.get(),
+                    blur.get(),
+                    layer_size.width,
+                    layer_size.height,
+                    0.,
+                    1.,
+                )
+                .with_anti_alias(false),
+            );
+        }
+
+        let shadow_image = if blur.get() > 0. {
+            let blurred_image =
+                shadow_image.filter(femtovg::ImageFilter::GaussianBlur { sigma: blur.get() / 2. });
+
+            self.canvas.borrow_mut().set_render_target(blurred_image.as_render_target());
+
+            self.textures_to_delete_after_flush.borrow_mut().push(shadow_image);
+
+            blurred_image
+        } else {
+            shadow_image
+        };
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+
+            canvas.global_composite_operation(femtovg::CompositeOperation::SourceIn);
+
+            let mut shadow_image_rect = femtovg::Path::new();
+            shadow_image_rect.rect(0., 0., shadow_width as f32, shadow_height as f32);
+            canvas.fill_path(&shadow_image_rect, &femtovg::Paint::color(to_femtovg_color(&color)));
+
+            canvas.restore();
+
+            canvas.set_render_target(self.current_render_target());
+        }
+
+        shadow_image
+    }
+
+    /// Renders everything that is behind the given item in the window into a new texture, which
+    /// covers the item's geometry extended by `padding` on each side. The blur is not applied.
+    fn render_backdrop(
+        &mut self,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+        padding: PhysicalLength,
+    ) -> Option<Rc<Texture>> {
+        let window_inner = WindowInner::from_pub(self.window);
+        let texture_size = (size * self.scale_factor
+            + euclid::size2(padding.get() * 2., padding.get() * 2.))
+        .ceil()
+        .try_cast::<u32>()?;
+        let texture =
+            Texture::new_empty_on_gpu(&self.canvas, texture_size.width, texture_size.height)?;
+        *self.metrics.layers_created.as_mut().unwrap() += 1;
+
+        // The backdrop is rendered with a separate cache, since layers in there may be only
+        // partially rendered.
+        let graphics_cache = ItemGraphicsCache::default();
+        let mut backdrop_renderer = GLItemRenderer::new(
+            &self.canvas,
+            &graphics_cache,
+            self.texture_cache,
+            self.window,
+            texture_size.width,
+            texture_size.height,
+        );
+        backdrop_renderer.backdrop_of = Some((item_rc.clone(), false));
+        backdrop_renderer.state.last_mut().unwrap().current_render_target =
+            texture.as_render_target();
+
+        let item_origin = item_rc.map_to_window(item_rc.geometry().origin);
+        let texture_origin =
+            item_origin * self.scale_factor - euclid::vec2(padding.get(), padding.get());
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.save();
+            canvas.set_render_target(texture.as_render_target());
+            canvas.reset();
+            canvas.clear_rect(
+                0,
+                0,
+                texture_size.width,
+                texture_size.height,
+                femtovg::Color::rgba(0, 0, 0, 0),
+            );
+        }
+
+        backdrop_renderer.translate(-(texture_origin / self.scale_factor).to_vector());
+
+        if let Some(window_item) = window_inner.window_item() {
+            backdrop_renderer.draw_rect(
+                i_slint_core::lengths::logical_size_from_api(
+                    self.window.size().to_logical(self.scale_factor.get()),
+                ),
+                window_item.as_pin_ref().background(),
+            );
+        }
+
+        i_slint_core::item_rendering::render_component_items(
+            &window_inner.component(),
+            &mut backdrop_renderer,
+            LogicalPoint::default(),
+        );
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.restore();
+            canvas.set_render_target(self.current_render_target());
+        }
+
+        *self.metrics.layers_created.as_mut().unwrap() +=
+            backdrop_renderer.metrics.layers_created.unwrap_or_default();
+        self.textures_to_delete_after_flush
+            .borrow_mut()
+            .append(&mut backdrop_renderer.textures_to_delete_after_flush.borrow_mut());
+        self.backdrop_caches_to_delete_after_flush
+            .append(&mut backdrop_renderer.backdrop_caches_to_delete_after_flush);
+        drop(backdrop_renderer);
+        self.backdrop_caches_to_delete_after_flush.push(graphics_cache);
+
+        Some(texture)
+    }
+
+    /// Returns true if this renderer renders the backdrop of a `Blur` item and that item was
+    /// reached, so that nothing must be drawn anymore.
+    fn backdrop_item_reached(&self) -> bool {
+        self.backdrop_of.as_ref().map_or(false, |(_, reached)| *reached)
+    }
+
     fn colorize_image(
         &self,
         original_cache_entry: ItemGraphicsCacheEntry,
@@ -1229,6 +1886,8 @@ fn colorize_image(
         };
 
         let image_id = original_image.id;
+        let atlas_offset = original_image.atlas_offset();
+        let storage_size: Size = original_image.storage_size().unwrap_or(image_size).cast();
         let colorized_image = self
             .canvas
             .borrow_mut()
@@ -1262,10 +1921,10 @@ fn colorize_image(
                 &image_rect,
                 &femtovg::Paint::image(
                     image_id,
-                    0.,
-                    0.,
-                    image_size.width,
-                    image_size.height,
+                    -atlas_offset.x,
+                    -atlas_offset.y,
+                    storage_size.width,
+                    storage_size.height,
                     0.,
                     1.0,
                 ),
@@ -1322,6 +1981,43 @@ fn draw_image_impl(
 
                 let image_rendering = item.rendering();
 
+                let animated_frame = match image_inner {
+                    ImageInner::AnimatedFrame(frame) => Some(frame),
+                    ImageInner::NineSlice(nine) => match &nine.0 {
+                        ImageInner::AnimatedFrame(frame) => Some(frame),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if let Some(frame) = animated_frame {
+                    return self
+                        .texture_cache
+                        .borrow_mut()
+                        .animation_frame_texture(frame, &self.canvas, image_rendering)
+                        .map(ItemGraphicsCacheEntry::Texture)
+                        .map(|cache_entry| {
+                            self.colorize_image(cache_entry, item.colorize(), image_rendering)
+                        });
+                }
+
+                // Tiled images are repeated by the GPU, which needs a texture of their own
+                if item.tiling() == (ImageTiling::None, ImageTiling::None) {
+                    let atlas_texture =
+                        self.texture_cache.borrow_mut().lookup_atlas_texture_or_create(
+                            image_inner,
+                            &self.canvas,
+                            target_size_for_scalable_source,
+                            image_rendering,
+                        );
+                    if let Some(texture) = atlas_texture {
+                        return Some(self.colorize_image(
+                            ItemGraphicsCacheEntry::Texture(texture),
+                            item.colorize(),
+                            image_rendering,
+                        ));
+                    }
+                }
+
                 TextureCacheKey::new(image_inner, target_size_for_scalable_source, image_rendering)
                     .and_then(|cache_key| {
                         self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
@@ -1374,6 +2070,9 @@ fn draw_image_impl(
         let image_id = cached_image.id;
         let orig_size = image.size().cast::<f32>();
         let buf_size = cached_image.size().unwrap_or_default().cast::<f32>();
+        // Atlas textures are an area of a larger femtovg image
+        let atlas_offset = cached_image.atlas_offset();
+        let storage_size = cached_image.storage_size().unwrap_or_default().cast::<f32>();
         let source_clip_rect = item.source_clip().unwrap_or(IntRect::from_size(orig_size.cast()));
 
         let image_inner: &ImageInner = (&image).into();
@@ -1441,10 +2140,10 @@ fn draw_image_impl(
                             &image_rect,
                             &femtovg::Paint::image(
                                 image_id,
-                                -scale_w * fit.clip_rect.origin.x as f32,
-                                -scale_h * fit.clip_rect.origin.y as f32,
-                                buf_size.cast().width,
-                                buf_size.cast().height,
+                                -scale_w * fit.clip_rect.origin.x as f32 - atlas_offset.x,
+                                -scale_h * fit.clip_rect.origin.y as f32 - atlas_offset.y,
+                                storage_size.width,
+                                storage_size.height,
                                 0.,
                                 1.0,
                             ),
@@ -1456,7 +2155,11 @@ fn draw_image_impl(
                         .push(Texture::adopt(&self.canvas, clipped_image));
                     (clipped_image, Default::default(), texture_size)
                 } else {
-                    (image_id, fit.clip_rect.origin.cast::<f32>(), buf_size)
+                    (
+                        image_id,
+                        fit.clip_rect.origin.cast::<f32>() + atlas_offset.cast_unit(),
+                        storage_size,
+                    )
                 };
             let tiled = fit.tiled.unwrap_or_default();
             let fill_paint = femtovg::Paint::image(
@@ -1479,6 +2182,7 @@ fn draw_image_impl(
             );
 
             self.canvas.borrow_mut().save_with(|canvas| {
+                set_blend_mode(canvas, item.blend_mode());
                 canvas.translate(fit.offset.x, fit.offset.y);
                 canvas.scale(fit.source_to_target_x / scale_w, fit.source_to_target_y / scale_h);
                 canvas.fill_path(&path, &fill_paint);
@@ -1513,15 +2217,148 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                 let path_width = path_bounds.width();
                 let path_height = path_bounds.height();
 
+                let (center, radius) = gradient.circle_for_size(
+                    [path_width, path_height].into(),
+                    (path_width + path_height) / 4.,
+                );
+
                 let stops =
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
-                femtovg::Paint::radial_gradient_stops(
-                    path_width / 2.,
-                    path_height / 2.,
-                    0.,
-                    (path_width + path_height) / 4.,
-                    stops,
+                femtovg::Paint::radial_gradient_stops(center.x, center.y, 0., radius, stops)
+            }
+            Brush::ConicGradient(gradient) => {
+                // femtovg doesn't support conic gradients, so render the gradient into a texture
+                // that is deleted after the next flush.
+                let path_bounds = path_bounding_box(&self.canvas, path);
+                let path_width = path_bounds.width();
+                let path_height = path_bounds.height();
+                if path_width < 1. || path_height < 1. {
+                    return None;
+                }
+                let scale = (CONIC_GRADIENT_MAX_TEXTURE_SIZE / path_width.max(path_height)).min(1.);
+                let width = (path_width * scale).ceil() as usize;
+                let height = (path_height * scale).ceil() as usize;
+                let center = gradient.center();
+                let (center_x, center_y) = (center.x * width as f32, center.y * height as f32);
+                let mut pixels = Vec::with_capacity(width * height);
+                for y in 0..height {
+                    for x in 0..width {
+                        let dx = x as f32 + 0.5 - center_x;
+                        let dy = y as f32 + 0.5 - center_y;
+                        let color = gradient.color_at_angle(dx.atan2(-dy).to_degrees());
+                        let alpha = color.alpha() as u16;
+                        let premultiply = |c: u8| (c as u16 * alpha / 255) as u8;
+                        pixels.push(rgb::RGBA8::new(
+                            premultiply(color.red()),
+                            premultiply(color.green()),
+                            premultiply(color.blue()),
+                            color.alpha(),
+                        ));
+                    }
+                }
+                let image_id = self
+                    .canvas
+                    .borrow_mut()
+                    .create_image(
+                        imgref::Img::new(pixels.as_slice(), width, height),
+                        femtovg::ImageFlags::PREMULTIPLIED,
+                    )
+                    .ok()?;
+                self.textures_to_delete_after_flush
+                    .borrow_mut()
+                    .push(super::images::Texture::adopt(&self.canvas, image_id));
+                femtovg::Paint::image(image_id, 0., 0., path_width, path_height, 0., 1.)
+            }
+            Brush::ImagePattern(pattern) => {
+                let image_inner: &ImageInner = pattern.image().into();
+                let scale_factor = self.scale_factor.get();
+                let offset = pattern.offset() * scale_factor;
+                let tile_size = pattern.tile_size() * scale_factor;
+                if tile_size.is_empty() {
+                    return None;
+                }
+                // Rasterize SVGs at the physical size of a tile
+                let target_size_for_scalable_source = image_inner.is_svg().then(|| {
+                    euclid::size2(tile_size.width.ceil() as u32, tile_size.height.ceil() as u32)
+                });
+                let texture = TextureCacheKey::new(
+                    image_inner,
+                    target_size_for_scalable_source,
+                    Default::default(),
                 )
+                .and_then(|cache_key| {
+                    self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
+                        cache_key,
+                        || {
+                            Texture::new_from_image(
+                                image_inner,
+                                &self.canvas,
+                                target_size_for_scalable_source,
+                                Default::default(),
+                            )
+                        },
+                    )
+                })
+                .or_else(|| {
+                    Texture::new_from_image(
+                        image_inner,
+                        &self.canvas,
+                        target_size_for_scalable_source,
+                        Default::default(),
+                    )
+                })?;
+                let tile_paint = femtovg::Paint::image(
+                    texture.id,
+                    offset.x,
+                    offset.y,
+                    tile_size.width,
+                    tile_size.height,
+                    0.,
+                    1.,
+                );
+                // Keep the texture alive until the paint was used
+                self.textures_to_delete_after_flush.borrow_mut().push(texture);
+
+                if pattern.tiling() == PatternTiling::Repeat {
+                    // Textures are created with REPEAT_X and REPEAT_Y, so femtovg tiles them for us.
+                    tile_paint
+                } else {
+                    // femtovg can only repeat in both directions, so render the single row or
+                    // column of tiles into a texture that covers the shape.
+                    let path_bounds = path_bounding_box(&self.canvas, path);
+                    let path_width = path_bounds.width();
+                    let path_height = path_bounds.height();
+                    if path_width < 1. || path_height < 1. {
+                        return None;
+                    }
+                    let band = Texture::new_empty_on_gpu(
+                        &self.canvas,
+                        path_width.ceil() as u32,
+                        path_height.ceil() as u32,
+                    )?;
+                    let mut band_path = femtovg::Path::new();
+                    if pattern.tiling() == PatternTiling::RepeatX {
+                        band_path.rect(0., offset.y, path_width, tile_size.height);
+                    } else {
+                        band_path.rect(offset.x, 0., tile_size.width, path_height);
+                    }
+                    self.canvas.borrow_mut().save_with(|canvas| {
+                        canvas.reset();
+                        canvas.set_render_target(band.as_render_target());
+                        canvas.clear_rect(
+                            0,
+                            0,
+                            path_width.ceil() as u32,
+                            path_height.ceil() as u32,
+                            femtovg::Color::rgba(0, 0, 0, 0),
+                        );
+                        canvas.fill_path(&band_path, &tile_paint);
+                        canvas.set_render_target(self.current_render_target());
+                    });
+                    let paint = band.as_paint();
+                    self.textures_to_delete_after_flush.borrow_mut().push(band);
+                    paint
+                }
             }
             _ => return None,
         })
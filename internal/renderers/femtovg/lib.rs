@@ -14,7 +14,10 @@
 use i_slint_core::graphics::{euclid, rendering_metrics_collector::RenderingMetricsCollector};
 use i_slint_core::graphics::{BorderRadius, Rgba8Pixel};
 use i_slint_core::graphics::{FontRequest, SharedPixelBuffer};
-use i_slint_core::item_rendering::ItemRenderer;
+use i_slint_core::item_rendering::{
+    DirtyRegion, DirtyRegionHistory, ItemRenderer, PartialRenderer, PartialRenderingCache,
+    RenderText,
+};
 use i_slint_core::items::TextWrap;
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
@@ -32,6 +35,8 @@
 
 use self::itemrenderer::CanvasRc;
 
+mod atlas;
+mod color_glyphs;
 mod fonts;
 mod images;
 mod itemrenderer;
@@ -65,6 +70,14 @@ fn resize(
     /// Returns the address of the OpenGL function specified by name, or a null pointer if the
     /// function does not exist.
     fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void;
+    /// Returns the age of the back buffer, i.e. the number of frames ago its content was rendered,
+    /// as reported for example by the EGL_EXT_buffer_age extension. When the age is known, the
+    /// renderer only repaints the parts of the window that changed since then. The default
+    /// implementation returns zero, meaning that the content of the back buffer is undefined and
+    /// the entire window is repainted.
+    fn buffer_age(&self) -> u32 {
+        0
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -127,6 +140,15 @@ pub struct FemtoVGRenderer {
     texture_cache: RefCell<images::TextureCache>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
+    partial_rendering_cache: RefCell<PartialRenderingCache>,
+    /// The area which we are going to redraw in the next frame, no matter if the items are dirty or not
+    force_dirty: RefCell<DirtyRegion>,
+    /// Force a redraw of the entire window in the next frame, no matter what's dirty.
+    force_screen_refresh: Cell<bool>,
+    /// The dirty regions of the previous frames, to determine what to repaint in the back buffer.
+    dirty_region_history: RefCell<DirtyRegionHistory>,
+    /// The surface size and scale factor of the previous frame.
+    previous_frame_geometry: Cell<Option<(i_slint_core::api::PhysicalSize, f32)>>,
     // Last field, so that it's dropped last and context exists and is current when destroying the FemtoVG canvas
     opengl_context: RefCell<Box<dyn OpenGLInterface>>,
     #[cfg(target_arch = "wasm32")]
@@ -199,6 +221,22 @@ fn internal_render_with_post_callback(
         let window_inner = WindowInner::from_pub(window);
         let scale = window_inner.scale_factor().ceil();
 
+        // Partial rendering is only possible if we know what's in the back buffer. Transformed
+        // rendering and underlays rendered by the rendering notifier would need a repaint anyway.
+        let buffer_age = if rotation_angle_degrees == 0.
+            && translation == (0., 0.)
+            && self.rendering_notifier.borrow().is_none()
+        {
+            self.opengl_context.borrow().buffer_age()
+        } else {
+            0
+        };
+
+        let frame_geometry = (surface_size, window_inner.scale_factor());
+        if self.previous_frame_geometry.replace(Some(frame_geometry)) != Some(frame_geometry) {
+            self.force_screen_refresh.set(true);
+        }
+
         window_inner
             .draw_contents(|components| -> Result<(), PlatformError> {
                 // self.canvas is checked for being Some(...) at the beginning of this function
@@ -207,6 +245,47 @@ fn internal_render_with_post_callback(
                 let window_background_brush =
                     window_inner.window_item().map(|w| w.as_pin_ref().background());
 
+                self.graphics_cache.clear_cache_if_scale_factor_changed(window);
+
+                let mut item_renderer = PartialRenderer::new(
+                    &self.partial_rendering_cache,
+                    self.force_dirty.take(),
+                    self::itemrenderer::GLItemRenderer::new(
+                        &canvas,
+                        &self.graphics_cache,
+                        &self.texture_cache,
+                        window,
+                        width.get(),
+                        height.get(),
+                    ),
+                );
+
+                let logical_window_size = i_slint_core::lengths::logical_size_from_api(
+                    window.size().to_logical(window_inner.scale_factor()),
+                );
+                for (component, origin) in components {
+                    item_renderer.compute_dirty_regions(component, *origin, logical_window_size);
+                }
+
+                let window_rect = LogicalRect::from_size(logical_window_size);
+                if self.force_screen_refresh.take() {
+                    item_renderer.dirty_region = window_rect.into();
+                }
+
+                // The part of the window to repaint, or None to repaint everything
+                let repaint_rect = self
+                    .dirty_region_history
+                    .borrow_mut()
+                    .add_frame(item_renderer.dirty_region.clone(), buffer_age)
+                    .map(|region| {
+                        // Align the region to physical pixels, so that there are no seams
+                        let physical_rect = (region.intersection(window_rect).bounding_rect()
+                            * ScaleFactor::new(window_inner.scale_factor()))
+                        .round_out();
+                        physical_rect / ScaleFactor::new(window_inner.scale_factor())
+                    });
+                item_renderer.dirty_region = repaint_rect.unwrap_or(window_rect).into();
+
                 {
                     let mut femtovg_canvas = canvas.borrow_mut();
                     // We pass an integer that is greater than or equal to the scale factor as
@@ -216,11 +295,17 @@ fn internal_render_with_post_callback(
 
                     // Clear with window background if it is a solid color otherwise it will drawn as gradient
                     if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
+                        let clear_rect = match repaint_rect {
+                            Some(rect) => (rect * ScaleFactor::new(window_inner.scale_factor()))
+                                .round()
+                                .cast::<u32>(),
+                            None => euclid::rect(0, 0, surface_size.width, surface_size.height),
+                        };
                         femtovg_canvas.clear_rect(
-                            0,
-                            0,
-                            surface_size.width,
-                            surface_size.height,
+                            clear_rect.origin.x,
+                            clear_rect.origin.y,
+                            clear_rect.width(),
+                            clear_rect.height(),
                             self::itemrenderer::to_femtovg_color(&clear_color),
                         );
                     }
@@ -249,22 +334,19 @@ fn internal_render_with_post_callback(
                     })?;
                 }
 
-                self.graphics_cache.clear_cache_if_scale_factor_changed(window);
-
-                let mut item_renderer = self::itemrenderer::GLItemRenderer::new(
-                    &canvas,
-                    &self.graphics_cache,
-                    &self.texture_cache,
-                    window,
-                    width.get(),
-                    height.get(),
-                );
+                if let Some(repaint_rect) = repaint_rect {
+                    item_renderer.combine_clip(
+                        repaint_rect,
+                        Default::default(),
+                        Default::default(),
+                    );
+                }
 
                 // Draws the window background as gradient
                 match window_background_brush {
                     Some(Brush::SolidColor(..)) | None => {}
                     Some(brush) => {
-                        item_renderer.draw_rect(
+                        item_renderer.actual_renderer.draw_rect(
                             i_slint_core::lengths::logical_size_from_api(
                                 window.size().to_logical(window_inner.scale_factor()),
                             ),
@@ -357,6 +439,14 @@ fn text_size(
         crate::fonts::text_size(&font_request, scale_factor, text, max_width)
     }
 
+    fn font_metrics(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> i_slint_core::items::FontMetrics {
+        crate::fonts::font_metrics(&font_request)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -384,6 +474,9 @@ fn text_input_byte_offset_for_position(
         let text_context =
             crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
         let font_height = text_context.measure_font(&paint).unwrap().height();
+        // Positions between lines belong to the closest line
+        let half_leading =
+            (font.line_height(PhysicalLength::new(font_height)).get() - font_height) / 2.;
         crate::fonts::layout_text_lines(
             &visual_representation.text,
             &font,
@@ -394,17 +487,14 @@ fn text_input_byte_offset_for_position(
             text_input.single_line(),
             None,
             &paint,
-            |line_text, line_pos, start, metrics| {
-                if (line_pos.y..(line_pos.y + font_height)).contains(&pos.y) {
-                    let mut current_x = 0.;
-                    for glyph in &metrics.glyphs {
-                        if line_pos.x + current_x + glyph.advance_x / 2. >= pos.x {
-                            result = start + glyph.byte_index;
-                            return;
-                        }
-                        current_x += glyph.advance_x;
-                    }
-                    result = start + line_text.trim_end().len();
+            |line_text, line_pos, start, line| {
+                if ((line_pos.y - half_leading)..(line_pos.y + font_height + half_leading))
+                    .contains(&pos.y)
+                {
+                    result = start
+                        + line
+                            .byte_offset_for_x(pos.x - line_pos.x)
+                            .min(line_text.trim_end().len());
                 }
             },
         );
@@ -455,24 +545,150 @@ fn text_input_cursor_rect_for_byte_offset(
         )
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let pos = pos * scale_factor;
+        let styled_text = text.styled_text();
+        let string = text.text();
+        let string = styled_text.as_ref().map_or(string.as_str(), |styled| styled.text.as_str());
+
+        let size = text.target_size() * scale_factor;
+        if size.width <= 0. || size.height <= 0. || pos.y < 0. {
+            return 0;
+        }
+
+        let font = crate::fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().font(font_request, scale_factor, string));
+
+        let paint = font.init_paint(text.letter_spacing() * scale_factor, Default::default());
+        let text_context =
+            crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
+        let font_height = text_context.measure_font(&paint).unwrap().height();
+        // Positions between lines belong to the closest line
+        let half_leading =
+            (font.line_height(PhysicalLength::new(font_height)).get() - font_height) / 2.;
+
+        let mut result = string.len();
+        crate::fonts::layout_text_lines(
+            string,
+            &font,
+            size,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+            false,
+            None,
+            &paint,
+            |line_text, line_pos, start, line| {
+                if ((line_pos.y - half_leading)..(line_pos.y + font_height + half_leading))
+                    .contains(&pos.y)
+                {
+                    result = start
+                        + line
+                            .byte_offset_for_x(pos.x - line_pos.x)
+                            .min(line_text.trim_end().len());
+                }
+            },
+        );
+        result
+    }
+
+    fn text_rects_for_byte_range(
+        &self,
+        text: Pin<&dyn RenderText>,
+        byte_range: std::ops::Range<usize>,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect> {
+        let styled_text = text.styled_text();
+        let string = text.text();
+        let string = styled_text.as_ref().map_or(string.as_str(), |styled| styled.text.as_str());
+
+        let size = text.target_size() * scale_factor;
+        if size.width <= 0. || size.height <= 0. {
+            return Vec::new();
+        }
+
+        let font = crate::fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().font(font_request, scale_factor, string));
+
+        let paint = font.init_paint(text.letter_spacing() * scale_factor, Default::default());
+        let text_context =
+            crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
+        let font_height = text_context.measure_font(&paint).unwrap().height();
+
+        let mut rects = Vec::new();
+        crate::fonts::layout_text_lines(
+            string,
+            &font,
+            size,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+            false,
+            None,
+            &paint,
+            |line_text, line_pos, start, line| {
+                let end = start + line_text.trim_end().len();
+                if byte_range.start >= end || byte_range.end <= start {
+                    return;
+                }
+                let range_in_line = byte_range.start.saturating_sub(start)
+                    ..(byte_range.end - start).min(end - start);
+                for x_range in line.x_ranges_for_byte_range(range_in_line) {
+                    let rect = PhysicalRect::new(
+                        PhysicalPoint::new(line_pos.x + x_range.start, line_pos.y),
+                        PhysicalSize::new(x_range.end - x_range.start, font_height),
+                    );
+                    rects.push(rect / scale_factor);
+                }
+            },
+        );
+        rects
+    }
+
     fn register_font_from_memory(
         &self,
-        data: &'static [u8],
+        data: std::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_memory(data)
+        sharedfontdb::register_font_from_memory(data)?;
+        crate::fonts::FONT_CACHE.with_borrow_mut(|cache| cache.update_available_families());
+        Ok(())
     }
 
     fn register_font_from_path(
         &self,
         path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_path(path)
+        sharedfontdb::register_font_from_path(path)?;
+        crate::fonts::FONT_CACHE.with_borrow_mut(|cache| cache.update_available_families());
+        Ok(())
+    }
+
+    fn font_families(&self) -> Vec<i_slint_core::graphics::FontFamily> {
+        i_slint_core::graphics::FontFamily::from_shared_fontdb()
     }
 
     fn default_font_size(&self) -> LogicalLength {
         self::fonts::DEFAULT_FONT_SIZE
     }
 
+    fn set_rendering_cache_budget(&self, budget: i_slint_core::graphics::RenderingCacheBudget) {
+        // Evicting entries deletes their textures. Textures that are shared through the texture
+        // cache are deleted when it is drained after the next frame.
+        let _ = self.opengl_context.borrow().ensure_current();
+        self.graphics_cache.set_budget(budget);
+    }
+
+    fn rendering_cache_statistics(&self) -> i_slint_core::graphics::RenderingCacheStatistics {
+        self.graphics_cache.statistics()
+    }
+
     fn set_rendering_notifier(
         &self,
         callback: Box<dyn i_slint_core::api::RenderingNotifier>,
@@ -481,6 +697,8 @@ fn set_rendering_notifier(
         if notifier.replace(callback).is_some() {
             Err(SetRenderingNotifierError::AlreadySet)
         } else {
+            // Send RenderingSetup before the next frame, even if frames were rendered already
+            self.rendering_first_time.set(true);
             Ok(())
         }
     }
@@ -488,8 +706,15 @@ fn set_rendering_notifier(
     fn free_graphics_resources(
         &self,
         component: i_slint_core::item_tree::ItemTreeRef,
-        _items: &mut dyn Iterator<Item = Pin<i_slint_core::items::ItemRef<'_>>>,
+        items: &mut dyn Iterator<Item = Pin<i_slint_core::items::ItemRef<'_>>>,
     ) -> Result<(), i_slint_core::platform::PlatformError> {
+        for item in items {
+            item.cached_rendering_data_offset()
+                .release(&mut self.partial_rendering_cache.borrow_mut());
+        }
+        // We don't know where the deleted items were on the screen, so refresh everything.
+        self.force_screen_refresh.set(true);
+
         if !self.graphics_cache.is_empty() {
             self.opengl_context.borrow().ensure_current()?;
             self.graphics_cache.component_destroyed(component);
@@ -499,6 +724,8 @@ fn free_graphics_resources(
 
     fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
+        self.partial_rendering_cache.borrow_mut().clear();
+        self.force_screen_refresh.set(true);
         if self.opengl_context.borrow().ensure_current().is_ok() {
             self.graphics_cache.clear_all();
             self.texture_cache.borrow_mut().clear();
@@ -512,6 +739,10 @@ fn resize(&self, size: i_slint_core::api::PhysicalSize) -> Result<(), PlatformEr
         Ok(())
     }
 
+    fn mark_dirty_region(&self, region: DirtyRegion) {
+        self.force_dirty.replace_with(|r| r.union(&region));
+    }
+
     /// Returns an image buffer of what was rendered last by reading the previous front buffer (using glReadPixels).
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.opengl_context.borrow().ensure_current()?;
@@ -530,6 +761,103 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
             screenshot.height() as u32,
         ))
     }
+
+    /// Renders the scene into a texture and reads it back (using glReadPixels).
+    fn render_to_image(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        self.opengl_context.borrow().ensure_current()?;
+        let Some(canvas) = self.canvas.borrow().as_ref().cloned() else {
+            return Err("FemtoVG renderer cannot render to an image without a window".into());
+        };
+
+        let window_adapter = self.window_adapter()?;
+        let window = window_adapter.window();
+        let window_size = window.size();
+
+        let Some((width, height)): Option<(NonZeroU32, NonZeroU32)> =
+            window_size.width.try_into().ok().zip(window_size.height.try_into().ok())
+        else {
+            return Err("render_to_image() called on window with invalid size".into());
+        };
+
+        let target_texture = images::Texture::new_empty_on_gpu(&canvas, width.get(), height.get())
+            .ok_or("FemtoVG error allocating texture to render into")?;
+
+        let window_inner = WindowInner::from_pub(window);
+        let scale = window_inner.scale_factor().ceil();
+
+        let screenshot = window_inner
+            .draw_contents(|components| -> Result<_, PlatformError> {
+                let window_background_brush =
+                    window_inner.window_item().map(|w| w.as_pin_ref().background());
+
+                self.graphics_cache.clear_cache_if_scale_factor_changed(window);
+
+                let mut item_renderer = self::itemrenderer::GLItemRenderer::new(
+                    &canvas,
+                    &self.graphics_cache,
+                    &self.texture_cache,
+                    window,
+                    width.get(),
+                    height.get(),
+                );
+
+                {
+                    let mut femtovg_canvas = canvas.borrow_mut();
+                    femtovg_canvas.set_size(width.get(), height.get(), scale);
+                    femtovg_canvas.reset();
+                }
+
+                item_renderer.set_render_target(&target_texture);
+
+                let clear_color = match &window_background_brush {
+                    Some(Brush::SolidColor(color)) => self::itemrenderer::to_femtovg_color(color),
+                    _ => femtovg::Color::rgba(0, 0, 0, 0),
+                };
+                canvas.borrow_mut().clear_rect(0, 0, width.get(), height.get(), clear_color);
+
+                // Draws the window background as gradient
+                match window_background_brush {
+                    Some(Brush::SolidColor(..)) | None => {}
+                    Some(brush) => {
+                        item_renderer.draw_rect(
+                            i_slint_core::lengths::logical_size_from_api(
+                                window_size.to_logical(window_inner.scale_factor()),
+                            ),
+                            brush,
+                        );
+                    }
+                }
+
+                for (component, origin) in components {
+                    i_slint_core::item_rendering::render_component_items(
+                        component,
+                        &mut item_renderer,
+                        *origin,
+                    );
+                }
+
+                // This flushes the canvas, while the texture is still the render target
+                let screenshot = canvas
+                    .borrow_mut()
+                    .screenshot()
+                    .map_err(|e| format!("FemtoVG error reading rendered image: {e}"))?;
+                canvas.borrow_mut().set_render_target(femtovg::RenderTarget::Screen);
+
+                // Delete any images and layer images (and their FBOs) before making the context not current anymore, to
+                // avoid GPU memory leaks.
+                self.texture_cache.borrow_mut().drain();
+                drop(item_renderer);
+                Ok(screenshot)
+            })
+            .ok_or("render_to_image() called on window without a component")??;
+
+        use rgb::ComponentBytes;
+        Ok(SharedPixelBuffer::clone_from_slice(
+            screenshot.buf().as_bytes(),
+            screenshot.width() as u32,
+            screenshot.height() as u32,
+        ))
+    }
 }
 
 impl Drop for FemtoVGRenderer {
@@ -567,10 +895,17 @@ fn new_without_context() -> Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
             canvas: RefCell::new(None),
-            graphics_cache: Default::default(),
+            graphics_cache: itemrenderer::ItemGraphicsCache::with_entry_size(
+                itemrenderer::ItemGraphicsCacheEntry::size_in_bytes,
+            ),
             texture_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
+            partial_rendering_cache: Default::default(),
+            force_dirty: Default::default(),
+            force_screen_refresh: Cell::new(true),
+            dirty_region_history: Default::default(),
+            previous_frame_geometry: Default::default(),
             opengl_context: RefCell::new(opengl_context),
             #[cfg(target_arch = "wasm32")]
             canvas_id: Default::default(),
@@ -655,6 +990,9 @@ fn set_opengl_context(
         *self.canvas.borrow_mut() = canvas.into();
         *self.opengl_context.borrow_mut() = opengl_context;
         self.rendering_first_time.set(true);
+        // Nothing is known about the content of the buffers of the new surface
+        self.dirty_region_history.borrow_mut().clear();
+        self.force_screen_refresh.set(true);
         Ok(())
     }
 
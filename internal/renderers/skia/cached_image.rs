@@ -71,6 +71,9 @@ pub(crate) fn as_skia_image(
                 SharedImageBuffer::RGB8(_) => unreachable!(),
                 SharedImageBuffer::RGBA8(_) => unreachable!(),
                 SharedImageBuffer::RGBA8Premultiplied(pixels) => pixels,
+                SharedImageBuffer::RGB565(_)
+                | SharedImageBuffer::Gray8(_)
+                | SharedImageBuffer::Alpha8(_) => unreachable!(),
             };
 
             let image_info = skia_safe::ImageInfo::new(
@@ -97,6 +100,13 @@ pub(crate) fn as_skia_image(
             origin,
             ..
         }) => unsafe {
+            let Some(mut recording_context) = canvas
+                .recording_context()
+                .filter(|context| context.backend() == skia_safe::gpu::BackendAPI::OpenGL)
+            else {
+                warn_borrowed_opengl_texture_unsupported();
+                return None;
+            };
             let mut texture_info = skia_safe::gpu::gl::TextureInfo::from_target_and_id(
                 glow::TEXTURE_2D,
                 texture_id.get(),
@@ -109,7 +119,7 @@ pub(crate) fn as_skia_image(
                 "Borrowed GL texture",
             );
             skia_safe::image::Image::from_texture(
-                canvas.recording_context().as_mut().unwrap(),
+                &mut recording_context,
                 &backend_texture,
                 match origin {
                     i_slint_core::graphics::BorrowedOpenGLTextureOrigin::TopLeft => {
@@ -128,10 +138,19 @@ pub(crate) fn as_skia_image(
             )
         },
         #[cfg(not(skia_backend_opengl))]
-        ImageInner::BorrowedOpenGLTexture(..) => None,
+        ImageInner::BorrowedOpenGLTexture(..) => {
+            warn_borrowed_opengl_texture_unsupported();
+            None
+        }
         ImageInner::NineSlice(n) => {
             as_skia_image(n.image(), target_size_fn, ImageFit::Preserve, scale_factor, canvas)
         }
+        ImageInner::AnimatedFrame(frame) => {
+            image_buffer_to_skia_image(&SharedImageBuffer::RGBA8(frame.frame().buffer.clone()))
+        }
+        ImageInner::Remote(remote) => {
+            as_skia_image(remote.image().into(), target_size_fn, image_fit, scale_factor, canvas)
+        }
     }
 }
 
@@ -166,6 +185,31 @@ fn image_buffer_to_skia_image(buffer: &SharedImageBuffer) -> Option<skia_safe::I
             skia_safe::ColorType::RGBA8888,
             skia_safe::AlphaType::Premul,
         ),
+        SharedImageBuffer::RGB565(pixels) => {
+            let bytes =
+                pixels.as_slice().iter().flat_map(|p| p.0.to_ne_bytes()).collect::<Vec<u8>>();
+            (
+                skia_safe::Data::new_copy(&*bytes),
+                pixels.width() as usize * 2,
+                pixels.size(),
+                skia_safe::ColorType::RGB565,
+                skia_safe::AlphaType::Opaque,
+            )
+        }
+        SharedImageBuffer::Gray8(pixels) => (
+            skia_safe::Data::new_copy(&pixels.as_slice().iter().map(|p| p.0).collect::<Vec<u8>>()),
+            pixels.width() as usize,
+            pixels.size(),
+            skia_safe::ColorType::Gray8,
+            skia_safe::AlphaType::Opaque,
+        ),
+        SharedImageBuffer::Alpha8(pixels) => (
+            skia_safe::Data::new_copy(&pixels.as_slice().iter().map(|p| p.0).collect::<Vec<u8>>()),
+            pixels.width() as usize,
+            pixels.size(),
+            skia_safe::ColorType::Alpha8,
+            skia_safe::AlphaType::Premul,
+        ),
     };
     let image_info = skia_safe::ImageInfo::new(
         skia_safe::ISize::new(size.width as i32, size.height as i32),
@@ -175,3 +219,11 @@ fn image_buffer_to_skia_image(buffer: &SharedImageBuffer) -> Option<skia_safe::I
     );
     skia_safe::images::raster_from_data(&image_info, data, bpl)
 }
+
+/// Images created from OpenGL textures can only be drawn when Skia renders with OpenGL.
+fn warn_borrowed_opengl_texture_unsupported() {
+    static WARNING: std::sync::Once = std::sync::Once::new();
+    WARNING.call_once(|| {
+        i_slint_core::debug_log!("The Skia renderer can't draw images created from OpenGL textures when it doesn't render with OpenGL.");
+    });
+}
@@ -9,16 +9,17 @@
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
-use i_slint_core::graphics::ApproxEq;
+use i_slint_core::graphics::{ApproxEq, PathDataEvent};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderImage, RenderText,
 };
 use i_slint_core::items::{
-    ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextStrokeStyle,
+    BlendMode, DropShadow, FillRule, ImageFit, ImageRendering, ItemRc, Layer, Opacity,
+    PatternTiling, RenderingResult, TextStrokeStyle,
 };
 use i_slint_core::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
-    LogicalVector, PhysicalPx, RectLengths, ScaleFactor, SizeLengths,
+    logical_point_from_api, LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx,
+    LogicalRect, LogicalSize, LogicalVector, PhysicalPx, RectLengths, ScaleFactor, SizeLengths,
 };
 use i_slint_core::window::WindowInner;
 use i_slint_core::{Brush, Color};
@@ -79,13 +80,14 @@ fn brush_to_paint(
         height: PhysicalLength,
     ) -> Option<skia_safe::Paint> {
         let (mut paint, shader) =
-            Self::brush_to_shader(self.default_paint().unwrap_or_default(), brush, width, height)?;
+            self.brush_to_shader(self.default_paint().unwrap_or_default(), brush, width, height)?;
         paint.set_shader(Some(shader));
 
         Some(paint)
     }
 
     fn brush_to_shader(
+        &self,
         mut paint: skia_safe::Paint,
         brush: Brush,
         width: PhysicalLength,
@@ -120,7 +122,10 @@ fn brush_to_shader(
             Brush::RadialGradient(g) => {
                 let (colors, pos): (Vec<_>, Vec<_>) =
                     g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
-                let circle_scale = width.max(height) / 2.;
+                let (center, radius) = g.circle_for_size(
+                    [width.get(), height.get()].into(),
+                    width.max(height).get() / 2.,
+                );
 
                 paint.set_dither(true);
 
@@ -131,11 +136,53 @@ fn brush_to_shader(
                     Some(&*pos),
                     TileMode::Clamp,
                     skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
-                    skia_safe::Matrix::scale((circle_scale.get(), circle_scale.get()))
-                        .post_translate((width.get() / 2., height.get() / 2.))
+                    skia_safe::Matrix::scale((radius, radius)).post_translate((center.x, center.y))
                         as &skia_safe::Matrix,
                 )
             }
+            Brush::ConicGradient(g) => {
+                let (colors, pos): (Vec<_>, Vec<_>) =
+                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let center = g.center();
+                let center = skia_safe::Point::new(center.x * width.get(), center.y * height.get());
+
+                paint.set_dither(true);
+
+                // Skia's sweep gradient starts at 3 o'clock, while ours start at 12 o'clock
+                skia_safe::gradient_shader::sweep(
+                    center,
+                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                    Some(&*pos),
+                    TileMode::Clamp,
+                    None,
+                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                    &skia_safe::Matrix::rotate_deg_pivot(g.from_angle() - 90., center),
+                )
+            }
+            Brush::ImagePattern(pattern) => {
+                let skia_image = image_pattern_tile(pattern, self.scale_factor, self.canvas)?;
+                let tile_size = LogicalSize::from_untyped(pattern.tile_size()) * self.scale_factor;
+                let offset = LogicalPoint::from_untyped(pattern.offset()) * self.scale_factor;
+                // Decal leaves the area outside of the image transparent, so that the image is only
+                // repeated in one direction.
+                let tile_modes = match pattern.tiling() {
+                    PatternTiling::Repeat => (TileMode::Repeat, TileMode::Repeat),
+                    PatternTiling::RepeatX => (TileMode::Repeat, TileMode::Decal),
+                    PatternTiling::RepeatY => (TileMode::Decal, TileMode::Repeat),
+                };
+                let matrix = Matrix::translate((offset.x, offset.y))
+                    * Matrix::scale((
+                        tile_size.width / skia_image.width() as f32,
+                        tile_size.height / skia_image.height() as f32,
+                    ));
+                skia_image.to_shader(
+                    tile_modes,
+                    skia_safe::sampling_options::SamplingOptions::from(
+                        skia_safe::sampling_options::FilterMode::Linear,
+                    ),
+                    &matrix,
+                )
+            }
             _ => None,
         }
         .map(|shader| (paint, shader))
@@ -153,7 +200,7 @@ fn colorize_image(
             None,
         );
 
-        Self::brush_to_shader(
+        self.brush_to_shader(
             skia_safe::Paint::default(), // Don't use the renderer's default paint because alpha is applied later
             colorize_brush,
             PhysicalLength::new(image.width() as f32),
@@ -227,6 +274,14 @@ fn draw_image_impl(
             )]
         };
 
+        let mut paint = self.default_paint();
+        let blend_mode = item.blend_mode();
+        if blend_mode != BlendMode::Normal {
+            paint
+                .get_or_insert_with(Default::default)
+                .set_blend_mode(to_skia_blend_mode(blend_mode));
+        }
+
         for fit in fits {
             self.canvas.save();
 
@@ -258,7 +313,7 @@ fn draw_image_impl(
                 if let Some(shader) = skia_image.make_subset(None, &src).and_then(|i| {
                     i.to_shader((TileMode::Repeat, TileMode::Repeat), filter_mode, &matrix)
                 }) {
-                    let mut paint = self.default_paint().unwrap_or_default();
+                    let mut paint = paint.clone().unwrap_or_default();
                     paint.set_shader(shader);
                     self.canvas.draw_paint(&paint);
                 }
@@ -271,7 +326,7 @@ fn draw_image_impl(
                     skia_image.clone(),
                     skia_safe::Point::default(),
                     filter_mode,
-                    self.default_paint().as_ref(),
+                    paint.as_ref(),
                 );
             }
 
@@ -356,6 +411,54 @@ pub fn draw_rect(&mut self, size: LogicalSize, brush: Brush) {
             };
         self.canvas.draw_rect(to_skia_rect(&geometry), &paint);
     }
+
+    /// Returns the path as a skia path in physical pixels, and the offset at which it's drawn.
+    fn fitted_path(
+        &self,
+        path: Pin<&i_slint_core::items::Path>,
+        item_rc: &i_slint_core::items::ItemRc,
+    ) -> Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)> {
+        self.path_cache.get_or_update_cache_entry(item_rc, || {
+            let (logical_offset, path_events): (crate::euclid::Vector2D<f32, LogicalPx>, _) =
+                path.fitted_path_events(item_rc)?;
+
+            let mut skpath = skia_safe::Path::new();
+
+            for x in path_events.iter() {
+                match x {
+                    PathDataEvent::Begin { at } => {
+                        skpath
+                            .move_to(to_skia_point(logical_point_from_api(at) * self.scale_factor));
+                    }
+                    PathDataEvent::Line { from: _, to } => {
+                        skpath
+                            .line_to(to_skia_point(logical_point_from_api(to) * self.scale_factor));
+                    }
+                    PathDataEvent::Quadratic { from: _, ctrl, to } => {
+                        skpath.quad_to(
+                            to_skia_point(logical_point_from_api(ctrl) * self.scale_factor),
+                            to_skia_point(logical_point_from_api(to) * self.scale_factor),
+                        );
+                    }
+
+                    PathDataEvent::Cubic { from: _, ctrl1, ctrl2, to } => {
+                        skpath.cubic_to(
+                            to_skia_point(logical_point_from_api(ctrl1) * self.scale_factor),
+                            to_skia_point(logical_point_from_api(ctrl2) * self.scale_factor),
+                            to_skia_point(logical_point_from_api(to) * self.scale_factor),
+                        );
+                    }
+                    PathDataEvent::End { last: _, first: _, close } => {
+                        if close {
+                            skpath.close();
+                        }
+                    }
+                }
+            }
+
+            (logical_offset * self.scale_factor, skpath).into()
+        })
+    }
 }
 
 impl<'a> ItemRenderer for SkiaItemRenderer<'a> {
@@ -425,6 +528,7 @@ fn draw_border_rectangle(
             geometry.height_length(),
         ) {
             fill_paint.set_style(skia_safe::PaintStyle::Fill);
+            fill_paint.set_blend_mode(to_skia_blend_mode(rect.blend_mode()));
             if !background_rect.is_rect() {
                 fill_paint.set_anti_alias(true);
             }
@@ -437,6 +541,7 @@ fn draw_border_rectangle(
             {
                 border_paint.set_style(skia_safe::PaintStyle::Stroke);
                 border_paint.set_stroke_width(border_width.get());
+                border_paint.set_blend_mode(to_skia_blend_mode(rect.blend_mode()));
                 if !border_rect.is_rect() {
                     border_paint.set_anti_alias(true);
                 }
@@ -473,8 +578,9 @@ fn draw_text(
             return;
         }
 
+        let styled_text = text.styled_text();
         let string = text.text();
-        let string = string.as_str();
+        let string = styled_text.as_ref().map_or(string.as_str(), |styled| styled.text.as_str());
         let font_request = text.font_request(WindowInner::from_pub(self.window));
 
         let paint = match self.brush_to_paint(text.color(), max_width, max_height) {
@@ -511,6 +617,11 @@ fn draw_text(
                     stroke_paint.set_stroke_join(skia_safe::PaintJoin::Miter);
                     stroke_paint.set_stroke_miter(10.0);
                     text_stroke_style.set_foreground_paint(&stroke_paint);
+                    // The stroke keeps its color in spans with a different color
+                    let stroke_styled_text = styled_text.clone().map(|mut styled| {
+                        styled.spans.iter_mut().for_each(|span| span.style.color = None);
+                        styled
+                    });
                     Some(super::textlayout::create_layout(
                         font_request.clone(),
                         self.scale_factor,
@@ -523,6 +634,7 @@ fn draw_text(
                         text.wrap(),
                         text.overflow(),
                         None,
+                        stroke_styled_text.as_ref(),
                     ))
                 }
             }
@@ -541,6 +653,7 @@ fn draw_text(
             text.wrap(),
             text.overflow(),
             None,
+            styled_text.as_ref(),
         );
 
         match (stroke_style, stroke_layout) {
@@ -614,6 +727,7 @@ fn draw_text_input(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             selection.as_ref(),
+            None,
         );
 
         layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
@@ -649,57 +763,11 @@ fn draw_path(
     ) {
         let geometry = PhysicalRect::from(size * self.scale_factor);
 
-        let (physical_offset, skpath): (crate::euclid::Vector2D<f32, PhysicalPx>, _) =
-            match self.path_cache.get_or_update_cache_entry(item_rc, || {
-                let (logical_offset, path_events): (crate::euclid::Vector2D<f32, LogicalPx>, _) =
-                    path.fitted_path_events(item_rc)?;
-
-                let mut skpath = skia_safe::Path::new();
-
-                for x in path_events.iter() {
-                    match x {
-                        lyon_path::Event::Begin { at } => {
-                            skpath.move_to(to_skia_point(
-                                LogicalPoint::from_untyped(at) * self.scale_factor,
-                            ));
-                        }
-                        lyon_path::Event::Line { from: _, to } => {
-                            skpath.line_to(to_skia_point(
-                                LogicalPoint::from_untyped(to) * self.scale_factor,
-                            ));
-                        }
-                        lyon_path::Event::Quadratic { from: _, ctrl, to } => {
-                            skpath.quad_to(
-                                to_skia_point(LogicalPoint::from_untyped(ctrl) * self.scale_factor),
-                                to_skia_point(LogicalPoint::from_untyped(to) * self.scale_factor),
-                            );
-                        }
-
-                        lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
-                            skpath.cubic_to(
-                                to_skia_point(
-                                    LogicalPoint::from_untyped(ctrl1) * self.scale_factor,
-                                ),
-                                to_skia_point(
-                                    LogicalPoint::from_untyped(ctrl2) * self.scale_factor,
-                                ),
-                                to_skia_point(LogicalPoint::from_untyped(to) * self.scale_factor),
-                            );
-                        }
-                        lyon_path::Event::End { last: _, first: _, close } => {
-                            if close {
-                                skpath.close();
-                            }
-                        }
-                    }
-                }
-
-                (logical_offset * self.scale_factor, skpath).into()
-            }) {
-                Some(offset_and_path) => offset_and_path,
-                None => return,
-            };
+        let Some((physical_offset, skpath)) = self.fitted_path(path, item_rc) else {
+            return;
+        };
 
+        self.canvas.save();
         self.canvas.translate((physical_offset.x, physical_offset.y));
 
         if let Some(mut fill_paint) =
@@ -714,8 +782,56 @@ fn draw_path(
             border_paint.set_anti_alias(true);
             border_paint.set_stroke_width((path.stroke_width() * self.scale_factor).get());
             border_paint.set_stroke(true);
+            let dash_pattern = path.dash_pattern();
+            if dash_pattern.iter().all(|length| *length >= 0.)
+                && dash_pattern.iter().sum::<f32>() > 0.
+            {
+                // Skia requires an even number of intervals
+                let intervals = dash_pattern
+                    .repeat(if dash_pattern.len() % 2 == 1 { 2 } else { 1 })
+                    .into_iter()
+                    .map(|length| length * self.scale_factor.get())
+                    .collect::<Vec<_>>();
+                border_paint.set_path_effect(skia_safe::PathEffect::dash(
+                    &intervals,
+                    (path.dash_offset() * self.scale_factor).get(),
+                ));
+            }
             self.canvas.draw_path(&skpath, &border_paint);
         }
+        self.canvas.restore();
+    }
+
+    fn visit_path_clip(
+        &mut self,
+        path: Pin<&i_slint_core::items::Path>,
+        item_rc: &i_slint_core::items::ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        if path.clip() {
+            self.save_state();
+            self.combine_clip(size.into(), LogicalBorderRadius::zero(), LogicalLength::zero());
+            self.draw_path(path, item_rc, size);
+            self.restore_state();
+        } else {
+            self.draw_path(path, item_rc, size);
+        }
+
+        let Some((physical_offset, skpath)) = self.fitted_path(path, item_rc) else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+        let mut clip_path = skpath.with_offset((physical_offset.x, physical_offset.y));
+        clip_path.set_fill_type(match path.fill_rule() {
+            FillRule::Nonzero => skia_safe::PathFillType::Winding,
+            FillRule::Evenodd => skia_safe::PathFillType::EvenOdd,
+        });
+        self.canvas.clip_path(&clip_path, None, true);
+
+        if self.canvas.local_clip_bounds().is_some() {
+            RenderingResult::ContinueRenderingChildren
+        } else {
+            RenderingResult::ContinueRenderingWithoutChildren
+        }
     }
 
     fn draw_box_shadow(
@@ -824,6 +940,10 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         self.canvas.rotate(angle_in_degrees, None);
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.canvas.scale((x_factor, y_factor));
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.current_state.alpha *= opacity;
     }
@@ -953,6 +1073,49 @@ fn visit_layer(
             RenderingResult::ContinueRenderingChildren
         }
     }
+
+    fn visit_drop_shadow(
+        &mut self,
+        drop_shadow: Pin<&DropShadow>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let color = drop_shadow.color();
+        if color.alpha() == 0 {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        let offset = LogicalPoint::from_lengths(drop_shadow.offset_x(), drop_shadow.offset_y())
+            * self.scale_factor;
+        // Negative blur values are ignored
+        let blur = drop_shadow.blur().max(LogicalLength::zero()) * self.scale_factor;
+
+        // The filter draws the children of the layer, with their shadow behind them
+        let Some(shadow_filter) = skia_safe::image_filters::drop_shadow(
+            skia_safe::Vector::new(offset.x, offset.y),
+            (blur.get() / 2., blur.get() / 2.),
+            to_skia_color(&color),
+            None,
+            None,
+            skia_safe::image_filters::CropRect::default(),
+        ) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+        let mut paint = skia_safe::Paint::default();
+        paint.set_image_filter(shadow_filter);
+
+        self.canvas.save_layer(&skia_safe::canvas::SaveLayerRec::default().paint(&paint));
+        self.state_stack.push(self.current_state);
+
+        i_slint_core::item_rendering::render_item_children(
+            self,
+            &item_rc.item_tree(),
+            item_rc.index() as isize,
+        );
+
+        self.current_state = self.state_stack.pop().unwrap();
+        self.canvas.restore();
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
 }
 
 pub fn from_skia_rect(rect: &skia_safe::Rect) -> PhysicalRect {
@@ -993,6 +1156,33 @@ pub fn to_skia_color(col: &Color) -> skia_safe::Color {
     skia_safe::Color::from_argb(col.alpha(), col.red(), col.green(), col.blue())
 }
 
+pub fn to_skia_blend_mode(blend_mode: BlendMode) -> skia_safe::BlendMode {
+    match blend_mode {
+        BlendMode::Normal => skia_safe::BlendMode::SrcOver,
+        BlendMode::Multiply => skia_safe::BlendMode::Multiply,
+        BlendMode::Screen => skia_safe::BlendMode::Screen,
+        BlendMode::Overlay => skia_safe::BlendMode::Overlay,
+        BlendMode::Darken => skia_safe::BlendMode::Darken,
+        BlendMode::Lighten => skia_safe::BlendMode::Lighten,
+    }
+}
+
+/// Returns the image of one tile of the pattern. SVGs are rasterized at the size of a tile, so that
+/// they stay sharp.
+fn image_pattern_tile(
+    pattern: &i_slint_core::graphics::ImagePatternBrush,
+    scale_factor: ScaleFactor,
+    canvas: &skia_safe::Canvas,
+) -> Option<skia_safe::Image> {
+    super::cached_image::as_skia_image(
+        pattern.image().clone(),
+        &|| LogicalSize::from_untyped(pattern.tile_size()),
+        ImageFit::Fill,
+        scale_factor,
+        canvas,
+    )
+}
+
 fn adjust_rect_and_border_for_inner_drawing(
     rect: &mut PhysicalRect,
     border_width: &mut PhysicalLength,
@@ -1004,3 +1194,14 @@ fn adjust_rect_and_border_for_inner_drawing(
     rect.origin += PhysicalSize::from_lengths(*border_width / 2., *border_width / 2.);
     rect.size -= PhysicalSize::from_lengths(*border_width, *border_width);
 }
+
+#[test]
+fn svg_image_pattern_rasterized_at_tile_size() {
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="5"><rect width="10" height="5" fill="red"/></svg>"#;
+    let image = i_slint_core::graphics::Image::load_from_svg_data(svg).unwrap();
+    let pattern = i_slint_core::graphics::ImagePatternBrush::new(image, PatternTiling::Repeat)
+        .with_transform(Default::default(), 3.);
+    let mut surface = skia_safe::surfaces::raster_n32_premul((1, 1)).unwrap();
+    let tile = image_pattern_tile(&pattern, ScaleFactor::new(2.), surface.canvas()).unwrap();
+    assert_eq!((tile.width(), tile.height()), (60, 30));
+}
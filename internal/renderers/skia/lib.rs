@@ -14,7 +14,7 @@
 use i_slint_core::graphics::euclid::{self, Vector2D};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetricsCollector;
 use i_slint_core::graphics::{BorderRadius, FontRequest, SharedPixelBuffer};
-use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
+use i_slint_core::item_rendering::{ItemCache, ItemRenderer, RenderText};
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
 };
@@ -82,6 +82,11 @@ fn create_default_surface(
     }
 }
 
+/// The number of bytes that a cached image occupies, for the budget of the image cache.
+fn image_cache_entry_size(image: &Option<skia_safe::Image>) -> usize {
+    image.as_ref().map_or(0, |image| image.image_info().compute_min_byte_size())
+}
+
 /// Use the SkiaRenderer when implementing a custom Slint platform where you deliver events to
 /// Slint and want the scene to be rendered using Skia as underlying graphics library.
 pub struct SkiaRenderer {
@@ -105,7 +110,7 @@ fn default() -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
-            image_cache: Default::default(),
+            image_cache: ItemCache::with_entry_size(image_cache_entry_size),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
@@ -123,7 +128,7 @@ pub fn default_software() -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
-            image_cache: Default::default(),
+            image_cache: ItemCache::with_entry_size(image_cache_entry_size),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
@@ -141,7 +146,7 @@ pub fn default_opengl() -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
-            image_cache: Default::default(),
+            image_cache: ItemCache::with_entry_size(image_cache_entry_size),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
@@ -168,7 +173,7 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
-            image_cache: Default::default(),
+            image_cache: ItemCache::with_entry_size(image_cache_entry_size),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
@@ -420,12 +425,21 @@ fn text_size(
             Default::default(),
             Default::default(),
             None,
+            None,
         );
 
         PhysicalSize::new(layout.max_intrinsic_width().ceil(), layout.height().ceil())
             / scale_factor
     }
 
+    fn font_metrics(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> i_slint_core::items::FontMetrics {
+        textlayout::font_metrics(font_request, scale_factor)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
@@ -455,6 +469,7 @@ fn text_input_byte_offset_for_position(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             None,
+            None,
         );
 
         let utf16_index =
@@ -503,6 +518,7 @@ fn text_input_cursor_rect_for_byte_offset(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             None,
+            None,
         );
 
         let physical_cursor_rect = textlayout::cursor_rect(
@@ -516,9 +532,113 @@ fn text_input_cursor_rect_for_byte_offset(
         physical_cursor_rect.translate(layout_top_left.to_vector()) / scale_factor
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: std::pin::Pin<&dyn RenderText>,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let max_width = text.target_size().width_length() * scale_factor;
+        let max_height = text.target_size().height_length() * scale_factor;
+        let pos = pos * scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return 0;
+        }
+
+        let styled_text = text.styled_text();
+        let string = text.text();
+        let string = styled_text.as_ref().map_or(string.as_str(), |styled| styled.text.as_str());
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let (layout, layout_top_left) = textlayout::create_layout(
+            font_request,
+            scale_factor,
+            string,
+            None,
+            Some(max_width),
+            max_height,
+            horizontal_alignment,
+            vertical_alignment,
+            text.wrap(),
+            text.overflow(),
+            None,
+            styled_text.as_ref(),
+        );
+
+        let utf16_index =
+            layout.get_glyph_position_at_coordinate((pos.x, pos.y - layout_top_left.y)).position;
+        let mut utf16_count = 0;
+        string
+            .char_indices()
+            .find(|(_, x)| {
+                let r = utf16_count >= utf16_index;
+                utf16_count += x.len_utf16() as i32;
+                r
+            })
+            .unwrap_or((string.len(), '\0'))
+            .0
+    }
+
+    fn text_rects_for_byte_range(
+        &self,
+        text: std::pin::Pin<&dyn RenderText>,
+        byte_range: std::ops::Range<usize>,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Vec<LogicalRect> {
+        let max_width = text.target_size().width_length() * scale_factor;
+        let max_height = text.target_size().height_length() * scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return Vec::new();
+        }
+
+        let styled_text = text.styled_text();
+        let string = text.text();
+        let string = styled_text.as_ref().map_or(string.as_str(), |styled| styled.text.as_str());
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let (layout, layout_top_left) = textlayout::create_layout(
+            font_request,
+            scale_factor,
+            string,
+            None,
+            Some(max_width),
+            max_height,
+            horizontal_alignment,
+            vertical_alignment,
+            text.wrap(),
+            text.overflow(),
+            None,
+            styled_text.as_ref(),
+        );
+
+        let utf16_start: usize = string[..byte_range.start].chars().map(char::len_utf16).sum();
+        let utf16_len: usize = string[byte_range].chars().map(char::len_utf16).sum();
+
+        layout
+            .get_rects_for_range(
+                utf16_start..utf16_start + utf16_len,
+                skia_safe::textlayout::RectHeightStyle::Max,
+                skia_safe::textlayout::RectWidthStyle::Tight,
+            )
+            .into_iter()
+            .map(|textbox| {
+                PhysicalRect::new(
+                    PhysicalPoint::new(textbox.rect.x(), textbox.rect.y()),
+                    PhysicalSize::new(textbox.rect.width(), textbox.rect.height()),
+                )
+                .translate(layout_top_left.to_vector())
+                    / scale_factor
+            })
+            .collect()
+    }
+
     fn register_font_from_memory(
         &self,
-        data: &'static [u8],
+        data: std::borrow::Cow<'static, [u8]>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         textlayout::register_font_from_memory(data)
     }
@@ -530,6 +650,10 @@ fn register_font_from_path(
         textlayout::register_font_from_path(path)
     }
 
+    fn font_families(&self) -> Vec<i_slint_core::graphics::FontFamily> {
+        textlayout::font_families()
+    }
+
     fn set_rendering_notifier(
         &self,
         callback: Box<dyn RenderingNotifier>,
@@ -543,6 +667,8 @@ fn set_rendering_notifier(
         if notifier.replace(callback).is_some() {
             Err(SetRenderingNotifierError::AlreadySet)
         } else {
+            // Send RenderingSetup before the next frame, even if frames were rendered already
+            self.rendering_first_time.set(true);
             Ok(())
         }
     }
@@ -551,6 +677,14 @@ fn default_font_size(&self) -> LogicalLength {
         self::textlayout::DEFAULT_FONT_SIZE
     }
 
+    fn set_rendering_cache_budget(&self, budget: i_slint_core::graphics::RenderingCacheBudget) {
+        self.image_cache.set_budget(budget);
+    }
+
+    fn rendering_cache_statistics(&self) -> i_slint_core::graphics::RenderingCacheStatistics {
+        self.image_cache.statistics()
+    }
+
     fn free_graphics_resources(
         &self,
         component: i_slint_core::item_tree::ItemTreeRef,
@@ -575,9 +709,15 @@ fn resize(&self, size: i_slint_core::api::PhysicalSize) -> Result<(), PlatformEr
         }
     }
 
-    /// Returns an image buffer of what was rendered last by reading the previous front buffer (using glReadPixels).
     fn take_snapshot(
         &self,
+    ) -> Result<SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>, PlatformError> {
+        self.render_to_image()
+    }
+
+    /// Renders the scene into a new image buffer, using Skia's raster backend.
+    fn render_to_image(
+        &self,
     ) -> Result<SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>, PlatformError> {
         let window_adapter = self.window_adapter()?;
         let window = window_adapter.window();
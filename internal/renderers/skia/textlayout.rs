@@ -5,7 +5,7 @@
 use std::collections::HashMap;
 
 use i_slint_core::graphics::euclid::num::Zero;
-use i_slint_core::graphics::FontRequest;
+use i_slint_core::graphics::{FontRequest, FontStyle};
 use i_slint_core::items::{TextHorizontalAlignment, TextVerticalAlignment};
 use i_slint_core::lengths::{LogicalLength, ScaleFactor};
 use i_slint_core::{items, Color};
@@ -17,7 +17,7 @@
 
 #[derive(PartialEq, Eq)]
 enum CustomFontSource {
-    ByData(&'static [u8]),
+    ByData(std::borrow::Cow<'static, [u8]>),
     ByPath(std::path::PathBuf),
 }
 
@@ -58,6 +58,61 @@ pub struct Selection {
     pub underline: bool,
 }
 
+fn font_families(font_request: &FontRequest) -> Vec<&str> {
+    font_request
+        .family
+        .iter()
+        .chain(font_request.fallback_families.iter())
+        .map(|family| family.as_str())
+        .collect()
+}
+
+fn font_style(font_request: &FontRequest) -> skia_safe::FontStyle {
+    skia_safe::FontStyle::new(
+        font_request.weight.map_or(skia_safe::font_style::Weight::NORMAL, |w| w.into()),
+        match font_request.stretch {
+            items::FontStretch::Normal => skia_safe::font_style::Width::NORMAL,
+            items::FontStretch::UltraCondensed => skia_safe::font_style::Width::ULTRA_CONDENSED,
+            items::FontStretch::ExtraCondensed => skia_safe::font_style::Width::EXTRA_CONDENSED,
+            items::FontStretch::Condensed => skia_safe::font_style::Width::CONDENSED,
+            items::FontStretch::SemiCondensed => skia_safe::font_style::Width::SEMI_CONDENSED,
+            items::FontStretch::SemiExpanded => skia_safe::font_style::Width::SEMI_EXPANDED,
+            items::FontStretch::Expanded => skia_safe::font_style::Width::EXPANDED,
+            items::FontStretch::ExtraExpanded => skia_safe::font_style::Width::EXTRA_EXPANDED,
+            items::FontStretch::UltraExpanded => skia_safe::font_style::Width::ULTRA_EXPANDED,
+        },
+        match font_request.style {
+            FontStyle::Normal => skia_safe::font_style::Slant::Upright,
+            FontStyle::Italic => skia_safe::font_style::Slant::Italic,
+            FontStyle::Oblique => skia_safe::font_style::Slant::Oblique,
+        },
+    )
+}
+
+pub fn font_metrics(font_request: FontRequest, scale_factor: ScaleFactor) -> items::FontMetrics {
+    let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
+    let style = font_style(&font_request);
+    let type_face = FONT_CACHE.with(|font_cache| {
+        let families = font_families(&font_request);
+        font_cache
+            .font_collection
+            .clone()
+            .find_typefaces(&families, style)
+            .into_iter()
+            .next()
+            .or_else(|| font_cache.font_mgr.legacy_make_typeface(None, style))
+    });
+    let Some(type_face) = type_face else { return Default::default() };
+    let (_, metrics) = skia_safe::Font::new(type_face, pixel_size.get()).metrics();
+    // Skia's ascent is negative and its descent positive, as y grows downwards
+    items::FontMetrics {
+        ascent: -metrics.ascent / scale_factor.get(),
+        descent: -metrics.descent / scale_factor.get(),
+        x_height: metrics.x_height / scale_factor.get(),
+        cap_height: metrics.cap_height / scale_factor.get(),
+    }
+}
+
 pub fn create_layout(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
@@ -70,11 +125,12 @@ pub fn create_layout(
     wrap: items::TextWrap,
     overflow: items::TextOverflow,
     selection: Option<&Selection>,
+    styled_text: Option<&i_slint_core::textlayout::StyledText>,
 ) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut text_style = text_style.unwrap_or_default();
 
-    if let Some(family_name) = font_request.family {
-        text_style.set_font_families(&[family_name.as_str()]);
+    if font_request.family.is_some() {
+        text_style.set_font_families(&font_families(&font_request));
     }
 
     let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
@@ -82,24 +138,29 @@ pub fn create_layout(
     if let Some(letter_spacing) = font_request.letter_spacing {
         text_style.set_letter_spacing((letter_spacing * scale_factor).get());
     }
+    if let Some(word_spacing) = font_request.word_spacing {
+        text_style.set_word_spacing((word_spacing * scale_factor).get());
+    }
     text_style.set_font_size(pixel_size.get());
-    text_style.set_font_style(skia_safe::FontStyle::new(
-        font_request.weight.map_or(skia_safe::font_style::Weight::NORMAL, |w| w.into()),
-        skia_safe::font_style::Width::NORMAL,
-        if font_request.italic {
-            skia_safe::font_style::Slant::Italic
-        } else {
-            skia_safe::font_style::Slant::Upright
-        },
-    ));
+    text_style.set_font_style(font_style(&font_request));
+    let metrics = text_style.font_metrics();
+    let font_height = metrics.descent - metrics.ascent;
+    let line_height = font_request.physical_line_height(font_height, scale_factor.get());
+    if let Some(line_height) = line_height {
+        // Skia's line height is a multiple of the font size
+        text_style.set_height_override(true);
+        text_style.set_height(line_height / pixel_size.get());
+        text_style.set_half_leading(true);
+    }
 
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
+    // Skia's paragraph has no tab stops, so tabs are shown as a space instead of a missing glyph
+    style.set_replace_tab_characters(true);
 
-    if overflow == items::TextOverflow::Elide {
+    if overflow != items::TextOverflow::Clip {
         style.set_ellipsis("…");
         if wrap != items::TextWrap::NoWrap {
-            let metrics = text_style.font_metrics();
-            let line_height = metrics.descent - metrics.ascent + metrics.leading;
+            let line_height = line_height.unwrap_or(font_height + metrics.leading);
             style.set_max_lines((max_height.get() / line_height).floor() as usize);
         }
     }
@@ -108,10 +169,29 @@ pub fn create_layout(
         items::TextHorizontalAlignment::Left => skia_safe::textlayout::TextAlign::Left,
         items::TextHorizontalAlignment::Center => skia_safe::textlayout::TextAlign::Center,
         items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
+        items::TextHorizontalAlignment::Start => skia_safe::textlayout::TextAlign::Start,
+        items::TextHorizontalAlignment::End => skia_safe::textlayout::TextAlign::End,
     });
+    // Start and End are relative to the direction of the paragraph, which is also the order of the
+    // runs of mixed direction text.
+    if i_slint_core::textlayout::is_rtl_paragraph(text) {
+        style.set_text_direction(skia_safe::textlayout::TextDirection::RTL);
+    }
 
     style.set_text_style(&text_style);
 
+    let elided_text = match (overflow, max_width) {
+        (items::TextOverflow::ElideLeft | items::TextOverflow::ElideMiddle, Some(max_width))
+            if wrap == items::TextWrap::NoWrap =>
+        {
+            Some(elide_lines(text, &style, max_width.get(), overflow))
+        }
+        _ => None,
+    };
+    let text = elided_text.as_deref().unwrap_or(text);
+    // The spans of the markup don't apply to the elided text anymore
+    let styled_text = styled_text.filter(|_| elided_text.is_none());
+
     let mut builder = FONT_CACHE.with(|font_cache| {
         skia_safe::textlayout::ParagraphBuilder::new(&style, font_cache.font_collection.clone())
     });
@@ -148,6 +228,28 @@ pub fn create_layout(
 
         let after_selection = &text[selection.range.end..];
         builder.add_text(after_selection);
+    } else if let Some(styled_text) = styled_text {
+        for span in &styled_text.spans {
+            let mut span_style = text_style.clone();
+            span_style.set_font_style(font_style(&span.style.font_request(&font_request)));
+
+            if let Some(color) = span.style.color {
+                let mut color_paint = skia_safe::Paint::default();
+                color_paint.set_color(to_skia_color(&color));
+                span_style.set_foreground_paint(&color_paint);
+            }
+
+            if span.style.underline {
+                let mut decoration = skia_safe::textlayout::Decoration::default();
+                decoration.ty = skia_safe::textlayout::TextDecoration::UNDERLINE;
+                decoration.color = span_style.foreground().color();
+                span_style.set_decoration(&decoration);
+            }
+
+            builder.push_style(&span_style);
+            builder.add_text(&text[span.range.clone()]);
+            builder.pop();
+        }
     } else {
         builder.add_text(text);
     }
@@ -166,6 +268,57 @@ pub fn create_layout(
     (paragraph, PhysicalPoint::from_lengths(Default::default(), layout_top_y))
 }
 
+/// Skia's paragraph only elides at the end of the text, so to elide at the start or in the middle,
+/// the lines that are too wide get their ellipsis before the paragraph is built.
+fn elide_lines(
+    text: &str,
+    style: &skia_safe::textlayout::ParagraphStyle,
+    max_width: f32,
+    overflow: items::TextOverflow,
+) -> String {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let text_width = |text: &str| {
+        let mut builder = FONT_CACHE.with(|font_cache| {
+            skia_safe::textlayout::ParagraphBuilder::new(style, font_cache.font_collection.clone())
+        });
+        builder.add_text(text);
+        let mut paragraph = builder.build();
+        paragraph.layout(f32::MAX);
+        paragraph.max_intrinsic_width()
+    };
+
+    text.split('\n')
+        .map(|line| {
+            if text_width(line) <= max_width {
+                return line.to_string();
+            }
+            let boundaries = line
+                .grapheme_indices(true)
+                .map(|(index, _)| index)
+                .chain(std::iter::once(line.len()))
+                .collect::<Vec<_>>();
+            let start = if overflow == items::TextOverflow::ElideMiddle {
+                let count = boundaries.partition_point(|boundary| {
+                    text_width(&format!("{}…", &line[..*boundary])) <= max_width / 2.
+                });
+                count.saturating_sub(1)
+            } else {
+                0
+            };
+            let prefix = &line[..boundaries[start]];
+            let end = start
+                + 1
+                + boundaries[start + 1..].partition_point(|boundary| {
+                    text_width(&format!("{prefix}…{}", &line[*boundary..])) > max_width
+                });
+            let suffix = boundaries.get(end).map_or("", |boundary| &line[*boundary..]);
+            format!("{prefix}…{suffix}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn register_font(source: CustomFontSource) -> Result<(), Box<dyn std::error::Error>> {
     FONT_CACHE.with(|font_cache| {
         if font_cache
@@ -179,7 +332,7 @@ fn register_font(source: CustomFontSource) -> Result<(), Box<dyn std::error::Err
         }
 
         let data: std::borrow::Cow<[u8]> = match &source {
-            CustomFontSource::ByData(data) => std::borrow::Cow::Borrowed(data),
+            CustomFontSource::ByData(data) => std::borrow::Cow::Borrowed(data.as_ref()),
             CustomFontSource::ByPath(path) => std::borrow::Cow::Owned(std::fs::read(path)?),
         };
 
@@ -201,7 +354,9 @@ fn register_font(source: CustomFontSource) -> Result<(), Box<dyn std::error::Err
     })
 }
 
-pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
+pub fn register_font_from_memory(
+    data: std::borrow::Cow<'static, [u8]>,
+) -> Result<(), Box<dyn std::error::Error>> {
     register_font(CustomFontSource::ByData(data))
 }
 
@@ -209,6 +364,39 @@ pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std
     register_font(CustomFontSource::ByPath(path.into()))
 }
 
+pub fn font_families() -> Vec<i_slint_core::graphics::FontFamily> {
+    use i_slint_core::graphics::{FontFace, FontFamily};
+    use skia_safe::font_style::Slant;
+    FONT_CACHE.with(|font_cache| {
+        // The registered custom fonts
+        let custom_font_mgr: skia_safe::FontMgr =
+            font_cache.type_face_font_provider.borrow().clone().into();
+        let faces = [&font_cache.font_mgr, &custom_font_mgr].into_iter().flat_map(
+            |font_mgr: &skia_safe::FontMgr| {
+                font_mgr.family_names().flat_map(move |family_name| {
+                    let mut style_set = font_mgr.match_family(&family_name);
+                    let family_name = i_slint_core::SharedString::from(family_name);
+                    (0..style_set.count())
+                        .map(|index| {
+                            let (style, _) = style_set.style(index);
+                            let face = FontFace {
+                                weight: *style.weight(),
+                                style: match style.slant() {
+                                    Slant::Upright => FontStyle::Normal,
+                                    Slant::Italic => FontStyle::Italic,
+                                    Slant::Oblique => FontStyle::Oblique,
+                                },
+                            };
+                            (family_name.clone(), face)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+        FontFamily::collect_families(faces)
+    })
+}
+
 pub fn cursor_rect(
     string: &str,
     cursor_pos: usize,
@@ -217,10 +405,10 @@ pub fn cursor_rect(
     h_align: TextHorizontalAlignment,
 ) -> PhysicalRect {
     if string.is_empty() {
-        let x = match h_align {
-            TextHorizontalAlignment::Left => PhysicalLength::default(),
+        let x = match h_align.resolve_for_text(string) {
             TextHorizontalAlignment::Center => PhysicalLength::new(layout.max_width() / 2.),
             TextHorizontalAlignment::Right => PhysicalLength::new(layout.max_width()),
+            _ => PhysicalLength::default(),
         };
         return PhysicalRect::new(
             PhysicalPoint::from_lengths(x, PhysicalLength::default()),
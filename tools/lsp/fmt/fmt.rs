@@ -1069,7 +1069,20 @@ fn format_transition(
 ) -> Result<(), std::io::Error> {
     let mut sub = node.children_with_tokens();
     let ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
-        && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?;
+        && match whitespace_to_one_of(
+            &mut sub,
+            &[SyntaxKind::LBrace, SyntaxKind::Identifier],
+            writer,
+            state,
+            " ",
+        )? {
+            // `in from xxx {` or `out to xxx {`
+            SyntaxMatch::Found(SyntaxKind::Identifier) => {
+                whitespace_to(&mut sub, SyntaxKind::TransitionState, writer, state, " ")?
+                    && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
+            }
+            r => r.is_found(),
+        };
 
     if !ok {
         finish_node(sub, writer, state)?;
@@ -1126,7 +1139,7 @@ fn format_property_animation(
     }
 
     let bindings = node.children().fold(0, |acc, e| {
-        if e.kind() == SyntaxKind::Binding {
+        if matches!(e.kind(), SyntaxKind::Binding | SyntaxKind::CallbackConnection) {
             return acc + 1;
         }
         acc
@@ -1734,6 +1747,27 @@ fn states_transitions() {
         }
     ]
 }
+",
+        );
+
+        assert_formatting(
+            "component FooBar {states[foo:{x:0;}bar:{in  from foo{animate x{duration:1ms;}}out to   foo {animate x{duration:2ms;}}x:1;}]}",
+            r"component FooBar {
+    states [
+        foo: {
+            x: 0;
+        }
+        bar: {
+            in from foo {
+                animate x { duration: 1ms; }
+            }
+            out to foo {
+                animate x { duration: 2ms; }
+            }
+            x: 1;
+        }
+    ]
+}
 ",
         );
     }
@@ -217,6 +217,7 @@ pub(crate) fn completion_at(
                     ("image-url", "image-url(\"$1\")"),
                     ("linear-gradient", "linear-gradient($1)"),
                     ("radial-gradient", "radial-gradient(circle, $1)"),
+                    ("conic-gradient", "conic-gradient($1)"),
                 ]
                 .into_iter()
                 .map(|(label, insert)| {
@@ -392,11 +393,20 @@ pub(crate) fn completion_at(
             .property_list()
             .into_iter()
             .map(|(k, t)| {
+                let is_callback = matches!(t, Type::Callback { .. });
                 let mut c = CompletionItem::new_simple(k, t.to_string());
-                c.kind = Some(CompletionItemKind::PROPERTY);
+                c.kind = Some(if is_callback {
+                    CompletionItemKind::METHOD
+                } else {
+                    CompletionItemKind::PROPERTY
+                });
                 if snippet_support {
                     c.insert_text_format = Some(InsertTextFormat::SNIPPET);
-                    c.insert_text = Some(format!("{}: $1;", c.label));
+                    c.insert_text = Some(if is_callback {
+                        format!("{} => {{ $1 }}", c.label)
+                    } else {
+                        format!("{}: $1;", c.label)
+                    });
                 }
                 c
             })
@@ -1212,6 +1222,8 @@ fn animation_completion() {
         res.iter().find(|ci| ci.label == "duration").unwrap();
         res.iter().find(|ci| ci.label == "iteration-count").unwrap();
         res.iter().find(|ci| ci.label == "easing").unwrap();
+        let finished = res.iter().find(|ci| ci.label == "finished").unwrap();
+        assert_eq!(finished.kind, Some(CompletionItemKind::METHOD));
     }
 
     #[test]
@@ -1257,6 +1269,7 @@ fn animation_easing_completion() {
         res.iter().find(|ci| ci.label == "ease-in-out-bounce").unwrap();
         res.iter().find(|ci| ci.label == "linear").unwrap();
         res.iter().find(|ci| ci.label == "cubic-bezier").unwrap();
+        res.iter().find(|ci| ci.label == "spring").unwrap();
     }
 
     #[test]
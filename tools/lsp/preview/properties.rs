@@ -351,14 +351,10 @@ fn get_properties(element: &common::ElementRcNode) -> Vec<PropertyInformation> {
                     group: String::new(),
                 });
 
-                if b.name == "Image" {
-                    result.extend(get_reserved_properties(
-                        "rotation",
-                        i_slint_compiler::typeregister::RESERVED_ROTATION_PROPERTIES
-                            .iter()
-                            .cloned(),
-                    ));
-                }
+                result.extend(get_reserved_properties(
+                    "transform",
+                    i_slint_compiler::typeregister::RESERVED_TRANSFORM_PROPERTIES.iter().cloned(),
+                ));
 
                 if b.name == "Rectangle" {
                     result.extend(get_reserved_properties(